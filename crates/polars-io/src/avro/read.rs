@@ -1,13 +1,21 @@
 use std::io::{Read, Seek};
 
+use arrow::io::avro::avro_schema::file::FileMetadata;
 use arrow::io::avro::{self, read};
 use arrow::record_batch::RecordBatch;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 use crate::shared::{ArrowReader, finish_reader};
 
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct AvroScanOptions;
+
 /// Read [Apache Avro] format into a [`DataFrame`]
 ///
 /// [Apache Avro]: https://avro.apache.org
@@ -70,6 +78,66 @@ impl<R: Read + Seek> AvroReader<R> {
     }
 }
 
+/// Location of a block in an Avro file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AvroBlockInfo {
+    /// Byte offset of the start of the block.
+    pub offset: usize,
+    pub num_rows: usize,
+}
+
+/// Read the metadata of an Avro file and the locations of its blocks.
+///
+/// This only reads the block headers, the blocks themselves are not decompressed or decoded.
+pub fn read_file_index(bytes: &[u8]) -> PolarsResult<(FileMetadata, Vec<AvroBlockInfo>)> {
+    let mut reader = std::io::Cursor::new(bytes);
+    let metadata = avro::avro_schema::read::read_metadata(&mut reader).map_err(to_compute_err)?;
+
+    let mut offset = reader.position() as usize;
+    let mut blocks = Vec::new();
+    while offset < bytes.len() {
+        let mut pos = offset;
+        let num_rows = read_block_long(bytes, &mut pos)?;
+        let num_bytes = read_block_long(bytes, &mut pos)?;
+
+        let marker_start = pos.saturating_add(num_bytes);
+        let marker = bytes.get(marker_start..marker_start.saturating_add(metadata.marker.len()));
+        polars_ensure!(
+            marker == Some(metadata.marker.as_slice()),
+            ComputeError: "avro block at offset {offset} does not end with the sync marker"
+        );
+
+        blocks.push(AvroBlockInfo { offset, num_rows });
+        offset = marker_start + metadata.marker.len();
+    }
+
+    Ok((metadata, blocks))
+}
+
+/// Read a non-negative zigzag encoded long from a block header.
+fn read_block_long(bytes: &[u8], pos: &mut usize) -> PolarsResult<usize> {
+    let mut value = 0u64;
+    for i in 0..10 {
+        let Some(&byte) = bytes.get(*pos) else {
+            polars_bail!(ComputeError: "unexpected end of avro file in block header");
+        };
+        *pos += 1;
+        value |= u64::from(byte & 0x7F) << (i * 7);
+        if byte >> 7 == 0 {
+            let value = (value >> 1) as i64 ^ -((value & 1) as i64);
+            return usize::try_from(value)
+                .map_err(|_| polars_err!(ComputeError: "negative length in avro block header"));
+        }
+    }
+    polars_bail!(ComputeError: "zigzag decoding failed - corrupt avro file")
+}
+
+/// Count the rows of an Avro file by summing the row counts of its blocks.
+pub fn count_rows(bytes: &[u8]) -> PolarsResult<usize> {
+    let (_, blocks) = read_file_index(bytes)?;
+    Ok(blocks.iter().map(|block| block.num_rows).sum())
+}
+
 impl<R> ArrowReader for read::Reader<R>
 where
     R: Read + Seek,
@@ -1,14 +1,55 @@
 use std::io::Write;
 
-pub use Compression as AvroCompression;
 pub use arrow::io::avro::avro_schema::file::Compression;
+use arrow::io::avro::avro_schema::file::{Block, CompressedBlock};
+use arrow::io::avro::avro_schema::schema::Record;
 use arrow::io::avro::avro_schema::{self};
 use arrow::io::avro::write;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::shared::{SerWriter, schema_to_arrow_checked};
 
+/// Compression codec
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum AvroCompression {
+    /// Deflate
+    Deflate,
+    /// Snappy
+    Snappy,
+}
+
+impl From<AvroCompression> for Compression {
+    fn from(value: AvroCompression) -> Self {
+        match value {
+            AvroCompression::Deflate => Compression::Deflate,
+            AvroCompression::Snappy => Compression::Snappy,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct AvroWriterOptions {
+    /// Block compression
+    pub compression: Option<AvroCompression>,
+    /// Name of the record schema
+    pub name: PlSmallStr,
+}
+
+impl AvroWriterOptions {
+    pub fn to_writer<W: Write>(&self, writer: W) -> AvroWriter<W> {
+        AvroWriter::new(writer)
+            .with_compression(self.compression)
+            .with_name(self.name.to_string())
+    }
+}
+
 /// Write a [`DataFrame`] to [Apache Avro] format
 ///
 /// [Apache Avro]: https://avro.apache.org
@@ -33,6 +74,7 @@ pub struct AvroWriter<W> {
     writer: W,
     compression: Option<AvroCompression>,
     name: String,
+    include_header: bool,
 }
 
 impl<W> AvroWriter<W>
@@ -49,6 +91,34 @@ where
         self.name = name;
         self
     }
+
+    /// Whether to write the file header, which holds the schema. Defaults to true.
+    ///
+    /// Writers that only produce the data blocks can be concatenated after a single header.
+    pub fn include_header(mut self, include_header: bool) -> Self {
+        self.include_header = include_header;
+        self
+    }
+
+    pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        let schema = schema_to_arrow_checked(schema, CompatLevel::oldest(), "avro")?;
+        let record = write::to_record(&schema, self.name)?;
+        let compression = self.compression.map(Compression::from);
+
+        let mut writer = self.writer;
+        if self.include_header {
+            avro_schema::write::write_metadata(&mut writer, record.clone(), compression)
+                .map_err(to_compute_err)?;
+        }
+
+        Ok(BatchedWriter {
+            writer,
+            record,
+            compression,
+            block: Block::new(0, vec![]),
+            compressed_block: CompressedBlock::default(),
+        })
+    }
 }
 
 impl<W> SerWriter<W> for AvroWriter<W>
@@ -60,41 +130,58 @@ where
             writer,
             compression: None,
             name: "".to_string(),
+            include_header: true,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let schema = schema_to_arrow_checked(df.schema(), CompatLevel::oldest(), "avro")?;
-        let record = write::to_record(&schema, self.name.clone())?;
+        let writer = AvroWriter {
+            writer: &mut self.writer,
+            compression: self.compression,
+            name: self.name.clone(),
+            include_header: self.include_header,
+        };
+        let mut batched = writer.batched(df.schema())?;
+        batched.write_batch(df)
+    }
+}
 
-        let mut data = vec![];
-        let mut compressed_block = avro_schema::file::CompressedBlock::default();
+/// Writes [`DataFrame`]s as the blocks of an Avro file.
+pub struct BatchedWriter<W: Write> {
+    writer: W,
+    record: Record,
+    compression: Option<Compression>,
+    // Reused for every block.
+    block: Block,
+    compressed_block: CompressedBlock,
+}
+
+impl<W: Write> BatchedWriter<W> {
+    /// Write a batch as one block per chunk.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         for chunk in df.iter_chunks(CompatLevel::oldest(), true) {
             let mut serializers = chunk
                 .iter()
-                .zip(record.fields.iter())
+                .zip(self.record.fields.iter())
                 .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
                 .collect::<Vec<_>>();
 
-            let mut block =
-                avro_schema::file::Block::new(chunk.arrays()[0].len(), std::mem::take(&mut data));
-            write::serialize(&mut serializers, &mut block);
-            let _was_compressed =
-                avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
-                    .map_err(to_compute_err)?;
-
-            avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
-                .map_err(to_compute_err)?;
+            self.block.number_of_rows = chunk.height();
+            self.block.data.clear();
+            write::serialize(&mut serializers, &mut self.block);
+            let _was_compressed = avro_schema::write::compress(
+                &mut self.block,
+                &mut self.compressed_block,
+                self.compression,
+            )
+            .map_err(to_compute_err)?;
 
-            avro_schema::write::write_block(&mut self.writer, &compressed_block)
+            avro_schema::write::write_block(&mut self.writer, &self.compressed_block)
                 .map_err(to_compute_err)?;
-            // reuse block for next iteration.
-            data = block.data;
-            data.clear();
 
-            // reuse block for next iteration
-            compressed_block.data.clear();
-            compressed_block.number_of_rows = 0
+            // Reuse the compressed block for the next chunk.
+            self.compressed_block.data.clear();
+            self.compressed_block.number_of_rows = 0
         }
 
         Ok(())
@@ -0,0 +1,419 @@
+//! Reading and writing of the Avro object container files used for the manifest lists and
+//! manifests of Iceberg tables.
+//!
+//! Files are read with the Arrow Avro reader, after which the rows are converted into an
+//! [`AvroValue`] tree rather than into a DataFrame, as manifests are small and deeply nested. A
+//! dedicated writer is needed as the Avro schemas of these files must carry the Iceberg `field-id`
+//! of every field.
+//!
+//! Reference: <https://avro.apache.org/docs/1.11.1/specification/#object-container-files>
+use arrow::array::{
+    Array, BinaryArray, BooleanArray, FixedSizeBinaryArray, ListArray, PrimitiveArray, StructArray,
+    Utf8Array,
+};
+use arrow::datatypes::ArrowDataType;
+use arrow::io::avro::avro_schema::schema::Schema as AvroSchema;
+use arrow::io::avro::avro_schema::write::encode::zigzag_encode;
+use arrow::io::avro::{avro_schema, read};
+use arrow::types::NativeType;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, to_compute_err};
+
+const MAGIC: &[u8; 4] = b"Obj\x01";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AvroValue {
+    Null,
+    Boolean(bool),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    /// Also used for `fixed` values.
+    Bytes(Vec<u8>),
+    /// Also used for `enum` values.
+    String(String),
+    Record(Vec<(String, AvroValue)>),
+    Array(Vec<AvroValue>),
+    Map(Vec<(String, AvroValue)>),
+}
+
+impl AvroValue {
+    /// Gets a field of a record. Returns `None` if the field does not exist or is null.
+    pub fn field(&self, name: &str) -> Option<&AvroValue> {
+        let AvroValue::Record(fields) = self else {
+            return None;
+        };
+
+        fields
+            .iter()
+            .find(|(field_name, _)| field_name == name)
+            .map(|(_, value)| value)
+            .filter(|value| !matches!(value, AvroValue::Null))
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            AvroValue::Boolean(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_i32(&self) -> Option<i32> {
+        match self {
+            AvroValue::Int(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Also accepts `int` values.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            AvroValue::Int(v) => Some(*v as i64),
+            AvroValue::Long(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            AvroValue::String(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            AvroValue::Bytes(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[AvroValue]> {
+        match self {
+            AvroValue::Array(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl From<i64> for AvroValue {
+    fn from(value: i64) -> Self {
+        AvroValue::Long(value)
+    }
+}
+
+impl From<Vec<u8>> for AvroValue {
+    fn from(value: Vec<u8>) -> Self {
+        AvroValue::Bytes(value)
+    }
+}
+
+fn downcast<T: 'static>(array: &dyn Array) -> &T {
+    array.as_any().downcast_ref::<T>().unwrap()
+}
+
+fn primitive_value<T: NativeType>(array: &dyn Array, idx: usize) -> T {
+    downcast::<PrimitiveArray<T>>(array).value(idx)
+}
+
+/// Converts the value at `idx` of an array produced by the Arrow Avro reader.
+fn array_value_to_avro(array: &dyn Array, idx: usize) -> PolarsResult<AvroValue> {
+    use ArrowDataType as D;
+
+    if array.is_null(idx) {
+        return Ok(AvroValue::Null);
+    }
+
+    Ok(match array.dtype().to_logical_type() {
+        D::Null => AvroValue::Null,
+        D::Boolean => AvroValue::Boolean(downcast::<BooleanArray>(array).value(idx)),
+        D::Int32 | D::Date32 | D::Time32(_) => AvroValue::Int(primitive_value(array, idx)),
+        D::Int64 | D::Timestamp(..) | D::Time64(_) => AvroValue::Long(primitive_value(array, idx)),
+        D::Float32 => AvroValue::Float(primitive_value(array, idx)),
+        D::Float64 => AvroValue::Double(primitive_value(array, idx)),
+        D::Decimal(..) => {
+            AvroValue::Bytes(primitive_value::<i128>(array, idx).to_be_bytes().to_vec())
+        },
+        D::Binary => AvroValue::Bytes(downcast::<BinaryArray<i32>>(array).value(idx).to_vec()),
+        D::FixedSizeBinary(_) => {
+            AvroValue::Bytes(downcast::<FixedSizeBinaryArray>(array).value(idx).to_vec())
+        },
+        D::Utf8 => AvroValue::String(downcast::<Utf8Array<i32>>(array).value(idx).to_string()),
+        D::List(_) => {
+            let values = downcast::<ListArray<i32>>(array).value(idx);
+
+            AvroValue::Array(
+                (0..values.len())
+                    .map(|i| array_value_to_avro(values.as_ref(), i))
+                    .collect::<PolarsResult<_>>()?,
+            )
+        },
+        D::Struct(_) => {
+            let array = downcast::<StructArray>(array);
+
+            AvroValue::Record(
+                array
+                    .fields()
+                    .iter()
+                    .zip(array.values())
+                    .map(|(field, values)| {
+                        Ok((
+                            field.name.to_string(),
+                            array_value_to_avro(values.as_ref(), idx)?,
+                        ))
+                    })
+                    .collect::<PolarsResult<_>>()?,
+            )
+        },
+        dtype => {
+            polars_bail!(ComputeError: "unsupported avro type in iceberg metadata: {:?}", dtype)
+        },
+    })
+}
+
+/// Reads the records of an Avro object container file.
+pub fn read_avro_file(bytes: &[u8]) -> PolarsResult<Vec<AvroValue>> {
+    let mut reader = std::io::Cursor::new(bytes);
+    let metadata = avro_schema::read::read_metadata(&mut reader).map_err(to_compute_err)?;
+    let schema = read::infer_schema(&metadata.record)?;
+    let names = schema.iter_names().cloned().collect::<Vec<_>>();
+
+    let mut values = vec![];
+
+    for batch in read::Reader::new(reader, metadata, schema, None) {
+        let batch = batch?;
+
+        for idx in 0..batch.height() {
+            values.push(AvroValue::Record(
+                names
+                    .iter()
+                    .zip(batch.arrays())
+                    .map(|(name, array)| {
+                        Ok((name.to_string(), array_value_to_avro(array.as_ref(), idx)?))
+                    })
+                    .collect::<PolarsResult<_>>()?,
+            ));
+        }
+    }
+
+    Ok(values)
+}
+
+fn write_long(out: &mut Vec<u8>, v: i64) {
+    // Writing to a `Vec` does not fail.
+    zigzag_encode(v, out).unwrap()
+}
+
+fn write_len_prefixed(out: &mut Vec<u8>, v: &[u8]) {
+    write_long(out, v.len() as i64);
+    out.extend_from_slice(v);
+}
+
+fn matches_schema(schema: &AvroSchema, value: &AvroValue) -> bool {
+    matches!(
+        (schema, value),
+        (AvroSchema::Null, AvroValue::Null)
+            | (AvroSchema::Boolean, AvroValue::Boolean(_))
+            | (AvroSchema::Int(_), AvroValue::Int(_))
+            | (AvroSchema::Long(_), AvroValue::Long(_) | AvroValue::Int(_))
+            | (AvroSchema::Float, AvroValue::Float(_))
+            | (AvroSchema::Double, AvroValue::Double(_))
+            | (
+                AvroSchema::Bytes(_) | AvroSchema::Fixed(_),
+                AvroValue::Bytes(_)
+            )
+            | (
+                AvroSchema::String(_) | AvroSchema::Enum(_),
+                AvroValue::String(_)
+            )
+            | (AvroSchema::Record(_), AvroValue::Record(_))
+            | (AvroSchema::Array(_), AvroValue::Array(_))
+            | (AvroSchema::Map(_), AvroValue::Map(_))
+    )
+}
+
+fn encode(schema: &AvroSchema, value: &AvroValue, out: &mut Vec<u8>) -> PolarsResult<()> {
+    match (schema, value) {
+        (AvroSchema::Union(variants), value) => {
+            let Some(idx) = variants.iter().position(|v| matches_schema(v, value)) else {
+                polars_bail!(ComputeError: "avro value {:?} does not match any variant of {:?}", value, schema)
+            };
+            write_long(out, idx as i64);
+            encode(&variants[idx], value, out)?;
+        },
+        (AvroSchema::Null, AvroValue::Null) => {},
+        (AvroSchema::Boolean, AvroValue::Boolean(v)) => out.push(*v as u8),
+        (AvroSchema::Int(_), AvroValue::Int(v)) => write_long(out, *v as i64),
+        (AvroSchema::Long(_), AvroValue::Long(v)) => write_long(out, *v),
+        (AvroSchema::Long(_), AvroValue::Int(v)) => write_long(out, *v as i64),
+        (AvroSchema::Float, AvroValue::Float(v)) => out.extend_from_slice(&v.to_le_bytes()),
+        (AvroSchema::Double, AvroValue::Double(v)) => out.extend_from_slice(&v.to_le_bytes()),
+        (AvroSchema::Bytes(_), AvroValue::Bytes(v)) => write_len_prefixed(out, v),
+        (AvroSchema::Fixed(fixed), AvroValue::Bytes(v)) => {
+            polars_ensure!(
+                v.len() == fixed.size,
+                ComputeError: "avro fixed value of length {} does not match size {}",
+                v.len(), fixed.size
+            );
+            out.extend_from_slice(v);
+        },
+        (AvroSchema::String(_), AvroValue::String(v)) => write_len_prefixed(out, v.as_bytes()),
+        (AvroSchema::Enum(enum_), AvroValue::String(v)) => {
+            let Some(idx) = enum_.symbols.iter().position(|s| s == v) else {
+                polars_bail!(ComputeError: "invalid avro enum symbol: {}", v)
+            };
+            write_long(out, idx as i64);
+        },
+        (AvroSchema::Record(record), value @ AvroValue::Record(_)) => {
+            for field in &record.fields {
+                encode(
+                    &field.schema,
+                    value.field(&field.name).unwrap_or(&AvroValue::Null),
+                    out,
+                )?;
+            }
+        },
+        (AvroSchema::Array(items), AvroValue::Array(values)) => {
+            if !values.is_empty() {
+                write_long(out, values.len() as i64);
+                for v in values {
+                    encode(items, v, out)?;
+                }
+            }
+            write_long(out, 0);
+        },
+        (AvroSchema::Map(schema), AvroValue::Map(values)) => {
+            if !values.is_empty() {
+                write_long(out, values.len() as i64);
+                for (k, v) in values {
+                    write_len_prefixed(out, k.as_bytes());
+                    encode(schema, v, out)?;
+                }
+            }
+            write_long(out, 0);
+        },
+        (schema, value) => {
+            polars_bail!(ComputeError: "avro value {:?} does not match schema {:?}", value, schema)
+        },
+    }
+
+    Ok(())
+}
+
+/// Writes an Avro object container file with the `null` codec. The `avro.schema` and
+/// `avro.codec` metadata are added to the given `metadata`.
+pub fn write_avro_file(
+    schema: &str,
+    metadata: &[(&str, &str)],
+    values: &[AvroValue],
+) -> PolarsResult<Vec<u8>> {
+    let parsed_schema: AvroSchema = serde_json::from_str(schema).map_err(to_compute_err)?;
+
+    let mut out = MAGIC.to_vec();
+
+    let metadata = [("avro.schema", schema), ("avro.codec", "null")]
+        .into_iter()
+        .chain(metadata.iter().copied())
+        .map(|(k, v)| (k.to_string(), AvroValue::Bytes(v.as_bytes().to_vec())))
+        .collect();
+    encode(
+        &AvroSchema::Map(Box::new(AvroSchema::Bytes(None))),
+        &AvroValue::Map(metadata),
+        &mut out,
+    )?;
+
+    let sync_marker = uuid::Uuid::new_v4().into_bytes();
+    out.extend_from_slice(&sync_marker);
+
+    if !values.is_empty() {
+        let mut block = vec![];
+        for value in values {
+            encode(&parsed_schema, value, &mut block)?;
+        }
+
+        write_long(&mut out, values.len() as i64);
+        write_len_prefixed(&mut out, &block);
+        out.extend_from_slice(&sync_marker);
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_avro_file_roundtrip() {
+        let schema = r#"{
+            "type": "record",
+            "name": "entry",
+            "fields": [
+                {"name": "id", "type": "long", "field-id": 1},
+                {"name": "name", "type": ["null", "string"], "field-id": 2},
+                {"name": "part", "type": {"type": "record", "name": "r3", "fields": []}},
+                {
+                    "name": "bounds",
+                    "type": ["null", {
+                        "type": "array",
+                        "logicalType": "map",
+                        "items": {
+                            "type": "record",
+                            "name": "k4_v5",
+                            "fields": [
+                                {"name": "key", "type": "int", "field-id": 4},
+                                {"name": "value", "type": "bytes", "field-id": 5}
+                            ]
+                        }
+                    }]
+                }
+            ]
+        }"#;
+
+        let record = |id: i64, name: Option<&str>, bounds: Option<Vec<(i32, &[u8])>>| {
+            AvroValue::Record(vec![
+                ("id".into(), AvroValue::Long(id)),
+                (
+                    "name".into(),
+                    name.map_or(AvroValue::Null, |v| AvroValue::String(v.into())),
+                ),
+                ("part".into(), AvroValue::Record(vec![])),
+                (
+                    "bounds".into(),
+                    bounds.map_or(AvroValue::Null, |bounds| {
+                        AvroValue::Array(
+                            bounds
+                                .into_iter()
+                                .map(|(k, v)| {
+                                    AvroValue::Record(vec![
+                                        ("key".into(), AvroValue::Int(k)),
+                                        ("value".into(), AvroValue::Bytes(v.to_vec())),
+                                    ])
+                                })
+                                .collect(),
+                        )
+                    }),
+                ),
+            ])
+        };
+
+        let values = vec![
+            record(1, Some("a"), Some(vec![(1, b"\x01\x00"), (2, b"")])),
+            record(-2, None, None),
+            record(3, Some(""), Some(vec![])),
+        ];
+
+        let bytes = write_avro_file(schema, &[("format-version", "2")], &values).unwrap();
+        let read_values = read_avro_file(&bytes).unwrap();
+
+        assert_eq!(read_values, values);
+        assert_eq!(
+            read_values[0].field("name").and_then(|v| v.as_str()),
+            Some("a")
+        );
+        assert!(read_values[1].field("name").is_none());
+
+        let empty = read_avro_file(&write_avro_file(schema, &[], &[]).unwrap()).unwrap();
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,747 @@
+use polars_core::prelude::PlHashMap;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err, to_compute_err};
+
+use super::file_io::{read_file, write_file};
+use super::manifest::{
+    DataFile, ManifestContent, ManifestFile, read_manifest_list, write_added_files_manifest,
+    write_manifest_list,
+};
+use super::models::{
+    CatalogConfig, CommitTableResponse, LoadTableResult, Namespace, Snapshot, TableIdentifier,
+    TableMetadata, TableRequirement, TableUpdate,
+};
+use crate::catalog::unity::utils::{do_request, do_request_unless_status};
+use crate::cloud::CloudOptions;
+use crate::utils::decode_json_response;
+
+/// Number of times an append is retried when the table is changed concurrently.
+const MAX_COMMIT_ATTEMPTS: usize = 10;
+
+/// Iceberg REST catalog client.
+pub struct IcebergCatalogClient {
+    uri: String,
+    /// Base URL of the namespace endpoints, i.e. `{uri}/v1/{prefix}/`.
+    base_url: String,
+    http_client: reqwest::Client,
+}
+
+impl IcebergCatalogClient {
+    /// Fetches the catalog configuration. The `prefix` override should be passed to
+    /// [`IcebergCatalogClientBuilder::with_prefix`].
+    pub async fn get_config(&self, warehouse: Option<&str>) -> PolarsResult<CatalogConfig> {
+        let mut request = self.http_client.get(format!("{}/v1/config", &self.uri));
+
+        if let Some(warehouse) = warehouse {
+            request = request.query(&[("warehouse", warehouse)]);
+        }
+
+        decode_json_response(&do_request(request).await?)
+    }
+
+    /// Lists the namespaces directly under `parent`, or the top-level namespaces if `parent` is
+    /// `None`.
+    pub async fn list_namespaces(&self, parent: Option<&[String]>) -> PolarsResult<Vec<Namespace>> {
+        let mut request = self
+            .http_client
+            .get(format!("{}namespaces", &self.base_url));
+
+        if let Some(parent) = parent {
+            request = request.query(&[("parent", parent.join("\x1F"))]);
+        }
+
+        return read_all_pages(request, |bytes| {
+            let Response {
+                namespaces,
+                next_page_token,
+            } = decode_json_response(bytes)?;
+
+            Ok((namespaces, next_page_token))
+        })
+        .await;
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "kebab-case")]
+        struct Response {
+            #[serde(default)]
+            namespaces: Vec<Namespace>,
+            #[serde(default)]
+            next_page_token: Option<String>,
+        }
+    }
+
+    pub async fn list_tables(&self, namespace: &[String]) -> PolarsResult<Vec<TableIdentifier>> {
+        let request = self.http_client.get(format!(
+            "{}namespaces/{}/tables",
+            &self.base_url,
+            encode_namespace(namespace)
+        ));
+
+        return read_all_pages(request, |bytes| {
+            let Response {
+                identifiers,
+                next_page_token,
+            } = decode_json_response(bytes)?;
+
+            Ok((identifiers, next_page_token))
+        })
+        .await;
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "kebab-case")]
+        struct Response {
+            #[serde(default)]
+            identifiers: Vec<TableIdentifier>,
+            #[serde(default)]
+            next_page_token: Option<String>,
+        }
+    }
+
+    pub async fn load_table(
+        &self,
+        namespace: &[String],
+        table_name: &str,
+    ) -> PolarsResult<LoadTableResult> {
+        let bytes = do_request(
+            self.http_client
+                .get(self.table_url(namespace, table_name))
+                .query(&[("snapshots", "all")]),
+        )
+        .await?;
+
+        decode_json_response(&bytes)
+    }
+
+    /// Commits updates to a table. The catalog rejects the commit if any of the requirements do
+    /// not hold.
+    pub async fn commit_table(
+        &self,
+        namespace: &[String],
+        table_name: &str,
+        requirements: &[TableRequirement],
+        updates: &[TableUpdate],
+    ) -> PolarsResult<CommitTableResponse> {
+        self.try_commit_table(namespace, table_name, requirements, updates)
+            .await?
+            .ok_or_else(|| {
+                polars_err!(
+                    ComputeError:
+                    "could not commit to iceberg table '{}': the table was changed concurrently",
+                    table_name
+                )
+            })
+    }
+
+    /// Like [`Self::commit_table`], but returns `None` if the catalog rejected the commit with
+    /// `409 Conflict` because a requirement does not hold.
+    async fn try_commit_table(
+        &self,
+        namespace: &[String],
+        table_name: &str,
+        requirements: &[TableRequirement],
+        updates: &[TableUpdate],
+    ) -> PolarsResult<Option<CommitTableResponse>> {
+        let Some(resp) = do_request_unless_status(
+            self.http_client
+                .post(self.table_url(namespace, table_name))
+                .json(&Body {
+                    identifier: TableIdentifier {
+                        namespace: namespace.to_vec(),
+                        name: table_name.to_string(),
+                    },
+                    requirements,
+                    updates,
+                }),
+            reqwest::StatusCode::CONFLICT,
+        )
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        return decode_json_response(&resp).map(Some);
+
+        #[derive(serde::Serialize)]
+        struct Body<'a> {
+            identifier: TableIdentifier,
+            requirements: &'a [TableRequirement],
+            updates: &'a [TableUpdate],
+        }
+    }
+
+    /// Adds already written data files to a table with a new `append` snapshot on the `main`
+    /// branch.
+    ///
+    /// A new manifest for the files and a new manifest list are written to the metadata
+    /// directory of the table. If the table was changed since it was loaded, the table is
+    /// reloaded and the append is retried on top of the new snapshot.
+    ///
+    /// Only v2 tables that are not partitioned are supported.
+    pub async fn append_data_files(
+        &self,
+        namespace: &[String],
+        table_name: &str,
+        data_files: &[DataFile],
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<CommitTableResponse> {
+        polars_ensure!(
+            data_files.iter().all(|f| f.partition.is_empty()),
+            ComputeError: "appending partitioned data files to iceberg tables is not supported"
+        );
+
+        for _ in 0..MAX_COMMIT_ATTEMPTS {
+            let LoadTableResult { metadata, .. } = self.load_table(namespace, table_name).await?;
+
+            if let Some(response) = self
+                .try_append_data_files(namespace, table_name, &metadata, data_files, cloud_options)
+                .await?
+            {
+                return Ok(response);
+            }
+        }
+
+        polars_bail!(
+            ComputeError: "could not append to iceberg table '{}' after {} attempts due to concurrent writers",
+            table_name, MAX_COMMIT_ATTEMPTS
+        )
+    }
+
+    /// Appends the data files on top of the current snapshot of `metadata`, returning `None` if
+    /// the table was changed since.
+    async fn try_append_data_files(
+        &self,
+        namespace: &[String],
+        table_name: &str,
+        metadata: &TableMetadata,
+        data_files: &[DataFile],
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Option<CommitTableResponse>> {
+        polars_ensure!(
+            metadata.format_version == 2,
+            ComputeError:
+            "appending is only supported for iceberg format version 2, table has version {}",
+            metadata.format_version
+        );
+
+        let partition_spec_id = metadata.default_spec_id.unwrap_or(0);
+
+        if metadata
+            .default_partition_spec()
+            .is_some_and(|spec| !spec.fields.is_empty())
+        {
+            polars_bail!(ComputeError: "appending to partitioned iceberg tables is not supported")
+        }
+
+        let schema = metadata
+            .schema_by_id(None)
+            .ok_or_else(|| polars_err!(ComputeError: "iceberg table metadata has no schema"))?;
+        let schema_json = {
+            let mut schema_json = serde_json::to_value(schema).map_err(to_compute_err)?;
+            schema_json["type"] = "struct".into();
+            schema_json.to_string()
+        };
+
+        let parent_snapshot = metadata.current_snapshot();
+        let snapshot_id = loop {
+            // Snapshot IDs must be positive.
+            let snapshot_id = (uuid::Uuid::new_v4().as_u64_pair().0 & i64::MAX as u64) as i64;
+
+            if snapshot_id != 0
+                && !metadata
+                    .snapshots
+                    .iter()
+                    .any(|snapshot| snapshot.snapshot_id == snapshot_id)
+            {
+                break snapshot_id;
+            }
+        };
+        let sequence_number = metadata.last_sequence_number + 1;
+        let commit_uuid = uuid::Uuid::new_v4();
+        let metadata_dir = format!("{}/metadata", metadata.location.trim_end_matches('/'));
+
+        let added_records = data_files.iter().map(|f| f.record_count).sum::<i64>();
+        let added_files_size = data_files.iter().map(|f| f.file_size_in_bytes).sum::<i64>();
+
+        let manifest_path = format!("{metadata_dir}/{commit_uuid}-m0.avro");
+        let manifest_bytes = write_added_files_manifest(
+            data_files,
+            &schema_json,
+            schema.schema_id.unwrap_or(0),
+            partition_spec_id,
+        )?;
+        let manifest = ManifestFile {
+            manifest_path: manifest_path.clone(),
+            manifest_length: manifest_bytes.len() as i64,
+            partition_spec_id,
+            content: ManifestContent::Data,
+            sequence_number,
+            min_sequence_number: sequence_number,
+            added_snapshot_id: snapshot_id,
+            added_files_count: Some(data_files.len() as i32),
+            existing_files_count: Some(0),
+            deleted_files_count: Some(0),
+            added_rows_count: Some(added_records),
+            existing_rows_count: Some(0),
+            deleted_rows_count: Some(0),
+            partitions: vec![],
+        };
+        write_file(&manifest_path, manifest_bytes, cloud_options).await?;
+
+        let mut manifests = vec![manifest];
+
+        if let Some(parent_snapshot) = parent_snapshot {
+            manifests.extend(read_manifest_list(
+                &read_file(&parent_snapshot.manifest_list, cloud_options).await?,
+            )?);
+        }
+
+        let parent_snapshot_id = parent_snapshot.map(|snapshot| snapshot.snapshot_id);
+        let manifest_list_path = format!("{metadata_dir}/snap-{snapshot_id}-1-{commit_uuid}.avro");
+        write_file(
+            &manifest_list_path,
+            write_manifest_list(&manifests, snapshot_id, parent_snapshot_id, sequence_number)?,
+            cloud_options,
+        )
+        .await?;
+
+        let summary = PlHashMap::from_iter([
+            ("operation".to_string(), "append".to_string()),
+            ("added-data-files".to_string(), data_files.len().to_string()),
+            ("added-records".to_string(), added_records.to_string()),
+            ("added-files-size".to_string(), added_files_size.to_string()),
+        ]);
+
+        let mut requirements = vec![TableRequirement::AssertRefSnapshotId {
+            ref_: "main".into(),
+            snapshot_id: parent_snapshot_id,
+        }];
+
+        if let Some(uuid) = &metadata.table_uuid {
+            requirements.push(TableRequirement::AssertTableUuid { uuid: uuid.clone() });
+        }
+
+        let updates = [
+            TableUpdate::AddSnapshot {
+                snapshot: Snapshot {
+                    snapshot_id,
+                    parent_snapshot_id,
+                    sequence_number,
+                    timestamp_ms: chrono::Utc::now().timestamp_millis(),
+                    manifest_list: manifest_list_path,
+                    summary,
+                    schema_id: schema.schema_id,
+                },
+            },
+            TableUpdate::SetSnapshotRef {
+                ref_name: "main".into(),
+                type_: "branch".into(),
+                snapshot_id,
+            },
+        ];
+
+        self.try_commit_table(namespace, table_name, &requirements, &updates)
+            .await
+    }
+
+    fn table_url(&self, namespace: &[String], table_name: &str) -> String {
+        format!(
+            "{}namespaces/{}/tables/{}",
+            &self.base_url,
+            encode_namespace(namespace),
+            percent_encoding::utf8_percent_encode(table_name, percent_encoding::NON_ALPHANUMERIC)
+        )
+    }
+}
+
+/// Multi-level namespaces are joined with the unit separator in URL paths.
+fn encode_namespace(namespace: &[String]) -> String {
+    percent_encoding::utf8_percent_encode(
+        &namespace.join("\x1F"),
+        percent_encoding::NON_ALPHANUMERIC,
+    )
+    .to_string()
+}
+
+/// Reads all pages of a list endpoint. Pages are requested with the `pageToken` query parameter
+/// until the response does not contain a `next-page-token`.
+async fn read_all_pages<T>(
+    request: reqwest::RequestBuilder,
+    deserializer: impl Fn(&[u8]) -> PolarsResult<(Vec<T>, Option<String>)>,
+) -> PolarsResult<Vec<T>> {
+    let mut out = vec![];
+    let mut page_token: Option<String> = None;
+
+    loop {
+        let mut request = request.try_clone().unwrap();
+
+        if let Some(page_token) = page_token.take() {
+            request = request.query(&[("pageToken", page_token)]);
+        }
+
+        let (values, next_page_token) = deserializer(&do_request(request).await?)?;
+        out.extend(values);
+
+        // Some servers return an empty token on the last page.
+        match next_page_token.filter(|token| !token.is_empty()) {
+            Some(token) => page_token = Some(token),
+            None => return Ok(out),
+        }
+    }
+}
+
+pub struct IcebergCatalogClientBuilder {
+    uri: Option<String>,
+    prefix: Option<String>,
+    bearer_token: Option<String>,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for IcebergCatalogClientBuilder {
+    fn default() -> Self {
+        Self {
+            uri: None,
+            prefix: None,
+            bearer_token: None,
+        }
+    }
+}
+
+impl IcebergCatalogClientBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Base URI of the catalog, without the `/v1` suffix.
+    pub fn with_uri(mut self, uri: impl Into<String>) -> Self {
+        self.uri = Some(uri.into());
+        self
+    }
+
+    /// Prefix of the namespace endpoints, usually given by the `prefix` override of
+    /// [`IcebergCatalogClient::get_config`].
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    pub fn with_bearer_token(mut self, bearer_token: impl Into<String>) -> Self {
+        self.bearer_token = Some(bearer_token.into());
+        self
+    }
+
+    pub fn build(self) -> PolarsResult<IcebergCatalogClient> {
+        let Some(uri) = self.uri else {
+            polars_bail!(ComputeError: "expected Some(_) for uri")
+        };
+
+        let uri = uri.trim_end_matches('/').to_string();
+        let base_url = match self.prefix.as_deref().map(|p| p.trim_matches('/')) {
+            Some(prefix) if !prefix.is_empty() => format!("{uri}/v1/{prefix}/"),
+            _ => format!("{uri}/v1/"),
+        };
+
+        Ok(IcebergCatalogClient {
+            uri,
+            base_url,
+            http_client: {
+                let builder = reqwest::ClientBuilder::new().user_agent("polars");
+
+                let builder = if let Some(bearer_token) = self.bearer_token {
+                    use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderValue, USER_AGENT};
+
+                    let mut headers = HeaderMap::new();
+
+                    let mut auth_value =
+                        HeaderValue::from_str(format!("Bearer {bearer_token}").as_str()).unwrap();
+                    auth_value.set_sensitive(true);
+
+                    headers.insert(AUTHORIZATION, auth_value);
+                    headers.insert(USER_AGENT, "polars".try_into().unwrap());
+
+                    builder.default_headers(headers)
+                } else {
+                    builder
+                };
+
+                builder.build().map_err(to_compute_err)?
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+
+    use arrow::bitmap::Bitmap;
+    use polars_core::prelude::*;
+
+    use super::*;
+    use crate::catalog::iceberg::avro::AvroValue;
+    use crate::catalog::iceberg::models::TableMetadata;
+    use crate::catalog::iceberg::scan::IcebergScan;
+    use crate::predicates::{PhysicalIoExpr, ScanIOPredicate, SkipBatchPredicate};
+
+    /// Serves the REST endpoints used by the client for a single table `db.t`. Commits are
+    /// applied to the in-memory table metadata, except for the first `conflicting_commits`
+    /// commits, which are rejected as if another writer committed first.
+    fn spawn_mock_catalog(metadata: TableMetadata, mut conflicting_commits: usize) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let uri = format!("http://{}", listener.local_addr().unwrap());
+        let metadata = Arc::new(Mutex::new(serde_json::to_value(metadata).unwrap()));
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut parts = request_line.split_whitespace();
+                let method = parts.next().unwrap().to_string();
+                let target = parts.next().unwrap().to_string();
+
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((k, v)) = line.split_once(':') {
+                        if k.eq_ignore_ascii_case("content-length") {
+                            content_length = v.trim().parse().unwrap();
+                        }
+                    }
+                }
+
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+
+                let (path, query) = target.split_once('?').unwrap_or((&target, ""));
+                let mut metadata = metadata.lock().unwrap();
+
+                let (status, response) = match (method.as_str(), path) {
+                    ("GET", "/v1/config") => (200, r#"{"overrides": {"prefix": "wh"}}"#.into()),
+                    ("GET", "/v1/wh/namespaces") if query.contains("pageToken=1") => {
+                        (200, r#"{"namespaces": [["other"]]}"#.into())
+                    },
+                    ("GET", "/v1/wh/namespaces") => (
+                        200,
+                        r#"{"namespaces": [["db"]], "next-page-token": "1"}"#.into(),
+                    ),
+                    ("GET", "/v1/wh/namespaces/db/tables") => (
+                        200,
+                        r#"{"identifiers": [{"namespace": ["db"], "name": "t"}]}"#.into(),
+                    ),
+                    ("GET", "/v1/wh/namespaces/db/tables/t") => (
+                        200,
+                        serde_json::json!({"metadata": *metadata, "config": {}}).to_string(),
+                    ),
+                    ("POST", "/v1/wh/namespaces/db/tables/t") if conflicting_commits > 0 => {
+                        conflicting_commits -= 1;
+                        (409, r#"{"error": {"message": "conflict"}}"#.into())
+                    },
+                    ("POST", "/v1/wh/namespaces/db/tables/t") => {
+                        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+                        let current = match &metadata["current-snapshot-id"] {
+                            v if *v == -1 => serde_json::Value::Null,
+                            v => v.clone(),
+                        };
+
+                        if body["requirements"][0]["snapshot-id"] != current {
+                            (409, r#"{"error": {"message": "conflict"}}"#.into())
+                        } else {
+                            let snapshot = body["updates"][0]["snapshot"].clone();
+                            metadata["last-sequence-number"] = snapshot["sequence-number"].clone();
+                            metadata["current-snapshot-id"] = snapshot["snapshot-id"].clone();
+                            metadata["snapshots"].as_array_mut().unwrap().push(snapshot);
+
+                            (
+                                200,
+                                serde_json::json!({
+                                    "metadata-location": "metadata/v2.metadata.json",
+                                    "metadata": *metadata,
+                                })
+                                .to_string(),
+                            )
+                        }
+                    },
+                    _ => (404, r#"{"error": {"message": "not found"}}"#.into()),
+                };
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} X\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                    response.len()
+                )
+                .unwrap();
+            }
+        });
+
+        uri
+    }
+
+    /// Skips batches where the maximum of `id` is below 10.
+    struct IdAtLeast10(SchemaRef);
+
+    impl PhysicalIoExpr for IdAtLeast10 {
+        fn evaluate_io(&self, _df: &DataFrame) -> PolarsResult<Series> {
+            unimplemented!()
+        }
+    }
+
+    impl SkipBatchPredicate for IdAtLeast10 {
+        fn schema(&self) -> &SchemaRef {
+            &self.0
+        }
+
+        fn evaluate_with_stat_df(&self, df: &DataFrame) -> PolarsResult<Bitmap> {
+            Ok(df
+                .column("id_max")?
+                .i64()?
+                .iter()
+                .map(|max| max.is_some_and(|max| max < 10))
+                .collect())
+        }
+    }
+
+    #[test]
+    fn test_iceberg_catalog_client() {
+        let table_dir = tempfile::tempdir().unwrap();
+
+        let metadata: TableMetadata = serde_json::from_value(serde_json::json!({
+            "format-version": 2,
+            "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+            "location": table_dir.path().to_str().unwrap(),
+            "last-sequence-number": 0,
+            "last-updated-ms": 0,
+            "last-column-id": 2,
+            "current-schema-id": 0,
+            "schemas": [{
+                "type": "struct",
+                "schema-id": 0,
+                "fields": [
+                    {"id": 1, "name": "id", "required": true, "type": "long"},
+                    {"id": 2, "name": "name", "required": false, "type": "string"}
+                ]
+            }],
+            "default-spec-id": 0,
+            "partition-specs": [{"spec-id": 0, "fields": []}],
+            "current-snapshot-id": -1,
+            "snapshots": []
+        }))
+        .unwrap();
+
+        let uri = spawn_mock_catalog(metadata, 1);
+
+        crate::pl_async::get_runtime().block_on(async {
+            let config = IcebergCatalogClientBuilder::new()
+                .with_uri(&uri)
+                .build()
+                .unwrap()
+                .get_config(Some("warehouse"))
+                .await
+                .unwrap();
+
+            let client = IcebergCatalogClientBuilder::new()
+                .with_uri(&uri)
+                .with_prefix(config.overrides.get("prefix").unwrap())
+                .with_bearer_token("token")
+                .build()
+                .unwrap();
+
+            assert_eq!(
+                client.list_namespaces(None).await.unwrap(),
+                [vec!["db".to_string()], vec!["other".to_string()]]
+            );
+            assert_eq!(
+                client.list_tables(&["db".into()]).await.unwrap(),
+                [TableIdentifier {
+                    namespace: vec!["db".into()],
+                    name: "t".into()
+                }]
+            );
+            assert!(client.load_table(&["db".into()], "missing").await.is_err());
+
+            let data_file = |i: i64| {
+                let mut data_file = DataFile::new(
+                    format!("{}/data/{i}.parquet", table_dir.path().to_str().unwrap()),
+                    10,
+                    100,
+                );
+                data_file.null_value_counts.insert(1, 0);
+                data_file
+                    .lower_bounds
+                    .insert(1, (10 * i).to_le_bytes().to_vec());
+                data_file
+                    .upper_bounds
+                    .insert(1, (10 * i + 9).to_le_bytes().to_vec());
+                data_file
+            };
+
+            let mut partitioned_file = data_file(2);
+            partitioned_file.partition.push(AvroValue::Int(1));
+            assert!(
+                client
+                    .append_data_files(&["db".into()], "t", &[partitioned_file], None)
+                    .await
+                    .is_err()
+            );
+
+            // The first commit conflicts and is retried.
+            for i in 0..2 {
+                let response = client
+                    .append_data_files(&["db".into()], "t", &[data_file(i)], None)
+                    .await
+                    .unwrap();
+                assert_eq!(response.metadata.last_sequence_number, i + 1);
+            }
+
+            let table = client.load_table(&["db".into()], "t").await.unwrap();
+            let snapshot = table.metadata.current_snapshot().unwrap();
+            assert_eq!(snapshot.summary.get("operation").unwrap(), "append");
+
+            let scan = IcebergScan::try_new(&table.metadata, None, None, None)
+                .await
+                .unwrap();
+            assert_eq!(scan.schema.len(), 2);
+            assert_eq!(scan.data_files.len(), 2);
+            assert!(scan.delete_files.is_empty());
+
+            let predicate = Arc::new(IdAtLeast10(Arc::new(Schema::from_iter([Field::new(
+                "id".into(),
+                DataType::Int64,
+            )]))));
+            let predicate = ScanIOPredicate {
+                predicate: predicate.clone(),
+                live_columns: Arc::new(PlIndexSet::from_iter(["id".into()])),
+                skip_batch_predicate: Some(predicate),
+                column_predicates: Default::default(),
+                hive_predicate: None,
+                hive_predicate_is_full_predicate: false,
+            };
+
+            let scan = IcebergScan::try_new(&table.metadata, None, Some(&predicate), None)
+                .await
+                .unwrap();
+            assert_eq!(scan.data_files.len(), 1);
+            assert!(
+                scan.data_files[0]
+                    .data_file
+                    .file_path
+                    .ends_with("1.parquet")
+            );
+            assert_eq!(scan.data_files[0].sequence_number, 2);
+
+            // The first snapshot only contains the first file.
+            let first_snapshot_id = snapshot.parent_snapshot_id.unwrap();
+            let scan = IcebergScan::try_new(&table.metadata, Some(first_snapshot_id), None, None)
+                .await
+                .unwrap();
+            assert_eq!(scan.data_files.len(), 1);
+            assert_eq!(scan.data_files[0].sequence_number, 1);
+        });
+    }
+}
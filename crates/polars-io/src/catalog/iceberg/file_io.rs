@@ -0,0 +1,46 @@
+use bytes::Bytes;
+use polars_error::PolarsResult;
+use polars_utils::plpath::PlPathRef;
+
+use crate::cloud::{CloudOptions, build_object_store, object_path_from_str};
+
+/// Reads the metadata file at `uri`, which is either a local path or a cloud URI.
+pub(super) async fn read_file(
+    uri: &str,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Bytes> {
+    if let Some(path) = PlPathRef::new(uri).as_local_path() {
+        return Ok(Bytes::from(tokio::fs::read(path).await?));
+    }
+
+    let (location, store) = build_object_store(uri, cloud_options, false).await?;
+    let path = object_path_from_str(&location.prefix)?;
+    let size = store.head(&path).await?.size as usize;
+
+    store.get_range(&path, 0..size).await
+}
+
+/// Writes a new metadata file to `uri`, which is either a local path or a cloud URI.
+pub(super) async fn write_file(
+    uri: &str,
+    bytes: Vec<u8>,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<()> {
+    if let Some(path) = PlPathRef::new(uri).as_local_path() {
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        return Ok(tokio::fs::write(path, bytes).await?);
+    }
+
+    let (location, store) = build_object_store(uri, cloud_options, false).await?;
+    let path = object_path_from_str(&location.prefix)?;
+
+    store
+        .to_dyn_object_store()
+        .await
+        .put(&path, bytes.into())
+        .await?;
+
+    Ok(())
+}
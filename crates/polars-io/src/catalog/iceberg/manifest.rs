@@ -0,0 +1,766 @@
+//! Manifest lists and manifests of Iceberg snapshots.
+//!
+//! Reference: <https://iceberg.apache.org/spec/#manifests>
+use polars_core::prelude::{InitHashMaps, PlHashMap};
+use polars_error::{PolarsResult, polars_bail, polars_err};
+
+use super::avro::{AvroValue, read_avro_file, write_avro_file};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestContent {
+    Data,
+    Deletes,
+}
+
+/// An entry of a manifest list.
+#[derive(Debug, Clone)]
+pub struct ManifestFile {
+    pub manifest_path: String,
+    pub manifest_length: i64,
+    pub partition_spec_id: i32,
+    pub content: ManifestContent,
+    /// Sequence number of the snapshot that added the manifest. Inherited by the entries that
+    /// were added by that snapshot.
+    pub sequence_number: i64,
+    pub min_sequence_number: i64,
+    pub added_snapshot_id: i64,
+    pub added_files_count: Option<i32>,
+    pub existing_files_count: Option<i32>,
+    pub deleted_files_count: Option<i32>,
+    pub added_rows_count: Option<i64>,
+    pub existing_rows_count: Option<i64>,
+    pub deleted_rows_count: Option<i64>,
+    /// Summaries of the partition fields, in the order of the partition spec.
+    pub partitions: Vec<FieldSummary>,
+}
+
+impl ManifestFile {
+    /// Number of rows in the live files of the manifest, if known.
+    pub fn live_rows_count(&self) -> Option<i64> {
+        Some(self.added_rows_count? + self.existing_rows_count?)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FieldSummary {
+    pub contains_null: bool,
+    pub contains_nan: Option<bool>,
+    pub lower_bound: Option<Vec<u8>>,
+    pub upper_bound: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestEntryStatus {
+    Existing,
+    Added,
+    Deleted,
+}
+
+#[derive(Debug, Clone)]
+pub struct ManifestEntry {
+    pub status: ManifestEntryStatus,
+    pub snapshot_id: i64,
+    /// Data sequence number of the file.
+    pub sequence_number: i64,
+    pub data_file: DataFile,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataContent {
+    Data,
+    PositionDeletes,
+    EqualityDeletes,
+}
+
+#[derive(Debug, Clone)]
+pub struct DataFile {
+    pub content: DataContent,
+    pub file_path: String,
+    /// e.g. `PARQUET`.
+    pub file_format: String,
+    /// Values of the partition fields, in the order of the partition spec.
+    pub partition: Vec<AvroValue>,
+    pub record_count: i64,
+    pub file_size_in_bytes: i64,
+    /// The following are keyed by field ID.
+    pub value_counts: PlHashMap<i32, i64>,
+    pub null_value_counts: PlHashMap<i32, i64>,
+    pub nan_value_counts: PlHashMap<i32, i64>,
+    /// Bounds use the single-value serialization of the column type.
+    pub lower_bounds: PlHashMap<i32, Vec<u8>>,
+    pub upper_bounds: PlHashMap<i32, Vec<u8>>,
+    /// Field IDs of the equality delete columns.
+    pub equality_ids: Vec<i32>,
+}
+
+impl DataFile {
+    /// A data file without column statistics.
+    pub fn new(file_path: String, record_count: i64, file_size_in_bytes: i64) -> Self {
+        Self {
+            content: DataContent::Data,
+            file_path,
+            file_format: "PARQUET".into(),
+            partition: vec![],
+            record_count,
+            file_size_in_bytes,
+            value_counts: PlHashMap::new(),
+            null_value_counts: PlHashMap::new(),
+            nan_value_counts: PlHashMap::new(),
+            lower_bounds: PlHashMap::new(),
+            upper_bounds: PlHashMap::new(),
+            equality_ids: vec![],
+        }
+    }
+}
+
+fn required<'a>(value: &'a AvroValue, name: &str) -> PolarsResult<&'a AvroValue> {
+    value
+        .field(name)
+        .ok_or_else(|| polars_err!(ComputeError: "iceberg manifest is missing field '{}'", name))
+}
+
+fn required_i64(value: &AvroValue, name: &str) -> PolarsResult<i64> {
+    required(value, name)?.as_i64().ok_or_else(
+        || polars_err!(ComputeError: "iceberg manifest field '{}' is not an integer", name),
+    )
+}
+
+fn required_str(value: &AvroValue, name: &str) -> PolarsResult<String> {
+    Ok(required(value, name)?
+        .as_str()
+        .ok_or_else(
+            || polars_err!(ComputeError: "iceberg manifest field '{}' is not a string", name),
+        )?
+        .to_string())
+}
+
+/// Reads a map stored as an array of `key` / `value` records.
+fn int_keyed_map<T>(
+    value: &AvroValue,
+    name: &str,
+    f: impl Fn(&AvroValue) -> Option<T>,
+) -> PolarsResult<PlHashMap<i32, T>> {
+    let Some(items) = value.field(name) else {
+        return Ok(PlHashMap::new());
+    };
+
+    items
+        .as_array()
+        .unwrap_or_default()
+        .iter()
+        .map(|item| {
+            item.field("key")
+                .and_then(AvroValue::as_i32)
+                .zip(item.field("value").and_then(&f))
+                .ok_or_else(
+                    || polars_err!(ComputeError: "invalid iceberg manifest field '{}'", name),
+                )
+        })
+        .collect()
+}
+
+fn int_keyed_map_to_avro(map: &PlHashMap<i32, impl Clone + Into<AvroValue>>) -> AvroValue {
+    let mut entries = map.iter().collect::<Vec<_>>();
+    entries.sort_unstable_by_key(|(k, _)| **k);
+
+    AvroValue::Array(
+        entries
+            .into_iter()
+            .map(|(k, v)| {
+                AvroValue::Record(vec![
+                    ("key".into(), AvroValue::Int(*k)),
+                    ("value".into(), v.clone().into()),
+                ])
+            })
+            .collect(),
+    )
+}
+
+/// Reads a v1 or v2 manifest list.
+pub fn read_manifest_list(bytes: &[u8]) -> PolarsResult<Vec<ManifestFile>> {
+    read_avro_file(bytes)?
+        .iter()
+        .map(|v| {
+            // v1 manifest lists use different names for the counts.
+            let count = |v2_name: &str, v1_name: &str| {
+                v.field(v2_name)
+                    .or_else(|| v.field(v1_name))
+                    .and_then(AvroValue::as_i64)
+            };
+
+            Ok(ManifestFile {
+                manifest_path: required_str(v, "manifest_path")?,
+                manifest_length: required_i64(v, "manifest_length")?,
+                partition_spec_id: required_i64(v, "partition_spec_id")? as i32,
+                content: match v.field("content").and_then(AvroValue::as_i64).unwrap_or(0) {
+                    0 => ManifestContent::Data,
+                    1 => ManifestContent::Deletes,
+                    c => polars_bail!(ComputeError: "invalid iceberg manifest content: {}", c),
+                },
+                sequence_number: v
+                    .field("sequence_number")
+                    .and_then(AvroValue::as_i64)
+                    .unwrap_or(0),
+                min_sequence_number: v
+                    .field("min_sequence_number")
+                    .and_then(AvroValue::as_i64)
+                    .unwrap_or(0),
+                added_snapshot_id: required_i64(v, "added_snapshot_id")?,
+                added_files_count: count("added_files_count", "added_data_files_count")
+                    .map(|v| v as i32),
+                existing_files_count: count("existing_files_count", "existing_data_files_count")
+                    .map(|v| v as i32),
+                deleted_files_count: count("deleted_files_count", "deleted_data_files_count")
+                    .map(|v| v as i32),
+                added_rows_count: v.field("added_rows_count").and_then(AvroValue::as_i64),
+                existing_rows_count: v.field("existing_rows_count").and_then(AvroValue::as_i64),
+                deleted_rows_count: v.field("deleted_rows_count").and_then(AvroValue::as_i64),
+                partitions: v
+                    .field("partitions")
+                    .and_then(AvroValue::as_array)
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|summary| FieldSummary {
+                        contains_null: summary
+                            .field("contains_null")
+                            .and_then(AvroValue::as_bool)
+                            .unwrap_or(true),
+                        contains_nan: summary.field("contains_nan").and_then(AvroValue::as_bool),
+                        lower_bound: summary
+                            .field("lower_bound")
+                            .and_then(AvroValue::as_bytes)
+                            .map(<[u8]>::to_vec),
+                        upper_bound: summary
+                            .field("upper_bound")
+                            .and_then(AvroValue::as_bytes)
+                            .map(<[u8]>::to_vec),
+                    })
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+/// Reads the entries of a manifest. Snapshot IDs and sequence numbers that are inherited from
+/// the manifest list entry are filled in.
+pub fn read_manifest(bytes: &[u8], manifest: &ManifestFile) -> PolarsResult<Vec<ManifestEntry>> {
+    read_avro_file(bytes)?
+        .iter()
+        .map(|v| {
+            let status = match required_i64(v, "status")? {
+                0 => ManifestEntryStatus::Existing,
+                1 => ManifestEntryStatus::Added,
+                2 => ManifestEntryStatus::Deleted,
+                s => polars_bail!(ComputeError: "invalid iceberg manifest entry status: {}", s),
+            };
+
+            let sequence_number = match v.field("sequence_number").and_then(AvroValue::as_i64) {
+                Some(v) => v,
+                None if status == ManifestEntryStatus::Added => manifest.sequence_number,
+                // v1 manifests do not have sequence numbers.
+                None => 0,
+            };
+
+            let data_file = required(v, "data_file")?;
+
+            Ok(ManifestEntry {
+                status,
+                snapshot_id: v
+                    .field("snapshot_id")
+                    .and_then(AvroValue::as_i64)
+                    .unwrap_or(manifest.added_snapshot_id),
+                sequence_number,
+                data_file: DataFile {
+                    content: match data_file.field("content").and_then(AvroValue::as_i64) {
+                        None | Some(0) => DataContent::Data,
+                        Some(1) => DataContent::PositionDeletes,
+                        Some(2) => DataContent::EqualityDeletes,
+                        Some(c) => {
+                            polars_bail!(ComputeError: "invalid iceberg data file content: {}", c)
+                        },
+                    },
+                    file_path: required_str(data_file, "file_path")?,
+                    file_format: required_str(data_file, "file_format")?,
+                    partition: match data_file.field("partition") {
+                        Some(AvroValue::Record(fields)) => {
+                            fields.iter().map(|(_, v)| v.clone()).collect()
+                        },
+                        _ => vec![],
+                    },
+                    record_count: required_i64(data_file, "record_count")?,
+                    file_size_in_bytes: required_i64(data_file, "file_size_in_bytes")?,
+                    value_counts: int_keyed_map(data_file, "value_counts", AvroValue::as_i64)?,
+                    null_value_counts: int_keyed_map(
+                        data_file,
+                        "null_value_counts",
+                        AvroValue::as_i64,
+                    )?,
+                    nan_value_counts: int_keyed_map(
+                        data_file,
+                        "nan_value_counts",
+                        AvroValue::as_i64,
+                    )?,
+                    lower_bounds: int_keyed_map(data_file, "lower_bounds", |v| {
+                        v.as_bytes().map(<[u8]>::to_vec)
+                    })?,
+                    upper_bounds: int_keyed_map(data_file, "upper_bounds", |v| {
+                        v.as_bytes().map(<[u8]>::to_vec)
+                    })?,
+                    equality_ids: data_file
+                        .field("equality_ids")
+                        .and_then(AvroValue::as_array)
+                        .unwrap_or_default()
+                        .iter()
+                        .filter_map(AvroValue::as_i32)
+                        .collect(),
+                },
+            })
+        })
+        .collect()
+}
+
+/// Writes a v2 manifest of an unpartitioned table where all files were added by the snapshot
+/// the manifest is written for. Snapshot IDs and sequence numbers are left to be inherited.
+///
+/// The partition values of the files are not written, the caller must ensure they are empty.
+pub fn write_added_files_manifest(
+    data_files: &[DataFile],
+    table_schema_json: &str,
+    schema_id: i32,
+    partition_spec_id: i32,
+) -> PolarsResult<Vec<u8>> {
+    let entries = data_files
+        .iter()
+        .map(|data_file| {
+            AvroValue::Record(vec![
+                ("status".into(), AvroValue::Int(1)),
+                ("snapshot_id".into(), AvroValue::Null),
+                ("sequence_number".into(), AvroValue::Null),
+                ("file_sequence_number".into(), AvroValue::Null),
+                (
+                    "data_file".into(),
+                    AvroValue::Record(vec![
+                        (
+                            "content".into(),
+                            AvroValue::Int(match data_file.content {
+                                DataContent::Data => 0,
+                                DataContent::PositionDeletes => 1,
+                                DataContent::EqualityDeletes => 2,
+                            }),
+                        ),
+                        (
+                            "file_path".into(),
+                            AvroValue::String(data_file.file_path.clone()),
+                        ),
+                        (
+                            "file_format".into(),
+                            AvroValue::String(data_file.file_format.clone()),
+                        ),
+                        ("partition".into(), AvroValue::Record(vec![])),
+                        (
+                            "record_count".into(),
+                            AvroValue::Long(data_file.record_count),
+                        ),
+                        (
+                            "file_size_in_bytes".into(),
+                            AvroValue::Long(data_file.file_size_in_bytes),
+                        ),
+                        (
+                            "value_counts".into(),
+                            int_keyed_map_to_avro(&data_file.value_counts),
+                        ),
+                        (
+                            "null_value_counts".into(),
+                            int_keyed_map_to_avro(&data_file.null_value_counts),
+                        ),
+                        (
+                            "nan_value_counts".into(),
+                            int_keyed_map_to_avro(&data_file.nan_value_counts),
+                        ),
+                        (
+                            "lower_bounds".into(),
+                            int_keyed_map_to_avro(&data_file.lower_bounds),
+                        ),
+                        (
+                            "upper_bounds".into(),
+                            int_keyed_map_to_avro(&data_file.upper_bounds),
+                        ),
+                        (
+                            "equality_ids".into(),
+                            if data_file.equality_ids.is_empty() {
+                                AvroValue::Null
+                            } else {
+                                AvroValue::Array(
+                                    data_file
+                                        .equality_ids
+                                        .iter()
+                                        .map(|id| AvroValue::Int(*id))
+                                        .collect(),
+                                )
+                            },
+                        ),
+                    ]),
+                ),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    write_avro_file(
+        MANIFEST_ENTRY_SCHEMA_V2,
+        &[
+            ("schema", table_schema_json),
+            ("schema-id", &schema_id.to_string()),
+            ("partition-spec", "[]"),
+            ("partition-spec-id", &partition_spec_id.to_string()),
+            ("format-version", "2"),
+            ("content", "data"),
+        ],
+        &entries,
+    )
+}
+
+/// Writes a v2 manifest list.
+pub fn write_manifest_list(
+    manifests: &[ManifestFile],
+    snapshot_id: i64,
+    parent_snapshot_id: Option<i64>,
+    sequence_number: i64,
+) -> PolarsResult<Vec<u8>> {
+    let opt_int = |v: Option<i32>| AvroValue::Int(v.unwrap_or(0));
+    let opt_long = |v: Option<i64>| AvroValue::Long(v.unwrap_or(0));
+    let opt_bytes = |v: &Option<Vec<u8>>| v.clone().map_or(AvroValue::Null, AvroValue::Bytes);
+
+    let values = manifests
+        .iter()
+        .map(|m| {
+            AvroValue::Record(vec![
+                (
+                    "manifest_path".into(),
+                    AvroValue::String(m.manifest_path.clone()),
+                ),
+                ("manifest_length".into(), AvroValue::Long(m.manifest_length)),
+                (
+                    "partition_spec_id".into(),
+                    AvroValue::Int(m.partition_spec_id),
+                ),
+                (
+                    "content".into(),
+                    AvroValue::Int(match m.content {
+                        ManifestContent::Data => 0,
+                        ManifestContent::Deletes => 1,
+                    }),
+                ),
+                ("sequence_number".into(), AvroValue::Long(m.sequence_number)),
+                (
+                    "min_sequence_number".into(),
+                    AvroValue::Long(m.min_sequence_number),
+                ),
+                (
+                    "added_snapshot_id".into(),
+                    AvroValue::Long(m.added_snapshot_id),
+                ),
+                ("added_files_count".into(), opt_int(m.added_files_count)),
+                (
+                    "existing_files_count".into(),
+                    opt_int(m.existing_files_count),
+                ),
+                ("deleted_files_count".into(), opt_int(m.deleted_files_count)),
+                ("added_rows_count".into(), opt_long(m.added_rows_count)),
+                (
+                    "existing_rows_count".into(),
+                    opt_long(m.existing_rows_count),
+                ),
+                ("deleted_rows_count".into(), opt_long(m.deleted_rows_count)),
+                (
+                    "partitions".into(),
+                    AvroValue::Array(
+                        m.partitions
+                            .iter()
+                            .map(|summary| {
+                                AvroValue::Record(vec![
+                                    (
+                                        "contains_null".into(),
+                                        AvroValue::Boolean(summary.contains_null),
+                                    ),
+                                    (
+                                        "contains_nan".into(),
+                                        summary
+                                            .contains_nan
+                                            .map_or(AvroValue::Null, AvroValue::Boolean),
+                                    ),
+                                    ("lower_bound".into(), opt_bytes(&summary.lower_bound)),
+                                    ("upper_bound".into(), opt_bytes(&summary.upper_bound)),
+                                ])
+                            })
+                            .collect(),
+                    ),
+                ),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    let parent_snapshot_id = parent_snapshot_id.map_or_else(|| "null".into(), |v| v.to_string());
+
+    write_avro_file(
+        MANIFEST_FILE_SCHEMA_V2,
+        &[
+            ("snapshot-id", &snapshot_id.to_string()),
+            ("parent-snapshot-id", &parent_snapshot_id),
+            ("sequence-number", &sequence_number.to_string()),
+            ("format-version", "2"),
+        ],
+        &values,
+    )
+}
+
+/// Avro schema of the entries of v2 manifests of unpartitioned tables.
+const MANIFEST_ENTRY_SCHEMA_V2: &str = r#"{
+    "type": "record",
+    "name": "manifest_entry",
+    "fields": [
+        {"name": "status", "type": "int", "field-id": 0},
+        {"name": "snapshot_id", "type": ["null", "long"], "default": null, "field-id": 1},
+        {"name": "sequence_number", "type": ["null", "long"], "default": null, "field-id": 3},
+        {"name": "file_sequence_number", "type": ["null", "long"], "default": null, "field-id": 4},
+        {
+            "name": "data_file",
+            "field-id": 2,
+            "type": {
+                "type": "record",
+                "name": "r2",
+                "fields": [
+                    {"name": "content", "type": "int", "field-id": 134},
+                    {"name": "file_path", "type": "string", "field-id": 100},
+                    {"name": "file_format", "type": "string", "field-id": 101},
+                    {
+                        "name": "partition",
+                        "type": {"type": "record", "name": "r102", "fields": []},
+                        "field-id": 102
+                    },
+                    {"name": "record_count", "type": "long", "field-id": 103},
+                    {"name": "file_size_in_bytes", "type": "long", "field-id": 104},
+                    {
+                        "name": "column_sizes",
+                        "type": ["null", {
+                            "type": "array",
+                            "logicalType": "map",
+                            "items": {
+                                "type": "record",
+                                "name": "k117_v118",
+                                "fields": [
+                                    {"name": "key", "type": "int", "field-id": 117},
+                                    {"name": "value", "type": "long", "field-id": 118}
+                                ]
+                            }
+                        }],
+                        "default": null,
+                        "field-id": 108
+                    },
+                    {
+                        "name": "value_counts",
+                        "type": ["null", {
+                            "type": "array",
+                            "logicalType": "map",
+                            "items": {
+                                "type": "record",
+                                "name": "k119_v120",
+                                "fields": [
+                                    {"name": "key", "type": "int", "field-id": 119},
+                                    {"name": "value", "type": "long", "field-id": 120}
+                                ]
+                            }
+                        }],
+                        "default": null,
+                        "field-id": 109
+                    },
+                    {
+                        "name": "null_value_counts",
+                        "type": ["null", {
+                            "type": "array",
+                            "logicalType": "map",
+                            "items": {
+                                "type": "record",
+                                "name": "k121_v122",
+                                "fields": [
+                                    {"name": "key", "type": "int", "field-id": 121},
+                                    {"name": "value", "type": "long", "field-id": 122}
+                                ]
+                            }
+                        }],
+                        "default": null,
+                        "field-id": 110
+                    },
+                    {
+                        "name": "nan_value_counts",
+                        "type": ["null", {
+                            "type": "array",
+                            "logicalType": "map",
+                            "items": {
+                                "type": "record",
+                                "name": "k138_v139",
+                                "fields": [
+                                    {"name": "key", "type": "int", "field-id": 138},
+                                    {"name": "value", "type": "long", "field-id": 139}
+                                ]
+                            }
+                        }],
+                        "default": null,
+                        "field-id": 137
+                    },
+                    {
+                        "name": "lower_bounds",
+                        "type": ["null", {
+                            "type": "array",
+                            "logicalType": "map",
+                            "items": {
+                                "type": "record",
+                                "name": "k126_v127",
+                                "fields": [
+                                    {"name": "key", "type": "int", "field-id": 126},
+                                    {"name": "value", "type": "bytes", "field-id": 127}
+                                ]
+                            }
+                        }],
+                        "default": null,
+                        "field-id": 125
+                    },
+                    {
+                        "name": "upper_bounds",
+                        "type": ["null", {
+                            "type": "array",
+                            "logicalType": "map",
+                            "items": {
+                                "type": "record",
+                                "name": "k129_v130",
+                                "fields": [
+                                    {"name": "key", "type": "int", "field-id": 129},
+                                    {"name": "value", "type": "bytes", "field-id": 130}
+                                ]
+                            }
+                        }],
+                        "default": null,
+                        "field-id": 128
+                    },
+                    {"name": "key_metadata", "type": ["null", "bytes"], "default": null, "field-id": 131},
+                    {
+                        "name": "split_offsets",
+                        "type": ["null", {"type": "array", "items": "long", "element-id": 133}],
+                        "default": null,
+                        "field-id": 132
+                    },
+                    {
+                        "name": "equality_ids",
+                        "type": ["null", {"type": "array", "items": "int", "element-id": 136}],
+                        "default": null,
+                        "field-id": 135
+                    },
+                    {"name": "sort_order_id", "type": ["null", "int"], "default": null, "field-id": 140}
+                ]
+            }
+        }
+    ]
+}"#;
+
+/// Avro schema of the entries of v2 manifest lists.
+const MANIFEST_FILE_SCHEMA_V2: &str = r#"{
+    "type": "record",
+    "name": "manifest_file",
+    "fields": [
+        {"name": "manifest_path", "type": "string", "field-id": 500},
+        {"name": "manifest_length", "type": "long", "field-id": 501},
+        {"name": "partition_spec_id", "type": "int", "field-id": 502},
+        {"name": "content", "type": "int", "field-id": 517},
+        {"name": "sequence_number", "type": "long", "field-id": 515},
+        {"name": "min_sequence_number", "type": "long", "field-id": 516},
+        {"name": "added_snapshot_id", "type": "long", "field-id": 503},
+        {"name": "added_files_count", "type": "int", "field-id": 504},
+        {"name": "existing_files_count", "type": "int", "field-id": 505},
+        {"name": "deleted_files_count", "type": "int", "field-id": 506},
+        {"name": "added_rows_count", "type": "long", "field-id": 512},
+        {"name": "existing_rows_count", "type": "long", "field-id": 513},
+        {"name": "deleted_rows_count", "type": "long", "field-id": 514},
+        {
+            "name": "partitions",
+            "type": ["null", {
+                "type": "array",
+                "element-id": 508,
+                "items": {
+                    "type": "record",
+                    "name": "r508",
+                    "fields": [
+                        {"name": "contains_null", "type": "boolean", "field-id": 509},
+                        {"name": "contains_nan", "type": ["null", "boolean"], "default": null, "field-id": 518},
+                        {"name": "lower_bound", "type": ["null", "bytes"], "default": null, "field-id": 510},
+                        {"name": "upper_bound", "type": ["null", "bytes"], "default": null, "field-id": 511}
+                    ]
+                }
+            }],
+            "default": null,
+            "field-id": 507
+        },
+        {"name": "key_metadata", "type": ["null", "bytes"], "default": null, "field-id": 519}
+    ]
+}"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_roundtrip() {
+        let mut data_file = DataFile::new("s3://bucket/table/data/a.parquet".into(), 10, 1024);
+        data_file.null_value_counts.insert(1, 2);
+        data_file
+            .lower_bounds
+            .insert(1, 5i64.to_le_bytes().to_vec());
+        data_file
+            .upper_bounds
+            .insert(1, 50i64.to_le_bytes().to_vec());
+
+        let bytes = write_added_files_manifest(
+            &[data_file],
+            r#"{"type":"struct","schema-id":0,"fields":[]}"#,
+            0,
+            0,
+        )
+        .unwrap();
+
+        let manifest = ManifestFile {
+            manifest_path: "s3://bucket/table/metadata/m.avro".into(),
+            manifest_length: bytes.len() as i64,
+            partition_spec_id: 0,
+            content: ManifestContent::Data,
+            sequence_number: 7,
+            min_sequence_number: 7,
+            added_snapshot_id: 123,
+            added_files_count: Some(1),
+            existing_files_count: Some(0),
+            deleted_files_count: Some(0),
+            added_rows_count: Some(10),
+            existing_rows_count: Some(0),
+            deleted_rows_count: Some(0),
+            partitions: vec![],
+        };
+
+        let entries = read_manifest(&bytes, &manifest).unwrap();
+        assert_eq!(entries.len(), 1);
+
+        let entry = &entries[0];
+        assert_eq!(entry.status, ManifestEntryStatus::Added);
+        assert_eq!(entry.snapshot_id, 123);
+        assert_eq!(entry.sequence_number, 7);
+        assert_eq!(entry.data_file.content, DataContent::Data);
+        assert_eq!(entry.data_file.record_count, 10);
+        assert_eq!(entry.data_file.null_value_counts.get(&1), Some(&2));
+        assert_eq!(
+            entry.data_file.upper_bounds.get(&1).map(Vec::as_slice),
+            Some(50i64.to_le_bytes().as_slice())
+        );
+
+        let bytes = write_manifest_list(&[manifest], 123, None, 7).unwrap();
+        let manifests = read_manifest_list(&bytes).unwrap();
+        assert_eq!(manifests.len(), 1);
+        assert_eq!(manifests[0].added_snapshot_id, 123);
+        assert_eq!(manifests[0].live_rows_count(), Some(10));
+    }
+}
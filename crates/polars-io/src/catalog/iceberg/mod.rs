@@ -0,0 +1,11 @@
+//! Client for Iceberg REST catalogs, and planning of scans of and appends to the tables they
+//! manage.
+mod avro;
+pub mod client;
+mod file_io;
+pub mod manifest;
+pub mod models;
+pub mod scan;
+pub mod schema;
+
+pub use avro::AvroValue;
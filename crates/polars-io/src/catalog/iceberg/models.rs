@@ -0,0 +1,197 @@
+//! Models of the Iceberg REST catalog API and of the table metadata.
+//!
+//! Reference: <https://github.com/apache/iceberg/blob/main/open-api/rest-catalog-open-api.yaml>
+use polars_core::prelude::PlHashMap;
+
+/// A namespace, as its list of levels.
+pub type Namespace = Vec<String>;
+
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct CatalogConfig {
+    #[serde(default)]
+    pub defaults: PlHashMap<String, String>,
+    #[serde(default)]
+    pub overrides: PlHashMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TableIdentifier {
+    pub namespace: Namespace,
+    pub name: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct LoadTableResult {
+    #[serde(default)]
+    pub metadata_location: Option<String>,
+    pub metadata: TableMetadata,
+    /// Table-specific configuration, e.g. storage credentials.
+    #[serde(default)]
+    pub config: PlHashMap<String, String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CommitTableResponse {
+    pub metadata_location: String,
+    pub metadata: TableMetadata,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct TableMetadata {
+    pub format_version: u8,
+    #[serde(default)]
+    pub table_uuid: Option<String>,
+    pub location: String,
+    #[serde(default)]
+    pub last_sequence_number: i64,
+    #[serde(default)]
+    pub last_updated_ms: i64,
+    #[serde(default)]
+    pub last_column_id: i32,
+    #[serde(default)]
+    pub current_schema_id: Option<i32>,
+    /// Only set by v1 tables, newer writers use `schemas`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<IcebergTableSchema>,
+    #[serde(default)]
+    pub schemas: Vec<IcebergTableSchema>,
+    #[serde(default)]
+    pub default_spec_id: Option<i32>,
+    /// Only set by v1 tables, newer writers use `partition-specs`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partition_spec: Option<Vec<PartitionField>>,
+    #[serde(default)]
+    pub partition_specs: Vec<PartitionSpec>,
+    #[serde(default)]
+    pub properties: PlHashMap<String, String>,
+    #[serde(default)]
+    pub current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub snapshots: Vec<Snapshot>,
+}
+
+impl TableMetadata {
+    /// Returns `None` if the table has no snapshots.
+    pub fn current_snapshot(&self) -> Option<&Snapshot> {
+        // Older writers use -1 instead of null.
+        let snapshot_id = self.current_snapshot_id.filter(|id| *id != -1)?;
+        self.snapshot(snapshot_id)
+    }
+
+    pub fn snapshot(&self, snapshot_id: i64) -> Option<&Snapshot> {
+        self.snapshots
+            .iter()
+            .find(|snapshot| snapshot.snapshot_id == snapshot_id)
+    }
+
+    /// Returns the schema with the given ID, or the current schema if `schema_id` is `None`.
+    pub fn schema_by_id(&self, schema_id: Option<i32>) -> Option<&IcebergTableSchema> {
+        let schema_id = schema_id.or(self.current_schema_id);
+
+        self.schemas
+            .iter()
+            .find(|schema| schema_id.is_none() || schema.schema_id == schema_id)
+            .or(self.schema.as_ref())
+    }
+
+    pub fn partition_spec(&self, spec_id: i32) -> Option<&PartitionSpec> {
+        self.partition_specs
+            .iter()
+            .find(|spec| spec.spec_id == spec_id)
+    }
+
+    pub fn default_partition_spec(&self) -> Option<&PartitionSpec> {
+        self.partition_spec(self.default_spec_id.unwrap_or(0))
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Snapshot {
+    pub snapshot_id: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_snapshot_id: Option<i64>,
+    /// Always 0 for v1 tables.
+    #[serde(default)]
+    pub sequence_number: i64,
+    pub timestamp_ms: i64,
+    pub manifest_list: String,
+    #[serde(default)]
+    pub summary: PlHashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<i32>,
+}
+
+/// Note: `type` is kept as JSON as it is either the name of a primitive type or a nested type
+/// object.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct IcebergTableSchema {
+    #[serde(default)]
+    pub schema_id: Option<i32>,
+    pub fields: Vec<NestedField>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct NestedField {
+    pub id: i32,
+    pub name: String,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(rename = "type")]
+    pub type_: serde_json::Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PartitionSpec {
+    pub spec_id: i32,
+    pub fields: Vec<PartitionField>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PartitionField {
+    pub source_id: i32,
+    /// Not set by v1 tables, where partition fields are numbered from 1000.
+    #[serde(default)]
+    pub field_id: Option<i32>,
+    pub name: String,
+    pub transform: String,
+}
+
+/// Requirements that must hold for a commit to be accepted.
+#[derive(Debug, serde::Serialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum TableRequirement {
+    AssertTableUuid {
+        uuid: String,
+    },
+    /// A `snapshot-id` of `None` asserts that the ref does not exist.
+    #[serde(rename_all = "kebab-case")]
+    AssertRefSnapshotId {
+        #[serde(rename = "ref")]
+        ref_: String,
+        snapshot_id: Option<i64>,
+    },
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(tag = "action", rename_all = "kebab-case")]
+pub enum TableUpdate {
+    AddSnapshot {
+        snapshot: Snapshot,
+    },
+    #[serde(rename_all = "kebab-case")]
+    SetSnapshotRef {
+        ref_name: String,
+        #[serde(rename = "type")]
+        type_: String,
+        snapshot_id: i64,
+    },
+}
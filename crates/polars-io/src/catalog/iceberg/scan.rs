@@ -0,0 +1,401 @@
+use arrow::bitmap::Bitmap;
+use polars_core::prelude::{
+    AnyValue, Column, DataFrame, DataType, IDX_DTYPE, IdxSize, PlIndexSet, Series,
+};
+use polars_core::schema::Schema;
+use polars_error::{PolarsResult, polars_err};
+use polars_utils::format_pl_smallstr;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::avro::AvroValue;
+use super::file_io::read_file;
+use super::manifest::{
+    DataContent, DataFile, ManifestContent, ManifestEntryStatus, ManifestFile, read_manifest,
+    read_manifest_list,
+};
+use super::models::{IcebergTableSchema, TableMetadata};
+use super::schema::{decode_single_value, iceberg_schema_to_polars};
+use crate::cloud::CloudOptions;
+use crate::predicates::{ScanIOPredicate, SkipBatchPredicate};
+
+/// A data or delete file of a scan.
+#[derive(Debug, Clone)]
+pub struct IcebergScanFile {
+    pub data_file: DataFile,
+    pub partition_spec_id: i32,
+    /// Data sequence number of the file.
+    pub sequence_number: i64,
+}
+
+impl IcebergScanFile {
+    fn same_partition(&self, other: &IcebergScanFile) -> bool {
+        self.partition_spec_id == other.partition_spec_id
+            && self.data_file.partition == other.data_file.partition
+    }
+}
+
+/// The files of a table snapshot that need to be read.
+#[derive(Debug)]
+pub struct IcebergScan {
+    /// Schema of the snapshot.
+    pub schema: Schema,
+    pub data_files: Vec<IcebergScanFile>,
+    pub delete_files: Vec<IcebergScanFile>,
+}
+
+impl IcebergScan {
+    /// Resolves the manifest list of a snapshot, the current snapshot if `snapshot_id` is `None`,
+    /// into the files to scan.
+    ///
+    /// If a predicate is given, data manifests are pruned using the summaries of their
+    /// partition values, and data files using their column statistics and partition values.
+    pub async fn try_new(
+        metadata: &TableMetadata,
+        snapshot_id: Option<i64>,
+        predicate: Option<&ScanIOPredicate>,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Self> {
+        let snapshot = match snapshot_id {
+            Some(snapshot_id) => Some(metadata.snapshot(snapshot_id).ok_or_else(
+                || polars_err!(ComputeError: "iceberg snapshot {} not found", snapshot_id),
+            )?),
+            None => metadata.current_snapshot(),
+        };
+
+        let table_schema = metadata
+            .schema_by_id(snapshot.and_then(|snapshot| snapshot.schema_id))
+            .ok_or_else(|| polars_err!(ComputeError: "iceberg table metadata has no schema"))?;
+        let schema = iceberg_schema_to_polars(table_schema)?;
+
+        let Some(snapshot) = snapshot else {
+            return Ok(Self {
+                schema,
+                data_files: vec![],
+                delete_files: vec![],
+            });
+        };
+
+        let pruner = predicate.and_then(|predicate| {
+            StatsPruner::new(
+                &predicate.live_columns,
+                predicate.skip_batch_predicate.as_deref()?,
+                metadata,
+                table_schema,
+            )
+        });
+
+        let mut manifests =
+            read_manifest_list(&read_file(&snapshot.manifest_list, cloud_options).await?)?;
+
+        if let Some(pruner) = &pruner {
+            let data_manifests = manifests
+                .iter()
+                .filter(|m| m.content == ManifestContent::Data)
+                .collect::<Vec<_>>();
+            let skip = pruner.skip_manifests(&data_manifests)?;
+            let mut skip = skip.iter();
+
+            manifests.retain(|m| m.content != ManifestContent::Data || !skip.next().unwrap());
+        }
+
+        let manifest_entries =
+            futures::future::try_join_all(manifests.iter().map(|manifest| async move {
+                read_manifest(
+                    &read_file(&manifest.manifest_path, cloud_options).await?,
+                    manifest,
+                )
+            }))
+            .await?;
+
+        let mut data_files = vec![];
+        let mut delete_files = vec![];
+
+        for (manifest, entries) in manifests.iter().zip(manifest_entries) {
+            for entry in entries {
+                if entry.status == ManifestEntryStatus::Deleted {
+                    continue;
+                }
+
+                let file = IcebergScanFile {
+                    data_file: entry.data_file,
+                    partition_spec_id: manifest.partition_spec_id,
+                    sequence_number: entry.sequence_number,
+                };
+
+                match file.data_file.content {
+                    DataContent::Data => data_files.push(file),
+                    DataContent::PositionDeletes | DataContent::EqualityDeletes => {
+                        delete_files.push(file)
+                    },
+                }
+            }
+        }
+
+        if let Some(pruner) = &pruner {
+            let skip = pruner.skip_data_files(&data_files)?;
+            let mut skip = skip.iter();
+
+            data_files.retain(|_| !skip.next().unwrap());
+        }
+
+        Ok(Self {
+            schema,
+            data_files,
+            delete_files,
+        })
+    }
+
+    /// Returns the delete files that apply to a data file of the scan.
+    ///
+    /// Position deletes apply to data files of the same partition with a lower or equal sequence
+    /// number. Equality deletes apply to data files with a lower sequence number, of the same
+    /// partition or of any partition if the delete file is unpartitioned.
+    pub fn delete_files_for<'a>(
+        &'a self,
+        data_file: &'a IcebergScanFile,
+    ) -> impl Iterator<Item = &'a IcebergScanFile> + 'a {
+        self.delete_files
+            .iter()
+            .filter(move |delete_file| match delete_file.data_file.content {
+                DataContent::PositionDeletes => {
+                    delete_file.sequence_number >= data_file.sequence_number
+                        && delete_file.same_partition(data_file)
+                },
+                DataContent::EqualityDeletes => {
+                    delete_file.sequence_number > data_file.sequence_number
+                        && (delete_file.data_file.partition.is_empty()
+                            || delete_file.same_partition(data_file))
+                },
+                DataContent::Data => false,
+            })
+    }
+}
+
+/// Evaluates a [`SkipBatchPredicate`] on statistics built from manifests and data files.
+struct StatsPruner<'a> {
+    live_columns: &'a PlIndexSet<PlSmallStr>,
+    skip_batch_predicate: &'a dyn SkipBatchPredicate,
+    metadata: &'a TableMetadata,
+    /// `(field_id, dtype)` for every live column. The field ID is `None` for columns that are not
+    /// top-level columns of the table.
+    columns: Vec<(Option<i32>, DataType)>,
+}
+
+struct ColumnStats {
+    min: AnyValue<'static>,
+    max: AnyValue<'static>,
+    null_count: Option<IdxSize>,
+}
+
+impl ColumnStats {
+    const UNKNOWN: Self = Self {
+        min: AnyValue::Null,
+        max: AnyValue::Null,
+        null_count: None,
+    };
+}
+
+impl<'a> StatsPruner<'a> {
+    fn new(
+        live_columns: &'a PlIndexSet<PlSmallStr>,
+        skip_batch_predicate: &'a dyn SkipBatchPredicate,
+        metadata: &'a TableMetadata,
+        table_schema: &IcebergTableSchema,
+    ) -> Option<Self> {
+        let columns = live_columns
+            .iter()
+            .map(|name| {
+                let dtype = skip_batch_predicate.schema().get(name)?.clone();
+                let field_id = table_schema
+                    .fields
+                    .iter()
+                    .find(|field| field.name == name.as_str())
+                    .map(|field| field.id);
+
+                Some((field_id, dtype))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self {
+            live_columns,
+            skip_batch_predicate,
+            metadata,
+            columns,
+        })
+    }
+
+    /// Index of the identity partition field of `field_id` in a partition spec.
+    fn identity_partition_index(&self, spec_id: i32, field_id: i32) -> Option<usize> {
+        self.metadata
+            .partition_spec(spec_id)?
+            .fields
+            .iter()
+            .position(|field| field.source_id == field_id && field.transform == "identity")
+    }
+
+    fn skip_manifests(&self, manifests: &[&ManifestFile]) -> PolarsResult<Bitmap> {
+        self.evaluate(
+            manifests.len(),
+            |i| manifests[i].live_rows_count(),
+            |i, field_id, dtype| {
+                let manifest = manifests[i];
+                let Some(summary) = self
+                    .identity_partition_index(manifest.partition_spec_id, field_id)
+                    .and_then(|idx| manifest.partitions.get(idx))
+                else {
+                    return ColumnStats::UNKNOWN;
+                };
+
+                if dtype.is_float() && summary.contains_nan != Some(false) {
+                    return ColumnStats::UNKNOWN;
+                }
+
+                let decode = |bound: &Option<Vec<u8>>| {
+                    bound
+                        .as_deref()
+                        .and_then(|bytes| decode_single_value(bytes, dtype))
+                        .unwrap_or(AnyValue::Null)
+                };
+
+                ColumnStats {
+                    min: decode(&summary.lower_bound),
+                    max: decode(&summary.upper_bound),
+                    null_count: (!summary.contains_null).then_some(0),
+                }
+            },
+        )
+    }
+
+    fn skip_data_files(&self, files: &[IcebergScanFile]) -> PolarsResult<Bitmap> {
+        self.evaluate(
+            files.len(),
+            |i| Some(files[i].data_file.record_count),
+            |i, field_id, dtype| {
+                let file = &files[i];
+                let data_file = &file.data_file;
+
+                if let Some(partition_value) = self
+                    .identity_partition_index(file.partition_spec_id, field_id)
+                    .and_then(|idx| data_file.partition.get(idx))
+                {
+                    if matches!(partition_value, AvroValue::Null) {
+                        return ColumnStats {
+                            min: AnyValue::Null,
+                            max: AnyValue::Null,
+                            null_count: IdxSize::try_from(data_file.record_count).ok(),
+                        };
+                    }
+
+                    let value = avro_to_any_value(partition_value, dtype);
+
+                    return ColumnStats {
+                        min: value.clone(),
+                        max: value,
+                        null_count: Some(0),
+                    };
+                }
+
+                // Bounds do not include NaN values.
+                if dtype.is_float() && data_file.nan_value_counts.get(&field_id) != Some(&0) {
+                    return ColumnStats::UNKNOWN;
+                }
+
+                let decode = |bound: Option<&Vec<u8>>| {
+                    bound
+                        .and_then(|bytes| decode_single_value(bytes, dtype))
+                        .unwrap_or(AnyValue::Null)
+                };
+
+                ColumnStats {
+                    min: decode(data_file.lower_bounds.get(&field_id)),
+                    max: decode(data_file.upper_bounds.get(&field_id)),
+                    null_count: data_file
+                        .null_value_counts
+                        .get(&field_id)
+                        .and_then(|v| IdxSize::try_from(*v).ok()),
+                }
+            },
+        )
+    }
+
+    /// Builds the statistics DataFrame expected by [`SkipBatchPredicate::evaluate_with_stat_df`]
+    /// with a row for every item. Returns a mask where set bits can be skipped.
+    fn evaluate(
+        &self,
+        num_items: usize,
+        len: impl Fn(usize) -> Option<i64>,
+        column_stats: impl Fn(usize, i32, &DataType) -> ColumnStats,
+    ) -> PolarsResult<Bitmap> {
+        let mut columns = Vec::with_capacity(1 + self.columns.len() * 3);
+
+        columns.push(Column::new(
+            PlSmallStr::from_static("len"),
+            (0..num_items)
+                .map(|i| len(i).and_then(|v| IdxSize::try_from(v).ok()))
+                .collect::<Vec<_>>(),
+        ));
+
+        for (name, (field_id, dtype)) in self.live_columns.iter().zip(&self.columns) {
+            let min_name = format_pl_smallstr!("{name}_min");
+            let max_name = format_pl_smallstr!("{name}_max");
+            let nc_name = format_pl_smallstr!("{name}_nc");
+
+            let stats = match field_id {
+                Some(field_id) => (0..num_items)
+                    .map(|i| column_stats(i, *field_id, dtype))
+                    .collect::<Vec<_>>(),
+                None => vec![],
+            };
+
+            if stats.iter().all(|s| s.min.is_null() && s.max.is_null()) {
+                columns.extend([
+                    Column::full_null(min_name, num_items, dtype),
+                    Column::full_null(max_name, num_items, dtype),
+                ]);
+            } else {
+                let build = |name: PlSmallStr, values: Vec<AnyValue<'static>>| {
+                    Series::from_any_values_and_dtype(name, &values, &dtype.to_physical(), false)?
+                        .cast(dtype)
+                        .map(Column::from)
+                };
+
+                columns.extend([
+                    build(min_name, stats.iter().map(|s| s.min.clone()).collect())?,
+                    build(max_name, stats.iter().map(|s| s.max.clone()).collect())?,
+                ]);
+            }
+
+            columns.push(if stats.is_empty() {
+                Column::full_null(nc_name, num_items, &IDX_DTYPE)
+            } else {
+                Column::new(
+                    nc_name,
+                    stats.iter().map(|s| s.null_count).collect::<Vec<_>>(),
+                )
+            });
+        }
+
+        let df = DataFrame::new_with_height(num_items, columns)?;
+        self.skip_batch_predicate.evaluate_with_stat_df(&df)
+    }
+}
+
+/// Converts the value of an identity partition field to the physical type of `dtype`.
+fn avro_to_any_value(value: &AvroValue, dtype: &DataType) -> AnyValue<'static> {
+    use DataType as D;
+
+    match (value, dtype) {
+        (AvroValue::Boolean(v), D::Boolean) => AnyValue::Boolean(*v),
+        (AvroValue::Int(v), D::Int32 | D::Date) => AnyValue::Int32(*v),
+        (AvroValue::Int(v), D::Int64) => AnyValue::Int64(*v as i64),
+        (AvroValue::Long(v), D::Int64 | D::Datetime(..)) => AnyValue::Int64(*v),
+        // Iceberg stores microseconds.
+        (AvroValue::Long(v), D::Time) => AnyValue::Int64(v.saturating_mul(1000)),
+        (AvroValue::Float(v), D::Float32) => AnyValue::Float32(*v),
+        (AvroValue::Float(v), D::Float64) => AnyValue::Float64(*v as f64),
+        (AvroValue::Double(v), D::Float64) => AnyValue::Float64(*v),
+        (AvroValue::String(v), D::String) => AnyValue::StringOwned(PlSmallStr::from_str(v)),
+        (AvroValue::Bytes(v), D::Binary) => AnyValue::BinaryOwned(v.clone()),
+        _ => AnyValue::Null,
+    }
+}
@@ -0,0 +1,196 @@
+use polars_core::prelude::{AnyValue, DataType, Field, TimeUnit, TimeZone};
+use polars_core::schema::Schema;
+use polars_error::{PolarsResult, polars_bail, polars_err};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::models::IcebergTableSchema;
+
+pub fn iceberg_schema_to_polars(schema: &IcebergTableSchema) -> PolarsResult<Schema> {
+    schema
+        .fields
+        .iter()
+        .map(|field| {
+            Ok(Field::new(
+                PlSmallStr::from_str(&field.name),
+                parse_iceberg_type(&field.type_)?,
+            ))
+        })
+        .collect()
+}
+
+/// e.g.
+/// ```json
+/// "long"
+/// {"type": "list", "element-id": 3, "element": "string", "element-required": false}
+/// ```
+pub fn parse_iceberg_type(type_: &serde_json::Value) -> PolarsResult<DataType> {
+    use serde_json::Value;
+
+    let object = match type_ {
+        Value::String(name) => return parse_primitive_type(name),
+        Value::Object(object) => object,
+        _ => polars_bail!(ComputeError: "invalid iceberg type: {}", type_),
+    };
+
+    let get = |key: &str| {
+        object
+            .get(key)
+            .ok_or_else(|| polars_err!(ComputeError: "iceberg type without '{}': {}", key, type_))
+    };
+
+    Ok(match get("type")?.as_str() {
+        Some("struct") => {
+            let fields = get("fields")?
+                .as_array()
+                .ok_or_else(|| polars_err!(ComputeError: "invalid iceberg type: {}", type_))?
+                .iter()
+                .map(|field| {
+                    let name = field.get("name").and_then(Value::as_str).ok_or_else(
+                        || polars_err!(ComputeError: "invalid iceberg type: {}", type_),
+                    )?;
+                    let dtype = parse_iceberg_type(field.get("type").unwrap_or(&Value::Null))?;
+                    Ok(Field::new(PlSmallStr::from_str(name), dtype))
+                })
+                .collect::<PolarsResult<_>>()?;
+
+            DataType::Struct(fields)
+        },
+        Some("list") => DataType::List(Box::new(parse_iceberg_type(get("element")?)?)),
+        Some("map") => DataType::List(Box::new(DataType::Struct(vec![
+            Field::new(
+                PlSmallStr::from_static("key"),
+                parse_iceberg_type(get("key")?)?,
+            ),
+            Field::new(
+                PlSmallStr::from_static("value"),
+                parse_iceberg_type(get("value")?)?,
+            ),
+        ]))),
+        _ => polars_bail!(ComputeError: "unsupported iceberg type: {}", type_),
+    })
+}
+
+fn parse_primitive_type(name: &str) -> PolarsResult<DataType> {
+    use DataType as D;
+
+    Ok(match name {
+        "boolean" => D::Boolean,
+        "int" => D::Int32,
+        "long" => D::Int64,
+        "float" => D::Float32,
+        "double" => D::Float64,
+        "date" => D::Date,
+        "time" => D::Time,
+        "timestamp" => D::Datetime(TimeUnit::Microseconds, None),
+        "timestamptz" => D::Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC)),
+        "timestamp_ns" => D::Datetime(TimeUnit::Nanoseconds, None),
+        "timestamptz_ns" => D::Datetime(TimeUnit::Nanoseconds, Some(TimeZone::UTC)),
+        "string" => D::String,
+        "uuid" | "binary" => D::Binary,
+        v if v.starts_with("fixed[") => D::Binary,
+        v if v.starts_with("decimal(") => {
+            let (precision, scale) = v
+                .strip_prefix("decimal(")
+                .and_then(|v| v.strip_suffix(')'))
+                .and_then(|v| v.split_once(','))
+                .and_then(|(p, s)| Some((p.trim().parse().ok()?, s.trim().parse().ok()?)))
+                .ok_or_else(|| polars_err!(ComputeError: "invalid iceberg type: {}", name))?;
+            D::Decimal(Some(precision), Some(scale))
+        },
+        _ => polars_bail!(ComputeError: "unsupported iceberg type: {}", name),
+    })
+}
+
+/// Decodes a value stored with Iceberg's single-value binary serialization, used for the lower
+/// and upper bounds of columns. The output has the physical type of `dtype`.
+///
+/// Returns `None` for values that cannot be decoded or are not supported.
+///
+/// Reference: <https://iceberg.apache.org/spec/#binary-single-value-serialization>
+pub fn decode_single_value(bytes: &[u8], dtype: &DataType) -> Option<AnyValue<'static>> {
+    use DataType as D;
+
+    Some(match dtype {
+        D::Boolean => AnyValue::Boolean(*bytes.first()? != 0),
+        D::Int32 | D::Date => AnyValue::Int32(i32::from_le_bytes(bytes.try_into().ok()?)),
+        // Iceberg bounds of `int` columns that were promoted to `long` are stored with 4 bytes.
+        D::Int64 => AnyValue::Int64(match bytes.len() {
+            4 => i32::from_le_bytes(bytes.try_into().ok()?) as i64,
+            _ => i64::from_le_bytes(bytes.try_into().ok()?),
+        }),
+        D::Datetime(..) => AnyValue::Int64(i64::from_le_bytes(bytes.try_into().ok()?)),
+        // Iceberg stores microseconds.
+        D::Time => AnyValue::Int64(i64::from_le_bytes(bytes.try_into().ok()?).checked_mul(1000)?),
+        D::Float32 => AnyValue::Float32(f32::from_le_bytes(bytes.try_into().ok()?)),
+        D::Float64 => AnyValue::Float64(match bytes.len() {
+            4 => f32::from_le_bytes(bytes.try_into().ok()?) as f64,
+            _ => f64::from_le_bytes(bytes.try_into().ok()?),
+        }),
+        D::String => AnyValue::StringOwned(PlSmallStr::from_str(std::str::from_utf8(bytes).ok()?)),
+        D::Binary => AnyValue::BinaryOwned(bytes.to_vec()),
+        _ => return None,
+    })
+}
+
+/// Encodes a value with Iceberg's single-value binary serialization. The value must have the
+/// physical type of `dtype`.
+pub fn encode_single_value(value: &AnyValue<'_>, dtype: &DataType) -> Option<Vec<u8>> {
+    use DataType as D;
+
+    Some(match (dtype, value) {
+        (D::Boolean, AnyValue::Boolean(v)) => vec![*v as u8],
+        (D::Int32 | D::Date, AnyValue::Int32(v)) => v.to_le_bytes().to_vec(),
+        (D::Int64 | D::Datetime(..), AnyValue::Int64(v)) => v.to_le_bytes().to_vec(),
+        (D::Time, AnyValue::Int64(v)) => (v / 1000).to_le_bytes().to_vec(),
+        (D::Float32, AnyValue::Float32(v)) => v.to_le_bytes().to_vec(),
+        (D::Float64, AnyValue::Float64(v)) => v.to_le_bytes().to_vec(),
+        (D::String, AnyValue::String(v)) => v.as_bytes().to_vec(),
+        (D::String, AnyValue::StringOwned(v)) => v.as_bytes().to_vec(),
+        (D::Binary, AnyValue::Binary(v)) => v.to_vec(),
+        (D::Binary, AnyValue::BinaryOwned(v)) => v.clone(),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_iceberg_type() {
+        let type_: serde_json::Value = serde_json::from_str(
+            r#"{"type": "list", "element-id": 3, "element": "timestamptz", "element-required": false}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            parse_iceberg_type(&type_).unwrap(),
+            DataType::List(Box::new(DataType::Datetime(
+                TimeUnit::Microseconds,
+                Some(TimeZone::UTC)
+            )))
+        );
+        assert!(parse_iceberg_type(&serde_json::Value::from("variant")).is_err());
+    }
+
+    #[test]
+    fn test_single_value_roundtrip() {
+        for (value, dtype) in [
+            (AnyValue::Boolean(true), DataType::Boolean),
+            (AnyValue::Int32(-3), DataType::Date),
+            (AnyValue::Int64(1 << 40), DataType::Int64),
+            (AnyValue::Int64(86_399_000_000_000), DataType::Time),
+            (AnyValue::Float64(1.5), DataType::Float64),
+            (AnyValue::StringOwned("abc".into()), DataType::String),
+        ] {
+            let bytes = encode_single_value(&value, &dtype).unwrap();
+            assert_eq!(decode_single_value(&bytes, &dtype), Some(value));
+        }
+
+        // Bounds of promoted columns.
+        assert_eq!(
+            decode_single_value(&7i32.to_le_bytes(), &DataType::Int64),
+            Some(AnyValue::Int64(7))
+        );
+    }
+}
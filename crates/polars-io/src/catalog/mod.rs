@@ -1 +1,2 @@
+pub mod iceberg;
 pub mod unity;
@@ -4,8 +4,26 @@ use polars_utils::error::TruncateErrorDetail;
 use reqwest::RequestBuilder;
 
 /// Performs the request and attaches the response body to any error messages.
-pub(super) async fn do_request(request: reqwest::RequestBuilder) -> PolarsResult<bytes::Bytes> {
+pub(crate) async fn do_request(request: reqwest::RequestBuilder) -> PolarsResult<bytes::Bytes> {
     let resp = request.send().await.map_err(to_compute_err)?;
+    read_response(resp).await
+}
+
+/// Like [`do_request`], but returns `None` instead of an error if the response has `status`.
+pub(crate) async fn do_request_unless_status(
+    request: reqwest::RequestBuilder,
+    status: reqwest::StatusCode,
+) -> PolarsResult<Option<bytes::Bytes>> {
+    let resp = request.send().await.map_err(to_compute_err)?;
+
+    if resp.status() == status {
+        return Ok(None);
+    }
+
+    read_response(resp).await.map(Some)
+}
+
+async fn read_response(resp: reqwest::Response) -> PolarsResult<bytes::Bytes> {
     let opt_err = resp.error_for_status_ref().map(|_| ());
     let resp_bytes = resp.bytes().await.map_err(to_compute_err)?;
 
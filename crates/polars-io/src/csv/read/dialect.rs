@@ -0,0 +1,347 @@
+use std::io::Read;
+
+use polars_core::prelude::*;
+
+use super::{CommentPrefix, CsvParseOptions};
+
+/// Rewrites CSV data with multi-byte separators or line terminators, or with escape characters,
+/// to the dialect of [`CsvParseOptions::into_normalized`] that the tokenizer supports.
+///
+/// Fields are quoted in the output if they were quoted in the input or if they contain a
+/// separator, line terminator or quote of the output dialect. Comment lines are copied as is.
+pub(crate) struct DialectNormalizer {
+    separator: Box<[u8]>,
+    eol: Box<[u8]>,
+    quote_char: Option<u8>,
+    escape_char: Option<u8>,
+    comment_prefix: Option<Box<[u8]>>,
+    out_separator: u8,
+    out_eol_char: u8,
+    out_quote_char: u8,
+    /// Bytes at which an unquoted run of field content ends.
+    is_special: [bool; 256],
+    /// Unescaped content of the current field.
+    field: Vec<u8>,
+    field_is_quoted: bool,
+    in_quotes: bool,
+    /// The previous byte was the escape character.
+    is_escaped: bool,
+    at_line_start: bool,
+    in_comment: bool,
+}
+
+impl DialectNormalizer {
+    pub(crate) fn try_new(parse_options: &CsvParseOptions) -> PolarsResult<Self> {
+        let separator: Box<[u8]> = match &parse_options.multi_byte_separator {
+            Some(separator) => separator.as_bytes().into(),
+            None => [parse_options.separator].into(),
+        };
+        let eol: Box<[u8]> = match &parse_options.multi_byte_eol {
+            Some(eol) => eol.as_bytes().into(),
+            None => [parse_options.eol_char].into(),
+        };
+        polars_ensure!(
+            !separator.is_empty() && !eol.is_empty(),
+            InvalidOperation: "CSV separator and line terminator cannot be empty"
+        );
+        polars_ensure!(
+            !separator.starts_with(&eol) && !eol.starts_with(&separator),
+            InvalidOperation: "CSV separator and line terminator cannot overlap"
+        );
+
+        let quote_char = parse_options.quote_char;
+        // A doubled quote is the regular way to escape a quote.
+        let escape_char = parse_options.escape_char.filter(|&c| Some(c) != quote_char);
+        let comment_prefix = parse_options
+            .comment_prefix
+            .as_ref()
+            .map(|prefix| match prefix {
+                CommentPrefix::Single(c) => [*c].into(),
+                CommentPrefix::Multi(s) => s.as_bytes().into(),
+            });
+
+        let normalized = parse_options.clone().into_normalized();
+
+        let mut is_special = [false; 256];
+        is_special[separator[0] as usize] = true;
+        is_special[eol[0] as usize] = true;
+        for c in quote_char.into_iter().chain(escape_char) {
+            is_special[c as usize] = true;
+        }
+
+        Ok(Self {
+            separator,
+            eol,
+            quote_char,
+            escape_char,
+            comment_prefix,
+            out_separator: normalized.separator,
+            out_eol_char: normalized.eol_char,
+            out_quote_char: normalized.quote_char.unwrap(),
+            is_special,
+            field: Vec::new(),
+            field_is_quoted: false,
+            in_quotes: false,
+            is_escaped: false,
+            at_line_start: true,
+            in_comment: false,
+        })
+    }
+
+    /// Normalizes `input` into `out` and returns the number of bytes of `input` that were
+    /// consumed. Bytes that may be the start of a separator or line terminator are only consumed
+    /// once more input is available, they must be passed again in the next call.
+    ///
+    /// All input is consumed if `is_last` is set.
+    pub(crate) fn normalize(&mut self, input: &[u8], is_last: bool, out: &mut Vec<u8>) -> usize {
+        let mut i = 0;
+
+        while i < input.len() {
+            let rest = &input[i..];
+
+            if self.in_comment {
+                if let Some(pos) = memchr::memmem::find(rest, &self.eol) {
+                    out.extend_from_slice(&rest[..pos]);
+                    out.push(self.out_eol_char);
+                    i += pos + self.eol.len();
+                    self.in_comment = false;
+                    self.at_line_start = true;
+                    continue;
+                }
+
+                // The end of the input may be the start of a line terminator.
+                let n = if is_last {
+                    rest.len()
+                } else {
+                    rest.len().saturating_sub(self.eol.len() - 1)
+                };
+                out.extend_from_slice(&rest[..n]);
+                return i + n;
+            }
+
+            if self.at_line_start {
+                if let Some(prefix) = &self.comment_prefix {
+                    if !is_last && rest.len() < prefix.len() && prefix.starts_with(rest) {
+                        return i;
+                    }
+                    if rest.starts_with(prefix) {
+                        self.in_comment = true;
+                        continue;
+                    }
+                }
+                self.at_line_start = false;
+            }
+
+            if self.is_escaped {
+                self.field.push(rest[0]);
+                self.is_escaped = false;
+                i += 1;
+                continue;
+            }
+
+            // Copy content up to the next byte that may need handling.
+            let n = rest
+                .iter()
+                .position(|&c| self.is_special[c as usize])
+                .unwrap_or(rest.len());
+            if n > 0 {
+                self.field.extend_from_slice(&rest[..n]);
+                i += n;
+                continue;
+            }
+
+            let c = rest[0];
+
+            if Some(c) == self.escape_char {
+                self.is_escaped = true;
+                i += 1;
+                continue;
+            }
+
+            if Some(c) == self.quote_char {
+                if self.in_quotes {
+                    match rest.get(1) {
+                        None if !is_last => return i,
+                        Some(&next) if next == c => {
+                            self.field.push(c);
+                            i += 2;
+                            continue;
+                        },
+                        _ => self.in_quotes = false,
+                    }
+                } else if self.field.is_empty() && !self.field_is_quoted {
+                    self.field_is_quoted = true;
+                    self.in_quotes = true;
+                } else {
+                    self.field.push(c);
+                }
+                i += 1;
+                continue;
+            }
+
+            if !self.in_quotes {
+                if rest.starts_with(&self.separator) {
+                    self.finish_field(out);
+                    out.push(self.out_separator);
+                    i += self.separator.len();
+                    continue;
+                }
+                if rest.starts_with(&self.eol) {
+                    self.finish_field(out);
+                    out.push(self.out_eol_char);
+                    self.at_line_start = true;
+                    i += self.eol.len();
+                    continue;
+                }
+                if !is_last && (self.separator.starts_with(rest) || self.eol.starts_with(rest)) {
+                    return i;
+                }
+            }
+
+            self.field.push(c);
+            i += 1;
+        }
+
+        if is_last && !self.at_line_start {
+            self.finish_field(out);
+            self.at_line_start = true;
+        }
+
+        i
+    }
+
+    fn finish_field(&mut self, out: &mut Vec<u8>) {
+        let q = self.out_quote_char;
+        let needs_quotes = self.field_is_quoted
+            || self
+                .field
+                .iter()
+                .any(|&c| c == self.out_separator || c == self.out_eol_char || c == q);
+
+        if needs_quotes {
+            out.reserve(self.field.len() + 2);
+            out.push(q);
+            for &c in &self.field {
+                if c == q {
+                    out.push(q);
+                }
+                out.push(c);
+            }
+            out.push(q);
+        } else {
+            out.extend_from_slice(&self.field);
+        }
+
+        self.field.clear();
+        self.field_is_quoted = false;
+        self.in_quotes = false;
+        self.is_escaped = false;
+    }
+}
+
+/// Normalizes the dialect of the output of `R`, see [`DialectNormalizer`].
+pub(crate) struct DialectReader<R: Read> {
+    inner: R,
+    normalizer: DialectNormalizer,
+    in_buf: Box<[u8]>,
+    in_start: usize,
+    in_end: usize,
+    out_buf: Vec<u8>,
+    out_start: usize,
+    is_finished: bool,
+}
+
+impl<R: Read> DialectReader<R> {
+    const BUFFER_SIZE: usize = 64 * 1024;
+
+    pub(crate) fn try_new(inner: R, parse_options: &CsvParseOptions) -> PolarsResult<Self> {
+        Ok(Self {
+            inner,
+            normalizer: DialectNormalizer::try_new(parse_options)?,
+            in_buf: vec![0; Self::BUFFER_SIZE].into_boxed_slice(),
+            in_start: 0,
+            in_end: 0,
+            out_buf: Vec::new(),
+            out_start: 0,
+            is_finished: false,
+        })
+    }
+
+    /// Normalizes the next block of input into `out_buf`.
+    fn normalize_next(&mut self) -> std::io::Result<()> {
+        // Keep the bytes that were not consumed in front of the new input.
+        self.in_buf.copy_within(self.in_start..self.in_end, 0);
+        self.in_end -= self.in_start;
+        self.in_start = 0;
+
+        let n_read = self.inner.read(&mut self.in_buf[self.in_end..])?;
+        self.in_end += n_read;
+        let is_last = n_read == 0;
+
+        self.out_buf.clear();
+        self.out_start = 0;
+        self.in_start =
+            self.normalizer
+                .normalize(&self.in_buf[..self.in_end], is_last, &mut self.out_buf);
+        self.is_finished = is_last;
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for DialectReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.out_start == self.out_buf.len() {
+            if self.is_finished {
+                return Ok(0);
+            }
+            self.normalize_next()?;
+        }
+
+        let n = buf.len().min(self.out_buf.len() - self.out_start);
+        buf[..n].copy_from_slice(&self.out_buf[self.out_start..self.out_start + n]);
+        self.out_start += n;
+        Ok(n)
+    }
+}
+
+/// Normalizes the dialect of the full `bytes` of a CSV file, see [`DialectNormalizer`].
+pub(crate) fn normalize_dialect(
+    bytes: &[u8],
+    parse_options: &CsvParseOptions,
+) -> PolarsResult<Vec<u8>> {
+    let mut out = Vec::with_capacity(bytes.len());
+    DialectNormalizer::try_new(parse_options)?.normalize(bytes, true, &mut out);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalize_in_pieces(input: &[u8], parse_options: &CsvParseOptions) -> Vec<u8> {
+        let mut normalizer = DialectNormalizer::try_new(parse_options).unwrap();
+        let mut out = Vec::new();
+        let mut pending = Vec::new();
+        for &c in input {
+            pending.push(c);
+            let n = normalizer.normalize(&pending, false, &mut out);
+            pending.drain(..n);
+        }
+        normalizer.normalize(&pending, true, &mut out);
+        out
+    }
+
+    #[test]
+    fn test_normalize_dialect() {
+        let parse_options = CsvParseOptions::default()
+            .with_multi_byte_separator(Some("||".into()))
+            .with_multi_byte_eol(Some("\r\n".into()))
+            .with_escape_char(Some(b'\\'))
+            .with_comment_prefix(Some("#"));
+        let input = b"a||b\r\n# x||\"y\r\n\"c||d\"||e\\||f\r\n1\n2||\"g\\\"h\"\"\"||";
+        let expected = b"a\x1fb\n# x||\"y\n\"c||d\"\x1fe||f\n\"1\n2\"\x1f\"g\"\"h\"\"\"\x1f";
+
+        assert_eq!(normalize_dialect(input, &parse_options).unwrap(), expected);
+        assert_eq!(normalize_in_pieces(input, &parse_options), expected);
+    }
+}
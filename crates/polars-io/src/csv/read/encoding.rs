@@ -0,0 +1,254 @@
+use std::io::Read;
+
+use polars_core::prelude::*;
+
+use super::CsvEncoding;
+
+impl CsvEncoding {
+    /// The `encoding_rs` codec of this encoding. `None` for UTF-8, which needs no transcoding,
+    /// and for Latin-1, which `encoding_rs` treats as an alias of Windows-1252.
+    pub(crate) fn encoding_rs(&self) -> Option<&'static encoding_rs::Encoding> {
+        match self {
+            Self::Utf8 | Self::LossyUtf8 | Self::Latin1 => None,
+            Self::Windows1250 => Some(encoding_rs::WINDOWS_1250),
+            Self::Windows1251 => Some(encoding_rs::WINDOWS_1251),
+            Self::Windows1252 => Some(encoding_rs::WINDOWS_1252),
+            Self::Utf16Le => Some(encoding_rs::UTF_16LE),
+            Self::Utf16Be => Some(encoding_rs::UTF_16BE),
+            Self::ShiftJis => Some(encoding_rs::SHIFT_JIS),
+            Self::Gb18030 => Some(encoding_rs::GB18030),
+            Self::EucKr => Some(encoding_rs::EUC_KR),
+            Self::Big5 => Some(encoding_rs::BIG5),
+        }
+    }
+}
+
+/// Transcodes the output of `R` from a [`CsvEncoding`] to UTF-8.
+///
+/// Malformed input results in an error instead of being replaced.
+pub(crate) struct TranscodingReader<R: Read> {
+    inner: R,
+    encoding: CsvEncoding,
+    /// `None` for Latin-1.
+    decoder: Option<encoding_rs::Decoder>,
+    in_buf: Box<[u8]>,
+    in_start: usize,
+    in_end: usize,
+    out_buf: Vec<u8>,
+    out_start: usize,
+    is_inner_eof: bool,
+    is_finished: bool,
+}
+
+impl<R: Read> TranscodingReader<R> {
+    const BUFFER_SIZE: usize = 64 * 1024;
+
+    pub(crate) fn new(inner: R, encoding: CsvEncoding) -> Self {
+        debug_assert!(encoding.is_transcoded());
+
+        Self {
+            inner,
+            encoding,
+            decoder: encoding
+                .encoding_rs()
+                .map(|encoding| encoding.new_decoder_with_bom_removal()),
+            in_buf: vec![0; Self::BUFFER_SIZE].into_boxed_slice(),
+            in_start: 0,
+            in_end: 0,
+            out_buf: Vec::new(),
+            out_start: 0,
+            is_inner_eof: false,
+            is_finished: false,
+        }
+    }
+
+    /// Decodes the next block of input into `out_buf`.
+    fn decode_next(&mut self) -> std::io::Result<()> {
+        if self.in_start == self.in_end && !self.is_inner_eof {
+            let n_read = self.inner.read(&mut self.in_buf)?;
+            self.in_start = 0;
+            self.in_end = n_read;
+            self.is_inner_eof = n_read == 0;
+        }
+
+        let src = &self.in_buf[self.in_start..self.in_end];
+        self.out_buf.clear();
+        self.out_start = 0;
+
+        match &mut self.decoder {
+            None => {
+                self.out_buf.resize(2 * src.len(), 0);
+                let n_written = encoding_rs::mem::convert_latin1_to_utf8(src, &mut self.out_buf);
+                self.out_buf.truncate(n_written);
+                self.in_start = self.in_end;
+            },
+            Some(decoder) => {
+                let max_len = decoder
+                    .max_utf8_buffer_length_without_replacement(src.len())
+                    .unwrap();
+                self.out_buf.resize(max_len, 0);
+
+                let (result, n_read, n_written) = decoder.decode_to_utf8_without_replacement(
+                    src,
+                    &mut self.out_buf,
+                    self.is_inner_eof,
+                );
+                if let encoding_rs::DecoderResult::Malformed(..) = result {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        format!(
+                            "invalid {:?} sequence in CSV data, \
+                            consider using a different encoding",
+                            self.encoding
+                        ),
+                    ));
+                }
+                self.out_buf.truncate(n_written);
+                self.in_start += n_read;
+            },
+        }
+
+        self.is_finished = self.is_inner_eof && self.in_start == self.in_end;
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for TranscodingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.out_start == self.out_buf.len() {
+            if self.is_finished {
+                return Ok(0);
+            }
+            self.decode_next()?;
+        }
+
+        let n = buf.len().min(self.out_buf.len() - self.out_start);
+        buf[..n].copy_from_slice(&self.out_buf[self.out_start..self.out_start + n]);
+        self.out_start += n;
+        Ok(n)
+    }
+}
+
+/// Transcodes the full `bytes` of a CSV file to UTF-8.
+pub(crate) fn transcode_to_utf8(bytes: &[u8], encoding: CsvEncoding) -> PolarsResult<Vec<u8>> {
+    let mut out = Vec::with_capacity(bytes.len());
+    TranscodingReader::new(bytes, encoding).read_to_end(&mut out)?;
+    Ok(out)
+}
+
+/// Encodes the UTF-8 `text` written by the CSV writer in `encoding`, appending it to `out`.
+///
+/// Characters that cannot be represented in `encoding` result in an error.
+pub(crate) fn encode_from_utf8(
+    text: &[u8],
+    encoding: CsvEncoding,
+    out: &mut Vec<u8>,
+) -> PolarsResult<()> {
+    if !encoding.is_transcoded() {
+        out.extend_from_slice(text);
+        return Ok(());
+    }
+
+    let text = std::str::from_utf8(text).map_err(|_| {
+        polars_err!(
+            ComputeError: "cannot encode CSV output that is not valid UTF-8 in {:?}", encoding
+        )
+    })?;
+    let unmappable = |c: char| {
+        polars_err!(
+            InvalidOperation: "character {:?} cannot be encoded in {:?}", c, encoding
+        )
+    };
+
+    match encoding {
+        CsvEncoding::Latin1 => {
+            out.reserve(text.len());
+            for c in text.chars() {
+                out.push(u8::try_from(c).map_err(|_| unmappable(c))?);
+            }
+        },
+        CsvEncoding::Utf16Le => out.extend(text.encode_utf16().flat_map(u16::to_le_bytes)),
+        CsvEncoding::Utf16Be => out.extend(text.encode_utf16().flat_map(u16::to_be_bytes)),
+        _ => {
+            let mut encoder = encoding.encoding_rs().unwrap().new_encoder();
+            let offset = out.len();
+            let max_len = encoder
+                .max_buffer_length_from_utf8_without_replacement(text.len())
+                .unwrap();
+            out.resize(offset + max_len, 0);
+
+            let (result, _, n_written) =
+                encoder.encode_from_utf8_without_replacement(text, &mut out[offset..], true);
+            match result {
+                encoding_rs::EncoderResult::InputEmpty => {},
+                encoding_rs::EncoderResult::Unmappable(c) => return Err(unmappable(c)),
+                encoding_rs::EncoderResult::OutputFull => unreachable!(),
+            }
+            out.truncate(offset + n_written);
+        },
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+
+    /// Returns the input one byte at a time, to split multi-byte sequences.
+    struct ByteByByteReader<'a>(&'a [u8]);
+
+    impl Read for ByteByByteReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let Some((first, rest)) = self.0.split_first().filter(|_| !buf.is_empty()) else {
+                return Ok(0);
+            };
+            buf[0] = *first;
+            self.0 = rest;
+            Ok(1)
+        }
+    }
+
+    #[test]
+    fn test_transcoding_reader_split_input() {
+        // "aé€\n" in UTF-16LE with a byte order mark.
+        let bytes = [0xFF, 0xFE, 0x61, 0x00, 0xE9, 0x00, 0xAC, 0x20, 0x0A, 0x00];
+        let mut out = String::new();
+        TranscodingReader::new(ByteByByteReader(&bytes), CsvEncoding::Utf16Le)
+            .read_to_string(&mut out)
+            .unwrap();
+        assert_eq!(out, "aé€\n");
+
+        // Truncated code unit at the end of the input.
+        let mut out = Vec::new();
+        assert!(
+            TranscodingReader::new(ByteByByteReader(&bytes[..9]), CsvEncoding::Utf16Le)
+                .read_to_end(&mut out)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_encode_roundtrip() {
+        for (text, encoding) in [
+            ("a,é\n", CsvEncoding::Latin1),
+            ("a,€\n", CsvEncoding::Windows1252),
+            ("a,ж\n", CsvEncoding::Windows1251),
+            ("a,€\n", CsvEncoding::Utf16Be),
+            ("a,日本\n", CsvEncoding::ShiftJis),
+        ] {
+            let mut encoded = Vec::new();
+            encode_from_utf8(text.as_bytes(), encoding, &mut encoded).unwrap();
+            assert_ne!(encoded, text.as_bytes());
+            assert_eq!(
+                transcode_to_utf8(&encoded, encoding).unwrap(),
+                text.as_bytes()
+            );
+        }
+
+        let mut encoded = Vec::new();
+        assert!(encode_from_utf8("€".as_bytes(), CsvEncoding::Latin1, &mut encoded).is_err());
+    }
+}
@@ -17,11 +17,14 @@
 //! ```
 
 pub mod buffer;
+mod dialect;
+pub(crate) mod encoding;
 mod options;
 mod parser;
 mod read_impl;
 mod reader;
 pub mod schema_inference;
+mod source_reader;
 mod splitfields;
 mod utils;
 
@@ -30,6 +33,7 @@ pub use parser::{count_rows, count_rows_from_slice, count_rows_from_slice_par};
 pub use read_impl::batched::{BatchedCsvReader, OwnedBatchedCsvReader};
 pub use reader::CsvReader;
 pub use schema_inference::infer_file_schema;
+pub use source_reader::CsvSourceReader;
 
 pub mod _csv_read_internal {
     pub use super::buffer::validate_utf8;
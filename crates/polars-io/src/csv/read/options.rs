@@ -51,6 +51,12 @@ pub struct CsvParseOptions {
     pub separator: u8,
     pub quote_char: Option<u8>,
     pub eol_char: u8,
+    /// Separator of more than one byte, e.g. `||`. Takes precedence over `separator`.
+    pub multi_byte_separator: Option<PlSmallStr>,
+    /// Line terminator of more than one byte, e.g. `\r\n`. Takes precedence over `eol_char`.
+    pub multi_byte_eol: Option<PlSmallStr>,
+    /// Character that makes the next character literal, e.g. `\` in `\"`.
+    pub escape_char: Option<u8>,
     pub encoding: CsvEncoding,
     pub null_values: Option<NullValues>,
     pub missing_is_null: bool,
@@ -99,6 +105,9 @@ impl Default for CsvParseOptions {
             separator: b',',
             quote_char: Some(b'"'),
             eol_char: b'\n',
+            multi_byte_separator: None,
+            multi_byte_eol: None,
+            escape_char: None,
             encoding: Default::default(),
             null_values: None,
             missing_is_null: true,
@@ -259,6 +268,36 @@ impl CsvReadOptions {
 }
 
 impl CsvParseOptions {
+    /// Whether the file is rewritten to the dialect of [`Self::into_normalized`] before it is
+    /// tokenized, which only supports single byte separators and line terminators.
+    pub fn needs_dialect_normalization(&self) -> bool {
+        self.multi_byte_separator.is_some()
+            || self.multi_byte_eol.is_some()
+            || self.escape_char.is_some()
+    }
+
+    /// The options to tokenize a file with after its dialect was normalized. Options that do
+    /// not need normalization are returned unchanged.
+    ///
+    /// The normalized dialect quotes fields with a `"` unless another quote character is set,
+    /// separates fields with the ASCII unit separator if the separator has more than one byte,
+    /// and ends lines with `\n` if the line terminator has more than one byte.
+    pub fn into_normalized(mut self) -> Self {
+        if !self.needs_dialect_normalization() {
+            return self;
+        }
+
+        if self.multi_byte_separator.take().is_some() {
+            self.separator = 0x1F;
+        }
+        if self.multi_byte_eol.take().is_some() {
+            self.eol_char = b'\n';
+        }
+        self.quote_char = Some(self.quote_char.unwrap_or(b'"'));
+        self.escape_char = None;
+        self
+    }
+
     /// The character used to separate fields in the CSV file. This
     /// is most often a comma ','.
     pub fn with_separator(mut self, separator: u8) -> Self {
@@ -279,6 +318,27 @@ impl CsvParseOptions {
         self
     }
 
+    /// Set a separator of more than one byte, e.g. `||`. This takes precedence over the
+    /// single byte separator.
+    pub fn with_multi_byte_separator(mut self, separator: Option<PlSmallStr>) -> Self {
+        self.multi_byte_separator = separator;
+        self
+    }
+
+    /// Set a line terminator of more than one byte, e.g. `\r\n`. This takes precedence over
+    /// the single byte end-of-line character.
+    pub fn with_multi_byte_eol(mut self, eol: Option<PlSmallStr>) -> Self {
+        self.multi_byte_eol = eol;
+        self
+    }
+
+    /// Set the character that makes the next character literal, e.g. a backslash to read `\"`
+    /// as a quote inside a quoted field. Set this to [None] to disable escaping.
+    pub fn with_escape_char(mut self, escape_char: Option<u8>) -> Self {
+        self.escape_char = escape_char;
+        self
+    }
+
     /// Set the encoding used by the file.
     pub fn with_encoding(mut self, encoding: CsvEncoding) -> Self {
         self.encoding = encoding;
@@ -339,6 +399,39 @@ pub enum CsvEncoding {
     Utf8,
     /// Utf8 encoding and unknown bytes are replaced with �.
     LossyUtf8,
+    /// ISO-8859-1, every byte maps to the code point with the same value.
+    Latin1,
+    /// Windows-1250 (Central European).
+    Windows1250,
+    /// Windows-1251 (Cyrillic).
+    Windows1251,
+    /// Windows-1252 (Western European).
+    Windows1252,
+    /// Little-endian UTF-16, a leading byte order mark is skipped.
+    Utf16Le,
+    /// Big-endian UTF-16, a leading byte order mark is skipped.
+    Utf16Be,
+    /// Shift_JIS (Japanese).
+    ShiftJis,
+    /// GB18030 (Simplified Chinese), a superset of GBK.
+    Gb18030,
+    /// EUC-KR (Korean).
+    EucKr,
+    /// Big5 (Traditional Chinese).
+    Big5,
+}
+
+impl CsvEncoding {
+    /// Whether data in this encoding is transcoded to UTF-8 before it is parsed.
+    pub fn is_transcoded(&self) -> bool {
+        !matches!(self, Self::Utf8 | Self::LossyUtf8)
+    }
+
+    /// Whether ASCII characters are encoded as single bytes with the same value, which means
+    /// that line endings, quotes and separators can be found without transcoding.
+    pub fn is_ascii_compatible(&self) -> bool {
+        !matches!(self, Self::Utf16Le | Self::Utf16Be)
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
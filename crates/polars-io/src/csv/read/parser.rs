@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use memchr::memchr2_iter;
 use num_traits::Pow;
 use polars_core::prelude::*;
@@ -10,6 +12,8 @@ use rayon::prelude::*;
 
 use super::CsvParseOptions;
 use super::buffer::Buffer;
+use super::dialect::normalize_dialect;
+use super::encoding::transcode_to_utf8;
 use super::options::{CommentPrefix, NullValuesCompiled};
 use super::splitfields::SplitFields;
 use super::utils::get_file_chunks;
@@ -18,13 +22,9 @@ use crate::utils::compression::maybe_decompress_bytes;
 
 /// Read the number of rows without parsing columns
 /// useful for count(*) queries
-#[allow(clippy::too_many_arguments)]
 pub fn count_rows(
     addr: PlPathRef<'_>,
-    separator: u8,
-    quote_char: Option<u8>,
-    comment_prefix: Option<&CommentPrefix>,
-    eol_char: u8,
+    parse_options: &CsvParseOptions,
     has_header: bool,
     skip_lines: usize,
     skip_rows_before_header: usize,
@@ -46,14 +46,32 @@ pub fn count_rows(
 
     let mmap = MMapSemaphore::new_from_file(&file).unwrap();
     let owned = &mut vec![];
-    let reader_bytes = maybe_decompress_bytes(mmap.as_ref(), owned)?;
+    let mut reader_bytes = maybe_decompress_bytes(mmap.as_ref(), owned)?;
+
+    // Lines can be counted on the raw bytes if the structural characters are encoded as in ASCII.
+    let encoding = parse_options.encoding;
+    let needs_normalization = parse_options.needs_dialect_normalization();
+    let transcoded;
+    if !encoding.is_ascii_compatible() || (encoding.is_transcoded() && needs_normalization) {
+        transcoded = transcode_to_utf8(reader_bytes, encoding)?;
+        reader_bytes = &transcoded;
+    }
+
+    let normalized;
+    let parse_options = if needs_normalization {
+        normalized = normalize_dialect(reader_bytes, parse_options)?;
+        reader_bytes = &normalized;
+        Cow::Owned(parse_options.clone().into_normalized())
+    } else {
+        Cow::Borrowed(parse_options)
+    };
 
     count_rows_from_slice_par(
         reader_bytes,
-        separator,
-        quote_char,
-        comment_prefix,
-        eol_char,
+        parse_options.separator,
+        parse_options.quote_char,
+        parse_options.comment_prefix.as_ref(),
+        parse_options.eol_char,
         has_header,
         skip_lines,
         skip_rows_before_header,
@@ -13,6 +13,8 @@ use rayon::prelude::*;
 
 use super::CsvParseOptions;
 use super::buffer::init_buffers;
+use super::dialect::normalize_dialect;
+use super::encoding::transcode_to_utf8;
 use super::options::{CommentPrefix, CsvEncoding, NullValuesCompiled};
 use super::parser::{
     CountLines, SplitLines, is_comment_line, parse_lines, skip_bom, skip_line_ending,
@@ -159,7 +161,6 @@ impl<'a> CoreReader<'a> {
     ) -> PolarsResult<CoreReader<'a>> {
         let separator = parse_options.separator;
 
-        #[cfg(feature = "decompress")]
         let mut reader_bytes = reader_bytes;
 
         if !cfg!(feature = "decompress") && SupportedCompression::check(&reader_bytes).is_some() {
@@ -173,8 +174,14 @@ impl<'a> CoreReader<'a> {
         // again after decompression.
         #[cfg(feature = "decompress")]
         {
-            let total_n_rows =
-                n_rows.map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
+            // Lines cannot be counted before transcoding or normalization, so the full file is
+            // decompressed.
+            let total_n_rows = n_rows
+                .filter(|_| {
+                    !parse_options.encoding.is_transcoded()
+                        && !parse_options.needs_dialect_normalization()
+                })
+                .map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
             if let Some(b) = decompress(
                 &reader_bytes,
                 total_n_rows,
@@ -186,6 +193,19 @@ impl<'a> CoreReader<'a> {
             }
         }
 
+        if parse_options.encoding.is_transcoded() {
+            let bytes = transcode_to_utf8(&reader_bytes, parse_options.encoding)?;
+            reader_bytes = ReaderBytes::Owned(bytes.into());
+        }
+
+        let parse_options = if parse_options.needs_dialect_normalization() {
+            let bytes = normalize_dialect(&reader_bytes, &parse_options)?;
+            reader_bytes = ReaderBytes::Owned(bytes.into());
+            Arc::new(Arc::unwrap_or_clone(parse_options).into_normalized())
+        } else {
+            parse_options
+        };
+
         let mut schema = match schema {
             Some(schema) => schema,
             None => {
@@ -6,12 +6,14 @@ use polars_time::chunkedarray::string::infer as date_infer;
 #[cfg(feature = "polars-time")]
 use polars_time::prelude::string::Pattern;
 use polars_utils::format_pl_smallstr;
+use polars_utils::mmap::MemSlice;
 
 use super::parser::{SplitLines, is_comment_line, skip_bom, skip_line_ending};
 use super::splitfields::SplitFields;
-use super::{CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues};
+use super::{CsvEncoding, CsvParseOptions, CsvReadOptions, CsvSourceReader, NullValues};
 use crate::csv::read::parser::skip_lines_naive;
 use crate::mmap::ReaderBytes;
+use crate::utils::compression::CompressedReader;
 use crate::utils::{BOOLEAN_RE, FLOAT_RE, FLOAT_RE_DECIMAL, INTEGER_RE};
 
 #[derive(Clone, Debug, Default)]
@@ -171,10 +173,11 @@ pub fn infer_field_schema(string: &str, try_parse_dates: bool, decimal_comma: bo
 #[inline]
 fn parse_bytes_with_encoding(bytes: &[u8], encoding: CsvEncoding) -> PolarsResult<Cow<'_, str>> {
     Ok(match encoding {
-        CsvEncoding::Utf8 => simdutf8::basic::from_utf8(bytes)
+        CsvEncoding::LossyUtf8 => String::from_utf8_lossy(bytes),
+        // Other encodings are transcoded to UTF-8 before parsing.
+        _ => simdutf8::basic::from_utf8(bytes)
             .map_err(|_| polars_err!(ComputeError: "invalid utf-8 sequence"))?
             .into(),
-        CsvEncoding::LossyUtf8 => String::from_utf8_lossy(bytes),
     })
 }
 
@@ -558,3 +561,40 @@ pub fn infer_file_schema(
         )
     }
 }
+
+/// Decompress or transcode the prefix of a CSV file that is needed to infer its schema from
+/// `infer_schema_length` rows. The full file is decoded if the number of rows is not limited.
+///
+/// Line endings in quoted fields are also counted, in which case fewer rows may be available for
+/// inference.
+pub fn decompress_schema_inference_prefix(
+    reader: &mut CsvSourceReader,
+    options: &CsvReadOptions,
+    infer_schema_length: Option<usize>,
+) -> PolarsResult<MemSlice> {
+    let Some(infer_schema_length) = infer_schema_length else {
+        return Ok(reader.read_next_slice(&MemSlice::default(), usize::MAX)?.0);
+    };
+
+    let eol_char = options.parse_options.eol_char;
+    let n_lines = options
+        .skip_lines
+        .saturating_add(options.skip_rows)
+        .saturating_add(options.skip_rows_after_header)
+        .saturating_add(usize::from(options.has_header))
+        .saturating_add(infer_schema_length);
+
+    let mut bytes = MemSlice::default();
+    let mut read_size = CompressedReader::DEFAULT_READ_SIZE;
+
+    loop {
+        let (new_bytes, n_read) = reader.read_next_slice(&bytes, read_size)?;
+        bytes = new_bytes;
+
+        if n_read < read_size || memchr::memchr_iter(eol_char, &bytes).count() > n_lines {
+            return Ok(bytes);
+        }
+
+        read_size = read_size.saturating_mul(2);
+    }
+}
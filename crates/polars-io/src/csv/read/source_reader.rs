@@ -0,0 +1,81 @@
+use std::io::Read;
+
+use polars_core::prelude::*;
+use polars_utils::mmap::MemSlice;
+
+use super::CsvParseOptions;
+use super::dialect::DialectReader;
+use super::encoding::TranscodingReader;
+use crate::utils::compression::{CompressedReader, read_slice_from};
+
+/// Reads the bytes of a CSV file in slices of UTF-8, decompressing and transcoding them from the
+/// [`CsvEncoding`] of the file and normalizing their dialect as needed.
+///
+/// [`CsvEncoding`]: super::CsvEncoding
+pub struct CsvSourceReader {
+    inner: CsvSourceReaderInner,
+    is_compressed: bool,
+}
+
+enum CsvSourceReaderInner {
+    Utf8(CompressedReader),
+    Decoded(Box<dyn Read + Send>),
+}
+
+impl CsvSourceReader {
+    pub fn try_new(slice: MemSlice, parse_options: &CsvParseOptions) -> PolarsResult<Self> {
+        let reader = CompressedReader::try_new(slice)?;
+        let is_compressed = reader.is_compressed();
+
+        let encoding = parse_options.encoding;
+        let inner = match (
+            encoding.is_transcoded(),
+            parse_options.needs_dialect_normalization(),
+        ) {
+            (false, false) => CsvSourceReaderInner::Utf8(reader),
+            (true, false) => {
+                CsvSourceReaderInner::Decoded(Box::new(TranscodingReader::new(reader, encoding)))
+            },
+            (false, true) => CsvSourceReaderInner::Decoded(Box::new(DialectReader::try_new(
+                reader,
+                parse_options,
+            )?)),
+            (true, true) => CsvSourceReaderInner::Decoded(Box::new(DialectReader::try_new(
+                TranscodingReader::new(reader, encoding),
+                parse_options,
+            )?)),
+        };
+
+        Ok(Self {
+            inner,
+            is_compressed,
+        })
+    }
+
+    pub fn is_compressed(&self) -> bool {
+        self.is_compressed
+    }
+
+    /// Whether the bytes are decompressed, transcoded or normalized. These files are decoded in
+    /// slices, other files are read zero-copy.
+    pub fn is_decoded(&self) -> bool {
+        match &self.inner {
+            CsvSourceReaderInner::Utf8(reader) => reader.is_compressed(),
+            CsvSourceReaderInner::Decoded(_) => true,
+        }
+    }
+
+    /// See [`CompressedReader::read_next_slice`].
+    pub fn read_next_slice(
+        &mut self,
+        prev_leftover: &MemSlice,
+        read_size: usize,
+    ) -> std::io::Result<(MemSlice, usize)> {
+        match &mut self.inner {
+            CsvSourceReaderInner::Utf8(reader) => reader.read_next_slice(prev_leftover, read_size),
+            CsvSourceReaderInner::Decoded(reader) => {
+                read_slice_from(reader, prev_leftover, read_size)
+            },
+        }
+    }
+}
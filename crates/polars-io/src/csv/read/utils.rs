@@ -133,6 +133,8 @@ pub(crate) fn decompress(
     quote_char: Option<u8>,
     eol_char: u8,
 ) -> Option<Vec<u8>> {
+    use polars_error::feature_gated;
+
     use crate::utils::compression::SupportedCompression;
 
     if let Some(algo) = SupportedCompression::check(bytes) {
@@ -149,6 +151,18 @@ pub(crate) fn decompress(
                 let mut decoder = zstd::Decoder::with_buffer(bytes).ok()?;
                 decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
             },
+            SupportedCompression::BZIP2 => feature_gated!("bzip2", {
+                let mut decoder = bzip2::read::MultiBzDecoder::new(bytes);
+                decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
+            }),
+            SupportedCompression::XZ => feature_gated!("xz", {
+                let mut decoder = liblzma::read::XzDecoder::new_multi_decoder(bytes);
+                decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
+            }),
+            SupportedCompression::LZ4 => feature_gated!("lz4", {
+                let mut decoder = crate::utils::compression::MultiLz4Decoder::new(bytes).ok()?;
+                decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
+            }),
         }
     } else {
         None
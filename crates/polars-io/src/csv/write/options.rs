@@ -3,6 +3,9 @@ use std::num::NonZeroUsize;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::csv::read::CsvEncoding;
+use crate::utils::compression::ExternalCompression;
+
 /// Options for writing CSV files.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -10,8 +13,10 @@ use serde::{Deserialize, Serialize};
 pub struct CsvWriterOptions {
     pub include_bom: bool,
     pub include_header: bool,
+    pub encoding: CsvEncoding,
     pub batch_size: NonZeroUsize,
     pub serialize_options: SerializeOptions,
+    pub compression: ExternalCompression,
 }
 
 impl Default for CsvWriterOptions {
@@ -19,8 +24,10 @@ impl Default for CsvWriterOptions {
         Self {
             include_bom: false,
             include_header: true,
+            encoding: CsvEncoding::Utf8,
             batch_size: NonZeroUsize::new(1024).unwrap(),
             serialize_options: SerializeOptions::default(),
+            compression: ExternalCompression::default(),
         }
     }
 }
@@ -10,6 +10,8 @@ use polars_error::polars_ensure;
 use rayon::prelude::*;
 use serializer::{serializer_for, string_serializer};
 
+use crate::csv::read::CsvEncoding;
+use crate::csv::read::encoding::encode_from_utf8;
 use crate::csv::write::SerializeOptions;
 
 pub(crate) fn write<W: Write>(
@@ -17,6 +19,7 @@ pub(crate) fn write<W: Write>(
     df: &DataFrame,
     chunk_size: usize,
     options: &SerializeOptions,
+    encoding: CsvEncoding,
     n_threads: usize,
 ) -> PolarsResult<()> {
     for s in df.get_columns() {
@@ -118,6 +121,7 @@ pub(crate) fn write<W: Write>(
     let mut n_rows_finished = 0;
 
     let mut buffers: Vec<_> = (0..n_threads).map(|_| (Vec::new(), Vec::new())).collect();
+    let mut encoded_buffer = Vec::new();
     while n_rows_finished < len {
         let buf_writer = |thread_no, write_buffer: &mut Vec<_>, serializers_vec: &mut Vec<_>| {
             let thread_offset = thread_no * chunk_size;
@@ -193,7 +197,13 @@ pub(crate) fn write<W: Write>(
         }
 
         for (write_buffer, _) in &mut buffers {
-            writer.write_all(write_buffer)?;
+            if encoding.is_transcoded() {
+                encoded_buffer.clear();
+                encode_from_utf8(write_buffer, encoding, &mut encoded_buffer)?;
+                writer.write_all(&encoded_buffer)?;
+            } else {
+                writer.write_all(write_buffer)?;
+            }
             write_buffer.clear();
         }
 
@@ -207,6 +217,7 @@ pub(crate) fn write_header<W: Write>(
     writer: &mut W,
     names: &[&str],
     options: &SerializeOptions,
+    encoding: CsvEncoding,
 ) -> PolarsResult<()> {
     let mut header = Vec::new();
 
@@ -225,13 +236,17 @@ pub(crate) fn write_header<W: Write>(
         }
     }
     header.extend_from_slice(options.line_terminator.as_bytes());
-    writer.write_all(&header)?;
+
+    let mut encoded = Vec::new();
+    encode_from_utf8(&header, encoding, &mut encoded)?;
+    writer.write_all(&encoded)?;
     Ok(())
 }
 
-/// Writes a UTF-8 BOM to `writer`.
-pub(crate) fn write_bom<W: Write>(writer: &mut W) -> PolarsResult<()> {
-    const BOM: [u8; 3] = [0xEF, 0xBB, 0xBF];
-    writer.write_all(&BOM)?;
+/// Writes a byte order mark in `encoding` to `writer`.
+pub(crate) fn write_bom<W: Write>(writer: &mut W, encoding: CsvEncoding) -> PolarsResult<()> {
+    let mut bom = Vec::new();
+    encode_from_utf8("\u{FEFF}".as_bytes(), encoding, &mut bom)?;
+    writer.write_all(&bom)?;
     Ok(())
 }
@@ -8,6 +8,7 @@ use polars_error::PolarsResult;
 
 use super::write_impl::{write, write_bom, write_header};
 use super::{QuoteStyle, SerializeOptions};
+use crate::csv::read::CsvEncoding;
 use crate::shared::SerWriter;
 
 /// Write a DataFrame to csv.
@@ -20,6 +21,7 @@ pub struct CsvWriter<W: Write> {
     options: SerializeOptions,
     header: bool,
     bom: bool,
+    encoding: CsvEncoding,
     batch_size: NonZeroUsize,
     n_threads: usize,
 }
@@ -40,6 +42,7 @@ where
             options,
             header: true,
             bom: false,
+            encoding: CsvEncoding::Utf8,
             batch_size: NonZeroUsize::new(1024).unwrap(),
             n_threads: POOL.current_num_threads(),
         }
@@ -47,7 +50,7 @@ where
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
         if self.bom {
-            write_bom(&mut self.buffer)?;
+            write_bom(&mut self.buffer, self.encoding)?;
         }
         let names = df
             .get_column_names()
@@ -55,13 +58,19 @@ where
             .map(|x| x.as_str())
             .collect::<Vec<_>>();
         if self.header {
-            write_header(&mut self.buffer, names.as_slice(), &self.options)?;
+            write_header(
+                &mut self.buffer,
+                names.as_slice(),
+                &self.options,
+                self.encoding,
+            )?;
         }
         write(
             &mut self.buffer,
             df,
             self.batch_size.into(),
             &self.options,
+            self.encoding,
             self.n_threads,
         )
     }
@@ -77,6 +86,13 @@ where
         self
     }
 
+    /// Set the encoding of the output. Characters that cannot be represented in the encoding
+    /// result in an error.
+    pub fn with_encoding(mut self, encoding: CsvEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
     /// Set whether to write headers.
     pub fn include_header(mut self, include_header: bool) -> Self {
         self.header = include_header;
@@ -198,7 +214,7 @@ impl<W: Write> BatchedWriter<W> {
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         if !self.has_written_bom {
             self.has_written_bom = true;
-            write_bom(&mut self.writer.buffer)?;
+            write_bom(&mut self.writer.buffer, self.writer.encoding)?;
         }
 
         if !self.has_written_header {
@@ -212,6 +228,7 @@ impl<W: Write> BatchedWriter<W> {
                 &mut self.writer.buffer,
                 names.as_slice(),
                 &self.writer.options,
+                self.writer.encoding,
             )?;
         }
 
@@ -220,6 +237,7 @@ impl<W: Write> BatchedWriter<W> {
             df,
             self.writer.batch_size.into(),
             &self.writer.options,
+            self.writer.encoding,
             self.writer.n_threads,
         )?;
         Ok(())
@@ -229,7 +247,7 @@ impl<W: Write> BatchedWriter<W> {
     pub fn finish(&mut self) -> PolarsResult<()> {
         if !self.has_written_bom {
             self.has_written_bom = true;
-            write_bom(&mut self.writer.buffer)?;
+            write_bom(&mut self.writer.buffer, self.writer.encoding)?;
         }
 
         if !self.has_written_header {
@@ -239,7 +257,12 @@ impl<W: Write> BatchedWriter<W> {
                 .iter_names()
                 .map(|x| x.as_str())
                 .collect::<Vec<_>>();
-            write_header(&mut self.writer.buffer, &names, &self.writer.options)?;
+            write_header(
+                &mut self.writer.buffer,
+                &names,
+                &self.writer.options,
+                self.writer.encoding,
+            )?;
         };
 
         Ok(())
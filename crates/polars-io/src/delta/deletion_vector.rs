@@ -0,0 +1,404 @@
+//! Decoding of Delta deletion vectors.
+//!
+//! A deletion vector is a `RoaringBitmapArray` of the positions of the deleted rows of a data
+//! file. It is stored either inline in the transaction log as Z85 encoded bytes, or in a `.bin`
+//! file next to the data files.
+//!
+//! The decoded masks are bounded by the `numRecords` statistic of their data file, as a corrupt
+//! deletion vector could otherwise make us allocate a mask of up to 2^64 bits.
+use std::ops::Range;
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_utils::plpath::{PlPath, PlPathRef};
+use serde::{Deserialize, Serialize};
+
+use super::log::resolve_data_path;
+
+/// The `deletionVector` field of an `add` action.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletionVectorDescriptor {
+    /// `u` for a path relative to the table root, `i` for inline and `p` for an absolute path.
+    pub storage_type: String,
+    pub path_or_inline_dv: String,
+    /// Position of the deletion vector in its file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i32>,
+    pub size_in_bytes: i32,
+    /// Number of deleted rows.
+    pub cardinality: i64,
+}
+
+impl DeletionVectorDescriptor {
+    /// Path of the file containing the deletion vector, `None` if it is stored inline.
+    pub fn absolute_path(&self, table_root: PlPathRef<'_>) -> PolarsResult<Option<PlPath>> {
+        Ok(match self.storage_type.as_str() {
+            "i" => None,
+            "p" => Some(resolve_data_path(table_root, &self.path_or_inline_dv)?),
+            "u" => {
+                // `<random prefix><20 characters of Z85 encoded UUID>`
+                let v = self.path_or_inline_dv.as_str();
+                polars_ensure!(
+                    v.len() >= 20 && v.is_char_boundary(v.len() - 20),
+                    ComputeError: "invalid Delta deletion vector path '{}'", v
+                );
+                let (prefix, uuid) = v.split_at(v.len() - 20);
+                let uuid = format_uuid(&decode_z85(uuid)?);
+                let file_name = format!("deletion_vector_{uuid}.bin");
+
+                Some(if prefix.is_empty() {
+                    table_root.join(&file_name)
+                } else {
+                    table_root.join(prefix).as_ref().join(&file_name)
+                })
+            },
+            v => polars_bail!(ComputeError: "unknown Delta deletion vector storage type '{}'", v),
+        })
+    }
+}
+
+const Z85_ALPHABET: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// Decodes Z85, which encodes every 4 bytes as 5 characters.
+fn decode_z85(encoded: &str) -> PolarsResult<Vec<u8>> {
+    let encoded = encoded.as_bytes();
+    polars_ensure!(
+        encoded.len() % 5 == 0,
+        ComputeError: "invalid Z85 data: length {} is not a multiple of 5", encoded.len()
+    );
+
+    let mut out = Vec::with_capacity(encoded.len() / 5 * 4);
+    for chunk in encoded.chunks_exact(5) {
+        let mut value: u64 = 0;
+        for &c in chunk {
+            let digit = Z85_ALPHABET.iter().position(|&x| x == c).ok_or_else(
+                || polars_err!(ComputeError: "invalid Z85 character '{}'", c as char),
+            )?;
+            value = value * 85 + digit as u64;
+        }
+        let value = u32::try_from(value)
+            .map_err(|_| polars_err!(ComputeError: "invalid Z85 data: value out of range"))?;
+        out.extend_from_slice(&value.to_be_bytes());
+    }
+
+    Ok(out)
+}
+
+fn format_uuid(bytes: &[u8]) -> String {
+    let hex = bytes.iter().map(|b| format!("{b:02x}")).collect::<String>();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+/// Decodes an inline deletion vector of a data file with `num_rows` rows into a mask in which the
+/// deleted rows are unset.
+pub fn decode_inline_deletion_vector(
+    encoded: &str,
+    size_in_bytes: usize,
+    num_rows: u64,
+) -> PolarsResult<Bitmap> {
+    let bytes = decode_z85(encoded)?;
+    polars_ensure!(
+        bytes.len() >= size_in_bytes,
+        ComputeError: "inline Delta deletion vector is shorter than its size of {} bytes", size_in_bytes
+    );
+    deserialize_roaring_bitmap_array(&bytes[..size_in_bytes], num_rows)
+}
+
+/// Byte range to read from a deletion vector file for the deletion vector at `offset`. This is
+/// a big-endian `u32` of the size, the deletion vector and a big-endian CRC-32 checksum of it.
+pub fn deletion_vector_file_range(offset: usize, size_in_bytes: usize) -> Range<usize> {
+    offset..offset + 4 + size_in_bytes + 4
+}
+
+/// Decodes a deletion vector of a data file with `num_rows` rows from the bytes of
+/// [`deletion_vector_file_range`] into a mask in which the deleted rows are unset.
+pub fn decode_deletion_vector_file_range(
+    bytes: &[u8],
+    size_in_bytes: usize,
+    num_rows: u64,
+) -> PolarsResult<Bitmap> {
+    polars_ensure!(
+        bytes.len() == 4 + size_in_bytes + 4,
+        ComputeError: "Delta deletion vector file is truncated"
+    );
+    let mut reader = Reader { bytes };
+    let size = reader.u32_be()? as usize;
+    polars_ensure!(
+        size == size_in_bytes,
+        ComputeError: "Delta deletion vector has size {} in its file, expected {}", size, size_in_bytes
+    );
+    let data = reader.take(size)?;
+    let checksum = reader.u32_be()?;
+    polars_ensure!(
+        crc32fast::hash(data) == checksum,
+        ComputeError: "Delta deletion vector checksum mismatch, the file is corrupt"
+    );
+    deserialize_roaring_bitmap_array(data, num_rows)
+}
+
+const PORTABLE_MAGIC: u32 = 1681511377;
+const NATIVE_MAGIC: u32 = 1681511376;
+const SERIAL_COOKIE_NO_RUNCONTAINER: u32 = 12346;
+const SERIAL_COOKIE: u32 = 12347;
+const NO_OFFSET_THRESHOLD: usize = 4;
+const MAX_ARRAY_CONTAINER_CARDINALITY: usize = 4096;
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> PolarsResult<&'a [u8]> {
+        polars_ensure!(
+            n <= self.bytes.len(),
+            ComputeError: "Delta deletion vector is truncated"
+        );
+        let (out, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Ok(out)
+    }
+
+    fn u16(&mut self) -> PolarsResult<u16> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> PolarsResult<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u32_be(&mut self) -> PolarsResult<u32> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> PolarsResult<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+}
+
+/// Deserializes a `RoaringBitmapArray` of a data file with `num_rows` rows into a mask in which
+/// the deleted rows are unset.
+fn deserialize_roaring_bitmap_array(bytes: &[u8], num_rows: u64) -> PolarsResult<Bitmap> {
+    let positions = roaring_bitmap_array_positions(bytes)?;
+
+    let len = match positions.iter().max() {
+        None => 0,
+        Some(&max) => {
+            polars_ensure!(
+                max < num_rows,
+                ComputeError: "Delta deletion vector deletes row {} of a file with {} rows",
+                max, num_rows
+            );
+            max as usize + 1
+        },
+    };
+    let mut mask = MutableBitmap::from_len_set(len);
+    for position in positions {
+        mask.set(position as usize, false);
+    }
+
+    Ok(mask.freeze())
+}
+
+/// Reads the positions of a `RoaringBitmapArray`, which is a magic number followed by the 32-bit
+/// roaring bitmaps of the upper 32 bits of the positions in one of two formats:
+/// * Portable: a little-endian `u64` count, then a `u32` key and a bitmap for each key.
+/// * Native: a big-endian `u32` count, then the big-endian `u32` size and the bitmap of each
+///   key, with the keys being `0..count`.
+fn roaring_bitmap_array_positions(bytes: &[u8]) -> PolarsResult<Vec<u64>> {
+    let mut reader = Reader { bytes };
+    let mut positions = vec![];
+
+    let magic: [u8; 4] = reader.take(4)?.try_into().unwrap();
+    if u32::from_le_bytes(magic) == PORTABLE_MAGIC {
+        let num_bitmaps = reader.u64()?;
+        for _ in 0..num_bitmaps {
+            let high = reader.u32()? as u64;
+            deserialize_roaring_bitmap(&mut reader, |low| positions.push(high << 32 | low as u64))?;
+        }
+    } else if u32::from_be_bytes(magic) == NATIVE_MAGIC {
+        let num_bitmaps = reader.u32_be()? as u64;
+        for high in 0..num_bitmaps {
+            let size = reader.u32_be()? as usize;
+            let mut bitmap_reader = Reader {
+                bytes: reader.take(size)?,
+            };
+            deserialize_roaring_bitmap(&mut bitmap_reader, |low| {
+                positions.push(high << 32 | low as u64)
+            })?;
+        }
+    } else {
+        polars_bail!(
+            ComputeError: "invalid Delta deletion vector: unexpected magic number {:?}", magic
+        );
+    }
+
+    Ok(positions)
+}
+
+/// Deserializes a 32-bit roaring bitmap in the portable format.
+///
+/// Reference: <https://github.com/RoaringBitmap/RoaringFormatSpec>
+fn deserialize_roaring_bitmap(reader: &mut Reader<'_>, mut f: impl FnMut(u32)) -> PolarsResult<()> {
+    let cookie = reader.u32()?;
+
+    let (num_containers, run_bitmap, has_offsets) = if cookie == SERIAL_COOKIE_NO_RUNCONTAINER {
+        (reader.u32()? as usize, None, true)
+    } else if cookie & 0xFFFF == SERIAL_COOKIE {
+        let num_containers = (cookie >> 16) as usize + 1;
+        let run_bitmap = reader.take(num_containers.div_ceil(8))?;
+        (
+            num_containers,
+            Some(run_bitmap),
+            num_containers >= NO_OFFSET_THRESHOLD,
+        )
+    } else {
+        polars_bail!(
+            ComputeError: "invalid Delta deletion vector: unexpected roaring bitmap cookie {}",
+            cookie
+        )
+    };
+
+    let containers = (0..num_containers)
+        .map(|_| Ok((reader.u16()?, reader.u16()? as usize + 1)))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    if has_offsets {
+        reader.take(4 * num_containers)?;
+    }
+
+    for (i, (key, cardinality)) in containers.into_iter().enumerate() {
+        let high = (key as u32) << 16;
+        let is_run = run_bitmap.is_some_and(|bitmap| bitmap[i / 8] & (1 << (i % 8)) != 0);
+
+        if is_run {
+            let num_runs = reader.u16()?;
+            for _ in 0..num_runs {
+                let start = reader.u16()? as u32;
+                let len = reader.u16()? as u32;
+                (start..=start + len).for_each(|low| f(high | low));
+            }
+        } else if cardinality <= MAX_ARRAY_CONTAINER_CARDINALITY {
+            for _ in 0..cardinality {
+                f(high | reader.u16()? as u32);
+            }
+        } else {
+            for word_idx in 0..1024 {
+                let mut word = reader.u64()?;
+                while word != 0 {
+                    let bit = word.trailing_zeros();
+                    f(high | (word_idx * 64 + bit));
+                    word &= word - 1;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deleted_rows(mask: &Bitmap) -> Vec<usize> {
+        mask.iter()
+            .enumerate()
+            .filter_map(|(i, keep)| (!keep).then_some(i))
+            .collect()
+    }
+
+    #[test]
+    fn test_decode_z85() {
+        assert_eq!(
+            decode_z85("HelloWorld").unwrap(),
+            [0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B]
+        );
+        assert!(decode_z85("Hello").is_ok());
+        assert!(decode_z85("Hell").is_err());
+        assert!(decode_z85("Hell~").is_err());
+    }
+
+    #[test]
+    fn test_decode_inline_deletion_vector() {
+        // Example of the Delta protocol specification, in the native format.
+        let encoded = "wi5b=000010000siXQKl0rr91000f55c8Xg0@@D72lkbi5=-{L";
+        let mask = decode_inline_deletion_vector(encoded, 40, 30).unwrap();
+        assert_eq!(deleted_rows(&mask), [3, 4, 7, 11, 18, 29]);
+        assert!(decode_inline_deletion_vector(encoded, 40, 29).is_err());
+    }
+
+    #[test]
+    fn test_deletion_vector_path() {
+        // Example of the Delta protocol specification.
+        let dv = DeletionVectorDescriptor {
+            storage_type: "u".into(),
+            path_or_inline_dv: "ab^-aqEH.-t@S}K{vb[*k^".into(),
+            offset: Some(4),
+            size_in_bytes: 40,
+            cardinality: 6,
+        };
+        let root = PlPath::new("s3://mytable");
+        assert_eq!(
+            dv.absolute_path(root.as_ref()).unwrap().unwrap().to_str(),
+            "s3://mytable/ab/deletion_vector_d2c639aa-8816-431a-aaf6-d3fe2512ff61.bin"
+        );
+    }
+
+    #[test]
+    fn test_decode_deletion_vector() {
+        let mut bytes = vec![];
+        bytes.extend(PORTABLE_MAGIC.to_le_bytes());
+        bytes.extend(1u64.to_le_bytes());
+
+        // Rows 1 and 3 in an array container, rows 65541..=65543 in a run container.
+        bytes.extend(0u32.to_le_bytes());
+        bytes.extend((SERIAL_COOKIE | (1 << 16)).to_le_bytes());
+        bytes.push(0b10);
+        bytes.extend([0u16, 1, 1, 2].iter().flat_map(|v| v.to_le_bytes()));
+        bytes.extend([1u16, 3].iter().flat_map(|v| v.to_le_bytes()));
+        bytes.extend([1u16, 5, 2].iter().flat_map(|v| v.to_le_bytes()));
+
+        let mask = deserialize_roaring_bitmap_array(&bytes, 70000).unwrap();
+        assert_eq!(mask.len(), 65544);
+        assert_eq!(deleted_rows(&mask), [1, 3, 65541, 65542, 65543]);
+        assert!(deserialize_roaring_bitmap_array(&bytes, 65543).is_err());
+
+        let mut file_range = (bytes.len() as u32).to_be_bytes().to_vec();
+        file_range.extend(&bytes);
+        file_range.extend(crc32fast::hash(&bytes).to_be_bytes());
+        assert_eq!(
+            file_range.len(),
+            deletion_vector_file_range(0, bytes.len()).len()
+        );
+        let mask = decode_deletion_vector_file_range(&file_range, bytes.len(), 70000).unwrap();
+        assert_eq!(deleted_rows(&mask), [1, 3, 65541, 65542, 65543]);
+        assert!(decode_deletion_vector_file_range(&file_range, bytes.len() - 1, 70000).is_err());
+        let last = file_range.len() - 1;
+        file_range[last] ^= 1;
+        assert!(decode_deletion_vector_file_range(&file_range, bytes.len(), 70000).is_err());
+
+        // Row `1 << 32 | 2`, in a bitmap without run containers.
+        bytes[4..12].copy_from_slice(&2u64.to_le_bytes());
+        bytes.extend(1u32.to_le_bytes());
+        bytes.extend(SERIAL_COOKIE_NO_RUNCONTAINER.to_le_bytes());
+        bytes.extend(1u32.to_le_bytes());
+        bytes.extend([0u16, 0].iter().flat_map(|v| v.to_le_bytes()));
+        bytes.extend(0u32.to_le_bytes());
+        bytes.extend(2u16.to_le_bytes());
+
+        assert_eq!(
+            roaring_bitmap_array_positions(&bytes).unwrap(),
+            [1, 3, 65541, 65542, 65543, 1 << 32 | 2]
+        );
+        assert!(roaring_bitmap_array_positions(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
@@ -0,0 +1,826 @@
+use std::collections::BTreeMap;
+use std::io::Cursor;
+use std::path::PathBuf;
+
+use bytes::Bytes;
+use polars_core::prelude::*;
+use polars_error::{feature_gated, to_compute_err};
+use polars_utils::plpath::{PlPath, PlPathRef};
+use serde::Deserialize;
+
+use super::deletion_vector::DeletionVectorDescriptor;
+use super::schema::parse_schema_string;
+use super::stats::{FileStats, file_statistics_df};
+use super::{DeltaFile, DeltaTableVersion};
+use crate::SerReader;
+use crate::cloud::CloudOptions;
+use crate::parquet::read::ParquetReader;
+
+/// Highest reader protocol version that can be read.
+const MAX_READER_VERSION: i64 = 3;
+
+/// Reader features of protocol version 3 that can be read.
+const SUPPORTED_READER_FEATURES: &[&str] =
+    &["deletionVectors", "timestampNtz", "vacuumProtocolCheck"];
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Add {
+    path: String,
+    #[serde(default)]
+    partition_values: PlHashMap<String, Option<String>>,
+    size: i64,
+    stats: Option<String>,
+    deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Remove {
+    path: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Metadata {
+    pub(super) schema_string: String,
+    #[serde(default)]
+    partition_columns: Vec<PlSmallStr>,
+    #[serde(default)]
+    pub(super) configuration: PlHashMap<String, Option<String>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Protocol {
+    min_reader_version: i64,
+    #[serde(default)]
+    pub(super) min_writer_version: i64,
+    reader_features: Option<Vec<String>>,
+    pub(super) writer_features: Option<Vec<String>>,
+}
+
+/// A line of a commit file. Actions other than these, e.g. `commitInfo`, are ignored.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Action {
+    add: Option<Add>,
+    remove: Option<Remove>,
+    meta_data: Option<Metadata>,
+    protocol: Option<Protocol>,
+}
+
+/// The actions of a commit or checkpoint.
+#[derive(Debug, Default)]
+struct Actions {
+    adds: Vec<Add>,
+    removes: Vec<Remove>,
+    metadata: Option<Metadata>,
+    protocol: Option<Protocol>,
+}
+
+impl Actions {
+    fn from_commit(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+
+        for line in bytes.split(|&c| c == b'\n') {
+            if line.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            let action: Action = serde_json::from_slice(line).map_err(to_compute_err)?;
+
+            out.adds.extend(action.add);
+            out.removes.extend(action.remove);
+            if action.meta_data.is_some() {
+                out.metadata = action.meta_data;
+            }
+            if action.protocol.is_some() {
+                out.protocol = action.protocol;
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Reads the `add`, `metaData` and `protocol` actions of a checkpoint. The `remove` actions
+    /// of a checkpoint only refer to files that are no longer part of the table.
+    fn from_checkpoint(bytes: Bytes) -> PolarsResult<Self> {
+        let mut reader = ParquetReader::new(Cursor::new(bytes));
+        let schema = reader.schema()?;
+        let columns = ["add", "metaData", "protocol"]
+            .into_iter()
+            .filter(|name| schema.contains(name))
+            .map(String::from)
+            .collect();
+        let df = reader.with_columns(Some(columns)).finish()?;
+
+        let mut out = Self::default();
+
+        if let Some(add) = checkpoint_actions(&df, "add")? {
+            let paths = add.column("path")?.str()?;
+            let sizes = add.column("size")?.cast(&DataType::Int64)?;
+            let sizes = sizes.i64()?;
+            let stats = match add.column("stats") {
+                Ok(stats) => stats.str()?.iter().map(|v| v.map(String::from)).collect(),
+                Err(_) => vec![None; add.height()],
+            };
+            let partition_values = map_values(add.column("partitionValues")?)?;
+            let deletion_vectors = match add.column("deletionVector") {
+                Ok(deletion_vectors) => checkpoint_deletion_vectors(deletion_vectors)?,
+                Err(_) => vec![None; add.height()],
+            };
+
+            out.adds = paths
+                .iter()
+                .zip(sizes.iter())
+                .zip(stats)
+                .zip(partition_values)
+                .zip(deletion_vectors)
+                .filter_map(
+                    |((((path, size), stats), partition_values), deletion_vector)| {
+                        Some(Add {
+                            path: path?.to_string(),
+                            partition_values,
+                            size: size.unwrap_or_default(),
+                            stats,
+                            deletion_vector,
+                        })
+                    },
+                )
+                .collect();
+        }
+
+        if let Some(metadata) = checkpoint_actions(&df, "metaData")? {
+            let schema_string = metadata.column("schemaString")?.str()?.get(0);
+            let partition_columns = metadata
+                .column("partitionColumns")?
+                .list()?
+                .get_as_series(0)
+                .map(|s| string_list(&s))
+                .transpose()?
+                .unwrap_or_default();
+            let configuration = match metadata.column("configuration") {
+                Ok(configuration) => map_values(configuration)?.pop().unwrap_or_default(),
+                Err(_) => PlHashMap::new(),
+            };
+
+            out.metadata = schema_string.map(|schema_string| Metadata {
+                schema_string: schema_string.to_string(),
+                partition_columns,
+                configuration,
+            });
+        }
+
+        if let Some(protocol) = checkpoint_actions(&df, "protocol")? {
+            let min_reader_version = protocol
+                .column("minReaderVersion")?
+                .cast(&DataType::Int64)?
+                .i64()?
+                .get(0);
+            let min_writer_version = match protocol.column("minWriterVersion") {
+                Ok(version) => version.cast(&DataType::Int64)?.i64()?.get(0),
+                Err(_) => None,
+            };
+            let features = |name: &str| -> PolarsResult<Option<Vec<String>>> {
+                Ok(match protocol.column(name) {
+                    Ok(features) => features
+                        .list()?
+                        .get_as_series(0)
+                        .map(|s| string_list(&s))
+                        .transpose()?
+                        .map(|features| {
+                            features.into_iter().map(PlSmallStr::into_string).collect()
+                        }),
+                    Err(_) => None,
+                })
+            };
+            let reader_features = features("readerFeatures")?;
+            let writer_features = features("writerFeatures")?;
+
+            out.protocol = min_reader_version.map(|min_reader_version| Protocol {
+                min_reader_version,
+                min_writer_version: min_writer_version.unwrap_or_default(),
+                reader_features,
+                writer_features,
+            });
+        }
+
+        Ok(out)
+    }
+}
+
+/// Returns the fields of the non-null rows of the struct column `name` of a checkpoint.
+fn checkpoint_actions(df: &DataFrame, name: &str) -> PolarsResult<Option<DataFrame>> {
+    let Ok(column) = df.column(name) else {
+        return Ok(None);
+    };
+    let column = column.filter(&column.is_not_null())?;
+    Ok(Some(column.struct_()?.clone().unnest()))
+}
+
+/// Reads a `map<string, string>` column, which is read as a list of key-value structs.
+fn map_values(column: &Column) -> PolarsResult<Vec<PlHashMap<String, Option<String>>>> {
+    column
+        .list()?
+        .into_iter()
+        .map(|entries| {
+            let Some(entries) = entries else {
+                return Ok(PlHashMap::new());
+            };
+            let fields = entries.struct_()?.fields_as_series();
+            let (keys, values) = (fields[0].str()?, fields[1].str()?);
+
+            Ok(keys
+                .iter()
+                .zip(values.iter())
+                .filter_map(|(key, value)| Some((key?.to_string(), value.map(String::from))))
+                .collect())
+        })
+        .collect()
+}
+
+/// Reads the `deletionVector` struct column of the `add` actions of a checkpoint.
+fn checkpoint_deletion_vectors(
+    column: &Column,
+) -> PolarsResult<Vec<Option<DeletionVectorDescriptor>>> {
+    let is_valid = column.is_not_null();
+    let fields = column.struct_()?.clone().unnest();
+    let storage_type = fields.column("storageType")?.str()?;
+    let path_or_inline_dv = fields.column("pathOrInlineDv")?.str()?;
+    let offset = fields.column("offset")?.cast(&DataType::Int32)?;
+    let offset = offset.i32()?;
+    let size_in_bytes = fields.column("sizeInBytes")?.cast(&DataType::Int32)?;
+    let size_in_bytes = size_in_bytes.i32()?;
+    let cardinality = fields.column("cardinality")?.cast(&DataType::Int64)?;
+    let cardinality = cardinality.i64()?;
+
+    Ok((0..column.len())
+        .map(|i| {
+            if !is_valid.get(i)? {
+                return None;
+            }
+            Some(DeletionVectorDescriptor {
+                storage_type: storage_type.get(i)?.to_string(),
+                path_or_inline_dv: path_or_inline_dv.get(i)?.to_string(),
+                offset: offset.get(i),
+                size_in_bytes: size_in_bytes.get(i)?,
+                cardinality: cardinality.get(i)?,
+            })
+        })
+        .collect())
+}
+
+fn string_list(s: &Series) -> PolarsResult<Vec<PlSmallStr>> {
+    Ok(s.str()?.iter().flatten().map(PlSmallStr::from).collect())
+}
+
+/// A file in the `_delta_log` directory.
+#[derive(Debug)]
+struct LogFile {
+    name: String,
+    size: usize,
+    /// Modification time in milliseconds since the epoch.
+    last_modified: i64,
+}
+
+enum LogFileKind {
+    Commit,
+    Checkpoint { part: u32, num_parts: u32 },
+}
+
+/// Parses the version and kind of a log file from names such as:
+/// * `00000000000000000010.json`
+/// * `00000000000000000010.checkpoint.parquet`
+/// * `00000000000000000010.checkpoint.0000000001.0000000002.parquet`
+fn parse_log_file_name(name: &str) -> Option<(i64, LogFileKind)> {
+    let (version, rest) = name.split_once('.')?;
+    if version.len() != 20 || !version.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let version = version.parse().ok()?;
+
+    let kind = match rest {
+        "json" => LogFileKind::Commit,
+        "checkpoint.parquet" => LogFileKind::Checkpoint {
+            part: 1,
+            num_parts: 1,
+        },
+        _ => {
+            let (part, num_parts) = rest
+                .strip_prefix("checkpoint.")?
+                .strip_suffix(".parquet")?
+                .split_once('.')?;
+            LogFileKind::Checkpoint {
+                part: part.parse().ok()?,
+                num_parts: num_parts.parse().ok()?,
+            }
+        },
+    };
+
+    Some((version, kind))
+}
+
+/// Access to the `_delta_log` directory of a table.
+pub(super) enum LogStore {
+    Local(PathBuf),
+    #[cfg(feature = "cloud")]
+    Cloud {
+        store: crate::cloud::PolarsObjectStore,
+        prefix: crate::cloud::ObjectStorePath,
+    },
+}
+
+impl LogStore {
+    pub(super) fn try_new(
+        table_root: PlPathRef<'_>,
+        #[cfg_attr(not(feature = "cloud"), allow(unused_variables))] cloud_options: Option<
+            &CloudOptions,
+        >,
+    ) -> PolarsResult<Self> {
+        let log_dir = table_root.join("_delta_log");
+
+        if let Some(path) = log_dir.as_ref().as_local_path() {
+            return Ok(Self::Local(path.to_path_buf()));
+        }
+
+        feature_gated!("cloud", {
+            crate::pl_async::get_runtime().block_in_place_on(async {
+                let (location, store) =
+                    crate::cloud::build_object_store(log_dir.to_str(), cloud_options, false)
+                        .await?;
+                let prefix = crate::cloud::object_path_from_str(&location.prefix)?;
+                Ok(Self::Cloud { store, prefix })
+            })
+        })
+    }
+
+    fn list(&self) -> PolarsResult<Vec<LogFile>> {
+        match self {
+            Self::Local(path) => {
+                let entries = std::fs::read_dir(path).map_err(|err| {
+                    polars_err!(
+                        ComputeError: "could not read the Delta transaction log at '{}': {}",
+                        path.display(), err
+                    )
+                })?;
+
+                let mut out = vec![];
+                for entry in entries {
+                    let entry = entry?;
+                    let metadata = entry.metadata()?;
+                    if !metadata.is_file() {
+                        continue;
+                    }
+                    let last_modified = metadata
+                        .modified()?
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .map_or(0, |d| d.as_millis() as i64);
+
+                    out.push(LogFile {
+                        name: entry.file_name().to_string_lossy().into_owned(),
+                        size: metadata.len() as usize,
+                        last_modified,
+                    });
+                }
+                Ok(out)
+            },
+            #[cfg(feature = "cloud")]
+            Self::Cloud { store, prefix } => {
+                use futures::TryStreamExt;
+
+                crate::pl_async::get_runtime().block_in_place_on(store.try_exec_rebuild_on_err(
+                    |store| {
+                        let store = store.clone();
+                        async move {
+                            let out = store
+                                .list(Some(prefix))
+                                .try_filter_map(|meta| async move {
+                                    // Skip files in subdirectories, e.g. `_sidecars`.
+                                    let name = meta
+                                        .location
+                                        .as_ref()
+                                        .strip_prefix(prefix.as_ref())
+                                        .map(|name| name.trim_start_matches('/'))
+                                        .filter(|name| !name.contains('/'))
+                                        .map(String::from);
+
+                                    Ok(name.map(|name| LogFile {
+                                        name,
+                                        size: meta.size as usize,
+                                        last_modified: meta.last_modified.timestamp_millis(),
+                                    }))
+                                })
+                                .try_collect::<Vec<_>>()
+                                .await?;
+                            Ok(out)
+                        }
+                    },
+                ))
+            },
+        }
+    }
+
+    /// The latest version of the table, `None` if the transaction log does not exist yet.
+    pub(super) fn latest_version(&self) -> PolarsResult<Option<i64>> {
+        if matches!(self, Self::Local(path) if !path.exists()) {
+            return Ok(None);
+        }
+
+        Ok(self
+            .list()?
+            .iter()
+            .filter_map(|file| parse_log_file_name(&file.name))
+            .map(|(version, _)| version)
+            .max())
+    }
+
+    /// Writes the file `name` unless it already exists, in which case `false` is returned.
+    pub(super) fn put_if_absent(&self, name: &str, bytes: Vec<u8>) -> PolarsResult<bool> {
+        match self {
+            Self::Local(path) => {
+                use std::io::Write;
+
+                std::fs::create_dir_all(path)?;
+                let file = std::fs::OpenOptions::new()
+                    .write(true)
+                    .create_new(true)
+                    .open(path.join(name));
+                match file {
+                    Ok(mut file) => {
+                        file.write_all(&bytes)?;
+                        Ok(true)
+                    },
+                    Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => Ok(false),
+                    Err(err) => Err(err.into()),
+                }
+            },
+            #[cfg(feature = "cloud")]
+            Self::Cloud { store, prefix } => {
+                use object_store::{PutMode, PutOptions};
+
+                crate::pl_async::get_runtime().block_in_place_on(async {
+                    let result = store
+                        .to_dyn_object_store()
+                        .await
+                        .put_opts(
+                            &prefix.child(name),
+                            bytes.into(),
+                            PutOptions::from(PutMode::Create),
+                        )
+                        .await;
+                    match result {
+                        Ok(_) => Ok(true),
+                        Err(object_store::Error::AlreadyExists { .. }) => Ok(false),
+                        Err(err) => Err(err.into()),
+                    }
+                })
+            },
+        }
+    }
+
+    fn read(&self, files: &[&LogFile]) -> PolarsResult<Vec<Bytes>> {
+        match self {
+            Self::Local(path) => files
+                .iter()
+                .map(|file| Ok(Bytes::from(std::fs::read(path.join(&file.name))?)))
+                .collect(),
+            #[cfg(feature = "cloud")]
+            Self::Cloud { store, prefix } => crate::pl_async::get_runtime().block_in_place_on(
+                futures::future::try_join_all(files.iter().map(|file| async move {
+                    store
+                        .get_range(&prefix.child(file.name.as_str()), 0..file.size)
+                        .await
+                })),
+            ),
+        }
+    }
+}
+
+/// Resolves the path of an `add` action, which is a percent-encoded URI relative to the table
+/// root or an absolute URI.
+pub(super) fn resolve_data_path(table_root: PlPathRef<'_>, path: &str) -> PolarsResult<PlPath> {
+    let decoded = percent_encoding::percent_decode_str(path)
+        .decode_utf8()
+        .map_err(to_compute_err)?;
+
+    Ok(if path.contains("://") {
+        PlPath::new(&decoded)
+    } else {
+        table_root.join(&*decoded)
+    })
+}
+
+fn check_protocol(protocol: &Protocol, metadata: &Metadata) -> PolarsResult<()> {
+    polars_ensure!(
+        protocol.min_reader_version <= MAX_READER_VERSION,
+        ComputeError: "Delta table requires reader version {}, the highest supported version is {}",
+        protocol.min_reader_version, MAX_READER_VERSION
+    );
+    for feature in protocol.reader_features.iter().flatten() {
+        polars_ensure!(
+            SUPPORTED_READER_FEATURES.contains(&feature.as_str()),
+            ComputeError: "Delta table reader feature '{}' is not supported", feature
+        );
+    }
+    if let Some(Some(mode)) = metadata.configuration.get("delta.columnMapping.mode") {
+        polars_ensure!(
+            mode == "none",
+            ComputeError: "Delta tables with column mapping mode '{}' are not supported", mode
+        );
+    }
+    Ok(())
+}
+
+/// The state of a version of a Delta table.
+#[derive(Debug, Clone)]
+pub struct DeltaSnapshot {
+    pub version: i64,
+    /// Schema of the table, including the partition columns.
+    pub schema: SchemaRef,
+    pub partition_columns: Vec<PlSmallStr>,
+    /// Data files of the table, sorted by path.
+    pub files: Vec<DeltaFile>,
+    pub(super) metadata: Metadata,
+    pub(super) protocol: Option<Protocol>,
+}
+
+impl DeltaSnapshot {
+    /// Replays the transaction log of the table at `table_root` up to `version`, starting from
+    /// the last complete checkpoint before it.
+    pub fn try_load(
+        table_root: PlPathRef<'_>,
+        cloud_options: Option<&CloudOptions>,
+        version: Option<DeltaTableVersion>,
+    ) -> PolarsResult<Self> {
+        let store = LogStore::try_new(table_root, cloud_options)?;
+
+        let mut commits = BTreeMap::new();
+        let mut checkpoints = BTreeMap::<(i64, u32), Vec<(u32, LogFile)>>::new();
+
+        for file in store.list()? {
+            match parse_log_file_name(&file.name) {
+                Some((version, LogFileKind::Commit)) => {
+                    commits.insert(version, file);
+                },
+                Some((version, LogFileKind::Checkpoint { part, num_parts })) => {
+                    checkpoints
+                        .entry((version, num_parts))
+                        .or_default()
+                        .push((part, file));
+                },
+                None => {},
+            }
+        }
+
+        let latest_version = commits
+            .keys()
+            .chain(checkpoints.keys().map(|(version, _)| version))
+            .max()
+            .copied()
+            .ok_or_else(|| {
+                polars_err!(
+                    ComputeError: "no Delta table found at '{}': the transaction log is empty",
+                    table_root.to_str()
+                )
+            })?;
+
+        let version = match version {
+            None => latest_version,
+            Some(DeltaTableVersion::Version(version)) => {
+                polars_ensure!(
+                    (0..=latest_version).contains(&version),
+                    ComputeError: "Delta table version {} does not exist, the latest version is {}",
+                    version, latest_version
+                );
+                version
+            },
+            Some(DeltaTableVersion::Timestamp(timestamp)) => commits
+                .iter()
+                .rev()
+                .find(|(_, file)| file.last_modified <= timestamp)
+                .map(|(version, _)| *version)
+                .ok_or_else(|| {
+                    polars_err!(
+                        ComputeError: "no version of the Delta table was committed at or before {} ms since the epoch",
+                        timestamp
+                    )
+                })?,
+        };
+
+        let checkpoint =
+            checkpoints
+                .into_iter()
+                .rev()
+                .find(|((checkpoint_version, num_parts), parts)| {
+                    *checkpoint_version <= version && parts.len() == *num_parts as usize
+                });
+
+        let first_commit = checkpoint.as_ref().map_or(0, |((v, _), _)| v + 1);
+        let commit_files = (first_commit..=version)
+            .map(|version| {
+                commits.get(&version).ok_or_else(|| {
+                    polars_err!(
+                        ComputeError: "the Delta transaction log is missing the commit of version {}",
+                        version
+                    )
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut actions = vec![];
+        if let Some((_, mut parts)) = checkpoint {
+            parts.sort_unstable_by_key(|(part, _)| *part);
+            let parts = parts.iter().map(|(_, file)| file).collect::<Vec<_>>();
+            for bytes in store.read(&parts)? {
+                actions.push(Actions::from_checkpoint(bytes)?);
+            }
+        }
+        for bytes in store.read(&commit_files)? {
+            actions.push(Actions::from_commit(&bytes)?);
+        }
+
+        let mut adds = PlHashMap::new();
+        let mut metadata = None;
+        let mut protocol = None;
+        for actions in actions {
+            for remove in actions.removes {
+                adds.remove(&remove.path);
+            }
+            for add in actions.adds {
+                adds.insert(add.path.clone(), add);
+            }
+            metadata = actions.metadata.or(metadata);
+            protocol = actions.protocol.or(protocol);
+        }
+
+        let metadata = metadata.ok_or_else(|| {
+            polars_err!(ComputeError: "the Delta transaction log does not contain the table metadata")
+        })?;
+        if let Some(protocol) = &protocol {
+            check_protocol(protocol, &metadata)?;
+        }
+
+        let schema = parse_schema_string(&metadata.schema_string)?;
+        let partition_columns = metadata.partition_columns.clone();
+        for name in &partition_columns {
+            polars_ensure!(
+                schema.contains(name),
+                ComputeError: "Delta table partition column '{}' is not in the table schema", name
+            );
+        }
+
+        let mut adds = adds.into_values().collect::<Vec<_>>();
+        adds.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+
+        let files = adds
+            .into_iter()
+            .map(|add| {
+                let partition_values = partition_columns
+                    .iter()
+                    .map(|name| add.partition_values.get(name.as_str()).cloned().flatten())
+                    .collect();
+
+                Ok(DeltaFile {
+                    path: resolve_data_path(table_root, &add.path)?,
+                    log_path: add.path,
+                    partition_values,
+                    size: add.size,
+                    stats: add.stats.as_deref().map(FileStats::parse).transpose()?,
+                    deletion_vector: add.deletion_vector,
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        Ok(Self {
+            version,
+            schema: Arc::new(schema),
+            partition_columns,
+            files,
+            metadata,
+            protocol,
+        })
+    }
+
+    /// A DataFrame with a row of statistics per file, see [`SkipBatchPredicate`].
+    ///
+    /// [`SkipBatchPredicate`]: crate::predicates::SkipBatchPredicate
+    pub fn file_statistics(&self) -> PolarsResult<Option<DataFrame>> {
+        file_statistics_df(&self.files, &self.schema, &self.partition_columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_commit(log_dir: &std::path::Path, version: i64, actions: &[&str]) {
+        std::fs::write(
+            log_dir.join(format!("{version:020}.json")),
+            actions.join("\n"),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_replay_delta_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_dir = dir.path().join("_delta_log");
+        std::fs::create_dir(&log_dir).unwrap();
+
+        write_commit(
+            &log_dir,
+            0,
+            &[
+                r#"{"protocol":{"minReaderVersion":1,"minWriterVersion":2}}"#,
+                r#"{"metaData":{"id":"x","format":{"provider":"parquet","options":{}},"schemaString":"{\"type\":\"struct\",\"fields\":[{\"name\":\"a\",\"type\":\"long\",\"nullable\":true,\"metadata\":{}},{\"name\":\"p\",\"type\":\"string\",\"nullable\":true,\"metadata\":{}}]}","partitionColumns":["p"],"configuration":{},"createdTime":0}}"#,
+                r#"{"add":{"path":"p=x/0.parquet","partitionValues":{"p":"x"},"size":10,"modificationTime":0,"dataChange":true,"stats":"{\"numRecords\":2,\"minValues\":{\"a\":1},\"maxValues\":{\"a\":2},\"nullCount\":{\"a\":0}}"}}"#,
+                r#"{"add":{"path":"p=y/1%20a.parquet","partitionValues":{"p":null},"size":20,"modificationTime":0,"dataChange":true}}"#,
+            ],
+        );
+        write_commit(
+            &log_dir,
+            1,
+            &[
+                r#"{"commitInfo":{"operation":"WRITE"}}"#,
+                r#"{"remove":{"path":"p=x/0.parquet","dataChange":true}}"#,
+                r#"{"add":{"path":"p=x/2.parquet","partitionValues":{"p":"x"},"size":30,"modificationTime":0,"dataChange":true,"stats":"{\"numRecords\":1,\"minValues\":{\"a\":5},\"maxValues\":{\"a\":5},\"nullCount\":{\"a\":0}}","deletionVector":{"storageType":"u","pathOrInlineDv":"ab^-aqEH.-t@S}K{vb[*k^","offset":1,"sizeInBytes":36,"cardinality":2}}}"#,
+            ],
+        );
+
+        let root = PlPath::new(dir.path().to_str().unwrap());
+        let file_names = |snapshot: &DeltaSnapshot| {
+            snapshot
+                .files
+                .iter()
+                .map(|file| {
+                    file.path
+                        .to_str()
+                        .strip_prefix(root.to_str())
+                        .unwrap()
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let snapshot = DeltaSnapshot::try_load(root.as_ref(), None, None).unwrap();
+        assert_eq!(snapshot.version, 1);
+        assert_eq!(
+            file_names(&snapshot),
+            ["/p=x/2.parquet", "/p=y/1 a.parquet"]
+        );
+        assert_eq!(snapshot.partition_columns, ["p"]);
+        assert_eq!(snapshot.files[0].partition_values, [Some("x".to_string())]);
+        assert_eq!(snapshot.files[1].partition_values, [None]);
+        assert_eq!(
+            snapshot.files[0]
+                .deletion_vector
+                .as_ref()
+                .map(|dv| dv.cardinality),
+            Some(2)
+        );
+        assert!(snapshot.files[1].deletion_vector.is_none());
+
+        let stats = snapshot.file_statistics().unwrap().unwrap();
+        assert_eq!(
+            stats.get_column_names_str(),
+            ["len", "a_min", "a_max", "a_nc"]
+        );
+        assert_eq!(
+            stats.column("a_min").unwrap().i64().unwrap().to_vec(),
+            [Some(5), None]
+        );
+
+        let snapshot =
+            DeltaSnapshot::try_load(root.as_ref(), None, Some(DeltaTableVersion::Version(0)))
+                .unwrap();
+        assert_eq!(snapshot.version, 0);
+        assert_eq!(
+            file_names(&snapshot),
+            ["/p=x/0.parquet", "/p=y/1 a.parquet"]
+        );
+
+        assert!(
+            DeltaSnapshot::try_load(root.as_ref(), None, Some(DeltaTableVersion::Version(2)))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_column_mapping_not_supported() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_dir = dir.path().join("_delta_log");
+        std::fs::create_dir(&log_dir).unwrap();
+
+        write_commit(
+            &log_dir,
+            0,
+            &[
+                r#"{"protocol":{"minReaderVersion":3,"minWriterVersion":7,"readerFeatures":["columnMapping"],"writerFeatures":["columnMapping"]}}"#,
+                r#"{"metaData":{"id":"x","format":{"provider":"parquet","options":{}},"schemaString":"{\"type\":\"struct\",\"fields\":[{\"name\":\"a\",\"type\":\"long\",\"nullable\":true,\"metadata\":{\"delta.columnMapping.id\":1,\"delta.columnMapping.physicalName\":\"col-1\"}}]}","partitionColumns":[],"configuration":{"delta.columnMapping.mode":"name"},"createdTime":0}}"#,
+            ],
+        );
+
+        let root = PlPath::new(dir.path().to_str().unwrap());
+        let err = DeltaSnapshot::try_load(root.as_ref(), None, None).unwrap_err();
+        assert!(err.to_string().contains("columnMapping"));
+    }
+}
@@ -0,0 +1,67 @@
+//! Reading and writing of Delta Lake tables.
+//!
+//! The transaction log in the `_delta_log` directory of a table is replayed to find the data
+//! files of a version of the table, which are then scanned as Parquet files. Writes add Parquet
+//! files to the table by committing a new version to the log.
+//!
+//! Reference: <https://github.com/delta-io/delta/blob/master/PROTOCOL.md>
+pub mod deletion_vector;
+mod log;
+mod schema;
+mod stats;
+mod write;
+
+use polars_core::prelude::*;
+use polars_utils::plpath::PlPath;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use self::deletion_vector::DeletionVectorDescriptor;
+pub use self::log::DeltaSnapshot;
+use self::stats::FileStats;
+pub use self::write::{DeltaTableWriter, DeltaWriteMode};
+use crate::parquet::read::ParquetOptions;
+
+/// The version of a Delta table to read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum DeltaTableVersion {
+    /// A version number of the table.
+    Version(i64),
+    /// The latest version committed at or before this time, in milliseconds since the epoch.
+    Timestamp(i64),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct DeltaScanOptions {
+    /// Read the latest version of the table if `None`.
+    pub version: Option<DeltaTableVersion>,
+    /// Options for reading the data files.
+    pub parquet_options: ParquetOptions,
+}
+
+/// A data file of a version of a Delta table.
+#[derive(Debug, Clone)]
+pub struct DeltaFile {
+    /// Path of the data file.
+    pub path: PlPath,
+    /// Path of the data file as written in the transaction log.
+    log_path: String,
+    /// Values of the partition columns of the table, `None` for nulls.
+    pub partition_values: Vec<Option<String>>,
+    /// Size of the file in bytes.
+    pub size: i64,
+    /// Deletion vector of the rows of the file that are deleted.
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+    stats: Option<FileStats>,
+}
+
+impl DeltaFile {
+    /// Number of rows in the file, if known from the statistics.
+    pub fn num_records(&self) -> Option<u64> {
+        self.stats.as_ref().and_then(FileStats::num_records)
+    }
+}
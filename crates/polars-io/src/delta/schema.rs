@@ -0,0 +1,197 @@
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use serde::Deserialize;
+
+/// A field of the `schemaString` of a Delta table, e.g.
+/// ```json
+/// {"name":"a","type":"long","nullable":true,"metadata":{}}
+/// {"name":"b","type":{"type":"array","elementType":"string","containsNull":true},"nullable":true,"metadata":{}}
+/// ```
+#[derive(Debug, Deserialize)]
+struct StructField {
+    name: PlSmallStr,
+    #[serde(rename = "type")]
+    type_: DeltaType,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum DeltaType {
+    Primitive(PlSmallStr),
+    Complex(Box<ComplexType>),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum ComplexType {
+    Struct {
+        fields: Vec<StructField>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Array {
+        element_type: DeltaType,
+    },
+    #[serde(rename_all = "camelCase")]
+    Map {
+        key_type: DeltaType,
+        value_type: DeltaType,
+    },
+}
+
+/// Parses the `schemaString` of the `metaData` action of a Delta table.
+pub(super) fn parse_schema_string(schema_string: &str) -> PolarsResult<Schema> {
+    let ComplexType::Struct { fields } =
+        serde_json::from_str(schema_string).map_err(to_compute_err)?
+    else {
+        polars_bail!(ComputeError: "Delta table schema must be a struct type");
+    };
+
+    fields
+        .into_iter()
+        .map(|field| Ok(Field::new(field.name, to_dtype(field.type_)?)))
+        .collect()
+}
+
+fn to_dtype(delta_type: DeltaType) -> PolarsResult<DataType> {
+    let complex = match delta_type {
+        DeltaType::Primitive(name) => return primitive_to_dtype(&name),
+        DeltaType::Complex(complex) => complex,
+    };
+
+    Ok(match *complex {
+        ComplexType::Struct { fields } => DataType::Struct(
+            fields
+                .into_iter()
+                .map(|field| Ok(Field::new(field.name, to_dtype(field.type_)?)))
+                .collect::<PolarsResult<_>>()?,
+        ),
+        ComplexType::Array { element_type } => DataType::List(Box::new(to_dtype(element_type)?)),
+        // Maps are read as a list of key-value structs.
+        ComplexType::Map {
+            key_type,
+            value_type,
+        } => DataType::List(Box::new(DataType::Struct(vec![
+            Field::new(PlSmallStr::from_static("key"), to_dtype(key_type)?),
+            Field::new(PlSmallStr::from_static("value"), to_dtype(value_type)?),
+        ]))),
+    })
+}
+
+/// Reference: <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#primitive-types>
+fn primitive_to_dtype(name: &str) -> PolarsResult<DataType> {
+    use DataType::*;
+
+    Ok(match name {
+        "string" => String,
+        "long" => Int64,
+        "integer" => Int32,
+        "short" => Int16,
+        "byte" => Int8,
+        "float" => Float32,
+        "double" => Float64,
+        "boolean" => Boolean,
+        "binary" => Binary,
+        "date" => Date,
+        "timestamp" => Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC)),
+        "timestamp_ntz" => Datetime(TimeUnit::Microseconds, None),
+        v => {
+            // e.g. decimal(10,2)
+            let decimal = v
+                .strip_prefix("decimal(")
+                .and_then(|v| v.strip_suffix(')'))
+                .and_then(|v| v.split_once(','))
+                .and_then(|(precision, scale)| {
+                    Some(Decimal(
+                        Some(precision.trim().parse().ok()?),
+                        Some(scale.trim().parse().ok()?),
+                    ))
+                });
+
+            decimal.ok_or_else(
+                || polars_err!(ComputeError: "unsupported Delta table data type: {}", v),
+            )?
+        },
+    })
+}
+
+/// Returns the data type that `dtype` is stored as in a Delta table, e.g. timestamps are stored
+/// in microseconds.
+pub(super) fn to_storage_dtype(dtype: &DataType) -> DataType {
+    match dtype {
+        DataType::Datetime(_, time_zone) => {
+            DataType::Datetime(TimeUnit::Microseconds, time_zone.clone())
+        },
+        DataType::Decimal(precision, scale) => {
+            DataType::Decimal(Some(precision.unwrap_or(38)), Some(scale.unwrap_or(0)))
+        },
+        DataType::List(inner) => DataType::List(Box::new(to_storage_dtype(inner))),
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|field| Field::new(field.name.clone(), to_storage_dtype(&field.dtype)))
+                .collect(),
+        ),
+        dtype => dtype.clone(),
+    }
+}
+
+/// Builds the `schemaString` of the `metaData` action of a Delta table.
+pub(super) fn to_schema_string(schema: &Schema) -> PolarsResult<String> {
+    let fields = schema
+        .iter()
+        .map(|(name, dtype)| to_struct_field(name, dtype))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    Ok(serde_json::json!({"type": "struct", "fields": fields}).to_string())
+}
+
+fn to_struct_field(name: &str, dtype: &DataType) -> PolarsResult<serde_json::Value> {
+    Ok(serde_json::json!({
+        "name": name,
+        "type": to_delta_type(dtype)?,
+        "nullable": true,
+        "metadata": {},
+    }))
+}
+
+/// Converts a data type with [`to_storage_dtype`] applied to a Delta data type.
+pub(super) fn to_delta_type(dtype: &DataType) -> PolarsResult<serde_json::Value> {
+    use DataType::*;
+
+    let name = match dtype {
+        String => "string",
+        Int64 => "long",
+        Int32 => "integer",
+        Int16 => "short",
+        Int8 => "byte",
+        Float32 => "float",
+        Float64 => "double",
+        Boolean => "boolean",
+        Binary => "binary",
+        Date => "date",
+        Datetime(TimeUnit::Microseconds, Some(_)) => "timestamp",
+        Datetime(TimeUnit::Microseconds, None) => "timestamp_ntz",
+        Decimal(Some(precision), Some(scale)) => {
+            return Ok(format!("decimal({precision},{scale})").into());
+        },
+        List(inner) => {
+            return Ok(serde_json::json!({
+                "type": "array",
+                "elementType": to_delta_type(inner)?,
+                "containsNull": true,
+            }));
+        },
+        Struct(fields) => {
+            let fields = fields
+                .iter()
+                .map(|field| to_struct_field(&field.name, &field.dtype))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            return Ok(serde_json::json!({"type": "struct", "fields": fields}));
+        },
+        dtype => polars_bail!(
+            ComputeError: "data type {} cannot be written to a Delta table", dtype
+        ),
+    };
+
+    Ok(name.into())
+}
@@ -0,0 +1,145 @@
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use polars_utils::format_pl_smallstr;
+use serde::Deserialize;
+
+use super::DeltaFile;
+
+/// The `stats` of an `add` action, e.g.
+/// ```json
+/// {"numRecords":3,"minValues":{"a":1},"maxValues":{"a":3},"nullCount":{"a":0}}
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct FileStats {
+    num_records: Option<u64>,
+    #[serde(default)]
+    min_values: serde_json::Map<String, serde_json::Value>,
+    #[serde(default)]
+    max_values: serde_json::Map<String, serde_json::Value>,
+    #[serde(default)]
+    null_count: serde_json::Map<String, serde_json::Value>,
+}
+
+impl FileStats {
+    pub(super) fn parse(stats: &str) -> PolarsResult<Self> {
+        serde_json::from_str(stats).map_err(to_compute_err)
+    }
+
+    pub(super) fn num_records(&self) -> Option<u64> {
+        self.num_records
+    }
+}
+
+/// Writers truncate string statistics to a prefix of this many characters, which makes the
+/// maximum unusable as an upper bound.
+pub(super) const TRUNCATED_STRING_STATS_LENGTH: usize = 32;
+
+fn stat_to_string(value: Option<&serde_json::Value>) -> Option<String> {
+    match value? {
+        serde_json::Value::String(v) => Some(v.clone()),
+        serde_json::Value::Number(v) => Some(v.to_string()),
+        _ => None,
+    }
+}
+
+fn parse_stats(mut values: Vec<Option<String>>, dtype: &DataType) -> PolarsResult<Series> {
+    match dtype {
+        // e.g. `2021-01-01T00:00:00.000Z`, the time zone of the column is applied afterwards.
+        DataType::Datetime(time_unit, _) => {
+            for v in values.iter_mut().flatten() {
+                if v.ends_with('Z') {
+                    v.pop();
+                }
+            }
+            Series::new(PlSmallStr::EMPTY, values)
+                .cast(&DataType::Datetime(*time_unit, None))?
+                .cast(dtype)
+        },
+        _ => Series::new(PlSmallStr::EMPTY, values).cast(dtype),
+    }
+}
+
+/// Builds a DataFrame with a row of statistics per file, in the layout used to skip batches with
+/// a predicate: a `len` column and `{col}_min`, `{col}_max` and `{col}_nc` columns.
+///
+/// Only top-level columns of numeric, temporal and string types are included. Statistics that
+/// are missing or cannot be parsed are null, which never allows skipping a file.
+pub(super) fn file_statistics_df(
+    files: &[DeltaFile],
+    schema: &Schema,
+    partition_columns: &[PlSmallStr],
+) -> PolarsResult<Option<DataFrame>> {
+    if files.iter().all(|file| file.stats.is_none()) {
+        return Ok(None);
+    }
+
+    let len = files
+        .iter()
+        .map(|file| {
+            file.stats
+                .as_ref()
+                .and_then(FileStats::num_records)
+                .map(|n| n as IdxSize)
+        })
+        .collect::<IdxCa>();
+    let mut columns = vec![len.with_name(PlSmallStr::from_static("len")).into_column()];
+
+    for (name, dtype) in schema.iter() {
+        if partition_columns.contains(name)
+            || !(dtype.is_primitive_numeric()
+                || dtype.is_temporal()
+                || dtype.is_decimal()
+                || dtype.is_string())
+        {
+            continue;
+        }
+
+        let (min, max): (Vec<_>, Vec<_>) = files
+            .iter()
+            .map(|file| {
+                let Some(stats) = &file.stats else {
+                    return (None, None);
+                };
+                let min = stat_to_string(stats.min_values.get(name.as_str()));
+                let max = stat_to_string(stats.max_values.get(name.as_str())).filter(|v| {
+                    !dtype.is_string() || v.chars().count() < TRUNCATED_STRING_STATS_LENGTH
+                });
+                (min, max)
+            })
+            .unzip();
+        let null_count = files
+            .iter()
+            .map(|file| {
+                let stats = file.stats.as_ref()?;
+                let nc = stats.null_count.get(name.as_str())?.as_u64()?;
+                Some(nc as IdxSize)
+            })
+            .collect::<IdxCa>();
+
+        let min = parse_stats(min, dtype)?;
+        let mut max = parse_stats(max, dtype)?;
+
+        // Timestamps are truncated to milliseconds.
+        if let DataType::Datetime(time_unit, _) = dtype {
+            let one_ms: i64 = match time_unit {
+                TimeUnit::Nanoseconds => 1_000_000,
+                TimeUnit::Microseconds => 1_000,
+                TimeUnit::Milliseconds => 1,
+            };
+            max = (&max.to_physical_repr().into_owned() + one_ms).cast(dtype)?;
+        }
+
+        columns.extend([
+            min.with_name(format_pl_smallstr!("{name}_min"))
+                .into_column(),
+            max.with_name(format_pl_smallstr!("{name}_max"))
+                .into_column(),
+            null_count
+                .with_name(format_pl_smallstr!("{name}_nc"))
+                .into_column(),
+        ]);
+    }
+
+    DataFrame::new_with_height(files.len(), columns).map(Some)
+}
@@ -0,0 +1,576 @@
+use polars_core::prelude::*;
+use polars_utils::plpath::{PlPath, PlPathRef};
+use serde_json::{Value, json};
+
+use super::log::{LogStore, Protocol};
+use super::schema::{to_delta_type, to_schema_string, to_storage_dtype};
+use super::stats::TRUNCATED_STRING_STATS_LENGTH;
+use super::{DeltaFile, DeltaSnapshot};
+use crate::cloud::CloudOptions;
+use crate::utils::URL_ENCODE_CHAR_SET;
+
+/// Highest writer protocol version that can be written.
+const MAX_WRITER_VERSION: i64 = 7;
+
+/// Writer features that have no effect on appending Parquet files, or whose use is checked in
+/// [`check_can_write`].
+const SUPPORTED_WRITER_FEATURES: &[&str] = &[
+    "appendOnly",
+    "changeDataFeed",
+    "checkConstraints",
+    "columnMapping",
+    "deletionVectors",
+    "domainMetadata",
+    "generatedColumns",
+    "identityColumns",
+    "invariants",
+    "timestampNtz",
+    "vacuumProtocolCheck",
+];
+
+/// Number of times an append is retried when another writer commits the same version first.
+const MAX_COMMIT_ATTEMPTS: usize = 10;
+
+/// How a write changes the data of a Delta table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DeltaWriteMode {
+    /// Add the written files to the table.
+    Append,
+    /// Replace the files of the table with the written files.
+    Overwrite,
+}
+
+impl DeltaWriteMode {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Append => "Append",
+            Self::Overwrite => "Overwrite",
+        }
+    }
+}
+
+/// Commits Parquet files that are written to a Delta table as a new version of the table.
+///
+/// The files are written to the table root with [`DeltaTableWriter::schema`] and named by
+/// [`DeltaTableWriter::data_file_name`]. The write metrics of the sink that wrote them are then
+/// passed to [`DeltaTableWriter::commit`]. The table is created by the first commit if its
+/// transaction log does not exist yet.
+#[derive(Debug)]
+pub struct DeltaTableWriter {
+    table_root: PlPath,
+    cloud_options: Option<CloudOptions>,
+    schema: SchemaRef,
+    mode: DeltaWriteMode,
+    write_id: String,
+}
+
+impl DeltaTableWriter {
+    /// Checks that data with `schema` can be written to the table at `table_root`.
+    pub fn try_new(
+        table_root: PlPathRef<'_>,
+        cloud_options: Option<CloudOptions>,
+        schema: &Schema,
+        mode: DeltaWriteMode,
+    ) -> PolarsResult<Self> {
+        let store = LogStore::try_new(table_root, cloud_options.as_ref())?;
+
+        let schema = match store.latest_version()? {
+            None => {
+                let schema = schema
+                    .iter()
+                    .map(|(name, dtype)| Field::new(name.clone(), to_storage_dtype(dtype)))
+                    .collect::<Schema>();
+                to_schema_string(&schema)?;
+                Arc::new(schema)
+            },
+            Some(_) => {
+                let snapshot = DeltaSnapshot::try_load(table_root, cloud_options.as_ref(), None)?;
+                check_column_names(&snapshot.schema, schema)?;
+                check_can_write(&snapshot, &snapshot.schema, mode)?;
+                snapshot.schema
+            },
+        };
+
+        Ok(Self {
+            table_root: table_root.into_owned(),
+            cloud_options,
+            schema,
+            mode,
+            write_id: uuid::Uuid::new_v4().to_string(),
+        })
+    }
+
+    /// The schema that the data files must be written with, which is the schema of the table if
+    /// it exists.
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Name of the `file_idx`-th data file, relative to the table root.
+    pub fn data_file_name(&self, file_idx: usize) -> String {
+        format!("part-{file_idx:05}-{}.parquet", self.write_id)
+    }
+
+    /// Commits the files in `write_metrics` as a new version of the table, returning the version.
+    ///
+    /// `write_metrics` has a row per file with the `path`, `num_rows` and `file_size` of the
+    /// file, and a `{col}_stats` struct column per column with the `null_count`, `lower_bound`
+    /// and `upper_bound` of the column in the file.
+    pub fn commit(&self, write_metrics: &DataFrame) -> PolarsResult<i64> {
+        let table_root = self.table_root.as_ref();
+        let store = LogStore::try_new(table_root, self.cloud_options.as_ref())?;
+        let now = timestamp_millis();
+        let adds = add_actions(table_root, &self.schema, write_metrics, now)?;
+
+        for _ in 0..MAX_COMMIT_ATTEMPTS {
+            let mut actions = vec![json!({
+                "commitInfo": {
+                    "timestamp": now,
+                    "operation": "WRITE",
+                    "operationParameters": {"mode": self.mode.as_str()},
+                    "isBlindAppend": self.mode == DeltaWriteMode::Append,
+                    "engineInfo": concat!("polars/", env!("CARGO_PKG_VERSION")),
+                }
+            })];
+
+            let version = match store.latest_version()? {
+                None => {
+                    actions.extend(create_table_actions(&self.schema, now)?);
+                    0
+                },
+                Some(_) => {
+                    let snapshot =
+                        DeltaSnapshot::try_load(table_root, self.cloud_options.as_ref(), None)?;
+                    check_can_write(&snapshot, &self.schema, self.mode)?;
+                    if self.mode == DeltaWriteMode::Overwrite {
+                        actions.extend(snapshot.files.iter().map(|file| remove_action(file, now)));
+                    }
+                    snapshot.version + 1
+                },
+            };
+            actions.extend(adds.iter().cloned());
+
+            let bytes = actions
+                .iter()
+                .map(Value::to_string)
+                .collect::<Vec<_>>()
+                .join("\n")
+                .into_bytes();
+            if store.put_if_absent(&format!("{version:020}.json"), bytes)? {
+                return Ok(version);
+            }
+
+            // Another writer committed this version first. Appends are retried on top of it, but
+            // an overwrite would remove files that it has not seen.
+            polars_ensure!(
+                self.mode == DeltaWriteMode::Append,
+                ComputeError: "could not overwrite the Delta table: version {} was committed by another writer",
+                version
+            );
+        }
+
+        polars_bail!(
+            ComputeError: "could not commit to the Delta table after {} attempts due to concurrent writers",
+            MAX_COMMIT_ATTEMPTS
+        )
+    }
+}
+
+fn timestamp_millis() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as i64)
+}
+
+/// Writer features of the protocol, which are implied by the version for versions before 7.
+fn writer_features(protocol: &Protocol) -> PolarsResult<Vec<&str>> {
+    let version = protocol.min_writer_version;
+    polars_ensure!(
+        version <= MAX_WRITER_VERSION,
+        ComputeError: "Delta table requires writer version {}, the highest supported version is {}",
+        version, MAX_WRITER_VERSION
+    );
+
+    if version == 7 {
+        return Ok(protocol
+            .writer_features
+            .iter()
+            .flatten()
+            .map(String::as_str)
+            .collect());
+    }
+
+    Ok([
+        (2, "appendOnly"),
+        (2, "invariants"),
+        (3, "checkConstraints"),
+        (4, "changeDataFeed"),
+        (4, "generatedColumns"),
+        (5, "columnMapping"),
+        (6, "identityColumns"),
+    ]
+    .into_iter()
+    .filter(|(min_version, _)| version >= *min_version)
+    .map(|(_, feature)| feature)
+    .collect())
+}
+
+fn check_can_write(
+    snapshot: &DeltaSnapshot,
+    schema: &Schema,
+    mode: DeltaWriteMode,
+) -> PolarsResult<()> {
+    polars_ensure!(
+        snapshot.partition_columns.is_empty(),
+        ComputeError: "writing to partitioned Delta tables is not supported"
+    );
+
+    if let Some(protocol) = &snapshot.protocol {
+        for feature in writer_features(protocol)? {
+            polars_ensure!(
+                SUPPORTED_WRITER_FEATURES.contains(&feature),
+                ComputeError: "Delta table writer feature '{}' is not supported", feature
+            );
+        }
+    }
+
+    let metadata = &snapshot.metadata;
+    let is_enabled =
+        |key: &str| matches!(metadata.configuration.get(key), Some(Some(v)) if v == "true");
+
+    if mode == DeltaWriteMode::Overwrite {
+        polars_ensure!(
+            !is_enabled("delta.appendOnly"),
+            ComputeError: "cannot overwrite an append-only Delta table"
+        );
+        polars_ensure!(
+            !is_enabled("delta.enableChangeDataFeed"),
+            ComputeError: "overwriting Delta tables with the change data feed enabled is not supported"
+        );
+    }
+    polars_ensure!(
+        !metadata
+            .configuration
+            .keys()
+            .any(|key| key.starts_with("delta.constraints.")),
+        ComputeError: "writing to Delta tables with CHECK constraints is not supported"
+    );
+    // These are stored in the metadata of the fields of the schema.
+    for (key, name) in [
+        ("delta.invariants", "column invariants"),
+        ("delta.generationExpression", "generated columns"),
+        ("delta.identity.", "identity columns"),
+    ] {
+        polars_ensure!(
+            !metadata.schema_string.contains(key),
+            ComputeError: "writing to Delta tables with {} is not supported", name
+        );
+    }
+
+    check_column_names(&snapshot.schema, schema)?;
+    for (name, dtype) in snapshot.schema.iter() {
+        let write_dtype = schema.get(name).unwrap();
+        polars_ensure!(
+            to_delta_type(write_dtype)? == to_delta_type(dtype)?,
+            SchemaMismatch: "column '{}' has type {}, but the Delta table has type {}",
+            name, write_dtype, dtype
+        );
+    }
+
+    Ok(())
+}
+
+fn check_column_names(table_schema: &Schema, schema: &Schema) -> PolarsResult<()> {
+    for name in table_schema.iter_names() {
+        polars_ensure!(
+            schema.contains(name),
+            SchemaMismatch: "column '{}' of the Delta table is missing", name
+        );
+    }
+    for name in schema.iter_names() {
+        polars_ensure!(
+            table_schema.contains(name),
+            SchemaMismatch: "column '{}' is not in the Delta table", name
+        );
+    }
+    Ok(())
+}
+
+/// The `protocol` and `metaData` actions of the first version of a table.
+fn create_table_actions(schema: &Schema, now: i64) -> PolarsResult<[Value; 2]> {
+    fn has_timestamp_ntz(dtype: &DataType) -> bool {
+        match dtype {
+            DataType::Datetime(_, None) => true,
+            DataType::List(inner) => has_timestamp_ntz(inner),
+            DataType::Struct(fields) => fields.iter().any(|field| has_timestamp_ntz(&field.dtype)),
+            _ => false,
+        }
+    }
+
+    let protocol = if schema.iter_values().any(has_timestamp_ntz) {
+        json!({
+            "minReaderVersion": 3,
+            "minWriterVersion": 7,
+            "readerFeatures": ["timestampNtz"],
+            "writerFeatures": ["timestampNtz"],
+        })
+    } else {
+        json!({"minReaderVersion": 1, "minWriterVersion": 2})
+    };
+
+    Ok([
+        json!({"protocol": protocol}),
+        json!({
+            "metaData": {
+                "id": uuid::Uuid::new_v4().to_string(),
+                "format": {"provider": "parquet", "options": {}},
+                "schemaString": to_schema_string(schema)?,
+                "partitionColumns": [],
+                "configuration": {},
+                "createdTime": now,
+            }
+        }),
+    ])
+}
+
+fn remove_action(file: &DeltaFile, now: i64) -> Value {
+    let mut remove = json!({
+        "path": file.log_path,
+        "deletionTimestamp": now,
+        "dataChange": true,
+        "extendedFileMetadata": true,
+        "partitionValues": {},
+        "size": file.size,
+    });
+    if let Some(deletion_vector) = &file.deletion_vector {
+        remove["deletionVector"] = json!(deletion_vector);
+    }
+    json!({"remove": remove})
+}
+
+fn add_actions(
+    table_root: PlPathRef<'_>,
+    schema: &Schema,
+    write_metrics: &DataFrame,
+    now: i64,
+) -> PolarsResult<Vec<Value>> {
+    let paths = write_metrics.column("path")?.str()?;
+    let num_rows = write_metrics.column("num_rows")?.u64()?;
+    let file_sizes = write_metrics.column("file_size")?.u64()?;
+
+    // Statistics are only written for top-level columns.
+    let column_stats = schema
+        .iter()
+        .filter(|(_, dtype)| !dtype.is_nested())
+        .map(|(name, _)| {
+            let stats = write_metrics.column(&format!("{name}_stats"))?;
+            Ok((name, stats.struct_()?.clone().unnest()))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let root = table_root.to_str().trim_end_matches('/');
+
+    (0..write_metrics.height())
+        .map(|i| {
+            let path = paths
+                .get(i)
+                .ok_or_else(|| polars_err!(ComputeError: "missing path of written file"))?;
+            let relative_path = path
+                .strip_prefix(root)
+                .and_then(|path| path.strip_prefix('/'))
+                .ok_or_else(|| {
+                    polars_err!(
+                        ComputeError: "written file '{}' is not in the Delta table directory '{}'",
+                        path, root
+                    )
+                })?;
+            let relative_path = relative_path
+                .split('/')
+                .map(|part| {
+                    percent_encoding::percent_encode(part.as_bytes(), URL_ENCODE_CHAR_SET)
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+                .join("/");
+
+            let mut min_values = serde_json::Map::new();
+            let mut max_values = serde_json::Map::new();
+            let mut null_count = serde_json::Map::new();
+            for (name, stats) in &column_stats {
+                let name = name.to_string();
+                if let Some(v) = stats_value(stats.column("lower_bound")?.get(i)?, false) {
+                    min_values.insert(name.clone(), v);
+                }
+                if let Some(v) = stats_value(stats.column("upper_bound")?.get(i)?, true) {
+                    max_values.insert(name.clone(), v);
+                }
+                null_count.insert(name, stats.column("null_count")?.u64()?.get(i).into());
+            }
+
+            let stats = json!({
+                "numRecords": num_rows.get(i),
+                "minValues": min_values,
+                "maxValues": max_values,
+                "nullCount": null_count,
+            });
+
+            Ok(json!({
+                "add": {
+                    "path": relative_path,
+                    "partitionValues": {},
+                    "size": file_sizes.get(i),
+                    "modificationTime": now,
+                    "dataChange": true,
+                    "stats": stats.to_string(),
+                }
+            }))
+        })
+        .collect()
+}
+
+/// Converts a lower or upper bound of a column to a value of the `minValues` or `maxValues` of
+/// the statistics of a file. Returns `None` if the bound is not stored.
+fn stats_value(value: AnyValue<'_>, is_upper_bound: bool) -> Option<Value> {
+    use arrow::temporal_conversions::{date32_to_date_opt, timestamp_ms_to_datetime_opt};
+
+    Some(match value {
+        AnyValue::Int8(v) => v.into(),
+        AnyValue::Int16(v) => v.into(),
+        AnyValue::Int32(v) => v.into(),
+        AnyValue::Int64(v) => v.into(),
+        AnyValue::Float32(v) => serde_json::Number::from_f64(v as f64)?.into(),
+        AnyValue::Float64(v) => serde_json::Number::from_f64(v)?.into(),
+        // A prefix of the minimum is still a lower bound, but the maximum cannot be truncated.
+        AnyValue::String(v) if is_upper_bound => {
+            if v.chars().count() >= TRUNCATED_STRING_STATS_LENGTH {
+                return None;
+            }
+            v.into()
+        },
+        AnyValue::String(v) => v
+            .chars()
+            .take(TRUNCATED_STRING_STATS_LENGTH)
+            .collect::<String>()
+            .into(),
+        AnyValue::Date(v) => date32_to_date_opt(v)?.format("%Y-%m-%d").to_string().into(),
+        // Timestamps are truncated to milliseconds, readers add a millisecond to the maximum.
+        AnyValue::Datetime(v, time_unit, time_zone) => {
+            let ms = match time_unit {
+                TimeUnit::Nanoseconds => v.div_euclid(1_000_000),
+                TimeUnit::Microseconds => v.div_euclid(1_000),
+                TimeUnit::Milliseconds => v,
+            };
+            let suffix = if time_zone.is_some() { "Z" } else { "" };
+            let datetime = timestamp_ms_to_datetime_opt(ms)?;
+            format!("{}{suffix}", datetime.format("%Y-%m-%dT%H:%M:%S%.3f")).into()
+        },
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_metrics(root: &str, files: &[(&str, i64, i64)]) -> DataFrame {
+        let n = files.len();
+        let stats = StructChunked::from_series(
+            PlSmallStr::from_static("a_stats"),
+            n,
+            [
+                Series::new(PlSmallStr::from_static("null_count"), vec![0u64; n]),
+                Series::new(PlSmallStr::from_static("nan_count"), vec![0u64; n]),
+                Series::new(
+                    PlSmallStr::from_static("lower_bound"),
+                    files.iter().map(|file| file.1).collect::<Vec<_>>(),
+                ),
+                Series::new(
+                    PlSmallStr::from_static("upper_bound"),
+                    files.iter().map(|file| file.2).collect::<Vec<_>>(),
+                ),
+            ]
+            .iter(),
+        )
+        .unwrap();
+
+        DataFrame::new(vec![
+            Column::new(
+                PlSmallStr::from_static("path"),
+                files
+                    .iter()
+                    .map(|file| format!("{root}/{}", file.0))
+                    .collect::<Vec<_>>(),
+            ),
+            Column::new(PlSmallStr::from_static("num_rows"), vec![2u64; n]),
+            Column::new(PlSmallStr::from_static("file_size"), vec![100u64; n]),
+            stats.into_column(),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_commit_delta_write() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = PlPath::new(dir.path().to_str().unwrap());
+        let root_str = root.to_str();
+        let schema = Schema::from_iter([Field::new(PlSmallStr::from_static("a"), DataType::Int64)]);
+        let file_names = |snapshot: &DeltaSnapshot| {
+            snapshot
+                .files
+                .iter()
+                .map(|file| file.log_path.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let writer =
+            DeltaTableWriter::try_new(root.as_ref(), None, &schema, DeltaWriteMode::Append)
+                .unwrap();
+        assert_eq!(
+            writer
+                .commit(&write_metrics(root_str, &[("0.parquet", 1, 2)]))
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            writer
+                .commit(&write_metrics(root_str, &[("1 a.parquet", 3, 4)]))
+                .unwrap(),
+            1
+        );
+
+        let snapshot = DeltaSnapshot::try_load(root.as_ref(), None, None).unwrap();
+        assert_eq!(snapshot.version, 1);
+        assert_eq!(*snapshot.schema, schema);
+        assert_eq!(file_names(&snapshot), ["0.parquet", "1%20a.parquet"]);
+        assert_eq!(snapshot.files[0].num_records(), Some(2));
+        let stats = snapshot.file_statistics().unwrap().unwrap();
+        assert_eq!(
+            stats.column("a_min").unwrap().i64().unwrap().to_vec(),
+            [Some(1), Some(3)]
+        );
+
+        let writer =
+            DeltaTableWriter::try_new(root.as_ref(), None, &schema, DeltaWriteMode::Overwrite)
+                .unwrap();
+        assert_eq!(
+            writer
+                .commit(&write_metrics(root_str, &[("2.parquet", 5, 5)]))
+                .unwrap(),
+            2
+        );
+        let snapshot = DeltaSnapshot::try_load(root.as_ref(), None, None).unwrap();
+        assert_eq!(file_names(&snapshot), ["2.parquet"]);
+
+        // Files outside of the table and columns that are not in the table cannot be committed.
+        assert!(
+            writer
+                .commit(&write_metrics("/elsewhere", &[("3.parquet", 0, 0)]))
+                .is_err()
+        );
+        let schema = Schema::from_iter([Field::new(PlSmallStr::from_static("b"), DataType::Int64)]);
+        assert!(
+            DeltaTableWriter::try_new(root.as_ref(), None, &schema, DeltaWriteMode::Append)
+                .is_err()
+        );
+    }
+}
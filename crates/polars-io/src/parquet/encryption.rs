@@ -0,0 +1,277 @@
+//! Options for reading and writing encrypted Parquet files.
+//!
+//! Keys are identified by a key id, which is stored in the file as the key metadata of the footer
+//! and of the encrypted columns. Readers retrieve the keys by these ids.
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use arrow::datatypes::ArrowSchema;
+use polars_error::{PolarsResult, polars_bail, polars_err};
+use polars_parquet::parquet::encryption::{
+    EncryptionKey, FileDecryptionProperties, FileEncryptionProperties, KeyRetriever,
+};
+use polars_parquet::parquet::error::{ParquetError, ParquetResult};
+use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "python")]
+use polars_utils::python_function::PythonObject;
+#[cfg(feature = "python")]
+use pyo3::PyObject;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Retrieves AES keys of 16 or 32 bytes by their key id.
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ParquetKeyRetriever {
+    /// Static mapping of key ids to keys. Never serialized, so that the keys don't end up in
+    /// serialized plans.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_static_keys"))]
+    Static(Vec<(PlSmallStr, Vec<u8>)>),
+    /// Rust function to retrieve the key of a key id.
+    #[cfg_attr(feature = "dsl-schema", schemars(skip))]
+    DynamicRust(RustKeyRetrieverFunction),
+    /// Python function to retrieve the key of a key id.
+    #[cfg(feature = "python")]
+    DynamicPython(python_impl::PythonKeyRetrieverFunction),
+}
+
+impl Debug for ParquetKeyRetriever {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            // Never print the keys themselves.
+            Self::Static(keys) => f
+                .debug_tuple("Static")
+                .field(&keys.iter().map(|(id, _)| id).collect::<Vec<_>>())
+                .finish(),
+            Self::DynamicRust(func) => f.debug_tuple("DynamicRust").field(func).finish(),
+            #[cfg(feature = "python")]
+            Self::DynamicPython(func) => f.debug_tuple("DynamicPython").field(func).finish(),
+        }
+    }
+}
+
+impl ParquetKeyRetriever {
+    /// Create a key retriever from a static mapping of key ids to keys.
+    pub fn from_static(keys: Vec<(PlSmallStr, Vec<u8>)>) -> Self {
+        Self::Static(keys)
+    }
+
+    /// Create a key retriever from a Rust function.
+    pub fn from_rust_function(
+        func: impl Fn(&str) -> PolarsResult<Vec<u8>> + Send + Sync + 'static,
+    ) -> Self {
+        Self::DynamicRust(RustKeyRetrieverFunction(Arc::new(func)))
+    }
+
+    /// Create a key retriever from a Python function.
+    #[cfg(feature = "python")]
+    pub fn from_py_function(py_object: PyObject) -> Self {
+        Self::DynamicPython(python_impl::PythonKeyRetrieverFunction(Arc::new(
+            PythonObject(py_object),
+        )))
+    }
+
+    /// Retrieve the key of `key_id`.
+    pub fn retrieve(&self, key_id: &str) -> PolarsResult<Vec<u8>> {
+        match self {
+            Self::Static(keys) => keys
+                .iter()
+                .find(|(id, _)| id == key_id)
+                .map(|(_, key)| key.clone())
+                .ok_or_else(|| polars_err!(ComputeError: "no key with id '{key_id}' was given")),
+            Self::DynamicRust(func) => func.0(key_id),
+            #[cfg(feature = "python")]
+            Self::DynamicPython(func) => func.call(key_id),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+fn serialize_static_keys<S>(
+    _keys: &[(PlSmallStr, Vec<u8>)],
+    _serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::ser::Error;
+    Err(S::Error::custom(
+        "cannot serialize static Parquet encryption keys, use a key retriever function instead",
+    ))
+}
+
+impl KeyRetriever for ParquetKeyRetriever {
+    fn retrieve_key(&self, key_metadata: &[u8]) -> ParquetResult<Vec<u8>> {
+        let key_id = std::str::from_utf8(key_metadata).map_err(|_| {
+            ParquetError::InvalidParameter("the key metadata is not a UTF-8 key id".to_string())
+        })?;
+        self.retrieve(key_id)
+            .map_err(|e| ParquetError::InvalidParameter(e.to_string()))
+    }
+}
+
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct RustKeyRetrieverFunction(Arc<dyn Fn(&str) -> PolarsResult<Vec<u8>> + Send + Sync>);
+
+impl Debug for RustKeyRetrieverFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "key retriever function at 0x{:016x}",
+            self.0.as_ref() as *const _ as *const () as usize
+        )
+    }
+}
+
+impl Eq for RustKeyRetrieverFunction {}
+
+impl PartialEq for RustKeyRetrieverFunction {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Hash for RustKeyRetrieverFunction {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(Arc::as_ptr(&self.0) as *const () as usize);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for RustKeyRetrieverFunction {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::Error;
+        Err(S::Error::custom(format!("cannot serialize {self:?}")))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for RustKeyRetrieverFunction {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+        Err(D::Error::custom(
+            "cannot deserialize RustKeyRetrieverFunction",
+        ))
+    }
+}
+
+/// How to encrypt a Parquet file, using the `AES_GCM_V1` algorithm.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct ParquetEncryptionOptions {
+    /// The id of the key that encrypts the footer, and every column if `column_key_ids` is empty.
+    pub footer_key_id: PlSmallStr,
+    /// The ids of the keys of the encrypted columns, by column name. The key of a column also
+    /// encrypts its nested fields. Other columns are written unencrypted.
+    pub column_key_ids: Vec<(PlSmallStr, PlSmallStr)>,
+    /// Retrieves the keys by their id.
+    pub keys: ParquetKeyRetriever,
+    /// Prepended to the additional authenticated data of every module, e.g. to bind the file to
+    /// its path.
+    pub aad_prefix: Option<Vec<u8>>,
+    /// Whether to store the `aad_prefix` in the file. If not, readers have to supply it.
+    pub store_aad_prefix: bool,
+}
+
+impl ParquetEncryptionOptions {
+    /// Build the encryption properties of a file with `schema`. Errors if a column key id is
+    /// given for a column that isn't in `schema`.
+    pub fn to_properties(&self, schema: &ArrowSchema) -> PolarsResult<FileEncryptionProperties> {
+        for (column, _) in &self.column_key_ids {
+            if !schema.contains(column) {
+                polars_bail!(ColumnNotFound: "cannot encrypt column '{column}', which is not in the schema");
+            }
+        }
+
+        let key = |key_id: &PlSmallStr| {
+            PolarsResult::Ok(EncryptionKey {
+                key: self.keys.retrieve(key_id)?,
+                key_metadata: Some(key_id.as_bytes().to_vec()),
+            })
+        };
+
+        Ok(FileEncryptionProperties {
+            footer_key: key(&self.footer_key_id)?,
+            column_keys: self
+                .column_key_ids
+                .iter()
+                .map(|(column, key_id)| Ok((vec![column.to_string()], key(key_id)?)))
+                .collect::<PolarsResult<_>>()?,
+            aad_prefix: self.aad_prefix.clone(),
+            store_aad_prefix: self.store_aad_prefix,
+        })
+    }
+}
+
+/// How to decrypt Parquet files.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct ParquetDecryptionOptions {
+    /// Retrieves the keys by the id stored in the files.
+    pub keys: ParquetKeyRetriever,
+    /// Must be given for files that were written without storing their AAD prefix.
+    pub aad_prefix: Option<Vec<u8>>,
+}
+
+impl ParquetDecryptionOptions {
+    pub fn to_properties(&self) -> FileDecryptionProperties {
+        FileDecryptionProperties {
+            key_retriever: Arc::new(self.keys.clone()),
+            aad_prefix: self.aad_prefix.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+mod python_impl {
+    use std::hash::Hash;
+    use std::sync::Arc;
+
+    use polars_error::{PolarsResult, to_compute_err};
+    use polars_utils::python_function::PythonObject;
+    use pyo3::types::PyAnyMethods;
+    use pyo3::{PyResult, Python};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+    pub struct PythonKeyRetrieverFunction(
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                serialize_with = "PythonObject::serialize_with_pyversion",
+                deserialize_with = "PythonObject::deserialize_with_pyversion"
+            )
+        )]
+        #[cfg_attr(feature = "dsl-schema", schemars(with = "Vec<u8>"))]
+        pub Arc<polars_utils::python_function::PythonFunction>,
+    );
+
+    impl PythonKeyRetrieverFunction {
+        pub fn call(&self, key_id: &str) -> PolarsResult<Vec<u8>> {
+            Python::with_gil(|py| {
+                let key: Vec<u8> = self.0.call1(py, (key_id,))?.into_bound(py).extract()?;
+                PyResult::Ok(key)
+            })
+            .map_err(to_compute_err)
+        }
+    }
+
+    impl Hash for PythonKeyRetrieverFunction {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            state.write_usize(Arc::as_ptr(&self.0) as *const () as usize);
+        }
+    }
+}
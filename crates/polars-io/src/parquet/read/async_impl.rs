@@ -8,6 +8,7 @@ use polars_parquet::write::FileMetadata;
 use crate::cloud::{
     CloudLocation, CloudOptions, PolarsObjectStore, build_object_store, object_path_from_str,
 };
+use crate::parquet::encryption::ParquetDecryptionOptions;
 use crate::parquet::metadata::FileMetadataRef;
 
 pub struct ParquetObjectStore {
@@ -16,6 +17,7 @@ pub struct ParquetObjectStore {
     length: Option<usize>,
     metadata: Option<FileMetadataRef>,
     schema: Option<ArrowSchemaRef>,
+    decryption: Option<ParquetDecryptionOptions>,
 }
 
 impl ParquetObjectStore {
@@ -33,9 +35,16 @@ impl ParquetObjectStore {
             length: None,
             metadata,
             schema: None,
+            decryption: None,
         })
     }
 
+    /// Decrypt the file if it is encrypted.
+    pub fn with_decryption(mut self, decryption: Option<ParquetDecryptionOptions>) -> Self {
+        self.decryption = decryption;
+        self
+    }
+
     /// Initialize the length property of the object, unless it has already been fetched.
     async fn length(&mut self) -> PolarsResult<usize> {
         if self.length.is_none() {
@@ -53,7 +62,7 @@ impl ParquetObjectStore {
     /// Fetch the metadata of the parquet file, do not memoize it.
     async fn fetch_metadata(&mut self) -> PolarsResult<FileMetadata> {
         let length = self.length().await?;
-        fetch_metadata(&self.store, &self.path, length).await
+        fetch_metadata(&self.store, &self.path, length, self.decryption.as_ref()).await
     }
 
     /// Fetch and memoize the metadata of the parquet file.
@@ -97,6 +106,7 @@ pub async fn fetch_metadata(
     store: &PolarsObjectStore,
     path: &ObjectPath,
     file_byte_length: usize,
+    decryption: Option<&ParquetDecryptionOptions>,
 ) -> PolarsResult<FileMetadata> {
     let footer_header_bytes = store
         .get_range(
@@ -114,9 +124,9 @@ pub async fn fetch_metadata(
     let footer_byte_length: usize = {
         let reader = &mut footer_header_bytes.as_ref();
         let footer_byte_size = read_i32le(reader).unwrap();
-        let magic = read_n(reader).unwrap();
+        let magic: [u8; 4] = read_n(reader).unwrap();
         debug_assert!(reader.is_empty());
-        if magic != polars_parquet::parquet::PARQUET_MAGIC {
+        if !polars_parquet::parquet::is_parquet_magic(&magic) {
             return Err(polars_parquet::parquet::error::ParquetError::OutOfSpec(
                 "incorrect magic in parquet footer".to_string(),
             )
@@ -142,11 +152,15 @@ pub async fn fetch_metadata(
         )
         .await?;
 
-    Ok(polars_parquet::parquet::read::deserialize_metadata(
-        std::io::Cursor::new(footer_bytes.as_ref()),
-        // TODO: Describe why this makes sense. Taken from the previous
-        // implementation which said "a highly nested but sparse struct could
-        // result in many allocations".
-        footer_bytes.as_ref().len() * 2 + 1024,
-    )?)
+    let decryption = decryption.map(|d| d.to_properties());
+    Ok(
+        polars_parquet::parquet::read::deserialize_metadata_with_decryption(
+            footer_bytes.as_ref(),
+            // TODO: Describe why this makes sense. Taken from the previous
+            // implementation which said "a highly nested but sparse struct could
+            // result in many allocations".
+            footer_bytes.as_ref().len() * 2 + 1024,
+            decryption.as_ref(),
+        )?,
+    )
 }
@@ -2,6 +2,7 @@ use arrow::array::Array;
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::Field;
 use polars_error::PolarsResult;
+use polars_parquet::parquet::read::PageMetaData;
 use polars_parquet::read::{
     BasicDecompressor, ColumnChunkMetadata, Filter, PageReader, column_iter_to_arrays,
 };
@@ -70,3 +71,24 @@ pub fn to_deserializer(
 
     column_iter_to_arrays(columns, types, field, filter)
 }
+
+/// Like [`to_deserializer`] for a non-nested column of which only some pages were read, e.g.
+/// because the page index showed the others aren't needed. `pages` holds the dictionary page, if
+/// any, followed by the data pages, which together contain `num_values` values.
+pub fn pages_to_deserializer(
+    column_meta: &ColumnChunkMetadata,
+    pages: MemSlice,
+    num_values: usize,
+    field: Field,
+    filter: Option<Filter>,
+) -> PolarsResult<(Box<dyn Array>, Bitmap)> {
+    let mut page_meta = PageMetaData::from(column_meta);
+    page_meta.num_values = num_values as i64;
+
+    let pages =
+        PageReader::new_with_page_meta(MemReader::new(pages), page_meta, vec![], usize::MAX);
+    let columns = vec![BasicDecompressor::new(pages, vec![])];
+    let types = vec![&column_meta.descriptor().descriptor.primitive_type];
+
+    column_iter_to_arrays(columns, types, field, filter)
+}
@@ -35,12 +35,13 @@ pub use options::{ParallelStrategy, ParquetOptions};
 use polars_error::{ErrString, PolarsError};
 pub use polars_parquet::arrow::read::infer_schema;
 pub use polars_parquet::read::FileMetadata;
-pub use read_impl::{create_sorting_map, try_set_sorted_flag};
+pub(crate) use read_impl::should_copy_sortedness;
+pub use read_impl::{create_sorting_map, file_sorting_columns, try_set_sorted_flag};
 pub use reader::ParquetReader;
 pub use utils::materialize_empty_df;
 
 pub mod _internal {
-    pub use super::mmap::to_deserializer;
+    pub use super::mmap::{pages_to_deserializer, to_deserializer};
     pub use super::read_impl::{PrefilterMaskSetting, calc_prefilter_cost};
     pub use super::utils::ensure_matching_dtypes_if_found;
 }
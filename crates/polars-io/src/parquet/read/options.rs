@@ -2,6 +2,8 @@ use polars_core::schema::SchemaRef;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::parquet::encryption::ParquetDecryptionOptions;
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -10,6 +12,8 @@ pub struct ParquetOptions {
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// Decrypt encrypted files.
+    pub decryption: Option<ParquetDecryptionOptions>,
 }
 
 impl Default for ParquetOptions {
@@ -19,6 +23,7 @@ impl Default for ParquetOptions {
             parallel: ParallelStrategy::default(),
             low_memory: false,
             use_statistics: true,
+            decryption: None,
         }
     }
 }
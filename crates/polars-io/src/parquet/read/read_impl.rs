@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use arrow::array::Array;
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::chunked_array::builder::NullChunkedBuilder;
@@ -18,6 +19,7 @@ use crate::hive::materialize_hive_partitions;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::parquet::metadata::FileMetadataRef;
 use crate::parquet::read::ROW_COUNT_OVERFLOW_ERR;
+use crate::parquet::write::ParquetSortingColumn;
 use crate::utils::slice::split_slice_at_file;
 
 #[cfg(debug_assertions)]
@@ -50,13 +52,27 @@ fn assert_dtypes(dtype: &ArrowDataType) {
     }
 }
 
-fn should_copy_sortedness(dtype: &DataType) -> bool {
-    // @NOTE: For now, we are a bit conservative with this.
+/// Whether Parquet orders values of `dtype` the same as Polars does.
+pub(crate) fn should_copy_sortedness(dtype: &DataType) -> bool {
+    // @NOTE: For now, we are a bit conservative with this. Floats are left out because of NaNs.
     use DataType as D;
 
     matches!(
         dtype,
-        D::Int8 | D::Int16 | D::Int32 | D::Int64 | D::UInt8 | D::UInt16 | D::UInt32 | D::UInt64
+        D::Int8
+            | D::Int16
+            | D::Int32
+            | D::Int64
+            | D::UInt8
+            | D::UInt16
+            | D::UInt32
+            | D::UInt64
+            | D::Date
+            | D::Datetime(_, _)
+            | D::Duration(_)
+            | D::Time
+            | D::String
+            | D::Binary
     )
 }
 
@@ -97,6 +113,108 @@ pub fn create_sorting_map(md: &RowGroupMetadata) -> Vec<(usize, IsSorted)> {
     sorting_map
 }
 
+/// The columns by which all rows of the file are (lexicographically) sorted, according to the
+/// `sorting_columns` of its row groups.
+///
+/// Row groups are only sorted on their own, so with multiple row groups the statistics of the
+/// leading column must show that the row groups follow each other in order. Columns after the
+/// leading one are only used if the leading column does not repeat values across row groups.
+pub fn file_sorting_columns(
+    md: &FileMetadata,
+    schema: &ArrowSchema,
+) -> PolarsResult<Vec<ParquetSortingColumn>> {
+    let Some((first, rest)) = md.row_groups.split_first() else {
+        return Ok(Vec::new());
+    };
+
+    let mut common = first.sorting_columns().unwrap_or_default();
+    for rg in rest {
+        let sorting_columns = rg.sorting_columns().unwrap_or_default();
+        let len = common
+            .iter()
+            .zip(sorting_columns)
+            .take_while(|(l, r)| l == r)
+            .count();
+        common = &common[..len];
+    }
+
+    let leaves = md.schema().columns();
+    let mut sorting_columns = Vec::with_capacity(common.len());
+    let mut leaf_idxs = Vec::with_capacity(common.len());
+    for sorting in common {
+        let Some(leaf) = leaves.get(sorting.column_idx as usize) else {
+            break;
+        };
+        let [name] = leaf.path_in_schema.as_slice() else {
+            break;
+        };
+        match schema.get(name) {
+            Some(field) if should_copy_sortedness(&DataType::from_arrow_field(field)) => {},
+            _ => break,
+        }
+
+        leaf_idxs.push(sorting.column_idx as usize);
+        sorting_columns.push(ParquetSortingColumn {
+            name: name.clone(),
+            descending: sorting.descending,
+            nulls_last: !sorting.nulls_first,
+        });
+    }
+
+    if sorting_columns.is_empty() || rest.is_empty() {
+        return Ok(sorting_columns);
+    }
+
+    // Check that the row groups are in order of the leading column. We don't use the statistics
+    // of encrypted columns we can't decrypt.
+    if md
+        .row_groups
+        .iter()
+        .any(|rg| rg.parquet_columns()[leaf_idxs[0]].decrypt().is_err())
+    {
+        return Ok(Vec::new());
+    }
+    let field = schema.get(&sorting_columns[0].name).unwrap();
+    let Some(statistics) = read::statistics::deserialize_all(field, &md.row_groups, leaf_idxs[0])?
+    else {
+        return Ok(Vec::new());
+    };
+    if statistics.null_count.null_count() > 0
+        || statistics.null_count.values().iter().any(|n| *n > 0)
+    {
+        return Ok(Vec::new());
+    }
+
+    let to_series = |arr| unsafe {
+        Series::_try_from_arrow_unchecked_with_md(
+            PlSmallStr::EMPTY,
+            vec![arr],
+            field.dtype(),
+            field.metadata.as_deref(),
+        )
+    };
+    let min = to_series(statistics.min_value)?;
+    let max = to_series(statistics.max_value)?;
+    if min.has_nulls() || max.has_nulls() {
+        return Ok(Vec::new());
+    }
+
+    let n = rest.len();
+    let (lower, upper) = if sorting_columns[0].descending {
+        (max.slice(1, n), min.slice(0, n))
+    } else {
+        (max.slice(0, n), min.slice(1, n))
+    };
+    if !lower.lt_eq(&upper)?.all() {
+        return Ok(Vec::new());
+    }
+    if !lower.lt(&upper)?.all() {
+        sorting_columns.truncate(1);
+    }
+
+    Ok(sorting_columns)
+}
+
 fn column_idx_to_series(
     column_i: usize,
     // The metadata belonging to this column
@@ -235,7 +353,9 @@ fn rg_to_dfs_optionally_par_over_columns(
                 store,
             )?;
 
-            try_set_sorted_flag(&mut series, *column_i, &sorting_map);
+            if let Some(&[leaf_idx]) = md.columns_idxs_under_root_iter(name) {
+                try_set_sorted_flag(&mut series, leaf_idx, &sorting_map);
+            }
             Ok(series.into_column())
         };
 
@@ -376,7 +496,9 @@ fn rg_to_dfs_par_over_rg(
                             store,
                         )?;
 
-                        try_set_sorted_flag(&mut series, *column_i, &sorting_map);
+                        if let Some(&[leaf_idx]) = md.columns_idxs_under_root_iter(name) {
+                            try_set_sorted_flag(&mut series, leaf_idx, &sorting_map);
+                        }
                         Ok(series.into_column())
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
@@ -431,6 +553,14 @@ pub fn read_parquet<R: MmapBytesReader>(
         .map(Cow::Borrowed)
         .unwrap_or_else(|| Cow::Owned((0usize..reader_schema.len()).collect::<Vec<_>>()));
 
+    // Only the encrypted columns we read are decrypted.
+    for rg in &file_metadata.row_groups {
+        for &i in materialized_projection.iter() {
+            let (name, _) = reader_schema.get_at_index(i).unwrap();
+            rg.decrypt_columns_under_root(name)?;
+        }
+    }
+
     if ParallelStrategy::Auto == parallel {
         if n_row_groups > materialized_projection.len() || n_row_groups > POOL.current_num_threads()
         {
@@ -9,6 +9,7 @@ use super::read_impl::read_parquet;
 use super::utils::{ensure_matching_dtypes_if_found, projected_arrow_schema_to_projection_indices};
 use crate::RowIndex;
 use crate::mmap::MmapBytesReader;
+use crate::parquet::encryption::ParquetDecryptionOptions;
 use crate::parquet::metadata::FileMetadataRef;
 use crate::prelude::*;
 
@@ -27,6 +28,7 @@ pub struct ParquetReader<R: Read + Seek> {
     metadata: Option<FileMetadataRef>,
     hive_partition_columns: Option<Vec<Series>>,
     include_file_path: Option<(PlSmallStr, Arc<str>)>,
+    decryption: Option<ParquetDecryptionOptions>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -163,13 +165,23 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Decrypt the file if it is encrypted.
+    pub fn with_decryption(mut self, decryption: Option<ParquetDecryptionOptions>) -> Self {
+        self.decryption = decryption;
+        self
+    }
+
     pub fn set_metadata(&mut self, metadata: FileMetadataRef) {
         self.metadata = Some(metadata);
     }
 
     pub fn get_metadata(&mut self) -> PolarsResult<&FileMetadataRef> {
         if self.metadata.is_none() {
-            self.metadata = Some(Arc::new(read::read_metadata(&mut self.reader)?));
+            let decryption = self.decryption.as_ref().map(|d| d.to_properties());
+            self.metadata = Some(Arc::new(read::read_metadata_with_decryption(
+                &mut self.reader,
+                decryption.as_ref(),
+            )?));
         }
         Ok(self.metadata.as_ref().unwrap())
     }
@@ -191,6 +203,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             schema: None,
             hive_partition_columns: None,
             include_file_path: None,
+            decryption: None,
         }
     }
 
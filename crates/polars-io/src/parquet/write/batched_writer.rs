@@ -6,9 +6,10 @@ use polars_core::POOL;
 use polars_core::prelude::*;
 use polars_parquet::read::{ParquetError, fallible_streaming_iterator};
 use polars_parquet::write::{
-    ColumnWriteOptions, CompressedPage, Compressor, DynIter, DynStreamingIterator,
-    FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
-    SchemaDescriptor, WriteOptions, array_to_columns, schema_to_metadata_key,
+    ColumnWriteOptions, CompressedPage, CompressionOptions, Compressor, DynIter,
+    DynStreamingIterator, FallibleStreamingIterator, FileWriter, Page, ParquetType,
+    RowGroupIterColumns, SchemaDescriptor, WriteOptions, array_to_bloom_filters, array_to_columns,
+    schema_to_metadata_key,
 };
 use rayon::prelude::*;
 
@@ -81,7 +82,8 @@ impl<W: Write> BatchedWriter<W> {
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
         for group in row_group_iter {
-            writer.write(group?)?;
+            let (group, bloom_filters) = group?;
+            writer.write_with_bloom_filters(group, bloom_filters)?;
         }
         Ok(())
     }
@@ -91,14 +93,20 @@ impl<W: Write> BatchedWriter<W> {
         writer.parquet_schema()
     }
 
-    pub fn write_row_group(&mut self, rg: &[Vec<CompressedPage>]) -> PolarsResult<()> {
+    /// Write a row group of compressed pages, along with the bloom filters of its Parquet
+    /// columns.
+    pub fn write_row_group(
+        &mut self,
+        rg: &[Vec<CompressedPage>],
+        bloom_filters: Vec<Option<Vec<u8>>>,
+    ) -> PolarsResult<()> {
         let writer = self.writer.get_mut().unwrap();
         let rg = DynIter::new(rg.iter().map(|col_pages| {
             Ok(DynStreamingIterator::new(
                 fallible_streaming_iterator::convert(col_pages.iter().map(PolarsResult::Ok)),
             ))
         }));
-        writer.write(rg)?;
+        writer.write_with_bloom_filters(rg, bloom_filters)?;
         Ok(())
     }
 
@@ -150,7 +158,8 @@ fn prepare_rg_iter<'a>(
     column_options: &'a [ColumnWriteOptions],
     options: WriteOptions,
     parallel: bool,
-) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+) -> impl Iterator<Item = PolarsResult<(RowGroupIterColumns<'static, PolarsError>, BloomFilters)>> + 'a
+{
     let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
@@ -170,11 +179,12 @@ fn prepare_rg_iter<'a>(
 
 fn pages_iter_to_compressor(
     encoded_columns: Vec<DynIter<'static, PolarsResult<Page>>>,
-    options: WriteOptions,
+    compressions: Vec<CompressionOptions>,
 ) -> Vec<PolarsResult<DynStreamingIterator<'static, CompressedPage, PolarsError>>> {
     encoded_columns
         .into_iter()
-        .map(|encoded_pages| {
+        .zip(compressions)
+        .map(|(encoded_pages, compression)| {
             // iterator over pages
             let pages = DynStreamingIterator::new(
                 Compressor::new_from_vec(
@@ -183,7 +193,7 @@ fn pages_iter_to_compressor(
                             ParquetError::FeatureNotSupported(format!("reraised in polars: {e}",))
                         })
                     }),
-                    options.compression,
+                    compression,
                     vec![],
                 )
                 .map_err(PolarsError::from),
@@ -201,16 +211,20 @@ fn array_to_pages_iter(
     options: WriteOptions,
 ) -> Vec<PolarsResult<DynStreamingIterator<'static, CompressedPage, PolarsError>>> {
     let encoded_columns = array_to_columns(array, type_.clone(), column_options, options).unwrap();
-    pages_iter_to_compressor(encoded_columns, options)
+    let compressions = column_options.leaf_compressions(options.compression);
+    pages_iter_to_compressor(encoded_columns, compressions)
 }
 
+/// The bloom filter bitsets of the Parquet columns of a row group.
+type BloomFilters = Vec<Option<Vec<u8>>>;
+
 fn create_serializer(
     batch: RecordBatch,
     fields: &[ParquetType],
     column_options: &[ColumnWriteOptions],
     options: WriteOptions,
     parallel: bool,
-) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
+) -> PolarsResult<(RowGroupIterColumns<'static, PolarsError>, BloomFilters)> {
     let func = move |((array, type_), column_options): (
         (&ArrayRef, &ParquetType),
         &ColumnWriteOptions,
@@ -236,9 +250,19 @@ fn create_serializer(
             .collect::<Vec<_>>()
     };
 
+    let bloom_filters = batch
+        .columns()
+        .iter()
+        .zip(fields)
+        .zip(column_options)
+        .flat_map(|((array, type_), column_options)| {
+            array_to_bloom_filters(array.as_ref(), type_.clone(), column_options)
+        })
+        .collect();
+
     let row_group = DynIter::new(columns.into_iter());
 
-    Ok(row_group)
+    Ok((row_group, bloom_filters))
 }
 
 /// This serializer encodes and compresses all eagerly in memory.
@@ -8,8 +8,9 @@ mod writer;
 pub use batched_writer::BatchedWriter;
 pub use key_value_metadata::{KeyValueMetadata, ParquetMetadataContext};
 pub use options::{
-    BrotliLevel, ChildFieldOverwrites, GzipLevel, MetadataKeyValue, ParquetCompression,
-    ParquetFieldOverwrites, ParquetWriteOptions, ZstdLevel,
+    BrotliLevel, ChildFieldOverwrites, GzipLevel, MetadataKeyValue, ParquetBloomFilterOptions,
+    ParquetCompression, ParquetEncoding, ParquetFieldOverwrites, ParquetSortingColumn,
+    ParquetWriteOptions, ZstdLevel,
 };
 pub use polars_parquet::write::{RowGroupIterColumns, StatisticsOptions};
-pub use writer::{ParquetWriter, get_column_write_options};
+pub use writer::{ParquetWriter, get_column_write_options, get_sorting_columns};
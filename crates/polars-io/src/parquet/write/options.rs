@@ -1,13 +1,17 @@
-use polars_error::PolarsResult;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use polars_error::{PolarsResult, polars_ensure};
 use polars_parquet::write::{
-    BrotliLevel as BrotliLevelParquet, CompressionOptions, GzipLevel as GzipLevelParquet,
-    StatisticsOptions, ZstdLevel as ZstdLevelParquet,
+    BloomFilterOptions, BrotliLevel as BrotliLevelParquet, CompressionOptions, Encoding,
+    GzipLevel as GzipLevelParquet, StatisticsOptions, ZstdLevel as ZstdLevelParquet,
 };
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use super::KeyValueMetadata;
+use crate::parquet::encryption::ParquetEncryptionOptions;
 
 #[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -26,6 +30,24 @@ pub struct ParquetWriteOptions {
 
     /// Per-field overwrites for writing properties.
     pub field_overwrites: Vec<ParquetFieldOverwrites>,
+
+    /// Encrypt the footer and (some of) the columns.
+    pub encryption: Option<Arc<ParquetEncryptionOptions>>,
+
+    /// The columns by which the rows of every row group are sorted, recorded as the
+    /// `sorting_columns` of the row groups. Only the leading columns whose sort order is the same
+    /// in Parquet and Polars are recorded.
+    pub sorting_columns: Vec<ParquetSortingColumn>,
+}
+
+/// A column by which the rows of a Parquet file are (lexicographically) sorted.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct ParquetSortingColumn {
+    pub name: PlSmallStr,
+    pub descending: bool,
+    pub nulls_last: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -57,6 +79,90 @@ pub struct ParquetFieldOverwrites {
     pub required: Option<bool>,
     pub field_id: Option<i32>,
     pub metadata: Option<Vec<MetadataKeyValue>>,
+    /// Write a bloom filter for every column chunk of this (leaf) field.
+    pub bloom_filter: Option<ParquetBloomFilterOptions>,
+
+    /// The encoding of the values of this (non-nested) field. If set, dictionary encoding is
+    /// disabled unless `dictionary` is `Some(true)`, in which case this is the encoding used when
+    /// the values don't fit a dictionary.
+    pub encoding: Option<ParquetEncoding>,
+    /// Whether to try to dictionary-encode the values of this (non-nested) field.
+    pub dictionary: Option<bool>,
+    /// Data page compression of this field. Applies to all leaves of a nested field.
+    pub compression: Option<ParquetCompression>,
+    /// Column statistics of this field. Applies to all leaves of a nested field.
+    pub statistics: Option<StatisticsOptions>,
+}
+
+/// The encodings a Parquet field can be written with, besides dictionary encoding.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ParquetEncoding {
+    Plain,
+    /// Integer and temporal types.
+    DeltaBinaryPacked,
+    /// String and binary types.
+    DeltaLengthByteArray,
+    /// String and binary types.
+    DeltaByteArray,
+    /// Numeric and temporal types.
+    ByteStreamSplit,
+}
+
+impl From<ParquetEncoding> for Encoding {
+    fn from(value: ParquetEncoding) -> Self {
+        match value {
+            ParquetEncoding::Plain => Encoding::Plain,
+            ParquetEncoding::DeltaBinaryPacked => Encoding::DeltaBinaryPacked,
+            ParquetEncoding::DeltaLengthByteArray => Encoding::DeltaLengthByteArray,
+            ParquetEncoding::DeltaByteArray => Encoding::DeltaByteArray,
+            ParquetEncoding::ByteStreamSplit => Encoding::ByteStreamSplit,
+        }
+    }
+}
+
+/// The sizing of the split-block bloom filters written for a Parquet field.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct ParquetBloomFilterOptions {
+    /// The number of distinct values to size the filters for. If `None`, each filter is sized for
+    /// the distinct values in its column chunk.
+    pub ndv: Option<u64>,
+    /// The false positive probability. If `None` will be 0.05.
+    pub fpp: Option<f64>,
+}
+
+impl Eq for ParquetBloomFilterOptions {}
+
+impl Hash for ParquetBloomFilterOptions {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ndv.hash(state);
+        self.fpp.map(f64::to_bits).hash(state);
+    }
+}
+
+impl ParquetBloomFilterOptions {
+    pub fn try_new(ndv: Option<u64>, fpp: Option<f64>) -> PolarsResult<Self> {
+        if let Some(fpp) = fpp {
+            polars_ensure!(
+                fpp > 0.0 && fpp < 1.0,
+                InvalidOperation: "bloom filter false positive probability must be between 0 and 1, got {fpp}"
+            );
+        }
+        Ok(Self { ndv, fpp })
+    }
+}
+
+impl From<ParquetBloomFilterOptions> for BloomFilterOptions {
+    fn from(value: ParquetBloomFilterOptions) -> Self {
+        let default = BloomFilterOptions::default();
+        Self {
+            ndv: value.ndv,
+            fpp: value.fpp.unwrap_or(default.fpp),
+        }
+    }
 }
 
 /// The compression strategy to use for writing Parquet files.
@@ -6,13 +6,18 @@ use polars_core::frame::chunk_df_for_writing;
 use polars_core::prelude::*;
 use polars_parquet::write::{
     ChildWriteOptions, ColumnWriteOptions, CompressionOptions, Encoding, FieldWriteOptions,
-    FileWriter, KeyValue, ListLikeFieldWriteOptions, StatisticsOptions, StructFieldWriteOptions,
-    Version, WriteOptions, to_parquet_schema,
+    FileWriter, KeyValue, ListLikeFieldWriteOptions, SchemaDescriptor, SortingColumn,
+    StatisticsOptions, StructFieldWriteOptions, Version, WriteOptions, to_parquet_schema,
 };
 
 use super::batched_writer::BatchedWriter;
-use super::options::ParquetCompression;
-use super::{KeyValueMetadata, MetadataKeyValue, ParquetFieldOverwrites, ParquetWriteOptions};
+use super::options::{ParquetCompression, ParquetEncoding};
+use super::{
+    KeyValueMetadata, MetadataKeyValue, ParquetFieldOverwrites, ParquetSortingColumn,
+    ParquetWriteOptions,
+};
+use crate::parquet::encryption::ParquetEncryptionOptions;
+use crate::parquet::read::should_copy_sortedness;
 use crate::prelude::ChildFieldOverwrites;
 use crate::shared::schema_to_arrow_checked;
 
@@ -27,6 +32,9 @@ impl ParquetWriteOptions {
             .with_row_group_size(self.row_group_size)
             .with_data_page_size(self.data_page_size)
             .with_key_value_metadata(self.key_value_metadata.clone())
+            .with_field_overwrites(self.field_overwrites.clone())
+            .with_encryption(self.encryption.as_deref().cloned())
+            .with_sorting_columns(self.sorting_columns.clone())
     }
 }
 
@@ -49,6 +57,10 @@ pub struct ParquetWriter<W> {
     key_value_metadata: Option<KeyValueMetadata>,
     /// Context info for the Parquet file being written.
     context_info: Option<PlHashMap<String, String>>,
+    /// Encrypt the footer and (some of) the columns.
+    encryption: Option<ParquetEncryptionOptions>,
+    /// The columns by which the rows of every row group are sorted.
+    sorting_columns: Vec<ParquetSortingColumn>,
 }
 
 impl<W> ParquetWriter<W>
@@ -70,6 +82,8 @@ where
             field_overwrites: Vec::new(),
             key_value_metadata: None,
             context_info: None,
+            encryption: None,
+            sorting_columns: Vec::new(),
         }
     }
 
@@ -113,23 +127,47 @@ where
         self
     }
 
+    /// Set per-field overwrites for writing properties, e.g. bloom filters.
+    pub fn with_field_overwrites(mut self, field_overwrites: Vec<ParquetFieldOverwrites>) -> Self {
+        self.field_overwrites = field_overwrites;
+        self
+    }
+
     /// Set context information for the writer
     pub fn with_context_info(mut self, context_info: Option<PlHashMap<String, String>>) -> Self {
         self.context_info = context_info;
         self
     }
 
+    /// Encrypt the footer and (some of) the columns of the Parquet file.
+    pub fn with_encryption(mut self, encryption: Option<ParquetEncryptionOptions>) -> Self {
+        self.encryption = encryption;
+        self
+    }
+
+    /// Record that the rows of every row group are sorted by `sorting_columns`.
+    pub fn with_sorting_columns(mut self, sorting_columns: Vec<ParquetSortingColumn>) -> Self {
+        self.sorting_columns = sorting_columns;
+        self
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
         let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
-        let column_options = get_column_write_options(&schema, &self.field_overwrites);
+        let column_options = get_column_write_options(&schema, &self.field_overwrites)?;
         let parquet_schema = to_parquet_schema(&schema, &column_options)?;
         let options = self.materialize_options();
-        let writer = Mutex::new(FileWriter::try_new(
-            self.writer,
-            schema,
-            options,
-            &column_options,
-        )?);
+        let sorting_columns = get_sorting_columns(&schema, &parquet_schema, &self.sorting_columns);
+        let encryption = self
+            .encryption
+            .as_ref()
+            .map(|e| e.to_properties(&schema))
+            .transpose()?;
+        let mut writer = FileWriter::try_new(self.writer, schema, options, &column_options)?
+            .with_sorting_columns(sorting_columns);
+        if let Some(encryption) = encryption {
+            writer = writer.with_encryption(encryption)?;
+        }
+        let writer = Mutex::new(writer);
 
         Ok(BatchedWriter {
             writer,
@@ -177,30 +215,52 @@ fn convert_metadata(md: &Option<Vec<MetadataKeyValue>>) -> Vec<KeyValue> {
 fn to_column_write_options_rec(
     field: &ArrowField,
     overwrites: Option<&ParquetFieldOverwrites>,
-) -> ColumnWriteOptions {
+    // The compression and statistics overwrites of the parent fields apply to the children.
+    mut compression: Option<ParquetCompression>,
+    mut statistics: Option<StatisticsOptions>,
+    is_nested: bool,
+) -> PolarsResult<ColumnWriteOptions> {
     let mut column_options = ColumnWriteOptions {
         field_id: None,
         metadata: Vec::new(),
         required: None,
 
         // Dummy value.
-        children: ChildWriteOptions::Leaf(FieldWriteOptions {
-            encoding: Encoding::Plain,
-        }),
+        children: ChildWriteOptions::Leaf(FieldWriteOptions::default_with_encoding(
+            Encoding::Plain,
+        )),
     };
 
     if let Some(overwrites) = overwrites {
         column_options.field_id = overwrites.field_id;
         column_options.metadata = convert_metadata(&overwrites.metadata);
         column_options.required = overwrites.required;
+        compression = overwrites.compression.or(compression);
+        statistics = overwrites.statistics.or(statistics);
     }
 
     use arrow::datatypes::PhysicalType::*;
     match field.dtype().to_physical_type() {
         Null | Boolean | Primitive(_) | Binary | FixedSizeBinary | LargeBinary | Utf8
         | Dictionary(_) | LargeUtf8 | BinaryView | Utf8View => {
+            // Values of nested columns can only be written plain.
+            if is_nested
+                && let Some(encoding) = overwrites.and_then(|o| o.encoding)
+                && encoding != ParquetEncoding::Plain
+            {
+                polars_bail!(
+                    InvalidOperation: "cannot write nested parquet field '{}' with encoding {:?}, \
+                    only top-level fields support encodings other than Plain",
+                    field.name, encoding
+                );
+            }
+            let (encoding, fallback_encoding) = field_encodings(field.dtype(), overwrites);
             column_options.children = ChildWriteOptions::Leaf(FieldWriteOptions {
-                encoding: encoding_map(field.dtype()),
+                encoding,
+                fallback_encoding,
+                compression: compression.map(Into::into),
+                statistics,
+                bloom_filter: overwrites.and_then(|o| o.bloom_filter).map(Into::into),
             });
         },
         List | FixedSizeList | LargeList => {
@@ -212,11 +272,11 @@ fn to_column_write_options_rec(
 
             let a = field.dtype().to_logical_type();
             let child = if let ArrowDataType::List(inner) = a {
-                to_column_write_options_rec(inner, child_overwrites)
+                to_column_write_options_rec(inner, child_overwrites, compression, statistics, true)?
             } else if let ArrowDataType::LargeList(inner) = a {
-                to_column_write_options_rec(inner, child_overwrites)
+                to_column_write_options_rec(inner, child_overwrites, compression, statistics, true)?
             } else if let ArrowDataType::FixedSizeList(inner, _) = a {
-                to_column_write_options_rec(inner, child_overwrites)
+                to_column_write_options_rec(inner, child_overwrites, compression, statistics, true)?
             } else {
                 unreachable!()
             };
@@ -242,9 +302,9 @@ fn to_column_write_options_rec(
                         let overwrites = children_overwrites
                             .as_ref()
                             .and_then(|o| o.get(&f.name).copied());
-                        to_column_write_options_rec(f, overwrites)
+                        to_column_write_options_rec(f, overwrites, compression, statistics, true)
                     })
-                    .collect();
+                    .collect::<PolarsResult<_>>()?;
 
                 column_options.children =
                     ChildWriteOptions::Struct(Box::new(StructFieldWriteOptions { children }));
@@ -256,13 +316,13 @@ fn to_column_write_options_rec(
         Map | Union => unreachable!(),
     }
 
-    column_options
+    Ok(column_options)
 }
 
 pub fn get_column_write_options(
     schema: &ArrowSchema,
     field_overwrites: &[ParquetFieldOverwrites],
-) -> Vec<ColumnWriteOptions> {
+) -> PolarsResult<Vec<ColumnWriteOptions>> {
     let field_overwrites = PlHashMap::from(
         field_overwrites
             .iter()
@@ -271,10 +331,75 @@ pub fn get_column_write_options(
     );
     schema
         .iter_values()
-        .map(|f| to_column_write_options_rec(f, field_overwrites.get(&f.name).copied()))
+        .map(|f| {
+            let overwrites = field_overwrites.get(&f.name).copied();
+            to_column_write_options_rec(f, overwrites, None, None, false)
+        })
         .collect()
 }
 
+/// The `sorting_columns` to record in the row groups of a file whose rows are sorted by
+/// `sorting_columns`.
+///
+/// This stops at the first column that is not a top-level leaf of the Parquet schema or whose sort
+/// order differs between Parquet and Polars, as the later columns are not sorted on their own.
+pub fn get_sorting_columns(
+    schema: &ArrowSchema,
+    parquet_schema: &SchemaDescriptor,
+    sorting_columns: &[ParquetSortingColumn],
+) -> Vec<SortingColumn> {
+    sorting_columns
+        .iter()
+        .map_while(|sorting_column| {
+            let field = schema.get(&sorting_column.name)?;
+            if !should_copy_sortedness(&DataType::from_arrow_field(field)) {
+                return None;
+            }
+            let column_idx = parquet_schema
+                .columns()
+                .iter()
+                .position(|c| c.path_in_schema.as_slice() == [sorting_column.name.clone()])?;
+
+            Some(SortingColumn {
+                column_idx: column_idx as i32,
+                descending: sorting_column.descending,
+                nulls_first: !sorting_column.nulls_last,
+            })
+        })
+        .collect()
+}
+
+/// The encoding and fallback encoding of a leaf field, see [`FieldWriteOptions`].
+fn field_encodings(
+    dtype: &ArrowDataType,
+    overwrites: Option<&ParquetFieldOverwrites>,
+) -> (Encoding, Encoding) {
+    let default_encoding = encoding_map(dtype);
+    let Some(overwrites) = overwrites else {
+        return (default_encoding, Encoding::Plain);
+    };
+
+    let encoding = overwrites.encoding.map(Encoding::from);
+    // Setting an encoding disables dictionary encoding, unless it is explicitly requested.
+    let dictionary = overwrites
+        .dictionary
+        .unwrap_or(encoding.is_none() && default_encoding == Encoding::RleDictionary);
+    let supports_dictionary = default_encoding == Encoding::RleDictionary
+        || matches!(
+            dtype.to_physical_type(),
+            PhysicalType::Primitive(
+                arrow::types::PrimitiveType::Float32 | arrow::types::PrimitiveType::Float64
+            )
+        );
+
+    let encoding = encoding.unwrap_or(Encoding::Plain);
+    if dictionary && supports_dictionary {
+        (Encoding::RleDictionary, encoding)
+    } else {
+        (encoding, Encoding::Plain)
+    }
+}
+
 /// Declare encodings
 fn encoding_map(dtype: &ArrowDataType) -> Encoding {
     match dtype.to_physical_type() {
@@ -118,6 +118,28 @@ impl ParquetColumnExpr for ColumnPredicateExpr {
     }
 }
 
+#[cfg(feature = "parquet")]
+impl SpecializedColumnPredicate {
+    /// Returns the bloom filter hashes of the values this predicate can be true for, hashed as
+    /// they are encoded with `physical_type`. Returns `None` if the predicate isn't limited to a
+    /// set of non-null values, as bloom filters can't rule out any other predicate.
+    pub fn bloom_filter_hashes(
+        &self,
+        physical_type: polars_parquet::parquet::schema::types::PhysicalType,
+    ) -> Option<Vec<u64>> {
+        let scalars = match self {
+            Self::Equal(scalar) => std::slice::from_ref(scalar),
+            Self::EqualOneOf(scalars) => scalars.as_ref(),
+            _ => return None,
+        };
+
+        scalars
+            .iter()
+            .map(|s| cast_to_parquet_scalar(s.clone())?.bloom_filter_hash(physical_type))
+            .collect()
+    }
+}
+
 #[cfg(feature = "parquet")]
 fn cast_to_parquet_scalar(scalar: Scalar) -> Option<ParquetScalar> {
     use {AnyValue as A, ParquetScalar as P};
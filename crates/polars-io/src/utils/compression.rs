@@ -1,13 +1,20 @@
-use std::io::Read;
+use std::io::{Read, Write};
+use std::sync::{Arc, Mutex};
 
 use polars_core::prelude::*;
 use polars_error::{feature_gated, to_compute_err};
+use polars_utils::mmap::MemSlice;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Represents the compression algorithms that we have decoders for
 pub enum SupportedCompression {
     GZIP,
     ZLIB,
     ZSTD,
+    BZIP2,
+    XZ,
+    LZ4,
 }
 
 impl SupportedCompression {
@@ -25,6 +32,20 @@ impl SupportedCompression {
             [0x78, 0xDA, _, _]   // ZLIB2
                                      => Some(Self::ZLIB),
             [0x28, 0xB5, 0x2F, 0xFD] => Some(Self::ZSTD),
+            // "BZh" followed by the block size. As this is valid text, we also check for the magic
+            // of the first block (or of the end of the stream for empty files).
+            [b'B', b'Z', b'h', b'1'..=b'9']
+                if bytes.len() >= 10
+                    && matches!(
+                        bytes[4..10],
+                        [0x31, 0x41, 0x59, 0x26, 0x53, 0x59] | [0x17, 0x72, 0x45, 0x38, 0x50, 0x90]
+                    ) =>
+            {
+                Some(Self::BZIP2)
+            },
+            [0xFD, b'7', b'z', b'X'] => Some(Self::XZ),
+            // LZ4 frame format
+            [0x04, 0x22, 0x4D, 0x18] => Some(Self::LZ4),
             _ => None,
         }
     }
@@ -52,6 +73,21 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
                 SupportedCompression::ZSTD => {
                     zstd::Decoder::with_buffer(bytes)?.read_to_end(out)?;
                 },
+                SupportedCompression::BZIP2 => feature_gated!("bzip2", {
+                    bzip2::read::MultiBzDecoder::new(bytes)
+                        .read_to_end(out)
+                        .map_err(to_compute_err)?;
+                }),
+                SupportedCompression::XZ => feature_gated!("xz", {
+                    liblzma::read::XzDecoder::new_multi_decoder(bytes)
+                        .read_to_end(out)
+                        .map_err(to_compute_err)?;
+                }),
+                SupportedCompression::LZ4 => feature_gated!("lz4", {
+                    MultiLz4Decoder::new(bytes)?
+                        .read_to_end(out)
+                        .map_err(to_compute_err)?;
+                }),
             }
 
             Ok(out)
@@ -60,3 +96,455 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
         Ok(bytes)
     }
 }
+
+/// Reads the bytes of a file in slices, decompressing them incrementally if compression is
+/// detected.
+///
+/// This allows sources to process compressed files with bounded memory instead of decompressing
+/// the full file upfront.
+pub enum CompressedReader {
+    Uncompressed {
+        slice: MemSlice,
+        offset: usize,
+    },
+    #[cfg(feature = "decompress")]
+    Gzip(flate2::bufread::MultiGzDecoder<std::io::Cursor<MemSlice>>),
+    #[cfg(feature = "decompress")]
+    Zlib(flate2::bufread::ZlibDecoder<std::io::Cursor<MemSlice>>),
+    #[cfg(feature = "decompress")]
+    Zstd(zstd::Decoder<'static, std::io::Cursor<MemSlice>>),
+    #[cfg(all(feature = "decompress", feature = "bzip2"))]
+    Bzip2(bzip2::bufread::MultiBzDecoder<std::io::Cursor<MemSlice>>),
+    #[cfg(all(feature = "decompress", feature = "xz"))]
+    Xz(liblzma::bufread::XzDecoder<std::io::Cursor<MemSlice>>),
+    #[cfg(all(feature = "decompress", feature = "lz4"))]
+    Lz4(MultiLz4Decoder<std::io::Cursor<MemSlice>>),
+}
+
+/// Decodes a stream of concatenated LZ4 frames, as written by [`CompressedWriter`].
+///
+/// [`lz4::Decoder`] stops at the end of the first frame, but it doesn't read past it, so the
+/// remaining frames are decoded by a new decoder over the same reader.
+#[cfg(all(feature = "decompress", feature = "lz4"))]
+pub struct MultiLz4Decoder<R: std::io::BufRead> {
+    decoder: Option<lz4::Decoder<R>>,
+}
+
+#[cfg(all(feature = "decompress", feature = "lz4"))]
+impl<R: std::io::BufRead> MultiLz4Decoder<R> {
+    pub fn new(reader: R) -> std::io::Result<Self> {
+        Ok(Self {
+            decoder: Some(lz4::Decoder::new(reader)?),
+        })
+    }
+}
+
+#[cfg(all(feature = "decompress", feature = "lz4"))]
+impl<R: std::io::BufRead> Read for MultiLz4Decoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        while let Some(decoder) = &mut self.decoder {
+            let n = decoder.read(buf)?;
+            if n > 0 {
+                return Ok(n);
+            }
+
+            // The frame has ended, continue with the next one if there is any input left.
+            let (mut reader, result) = self.decoder.take().unwrap().finish();
+            result?;
+            if !reader.fill_buf()?.is_empty() {
+                self.decoder = Some(lz4::Decoder::new(reader)?);
+            }
+        }
+
+        Ok(0)
+    }
+}
+
+impl CompressedReader {
+    /// Number of decompressed bytes that sources read at a time.
+    // Use a small size in debug builds to catch failures at slice boundaries in tests.
+    #[cfg(debug_assertions)]
+    pub const DEFAULT_READ_SIZE: usize = 1024;
+    #[cfg(not(debug_assertions))]
+    pub const DEFAULT_READ_SIZE: usize = 4 * 1024 * 1024;
+
+    pub fn try_new(slice: MemSlice) -> PolarsResult<Self> {
+        let Some(algo) = SupportedCompression::check(&slice) else {
+            return Ok(Self::Uncompressed { slice, offset: 0 });
+        };
+
+        feature_gated!("decompress", {
+            let reader = std::io::Cursor::new(slice);
+
+            Ok(match algo {
+                SupportedCompression::GZIP => {
+                    Self::Gzip(flate2::bufread::MultiGzDecoder::new(reader))
+                },
+                SupportedCompression::ZLIB => Self::Zlib(flate2::bufread::ZlibDecoder::new(reader)),
+                SupportedCompression::ZSTD => Self::Zstd(zstd::Decoder::with_buffer(reader)?),
+                SupportedCompression::BZIP2 => feature_gated!("bzip2", {
+                    Self::Bzip2(bzip2::bufread::MultiBzDecoder::new(reader))
+                }),
+                SupportedCompression::XZ => feature_gated!("xz", {
+                    Self::Xz(liblzma::bufread::XzDecoder::new_multi_decoder(reader))
+                }),
+                SupportedCompression::LZ4 => {
+                    feature_gated!("lz4", Self::Lz4(MultiLz4Decoder::new(reader)?))
+                },
+            })
+        })
+    }
+
+    pub fn is_compressed(&self) -> bool {
+        !matches!(self, Self::Uncompressed { .. })
+    }
+
+    /// Reads up to `read_size` bytes and returns them appended to `prev_leftover`, together with
+    /// the number of bytes that were read. Less than `read_size` bytes are only read at the end of
+    /// the file.
+    ///
+    /// `prev_leftover` must be empty or a suffix of the slice returned by the previous call. This
+    /// is zero-copy for uncompressed files.
+    pub fn read_next_slice(
+        &mut self,
+        prev_leftover: &MemSlice,
+        read_size: usize,
+    ) -> std::io::Result<(MemSlice, usize)> {
+        if let Self::Uncompressed { slice, offset } = self {
+            // The leftover directly precedes the unread bytes in the file.
+            let start = *offset - prev_leftover.len();
+            debug_assert!(
+                prev_leftover.is_empty() || prev_leftover.as_ptr() == slice[start..].as_ptr()
+            );
+            let end = offset.saturating_add(read_size).min(slice.len());
+            let n_read = end - *offset;
+            *offset = end;
+
+            return Ok((slice.slice(start..end), n_read));
+        }
+
+        read_slice_from(self, prev_leftover, read_size)
+    }
+}
+
+/// Reads up to `read_size` bytes from `reader` into a new buffer that starts with `prev_leftover`.
+/// Returns the buffer together with the number of bytes that were read.
+pub(crate) fn read_slice_from<R: Read>(
+    reader: &mut R,
+    prev_leftover: &MemSlice,
+    read_size: usize,
+) -> std::io::Result<(MemSlice, usize)> {
+    let mut buf = Vec::with_capacity(
+        prev_leftover
+            .len()
+            .saturating_add(read_size.min(CompressedReader::DEFAULT_READ_SIZE)),
+    );
+    buf.extend_from_slice(prev_leftover);
+    let n_read = reader
+        .take(read_size.try_into().unwrap_or(u64::MAX))
+        .read_to_end(&mut buf)?;
+
+    Ok((MemSlice::from_vec(buf), n_read))
+}
+
+impl Read for CompressedReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Uncompressed { slice, offset } => {
+                let n = buf.len().min(slice.len() - *offset);
+                buf[..n].copy_from_slice(&slice[*offset..*offset + n]);
+                *offset += n;
+                Ok(n)
+            },
+            #[cfg(feature = "decompress")]
+            Self::Gzip(decoder) => decoder.read(buf),
+            #[cfg(feature = "decompress")]
+            Self::Zlib(decoder) => decoder.read(buf),
+            #[cfg(feature = "decompress")]
+            Self::Zstd(decoder) => decoder.read(buf),
+            #[cfg(all(feature = "decompress", feature = "bzip2"))]
+            Self::Bzip2(decoder) => decoder.read(buf),
+            #[cfg(all(feature = "decompress", feature = "xz"))]
+            Self::Xz(decoder) => decoder.read(buf),
+            #[cfg(all(feature = "decompress", feature = "lz4"))]
+            Self::Lz4(decoder) => decoder.read(buf),
+        }
+    }
+}
+
+/// Compression applied to the whole output of a text-based file format (e.g. CSV, NDJSON), as
+/// opposed to the internal compression of formats such as Parquet and IPC.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ExternalCompression {
+    #[default]
+    Uncompressed,
+    Gzip {
+        level: Option<u32>,
+    },
+    Zstd {
+        level: Option<i32>,
+    },
+    Bzip2 {
+        level: Option<u32>,
+    },
+    Xz {
+        level: Option<u32>,
+    },
+    Lz4 {
+        level: Option<u32>,
+    },
+}
+
+impl ExternalCompression {
+    pub fn is_compressed(&self) -> bool {
+        !matches!(self, Self::Uncompressed)
+    }
+
+    /// Conventional file extension for this compression, without the leading dot.
+    pub fn file_extension(&self) -> Option<&'static str> {
+        match self {
+            Self::Uncompressed => None,
+            Self::Gzip { .. } => Some("gz"),
+            Self::Zstd { .. } => Some("zst"),
+            Self::Bzip2 { .. } => Some("bz2"),
+            Self::Xz { .. } => Some("xz"),
+            Self::Lz4 { .. } => Some("lz4"),
+        }
+    }
+}
+
+/// Writer that compresses everything written to it with an [`ExternalCompression`].
+pub enum CompressedWriter<W: Write> {
+    Uncompressed(W),
+    #[cfg(feature = "compress")]
+    Gzip(flate2::write::GzEncoder<W>),
+    #[cfg(feature = "compress")]
+    Zstd(zstd::Encoder<'static, W>),
+    #[cfg(all(feature = "compress", feature = "bzip2"))]
+    Bzip2(bzip2::write::BzEncoder<W>),
+    #[cfg(all(feature = "compress", feature = "xz"))]
+    Xz(liblzma::write::XzEncoder<W>),
+    #[cfg(all(feature = "compress", feature = "lz4"))]
+    Lz4(lz4::Encoder<W>),
+}
+
+impl<W: Write> CompressedWriter<W> {
+    pub fn try_new(writer: W, compression: ExternalCompression) -> PolarsResult<Self> {
+        if !compression.is_compressed() {
+            return Ok(Self::Uncompressed(writer));
+        }
+
+        feature_gated!("compress", {
+            Ok(match compression {
+                ExternalCompression::Uncompressed => unreachable!(),
+                ExternalCompression::Gzip { level } => {
+                    let compression = match level {
+                        None => flate2::Compression::default(),
+                        Some(level) => flate2::Compression::new(check_level("gzip", level, 0..=9)?),
+                    };
+                    Self::Gzip(flate2::write::GzEncoder::new(writer, compression))
+                },
+                ExternalCompression::Zstd { level } => {
+                    let level = match level {
+                        None => zstd::DEFAULT_COMPRESSION_LEVEL,
+                        Some(level) => check_level("zstd", level, zstd::compression_level_range())?,
+                    };
+                    Self::Zstd(zstd::Encoder::new(writer, level)?)
+                },
+                ExternalCompression::Bzip2 { level } => feature_gated!("bzip2", {
+                    let compression = match level {
+                        None => bzip2::Compression::default(),
+                        Some(level) => bzip2::Compression::new(check_level("bzip2", level, 1..=9)?),
+                    };
+                    Self::Bzip2(bzip2::write::BzEncoder::new(writer, compression))
+                }),
+                ExternalCompression::Xz { level } => feature_gated!("xz", {
+                    let level = check_level("xz", level.unwrap_or(6), 0..=9)?;
+                    Self::Xz(liblzma::write::XzEncoder::new(writer, level))
+                }),
+                ExternalCompression::Lz4 { level } => feature_gated!("lz4", {
+                    let level = check_level("lz4", level.unwrap_or(0), 0..=16)?;
+                    Self::Lz4(lz4::EncoderBuilder::new().level(level).build(writer)?)
+                }),
+            })
+        })
+    }
+
+    /// Writes the end of the compressed stream and returns the inner writer.
+    pub fn finish(self) -> PolarsResult<W> {
+        Ok(match self {
+            Self::Uncompressed(writer) => writer,
+            #[cfg(feature = "compress")]
+            Self::Gzip(encoder) => encoder.finish()?,
+            #[cfg(feature = "compress")]
+            Self::Zstd(encoder) => encoder.finish()?,
+            #[cfg(all(feature = "compress", feature = "bzip2"))]
+            Self::Bzip2(encoder) => encoder.finish()?,
+            #[cfg(all(feature = "compress", feature = "xz"))]
+            Self::Xz(encoder) => encoder.finish()?,
+            #[cfg(all(feature = "compress", feature = "lz4"))]
+            Self::Lz4(encoder) => {
+                let (writer, result) = encoder.finish();
+                result?;
+                writer
+            },
+        })
+    }
+}
+
+#[cfg(feature = "compress")]
+fn check_level<T: PartialOrd + std::fmt::Display>(
+    codec: &str,
+    level: T,
+    range: std::ops::RangeInclusive<T>,
+) -> PolarsResult<T> {
+    polars_ensure!(
+        range.contains(&level),
+        InvalidOperation: "invalid {} compression level {}, expected a value in {}..={}",
+        codec, level, range.start(), range.end()
+    );
+    Ok(level)
+}
+
+impl<W: Write> Write for CompressedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Uncompressed(writer) => writer.write(buf),
+            #[cfg(feature = "compress")]
+            Self::Gzip(encoder) => encoder.write(buf),
+            #[cfg(feature = "compress")]
+            Self::Zstd(encoder) => encoder.write(buf),
+            #[cfg(all(feature = "compress", feature = "bzip2"))]
+            Self::Bzip2(encoder) => encoder.write(buf),
+            #[cfg(all(feature = "compress", feature = "xz"))]
+            Self::Xz(encoder) => encoder.write(buf),
+            #[cfg(all(feature = "compress", feature = "lz4"))]
+            Self::Lz4(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Uncompressed(writer) => writer.flush(),
+            #[cfg(feature = "compress")]
+            Self::Gzip(encoder) => encoder.flush(),
+            #[cfg(feature = "compress")]
+            Self::Zstd(encoder) => encoder.flush(),
+            #[cfg(all(feature = "compress", feature = "bzip2"))]
+            Self::Bzip2(encoder) => encoder.flush(),
+            #[cfg(all(feature = "compress", feature = "xz"))]
+            Self::Xz(encoder) => encoder.flush(),
+            #[cfg(all(feature = "compress", feature = "lz4"))]
+            Self::Lz4(encoder) => encoder.flush(),
+        }
+    }
+}
+
+/// In-memory writer of which the written bytes can be taken while an encoder owns it.
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl SharedBuffer {
+    fn take(&self) -> Vec<u8> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Compresses consecutive batches into a single compressed stream.
+///
+/// Every call returns the compressed bytes the encoder produced so far, such that sinks can write
+/// them out incrementally without keeping the whole stream in memory.
+pub struct StreamCompressor {
+    writer: CompressedWriter<SharedBuffer>,
+    output: SharedBuffer,
+}
+
+impl StreamCompressor {
+    pub fn try_new(compression: ExternalCompression) -> PolarsResult<Self> {
+        let output = SharedBuffer::default();
+        Ok(Self {
+            writer: CompressedWriter::try_new(output.clone(), compression)?,
+            output,
+        })
+    }
+
+    /// Compresses `bytes`, returning the compressed output that is ready to be written.
+    pub fn compress(&mut self, bytes: &[u8]) -> PolarsResult<Vec<u8>> {
+        self.writer.write_all(bytes)?;
+        Ok(self.output.take())
+    }
+
+    /// Writes the end of the compressed stream, returning the remaining compressed output.
+    pub fn finish(self) -> PolarsResult<Vec<u8>> {
+        self.writer.finish()?;
+        Ok(self.output.take())
+    }
+}
+
+#[cfg(all(test, feature = "compress", feature = "decompress", feature = "lz4"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lz4_concatenated_frames() {
+        let mut compressed = Vec::new();
+        for part in [&b"a,b\n1,2\n"[..], b"3,4\n"] {
+            let mut writer =
+                CompressedWriter::try_new(Vec::new(), ExternalCompression::Lz4 { level: None })
+                    .unwrap();
+            writer.write_all(part).unwrap();
+            compressed.extend(writer.finish().unwrap());
+        }
+
+        let mut out = Vec::new();
+        maybe_decompress_bytes(&compressed, &mut out).unwrap();
+        assert_eq!(out, b"a,b\n1,2\n3,4\n");
+
+        let mut out = Vec::new();
+        CompressedReader::try_new(MemSlice::from_vec(compressed))
+            .unwrap()
+            .read_to_end(&mut out)
+            .unwrap();
+        assert_eq!(out, b"a,b\n1,2\n3,4\n");
+    }
+
+    #[test]
+    fn test_stream_compressor() {
+        for compression in [
+            ExternalCompression::Gzip { level: None },
+            ExternalCompression::Zstd { level: None },
+            ExternalCompression::Lz4 { level: None },
+        ] {
+            let mut compressor = StreamCompressor::try_new(compression).unwrap();
+            let mut compressed = Vec::new();
+            for part in [&b"a,b\n1,2\n"[..], b"3,4\n"] {
+                compressed.extend(compressor.compress(part).unwrap());
+            }
+            compressed.extend(compressor.finish().unwrap());
+
+            let mut out = Vec::new();
+            CompressedReader::try_new(MemSlice::from_vec(compressed))
+                .unwrap()
+                .read_to_end(&mut out)
+                .unwrap();
+            assert_eq!(out, b"a,b\n1,2\n3,4\n");
+        }
+    }
+}
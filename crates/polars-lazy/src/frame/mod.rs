@@ -12,8 +12,12 @@ pub mod pivot;
 use std::sync::{Arc, Mutex};
 
 pub use anonymous_scan::*;
+#[cfg(feature = "avro")]
+pub use avro::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
+#[cfg(feature = "delta")]
+pub use delta::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
@@ -155,6 +159,13 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle removing sorts of already sorted data and recording the sort order of the data
+    /// written by Parquet sinks.
+    pub fn with_sortedness(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::SORTEDNESS, toggle);
+        self
+    }
+
     /// Toggle predicate pushdown optimization.
     pub fn with_predicate_pushdown(mut self, toggle: bool) -> Self {
         self.opt_state.set(OptFlags::PREDICATE_PUSHDOWN, toggle);
@@ -951,6 +962,25 @@ impl LazyFrame {
         }))
     }
 
+    /// Stream a query result into an avro file. This is useful if the final result doesn't fit
+    /// into memory. This methods will return an error if the query cannot be completely done in a
+    /// streaming fashion.
+    #[cfg(feature = "avro")]
+    pub fn sink_avro(
+        self,
+        target: SinkTarget,
+        options: AvroWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+    ) -> PolarsResult<Self> {
+        self.sink(SinkType::File(FileSinkType {
+            target,
+            sink_options,
+            file_type: FileType::Avro(options),
+            cloud_options,
+        }))
+    }
+
     /// Stream a query result into a parquet file in a partitioned manner. This is useful if the
     /// final result doesn't fit into memory. This methods will return an error if the query cannot
     /// be completely done in a streaming fashion.
@@ -1063,6 +1093,34 @@ impl LazyFrame {
         }))
     }
 
+    /// Stream a query result into an avro file in a partitioned manner. This is useful if the
+    /// final result doesn't fit into memory. This methods will return an error if the query cannot
+    /// be completely done in a streaming fashion.
+    #[cfg(feature = "avro")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn sink_avro_partitioned(
+        self,
+        base_path: Arc<PlPath>,
+        file_path_cb: Option<PartitionTargetCallback>,
+        variant: PartitionVariant,
+        options: AvroWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+        per_partition_sort_by: Option<Vec<SortColumn>>,
+        finish_callback: Option<SinkFinishCallback>,
+    ) -> PolarsResult<Self> {
+        self.sink(SinkType::Partition(PartitionSinkType {
+            base_path,
+            file_path_cb,
+            sink_options,
+            variant,
+            file_type: FileType::Avro(options),
+            cloud_options,
+            per_partition_sort_by,
+            finish_callback,
+        }))
+    }
+
     #[cfg(feature = "new_streaming")]
     pub fn try_new_streaming_if_requested(
         &mut self,
@@ -58,6 +58,7 @@ impl LazyFrame {
                 include_file_paths: None,
                 column_mapping: None,
                 deletion_files: None,
+                file_statistics: None,
             },
         )?
         .build()
@@ -2,10 +2,16 @@ use polars_core::error::{PolarsResult, feature_gated, polars_bail};
 use polars_io::catalog::unity::models::{DataSourceFormat, TableInfo};
 use polars_io::catalog::unity::schema::table_info_to_schemas;
 use polars_io::cloud::CloudOptions;
+#[cfg(feature = "delta")]
+use polars_io::delta::DeltaWriteMode;
 use polars_utils::plpath::PlPath;
 
 use crate::frame::LazyFrame;
 
+/// Maximum number of rows in a data file written by [`LazyFrame::sink_catalog_table`].
+#[cfg(feature = "delta")]
+const MAX_ROWS_PER_FILE: polars_utils::IdxSize = 1 << 22;
+
 impl LazyFrame {
     pub fn scan_catalog_table(
         table_info: &TableInfo,
@@ -54,4 +60,78 @@ impl LazyFrame {
             ),
         }
     }
+
+    /// Writes the result of the query to the Delta table of `table_info` as Parquet files and
+    /// commits them as a new version of the table. The columns are cast to the types of the table.
+    ///
+    /// The returned [`LazyFrame`] must be collected with the streaming engine.
+    #[cfg(feature = "delta")]
+    pub fn sink_catalog_table(
+        mut self,
+        table_info: &TableInfo,
+        mode: DeltaWriteMode,
+        cloud_options: Option<CloudOptions>,
+    ) -> PolarsResult<Self> {
+        use std::sync::Arc;
+
+        use polars_core::frame::DataFrame;
+        use polars_io::delta::DeltaTableWriter;
+
+        use crate::prelude::*;
+
+        if !matches!(table_info.data_source_format, Some(DataSourceFormat::Delta)) {
+            polars_bail!(
+                ComputeError:
+                "sink_catalog_table only supports Delta tables, got data_source_format: {:?}",
+                table_info.data_source_format
+            )
+        }
+
+        let Some(storage_location) = table_info.storage_location.as_deref() else {
+            polars_bail!(ComputeError: "sink_catalog_table requires Some(_) for storage_location")
+        };
+
+        let storage_location = PlPath::new(storage_location);
+        let writer = Arc::new(DeltaTableWriter::try_new(
+            storage_location.as_ref(),
+            cloud_options.clone(),
+            self.collect_schema()?.as_ref(),
+            mode,
+        )?);
+
+        let columns = writer
+            .schema()
+            .iter()
+            .map(|(name, dtype)| col(name.clone()).strict_cast(dtype.clone()))
+            .collect::<Vec<_>>();
+
+        let file_path_cb = {
+            let writer = writer.clone();
+            PartitionTargetCallback::Rust(SpecialEq::new(Arc::new(
+                move |ctx: PartitionTargetContext| {
+                    Ok(PartitionTargetCallbackResult::Str(
+                        writer.data_file_name(ctx.file_idx),
+                    ))
+                },
+            )))
+        };
+        let finish_callback =
+            SinkFinishCallback::Rust(SpecialEq::new(Arc::new(move |write_metrics: DataFrame| {
+                writer.commit(&write_metrics).map(|_| ())
+            })));
+
+        self.select(columns).sink_parquet_partitioned(
+            Arc::new(storage_location),
+            Some(file_path_cb),
+            PartitionVariant::MaxSize(MAX_ROWS_PER_FILE),
+            ParquetWriteOptions::default(),
+            cloud_options,
+            SinkOptions {
+                mkdir: true,
+                ..Default::default()
+            },
+            None,
+            Some(finish_callback),
+        )
+    }
 }
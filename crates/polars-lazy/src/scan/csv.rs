@@ -1,10 +1,11 @@
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
+use polars_io::csv::read::schema_inference::decompress_schema_inference_prefix;
 use polars_io::csv::read::{
-    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues, infer_file_schema,
+    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, CsvSourceReader, NullValues,
+    infer_file_schema,
 };
 use polars_io::path_utils::expand_paths;
-use polars_io::utils::compression::maybe_decompress_bytes;
 use polars_io::utils::get_reader_bytes;
 use polars_io::{HiveOptions, RowIndex};
 use polars_utils::mmap::MemSlice;
@@ -169,6 +170,25 @@ impl LazyCsvReader {
         self.map_parse_options(|opts| opts.with_eol_char(eol_char))
     }
 
+    /// Set a column separator of more than one byte, e.g. `||`.
+    #[must_use]
+    pub fn with_multi_byte_separator(self, separator: Option<PlSmallStr>) -> Self {
+        self.map_parse_options(|opts| opts.with_multi_byte_separator(separator.clone()))
+    }
+
+    /// Set a line terminator of more than one byte, e.g. `\r\n`.
+    #[must_use]
+    pub fn with_multi_byte_eol(self, eol: Option<PlSmallStr>) -> Self {
+        self.map_parse_options(|opts| opts.with_multi_byte_eol(eol.clone()))
+    }
+
+    /// Set the `char` that makes the next `char` literal, e.g. `b'\\'`. If set to [`None`]
+    /// escaping is disabled, which is the default.
+    #[must_use]
+    pub fn with_escape_char(self, escape_char: Option<u8>) -> Self {
+        self.map_parse_options(|opts| opts.with_escape_char(escape_char))
+    }
+
     /// Set values that will be interpreted as missing/ null.
     #[must_use]
     pub fn with_null_values(self, null_values: Option<NullValues>) -> Self {
@@ -251,8 +271,22 @@ impl LazyCsvReader {
             let skip_lines = self.read_options.skip_lines;
             let parse_options = self.read_options.get_parse_options();
 
-            let mut owned = vec![];
-            let bytes = maybe_decompress_bytes(bytes.as_ref(), &mut owned)?;
+            let mut reader = CsvSourceReader::try_new(bytes.clone(), &parse_options)?;
+            // The decoded bytes are in the normalized dialect.
+            let read_options = self
+                .read_options
+                .clone()
+                .map_parse_options(CsvParseOptions::into_normalized);
+            let parse_options = read_options.get_parse_options();
+            let bytes = if reader.is_decoded() {
+                decompress_schema_inference_prefix(
+                    &mut reader,
+                    &read_options,
+                    self.read_options.infer_schema_length,
+                )?
+            } else {
+                bytes
+            };
 
             PolarsResult::Ok(
                 infer_file_schema(
@@ -346,6 +380,7 @@ impl LazyFileListReader for LazyCsvReader {
                 include_file_paths: self.include_file_paths,
                 column_mapping: None,
                 deletion_files: None,
+                file_statistics: None,
             },
         )?
         .build()
@@ -0,0 +1,106 @@
+use polars_core::prelude::*;
+use polars_io::RowIndex;
+use polars_io::cloud::CloudOptions;
+use polars_io::delta::{DeltaScanOptions, DeltaTableVersion};
+use polars_io::parquet::read::{ParallelStrategy, ParquetOptions};
+use polars_utils::plpath::PlPath;
+use polars_utils::slice_enum::Slice;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsDelta {
+    /// Version of the table to read, the latest version if `None`.
+    pub version: Option<DeltaTableVersion>,
+    pub n_rows: Option<usize>,
+    pub parallel: ParallelStrategy,
+    pub row_index: Option<RowIndex>,
+    pub cloud_options: Option<CloudOptions>,
+    pub use_statistics: bool,
+    pub low_memory: bool,
+    pub rechunk: bool,
+    pub cache: bool,
+    pub include_file_paths: Option<PlSmallStr>,
+}
+
+impl Default for ScanArgsDelta {
+    fn default() -> Self {
+        Self {
+            version: None,
+            n_rows: None,
+            parallel: Default::default(),
+            row_index: None,
+            cloud_options: None,
+            use_statistics: true,
+            low_memory: false,
+            rechunk: false,
+            cache: true,
+            include_file_paths: None,
+        }
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from the Delta Lake table at `table_root`.
+    ///
+    /// The transaction log of the table is read when the query is resolved.
+    pub fn scan_delta(table_root: PlPath, args: ScanArgsDelta) -> PolarsResult<Self> {
+        let options = DeltaScanOptions {
+            version: args.version,
+            parquet_options: ParquetOptions {
+                schema: None,
+                parallel: args.parallel,
+                low_memory: args.low_memory,
+                use_statistics: args.use_statistics,
+                decryption: None,
+            },
+        };
+
+        // The data files of a table can be written by different writers and before or after
+        // changes to the schema of the table.
+        let cast_columns_policy = CastColumnsPolicy {
+            integer_upcast: true,
+            float_upcast: true,
+            float_downcast: true,
+            datetime_nanoseconds_downcast: true,
+            datetime_microseconds_downcast: false,
+            datetime_convert_timezone: true,
+            missing_struct_fields: MissingColumnsPolicy::Insert,
+            extra_struct_fields: ExtraColumnsPolicy::Ignore,
+        };
+
+        let unified_scan_args = UnifiedScanArgs {
+            schema: None,
+            cloud_options: args.cloud_options,
+            hive_options: Default::default(),
+            rechunk: args.rechunk,
+            cache: args.cache,
+            glob: false,
+            projection: None,
+            // Note: We call `with_row_index()` on the LazyFrame below
+            row_index: None,
+            pre_slice: args.n_rows.map(|len| Slice::Positive { offset: 0, len }),
+            cast_columns_policy,
+            missing_columns_policy: MissingColumnsPolicy::Insert,
+            extra_columns_policy: ExtraColumnsPolicy::Ignore,
+            include_file_paths: args.include_file_paths,
+            column_mapping: None,
+            deletion_files: None,
+            file_statistics: None,
+        };
+
+        let mut lf: LazyFrame = DslBuilder::scan_delta(
+            ScanSources::Paths([table_root].into()),
+            options,
+            unified_scan_args,
+        )?
+        .build()
+        .into();
+
+        if let Some(row_index) = args.row_index {
+            lf = lf.with_row_index(row_index.name, Some(row_index.offset))
+        }
+
+        Ok(lf)
+    }
+}
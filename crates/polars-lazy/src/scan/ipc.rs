@@ -80,6 +80,7 @@ impl LazyFileListReader for LazyIpcReader {
                 include_file_paths,
                 column_mapping: None,
                 deletion_files: None,
+                file_statistics: None,
             },
         )?
         .build()
@@ -1,6 +1,10 @@
 pub(super) mod anonymous_scan;
+#[cfg(feature = "avro")]
+pub(super) mod avro;
 #[cfg(feature = "csv")]
 pub(super) mod csv;
+#[cfg(feature = "delta")]
+pub(super) mod delta;
 pub(super) mod file_list_reader;
 #[cfg(feature = "ipc")]
 pub(super) mod ipc;
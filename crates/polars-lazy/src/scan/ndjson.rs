@@ -141,6 +141,7 @@ impl LazyFileListReader for LazyJsonLineReader {
             include_file_paths: self.include_file_paths,
             column_mapping: None,
             deletion_files: None,
+            file_statistics: None,
         };
 
         let options = NDJsonReadOptions {
@@ -1,5 +1,6 @@
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
+use polars_io::parquet::encryption::ParquetDecryptionOptions;
 use polars_io::parquet::read::ParallelStrategy;
 use polars_io::prelude::ParquetOptions;
 use polars_io::{HiveOptions, RowIndex};
@@ -24,6 +25,8 @@ pub struct ScanArgsParquet {
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
     pub allow_missing_columns: bool,
+    /// Decrypt encrypted files.
+    pub decryption: Option<ParquetDecryptionOptions>,
 }
 
 impl Default for ScanArgsParquet {
@@ -42,6 +45,7 @@ impl Default for ScanArgsParquet {
             glob: true,
             include_file_paths: None,
             allow_missing_columns: false,
+            decryption: None,
         }
     }
 }
@@ -69,6 +73,7 @@ impl LazyFileListReader for LazyParquetReader {
             parallel: self.args.parallel,
             low_memory: self.args.low_memory,
             use_statistics: self.args.use_statistics,
+            decryption: self.args.decryption,
         };
 
         let unified_scan_args = UnifiedScanArgs {
@@ -95,6 +100,7 @@ impl LazyFileListReader for LazyParquetReader {
             include_file_paths: self.args.include_file_paths,
             column_mapping: None,
             deletion_files: None,
+            file_statistics: None,
         };
 
         let mut lf: LazyFrame =
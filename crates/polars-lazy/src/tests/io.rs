@@ -781,6 +781,124 @@ fn scan_anonymous_fn_count() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "new_streaming")]
+fn scan_anonymous_fn_streaming() -> PolarsResult<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Produces one row per batch.
+    struct MyBatchedScan {
+        next_row: AtomicUsize,
+    }
+
+    impl AnonymousScan for MyBatchedScan {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn allows_projection_pushdown(&self) -> bool {
+            true
+        }
+
+        fn allows_predicate_pushdown(&self) -> bool {
+            true
+        }
+
+        fn allows_batched_scan(&self) -> bool {
+            true
+        }
+
+        fn scan(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+            unreachable!()
+        }
+
+        fn next_batch(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<Option<DataFrame>> {
+            let with_columns = scan_opts.with_columns.unwrap();
+            assert_eq!(with_columns.len(), 2);
+            assert!(scan_opts.predicate.is_some());
+
+            // Ignore the predicate, it is applied again after the scan.
+            let row = self.next_row.fetch_add(1, Ordering::Relaxed);
+            if row >= 5 {
+                return Ok(None);
+            }
+            let df = fruits_cars().slice(row as i64, 1);
+            Ok(Some(df.select(with_columns.iter().cloned())?))
+        }
+    }
+
+    let function = Arc::new(MyBatchedScan {
+        next_row: AtomicUsize::new(0),
+    });
+    let args = ScanArgsAnonymous {
+        schema: Some(fruits_cars().schema().clone()),
+        ..ScanArgsAnonymous::default()
+    };
+    let df = LazyFrame::anonymous_scan(function, args)?
+        .filter(col("fruits").eq(lit("banana")))
+        .select([col("A")])
+        .collect_with_engine(Engine::Streaming)?;
+    assert_eq!(df, df!("A" => [1, 2, 5])?);
+
+    // Produces everything at once, ignoring n_rows.
+    struct MyScan {}
+
+    impl AnonymousScan for MyScan {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn allows_slice_pushdown(&self) -> bool {
+            true
+        }
+
+        fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+            assert_eq!(scan_opts.n_rows, Some(2));
+            Ok(fruits_cars())
+        }
+    }
+
+    let args = ScanArgsAnonymous {
+        schema: Some(fruits_cars().schema().clone()),
+        ..ScanArgsAnonymous::default()
+    };
+    let df = LazyFrame::anonymous_scan(Arc::new(MyScan {}), args)?
+        .limit(2)
+        .collect_with_engine(Engine::Streaming)?;
+    assert_eq!(df, fruits_cars().head(Some(2)));
+
+    // Produces everything at once, ignoring n_rows, which covers the end of a sliced scan.
+    struct MySlicedScan {}
+
+    impl AnonymousScan for MySlicedScan {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn allows_slice_pushdown(&self) -> bool {
+            true
+        }
+
+        fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+            assert_eq!(scan_opts.n_rows, Some(3));
+            Ok(fruits_cars())
+        }
+    }
+
+    for engine in [Engine::InMemory, Engine::Streaming] {
+        let args = ScanArgsAnonymous {
+            schema: Some(fruits_cars().schema().clone()),
+            ..ScanArgsAnonymous::default()
+        };
+        let df = LazyFrame::anonymous_scan(Arc::new(MySlicedScan {}), args)?
+            .slice(1, 2)
+            .collect_with_engine(engine)?;
+        assert_eq!(df, fruits_cars().slice(1, 2));
+    }
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "dtype-full")]
 fn scan_small_dtypes() -> PolarsResult<()> {
@@ -25,6 +25,8 @@ mod unique;
 use std::borrow::Cow;
 
 pub use executor::*;
+#[cfg(feature = "asof_join")]
+pub use join::resolve_asof_tolerance;
 use polars_core::POOL;
 use polars_plan::utils::*;
 use projection_utils::*;
@@ -43,12 +43,20 @@ pub(crate) struct AnonymousScanExec {
 
 impl Executor for AnonymousScanExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let pre_slice = self
+            .unified_scan_args
+            .pre_slice
+            .clone()
+            .map(|x| match x {
+                Slice::Positive { offset, len } => Ok((offset, len)),
+                Slice::Negative { .. } => {
+                    polars_bail!(ComputeError: "anonymous scan does not support negative slices")
+                },
+            })
+            .transpose()?;
         let mut args = AnonymousScanArgs {
-            n_rows: self.unified_scan_args.pre_slice.clone().map(|x| {
-                assert!(matches!(x, Slice::Positive { offset: 0, .. }));
-
-                x.len()
-            }),
+            // The function only needs to produce the rows up to the end of the slice.
+            n_rows: pre_slice.map(|(offset, len)| offset.saturating_add(len)),
             with_columns: self.unified_scan_args.projection.clone(),
             schema: self.file_info.schema.clone(),
             output_schema: self.output_schema.clone(),
@@ -58,7 +66,7 @@ impl Executor for AnonymousScanExec {
             state.insert_has_window_function_flag()
         }
 
-        match (self.function.allows_predicate_pushdown(), &self.predicate) {
+        let df = match (self.function.allows_predicate_pushdown(), &self.predicate) {
             (true, Some(predicate)) => state.record(
                 || {
                     args.predicate = predicate.predicate.as_expression().cloned();
@@ -83,6 +91,11 @@ impl Executor for AnonymousScanExec {
                 "anonymous_scan".into(),
             ),
             _ => state.record(|| self.function.scan(args), "anonymous_scan".into()),
-        }
+        }?;
+
+        Ok(match pre_slice {
+            Some((offset, len)) => df.slice(offset as i64, len),
+            None => df,
+        })
     }
 }
@@ -129,6 +129,8 @@ pub fn sink_name(file_type: &FileType) -> &'static str {
         FileType::Csv(_) => "csv",
         #[cfg(feature = "json")]
         FileType::Json(_) => "json",
+        #[cfg(feature = "avro")]
+        FileType::Avro(_) => "avro",
         #[allow(unreachable_patterns)]
         _ => panic!("enable filetype feature"),
     }
@@ -281,14 +281,7 @@ fn create_physical_plan_impl(
                             match &file_type {
                                 #[cfg(feature = "parquet")]
                                 FileType::Parquet(options) => {
-                                    use polars_io::parquet::write::ParquetWriter;
-                                    ParquetWriter::new(BufWriter::new(writer))
-                                        .with_compression(options.compression)
-                                        .with_statistics(options.statistics)
-                                        .with_row_group_size(options.row_group_size)
-                                        .with_data_page_size(options.data_page_size)
-                                        .with_key_value_metadata(options.key_value_metadata.clone())
-                                        .finish(&mut df)?;
+                                    options.to_writer(BufWriter::new(writer)).finish(&mut df)?;
                                 },
                                 #[cfg(feature = "ipc")]
                                 FileType::Ipc(options) => {
@@ -303,9 +296,16 @@ fn create_physical_plan_impl(
                                 FileType::Csv(options) => {
                                     use polars_io::SerWriter;
                                     use polars_io::csv::write::CsvWriter;
-                                    CsvWriter::new(BufWriter::new(writer))
+                                    use polars_io::utils::compression::CompressedWriter;
+
+                                    let mut writer = CompressedWriter::try_new(
+                                        BufWriter::new(writer),
+                                        options.compression,
+                                    )?;
+                                    CsvWriter::new(&mut writer)
                                         .include_bom(options.include_bom)
                                         .include_header(options.include_header)
+                                        .with_encoding(options.encoding)
                                         .with_separator(options.serialize_options.separator)
                                         .with_line_terminator(
                                             options.serialize_options.line_terminator.clone(),
@@ -331,15 +331,27 @@ fn create_physical_plan_impl(
                                         .with_null_value(options.serialize_options.null.clone())
                                         .with_quote_style(options.serialize_options.quote_style)
                                         .finish(&mut df)?;
+                                    writer.finish()?;
                                 },
                                 #[cfg(feature = "json")]
-                                FileType::Json(_options) => {
+                                FileType::Json(options) => {
                                     use polars_io::SerWriter;
                                     use polars_io::json::{JsonFormat, JsonWriter};
+                                    use polars_io::utils::compression::CompressedWriter;
 
-                                    JsonWriter::new(BufWriter::new(writer))
+                                    let mut writer = CompressedWriter::try_new(
+                                        BufWriter::new(writer),
+                                        options.compression,
+                                    )?;
+                                    JsonWriter::new(&mut writer)
                                         .with_json_format(JsonFormat::JsonLines)
                                         .finish(&mut df)?;
+                                    writer.finish()?;
+                                },
+                                #[cfg(feature = "avro")]
+                                FileType::Avro(options) => {
+                                    use polars_io::SerWriter;
+                                    options.to_writer(BufWriter::new(writer)).finish(&mut df)?;
                                 },
                                 #[allow(unreachable_patterns)]
                                 _ => panic!("enable filetype feature"),
@@ -4,6 +4,9 @@ use arrow::array::Array;
 use arrow::bitmap::{Bitmap, BitmapBuilder};
 use arrow::types::AlignedBytes;
 
+#[cfg(feature = "bloom_filter")]
+use crate::parquet::schema::types::PhysicalType;
+
 #[derive(Clone)]
 pub enum ParquetScalar {
     Null,
@@ -33,6 +36,35 @@ impl ParquetScalar {
         matches!(self, Self::Null)
     }
 
+    /// Returns the split-block bloom filter hash of this scalar as it is encoded with
+    /// `physical_type`, or `None` if it isn't a non-null value of that physical type.
+    ///
+    /// Floats are never hashed: writers hash their raw bits, so a filter can't be probed for
+    /// values that compare equal with different bits (`0.0` and `-0.0`, NaN payloads).
+    #[cfg(feature = "bloom_filter")]
+    pub fn bloom_filter_hash(&self, physical_type: PhysicalType) -> Option<u64> {
+        use crate::parquet::bloom_filter::{hash_byte, hash_native};
+
+        Some(match (self, physical_type) {
+            (Self::Int8(v), PhysicalType::Int32) => hash_native(*v as i32),
+            (Self::Int16(v), PhysicalType::Int32) => hash_native(*v as i32),
+            (Self::Int32(v), PhysicalType::Int32) => hash_native(*v),
+            (Self::UInt8(v), PhysicalType::Int32) => hash_native(*v as i32),
+            (Self::UInt16(v), PhysicalType::Int32) => hash_native(*v as i32),
+            (Self::UInt32(v), PhysicalType::Int32) => hash_native(*v as i32),
+            (Self::Int64(v), PhysicalType::Int64) => hash_native(*v),
+            (Self::UInt64(v), PhysicalType::Int64) => hash_native(*v as i64),
+            (Self::String(v), PhysicalType::ByteArray) => hash_byte(v.as_bytes()),
+            (Self::Binary(v), PhysicalType::ByteArray) => hash_byte(v),
+            (Self::FixedSizeBinary(v) | Self::Binary(v), PhysicalType::FixedLenByteArray(n))
+                if v.len() == n =>
+            {
+                hash_byte(v)
+            },
+            _ => return None,
+        })
+    }
+
     pub(crate) fn to_aligned_bytes<B: AlignedBytes>(&self) -> Option<B> {
         match self {
             Self::Int8(v) => <B::Unaligned>::try_from(&v.to_le_bytes())
@@ -7,12 +7,14 @@ use arrow::array::{
 use arrow::datatypes::{ArrowDataType, Field, IntegerType, IntervalUnit, TimeUnit};
 use arrow::types::{NativeType, days_ms, f16, i256};
 use ethnum::I256;
+use polars_parquet_format::Statistics as ThriftStatistics;
 use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
 
 use super::{ParquetTimeUnit, RowGroupMetadata};
 use crate::parquet::error::{ParquetError, ParquetResult};
-use crate::parquet::schema::types::PhysicalType as ParquetPhysicalType;
+use crate::parquet::read::ColumnIndex;
+use crate::parquet::schema::types::{PhysicalType as ParquetPhysicalType, PrimitiveType};
 use crate::parquet::statistics::Statistics as ParquetStatistics;
 use crate::read::{
     ColumnChunkMetadata, PrimitiveLogicalType, convert_days_ms, convert_i128, convert_i256,
@@ -299,6 +301,70 @@ pub fn deserialize_all(
     field_idx: usize,
 ) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
     assert!(!row_groups.is_empty());
+    let primitive_type = &row_groups[0].parquet_columns()[field_idx]
+        .descriptor()
+        .descriptor
+        .primitive_type;
+
+    deserialize_all_impl(
+        field,
+        primitive_type,
+        row_groups.len(),
+        row_groups
+            .iter()
+            .map(|rg| rg.parquet_columns()[field_idx].statistics().transpose()),
+    )
+}
+
+/// Deserializes the statistics of the pages of `column` from its [`ColumnIndex`] into
+/// [`ArrowColumnStatisticsArrays`] associated from `field`'s name, with one value per page.
+///
+/// # Errors
+/// This function errors if the deserialization of the statistics fails (e.g. invalid utf8)
+pub fn deserialize_page_statistics(
+    field: &Field,
+    column: &ColumnChunkMetadata,
+    column_index: &ColumnIndex,
+) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
+    let primitive_type = &column.descriptor().descriptor.primitive_type;
+    let num_pages = column_index.null_pages.len();
+
+    deserialize_all_impl(
+        field,
+        primitive_type,
+        num_pages,
+        (0..num_pages).map(|i| {
+            // The min and max of pages with only nulls are meaningless.
+            let (min_value, max_value) = if column_index.null_pages[i] {
+                (None, None)
+            } else {
+                (
+                    Some(column_index.min_values[i].clone()),
+                    Some(column_index.max_values[i].clone()),
+                )
+            };
+            let statistics = ThriftStatistics {
+                null_count: column_index.null_counts.as_ref().map(|v| v[i]),
+                min_value,
+                max_value,
+                distinct_count: None,
+                max: None,
+                min: None,
+                is_max_value_exact: None,
+                is_min_value_exact: None,
+            };
+
+            ParquetStatistics::deserialize(&statistics, primitive_type.clone()).map(Some)
+        }),
+    )
+}
+
+fn deserialize_all_impl(
+    field: &Field,
+    primitive_type: &PrimitiveType,
+    len: usize,
+    statistics: impl Iterator<Item = ParquetResult<Option<ParquetStatistics>>>,
+) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
     use ArrowDataType as D;
     match field.dtype() {
         // @TODO: These are all a bit more complex, skip for now.
@@ -308,26 +374,19 @@ pub fn deserialize_all(
         D::Struct(..) => Ok(None),
 
         _ => {
-            let mut null_count = MutablePrimitiveArray::<IdxSize>::with_capacity(row_groups.len());
-            let mut distinct_count =
-                MutablePrimitiveArray::<IdxSize>::with_capacity(row_groups.len());
-
-            let primitive_type = &row_groups[0].parquet_columns()[field_idx]
-                .descriptor()
-                .descriptor
-                .primitive_type;
+            let mut null_count = MutablePrimitiveArray::<IdxSize>::with_capacity(len);
+            let mut distinct_count = MutablePrimitiveArray::<IdxSize>::with_capacity(len);
 
             let logical_type = &primitive_type.logical_type;
             let physical_type = &primitive_type.physical_type;
 
             macro_rules! rmap {
                 ($expect:ident, $map:expr, $arr:ty$(, $arg:expr)?) => {{
-                    let mut min_arr = <$arr>::with_capacity(row_groups.len()$(, $arg)?);
-                    let mut max_arr = <$arr>::with_capacity(row_groups.len()$(, $arg)?);
+                    let mut min_arr = <$arr>::with_capacity(len$(, $arg)?);
+                    let mut max_arr = <$arr>::with_capacity(len$(, $arg)?);
 
-                    for rg in row_groups {
-                        let column = &rg.parquet_columns()[field_idx];
-                        let s = column.statistics().transpose()?;
+                    for s in statistics {
+                        let s = s?;
 
                         let (v_min, v_max, v_null_count, v_distinct_count) = match s {
                             None => (None, None, None, None),
@@ -401,8 +460,8 @@ pub fn deserialize_all(
             use {ArrowDataType as D, ParquetPhysicalType as PPT};
             let (min_value, max_value) = match (field.dtype(), physical_type) {
                 (D::Null, _) => (
-                    NullArray::new(ArrowDataType::Null, row_groups.len()).to_boxed(),
-                    NullArray::new(ArrowDataType::Null, row_groups.len()).to_boxed(),
+                    NullArray::new(ArrowDataType::Null, len).to_boxed(),
+                    NullArray::new(ArrowDataType::Null, len).to_boxed(),
                 ),
 
                 (D::Boolean, _) => rmap!(
@@ -5,7 +5,7 @@ use polars_error::PolarsResult;
 
 use super::super::{WriteOptions, utils};
 use crate::arrow::read::schema::is_nullable;
-use crate::parquet::encoding::{Encoding, delta_bitpacked};
+use crate::parquet::encoding::{Encoding, delta_bitpacked, delta_byte_array};
 use crate::parquet::schema::types::PrimitiveType;
 use crate::parquet::statistics::{BinaryStatistics, ParquetStatistics};
 use crate::write::utils::invalid_encoding;
@@ -46,6 +46,19 @@ pub(crate) fn encode_plain<O: Offset>(
     }
 }
 
+pub(crate) fn encode_delta_byte_array<O: Offset>(
+    array: &BinaryArray<O>,
+    options: EncodeNullability,
+    buffer: &mut Vec<u8>,
+) {
+    let values: Vec<&[u8]> = if options.is_optional() && array.validity().is_some() {
+        array.non_null_values_iter().collect()
+    } else {
+        array.values_iter().collect()
+    };
+    delta_byte_array::encode(values.iter().copied(), buffer);
+}
+
 pub fn array_to_page<O: Offset>(
     array: &BinaryArray<O>,
     options: WriteOptions,
@@ -76,6 +89,7 @@ pub fn array_to_page<O: Offset>(
             encode_options,
             &mut buffer,
         ),
+        Encoding::DeltaByteArray => encode_delta_byte_array(array, encode_options, &mut buffer),
         _ => return Err(invalid_encoding(encoding, array.dtype())),
     }
 
@@ -0,0 +1,157 @@
+use arrow::array::*;
+use arrow::datatypes::PhysicalType;
+use arrow::match_integer_type;
+use arrow::types::PrimitiveType;
+
+use super::{ColumnWriteOptions, ParquetType, to_leaves, to_parquet_leaves};
+use crate::parquet::bloom_filter::{hash_byte, hash_native, insert, optimal_num_bytes};
+use crate::parquet::schema::types::PhysicalType as ParquetPhysicalType;
+
+/// Builds the split-block bloom filter bitsets of the Parquet leaves of `array`. A leaf gets a
+/// bloom filter if its [`FieldWriteOptions`][super::FieldWriteOptions] ask for one and its values
+/// can be hashed.
+pub fn array_to_bloom_filters(
+    array: &dyn Array,
+    type_: ParquetType,
+    column_options: &ColumnWriteOptions,
+) -> Vec<Option<Vec<u8>>> {
+    let mut field_options = Vec::new();
+    column_options.to_leaves(&mut field_options);
+    if field_options.iter().all(|o| o.bloom_filter.is_none()) {
+        return vec![None; field_options.len()];
+    }
+
+    let types = to_parquet_leaves(type_);
+    let mut values = Vec::new();
+    to_leaves(array, &mut values);
+
+    values
+        .iter()
+        .zip(types)
+        .zip(field_options)
+        .map(|((values, type_), field_options)| {
+            let options = field_options.bloom_filter?;
+
+            let mut hashes = Vec::new();
+            if !hash_values(values.as_ref(), type_.physical_type, &mut hashes) {
+                return None;
+            }
+            hashes.sort_unstable();
+            hashes.dedup();
+
+            let ndv = options.ndv.map_or(hashes.len(), |ndv| ndv as usize);
+            let mut bitset = vec![0; optimal_num_bytes(ndv, options.fpp)];
+            for hash in hashes {
+                insert(&mut bitset, hash);
+            }
+            Some(bitset)
+        })
+        .collect()
+}
+
+/// Hashes the non-null values of `array` the way they are encoded as `physical_type`. Returns
+/// false if the values can't be hashed.
+///
+/// Floats aren't hashed, as their bloom filters can't be used for pruning (see
+/// [`ParquetScalar::bloom_filter_hash`][crate::arrow::read::expr::ParquetScalar::bloom_filter_hash]).
+fn hash_values(array: &dyn Array, physical_type: ParquetPhysicalType, out: &mut Vec<u64>) -> bool {
+    use ParquetPhysicalType as P;
+
+    fn hash_primitive<T: arrow::types::NativeType>(
+        array: &dyn Array,
+        out: &mut Vec<u64>,
+        hash: impl Fn(T) -> u64,
+    ) -> bool {
+        let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+        out.extend(array.non_null_values_iter().map(hash));
+        true
+    }
+
+    fn hash_bytes<'a>(values: impl Iterator<Item = Option<&'a [u8]>>, out: &mut Vec<u64>) -> bool {
+        out.extend(values.flatten().map(hash_byte));
+        true
+    }
+
+    match (array.dtype().to_physical_type(), physical_type) {
+        (PhysicalType::Primitive(primitive), P::Int32) => match primitive {
+            PrimitiveType::Int8 => hash_primitive(array, out, |v: i8| hash_native(v as i32)),
+            PrimitiveType::Int16 => hash_primitive(array, out, |v: i16| hash_native(v as i32)),
+            PrimitiveType::Int32 => hash_primitive(array, out, hash_native::<i32>),
+            PrimitiveType::UInt8 => hash_primitive(array, out, |v: u8| hash_native(v as i32)),
+            PrimitiveType::UInt16 => hash_primitive(array, out, |v: u16| hash_native(v as i32)),
+            PrimitiveType::UInt32 => hash_primitive(array, out, |v: u32| hash_native(v as i32)),
+            _ => false,
+        },
+        (PhysicalType::Primitive(primitive), P::Int64) => match primitive {
+            PrimitiveType::Int64 => hash_primitive(array, out, hash_native::<i64>),
+            PrimitiveType::UInt64 => hash_primitive(array, out, |v: u64| hash_native(v as i64)),
+            _ => false,
+        },
+        (PhysicalType::Binary, P::ByteArray) => hash_bytes(
+            array
+                .as_any()
+                .downcast_ref::<BinaryArray<i32>>()
+                .unwrap()
+                .iter(),
+            out,
+        ),
+        (PhysicalType::LargeBinary, P::ByteArray) => hash_bytes(
+            array
+                .as_any()
+                .downcast_ref::<BinaryArray<i64>>()
+                .unwrap()
+                .iter(),
+            out,
+        ),
+        (PhysicalType::Utf8, P::ByteArray) => hash_bytes(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i32>>()
+                .unwrap()
+                .iter()
+                .map(|v| v.map(str::as_bytes)),
+            out,
+        ),
+        (PhysicalType::LargeUtf8, P::ByteArray) => hash_bytes(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i64>>()
+                .unwrap()
+                .iter()
+                .map(|v| v.map(str::as_bytes)),
+            out,
+        ),
+        (PhysicalType::BinaryView, P::ByteArray) => hash_bytes(
+            array
+                .as_any()
+                .downcast_ref::<BinaryViewArray>()
+                .unwrap()
+                .iter(),
+            out,
+        ),
+        (PhysicalType::Utf8View, P::ByteArray) => hash_bytes(
+            array
+                .as_any()
+                .downcast_ref::<Utf8ViewArray>()
+                .unwrap()
+                .iter()
+                .map(|v| v.map(str::as_bytes)),
+            out,
+        ),
+        (PhysicalType::FixedSizeBinary, P::FixedLenByteArray(_)) => hash_bytes(
+            array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap()
+                .iter(),
+            out,
+        ),
+        // Every dictionary value is hashed, whether it is used or not. Those which aren't only
+        // add false positives.
+        (PhysicalType::Dictionary(key_type), _) => match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            hash_values(array.values().as_ref(), physical_type, out)
+        }),
+        _ => false,
+    }
+}
@@ -14,6 +14,8 @@
 
 mod binary;
 mod binview;
+#[cfg(feature = "bloom_filter")]
+mod bloom_filter;
 mod boolean;
 mod dictionary;
 mod file;
@@ -36,7 +38,7 @@ pub use utils::write_def_levels;
 pub use crate::parquet::compression::{BrotliLevel, CompressionOptions, GzipLevel, ZstdLevel};
 pub use crate::parquet::encoding::Encoding;
 pub use crate::parquet::metadata::{
-    Descriptor, FileMetadata, KeyValue, SchemaDescriptor, ThriftFileMetadata,
+    Descriptor, FileMetadata, KeyValue, SchemaDescriptor, SortingColumn, ThriftFileMetadata,
 };
 pub use crate::parquet::page::{CompressedDataPage, CompressedPage, Page};
 use crate::parquet::schema::Repetition;
@@ -108,6 +110,16 @@ pub enum ChildWriteOptions {
 }
 
 impl ColumnWriteOptions {
+    /// Returns the compression of every leaf, i.e. `compression` unless a leaf overwrites it.
+    pub fn leaf_compressions(&self, compression: CompressionOptions) -> Vec<CompressionOptions> {
+        let mut leaves = Vec::new();
+        self.to_leaves(&mut leaves);
+        leaves
+            .into_iter()
+            .map(|o| o.compression.unwrap_or(compression))
+            .collect()
+    }
+
     pub fn to_leaves<'a>(&'a self, out: &mut Vec<&'a FieldWriteOptions>) {
         match &self.children {
             ChildWriteOptions::Leaf(o) => out.push(o),
@@ -124,6 +136,34 @@ impl ColumnWriteOptions {
 #[derive(Clone)]
 pub struct FieldWriteOptions {
     pub encoding: Encoding,
+    /// The encoding used if `encoding` is [`Encoding::RleDictionary`] but the values aren't
+    /// dictionary encoded.
+    pub fallback_encoding: Encoding,
+    /// Overwrites the compression of the [`WriteOptions`].
+    pub compression: Option<CompressionOptions>,
+    /// Overwrites the statistics of the [`WriteOptions`].
+    pub statistics: Option<StatisticsOptions>,
+    /// Write a split-block bloom filter for every column chunk of this field.
+    pub bloom_filter: Option<BloomFilterOptions>,
+}
+
+/// The sizing of a split-block bloom filter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BloomFilterOptions {
+    /// The number of distinct values to size the filter for. Defaults to the number of distinct
+    /// values in the column chunk.
+    pub ndv: Option<u64>,
+    /// The false positive probability.
+    pub fpp: f64,
+}
+
+impl Default for BloomFilterOptions {
+    fn default() -> Self {
+        Self {
+            ndv: None,
+            fpp: 0.05,
+        }
+    }
 }
 
 impl ColumnWriteOptions {
@@ -139,7 +179,24 @@ impl ColumnWriteOptions {
 
 impl FieldWriteOptions {
     pub fn default_with_encoding(encoding: Encoding) -> Self {
-        Self { encoding }
+        Self {
+            encoding,
+            fallback_encoding: Encoding::Plain,
+            compression: None,
+            statistics: None,
+            bloom_filter: None,
+        }
+    }
+
+    /// Returns the [`WriteOptions`] with the overwrites of this field applied.
+    pub fn apply(&self, mut options: WriteOptions) -> WriteOptions {
+        if let Some(compression) = self.compression {
+            options.compression = compression;
+        }
+        if let Some(statistics) = self.statistics {
+            options.statistics = statistics;
+        }
+        options
     }
 
     pub fn into_default_column_write_options(self) -> ColumnWriteOptions {
@@ -159,6 +216,8 @@ pub struct StructFieldWriteOptions {
 
 use arrow::compute::aggregate::estimated_bytes_size;
 use arrow::match_integer_type;
+#[cfg(feature = "bloom_filter")]
+pub use bloom_filter::array_to_bloom_filters;
 pub use file::FileWriter;
 pub use pages::{Nested, array_to_columns, arrays_to_columns};
 use polars_error::{PolarsResult, polars_bail};
@@ -354,6 +413,7 @@ pub fn array_to_pages(
     options: WriteOptions,
     field_options: &FieldWriteOptions,
 ) -> PolarsResult<DynIter<'static, PolarsResult<Page>>> {
+    let options = field_options.apply(options);
     let mut encoding = field_options.encoding;
     if let ArrowDataType::Dictionary(key_type, _, _) = primitive_array.dtype().to_logical_type() {
         return match_integer_type!(key_type, |$T| {
@@ -376,8 +436,12 @@ pub fn array_to_pages(
             }
         }
 
-        // We didn't succeed, fallback to plain
-        encoding = Encoding::Plain;
+        // We didn't succeed, fallback
+        encoding = field_options.fallback_encoding;
+    }
+
+    if nested.len() > 1 && encoding != Encoding::Plain {
+        polars_bail!(nyi = "Encoding nested parquet columns as {encoding:?}");
     }
 
     let nested = nested.to_vec();
@@ -531,16 +595,22 @@ pub fn array_to_page_simple(
                 encoding,
             );
         },
-        ArrowDataType::Float32 => primitive::array_to_page_plain::<f32, f32>(
-            array.as_any().downcast_ref().unwrap(),
-            options,
-            type_,
-        ),
-        ArrowDataType::Float64 => primitive::array_to_page_plain::<f64, f64>(
-            array.as_any().downcast_ref().unwrap(),
-            options,
-            type_,
-        ),
+        ArrowDataType::Float32 => {
+            return primitive::array_to_page_float::<f32, f32>(
+                array.as_any().downcast_ref().unwrap(),
+                options,
+                type_,
+                encoding,
+            );
+        },
+        ArrowDataType::Float64 => {
+            return primitive::array_to_page_float::<f64, f64>(
+                array.as_any().downcast_ref().unwrap(),
+                options,
+                type_,
+                encoding,
+            );
+        },
         ArrowDataType::LargeUtf8 => {
             let array =
                 polars_compute::cast::cast(array, &ArrowDataType::LargeBinary, Default::default())
@@ -35,9 +35,11 @@ pub fn row_group_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
             .flat_map(move |((array, type_), column_options)| {
                 let encoded_columns =
                     array_to_columns(array, type_, &column_options, options).unwrap();
+                let compressions = column_options.leaf_compressions(options.compression);
                 encoded_columns
                     .into_iter()
-                    .map(|encoded_pages| {
+                    .zip(compressions)
+                    .map(|(encoded_pages, compression)| {
                         let pages = encoded_pages;
 
                         let pages = DynIter::new(
@@ -46,8 +48,8 @@ pub fn row_group_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
                                 .map(|x| x.map_err(|e| ParquetError::oos(e.to_string()))),
                         );
 
-                        let compressed_pages = Compressor::new(pages, options.compression, vec![])
-                            .map_err(to_compute_err);
+                        let compressed_pages =
+                            Compressor::new(pages, compression, vec![]).map_err(to_compute_err);
                         Ok(DynStreamingIterator::new(compressed_pages))
                     })
                     .collect::<Vec<_>>()
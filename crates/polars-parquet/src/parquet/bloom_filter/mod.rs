@@ -1,11 +1,11 @@
-//! API to read and use bloom filters
+//! API to read, write and use bloom filters
 mod hash;
 mod read;
 mod split_block;
 
 pub use hash::{hash_byte, hash_native};
-pub use read::read;
-pub use split_block::{insert, is_in_set};
+pub use read::{read, read_from_bytes};
+pub use split_block::{insert, is_in_set, optimal_num_bytes};
 
 #[cfg(test)]
 mod tests {
@@ -68,4 +68,30 @@ mod tests {
         ];
         assert_eq!(bitset, expected);
     }
+
+    #[test]
+    fn sizing() {
+        assert_eq!(optimal_num_bytes(0, 0.05), 32);
+        // 1M distinct values at 5% need ~860 KiB, rounded up to a power of two.
+        assert_eq!(optimal_num_bytes(1_000_000, 0.05), 1024 * 1024);
+        assert_eq!(optimal_num_bytes(usize::MAX, 0.05), 128 * 1024 * 1024);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut bitset = vec![0; optimal_num_bytes(100, 0.01)];
+        for a in 0..100i32 {
+            insert(&mut bitset, hash_native(a));
+        }
+
+        let mut bytes = vec![];
+        crate::parquet::write::write_bloom_filter(&mut bytes, &bitset).unwrap();
+
+        let mut read_bitset = vec![];
+        read_from_bytes(&bytes, &mut read_bitset).unwrap();
+        assert_eq!(read_bitset, bitset);
+        assert!((0..100i32).all(|a| is_in_set(&read_bitset, hash_native(a))));
+
+        assert!(read_from_bytes(&bytes[..bytes.len() - 1], &mut read_bitset).is_err());
+    }
 }
@@ -6,7 +6,7 @@ use polars_parquet_format::{
     Uncompressed,
 };
 
-use crate::parquet::error::ParquetResult;
+use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::ColumnChunkMetadata;
 
 /// Reads the bloom filter associated to [`ColumnChunkMetadata`] into `bitset`.
@@ -15,7 +15,7 @@ use crate::parquet::metadata::ColumnChunkMetadata;
 /// Errors if the column contains no metadata or the filter can't be read or deserialized.
 pub fn read<R: Read + Seek>(
     column_metadata: &ColumnChunkMetadata,
-    mut reader: &mut R,
+    reader: &mut R,
     bitset: &mut Vec<u8>,
 ) -> ParquetResult<()> {
     let offset = column_metadata.metadata().bloom_filter_offset;
@@ -28,24 +28,45 @@ pub fn read<R: Read + Seek>(
     };
     reader.seek(SeekFrom::Start(offset))?;
 
+    read_header_and_bitset(reader, bitset)?;
+    Ok(())
+}
+
+/// Reads a bloom filter into `bitset` from `bytes`, which start at the offset of the filter, e.g.
+/// because they were fetched separately from the rest of the file.
+/// Results in an empty `bitset` if the algorithm is not supported.
+/// # Error
+/// Errors if the filter can't be deserialized or `bytes` doesn't contain all of it.
+pub fn read_from_bytes(mut bytes: &[u8], bitset: &mut Vec<u8>) -> ParquetResult<()> {
+    let num_bytes = read_header_and_bitset(&mut bytes, bitset)?;
+    if num_bytes != bitset.len() {
+        return Err(ParquetError::oos("bloom filter is truncated"));
+    }
+    Ok(())
+}
+
+/// Returns the size of the bitset announced by the header, the bitset may be shorter if the
+/// reader ended early.
+fn read_header_and_bitset<R: Read>(
+    mut reader: &mut R,
+    bitset: &mut Vec<u8>,
+) -> ParquetResult<usize> {
     // deserialize header
     let mut prot = TCompactInputProtocol::new(&mut reader, usize::MAX); // max is ok since `BloomFilterHeader` never allocates
     let header = BloomFilterHeader::read_from_in_protocol(&mut prot)?;
 
+    bitset.clear();
     if header.algorithm != BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}) {
-        bitset.clear();
-        return Ok(());
+        return Ok(0);
     }
     if header.compression != BloomFilterCompression::UNCOMPRESSED(Uncompressed {}) {
-        bitset.clear();
-        return Ok(());
+        return Ok(0);
     }
 
     let length: usize = header.num_bytes.try_into()?;
 
-    bitset.clear();
     bitset.try_reserve(length)?;
     reader.by_ref().take(length as u64).read_to_end(bitset)?;
 
-    Ok(())
+    Ok(length)
 }
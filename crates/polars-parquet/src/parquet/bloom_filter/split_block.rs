@@ -3,6 +3,24 @@ const SALT: [u32; 8] = [
     1203114875, 1150766481, 2284105051, 2729912477, 1884591559, 770785867, 2667333959, 1550580529,
 ];
 
+/// The smallest and largest bitset we create, in bytes.
+const MIN_NUM_BYTES: usize = 32;
+const MAX_NUM_BYTES: usize = 128 * 1024 * 1024;
+
+/// Returns the number of bytes of a bitset holding `ndv` distinct values with a false positive
+/// probability of `fpp`.
+///
+/// The size is rounded up to a power of two and clamped to `[32 B, 128 MiB]`, as is done by other
+/// writers.
+pub fn optimal_num_bytes(ndv: usize, fpp: f64) -> usize {
+    // https://github.com/apache/parquet-format/blob/master/BloomFilter.md#sizing-an-sbbf
+    let num_bits = -8.0 * ndv as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+    let num_bytes = (num_bits / 8.0).ceil() as usize;
+    num_bytes
+        .clamp(MIN_NUM_BYTES, MAX_NUM_BYTES)
+        .next_power_of_two()
+}
+
 fn hash_to_block_index(hash: u64, len: usize) -> usize {
     let number_of_blocks = len as u64 / 32;
     let low_hash = hash >> 32;
@@ -0,0 +1,18 @@
+use crate::parquet::types::NativeType;
+
+/// Encodes `data` according to BYTE_STREAM_SPLIT, i.e. the `k`-th bytes of all values are
+/// written before the `k + 1`-th bytes.
+pub fn encode<T: NativeType>(data: &[T], buffer: &mut Vec<u8>) {
+    let element_size = size_of::<T>();
+    let num_elements = data.len();
+    let start = buffer.len();
+    buffer.resize(start + size_of_val(data), 0);
+    let out = &mut buffer[start..];
+
+    for (i, v) in data.iter().enumerate() {
+        let value_bytes = v.to_le_bytes();
+        for (n, byte) in value_bytes.as_ref().iter().enumerate().take(element_size) {
+            out[(num_elements * n) + i] = *byte;
+        }
+    }
+}
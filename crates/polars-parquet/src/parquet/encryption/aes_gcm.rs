@@ -0,0 +1,192 @@
+//! The `AES_GCM_V1` cipher. A module is serialized as its length (4 bytes, little endian),
+//! followed by the nonce, the ciphertext and the authentication tag.
+
+use crate::parquet::error::{ParquetError, ParquetResult};
+
+pub(crate) const SIZE_LEN: usize = 4;
+pub(crate) const NONCE_LEN: usize = 12;
+pub(crate) const TAG_LEN: usize = 16;
+
+#[cfg(feature = "encryption")]
+mod imp {
+    use ring::aead::{AES_128_GCM, AES_256_GCM, Aad, LessSafeKey, Nonce, UnboundKey};
+    use ring::rand::{SecureRandom, SystemRandom};
+
+    use super::{NONCE_LEN, TAG_LEN};
+    use crate::parquet::error::{ParquetError, ParquetResult};
+
+    fn key(key: &[u8]) -> ParquetResult<LessSafeKey> {
+        let algorithm = match key.len() {
+            16 => &AES_128_GCM,
+            32 => &AES_256_GCM,
+            n => {
+                return Err(ParquetError::InvalidParameter(format!(
+                    "AES-GCM keys must be 16 or 32 bytes long, got {n} bytes"
+                )));
+            },
+        };
+        let key = UnboundKey::new(algorithm, key)
+            .map_err(|_| ParquetError::InvalidParameter("invalid AES-GCM key".to_string()))?;
+        Ok(LessSafeKey::new(key))
+    }
+
+    pub fn random_bytes(out: &mut [u8]) -> ParquetResult<()> {
+        SystemRandom::new()
+            .fill(out)
+            .map_err(|_| ParquetError::oos("failed to generate random bytes"))
+    }
+
+    pub fn seal(
+        key_bytes: &[u8],
+        nonce: [u8; NONCE_LEN],
+        aad: &[u8],
+        in_out: &mut [u8],
+    ) -> ParquetResult<[u8; TAG_LEN]> {
+        let nonce = Nonce::assume_unique_for_key(nonce);
+        let tag = key(key_bytes)?
+            .seal_in_place_separate_tag(nonce, Aad::from(aad), in_out)
+            .map_err(|_| ParquetError::oos("failed to encrypt module"))?;
+        Ok(tag.as_ref().try_into().unwrap())
+    }
+
+    pub fn open(
+        key_bytes: &[u8],
+        nonce: [u8; NONCE_LEN],
+        aad: &[u8],
+        in_out: &mut [u8],
+    ) -> ParquetResult<usize> {
+        let nonce = Nonce::assume_unique_for_key(nonce);
+        key(key_bytes)?
+            .open_in_place(nonce, Aad::from(aad), in_out)
+            .map(|plaintext| plaintext.len())
+            .map_err(|_| {
+                ParquetError::oos("failed to decrypt module, the key is wrong or the file corrupt")
+            })
+    }
+}
+
+#[cfg(not(feature = "encryption"))]
+mod imp {
+    use super::{NONCE_LEN, TAG_LEN};
+    use crate::parquet::error::{Feature, ParquetError, ParquetResult};
+
+    fn not_active() -> ParquetError {
+        ParquetError::FeatureNotActive(
+            Feature::Encryption,
+            "encrypt or decrypt Parquet modules".to_string(),
+        )
+    }
+
+    pub fn random_bytes(_out: &mut [u8]) -> ParquetResult<()> {
+        Err(not_active())
+    }
+
+    pub fn seal(
+        _key: &[u8],
+        _nonce: [u8; NONCE_LEN],
+        _aad: &[u8],
+        _in_out: &mut [u8],
+    ) -> ParquetResult<[u8; TAG_LEN]> {
+        Err(not_active())
+    }
+
+    pub fn open(
+        _key: &[u8],
+        _nonce: [u8; NONCE_LEN],
+        _aad: &[u8],
+        _in_out: &mut [u8],
+    ) -> ParquetResult<usize> {
+        Err(not_active())
+    }
+}
+
+pub(crate) use imp::random_bytes;
+
+/// Encrypts `plaintext` with a random nonce and appends the module to `out`.
+pub(crate) fn encrypt(
+    key: &[u8],
+    aad: &[u8],
+    plaintext: &[u8],
+    out: &mut Vec<u8>,
+) -> ParquetResult<()> {
+    let mut nonce = [0u8; NONCE_LEN];
+    random_bytes(&mut nonce)?;
+
+    let len: u32 = (NONCE_LEN + plaintext.len() + TAG_LEN)
+        .try_into()
+        .map_err(|_| ParquetError::oos("An encrypted module can contain at most u32::MAX bytes"))?;
+    out.reserve(SIZE_LEN + len as usize);
+    out.extend_from_slice(&len.to_le_bytes());
+    out.extend_from_slice(&nonce);
+    let start = out.len();
+    out.extend_from_slice(plaintext);
+    let tag = imp::seal(key, nonce, aad, &mut out[start..])?;
+    out.extend_from_slice(&tag);
+    Ok(())
+}
+
+/// Decrypts a module, given without its length.
+pub(crate) fn decrypt(key: &[u8], aad: &[u8], module: &[u8]) -> ParquetResult<Vec<u8>> {
+    if module.len() < NONCE_LEN + TAG_LEN {
+        return Err(ParquetError::oos(
+            "An encrypted module must contain at least a nonce and a tag",
+        ));
+    }
+    let (nonce, ciphertext) = module.split_at(NONCE_LEN);
+    let mut buffer = ciphertext.to_vec();
+    let len = imp::open(key, nonce.try_into().unwrap(), aad, &mut buffer)?;
+    buffer.truncate(len);
+    Ok(buffer)
+}
+
+/// Computes the authentication tag of `plaintext` under `nonce`, used to sign plaintext footers.
+pub(crate) fn tag(
+    key: &[u8],
+    nonce: [u8; NONCE_LEN],
+    aad: &[u8],
+    plaintext: &[u8],
+) -> ParquetResult<[u8; TAG_LEN]> {
+    let mut buffer = plaintext.to_vec();
+    imp::seal(key, nonce, aad, &mut buffer)
+}
+
+/// Returns the module that starts at `bytes`, without its length.
+pub(crate) fn split_module(bytes: &[u8]) -> ParquetResult<&[u8]> {
+    if bytes.len() < SIZE_LEN {
+        return Err(ParquetError::oos(
+            "An encrypted module must start with its length",
+        ));
+    }
+    let len = module_len(bytes[..SIZE_LEN].try_into().unwrap());
+    bytes[SIZE_LEN..]
+        .get(..len)
+        .ok_or_else(|| ParquetError::oos("The encrypted module is shorter than its length"))
+}
+
+pub(crate) fn module_len(len: [u8; SIZE_LEN]) -> usize {
+    u32::from_le_bytes(len) as usize
+}
+
+#[cfg(all(test, feature = "encryption"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() -> ParquetResult<()> {
+        let key = [7u8; 16];
+        let mut module = vec![];
+        encrypt(&key, b"aad", b"some plaintext", &mut module)?;
+        assert_eq!(module.len(), SIZE_LEN + NONCE_LEN + 14 + TAG_LEN);
+
+        let module = split_module(&module)?;
+        assert_eq!(decrypt(&key, b"aad", module)?, b"some plaintext");
+        assert!(decrypt(&key, b"other aad", module).is_err());
+        assert!(decrypt(&[8u8; 16], b"aad", module).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_key_length() {
+        assert!(encrypt(&[0u8; 24], b"", b"", &mut vec![]).is_err());
+    }
+}
@@ -0,0 +1,457 @@
+//! Parquet modular encryption, see
+//! <https://github.com/apache/parquet-format/blob/master/Encryption.md>.
+//!
+//! Only the `AES_GCM_V1` algorithm is supported. Files are written in encrypted footer mode, and
+//! both encrypted and plaintext (signed) footers can be read. The ciphers require the
+//! `encryption` feature.
+mod aes_gcm;
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use polars_parquet_format::thrift::protocol::{TCompactInputProtocol, TCompactOutputProtocol};
+use polars_parquet_format::{
+    AesGcmV1, ColumnChunk, ColumnCryptoMetaData, ColumnMetaData, EncryptionAlgorithm,
+    EncryptionWithColumnKey, EncryptionWithFooterKey, FileCryptoMetaData,
+};
+use polars_utils::aliases::PlHashMap;
+
+pub(crate) use self::aes_gcm::{NONCE_LEN, SIZE_LEN, TAG_LEN, module_len, split_module};
+use crate::parquet::error::{ParquetError, ParquetResult};
+
+/// The length of the unique part of the AAD of a file.
+const AAD_FILE_UNIQUE_LEN: usize = 8;
+
+/// A key to encrypt the footer or columns of a Parquet file with.
+#[derive(Clone)]
+pub struct EncryptionKey {
+    /// An AES key of 16 or 32 bytes.
+    pub key: Vec<u8>,
+    /// Stored in the file for readers to retrieve the key with, e.g. a key id.
+    pub key_metadata: Option<Vec<u8>>,
+}
+
+impl Debug for EncryptionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EncryptionKey")
+            .field("key", &"<redacted>")
+            .field("key_metadata", &self.key_metadata)
+            .finish()
+    }
+}
+
+/// How to encrypt a Parquet file.
+#[derive(Clone, Debug)]
+pub struct FileEncryptionProperties {
+    /// Encrypts the footer, and all columns if `column_keys` is empty.
+    pub footer_key: EncryptionKey,
+    /// The keys of the encrypted columns, by the path of a field. The key of a field applies to
+    /// all of its leaves. Columns without a key are not encrypted.
+    pub column_keys: Vec<(Vec<String>, EncryptionKey)>,
+    /// Prepended to the AAD of every module, e.g. to bind the file to its path.
+    pub aad_prefix: Option<Vec<u8>>,
+    /// Whether to store the `aad_prefix` in the file. If not, readers have to supply it.
+    pub store_aad_prefix: bool,
+}
+
+/// Retrieves the keys of an encrypted Parquet file from the key metadata stored in it.
+pub trait KeyRetriever: Send + Sync {
+    fn retrieve_key(&self, key_metadata: &[u8]) -> ParquetResult<Vec<u8>>;
+}
+
+/// How to decrypt a Parquet file.
+#[derive(Clone)]
+pub struct FileDecryptionProperties {
+    pub key_retriever: Arc<dyn KeyRetriever>,
+    /// Must be given if the file was written without storing its AAD prefix.
+    pub aad_prefix: Option<Vec<u8>>,
+}
+
+impl Debug for FileDecryptionProperties {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileDecryptionProperties")
+            .field("aad_prefix", &self.aad_prefix)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The type of a module, which is part of its AAD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum ModuleType {
+    Footer = 0,
+    ColumnMetaData = 1,
+    DataPage = 2,
+    DictionaryPage = 3,
+    DataPageHeader = 4,
+    DictionaryPageHeader = 5,
+}
+
+fn ordinal(value: usize, name: &str) -> ParquetResult<[u8; 2]> {
+    i16::try_from(value).map(i16::to_le_bytes).map_err(|_| {
+        ParquetError::not_supported(format!(
+            "Encrypted files can contain at most {} {name}s",
+            i16::MAX
+        ))
+    })
+}
+
+/// The key and AAD of the modules of an encrypted column chunk.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ColumnCipher {
+    key: Arc<[u8]>,
+    file_aad: Arc<[u8]>,
+    row_group: [u8; 2],
+    column: [u8; 2],
+}
+
+impl Debug for ColumnCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColumnCipher")
+            .field("row_group", &i16::from_le_bytes(self.row_group))
+            .field("column", &i16::from_le_bytes(self.column))
+            .finish_non_exhaustive()
+    }
+}
+
+impl ColumnCipher {
+    fn try_new(
+        key: Arc<[u8]>,
+        file_aad: Arc<[u8]>,
+        row_group: usize,
+        column: usize,
+    ) -> ParquetResult<Self> {
+        Ok(Self {
+            key,
+            file_aad,
+            row_group: ordinal(row_group, "row group")?,
+            column: ordinal(column, "column")?,
+        })
+    }
+
+    /// The AAD of a module. Only data pages and their headers have a page ordinal.
+    fn aad(&self, module: ModuleType, page: Option<usize>) -> ParquetResult<Vec<u8>> {
+        let mut aad = Vec::with_capacity(self.file_aad.len() + 7);
+        aad.extend_from_slice(&self.file_aad);
+        aad.push(module as u8);
+        aad.extend_from_slice(&self.row_group);
+        aad.extend_from_slice(&self.column);
+        if let Some(page) = page {
+            aad.extend_from_slice(&ordinal(page, "page")?);
+        }
+        Ok(aad)
+    }
+
+    /// Encrypts `plaintext` and appends the module, including its length, to `out`.
+    pub(crate) fn encrypt(
+        &self,
+        module: ModuleType,
+        page: Option<usize>,
+        plaintext: &[u8],
+        out: &mut Vec<u8>,
+    ) -> ParquetResult<()> {
+        aes_gcm::encrypt(&self.key, &self.aad(module, page)?, plaintext, out)
+    }
+
+    /// Decrypts a module, given without its length.
+    pub(crate) fn decrypt(
+        &self,
+        module: ModuleType,
+        page: Option<usize>,
+        ciphertext: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        aes_gcm::decrypt(&self.key, &self.aad(module, page)?, ciphertext)
+    }
+}
+
+fn footer_aad(file_aad: &[u8]) -> Vec<u8> {
+    let mut aad = file_aad.to_vec();
+    aad.push(ModuleType::Footer as u8);
+    aad
+}
+
+/// Encrypts the modules of a file that is being written.
+pub struct FileEncryptor {
+    properties: FileEncryptionProperties,
+    footer_key: Arc<[u8]>,
+    column_keys: Vec<Arc<[u8]>>,
+    aad_file_unique: Vec<u8>,
+    file_aad: Arc<[u8]>,
+}
+
+impl FileEncryptor {
+    pub fn try_new(properties: FileEncryptionProperties) -> ParquetResult<Self> {
+        let mut aad_file_unique = vec![0u8; AAD_FILE_UNIQUE_LEN];
+        aes_gcm::random_bytes(&mut aad_file_unique)?;
+
+        let mut file_aad = properties.aad_prefix.clone().unwrap_or_default();
+        file_aad.extend_from_slice(&aad_file_unique);
+
+        let footer_key: Arc<[u8]> = properties.footer_key.key.clone().into();
+        let column_keys = properties
+            .column_keys
+            .iter()
+            .map(|(_, key)| key.key.clone().into())
+            .collect();
+
+        let encryptor = Self {
+            properties,
+            footer_key,
+            column_keys,
+            aad_file_unique,
+            file_aad: file_aad.into(),
+        };
+        // Fail early on invalid keys.
+        encryptor.encrypt_footer(&[], &mut vec![])?;
+        for key in &encryptor.column_keys {
+            aes_gcm::encrypt(key, &[], &[], &mut vec![])?;
+        }
+        Ok(encryptor)
+    }
+
+    /// Returns the index of the column key of a leaf, or `None` if the footer key is used.
+    /// Returns `Err` if the leaf is not encrypted.
+    fn column_key(&self, path_in_schema: &[String]) -> Result<Option<usize>, ()> {
+        if self.properties.column_keys.is_empty() {
+            return Ok(None);
+        }
+        self.properties
+            .column_keys
+            .iter()
+            .position(|(path, _)| path_in_schema.starts_with(path))
+            .map(Some)
+            .ok_or(())
+    }
+
+    pub fn is_encrypted(&self, path_in_schema: &[String]) -> bool {
+        self.column_key(path_in_schema).is_ok()
+    }
+
+    /// Returns the cipher of a column chunk, or `None` if the column is not encrypted.
+    pub fn column_cipher(
+        &self,
+        path_in_schema: &[String],
+        row_group: usize,
+        column: usize,
+    ) -> ParquetResult<Option<ColumnCipher>> {
+        let Ok(key) = self.column_key(path_in_schema) else {
+            return Ok(None);
+        };
+        let key = key.map_or(&self.footer_key, |i| &self.column_keys[i]);
+        ColumnCipher::try_new(key.clone(), self.file_aad.clone(), row_group, column).map(Some)
+    }
+
+    /// The [`ColumnCryptoMetaData`] of a column chunk, or `None` if it is not encrypted.
+    pub fn column_crypto_metadata(
+        &self,
+        path_in_schema: &[String],
+    ) -> Option<ColumnCryptoMetaData> {
+        match self.column_key(path_in_schema).ok()? {
+            None => Some(ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(
+                EncryptionWithFooterKey {},
+            )),
+            Some(i) => Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(
+                EncryptionWithColumnKey {
+                    path_in_schema: path_in_schema.to_vec(),
+                    key_metadata: self.properties.column_keys[i].1.key_metadata.clone(),
+                },
+            )),
+        }
+    }
+
+    /// Replaces the [`ColumnMetaData`] of a column chunk encrypted with a column key by its
+    /// encrypted form, as readers without that key must not see it.
+    pub fn encrypt_column_metadata(
+        &self,
+        column_chunk: &mut ColumnChunk,
+        row_group: usize,
+        column: usize,
+    ) -> ParquetResult<()> {
+        let Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(crypto)) =
+            &column_chunk.crypto_metadata
+        else {
+            return Ok(());
+        };
+        let Some(metadata) = column_chunk.meta_data.take() else {
+            return Ok(());
+        };
+        let cipher = self
+            .column_cipher(&crypto.path_in_schema, row_group, column)?
+            .unwrap();
+
+        let mut serialized = vec![];
+        metadata.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut serialized))?;
+        let mut encrypted = vec![];
+        cipher.encrypt(
+            ModuleType::ColumnMetaData,
+            None,
+            &serialized,
+            &mut encrypted,
+        )?;
+        column_chunk.encrypted_column_metadata = Some(encrypted);
+        Ok(())
+    }
+
+    /// The [`FileCryptoMetaData`] that precedes the encrypted footer.
+    pub fn file_crypto_metadata(&self) -> FileCryptoMetaData {
+        let store_aad_prefix = self.properties.store_aad_prefix;
+        let aad_prefix = self.properties.aad_prefix.as_ref();
+        FileCryptoMetaData {
+            encryption_algorithm: EncryptionAlgorithm::AESGCMV1(AesGcmV1 {
+                aad_prefix: aad_prefix.filter(|_| store_aad_prefix).cloned(),
+                aad_file_unique: Some(self.aad_file_unique.clone()),
+                supply_aad_prefix: Some(aad_prefix.is_some() && !store_aad_prefix),
+            }),
+            key_metadata: self.properties.footer_key.key_metadata.clone(),
+        }
+    }
+
+    /// Encrypts the serialized footer and appends the module to `out`.
+    pub fn encrypt_footer(&self, footer: &[u8], out: &mut Vec<u8>) -> ParquetResult<()> {
+        aes_gcm::encrypt(&self.footer_key, &footer_aad(&self.file_aad), footer, out)
+    }
+}
+
+/// Decrypts the modules of a file that is being read.
+///
+/// Keys are retrieved once, and then shared by all modules they encrypt.
+pub struct FileDecryptor {
+    properties: FileDecryptionProperties,
+    file_aad: Arc<[u8]>,
+    footer_key_metadata: Option<Vec<u8>>,
+    footer_key: OnceLock<Arc<[u8]>>,
+    /// The retrieved column keys, by their key metadata.
+    column_keys: Mutex<PlHashMap<Vec<u8>, Arc<[u8]>>>,
+}
+
+impl Debug for FileDecryptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileDecryptor")
+            .field("properties", &self.properties)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FileDecryptor {
+    pub fn try_new(
+        properties: &FileDecryptionProperties,
+        algorithm: &EncryptionAlgorithm,
+        footer_key_metadata: Option<&[u8]>,
+    ) -> ParquetResult<Self> {
+        let EncryptionAlgorithm::AESGCMV1(algorithm) = algorithm else {
+            return Err(ParquetError::not_supported(
+                "Decrypting files encrypted with AES_GCM_CTR_V1",
+            ));
+        };
+
+        let aad_prefix = match (&algorithm.aad_prefix, &properties.aad_prefix) {
+            (Some(stored), Some(supplied)) if stored != supplied => {
+                return Err(ParquetError::InvalidParameter(
+                    "The supplied AAD prefix differs from the one stored in the file".to_string(),
+                ));
+            },
+            (Some(prefix), _) | (None, Some(prefix)) => prefix.as_slice(),
+            (None, None) if algorithm.supply_aad_prefix == Some(true) => {
+                return Err(ParquetError::InvalidParameter(
+                    "The file requires an AAD prefix to be supplied".to_string(),
+                ));
+            },
+            (None, None) => &[],
+        };
+        let mut file_aad = aad_prefix.to_vec();
+        file_aad.extend_from_slice(algorithm.aad_file_unique.as_deref().unwrap_or_default());
+
+        Ok(Self {
+            properties: properties.clone(),
+            file_aad: file_aad.into(),
+            footer_key_metadata: footer_key_metadata.map(<[u8]>::to_vec),
+            footer_key: OnceLock::new(),
+            column_keys: Mutex::default(),
+        })
+    }
+
+    fn footer_key(&self) -> ParquetResult<Arc<[u8]>> {
+        if let Some(key) = self.footer_key.get() {
+            return Ok(key.clone());
+        }
+        let key_metadata = self.footer_key_metadata.as_deref().unwrap_or_default();
+        let key = self.properties.key_retriever.retrieve_key(key_metadata)?;
+        Ok(self.footer_key.get_or_init(|| key.into()).clone())
+    }
+
+    fn column_key(&self, key_metadata: &[u8]) -> ParquetResult<Arc<[u8]>> {
+        let mut column_keys = self.column_keys.lock().unwrap();
+        if let Some(key) = column_keys.get(key_metadata) {
+            return Ok(key.clone());
+        }
+        let key: Arc<[u8]> = self
+            .properties
+            .key_retriever
+            .retrieve_key(key_metadata)?
+            .into();
+        column_keys.insert(key_metadata.to_vec(), key.clone());
+        Ok(key)
+    }
+
+    /// Decrypts the footer module that starts at `bytes`.
+    pub fn decrypt_footer(&self, bytes: &[u8]) -> ParquetResult<Vec<u8>> {
+        let key = self.footer_key()?;
+        aes_gcm::decrypt(&key, &footer_aad(&self.file_aad), split_module(bytes)?)
+    }
+
+    /// Verifies the signature of a plaintext footer, i.e. its nonce and authentication tag.
+    pub fn verify_footer_signature(&self, footer: &[u8], signature: &[u8]) -> ParquetResult<()> {
+        let Some(signature) = signature.get(..NONCE_LEN + TAG_LEN) else {
+            return Err(ParquetError::oos(
+                "A plaintext footer must be followed by its signature",
+            ));
+        };
+        let (nonce, tag) = signature.split_at(NONCE_LEN);
+        let key = self.footer_key()?;
+        let expected = aes_gcm::tag(
+            &key,
+            nonce.try_into().unwrap(),
+            &footer_aad(&self.file_aad),
+            footer,
+        )?;
+        if expected != tag {
+            return Err(ParquetError::oos(
+                "The signature of the plaintext footer does not match",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the cipher of an encrypted column chunk, together with its decrypted
+    /// [`ColumnMetaData`] if it is encrypted with a column key.
+    pub fn decrypt_column_chunk(
+        &self,
+        column_chunk: &ColumnChunk,
+        row_group: usize,
+        column: usize,
+    ) -> ParquetResult<(ColumnCipher, Option<ColumnMetaData>)> {
+        let key = match &column_chunk.crypto_metadata {
+            Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(crypto)) => {
+                let key_metadata = crypto.key_metadata.as_deref().unwrap_or_default();
+                self.column_key(key_metadata).map_err(|e| {
+                    ParquetError::InvalidParameter(format!(
+                        "failed to retrieve the key of encrypted column '{}': {e}",
+                        crypto.path_in_schema.join(".")
+                    ))
+                })?
+            },
+            _ => self.footer_key()?,
+        };
+        let cipher = ColumnCipher::try_new(key, self.file_aad.clone(), row_group, column)?;
+
+        let Some(encrypted) = &column_chunk.encrypted_column_metadata else {
+            return Ok((cipher, None));
+        };
+        let metadata =
+            cipher.decrypt(ModuleType::ColumnMetaData, None, split_module(encrypted)?)?;
+        let mut reader = metadata.as_slice();
+        let mut protocol = TCompactInputProtocol::new(&mut reader, metadata.len() * 2 + 1024);
+        let metadata = ColumnMetaData::read_from_in_protocol(&mut protocol)?;
+        Ok((cipher, Some(metadata)))
+    }
+}
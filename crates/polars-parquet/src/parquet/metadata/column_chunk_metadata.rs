@@ -1,7 +1,10 @@
+use std::sync::{Arc, OnceLock};
+
 use polars_parquet_format::{ColumnChunk, ColumnMetaData, Encoding};
 
 use super::column_descriptor::ColumnDescriptor;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::{ColumnCipher, FileDecryptor};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::schema::types::PhysicalType;
 use crate::parquet::statistics::Statistics;
@@ -20,6 +23,14 @@ mod serde_types {
 #[cfg(feature = "serde")]
 use serde_types::*;
 
+/// What is needed to decrypt an encrypted column chunk once it is read.
+#[derive(Debug, Clone)]
+pub(crate) struct ColumnDecryption {
+    pub decryptor: Arc<FileDecryptor>,
+    pub row_group: usize,
+    pub column: usize,
+}
+
 /// Metadata for a column chunk.
 ///
 /// This contains the `ColumnDescriptor` associated with the chunk so that deserializers have
@@ -36,6 +47,15 @@ pub struct ColumnChunkMetadata {
     )]
     column_chunk: ColumnChunk,
     column_descr: ColumnDescriptor,
+    /// Decrypts an encrypted column chunk in [`ColumnChunkMetadata::decrypt`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    decryption: Option<ColumnDecryption>,
+    /// Decrypts the pages of an encrypted column chunk.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    cipher: OnceLock<ColumnCipher>,
+    /// The metadata of a column chunk encrypted with a column key.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    decrypted_metadata: OnceLock<ColumnMetaData>,
 }
 
 #[cfg(feature = "serde")]
@@ -76,6 +96,9 @@ impl ColumnChunkMetadata {
         Self {
             column_chunk,
             column_descr,
+            decryption: None,
+            cipher: OnceLock::new(),
+            decrypted_metadata: OnceLock::new(),
         }
     }
 
@@ -98,8 +121,14 @@ impl ColumnChunkMetadata {
     }
 
     /// The column's [`ColumnMetaData`]
+    ///
+    /// # Panics
+    /// If the column chunk is encrypted with a column key and was not decrypted yet.
     pub fn metadata(&self) -> &ColumnMetaData {
-        self.column_chunk.meta_data.as_ref().unwrap()
+        self.decrypted_metadata
+            .get()
+            .or(self.column_chunk.meta_data.as_ref())
+            .expect("the metadata of an encrypted column chunk is only known once it is decrypted")
     }
 
     /// The [`ColumnDescriptor`] for this column. This descriptor contains the physical and logical type
@@ -108,6 +137,39 @@ impl ColumnChunkMetadata {
         &self.column_descr
     }
 
+    /// Whether the pages of this column chunk are encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        self.column_chunk.crypto_metadata.is_some()
+    }
+
+    /// The cipher of this column chunk, if it is encrypted and was decrypted.
+    pub fn cipher(&self) -> Option<&ColumnCipher> {
+        self.cipher.get()
+    }
+
+    /// Retrieves the key of an encrypted column chunk and decrypts its [`ColumnMetaData`], if
+    /// that is encrypted with a column key. This must be done before the column chunk is read,
+    /// and does nothing if it is not encrypted or its file was read without decryption keys.
+    pub fn decrypt(&self) -> ParquetResult<()> {
+        let Some(decryption) = &self.decryption else {
+            return Ok(());
+        };
+        if self.cipher.get().is_some() {
+            return Ok(());
+        }
+        let (cipher, metadata) = decryption.decryptor.decrypt_column_chunk(
+            &self.column_chunk,
+            decryption.row_group,
+            decryption.column,
+        )?;
+        if let Some(metadata) = metadata {
+            validate_metadata(&metadata)?;
+            let _ = self.decrypted_metadata.set(metadata);
+        }
+        let _ = self.cipher.set(cipher);
+        Ok(())
+    }
+
     /// The [`PhysicalType`] of this column.
     pub fn physical_type(&self) -> PhysicalType {
         self.column_descr.descriptor.primitive_type.physical_type
@@ -172,28 +234,47 @@ impl ColumnChunkMetadata {
         column_metadata_byte_range(self.metadata())
     }
 
+    /// Returns the offset and length in bytes of the [column index] of this column chunk within
+    /// the file, if it was written.
+    ///
+    /// [column index]: https://github.com/apache/parquet-format/blob/master/PageIndex.md
+    pub fn column_index_byte_range(&self) -> Option<core::ops::Range<u64>> {
+        index_byte_range(
+            self.column_chunk.column_index_offset,
+            self.column_chunk.column_index_length,
+        )
+    }
+
+    /// Returns the offset and length in bytes of the [offset index] of this column chunk within
+    /// the file, if it was written.
+    ///
+    /// [offset index]: https://github.com/apache/parquet-format/blob/master/PageIndex.md
+    pub fn offset_index_byte_range(&self) -> Option<core::ops::Range<u64>> {
+        index_byte_range(
+            self.column_chunk.offset_index_offset,
+            self.column_chunk.offset_index_length,
+        )
+    }
+
     /// Method to convert from Thrift.
     pub(crate) fn try_from_thrift(
         column_descr: ColumnDescriptor,
         column_chunk: ColumnChunk,
+        decryption: Option<ColumnDecryption>,
     ) -> ParquetResult<Self> {
-        // validate metadata
-        if let Some(meta) = &column_chunk.meta_data {
-            let _: u64 = meta.total_compressed_size.try_into()?;
-
-            if let Some(offset) = meta.dictionary_page_offset {
-                let _: u64 = offset.try_into()?;
-            }
-            let _: u64 = meta.data_page_offset.try_into()?;
-
-            let _: Compression = meta.codec.try_into()?;
-        } else {
-            return Err(ParquetError::oos("Column chunk requires metadata"));
+        match &column_chunk.meta_data {
+            Some(meta) => validate_metadata(meta)?,
+            // Metadata encrypted with a column key is validated once it is decrypted.
+            None if decryption.is_some() && column_chunk.encrypted_column_metadata.is_some() => {},
+            None => return Err(ParquetError::oos("Column chunk requires metadata")),
         }
 
         Ok(Self {
             column_chunk,
             column_descr,
+            decryption,
+            cipher: OnceLock::new(),
+            decrypted_metadata: OnceLock::new(),
         })
     }
 
@@ -203,6 +284,18 @@ impl ColumnChunkMetadata {
     }
 }
 
+fn validate_metadata(meta: &ColumnMetaData) -> ParquetResult<()> {
+    let _: u64 = meta.total_compressed_size.try_into()?;
+
+    if let Some(offset) = meta.dictionary_page_offset {
+        let _: u64 = offset.try_into()?;
+    }
+    let _: u64 = meta.data_page_offset.try_into()?;
+
+    let _: Compression = meta.codec.try_into()?;
+    Ok(())
+}
+
 pub(super) fn column_metadata_byte_range(
     column_metadata: &ColumnMetaData,
 ) -> core::ops::Range<u64> {
@@ -214,3 +307,9 @@ pub(super) fn column_metadata_byte_range(
     let len = column_metadata.total_compressed_size as u64;
     offset..offset.checked_add(len).unwrap()
 }
+
+fn index_byte_range(offset: Option<i64>, length: Option<i32>) -> Option<core::ops::Range<u64>> {
+    let offset = u64::try_from(offset?).ok()?;
+    let length = u64::try_from(length?).ok()?;
+    Some(offset..offset.checked_add(length)?)
+}
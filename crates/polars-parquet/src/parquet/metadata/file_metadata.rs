@@ -1,8 +1,11 @@
+use std::sync::Arc;
+
 use polars_parquet_format::ColumnOrder as TColumnOrder;
 
 use super::RowGroupMetadata;
 use super::column_order::ColumnOrder;
 use super::schema_descriptor::SchemaDescriptor;
+use crate::parquet::encryption::FileDecryptor;
 use crate::parquet::error::ParquetError;
 use crate::parquet::metadata::get_sort_order;
 pub use crate::parquet::thrift_format::KeyValue;
@@ -66,6 +69,15 @@ impl FileMetadata {
     /// Deserializes [`crate::parquet::thrift_format::FileMetadata`] into this struct
     pub fn try_from_thrift(
         metadata: polars_parquet_format::FileMetaData,
+    ) -> Result<Self, ParquetError> {
+        Self::try_from_thrift_with_decryptor(metadata, None)
+    }
+
+    /// Deserializes [`crate::parquet::thrift_format::FileMetadata`] of a file of which the column
+    /// chunks may be encrypted.
+    pub fn try_from_thrift_with_decryptor(
+        metadata: polars_parquet_format::FileMetaData,
+        decryptor: Option<Arc<FileDecryptor>>,
     ) -> Result<Self, ParquetError> {
         let schema_descr = SchemaDescriptor::try_from_thrift(&metadata.schema)?;
 
@@ -74,8 +86,10 @@ impl FileMetadata {
         let row_groups = metadata
             .row_groups
             .into_iter()
-            .map(|rg| {
-                let md = RowGroupMetadata::try_from_thrift(&schema_descr, rg)?;
+            .enumerate()
+            .map(|(i, rg)| {
+                let md =
+                    RowGroupMetadata::try_from_thrift(&schema_descr, rg, i, decryptor.as_ref())?;
                 max_row_group_height = max_row_group_height.max(md.num_rows());
                 Ok(md)
             })
@@ -7,8 +7,9 @@ use polars_utils::idx_vec::UnitVec;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::unitvec;
 
-use super::column_chunk_metadata::{ColumnChunkMetadata, column_metadata_byte_range};
+use super::column_chunk_metadata::{ColumnChunkMetadata, ColumnDecryption};
 use super::schema_descriptor::SchemaDescriptor;
+use crate::parquet::encryption::FileDecryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 
 type ColumnLookup = PlHashMap<PlSmallStr, UnitVec<usize>>;
@@ -43,7 +44,6 @@ pub struct RowGroupMetadata {
     column_lookup: PlHashMap<PlSmallStr, UnitVec<usize>>,
     num_rows: usize,
     total_byte_size: usize,
-    full_byte_range: core::ops::Range<u64>,
     sorting_columns: Option<Vec<SortingColumn>>,
 }
 
@@ -63,6 +63,19 @@ impl RowGroupMetadata {
             .map(|x| x.iter().map(|&x| &self.columns[x]))
     }
 
+    /// Decrypts the encrypted columns under this root name, see
+    /// [`ColumnChunkMetadata::decrypt`].
+    pub fn decrypt_columns_under_root(&self, root_name: &str) -> ParquetResult<()> {
+        for column in self
+            .columns_under_root_iter(root_name)
+            .into_iter()
+            .flatten()
+        {
+            column.decrypt()?;
+        }
+        Ok(())
+    }
+
     /// Fetch all columns under this root name if it exists.
     pub fn columns_idxs_under_root_iter<'a>(&'a self, root_name: &str) -> Option<&'a [usize]> {
         self.column_lookup.get(root_name).map(|x| x.as_slice())
@@ -91,7 +104,9 @@ impl RowGroupMetadata {
     }
 
     pub fn full_byte_range(&self) -> core::ops::Range<u64> {
-        self.full_byte_range.clone()
+        self.byte_ranges_iter()
+            .reduce(|a, b| a.start.min(b.start)..a.end.max(b.end))
+            .unwrap_or(0..0)
     }
 
     pub fn byte_ranges_iter(&self) -> impl '_ + ExactSizeIterator<Item = core::ops::Range<u64>> {
@@ -103,9 +118,14 @@ impl RowGroupMetadata {
     }
 
     /// Method to convert from Thrift.
+    ///
+    /// The column chunks of encrypted files are decrypted with `decryptor` once they are read,
+    /// where `ordinal` is the index of the row group in the file.
     pub(crate) fn try_from_thrift(
         schema_descr: &SchemaDescriptor,
         rg: RowGroup,
+        ordinal: usize,
+        decryptor: Option<&Arc<FileDecryptor>>,
     ) -> ParquetResult<RowGroupMetadata> {
         if schema_descr.columns().len() != rg.columns.len() {
             return Err(ParquetError::oos(format!(
@@ -117,33 +137,49 @@ impl RowGroupMetadata {
         let total_byte_size = rg.total_byte_size.try_into()?;
         let num_rows = rg.num_rows.try_into()?;
 
-        let mut column_lookup = ColumnLookup::with_capacity(rg.columns.len());
-        let mut full_byte_range = if let Some(first_column_chunk) = rg.columns.first() {
-            let Some(metadata) = &first_column_chunk.meta_data else {
-                return Err(ParquetError::oos("Column chunk requires metadata"));
-            };
-            column_metadata_byte_range(metadata)
-        } else {
-            0..0
-        };
+        let decryptions = rg
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column_chunk)| {
+                if column_chunk.crypto_metadata.is_none() {
+                    return Ok(None);
+                }
+                match decryptor {
+                    Some(decryptor) => Ok(Some(ColumnDecryption {
+                        decryptor: decryptor.clone(),
+                        row_group: ordinal,
+                        column: i,
+                    })),
+                    None if column_chunk.meta_data.is_none() => Err(ParquetError::InvalidParameter(
+                        "The column chunks of this file are encrypted, decryption keys are required"
+                            .to_string(),
+                    )),
+                    // Columns encrypted in files with a plaintext footer can still be pruned on
+                    // their metadata, reading their pages fails.
+                    None => Ok(None),
+                }
+            })
+            .collect::<ParquetResult<Vec<_>>>()?;
 
+        let mut column_lookup = ColumnLookup::with_capacity(rg.columns.len());
         let sorting_columns = rg.sorting_columns.clone();
 
         let columns = rg
             .columns
             .into_iter()
             .zip(schema_descr.columns())
+            .zip(decryptions)
             .enumerate()
-            .map(|(i, (column_chunk, descriptor))| {
-                let column =
-                    ColumnChunkMetadata::try_from_thrift(descriptor.clone(), column_chunk)?;
+            .map(|(i, ((column_chunk, descriptor), decryption))| {
+                let column = ColumnChunkMetadata::try_from_thrift(
+                    descriptor.clone(),
+                    column_chunk,
+                    decryption,
+                )?;
 
                 column_lookup.add_column(i, &column);
 
-                let byte_range = column.byte_range();
-                full_byte_range = full_byte_range.start.min(byte_range.start)
-                    ..full_byte_range.end.max(byte_range.end);
-
                 Ok(column)
             })
             .collect::<ParquetResult<Vec<_>>>()?;
@@ -154,7 +190,6 @@ impl RowGroupMetadata {
             column_lookup,
             num_rows,
             total_byte_size,
-            full_byte_range,
             sorting_columns,
         })
     }
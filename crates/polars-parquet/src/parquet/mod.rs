@@ -4,6 +4,7 @@ pub mod error;
 pub mod bloom_filter;
 pub mod compression;
 pub mod encoding;
+pub mod encryption;
 pub mod metadata;
 pub mod page;
 mod parquet_bridge;
@@ -22,6 +23,13 @@ pub use streaming_decompression::{FallibleStreamingIterator, fallible_streaming_
 pub const HEADER_SIZE: u64 = PARQUET_MAGIC.len() as u64;
 pub const FOOTER_SIZE: u64 = 8;
 pub const PARQUET_MAGIC: [u8; 4] = [b'P', b'A', b'R', b'1'];
+/// The magic of files of which the footer is encrypted.
+pub const PARQUET_MAGIC_ENCRYPTED_FOOTER: [u8; 4] = [b'P', b'A', b'R', b'E'];
+
+/// Whether `magic` is the magic of a Parquet file, of which the footer may be encrypted.
+pub fn is_parquet_magic(magic: &[u8]) -> bool {
+    magic == PARQUET_MAGIC || magic == PARQUET_MAGIC_ENCRYPTED_FOOTER
+}
 
 /// The number of bytes read at the end of the parquet file on first read
 const DEFAULT_FOOTER_READ_SIZE: u64 = 64 * 1024;
@@ -0,0 +1,56 @@
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+pub use polars_parquet_format::{ColumnIndex, OffsetIndex, PageLocation};
+
+use crate::parquet::error::{ParquetError, ParquetResult};
+
+/// Deserializes a [`ColumnIndex`] from `bytes`, which span
+/// [`column_index_byte_range`](crate::parquet::metadata::ColumnChunkMetadata::column_index_byte_range).
+pub fn deserialize_column_index(mut bytes: &[u8]) -> ParquetResult<ColumnIndex> {
+    let max_size = max_size(bytes);
+    let mut prot = TCompactInputProtocol::new(&mut bytes, max_size);
+    let index = ColumnIndex::read_from_in_protocol(&mut prot)?;
+
+    let num_pages = index.null_pages.len();
+    if index.min_values.len() != num_pages
+        || index.max_values.len() != num_pages
+        || index
+            .null_counts
+            .as_ref()
+            .is_some_and(|v| v.len() != num_pages)
+    {
+        return Err(ParquetError::oos(
+            "the lists of a column index must have the same length",
+        ));
+    }
+
+    Ok(index)
+}
+
+/// Deserializes an [`OffsetIndex`] from `bytes`, which span
+/// [`offset_index_byte_range`](crate::parquet::metadata::ColumnChunkMetadata::offset_index_byte_range).
+pub fn deserialize_offset_index(mut bytes: &[u8]) -> ParquetResult<OffsetIndex> {
+    let max_size = max_size(bytes);
+    let mut prot = TCompactInputProtocol::new(&mut bytes, max_size);
+    let index = OffsetIndex::read_from_in_protocol(&mut prot)?;
+
+    let locations = &index.page_locations;
+    if locations.first().is_some_and(|l| l.first_row_index != 0)
+        || locations
+            .windows(2)
+            .any(|w| w[0].first_row_index >= w[1].first_row_index)
+        || locations
+            .iter()
+            .any(|l| l.offset < 0 || l.compressed_page_size < 0)
+    {
+        return Err(ParquetError::oos(
+            "the page locations of an offset index must start at row 0 and be increasing",
+        ));
+    }
+
+    Ok(index)
+}
+
+fn max_size(bytes: &[u8]) -> usize {
+    // every list element counts as a `usize`, while e.g. a boolean is encoded in a single byte
+    bytes.len() * 8 + 1024
+}
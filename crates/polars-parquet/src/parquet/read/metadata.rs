@@ -1,11 +1,16 @@
 use std::cmp::min;
 use std::io::{Read, Seek, SeekFrom};
+use std::sync::Arc;
 
-use polars_parquet_format::FileMetaData as TFileMetadata;
 use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+use polars_parquet_format::{FileCryptoMetaData, FileMetaData as TFileMetadata};
 
+use super::super::encryption::{FileDecryptionProperties, FileDecryptor};
 use super::super::metadata::FileMetadata;
-use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_MAGIC};
+use super::super::{
+    DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_MAGIC_ENCRYPTED_FOOTER,
+    is_parquet_magic,
+};
 use crate::parquet::error::{ParquetError, ParquetResult};
 
 pub(super) fn metadata_len(buffer: &[u8], len: usize) -> u32 {
@@ -37,6 +42,24 @@ pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> ParquetResult<FileMetada
 pub fn read_metadata_with_size<R: Read + Seek>(
     reader: &mut R,
     file_size: u64,
+) -> ParquetResult<FileMetadata> {
+    read_metadata_impl(reader, file_size, None)
+}
+
+/// Reads a [`FileMetadata`] from the reader, located at the end of the file. The footer and column
+/// chunks of encrypted files are decrypted with `decryption`.
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    decryption: Option<&FileDecryptionProperties>,
+) -> ParquetResult<FileMetadata> {
+    let file_size = stream_len(reader)?;
+    read_metadata_impl(reader, file_size, decryption)
+}
+
+fn read_metadata_impl<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> ParquetResult<FileMetadata> {
     if file_size < HEADER_SIZE + FOOTER_SIZE {
         return Err(ParquetError::oos(
@@ -55,8 +78,8 @@ pub fn read_metadata_with_size<R: Read + Seek>(
         .read_to_end(&mut buffer)?;
 
     // check this is indeed a parquet file
-    if buffer[default_end_len - 4..] != PARQUET_MAGIC {
-        return Err(ParquetError::oos("The file must end with PAR1"));
+    if !is_parquet_magic(&buffer[default_end_len - 4..]) {
+        return Err(ParquetError::oos("The file must end with PAR1 or PARE"));
     }
 
     let metadata_len: u32 = metadata_len(&buffer, default_end_len);
@@ -87,7 +110,7 @@ pub fn read_metadata_with_size<R: Read + Seek>(
     // a highly nested but sparse struct could result in many allocations
     let max_size = reader.len() * 2 + 1024;
 
-    deserialize_metadata(reader, max_size)
+    deserialize_metadata_with_decryption(reader, max_size, decryption)
 }
 
 /// Parse loaded metadata bytes
@@ -97,3 +120,59 @@ pub fn deserialize_metadata<R: Read>(reader: R, max_size: usize) -> ParquetResul
 
     FileMetadata::try_from_thrift(metadata)
 }
+
+/// Parse loaded metadata bytes, including the footer length and magic at their end, of a file
+/// that may be encrypted.
+///
+/// Files with an encrypted footer can only be read with `decryption`. The signature of plaintext
+/// footers is verified if `decryption` is given.
+pub fn deserialize_metadata_with_decryption(
+    footer: &[u8],
+    max_size: usize,
+    decryption: Option<&FileDecryptionProperties>,
+) -> ParquetResult<FileMetadata> {
+    let Some(metadata_end) = footer.len().checked_sub(FOOTER_SIZE as usize) else {
+        return Err(ParquetError::oos(
+            "The footer must end with its length and the magic",
+        ));
+    };
+    let (metadata, trailer) = footer.split_at(metadata_end);
+    let mut reader = metadata;
+
+    if trailer[4..] == PARQUET_MAGIC_ENCRYPTED_FOOTER {
+        let Some(decryption) = decryption else {
+            return Err(ParquetError::InvalidParameter(
+                "The footer of this file is encrypted, decryption keys are required".to_string(),
+            ));
+        };
+        let mut prot = TCompactInputProtocol::new(&mut reader, max_size);
+        let crypto = FileCryptoMetaData::read_from_in_protocol(&mut prot)?;
+        let decryptor = FileDecryptor::try_new(
+            decryption,
+            &crypto.encryption_algorithm,
+            crypto.key_metadata.as_deref(),
+        )?;
+
+        let metadata = decryptor.decrypt_footer(reader)?;
+        let mut prot = TCompactInputProtocol::new(metadata.as_slice(), max_size);
+        let metadata = TFileMetadata::read_from_in_protocol(&mut prot)?;
+        return FileMetadata::try_from_thrift_with_decryptor(metadata, Some(Arc::new(decryptor)));
+    }
+
+    let mut prot = TCompactInputProtocol::new(&mut reader, max_size);
+    let thrift_metadata = TFileMetadata::read_from_in_protocol(&mut prot)?;
+
+    let (Some(algorithm), Some(decryption)) = (&thrift_metadata.encryption_algorithm, decryption)
+    else {
+        return FileMetadata::try_from_thrift(thrift_metadata);
+    };
+    // A plaintext footer is followed by its signature.
+    let (plaintext, signature) = metadata.split_at(metadata.len() - reader.len());
+    let decryptor = FileDecryptor::try_new(
+        decryption,
+        algorithm,
+        thrift_metadata.footer_signing_key_metadata.as_deref(),
+    )?;
+    decryptor.verify_footer_signature(plaintext, signature)?;
+    FileMetadata::try_from_thrift_with_decryptor(thrift_metadata, Some(Arc::new(decryptor)))
+}
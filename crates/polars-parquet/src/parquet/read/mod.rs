@@ -1,5 +1,6 @@
 mod column;
 mod compression;
+mod indexes;
 pub mod levels;
 mod metadata;
 mod page;
@@ -10,7 +11,13 @@ use std::io::{Seek, SeekFrom};
 
 pub use column::*;
 pub use compression::{BasicDecompressor, decompress};
-pub use metadata::{deserialize_metadata, read_metadata, read_metadata_with_size};
+pub use indexes::{
+    ColumnIndex, OffsetIndex, PageLocation, deserialize_column_index, deserialize_offset_index,
+};
+pub use metadata::{
+    deserialize_metadata, deserialize_metadata_with_decryption, read_metadata,
+    read_metadata_with_decryption, read_metadata_with_size,
+};
 pub use page::{PageIterator, PageMetaData, PageReader};
 #[cfg(feature = "async")]
 pub use page::{get_page_stream, get_page_stream_from_column_start};
@@ -7,6 +7,7 @@ use polars_utils::mmap::{MemReader, MemSlice};
 use super::PageIterator;
 use crate::parquet::CowBuffer;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::{ColumnCipher, ModuleType, SIZE_LEN, module_len, split_module};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::{ColumnChunkMetadata, Descriptor};
 use crate::parquet::page::{
@@ -26,6 +27,10 @@ pub struct PageMetaData {
     pub compression: Compression,
     /// The descriptor of this parquet column
     pub descriptor: Descriptor,
+    /// Whether the pages of this column chunk are encrypted.
+    pub is_encrypted: bool,
+    /// Decrypts the pages of an encrypted column chunk, if its key is known.
+    pub cipher: Option<ColumnCipher>,
 }
 
 impl PageMetaData {
@@ -41,6 +46,8 @@ impl PageMetaData {
             num_values,
             compression,
             descriptor,
+            is_encrypted: false,
+            cipher: None,
         }
     }
 }
@@ -52,6 +59,8 @@ impl From<&ColumnChunkMetadata> for PageMetaData {
             num_values: column.num_values(),
             compression: column.compression(),
             descriptor: column.descriptor().descriptor.clone(),
+            is_encrypted: column.is_encrypted(),
+            cipher: column.cipher().cloned(),
         }
     }
 }
@@ -81,6 +90,14 @@ pub struct PageReader {
 
     // Maximum page size (compressed or uncompressed) to limit allocations
     max_page_size: usize,
+
+    is_encrypted: bool,
+    cipher: Option<ColumnCipher>,
+    // The ordinal of the next data page, which is part of the AAD of encrypted pages.
+    data_page_ordinal: usize,
+    // Whether no page header was read yet, in which case an encrypted header is either of a
+    // dictionary page or of the first data page.
+    at_start: bool,
 }
 
 impl PageReader {
@@ -114,6 +131,10 @@ impl PageReader {
             descriptor: reader_meta.descriptor,
             scratch,
             max_page_size,
+            is_encrypted: reader_meta.is_encrypted,
+            cipher: reader_meta.cipher,
+            data_page_ordinal: 0,
+            at_start: true,
         }
     }
 
@@ -137,15 +158,61 @@ impl PageReader {
         // a dictionary page exists iff the first data page is not at the start of
         // the column
         let seek_offset = self.reader.position();
-        let page_header = read_page_header(&mut self.reader, self.max_page_size)?;
+        let at_start = self.at_start;
+        let page_header = self.read_header()?;
         let page_type = page_header.type_.try_into()?;
 
         if !matches!(page_type, PageType::DictionaryPage) {
             self.reader
                 .seek(std::io::SeekFrom::Start(seek_offset as u64))?;
+            self.at_start = at_start;
             return Ok(None);
         }
 
+        let buffer = self.read_page_data(&page_header)?;
+
+        finish_page(page_header, buffer, self.compression, &self.descriptor).map(|p| {
+            if let CompressedPage::Dict(d) = p {
+                Some(d)
+            } else {
+                unreachable!()
+            }
+        })
+    }
+
+    /// Reads the next page header, decrypting it if the column is encrypted.
+    fn read_header(&mut self) -> ParquetResult<ParquetPageHeader> {
+        let at_start = std::mem::replace(&mut self.at_start, false);
+        let Some(cipher) = &self.cipher else {
+            if self.is_encrypted {
+                return Err(ParquetError::InvalidParameter(format!(
+                    "The pages of column '{}' are encrypted, its key is required",
+                    self.descriptor.primitive_type.field_info.name
+                )));
+            }
+            return read_page_header(&mut self.reader, self.max_page_size);
+        };
+
+        let module = read_module(&mut self.reader, self.max_page_size)?;
+        let data_page_header = |cipher: &ColumnCipher| {
+            cipher.decrypt(
+                ModuleType::DataPageHeader,
+                Some(self.data_page_ordinal),
+                &module,
+            )
+        };
+        let header = if at_start {
+            cipher
+                .decrypt(ModuleType::DictionaryPageHeader, None, &module)
+                .or_else(|_| data_page_header(cipher))?
+        } else {
+            data_page_header(cipher)?
+        };
+        read_page_header(&mut MemReader::from_vec(header), self.max_page_size)
+    }
+
+    /// Reads the data of the page of `page_header`, decrypting it if the column is encrypted.
+    fn read_page_data(&mut self, page_header: &ParquetPageHeader) -> ParquetResult<MemSlice> {
         let read_size: usize = page_header.compressed_page_size.try_into()?;
 
         if read_size > self.max_page_size {
@@ -160,16 +227,44 @@ impl PageReader {
             ));
         }
 
-        finish_page(page_header, buffer, self.compression, &self.descriptor).map(|p| {
-            if let CompressedPage::Dict(d) = p {
-                Some(d)
-            } else {
-                unreachable!()
-            }
-        })
+        let Some(cipher) = &self.cipher else {
+            return Ok(buffer);
+        };
+        let module = split_module(&buffer)?;
+        let page_type: PageType = page_header.type_.try_into()?;
+        let data = if matches!(page_type, PageType::DictionaryPage) {
+            cipher.decrypt(ModuleType::DictionaryPage, None, module)?
+        } else {
+            let data =
+                cipher.decrypt(ModuleType::DataPage, Some(self.data_page_ordinal), module)?;
+            self.data_page_ordinal += 1;
+            data
+        };
+        Ok(MemSlice::from_vec(data))
     }
 }
 
+/// Reads an encrypted module, without its length.
+fn read_module(reader: &mut MemReader, max_size: usize) -> ParquetResult<MemSlice> {
+    let len = reader.read_slice(SIZE_LEN);
+    let Ok(len) = <[u8; SIZE_LEN]>::try_from(&len[..]) else {
+        return Err(ParquetError::oos(
+            "An encrypted module must start with its length",
+        ));
+    };
+    let len = module_len(len);
+    if len > max_size {
+        return Err(ParquetError::WouldOverAllocate);
+    }
+    let module = reader.read_slice(len);
+    if module.len() != len {
+        return Err(ParquetError::oos(
+            "The encrypted module is shorter than its length",
+        ));
+    }
+    Ok(module)
+}
+
 impl PageIterator for PageReader {
     fn swap_buffer(&mut self, scratch: &mut Vec<u8>) {
         std::mem::swap(&mut self.scratch, scratch)
@@ -210,23 +305,11 @@ fn next_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
 }
 
 pub(super) fn build_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
-    let page_header = read_page_header(&mut reader.reader, reader.max_page_size)?;
+    let page_header = reader.read_header()?;
 
     reader.seen_num_values += get_page_num_values(&page_header)? as i64;
 
-    let read_size: usize = page_header.compressed_page_size.try_into()?;
-
-    if read_size > reader.max_page_size {
-        return Err(ParquetError::WouldOverAllocate);
-    }
-
-    let buffer = reader.reader.read_slice(read_size);
-
-    if buffer.len() != read_size {
-        return Err(ParquetError::oos(
-            "The page header reported the wrong page size",
-        ));
-    }
+    let buffer = reader.read_page_data(&page_header)?;
 
     finish_page(page_header, buffer, reader.compression, &reader.descriptor).map(Some)
 }
@@ -30,6 +30,7 @@ pub async fn get_page_stream_from_column_start<'a, R: AsyncRead + Unpin + Send>(
     max_header_size: usize,
 ) -> ParquetResult<impl Stream<Item = ParquetResult<CompressedPage>> + 'a> {
     let page_metadata: PageMetaData = column_metadata.into();
+    check_not_encrypted(&page_metadata)?;
     Ok(_get_page_stream(
         reader,
         page_metadata.num_values,
@@ -40,6 +41,15 @@ pub async fn get_page_stream_from_column_start<'a, R: AsyncRead + Unpin + Send>(
     ))
 }
 
+fn check_not_encrypted(page_metadata: &PageMetaData) -> ParquetResult<()> {
+    if page_metadata.is_encrypted {
+        return Err(ParquetError::not_supported(
+            "Reading the pages of encrypted columns asynchronously",
+        ));
+    }
+    Ok(())
+}
+
 /// Returns a stream of compressed data pages with [`PageMetaData`]
 pub async fn get_page_stream_with_page_meta<RR: AsyncRead + Unpin + Send + AsyncSeek>(
     page_metadata: PageMetaData,
@@ -47,6 +57,7 @@ pub async fn get_page_stream_with_page_meta<RR: AsyncRead + Unpin + Send + Async
     scratch: Vec<u8>,
     max_page_size: usize,
 ) -> ParquetResult<impl Stream<Item = ParquetResult<CompressedPage>> + '_> {
+    check_not_encrypted(&page_metadata)?;
     let column_start = page_metadata.column_start;
     reader.seek(SeekFrom::Start(column_start)).await?;
     Ok(_get_page_stream(
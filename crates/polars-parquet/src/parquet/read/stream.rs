@@ -3,8 +3,8 @@ use std::io::SeekFrom;
 use futures::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
 
 use super::super::metadata::FileMetadata;
-use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, PARQUET_MAGIC};
-use super::metadata::{deserialize_metadata, metadata_len};
+use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, is_parquet_magic};
+use super::metadata::{deserialize_metadata_with_decryption, metadata_len};
 use crate::parquet::HEADER_SIZE;
 use crate::parquet::error::{ParquetError, ParquetResult};
 
@@ -49,7 +49,7 @@ pub async fn read_metadata<R: AsyncRead + AsyncSeek + Send + std::marker::Unpin>
         .await?;
 
     // check this is indeed a parquet file
-    if buffer[default_end_len - 4..] != PARQUET_MAGIC {
+    if !is_parquet_magic(&buffer[default_end_len - 4..]) {
         return Err(ParquetError::oos("Invalid Parquet file. Corrupt footer"));
     }
 
@@ -84,5 +84,5 @@ pub async fn read_metadata<R: AsyncRead + AsyncSeek + Send + std::marker::Unpin>
     // a highly nested but sparse struct could result in many allocations
     let max_size = reader.len() * 2 + 1024;
 
-    deserialize_metadata(reader, max_size)
+    deserialize_metadata_with_decryption(reader, max_size, None)
 }
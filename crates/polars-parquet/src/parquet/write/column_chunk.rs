@@ -16,15 +16,19 @@ use super::statistics::reduce;
 use crate::parquet::FallibleStreamingIterator;
 use crate::parquet::compression::Compression;
 use crate::parquet::encoding::Encoding;
+use crate::parquet::encryption::{ColumnCipher, ModuleType};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::ColumnDescriptor;
 use crate::parquet::page::{CompressedPage, PageType};
 
+/// Writes the pages of a column chunk followed by its [`ColumnMetaData`], all of which are
+/// encrypted if `cipher` is given.
 pub fn write_column_chunk<W, E>(
     writer: &mut W,
     mut offset: u64,
     descriptor: &ColumnDescriptor,
     mut compressed_pages: DynStreamingIterator<'_, CompressedPage, E>,
+    cipher: Option<&ColumnCipher>,
 ) -> ParquetResult<(ColumnChunk, Vec<PageWriteSpec>, u64)>
 where
     W: Write,
@@ -36,8 +40,13 @@ where
     let initial = offset;
 
     let mut specs = vec![];
+    let mut data_page_ordinal = 0;
     while let Some(compressed_page) = compressed_pages.next()? {
-        let spec = write_page(writer, offset, compressed_page)?;
+        let cipher = cipher.map(|cipher| (cipher, data_page_ordinal));
+        let spec = write_page(writer, offset, compressed_page, cipher)?;
+        if matches!(compressed_page, CompressedPage::Data(_)) {
+            data_page_ordinal += 1;
+        }
         offset += spec.bytes_written;
         specs.push(spec);
     }
@@ -46,12 +55,23 @@ where
     let column_chunk = build_column_chunk(&specs, descriptor)?;
 
     // write metadata
-    let mut protocol = TCompactOutputProtocol::new(writer);
-    bytes_written += column_chunk
-        .meta_data
-        .as_ref()
-        .unwrap()
-        .write_to_out_protocol(&mut protocol)? as u64;
+    let metadata = column_chunk.meta_data.as_ref().unwrap();
+    if let Some(cipher) = cipher {
+        let mut serialized = vec![];
+        metadata.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut serialized))?;
+        let mut encrypted = vec![];
+        cipher.encrypt(
+            ModuleType::ColumnMetaData,
+            None,
+            &serialized,
+            &mut encrypted,
+        )?;
+        writer.write_all(&encrypted)?;
+        bytes_written += encrypted.len() as u64;
+    } else {
+        let mut protocol = TCompactOutputProtocol::new(writer);
+        bytes_written += metadata.write_to_out_protocol(&mut protocol)? as u64;
+    }
 
     Ok((column_chunk, specs, bytes_written))
 }
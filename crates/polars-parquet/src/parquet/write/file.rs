@@ -3,21 +3,51 @@ use std::io::Write;
 use polars_parquet_format::RowGroup;
 use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
 
-use super::indexes::{write_column_index, write_offset_index};
+use super::bloom_filter::write_bloom_filter;
+use super::indexes::{can_write_column_index, write_column_index, write_offset_index};
 use super::page::PageWriteSpec;
 use super::row_group::write_row_group;
 use super::{RowGroupIterColumns, WriteOptions};
+use crate::parquet::encryption::{FileEncryptionProperties, FileEncryptor};
 use crate::parquet::error::{ParquetError, ParquetResult};
 pub use crate::parquet::metadata::KeyValue;
-use crate::parquet::metadata::{SchemaDescriptor, ThriftFileMetadata};
+use crate::parquet::metadata::{SchemaDescriptor, SortingColumn, ThriftFileMetadata};
 use crate::parquet::write::State;
-use crate::parquet::{FOOTER_SIZE, PARQUET_MAGIC};
+use crate::parquet::{FOOTER_SIZE, PARQUET_MAGIC, PARQUET_MAGIC_ENCRYPTED_FOOTER};
 
 pub(super) fn start_file<W: Write>(writer: &mut W) -> ParquetResult<u64> {
     writer.write_all(&PARQUET_MAGIC)?;
     Ok(PARQUET_MAGIC.len() as u64)
 }
 
+/// Writes the encrypted footer of a file, i.e. the [`FileCryptoMetaData`] followed by the
+/// encrypted metadata.
+///
+/// [`FileCryptoMetaData`]: polars_parquet_format::FileCryptoMetaData
+fn end_encrypted_file<W: Write>(
+    writer: &mut W,
+    metadata: &ThriftFileMetadata,
+    encryptor: &FileEncryptor,
+) -> ParquetResult<u64> {
+    let mut footer = vec![];
+    encryptor
+        .file_crypto_metadata()
+        .write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut footer))?;
+
+    let mut serialized = vec![];
+    metadata.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut serialized))?;
+    encryptor.encrypt_footer(&serialized, &mut footer)?;
+
+    let footer_len: u32 = footer.len().try_into().map_err(|_| {
+        ParquetError::oos("The footer of a Parquet file can contain at most u32::MAX bytes")
+    })?;
+    footer.extend_from_slice(&footer_len.to_le_bytes());
+    footer.extend_from_slice(&PARQUET_MAGIC_ENCRYPTED_FOOTER);
+    writer.write_all(&footer)?;
+    writer.flush()?;
+    Ok(footer.len() as u64)
+}
+
 pub(super) fn end_file<W: Write>(
     mut writer: &mut W,
     metadata: &ThriftFileMetadata,
@@ -66,10 +96,16 @@ pub struct FileWriter<W: Write> {
     offset: u64,
     row_groups: Vec<RowGroup>,
     page_specs: Vec<Vec<Vec<PageWriteSpec>>>,
+    /// The bloom filter bitsets of the columns of each row group, written before the indexes
+    bloom_filters: Vec<Vec<Option<Vec<u8>>>>,
     /// Used to store the current state for writing the file
     state: State,
     // when the file is written, metadata becomes available
     metadata: Option<ThriftFileMetadata>,
+    /// Encrypts the footer and columns, see [`Self::with_encryption`].
+    encryptor: Option<FileEncryptor>,
+    /// The sort order of the rows, recorded in every row group.
+    sorting_columns: Option<Vec<SortingColumn>>,
 }
 
 /// Writes a parquet file containing only the header and footer
@@ -125,11 +161,35 @@ impl<W: Write> FileWriter<W> {
             offset: 0,
             row_groups: vec![],
             page_specs: vec![],
+            bloom_filters: vec![],
             state: State::Initialised,
             metadata: None,
+            encryptor: None,
+            sorting_columns: None,
         }
     }
 
+    /// Encrypts the file with `properties`, see [`crate::parquet::encryption`].
+    ///
+    /// The footer is encrypted, and bloom filters and page indexes are not written for encrypted
+    /// columns.
+    pub fn with_encryption(mut self, properties: FileEncryptionProperties) -> ParquetResult<Self> {
+        if self.offset != 0 {
+            return Err(ParquetError::InvalidParameter(
+                "Encryption must be set before data is written".to_string(),
+            ));
+        }
+        self.encryptor = Some(FileEncryptor::try_new(properties)?);
+        Ok(self)
+    }
+
+    /// Records that the rows of every row group are sorted by `sorting_columns`, which refer to
+    /// the leaf columns of the schema.
+    pub fn with_sorting_columns(mut self, sorting_columns: Vec<SortingColumn>) -> Self {
+        self.sorting_columns = (!sorting_columns.is_empty()).then_some(sorting_columns);
+        self
+    }
+
     /// Writes the header of the file.
     ///
     /// This is automatically called by [`Self::write`] if not called following [`Self::new`].
@@ -138,7 +198,12 @@ impl<W: Write> FileWriter<W> {
     /// Returns an error if data has been written to the file.
     fn start(&mut self) -> ParquetResult<()> {
         if self.offset == 0 {
-            self.offset = start_file(&mut self.writer)?;
+            self.offset = if self.encryptor.is_some() {
+                self.writer.write_all(&PARQUET_MAGIC_ENCRYPTED_FOOTER)?;
+                PARQUET_MAGIC_ENCRYPTED_FOOTER.len() as u64
+            } else {
+                start_file(&mut self.writer)?
+            };
             self.state = State::Started;
             Ok(())
         } else {
@@ -152,6 +217,22 @@ impl<W: Write> FileWriter<W> {
     ///
     /// This call is IO-bounded
     pub fn write<E>(&mut self, row_group: RowGroupIterColumns<'_, E>) -> ParquetResult<()>
+    where
+        ParquetError: From<E>,
+        E: std::error::Error,
+    {
+        self.write_with_bloom_filters(row_group, vec![])
+    }
+
+    /// Writes a row group to the file, together with the split-block bloom filter bitsets of its
+    /// columns. Columns without an entry in `bloom_filters` get no bloom filter.
+    ///
+    /// The bloom filters are kept in memory until [`Self::end`] writes them.
+    pub fn write_with_bloom_filters<E>(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, E>,
+        bloom_filters: Vec<Option<Vec<u8>>>,
+    ) -> ParquetResult<()>
     where
         ParquetError: From<E>,
         E: std::error::Error,
@@ -160,16 +241,19 @@ impl<W: Write> FileWriter<W> {
             self.start()?;
         }
         let ordinal = self.row_groups.len();
-        let (group, specs, size) = write_row_group(
+        let (mut group, specs, size) = write_row_group(
             &mut self.writer,
             self.offset,
             self.schema.columns(),
             row_group,
             ordinal,
+            self.encryptor.as_ref(),
         )?;
+        group.sorting_columns.clone_from(&self.sorting_columns);
         self.offset += size;
         self.row_groups.push(group);
         self.page_specs.push(specs);
+        self.bloom_filters.push(bloom_filters);
         Ok(())
     }
 
@@ -188,6 +272,32 @@ impl<W: Write> FileWriter<W> {
         // compute file stats
         let num_rows = self.row_groups.iter().map(|group| group.num_rows).sum();
 
+        // write bloom filters
+        self.row_groups
+            .iter_mut()
+            .zip(std::mem::take(&mut self.bloom_filters))
+            .try_for_each(|(group, bloom_filters)| {
+                group
+                    .columns
+                    .iter_mut()
+                    .zip(bloom_filters)
+                    .try_for_each(|(column, bitset)| {
+                        let Some(bitset) = bitset else {
+                            return Ok(());
+                        };
+                        if column.crypto_metadata.is_some() {
+                            return Ok(());
+                        }
+                        let offset = self.offset;
+                        self.offset += write_bloom_filter(&mut self.writer, &bitset)?;
+                        let metadata = column.meta_data.as_mut().unwrap();
+                        metadata.bloom_filter_offset = Some(offset as i64);
+                        metadata.bloom_filter_length = Some((self.offset - offset) as i32);
+                        ParquetResult::Ok(())
+                    })?;
+                ParquetResult::Ok(())
+            })?;
+
         if self.options.write_statistics {
             // write column indexes (require page statistics)
             self.row_groups
@@ -196,6 +306,9 @@ impl<W: Write> FileWriter<W> {
                 .try_for_each(|(group, pages)| {
                     group.columns.iter_mut().zip(pages.iter()).try_for_each(
                         |(column, pages)| {
+                            if column.crypto_metadata.is_some() || !can_write_column_index(pages) {
+                                return ParquetResult::Ok(());
+                            }
                             let offset = self.offset;
                             column.column_index_offset = Some(offset as i64);
                             self.offset += write_column_index(&mut self.writer, pages)?;
@@ -218,6 +331,9 @@ impl<W: Write> FileWriter<W> {
                     .iter_mut()
                     .zip(pages.iter())
                     .try_for_each(|(column, pages)| {
+                        if column.crypto_metadata.is_some() {
+                            return ParquetResult::Ok(());
+                        }
                         let offset = self.offset;
                         column.offset_index_offset = Some(offset as i64);
                         self.offset += write_offset_index(&mut self.writer, pages)?;
@@ -239,7 +355,18 @@ impl<W: Write> FileWriter<W> {
             None,
         );
 
-        let len = end_file(&mut self.writer, &metadata)?;
+        let len = match &self.encryptor {
+            Some(encryptor) => {
+                let mut encrypted_metadata = metadata.clone();
+                for (i, group) in encrypted_metadata.row_groups.iter_mut().enumerate() {
+                    for (j, column) in group.columns.iter_mut().enumerate() {
+                        encryptor.encrypt_column_metadata(column, i, j)?;
+                    }
+                }
+                end_encrypted_file(&mut self.writer, &encrypted_metadata, encryptor)?
+            },
+            None => end_file(&mut self.writer, &metadata)?,
+        };
         self.state = State::Finished;
         self.metadata = Some(metadata);
         Ok(self.offset + len)
@@ -8,7 +8,17 @@ use polars_parquet_format::thrift::protocol::TCompactOutputStreamProtocol;
 
 use super::serialize::{serialize_column_index, serialize_offset_index};
 use crate::parquet::error::ParquetResult;
-use crate::parquet::write::page::PageWriteSpec;
+use crate::parquet::write::page::{PageWriteSpec, is_data_page};
+
+/// Whether the column index of a column chunk can be written, i.e. whether all of its data pages
+/// have statistics with a null count. Statistics can be disabled per column.
+pub fn can_write_column_index(pages: &[PageWriteSpec]) -> bool {
+    pages.iter().filter(|x| is_data_page(x)).all(|spec| {
+        spec.statistics
+            .as_ref()
+            .is_some_and(|s| s.null_count().is_some())
+    })
+}
 
 pub fn write_column_index<W: Write>(writer: &mut W, pages: &[PageWriteSpec]) -> ParquetResult<u64> {
     let index = serialize_column_index(pages)?;
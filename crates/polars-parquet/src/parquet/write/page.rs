@@ -8,6 +8,7 @@ use polars_parquet_format::thrift::protocol::TCompactOutputStreamProtocol;
 use polars_parquet_format::{DictionaryPageHeader, Encoding, PageType};
 
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::{ColumnCipher, ModuleType};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::page::{
     CompressedDataPage, CompressedDictPage, CompressedPage, DataPageHeader, ParquetPageHeader,
@@ -48,32 +49,37 @@ pub struct PageWriteSpec {
     pub statistics: Option<Statistics>,
 }
 
+/// Writes a page and its header. The page is encrypted if `cipher` is given together with the
+/// ordinal of the page among the data pages of the column chunk.
 pub fn write_page<W: Write>(
     writer: &mut W,
     offset: u64,
     compressed_page: &CompressedPage,
+    cipher: Option<(&ColumnCipher, usize)>,
 ) -> ParquetResult<PageWriteSpec> {
     let num_values = compressed_page.num_values();
     let num_rows = compressed_page
         .num_rows()
         .expect("We should have num_rows when we are writing");
 
-    let header = match &compressed_page {
+    let mut header = match &compressed_page {
         CompressedPage::Data(compressed_page) => assemble_data_page_header(compressed_page),
         CompressedPage::Dict(compressed_page) => assemble_dict_page_header(compressed_page),
     }?;
 
-    let header_size = write_page_header(writer, &header)?;
-    let mut bytes_written = header_size;
+    let buffer: &[u8] = match &compressed_page {
+        CompressedPage::Data(compressed_page) => &compressed_page.buffer,
+        CompressedPage::Dict(compressed_page) => &compressed_page.buffer,
+    };
 
-    bytes_written += match &compressed_page {
-        CompressedPage::Data(compressed_page) => {
-            writer.write_all(&compressed_page.buffer)?;
-            compressed_page.buffer.len() as u64
+    let (header_size, bytes_written) = match cipher {
+        Some((cipher, ordinal)) => {
+            write_encrypted_page(writer, &mut header, buffer, cipher, ordinal)?
         },
-        CompressedPage::Dict(compressed_page) => {
-            writer.write_all(&compressed_page.buffer)?;
-            compressed_page.buffer.len() as u64
+        None => {
+            let header_size = write_page_header(writer, &header)?;
+            writer.write_all(buffer)?;
+            (header_size, header_size + buffer.len() as u64)
         },
     };
 
@@ -94,6 +100,49 @@ pub fn write_page<W: Write>(
     })
 }
 
+/// Encrypts and writes a page and its header, of which the compressed page size becomes the size
+/// of the encrypted page. Returns the size of the header and the total number of bytes written.
+fn write_encrypted_page<W: Write>(
+    writer: &mut W,
+    header: &mut ParquetPageHeader,
+    buffer: &[u8],
+    cipher: &ColumnCipher,
+    ordinal: usize,
+) -> ParquetResult<(u64, u64)> {
+    let (page_module, header_module, ordinal) = if header.type_ == PageType::DICTIONARY_PAGE {
+        (
+            ModuleType::DictionaryPage,
+            ModuleType::DictionaryPageHeader,
+            None,
+        )
+    } else {
+        (
+            ModuleType::DataPage,
+            ModuleType::DataPageHeader,
+            Some(ordinal),
+        )
+    };
+
+    let mut page = vec![];
+    cipher.encrypt(page_module, ordinal, buffer, &mut page)?;
+    header.compressed_page_size = maybe_bytes(0, page.len())?.1;
+
+    let mut serialized_header = vec![];
+    header.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut serialized_header))?;
+    let mut encrypted_header = vec![];
+    cipher.encrypt(
+        header_module,
+        ordinal,
+        &serialized_header,
+        &mut encrypted_header,
+    )?;
+
+    writer.write_all(&encrypted_header)?;
+    writer.write_all(&page)?;
+    let header_size = encrypted_header.len() as u64;
+    Ok((header_size, header_size + page.len() as u64))
+}
+
 #[cfg(feature = "async")]
 #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
 pub async fn write_page_async<W: AsyncWrite + Unpin + Send>(
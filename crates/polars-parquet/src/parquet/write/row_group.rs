@@ -9,6 +9,7 @@ use super::column_chunk::write_column_chunk;
 use super::column_chunk::write_column_chunk_async;
 use super::page::{PageWriteSpec, is_data_page};
 use super::{DynIter, DynStreamingIterator};
+use crate::parquet::encryption::FileEncryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::{ColumnChunkMetadata, ColumnDescriptor};
 use crate::parquet::page::CompressedPage;
@@ -76,6 +77,7 @@ pub fn write_row_group<
     descriptors: &[ColumnDescriptor],
     columns: DynIter<'a, std::result::Result<DynStreamingIterator<'a, CompressedPage, E>, E>>,
     ordinal: usize,
+    encryptor: Option<&FileEncryptor>,
 ) -> ParquetResult<(RowGroup, Vec<Vec<PageWriteSpec>>, u64)>
 where
     W: Write,
@@ -86,9 +88,22 @@ where
 
     let initial = offset;
     let columns = column_iter
-        .map(|(descriptor, page_iter)| {
-            let (column, page_specs, size) =
-                write_column_chunk(writer, offset, descriptor, page_iter?)?;
+        .enumerate()
+        .map(|(i, (descriptor, page_iter))| {
+            let path_in_schema = descriptor
+                .path_in_schema
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>();
+            let cipher = encryptor
+                .map(|encryptor| encryptor.column_cipher(&path_in_schema, ordinal, i))
+                .transpose()?
+                .flatten();
+
+            let (mut column, page_specs, size) =
+                write_column_chunk(writer, offset, descriptor, page_iter?, cipher.as_ref())?;
+            column.crypto_metadata =
+                encryptor.and_then(|encryptor| encryptor.column_crypto_metadata(&path_in_schema));
             offset += size;
             Ok((column, page_specs))
         })
@@ -1,8 +1,12 @@
 use std::sync::Arc;
 
 use polars_core::prelude::*;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroScanOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
+#[cfg(feature = "delta")]
+use polars_io::delta::DeltaScanOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcScanOptions;
 #[cfg(feature = "parquet")]
@@ -82,6 +86,36 @@ impl DslBuilder {
         .into())
     }
 
+    #[cfg(feature = "avro")]
+    pub fn scan_avro(
+        sources: ScanSources,
+        options: AvroScanOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScanDsl::Avro { options }),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
+    #[cfg(feature = "delta")]
+    pub fn scan_delta(
+        sources: ScanSources,
+        options: DeltaScanOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScanDsl::Delta { options }),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "csv")]
     pub fn scan_csv(
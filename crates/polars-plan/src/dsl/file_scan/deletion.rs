@@ -2,9 +2,6 @@ use std::sync::Arc;
 
 use polars_core::prelude::PlIndexMap;
 
-// Note, there are a lot of single variant enums here, but the intention is that we'll support
-// Delta deletion vectors as well at some point in the future.
-
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -20,6 +17,82 @@ pub enum DeletionFilesList {
     //
     /// Iceberg positional deletes
     IcebergPositionDelete(Arc<PlIndexMap<usize, Arc<[String]>>>),
+    /// Iceberg positional and equality deletes, along with the sequence numbers needed to decide
+    /// which of them apply to a data file.
+    IcebergDelete(Arc<PlIndexMap<usize, IcebergDeleteFiles>>),
+    /// Delta deletion vectors, a single one per data file.
+    DeltaDeletionVector(Arc<PlIndexMap<usize, DeltaDeletionVector>>),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct IcebergDeleteFiles {
+    /// Path of the data file, as referenced by the `file_path` column of position delete files.
+    pub data_file_path: String,
+    /// Data sequence number of the data file.
+    pub data_sequence_number: i64,
+    pub files: Arc<[IcebergDeleteFile]>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct IcebergDeleteFile {
+    pub path: String,
+    /// Data sequence number of the delete file.
+    pub data_sequence_number: i64,
+    pub content: IcebergDeleteContent,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum IcebergDeleteContent {
+    /// Rows are deleted by their position in the data file.
+    Position,
+    /// Rows are deleted if their values in the columns with the given field IDs are equal to the
+    /// values of a row in the delete file.
+    Equality { equality_ids: Arc<[u32]> },
+}
+
+impl IcebergDeleteFile {
+    /// Whether the deletes apply to a data file with the given data sequence number.
+    ///
+    /// Position deletes apply to data files with a sequence number less than or equal to their
+    /// own, as they can be committed together with the data file they reference. Equality deletes
+    /// only apply to data files with a strictly smaller sequence number, rows written after the
+    /// delete are not deleted by it.
+    pub fn applies_to(&self, data_sequence_number: i64) -> bool {
+        match self.content {
+            IcebergDeleteContent::Position => self.data_sequence_number >= data_sequence_number,
+            IcebergDeleteContent::Equality { .. } => {
+                self.data_sequence_number > data_sequence_number
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct DeltaDeletionVector {
+    pub storage: DeltaDeletionVectorStorage,
+    pub size_in_bytes: usize,
+    /// Number of deleted rows.
+    pub cardinality: u64,
+    /// Number of rows in the data file, bounds the positions of the deleted rows.
+    pub num_records: u64,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum DeltaDeletionVectorStorage {
+    /// Z85 encoded in the transaction log.
+    Inline(String),
+    /// Stored at `offset` in the file at `path`.
+    File { path: String, offset: usize },
 }
 
 impl DeletionFilesList {
@@ -31,6 +104,10 @@ impl DeletionFilesList {
             Some(IcebergPositionDelete(paths)) => {
                 (!paths.is_empty()).then_some(IcebergPositionDelete(paths))
             },
+            Some(IcebergDelete(files)) => (!files.is_empty()).then_some(IcebergDelete(files)),
+            Some(DeltaDeletionVector(vectors)) => {
+                (!vectors.is_empty()).then_some(DeltaDeletionVector(vectors))
+            },
             None => None,
         }
     }
@@ -40,6 +117,8 @@ impl DeletionFilesList {
 
         match self {
             IcebergPositionDelete(paths) => paths.len(),
+            IcebergDelete(files) => files.len(),
+            DeltaDeletionVector(vectors) => vectors.len(),
         }
     }
 }
@@ -56,6 +135,16 @@ impl std::hash::Hash for DeletionFilesList {
                     .first()
                     .map_or(0, |(_, paths)| Arc::as_ptr(paths) as *const () as usize);
 
+                addr.hash(state)
+            },
+            IcebergDelete(files) => {
+                let addr = Arc::as_ptr(files) as *const () as usize;
+
+                addr.hash(state)
+            },
+            DeltaDeletionVector(vectors) => {
+                let addr = Arc::as_ptr(vectors) as *const () as usize;
+
                 addr.hash(state)
             },
         }
@@ -71,6 +160,14 @@ impl std::fmt::Display for DeletionFilesList {
                 let s = if paths.len() == 1 { "" } else { "s" };
                 write!(f, "iceberg-position-delete: {} source{s}", paths.len())?;
             },
+            IcebergDelete(files) => {
+                let s = if files.len() == 1 { "" } else { "s" };
+                write!(f, "iceberg-delete: {} source{s}", files.len())?;
+            },
+            DeltaDeletionVector(vectors) => {
+                let s = if vectors.len() == 1 { "" } else { "s" };
+                write!(f, "delta-deletion-vector: {} source{s}", vectors.len())?;
+            },
         }
 
         Ok(())
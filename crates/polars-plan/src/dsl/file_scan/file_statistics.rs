@@ -0,0 +1,50 @@
+use polars_core::prelude::DataFrame;
+
+/// Statistics of the files of a scan that are known up front, e.g. from the metadata of a table
+/// format. Used to skip files before they are opened.
+///
+/// The DataFrame has a row per scan source, in the layout evaluated by skip batch predicates: a
+/// `len` column and `{col}_min`, `{col}_max` and `{col}_nc` columns. Unknown statistics are null.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FileStatistics(DataFrame);
+
+impl FileStatistics {
+    pub fn new(df: DataFrame) -> Self {
+        Self(df)
+    }
+
+    pub fn df(&self) -> &DataFrame {
+        &self.0
+    }
+
+    pub fn num_files(&self) -> usize {
+        self.0.height()
+    }
+}
+
+impl PartialEq for FileStatistics {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.equals_missing(&other.0)
+    }
+}
+
+impl Eq for FileStatistics {}
+
+impl std::hash::Hash for FileStatistics {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.height().hash(state);
+        for name in self.0.get_column_names() {
+            name.hash(state);
+        }
+    }
+}
+
+impl std::fmt::Display for FileStatistics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let n = self.num_files();
+        let s = if n == 1 { "" } else { "s" };
+        write!(f, "file-statistics: {n} source{s}")
+    }
+}
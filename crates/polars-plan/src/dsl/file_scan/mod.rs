@@ -2,11 +2,16 @@ use std::hash::Hash;
 use std::sync::Mutex;
 
 use deletion::DeletionFilesList;
+use file_statistics::FileStatistics;
 use polars_core::schema::iceberg::IcebergSchemaRef;
 use polars_core::utils::get_numeric_upcast_supertype_lossless;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroScanOptions;
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
+#[cfg(feature = "delta")]
+use polars_io::delta::DeltaScanOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcScanOptions;
 #[cfg(feature = "parquet")]
@@ -21,6 +26,7 @@ use strum_macros::IntoStaticStr;
 
 use super::*;
 pub mod deletion;
+pub mod file_statistics;
 
 #[cfg(feature = "python")]
 pub mod python_dataset;
@@ -51,6 +57,13 @@ pub enum FileScanDsl {
     #[cfg(feature = "ipc")]
     Ipc { options: IpcScanOptions },
 
+    #[cfg(feature = "avro")]
+    Avro { options: AvroScanOptions },
+
+    /// Resolved to a Parquet scan of the data files of the table during IR conversion.
+    #[cfg(feature = "delta")]
+    Delta { options: DeltaScanOptions },
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -89,6 +102,9 @@ pub enum FileScanIR {
         metadata: Option<Arc<arrow::io::ipc::read::FileMetadata>>,
     },
 
+    #[cfg(feature = "avro")]
+    Avro { options: AvroScanOptions },
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -114,6 +130,8 @@ impl FileScanIR {
             Self::Parquet { .. } => ScanFlags::SPECIALIZED_PREDICATE_FILTER,
             #[cfg(feature = "json")]
             Self::NDJson { .. } => ScanFlags::empty(),
+            #[cfg(feature = "avro")]
+            Self::Avro { .. } => ScanFlags::empty(),
             #[allow(unreachable_patterns)]
             _ => ScanFlags::empty(),
         }
@@ -142,6 +160,8 @@ impl FileScanIR {
             Self::Parquet { .. } => true,
             #[cfg(feature = "json")]
             Self::NDJson { .. } => false,
+            #[cfg(feature = "avro")]
+            Self::Avro { .. } => false,
             #[allow(unreachable_patterns)]
             _ => false,
         }
@@ -247,6 +267,7 @@ pub struct UnifiedScanArgs {
     pub include_file_paths: Option<PlSmallStr>,
 
     pub deletion_files: Option<DeletionFilesList>,
+    pub file_statistics: Option<FileStatistics>,
     pub column_mapping: Option<ColumnMapping>,
 }
 
@@ -267,6 +288,7 @@ impl Default for UnifiedScanArgs {
             extra_columns_policy: ExtraColumnsPolicy::default(),
             include_file_paths: None,
             deletion_files: None,
+            file_statistics: None,
             column_mapping: None,
         }
     }
@@ -321,6 +343,11 @@ mod _file_scan_eq_hash {
             metadata: Option<usize>,
         },
 
+        #[cfg(feature = "avro")]
+        Avro {
+            options: &'a polars_io::avro::AvroScanOptions,
+        },
+
         #[cfg(feature = "python")]
         PythonDataset {
             dataset_object: usize,
@@ -358,6 +385,9 @@ mod _file_scan_eq_hash {
                     metadata: metadata.as_ref().map(arc_as_ptr),
                 },
 
+                #[cfg(feature = "avro")]
+                FileScanIR::Avro { options } => FileScanEqHashWrap::Avro { options },
+
                 #[cfg(feature = "python")]
                 FileScanIR::PythonDataset {
                     dataset_object,
@@ -8,6 +8,8 @@ mod sink;
 
 use polars_core::error::PolarsResult;
 use polars_core::prelude::*;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroWriterOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
@@ -24,8 +26,8 @@ use polars_ops::prelude::{JoinArgs, JoinType};
 use polars_time::DynamicGroupOptions;
 #[cfg(feature = "dynamic_group_by")]
 use polars_time::RollingGroupOptions;
-use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
+use polars_utils::{IdxSize, format_pl_smallstr};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 pub use sink::*;
@@ -361,22 +363,31 @@ pub enum FileType {
     Csv(CsvWriterOptions),
     #[cfg(feature = "json")]
     Json(JsonWriterOptions),
+    #[cfg(feature = "avro")]
+    Avro(AvroWriterOptions),
 }
 
 impl FileType {
-    pub fn extension(&self) -> &'static str {
-        match self {
+    pub fn extension(&self) -> PlSmallStr {
+        let (ext, compression) = match self {
             #[cfg(feature = "parquet")]
-            Self::Parquet(_) => "parquet",
+            Self::Parquet(_) => ("parquet", None),
             #[cfg(feature = "ipc")]
-            Self::Ipc(_) => "ipc",
+            Self::Ipc(_) => ("ipc", None),
             #[cfg(feature = "csv")]
-            Self::Csv(_) => "csv",
+            Self::Csv(options) => ("csv", options.compression.file_extension()),
             #[cfg(feature = "json")]
-            Self::Json(_) => "jsonl",
+            Self::Json(options) => ("jsonl", options.compression.file_extension()),
+            #[cfg(feature = "avro")]
+            Self::Avro(_) => ("avro", None),
 
             #[allow(unreachable_patterns)]
             _ => unreachable!("enable file type features"),
+        };
+
+        match compression {
+            None => PlSmallStr::from_static(ext),
+            Some(compression) => format_pl_smallstr!("{ext}.{compression}"),
         }
     }
 }
@@ -39,6 +39,9 @@ bitflags! {
         /// Check if operations are order dependent and unset maintaining_order if
         /// the order would not be observed.
         const CHECK_ORDER_OBSERVE = 1 << 16;
+        /// Remove sorts of data that is known to be sorted already, and record the sort order
+        /// of the data written by Parquet sinks.
+        const SORTEDNESS = 1 << 17;
     }
 }
 
@@ -78,6 +81,9 @@ impl OptFlags {
     pub fn fast_projection(&self) -> bool {
         self.contains(OptFlags::FAST_PROJECTION)
     }
+    pub fn sortedness(&self) -> bool {
+        self.contains(OptFlags::SORTEDNESS)
+    }
 }
 
 impl Default for OptFlags {
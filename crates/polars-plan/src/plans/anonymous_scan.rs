@@ -47,6 +47,13 @@ pub trait AnonymousScan: Send + Sync {
     fn allows_slice_pushdown(&self) -> bool {
         false
     }
+    /// Specify if the streaming engine should call `next_batch` until it returns `None`,
+    /// instead of calling `scan` once. `n_rows` is then the number of rows still needed.
+    ///
+    /// Defaults to `false`
+    fn allows_batched_scan(&self) -> bool {
+        false
+    }
 }
 
 impl Debug for dyn AnonymousScan {
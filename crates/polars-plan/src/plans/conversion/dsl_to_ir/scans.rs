@@ -4,7 +4,7 @@ use polars_io::cloud::CloudOptions;
 #[cfg(feature = "cloud")]
 use polars_io::pl_async::get_runtime;
 use polars_io::prelude::*;
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::CompressedReader;
 
 use super::*;
 
@@ -40,6 +40,12 @@ pub(super) fn dsl_to_ir(
             }
         }
 
+        #[cfg(feature = "delta")]
+        let (sources, scan_type, delta_hive_parts) =
+            resolve_delta_scan(sources, scan_type, unified_scan_args, cloud_options)?;
+        #[cfg(not(feature = "delta"))]
+        let delta_hive_parts = None;
+
         let sources = match &*scan_type {
             #[cfg(feature = "parquet")]
             FileScanDsl::Parquet { .. } => {
@@ -49,6 +55,12 @@ pub(super) fn dsl_to_ir(
             FileScanDsl::Ipc { .. } => {
                 sources.expand_paths_with_hive_update(unified_scan_args, cloud_options)?
             },
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro { .. } => {
+                sources.expand_paths_with_hive_update(unified_scan_args, cloud_options)?
+            },
+            #[cfg(feature = "delta")]
+            FileScanDsl::Delta { .. } => unreachable!("Delta scans are resolved to Parquet scans"),
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { .. } => sources.expand_paths(unified_scan_args, cloud_options)?,
             #[cfg(feature = "json")]
@@ -78,7 +90,9 @@ pub(super) fn dsl_to_ir(
             unified_scan_args.hive_options.enabled = Some(false);
         }
 
-        let hive_parts = if unified_scan_args.hive_options.enabled.unwrap()
+        let hive_parts = if delta_hive_parts.is_some() {
+            delta_hive_parts
+        } else if unified_scan_args.hive_options.enabled.unwrap()
             && file_info.reader_schema.is_some()
         {
             let paths = sources
@@ -170,6 +184,136 @@ pub(super) fn dsl_to_ir(
     Ok(cached_ir.clone().unwrap())
 }
 
+/// Resolves a Delta table scan to a Parquet scan of the data files of the table. Other scans are
+/// returned as is.
+///
+/// # Returns
+/// `(sources, scan_type, hive_parts)`
+#[cfg(feature = "delta")]
+fn resolve_delta_scan(
+    sources: ScanSources,
+    scan_type: Box<FileScanDsl>,
+    unified_scan_args: &mut UnifiedScanArgs,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<(
+    ScanSources,
+    Box<FileScanDsl>,
+    Option<crate::plans::hive::HivePartitionsDf>,
+)> {
+    use polars_io::delta::DeltaSnapshot;
+
+    use crate::dsl::file_statistics::FileStatistics;
+    use crate::plans::hive::hive_partitions_from_values;
+
+    let FileScanDsl::Delta { options } = *scan_type else {
+        return Ok((sources, scan_type, None));
+    };
+
+    let table_root = match sources.as_paths() {
+        Some([table_root]) => table_root.clone(),
+        _ => polars_bail!(InvalidOperation: "Delta scans take the path of a single table"),
+    };
+
+    let snapshot = DeltaSnapshot::try_load(table_root.as_ref(), cloud_options, options.version)
+        .map_err(|e| e.context(failed_here!(delta scan)))?;
+
+    if verbose() {
+        eprintln!(
+            "delta scan: version {}: {} files",
+            snapshot.version,
+            snapshot.files.len()
+        );
+    }
+
+    let hive_schema = snapshot
+        .partition_columns
+        .iter()
+        .map(|name| Field::new(name.clone(), snapshot.schema.get(name).unwrap().clone()))
+        .collect::<Schema>();
+    let hive_parts = if hive_schema.is_empty() || snapshot.files.is_empty() {
+        None
+    } else {
+        Some(hive_partitions_from_values(
+            &hive_schema,
+            snapshot
+                .files
+                .iter()
+                .map(|file| file.partition_values.as_slice()),
+        )?)
+    };
+
+    // The partition values and the list of data files are taken from the transaction log.
+    unified_scan_args.hive_options.enabled = Some(false);
+    unified_scan_args.glob = false;
+    unified_scan_args.file_statistics = snapshot.file_statistics()?.map(FileStatistics::new);
+    unified_scan_args.deletion_files = delta_deletion_files(&snapshot, table_root.as_ref())?;
+
+    let sources = ScanSources::Paths(
+        snapshot
+            .files
+            .iter()
+            .map(|file| file.path.clone())
+            .collect(),
+    );
+
+    let mut parquet_options = options.parquet_options;
+    parquet_options.schema = Some(snapshot.schema.clone());
+
+    Ok((
+        sources,
+        Box::new(FileScanDsl::Parquet {
+            options: parquet_options,
+        }),
+        hive_parts,
+    ))
+}
+
+#[cfg(feature = "delta")]
+fn delta_deletion_files(
+    snapshot: &polars_io::delta::DeltaSnapshot,
+    table_root: polars_utils::plpath::PlPathRef<'_>,
+) -> PolarsResult<Option<crate::dsl::deletion::DeletionFilesList>> {
+    use crate::dsl::deletion::{
+        DeletionFilesList, DeltaDeletionVector, DeltaDeletionVectorStorage,
+    };
+
+    let mut vectors = PlIndexMap::new();
+
+    for (scan_source_idx, file) in snapshot.files.iter().enumerate() {
+        let Some(dv) = &file.deletion_vector else {
+            continue;
+        };
+        let num_records = file.num_records().ok_or_else(|| {
+            polars_err!(
+                ComputeError: "Delta data file '{}' has a deletion vector but no numRecords statistic",
+                file.path.to_str()
+            )
+        })?;
+
+        let storage = match dv.absolute_path(table_root)? {
+            None => DeltaDeletionVectorStorage::Inline(dv.path_or_inline_dv.clone()),
+            Some(path) => DeltaDeletionVectorStorage::File {
+                path: path.to_str().to_string(),
+                offset: dv.offset.unwrap_or(0) as usize,
+            },
+        };
+
+        vectors.insert(
+            scan_source_idx,
+            DeltaDeletionVector {
+                storage,
+                size_in_bytes: dv.size_in_bytes as usize,
+                cardinality: dv.cardinality as u64,
+                num_records,
+            },
+        );
+    }
+
+    Ok(DeletionFilesList::filter_empty(Some(
+        DeletionFilesList::DeltaDeletionVector(Arc::new(vectors)),
+    )))
+}
+
 pub(super) fn insert_row_index_to_schema(
     schema: &mut Schema,
     name: PlSmallStr,
@@ -188,7 +332,7 @@ pub(super) fn insert_row_index_to_schema(
     Ok(())
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "avro"))]
 fn prepare_output_schema(
     mut schema: Schema,
     row_index: Option<&RowIndex>,
@@ -219,6 +363,7 @@ pub(super) fn parquet_file_info(
     sources: &ScanSources,
     row_index: Option<&RowIndex>,
     #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    decryption: Option<&polars_io::parquet::encryption::ParquetDecryptionOptions>,
 ) -> PolarsResult<(FileInfo, Option<FileMetadataRef>)> {
     use polars_core::error::feature_gated;
 
@@ -228,7 +373,9 @@ pub(super) fn parquet_file_info(
             feature_gated!("cloud", {
                 let uri = first_path.to_str();
                 get_runtime().block_in_place_on(async {
-                    let mut reader = ParquetObjectStore::from_uri(uri, cloud_options, None).await?;
+                    let mut reader = ParquetObjectStore::from_uri(uri, cloud_options, None)
+                        .await?
+                        .with_decryption(decryption.cloned());
 
                     PolarsResult::Ok((
                         reader.schema().await?,
@@ -242,7 +389,8 @@ pub(super) fn parquet_file_info(
                 .first()
                 .ok_or_else(|| polars_err!(ComputeError: "expected at least 1 source"))?;
             let memslice = first_source.to_memslice()?;
-            let mut reader = ParquetReader::new(std::io::Cursor::new(memslice));
+            let mut reader = ParquetReader::new(std::io::Cursor::new(memslice))
+                .with_decryption(decryption.cloned());
             (
                 reader.schema()?,
                 Some(reader.num_rows()?),
@@ -314,6 +462,52 @@ pub(super) fn ipc_file_info(
     Ok((file_info, metadata))
 }
 
+#[cfg(feature = "avro")]
+pub(super) fn avro_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+    use polars_io::avro::AvroReader;
+
+    let Some(first) = sources.first() else {
+        polars_bail!(ComputeError: "expected at least 1 source");
+    };
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    // The schema is in the header of the file.
+    let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let reader_schema = Arc::new(AvroReader::new(std::io::Cursor::new(memslice)).arrow_schema()?);
+
+    Ok(FileInfo::new(
+        prepare_output_schema(Schema::from_arrow_schema(reader_schema.as_ref()), row_index)?,
+        Some(Either::Left(reader_schema)),
+        (None, 0),
+    ))
+}
+
 #[cfg(feature = "csv")]
 pub fn csv_file_info(
     sources: &ScanSources,
@@ -357,11 +551,34 @@ pub fn csv_file_info(
         }
     };
 
+    // Files with a multi-byte separator or line terminator are inferred after normalization.
+    let normalized_options;
+    let infer_options = if csv_options.parse_options.needs_dialect_normalization() {
+        normalized_options = csv_options
+            .clone()
+            .map_parse_options(CsvParseOptions::into_normalized);
+        &normalized_options
+    } else {
+        &*csv_options
+    };
+
     let infer_schema_func = |i| {
         let source = sources.at(i);
         let memslice = source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
-        let owned = &mut vec![];
-        let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
+        let mut reader = polars_io::csv::read::CsvSourceReader::try_new(
+            memslice.clone(),
+            &csv_options.parse_options,
+        )?;
+        let memslice = if reader.is_decoded() {
+            polars_io::csv::read::schema_inference::decompress_schema_inference_prefix(
+                &mut reader,
+                infer_options,
+                csv_options.infer_schema_length,
+            )?
+        } else {
+            memslice
+        };
+        let mut reader = std::io::Cursor::new(memslice);
         if reader.read(&mut [0; 4])? < 2 && csv_options.raise_if_empty {
             polars_bail!(NoData: "empty CSV")
         }
@@ -370,7 +587,7 @@ pub fn csv_file_info(
         let reader_bytes = get_reader_bytes(&mut reader).expect("could not mmap file");
 
         // this needs a way to estimated bytes/rows.
-        SchemaInferenceResult::try_from_reader_bytes_and_options(&reader_bytes, csv_options)
+        SchemaInferenceResult::try_from_reader_bytes_and_options(&reader_bytes, infer_options)
     };
 
     let merge_func = |a: PolarsResult<SchemaInferenceResult>,
@@ -473,13 +690,12 @@ pub fn ndjson_file_info(
         }
     };
 
-    let owned = &mut vec![];
-
     let mut schema = if let Some(schema) = ndjson_options.schema.clone() {
         schema
     } else {
         let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
-        let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
+        // Only the lines used for inference are decompressed.
+        let mut reader = std::io::BufReader::new(CompressedReader::try_new(memslice)?);
 
         Arc::new(polars_io::ndjson::infer_schema(
             &mut reader,
@@ -555,6 +771,7 @@ impl SourcesToFileInfo {
                         sources,
                         unified_scan_args.row_index.as_ref(),
                         cloud_options,
+                        options.decryption.as_ref(),
                     )
                     .map_err(|e| e.context(failed_here!(parquet scan)))?;
 
@@ -608,6 +825,14 @@ impl SourcesToFileInfo {
                 .map_err(|e| e.context(failed_here!(ndjson scan)))?,
                 FileScanIR::NDJson { options },
             ),
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro { options } => (
+                scans::avro_file_info(sources, unified_scan_args.row_index.as_ref(), cloud_options)
+                    .map_err(|e| e.context(failed_here!(avro scan)))?,
+                FileScanIR::Avro { options },
+            ),
+            #[cfg(feature = "delta")]
+            FileScanDsl::Delta { .. } => unreachable!("Delta scans are resolved to Parquet scans"),
             #[cfg(feature = "python")]
             FileScanDsl::PythonDataset { dataset_object } => {
                 if crate::dsl::DATASET_PROVIDER_VTABLE.get().is_none() {
@@ -688,6 +913,16 @@ impl SourcesToFileInfo {
                 let v = self.inner.get(&key);
                 (key, v)
             },
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro { options: _ } => {
+                let key = CachedSourceKey::ParquetIpc {
+                    first_path: paths[0].clone(),
+                    schema_overwrite: None,
+                };
+
+                let v = self.inner.get(&key);
+                (key, v)
+            },
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { options } => {
                 let key = CachedSourceKey::CsvJson {
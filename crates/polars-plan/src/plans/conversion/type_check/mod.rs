@@ -66,10 +66,13 @@ impl OptimizationRule for TypeCheckRule {
 
                     fn push_children<'a>(
                         stack: &mut Vec<Item<'a>>,
-                        children: &'a ChildFieldOverwrites,
+                        overwrites: &'a ParquetFieldOverwrites,
                         dtype: &'a DataType,
                     ) -> PolarsResult<()> {
-                        match children {
+                        if overwrites.bloom_filter.is_some() && dtype.is_nested() {
+                            polars_bail!(InvalidOperation: "cannot give a parquet bloom filter overwrite to a list / array / struct field");
+                        }
+                        match &overwrites.children {
                             ChildFieldOverwrites::None => {},
                             ChildFieldOverwrites::ListLike(child_overwrites) => {
                                 let Some(child_dtype) = dtype.inner_dtype() else {
@@ -104,7 +107,7 @@ impl OptimizationRule for TypeCheckRule {
                             polars_bail!(InvalidOperation: "duplicate parquet field overwrite for struct field `{name}`");
                         }
 
-                        push_children(&mut stack, &o.children, dtype)?;
+                        push_children(&mut stack, o, dtype)?;
                     }
 
                     while let Some(item) = stack.pop() {
@@ -113,7 +116,7 @@ impl OptimizationRule for TypeCheckRule {
                                 if o.name.is_some() {
                                     polars_bail!(InvalidOperation: "parquet field overwrite list child cannot have name");
                                 };
-                                push_children(&mut stack, &o.children, dt)?;
+                                push_children(&mut stack, o, dt)?;
                             },
                             Item::Struct(fields, os) => {
                                 // @NOTE: Avoid quadratic behavior through HashMap.
@@ -134,7 +137,7 @@ impl OptimizationRule for TypeCheckRule {
                                         polars_bail!(InvalidOperation: "duplicate parquet field overwrite for struct field `{name}`");
                                     }
 
-                                    push_children(&mut stack, &o.children, field.dtype())?;
+                                    push_children(&mut stack, o, field.dtype())?;
                                 }
                             },
                         }
@@ -4,12 +4,13 @@ use arrow::io::ipc::read::get_row_count as count_rows_ipc_sync;
     feature = "parquet",
     feature = "ipc",
     feature = "json",
-    feature = "csv"
+    feature = "csv",
+    feature = "avro"
 ))]
 use polars_core::error::feature_gated;
 #[cfg(any(feature = "json", feature = "parquet"))]
 use polars_io::SerReader;
-#[cfg(any(feature = "parquet", feature = "json"))]
+#[cfg(any(feature = "parquet", feature = "json", feature = "avro"))]
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetReader;
@@ -30,7 +31,8 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "avro"
     )))]
     {
         unreachable!()
@@ -40,14 +42,17 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "avro"
     ))]
     {
         let count: PolarsResult<usize> = match scan_type {
             #[cfg(feature = "csv")]
             FileScanIR::Csv { options } => count_all_rows_csv(sources, options),
             #[cfg(feature = "parquet")]
-            FileScanIR::Parquet { .. } => count_rows_parquet(sources, cloud_options),
+            FileScanIR::Parquet { options, .. } => {
+                count_rows_parquet(sources, cloud_options, options.decryption.as_ref())
+            },
             #[cfg(feature = "ipc")]
             FileScanIR::Ipc { options, metadata } => count_rows_ipc(
                 sources,
@@ -57,6 +62,8 @@ pub fn count_rows(
             ),
             #[cfg(feature = "json")]
             FileScanIR::NDJson { options } => count_rows_ndjson(sources, cloud_options),
+            #[cfg(feature = "avro")]
+            FileScanIR::Avro { .. } => count_rows_avro(sources, cloud_options),
             #[cfg(feature = "python")]
             FileScanIR::PythonDataset { .. } => unreachable!(),
             FileScanIR::Anonymous { .. } => {
@@ -84,10 +91,7 @@ fn count_all_rows_csv(
         .map(|source| match source {
             ScanSourceRef::Path(addr) => polars_io::csv::read::count_rows(
                 addr,
-                parse_options.separator,
-                parse_options.quote_char,
-                parse_options.comment_prefix.as_ref(),
-                parse_options.eol_char,
+                &parse_options,
                 options.has_header,
                 options.skip_lines,
                 options.skip_rows,
@@ -95,6 +99,10 @@ fn count_all_rows_csv(
             ),
             _ => {
                 let memslice = source.to_memslice()?;
+                let (memslice, _) =
+                    polars_io::csv::read::CsvSourceReader::try_new(memslice, &parse_options)?
+                        .read_next_slice(&Default::default(), usize::MAX)?;
+                let parse_options = parse_options.as_ref().clone().into_normalized();
 
                 polars_io::csv::read::count_rows_from_slice_par(
                     &memslice[..],
@@ -116,6 +124,7 @@ fn count_all_rows_csv(
 pub(super) fn count_rows_parquet(
     sources: &ScanSources,
     #[allow(unused)] cloud_options: Option<&CloudOptions>,
+    decryption: Option<&polars_io::parquet::encryption::ParquetDecryptionOptions>,
 ) -> PolarsResult<usize> {
     if sources.is_empty() {
         return Ok(0);
@@ -126,13 +135,16 @@ pub(super) fn count_rows_parquet(
             get_runtime().block_on(count_rows_cloud_parquet(
                 sources.as_paths().unwrap(),
                 cloud_options,
+                decryption,
             ))
         })
     } else {
         sources
             .iter()
             .map(|source| {
-                ParquetReader::new(std::io::Cursor::new(source.to_memslice()?)).num_rows()
+                ParquetReader::new(std::io::Cursor::new(source.to_memslice()?))
+                    .with_decryption(decryption.cloned())
+                    .num_rows()
             })
             .sum::<PolarsResult<usize>>()
     }
@@ -142,13 +154,15 @@ pub(super) fn count_rows_parquet(
 async fn count_rows_cloud_parquet(
     addrs: &[PlPath],
     cloud_options: Option<&CloudOptions>,
+    decryption: Option<&polars_io::parquet::encryption::ParquetDecryptionOptions>,
 ) -> PolarsResult<usize> {
     use polars_io::prelude::ParquetObjectStore;
 
     let collection = addrs.iter().map(|path| {
         with_concurrency_budget(1, || async {
-            let mut reader =
-                ParquetObjectStore::from_uri(path.to_str(), cloud_options, None).await?;
+            let mut reader = ParquetObjectStore::from_uri(path.to_str(), cloud_options, None)
+                .await?
+                .with_decryption(decryption.cloned());
             reader.num_rows().await
         })
     });
@@ -254,3 +268,45 @@ pub(super) fn count_rows_ndjson(
         })
         .sum()
 }
+
+#[cfg(feature = "avro")]
+pub(super) fn count_rows_avro(
+    sources: &ScanSources,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    use polars_core::config;
+
+    if sources.is_empty() {
+        return Ok(0);
+    }
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    sources
+        .iter()
+        .map(|source| {
+            let memslice =
+                source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+            polars_io::avro::count_rows(&memslice)
+        })
+        .sum()
+}
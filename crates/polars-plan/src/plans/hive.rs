@@ -221,6 +221,64 @@ pub fn hive_partitions_from_paths(
     )?)))
 }
 
+/// Builds the partitions of files whose partition values are known up front, e.g. from the
+/// metadata of a table format, instead of being parsed from the paths.
+///
+/// `values` has the values of the columns of `hive_schema` for each file, `None` for nulls.
+pub fn hive_partitions_from_values<'a>(
+    hive_schema: &Schema,
+    values: impl ExactSizeIterator<Item = &'a [Option<String>]>,
+) -> PolarsResult<HivePartitionsDf> {
+    // There are no CSV buffers for decimals and binary values, these are cast from strings.
+    let parse_schema = hive_schema
+        .iter()
+        .map(|(name, dtype)| {
+            let dtype = if dtype.is_decimal() || dtype == &DataType::Binary {
+                DataType::String
+            } else {
+                dtype.clone()
+            };
+            Field::new(name.clone(), dtype)
+        })
+        .collect::<Schema>();
+
+    let num_files = values.len();
+    let mut buffers = polars_io::csv::read::buffer::init_buffers(
+        &(0..hive_schema.len()).collect::<Vec<_>>(),
+        num_files,
+        &parse_schema,
+        None,
+        polars_io::prelude::CsvEncoding::Utf8,
+        false,
+    )?;
+
+    for file_values in values {
+        polars_ensure!(
+            file_values.len() == hive_schema.len(),
+            ComputeError: "expected {} partition values, got {}", hive_schema.len(), file_values.len()
+        );
+
+        for (buf, value) in buffers.iter_mut().zip(file_values) {
+            match value.as_deref() {
+                Some(value) if !value.is_empty() && value != "__HIVE_DEFAULT_PARTITION__" => {
+                    buf.add(value.as_bytes(), false, false, false)?
+                },
+                _ => buf.add_null(false),
+            }
+        }
+    }
+
+    let buffers = buffers
+        .into_iter()
+        .zip(hive_schema.iter_values())
+        .map(|(x, dtype)| Ok(x.into_series()?.strict_cast(dtype)?.into_column()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    Ok(HivePartitionsDf(DataFrame::new_with_height(
+        num_files, buffers,
+    )?))
+}
+
 /// Parse a Hive partition string (e.g. "column=1.5") into a name and value part.
 ///
 /// Returns `None` if the string is not a Hive partition string.
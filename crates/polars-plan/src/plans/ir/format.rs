@@ -8,6 +8,7 @@ use recursive::recursive;
 
 use self::ir::dot::ScanSourcesDisplay;
 use crate::dsl::deletion::DeletionFilesList;
+use crate::dsl::file_statistics::FileStatistics;
 use crate::prelude::*;
 
 const INDENT_INCREMENT: usize = 2;
@@ -74,6 +75,7 @@ fn write_scan(
     pre_slice: Option<Slice>,
     row_index: Option<&RowIndex>,
     deletion_files: Option<&DeletionFilesList>,
+    file_statistics: Option<&FileStatistics>,
 ) -> fmt::Result {
     write!(
         f,
@@ -107,6 +109,9 @@ fn write_scan(
     if let Some(deletion_files) = deletion_files {
         write!(f, "\n{deletion_files}")?;
     }
+    if let Some(file_statistics) = file_statistics {
+        write!(f, "\n{file_statistics}")?;
+    }
     Ok(())
 }
 
@@ -682,6 +687,7 @@ pub fn write_ir_non_recursive(
                     .map(|len| polars_utils::slice_enum::Slice::Positive { offset: 0, len }),
                 None,
                 None,
+                None,
             )
         },
         IR::Slice {
@@ -728,6 +734,7 @@ pub fn write_ir_non_recursive(
                 unified_scan_args.pre_slice.clone(),
                 unified_scan_args.row_index.as_ref(),
                 unified_scan_args.deletion_files.as_ref(),
+                unified_scan_args.file_statistics.as_ref(),
             )
         },
         IR::DataFrameScan {
@@ -130,6 +130,7 @@ impl OptimizationRule for ExpandDatasets {
                                 extra_columns_policy,
                                 include_file_paths: _include_file_paths @ None,
                                 deletion_files,
+                                file_statistics,
                                 column_mapping,
                             } = *resolved_unified_scan_args
                             else {
@@ -146,6 +147,7 @@ impl OptimizationRule for ExpandDatasets {
                             unified_scan_args.missing_columns_policy = missing_columns_policy;
                             unified_scan_args.extra_columns_policy = extra_columns_policy;
                             unified_scan_args.deletion_files = deletion_files;
+                            unified_scan_args.file_statistics = file_statistics;
                             unified_scan_args.column_mapping = column_mapping;
 
                             *sources = resolved_sources;
@@ -168,6 +170,14 @@ impl OptimizationRule for ExpandDatasets {
                                 #[cfg(feature = "json")]
                                 FileScanDsl::NDJson { options } => FileScanIR::NDJson { options },
 
+                                #[cfg(feature = "avro")]
+                                FileScanDsl::Avro { options } => FileScanIR::Avro { options },
+
+                                #[cfg(feature = "delta")]
+                                FileScanDsl::Delta { .. } => {
+                                    polars_bail!(nyi = "Delta scans resolved from Python datasets")
+                                },
+
                                 #[cfg(feature = "python")]
                                 FileScanDsl::PythonDataset { dataset_object } => {
                                     FileScanIR::PythonDataset {
@@ -26,6 +26,7 @@ mod set_order;
 mod simplify_expr;
 mod slice_pushdown_expr;
 mod slice_pushdown_lp;
+mod sortedness;
 mod stack_opt;
 
 use collapse_and_project::SimpleProjectionAndCollapse;
@@ -39,6 +40,7 @@ pub use predicate_pushdown::PredicatePushDown;
 pub use projection_pushdown::ProjectionPushDown;
 pub use simplify_expr::{SimplifyBooleanRule, SimplifyExprRule};
 use slice_pushdown_lp::SlicePushDown;
+pub use sortedness::{SortedColumn, output_sortedness};
 pub use stack_opt::{OptimizationRule, OptimizeExprContext, StackOptimizer};
 
 use self::flatten_union::FlattenUnionRule;
@@ -242,6 +244,10 @@ pub fn optimize(
         cluster_with_columns::optimize(lp_top, lp_arena, expr_arena)
     }
 
+    if opt_flags.sortedness() {
+        sortedness::optimize(lp_top, lp_arena, expr_arena);
+    }
+
     if _cse_plan_changed
         && get_members_opt!().is_some_and(|members| {
             (members.has_joins_or_unions | members.has_sink_multiple) && members.has_cache
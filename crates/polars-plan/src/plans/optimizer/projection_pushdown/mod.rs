@@ -453,6 +453,8 @@ impl ProjectionPushDown {
                     FileScanIR::NDJson { .. } => true,
                     #[cfg(feature = "ipc")]
                     FileScanIR::Ipc { .. } => true,
+                    #[cfg(feature = "avro")]
+                    FileScanIR::Avro { .. } => true,
                     #[cfg(feature = "csv")]
                     FileScanIR::Csv { .. } => true,
                     #[cfg(feature = "parquet")]
@@ -233,11 +233,13 @@ impl SlicePushDown {
                 #[cfg(feature = "json")]
                 FileScanIR::NDJson { .. } => true,
 
+                #[cfg(feature = "avro")]
+                FileScanIR::Avro { .. } => true,
+
                 #[cfg(feature = "python")]
                 FileScanIR::PythonDataset { .. } => true,
 
-                // TODO: This can be `true` after Anonymous scan dispatches to new-streaming.
-                FileScanIR::Anonymous { .. } => state.offset == 0,
+                FileScanIR::Anonymous { .. } => state.offset >= 0,
             }  =>  {
                 unified_scan_args.pre_slice = Some(state.to_slice_enum());
 
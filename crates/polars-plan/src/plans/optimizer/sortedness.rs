@@ -0,0 +1,337 @@
+//! Tracks by which columns the output of a plan is sorted.
+//!
+//! This is used to remove sorts of data that is already sorted, and to record the sort order as the
+//! `sorting_columns` of Parquet sinks.
+#[cfg(feature = "parquet")]
+use either::Either;
+use polars_core::series::IsSorted;
+#[cfg(feature = "parquet")]
+use polars_io::parquet::write::ParquetSortingColumn;
+use polars_utils::arena::{Arena, Node};
+
+use super::*;
+
+/// A column in the (lexicographic) sort order of the output of a plan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortedColumn {
+    pub name: PlSmallStr,
+    pub descending: bool,
+    /// Whether the nulls come last, `None` if this is not known.
+    pub nulls_last: Option<bool>,
+}
+
+impl SortedColumn {
+    fn from_flag(name: PlSmallStr, is_sorted: IsSorted, nulls_last: Option<bool>) -> Option<Self> {
+        let descending = match is_sorted {
+            IsSorted::Ascending => false,
+            IsSorted::Descending => true,
+            IsSorted::Not => return None,
+        };
+        Some(Self {
+            name,
+            descending,
+            nulls_last,
+        })
+    }
+}
+
+/// Returns the columns by which the output of `node` is (lexicographically) sorted.
+///
+/// This is conservative, an empty result means that the sort order is not known.
+pub fn output_sortedness(
+    node: Node,
+    ir_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Vec<SortedColumn> {
+    match ir_arena.get(node) {
+        IR::Sort {
+            by_column,
+            sort_options,
+            ..
+        } => {
+            let broadcast = |v: &[bool], i: usize| if v.len() == 1 { v[0] } else { v[i] };
+            by_column
+                .iter()
+                .enumerate()
+                .map_while(|(i, e)| {
+                    let AExpr::Column(name) = expr_arena.get(e.node()) else {
+                        return None;
+                    };
+                    Some(SortedColumn {
+                        name: name.clone(),
+                        descending: broadcast(&sort_options.descending, i),
+                        nulls_last: Some(broadcast(&sort_options.nulls_last, i)),
+                    })
+                })
+                .collect()
+        },
+        IR::Filter { input, .. } | IR::Slice { input, .. } | IR::Cache { input, .. } => {
+            output_sortedness(*input, ir_arena, expr_arena)
+        },
+        IR::SimpleProjection { input, columns } => {
+            let mut sorted = output_sortedness(*input, ir_arena, expr_arena);
+            let len = sorted
+                .iter()
+                .take_while(|c| columns.contains(&c.name))
+                .count();
+            sorted.truncate(len);
+            sorted
+        },
+        IR::Select { input, expr, .. } => {
+            let input_sorted = output_sortedness(*input, ir_arena, expr_arena);
+            let mut sorted = set_sorted_columns(expr, expr_arena);
+            sorted.extend(input_sorted.into_iter().map_while(|c| {
+                let e = expr.iter().find(
+                    |e| matches!(expr_arena.get(e.node()), AExpr::Column(name) if name == &c.name),
+                )?;
+                Some(SortedColumn {
+                    name: e.output_name().clone(),
+                    ..c
+                })
+            }));
+            sorted
+        },
+        IR::HStack { input, exprs, .. } => {
+            let input_sorted = output_sortedness(*input, ir_arena, expr_arena);
+            let mut sorted = set_sorted_columns(exprs, expr_arena);
+            sorted.extend(input_sorted.into_iter().take_while(|c| {
+                exprs.iter().all(|e| {
+                    e.output_name() != &c.name
+                        || matches!(expr_arena.get(e.node()), AExpr::Column(name) if name == &c.name)
+                })
+            }));
+            sorted
+        },
+        IR::MapFunction { input, function } => match function {
+            FunctionIR::RowIndex { name, .. } => {
+                let mut sorted = vec![SortedColumn {
+                    name: name.clone(),
+                    descending: false,
+                    nulls_last: Some(false),
+                }];
+                sorted.extend(output_sortedness(*input, ir_arena, expr_arena));
+                sorted
+            },
+            FunctionIR::Rechunk => output_sortedness(*input, ir_arena, expr_arena),
+            _ => Vec::new(),
+        },
+        IR::DataFrameScan {
+            df, output_schema, ..
+        } => df
+            .get_columns()
+            .iter()
+            .filter(|c| output_schema.as_ref().is_none_or(|s| s.contains(c.name())))
+            .filter_map(|c| {
+                let nulls_last =
+                    c.null_count() > 0 && c.get(c.len() - 1).is_ok_and(|av| av.is_null());
+                SortedColumn::from_flag(c.name().clone(), c.is_sorted_flag(), Some(nulls_last))
+            })
+            .collect(),
+        #[cfg(feature = "parquet")]
+        IR::Scan {
+            sources,
+            file_info,
+            output_schema,
+            scan_type,
+            unified_scan_args,
+            ..
+        } => {
+            let FileScanIR::Parquet {
+                metadata: Some(metadata),
+                ..
+            } = scan_type.as_ref()
+            else {
+                return Vec::new();
+            };
+            let Some(Either::Left(reader_schema)) = &file_info.reader_schema else {
+                return Vec::new();
+            };
+            // The sort order of one file says nothing about the order across files. Deriving it
+            // for multiple files (e.g. partitioned datasets) would need the statistics of every
+            // file, which are only fetched once the scan runs, so we don't.
+            if sources.len() != 1 || unified_scan_args.column_mapping.is_some() {
+                return Vec::new();
+            }
+
+            let mut sorted = Vec::new();
+            if let Some(row_index) = &unified_scan_args.row_index {
+                sorted.push(SortedColumn {
+                    name: row_index.name.clone(),
+                    descending: false,
+                    nulls_last: Some(false),
+                });
+            }
+
+            let Ok(file_sorted) =
+                polars_io::parquet::read::file_sorting_columns(metadata, reader_schema)
+            else {
+                return sorted;
+            };
+            let output_schema = output_schema.as_ref().unwrap_or(&file_info.schema);
+            sorted.extend(
+                file_sorted
+                    .into_iter()
+                    .take_while(|c| {
+                        // A cast to another type may change the order.
+                        let file_dtype = reader_schema.get(&c.name).map(DataType::from_arrow_field);
+                        output_schema.get(&c.name).is_some()
+                            && file_info.schema.get(&c.name) == file_dtype.as_ref()
+                    })
+                    .map(|c| SortedColumn {
+                        name: c.name,
+                        descending: c.descending,
+                        nulls_last: Some(c.nulls_last),
+                    }),
+            );
+            sorted
+        },
+        _ => Vec::new(),
+    }
+}
+
+/// The columns that are explicitly flagged as sorted by `exprs`. The flag doesn't say where the
+/// nulls are.
+fn set_sorted_columns(exprs: &[ExprIR], expr_arena: &Arena<AExpr>) -> Vec<SortedColumn> {
+    exprs
+        .iter()
+        .filter_map(|e| match expr_arena.get(e.node()) {
+            AExpr::Function {
+                function: IRFunctionExpr::SetSortedFlag(is_sorted),
+                ..
+            } => SortedColumn::from_flag(e.output_name().clone(), *is_sorted, None),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Whether the output of a node sorted by `sorted` already is in the order of the sort by
+/// `by_column`.
+fn is_sorted_by(
+    sorted: &[SortedColumn],
+    by_column: &[ExprIR],
+    sort_options: &SortMultipleOptions,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    let broadcast = |v: &[bool], i: usize| if v.len() == 1 { v[0] } else { v[i] };
+    by_column.len() <= sorted.len()
+        && by_column.iter().enumerate().all(|(i, e)| {
+            matches!(expr_arena.get(e.node()), AExpr::Column(name) if name == &sorted[i].name)
+                && broadcast(&sort_options.descending, i) == sorted[i].descending
+                && Some(broadcast(&sort_options.nulls_last, i)) == sorted[i].nulls_last
+        })
+}
+
+/// Removes sorts of data that is already sorted, and fills the `sorting_columns` of Parquet sinks
+/// with the sort order of their input.
+pub(super) fn optimize(lp_top: Node, ir_arena: &mut Arena<IR>, expr_arena: &Arena<AExpr>) {
+    let mut stack = vec![lp_top];
+
+    while let Some(node) = stack.pop() {
+        match ir_arena.get(node) {
+            IR::Sort {
+                input,
+                by_column,
+                slice,
+                sort_options,
+            } => {
+                let input = *input;
+                let sorted = output_sortedness(input, ir_arena, expr_arena);
+                if is_sorted_by(&sorted, by_column, sort_options, expr_arena) {
+                    let slice = *slice;
+                    match slice {
+                        None => {
+                            let input = ir_arena.get(input).clone();
+                            ir_arena.replace(node, input);
+                        },
+                        Some((offset, len)) => {
+                            ir_arena.replace(
+                                node,
+                                IR::Slice {
+                                    input,
+                                    offset,
+                                    len: len as IdxSize,
+                                },
+                            );
+                        },
+                    }
+                    // Revisit the node, its input may be another sort.
+                    stack.push(node);
+                    continue;
+                }
+            },
+            #[cfg(feature = "parquet")]
+            IR::Sink { input, payload } => {
+                let input = *input;
+                let sorted = match payload {
+                    SinkTypeIR::File(FileSinkType {
+                        file_type: FileType::Parquet(options),
+                        sink_options,
+                        ..
+                    }) if options.sorting_columns.is_empty() && sink_options.maintain_order => {
+                        Some(output_sortedness(input, ir_arena, expr_arena))
+                    },
+                    SinkTypeIR::Partition(PartitionSinkTypeIR {
+                        file_type: FileType::Parquet(options),
+                        sink_options,
+                        per_partition_sort_by,
+                        ..
+                    }) if options.sorting_columns.is_empty() => {
+                        Some(match per_partition_sort_by {
+                            Some(sort_by) => sort_by
+                                .iter()
+                                .map_while(|s| match expr_arena.get(s.expr.node()) {
+                                    AExpr::Column(name) => Some(SortedColumn {
+                                        name: name.clone(),
+                                        descending: s.descending,
+                                        nulls_last: Some(s.nulls_last),
+                                    }),
+                                    _ => None,
+                                })
+                                .collect(),
+                            // Partitioning keeps the order of the rows within a partition.
+                            None if sink_options.maintain_order => {
+                                output_sortedness(input, ir_arena, expr_arena)
+                            },
+                            None => Vec::new(),
+                        })
+                    },
+                    _ => None,
+                };
+
+                // Parquet requires the placement of the nulls, so we can only record the columns
+                // up to the first for which it is unknown.
+                let sorting_columns: Vec<_> = sorted
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map_while(|c| {
+                        Some(ParquetSortingColumn {
+                            name: c.name,
+                            descending: c.descending,
+                            nulls_last: c.nulls_last?,
+                        })
+                    })
+                    .collect();
+                if !sorting_columns.is_empty() {
+                    if let IR::Sink {
+                        payload:
+                            SinkTypeIR::File(FileSinkType {
+                                file_type: FileType::Parquet(options),
+                                ..
+                            })
+                            | SinkTypeIR::Partition(PartitionSinkTypeIR {
+                                file_type: FileType::Parquet(options),
+                                ..
+                            }),
+                        ..
+                    } = ir_arena.get_mut(node)
+                    {
+                        options.sorting_columns = sorting_columns;
+                    }
+                }
+            },
+            _ => {},
+        }
+
+        ir_arena.get(node).copy_inputs(&mut stack);
+    }
+}
@@ -26,7 +26,7 @@ impl PyBatchedCsv {
     #[pyo3(signature = (
         infer_schema_length, chunk_size, has_header, ignore_errors, n_rows, skip_rows, skip_lines,
         projection, separator, rechunk, columns, encoding, n_threads, path, schema_overrides,
-        overwrite_dtype_slice, low_memory, comment_prefix, quote_char, null_values,
+        overwrite_dtype_slice, low_memory, comment_prefix, quote_char, escape_char, null_values,
         missing_utf8_is_empty_string, try_parse_dates, skip_rows_after_header, row_index,
         eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma)
     )]
@@ -50,6 +50,7 @@ impl PyBatchedCsv {
         low_memory: bool,
         comment_prefix: Option<&str>,
         quote_char: Option<&str>,
+        escape_char: Option<&str>,
         null_values: Option<Wrap<NullValues>>,
         missing_utf8_is_empty_string: bool,
         try_parse_dates: bool,
@@ -61,7 +62,6 @@ impl PyBatchedCsv {
         decimal_comma: bool,
     ) -> PyResult<PyBatchedCsv> {
         let null_values = null_values.map(|w| w.0);
-        let eol_char = eol_char.as_bytes()[0];
         let row_index = row_index.map(|(name, offset)| RowIndex {
             name: name.into(),
             offset,
@@ -75,6 +75,7 @@ impl PyBatchedCsv {
         } else {
             None
         };
+        let escape_char = escape_char.and_then(|s| s.as_bytes().first().copied());
 
         let schema_overrides = schema_overrides.map(|overwrite_dtype| {
             overwrite_dtype
@@ -116,13 +117,16 @@ impl PyBatchedCsv {
             .with_parse_options(
                 CsvParseOptions::default()
                     .with_separator(separator.as_bytes()[0])
+                    .with_multi_byte_separator((separator.len() > 1).then(|| separator.into()))
                     .with_encoding(encoding.0)
                     .with_missing_is_null(!missing_utf8_is_empty_string)
                     .with_comment_prefix(comment_prefix)
                     .with_null_values(null_values)
                     .with_try_parse_dates(try_parse_dates)
                     .with_quote_char(quote_char)
-                    .with_eol_char(eol_char)
+                    .with_escape_char(escape_char)
+                    .with_eol_char(eol_char.as_bytes()[0])
+                    .with_multi_byte_eol((eol_char.len() > 1).then(|| eol_char.into()))
                     .with_truncate_ragged_lines(truncate_ragged_lines)
                     .with_decimal_comma(decimal_comma),
             )
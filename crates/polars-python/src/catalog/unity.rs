@@ -271,6 +271,52 @@ impl PyCatalogClient {
         )
     }
 
+    #[cfg(feature = "delta")]
+    #[pyo3(signature = (lf, table_info, mode, cloud_options, credential_provider, retries))]
+    pub fn sink_table(
+        &self,
+        py: Python<'_>,
+        lf: PyLazyFrame,
+        table_info: &Bound<'_, PyAny>,
+        mode: &str,
+        cloud_options: Option<Vec<(String, String)>>,
+        credential_provider: Option<PyObject>,
+        retries: usize,
+    ) -> PyResult<PyLazyFrame> {
+        use polars_io::delta::DeltaWriteMode;
+
+        let mode = match mode {
+            "append" => DeltaWriteMode::Append,
+            "overwrite" => DeltaWriteMode::Overwrite,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`mode` must be one of {{'append', 'overwrite'}}, got {v}"
+                )));
+            },
+        };
+
+        let table_info = table_info_from_pyobject(table_info)?;
+
+        let Some(storage_location) = table_info.storage_location.as_deref() else {
+            return Err(PyValueError::new_err(
+                "cannot sink to catalog table: no storage_location found",
+            ));
+        };
+
+        let cloud_options =
+            parse_cloud_options(storage_location, cloud_options.unwrap_or_default())?
+                .with_max_retries(retries)
+                .with_credential_provider(
+                    credential_provider.map(PlCredentialProvider::from_python_builder),
+                );
+
+        py.enter_polars(|| {
+            lf.ldf
+                .sink_catalog_table(&table_info, mode, Some(cloud_options))
+        })
+        .map(Into::into)
+    }
+
     #[pyo3(signature = (catalog_name, comment, storage_root))]
     pub fn create_catalog(
         &self,
@@ -589,6 +635,64 @@ fn table_info_to_pyobject(py: Python<'_>, table_info: TableInfo) -> PyResult<Bou
         .call((), Some(&dict))
 }
 
+/// Converts a `TableInfo` dataclass that was previously returned by [`table_info_to_pyobject`]
+/// back into a [`TableInfo`].
+#[cfg(feature = "delta")]
+fn table_info_from_pyobject(table_info: &Bound<'_, PyAny>) -> PyResult<TableInfo> {
+    let columns = table_info
+        .getattr("columns")?
+        .extract::<Option<Vec<Bound<'_, PyAny>>>>()?
+        .map(|columns| {
+            columns
+                .iter()
+                .map(|column| {
+                    Ok(ColumnInfo {
+                        name: column.getattr("name")?.extract::<String>()?.into(),
+                        type_name: column.getattr("type_name")?.extract::<String>()?.into(),
+                        type_text: column.getattr("type_text")?.extract::<String>()?.into(),
+                        type_json: column.getattr("type_json")?.extract()?,
+                        position: column.getattr("position")?.extract()?,
+                        comment: column.getattr("comment")?.extract()?,
+                        partition_index: column.getattr("partition_index")?.extract()?,
+                    })
+                })
+                .collect::<PyResult<Vec<_>>>()
+        })
+        .transpose()?;
+
+    let table_type = TableType::from_str(&table_info.getattr("table_type")?.extract::<String>()?)
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+    let data_source_format = table_info
+        .getattr("data_source_format")?
+        .extract::<Option<String>>()?
+        .map(|v| DataSourceFormat::from_str(&v))
+        .transpose()
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+    let properties = table_info
+        .getattr("properties")?
+        .extract::<std::collections::HashMap<String, String>>()?
+        .into_iter()
+        .map(|(k, v)| (PlSmallStr::from_string(k), v))
+        .collect();
+
+    Ok(TableInfo {
+        name: table_info.getattr("name")?.extract()?,
+        table_id: table_info.getattr("table_id")?.extract()?,
+        table_type,
+        comment: table_info.getattr("comment")?.extract()?,
+        storage_location: table_info.getattr("storage_location")?.extract()?,
+        data_source_format,
+        columns,
+        properties,
+        created_at: table_info.getattr("created_at")?.extract()?,
+        created_by: table_info.getattr("created_by")?.extract()?,
+        updated_at: table_info.getattr("updated_at")?.extract()?,
+        updated_by: table_info.getattr("updated_by")?.extract()?,
+    })
+}
+
 fn properties_to_pyobject(
     py: Python<'_>,
     properties: PlHashMap<PlSmallStr, String>,
@@ -16,8 +16,12 @@ use polars::frame::row::Row;
 use polars::io::avro::AvroCompression;
 #[cfg(feature = "cloud")]
 use polars::io::cloud::CloudOptions;
+#[cfg(any(feature = "csv", feature = "json"))]
+use polars::io::utils::compression::ExternalCompression;
 use polars::prelude::ColumnMapping;
-use polars::prelude::deletion::DeletionFilesList;
+use polars::prelude::deletion::{
+    DeletionFilesList, IcebergDeleteContent, IcebergDeleteFile, IcebergDeleteFiles,
+};
 use polars::series::ops::NullBehavior;
 use polars_core::schema::iceberg::IcebergSchema;
 use polars_core::utils::arrow::array::Array;
@@ -926,9 +930,19 @@ impl<'py> FromPyObject<'py> for Wrap<CsvEncoding> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
             "utf8" => CsvEncoding::Utf8,
             "utf8-lossy" => CsvEncoding::LossyUtf8,
+            "latin1" => CsvEncoding::Latin1,
+            "windows-1250" => CsvEncoding::Windows1250,
+            "windows-1251" => CsvEncoding::Windows1251,
+            "windows-1252" => CsvEncoding::Windows1252,
+            "utf16-le" => CsvEncoding::Utf16Le,
+            "utf16-be" => CsvEncoding::Utf16Be,
+            "shift-jis" => CsvEncoding::ShiftJis,
+            "gb18030" => CsvEncoding::Gb18030,
+            "euc-kr" => CsvEncoding::EucKr,
+            "big5" => CsvEncoding::Big5,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "csv `encoding` must be one of {{'utf8', 'utf8-lossy'}}, got {v}",
+                    "csv `encoding` must be one of {{'utf8', 'utf8-lossy', 'latin1', 'windows-1250', 'windows-1251', 'windows-1252', 'utf16-le', 'utf16-be', 'shift-jis', 'gb18030', 'euc-kr', 'big5'}}, got {v}",
                 )));
             },
         };
@@ -1069,6 +1083,27 @@ impl<'py> FromPyObject<'py> for Wrap<ParallelStrategy> {
     }
 }
 
+#[cfg(feature = "parquet")]
+impl<'py> FromPyObject<'py> for Wrap<polars_io::parquet::write::ParquetEncoding> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        use polars_io::parquet::write::ParquetEncoding;
+
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "plain" => ParquetEncoding::Plain,
+            "delta_binary_packed" => ParquetEncoding::DeltaBinaryPacked,
+            "delta_length_byte_array" => ParquetEncoding::DeltaLengthByteArray,
+            "delta_byte_array" => ParquetEncoding::DeltaByteArray,
+            "byte_stream_split" => ParquetEncoding::ByteStreamSplit,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`encoding` must be one of {{'plain', 'delta_binary_packed', 'delta_length_byte_array', 'delta_byte_array', 'byte_stream_split'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<IndexOrder> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1505,6 +1540,46 @@ pub(crate) fn parse_parquet_compression(
     Ok(parsed)
 }
 
+#[cfg(any(feature = "csv", feature = "json"))]
+pub(crate) fn parse_external_compression(
+    compression: &str,
+    compression_level: Option<i32>,
+) -> PyResult<ExternalCompression> {
+    let unsigned_level = || {
+        compression_level
+            .map(|lvl| {
+                u32::try_from(lvl).map_err(|_| {
+                    PyValueError::new_err(format!("invalid `compression_level` {lvl}"))
+                })
+            })
+            .transpose()
+    };
+    let parsed = match compression {
+        "uncompressed" => ExternalCompression::Uncompressed,
+        "gzip" => ExternalCompression::Gzip {
+            level: unsigned_level()?,
+        },
+        "zstd" => ExternalCompression::Zstd {
+            level: compression_level,
+        },
+        "bzip2" => ExternalCompression::Bzip2 {
+            level: unsigned_level()?,
+        },
+        "xz" => ExternalCompression::Xz {
+            level: unsigned_level()?,
+        },
+        "lz4" => ExternalCompression::Lz4 {
+            level: unsigned_level()?,
+        },
+        e => {
+            return Err(PyValueError::new_err(format!(
+                "`compression` must be one of {{'uncompressed', 'gzip', 'zstd', 'bzip2', 'xz', 'lz4'}}, got {e}",
+            )));
+        },
+    };
+    Ok(parsed)
+}
+
 pub(crate) fn strings_to_pl_smallstr<I, S>(container: I) -> Vec<PlSmallStr>
 where
     I: IntoIterator<Item = S>,
@@ -1707,6 +1782,60 @@ impl<'py> FromPyObject<'py> for Wrap<DeletionFilesList> {
                 DeletionFilesList::IcebergPositionDelete(Arc::new(out))
             },
 
+            "iceberg-delete" => {
+                let dict: Bound<'_, PyDict> = ob.extract()?;
+
+                let mut out = PlIndexMap::new();
+
+                for (k, v) in dict
+                    .try_iter()?
+                    .zip(dict.call_method0("values")?.try_iter()?)
+                {
+                    let k: usize = k?.extract()?;
+                    let (data_file_path, data_sequence_number, v): (String, i64, Bound<'_, PyAny>) =
+                        v?.extract()?;
+
+                    let files = v
+                        .try_iter()?
+                        .map(|x| {
+                            x.and_then(|x| {
+                                let (path, data_sequence_number, equality_ids): (
+                                    String,
+                                    i64,
+                                    Option<Vec<u32>>,
+                                ) = x.extract()?;
+
+                                let content = match equality_ids {
+                                    None => IcebergDeleteContent::Position,
+                                    Some(ids) => IcebergDeleteContent::Equality {
+                                        equality_ids: ids.into(),
+                                    },
+                                };
+
+                                Ok(IcebergDeleteFile {
+                                    path,
+                                    data_sequence_number,
+                                    content,
+                                })
+                            })
+                        })
+                        .collect::<PyResult<Arc<[IcebergDeleteFile]>>>()?;
+
+                    if !files.is_empty() {
+                        out.insert(
+                            k,
+                            IcebergDeleteFiles {
+                                data_file_path,
+                                data_sequence_number,
+                                files,
+                            },
+                        );
+                    }
+                }
+
+                DeletionFilesList::IcebergDelete(Arc::new(out))
+            },
+
             v => {
                 return Err(PyValueError::new_err(format!(
                     "unknown deletion file type: {v}"
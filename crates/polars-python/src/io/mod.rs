@@ -131,6 +131,7 @@ impl PyScanOptions<'_> {
             extra_columns_policy: extra_columns.0,
             include_file_paths: include_file_paths.map(|x| x.0),
             deletion_files: DeletionFilesList::filter_empty(deletion_files.map(|x| x.0)),
+            file_statistics: None,
             column_mapping: column_mapping.map(|x| x.0),
         };
 
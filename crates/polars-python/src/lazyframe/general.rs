@@ -151,7 +151,7 @@ impl PyLazyFrame {
     #[staticmethod]
     #[cfg(feature = "csv")]
     #[pyo3(signature = (source, sources, separator, has_header, ignore_errors, skip_rows, skip_lines, n_rows, cache, overwrite_dtype,
-        low_memory, comment_prefix, quote_char, null_values, missing_utf8_is_empty_string,
+        low_memory, comment_prefix, quote_char, escape_char, null_values, missing_utf8_is_empty_string,
         infer_schema_length, with_schema_modify, rechunk, skip_rows_after_header,
         encoding, row_index, try_parse_dates, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, glob, schema,
         cloud_options, credential_provider, retries, file_cache_ttl, include_file_paths
@@ -171,6 +171,7 @@ impl PyLazyFrame {
         low_memory: bool,
         comment_prefix: Option<&str>,
         quote_char: Option<&str>,
+        escape_char: Option<&str>,
         null_values: Option<Wrap<NullValues>>,
         missing_utf8_is_empty_string: bool,
         infer_schema_length: Option<usize>,
@@ -197,12 +198,15 @@ impl PyLazyFrame {
 
         let null_values = null_values.map(|w| w.0);
         let quote_char = quote_char.and_then(|s| s.as_bytes().first()).copied();
+        let escape_char = escape_char.and_then(|s| s.as_bytes().first()).copied();
+        let multi_byte_separator = (separator.len() > 1).then(|| separator.into());
         let separator = separator
             .as_bytes()
             .first()
             .ok_or_else(|| polars_err!(InvalidOperation: "`separator` cannot be empty"))
             .copied()
             .map_err(PyPolarsErr::from)?;
+        let multi_byte_eol = (eol_char.len() > 1).then(|| eol_char.into());
         let eol_char = eol_char
             .as_bytes()
             .first()
@@ -249,6 +253,7 @@ impl PyLazyFrame {
         let mut r = r
             .with_infer_schema_length(infer_schema_length)
             .with_separator(separator)
+            .with_multi_byte_separator(multi_byte_separator)
             .with_has_header(has_header)
             .with_ignore_errors(ignore_errors)
             .with_skip_rows(skip_rows)
@@ -260,7 +265,9 @@ impl PyLazyFrame {
             .with_low_memory(low_memory)
             .with_comment_prefix(comment_prefix.map(|x| x.into()))
             .with_quote_char(quote_char)
+            .with_escape_char(escape_char)
             .with_eol_char(eol_char)
+            .with_multi_byte_eol(multi_byte_eol)
             .with_rechunk(rechunk)
             .with_skip_rows_after_header(skip_rows_after_header)
             .with_encoding(encoding.0)
@@ -303,7 +310,7 @@ impl PyLazyFrame {
     #[cfg(feature = "parquet")]
     #[staticmethod]
     #[pyo3(signature = (
-        sources, schema, scan_options, parallel, low_memory, use_statistics
+        sources, schema, scan_options, parallel, low_memory, use_statistics, decryption=None
     ))]
     fn new_from_parquet(
         sources: Wrap<ScanSources>,
@@ -312,6 +319,7 @@ impl PyLazyFrame {
         parallel: Wrap<ParallelStrategy>,
         low_memory: bool,
         use_statistics: bool,
+        decryption: Option<Wrap<polars_io::parquet::encryption::ParquetDecryptionOptions>>,
     ) -> PyResult<Self> {
         use crate::utils::to_py_err;
 
@@ -322,6 +330,7 @@ impl PyLazyFrame {
             parallel,
             low_memory,
             use_statistics,
+            decryption: decryption.map(|d| d.0),
         };
 
         let sources = sources.0;
@@ -413,6 +422,142 @@ impl PyLazyFrame {
         Ok(lf.into())
     }
 
+    #[cfg(feature = "avro")]
+    #[staticmethod]
+    #[pyo3(signature = (
+        source, sources, n_rows, cache, rechunk, row_index, cloud_options, credential_provider,
+        hive_partitioning, hive_schema, try_parse_hive_dates, retries, file_cache_ttl,
+        include_file_paths
+    ))]
+    fn new_from_avro(
+        source: Option<PyObject>,
+        sources: Wrap<ScanSources>,
+        n_rows: Option<usize>,
+        cache: bool,
+        rechunk: bool,
+        row_index: Option<(String, IdxSize)>,
+        cloud_options: Option<Vec<(String, String)>>,
+        credential_provider: Option<PyObject>,
+        hive_partitioning: Option<bool>,
+        hive_schema: Option<Wrap<Schema>>,
+        try_parse_hive_dates: bool,
+        retries: usize,
+        file_cache_ttl: Option<u64>,
+        include_file_paths: Option<String>,
+    ) -> PyResult<Self> {
+        #[cfg(feature = "cloud")]
+        use cloud::credential_provider::PlCredentialProvider;
+        let row_index = row_index.map(|(name, offset)| RowIndex {
+            name: name.into(),
+            offset,
+        });
+
+        let hive_options = HiveOptions {
+            enabled: hive_partitioning,
+            hive_start_idx: 0,
+            schema: hive_schema.map(|x| Arc::new(x.0)),
+            try_parse_dates: try_parse_hive_dates,
+        };
+
+        let mut args = ScanArgsAvro {
+            n_rows,
+            cache,
+            rechunk,
+            row_index,
+            cloud_options: None,
+            hive_options,
+            include_file_paths: include_file_paths.map(|x| x.into()),
+        };
+
+        let sources = sources.0;
+        let (first_path, sources) = match source {
+            None => (sources.first_path().map(|p| p.into_owned()), sources),
+            Some(source) => pyobject_to_first_path_and_scan_sources(source)?,
+        };
+
+        #[cfg(feature = "cloud")]
+        if let Some(first_path) = first_path {
+            let first_path_url = first_path.to_str();
+
+            let mut cloud_options =
+                parse_cloud_options(first_path_url, cloud_options.unwrap_or_default())?;
+            if let Some(file_cache_ttl) = file_cache_ttl {
+                cloud_options.file_cache_ttl = file_cache_ttl;
+            }
+            args.cloud_options = Some(
+                cloud_options
+                    .with_max_retries(retries)
+                    .with_credential_provider(
+                        credential_provider.map(PlCredentialProvider::from_python_builder),
+                    ),
+            );
+        }
+
+        let lf = LazyFrame::scan_avro_sources(sources, args).map_err(PyPolarsErr::from)?;
+        Ok(lf.into())
+    }
+
+    #[cfg(feature = "delta")]
+    #[staticmethod]
+    #[pyo3(signature = (
+        table_uri, version, timestamp_ms, n_rows, cache, rechunk, row_index, cloud_options,
+        credential_provider, retries, include_file_paths
+    ))]
+    fn new_from_delta(
+        table_uri: String,
+        version: Option<i64>,
+        timestamp_ms: Option<i64>,
+        n_rows: Option<usize>,
+        cache: bool,
+        rechunk: bool,
+        row_index: Option<(String, IdxSize)>,
+        cloud_options: Option<Vec<(String, String)>>,
+        credential_provider: Option<PyObject>,
+        retries: usize,
+        include_file_paths: Option<String>,
+    ) -> PyResult<Self> {
+        #[cfg(feature = "cloud")]
+        use cloud::credential_provider::PlCredentialProvider;
+        use polars::io::delta::DeltaTableVersion;
+
+        let version = match (version, timestamp_ms) {
+            (Some(version), _) => Some(DeltaTableVersion::Version(version)),
+            (None, Some(timestamp_ms)) => Some(DeltaTableVersion::Timestamp(timestamp_ms)),
+            (None, None) => None,
+        };
+
+        let mut args = ScanArgsDelta {
+            version,
+            n_rows,
+            cache,
+            rechunk,
+            row_index: row_index.map(|(name, offset)| RowIndex {
+                name: name.into(),
+                offset,
+            }),
+            include_file_paths: include_file_paths.map(|x| x.into()),
+            ..Default::default()
+        };
+
+        let table_root = PlPath::new(&table_uri);
+
+        #[cfg(feature = "cloud")]
+        {
+            let cloud_options =
+                parse_cloud_options(table_root.to_str(), cloud_options.unwrap_or_default())?;
+            args.cloud_options = Some(
+                cloud_options
+                    .with_max_retries(retries)
+                    .with_credential_provider(
+                        credential_provider.map(PlCredentialProvider::from_python_builder),
+                    ),
+            );
+        }
+
+        let lf = LazyFrame::scan_delta(table_root, args).map_err(PyPolarsErr::from)?;
+        Ok(lf.into())
+    }
+
     #[staticmethod]
     #[pyo3(signature = (
         dataset_object
@@ -706,6 +851,7 @@ impl PyLazyFrame {
     #[pyo3(signature = (
         target, compression, compression_level, statistics, row_group_size, data_page_size,
         cloud_options, credential_provider, retries, sink_options, metadata, field_overwrites,
+        encryption=None,
     ))]
     fn sink_parquet(
         &self,
@@ -722,6 +868,7 @@ impl PyLazyFrame {
         sink_options: Wrap<SinkOptions>,
         metadata: Wrap<Option<KeyValueMetadata>>,
         field_overwrites: Vec<Wrap<ParquetFieldOverwrites>>,
+        encryption: Option<Wrap<polars_io::parquet::encryption::ParquetEncryptionOptions>>,
     ) -> PyResult<PyLazyFrame> {
         let compression = parse_parquet_compression(compression, compression_level)?;
 
@@ -732,6 +879,8 @@ impl PyLazyFrame {
             data_page_size,
             key_value_metadata: metadata.0,
             field_overwrites: field_overwrites.into_iter().map(|f| f.0).collect(),
+            encryption: encryption.map(|e| Arc::new(e.0)),
+            sorting_columns: Vec::new(),
         };
 
         let cloud_options = match target.base_path() {
@@ -835,11 +984,74 @@ impl PyLazyFrame {
         .map_err(Into::into)
     }
 
+    #[cfg(feature = "avro")]
+    #[pyo3(signature = (
+        target, compression, name, cloud_options, credential_provider, retries, sink_options
+    ))]
+    fn sink_avro(
+        &self,
+        py: Python<'_>,
+        target: SinkTarget,
+        compression: Wrap<Option<polars::io::avro::AvroCompression>>,
+        name: String,
+        cloud_options: Option<Vec<(String, String)>>,
+        credential_provider: Option<PyObject>,
+        retries: usize,
+        sink_options: Wrap<SinkOptions>,
+    ) -> PyResult<PyLazyFrame> {
+        let options = polars::io::avro::AvroWriterOptions {
+            compression: compression.0,
+            name: name.into(),
+        };
+
+        #[cfg(feature = "cloud")]
+        let cloud_options = match target.base_path() {
+            None => None,
+            Some(base_path) => {
+                let cloud_options =
+                    parse_cloud_options(base_path.to_str(), cloud_options.unwrap_or_default())?;
+                Some(
+                    cloud_options
+                        .with_max_retries(retries)
+                        .with_credential_provider(
+                            credential_provider.map(polars::prelude::cloud::credential_provider::PlCredentialProvider::from_python_builder),
+                        ),
+                )
+            },
+        };
+
+        #[cfg(not(feature = "cloud"))]
+        let cloud_options = None;
+
+        py.enter_polars(|| {
+            let ldf = self.ldf.clone();
+            match target {
+                SinkTarget::File(target) => {
+                    ldf.sink_avro(target, options, cloud_options, sink_options.0)
+                },
+                SinkTarget::Partition(partition) => ldf.sink_avro_partitioned(
+                    Arc::new(partition.base_path.0),
+                    partition.file_path_cb.map(PartitionTargetCallback::Python),
+                    partition.variant,
+                    options,
+                    cloud_options,
+                    sink_options.0,
+                    partition.per_partition_sort_by,
+                    partition.finish_callback,
+                ),
+            }
+        })
+        .map(Into::into)
+        .map_err(Into::into)
+    }
+
     #[cfg(feature = "csv")]
     #[pyo3(signature = (
-        target, include_bom, include_header, separator, line_terminator, quote_char, batch_size,
+        target, include_bom, include_header, encoding, separator, line_terminator, quote_char,
+        batch_size,
         datetime_format, date_format, time_format, float_scientific, float_precision, decimal_comma, null_value,
-        quote_style, cloud_options, credential_provider, retries, sink_options
+        quote_style, compression, compression_level, cloud_options, credential_provider, retries,
+        sink_options
     ))]
     fn sink_csv(
         &self,
@@ -847,6 +1059,7 @@ impl PyLazyFrame {
         target: SinkTarget,
         include_bom: bool,
         include_header: bool,
+        encoding: Wrap<CsvEncoding>,
         separator: u8,
         line_terminator: String,
         quote_char: u8,
@@ -859,6 +1072,8 @@ impl PyLazyFrame {
         decimal_comma: bool,
         null_value: Option<String>,
         quote_style: Option<Wrap<QuoteStyle>>,
+        compression: &str,
+        compression_level: Option<i32>,
         cloud_options: Option<Vec<(String, String)>>,
         credential_provider: Option<PyObject>,
         retries: usize,
@@ -884,8 +1099,10 @@ impl PyLazyFrame {
         let options = CsvWriterOptions {
             include_bom,
             include_header,
+            encoding: encoding.0,
             batch_size,
             serialize_options,
+            compression: parse_external_compression(compression, compression_level)?,
         };
 
         #[cfg(feature = "cloud")]
@@ -931,17 +1148,24 @@ impl PyLazyFrame {
 
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "json")]
-    #[pyo3(signature = (target, cloud_options, credential_provider, retries, sink_options))]
+    #[pyo3(signature = (
+        target, compression, compression_level, cloud_options, credential_provider, retries,
+        sink_options
+    ))]
     fn sink_json(
         &self,
         py: Python<'_>,
         target: SinkTarget,
+        compression: &str,
+        compression_level: Option<i32>,
         cloud_options: Option<Vec<(String, String)>>,
         credential_provider: Option<PyObject>,
         retries: usize,
         sink_options: Wrap<SinkOptions>,
     ) -> PyResult<PyLazyFrame> {
-        let options = JsonWriterOptions {};
+        let options = JsonWriterOptions {
+            compression: parse_external_compression(compression, compression_level)?,
+        };
 
         let cloud_options = match target.base_path() {
             None => None,
@@ -1554,7 +1778,9 @@ impl PyLazyFrame {
 #[cfg(feature = "parquet")]
 impl<'py> FromPyObject<'py> for Wrap<polars_io::parquet::write::ParquetFieldOverwrites> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
-        use polars_io::parquet::write::ParquetFieldOverwrites;
+        use polars_io::parquet::write::{
+            ParquetBloomFilterOptions, ParquetEncoding, ParquetFieldOverwrites,
+        };
 
         let parsed = ob.extract::<pyo3::Bound<'_, PyDict>>()?;
 
@@ -1596,12 +1822,114 @@ impl<'py> FromPyObject<'py> for Wrap<polars_io::parquet::write::ParquetFieldOver
             .map(|v| v.extract::<bool>())
             .transpose()?;
 
+        let bloom_filter = PyDictMethods::get_item(&parsed, "bloom_filter")?
+            .map(|v| {
+                let (ndv, fpp) = v.extract::<(Option<u64>, Option<f64>)>()?;
+                ParquetBloomFilterOptions::try_new(ndv, fpp).map_err(PyPolarsErr::from)
+            })
+            .transpose()?;
+
+        let encoding = PyDictMethods::get_item(&parsed, "encoding")?
+            .map(|v| PyResult::Ok(v.extract::<Wrap<ParquetEncoding>>()?.0))
+            .transpose()?;
+
+        let dictionary = PyDictMethods::get_item(&parsed, "dictionary")?
+            .map(|v| v.extract::<bool>())
+            .transpose()?;
+
+        let compression = PyDictMethods::get_item(&parsed, "compression")?
+            .map(|v| {
+                let (compression, compression_level) = v.extract::<(String, Option<i32>)>()?;
+                parse_parquet_compression(&compression, compression_level)
+            })
+            .transpose()?;
+
+        let statistics = PyDictMethods::get_item(&parsed, "statistics")?
+            .map(|v| PyResult::Ok(v.extract::<Wrap<StatisticsOptions>>()?.0))
+            .transpose()?;
+
         Ok(Wrap(ParquetFieldOverwrites {
             name,
             children,
             field_id,
             metadata,
             required,
+            bloom_filter,
+            encoding,
+            dictionary,
+            compression,
+            statistics,
+        }))
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl<'py> FromPyObject<'py> for Wrap<polars_io::parquet::encryption::ParquetKeyRetriever> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        use polars_io::parquet::encryption::ParquetKeyRetriever;
+
+        #[derive(FromPyObject)]
+        enum Keys {
+            Static(Vec<(String, Vec<u8>)>),
+            Dynamic(PyObject),
+        }
+
+        Ok(Wrap(match Keys::extract_bound(ob)? {
+            Keys::Static(keys) => ParquetKeyRetriever::from_static(
+                keys.into_iter().map(|(id, key)| (id.into(), key)).collect(),
+            ),
+            Keys::Dynamic(func) => ParquetKeyRetriever::from_py_function(func),
         }))
     }
 }
+
+#[cfg(feature = "parquet")]
+impl<'py> FromPyObject<'py> for Wrap<polars_io::parquet::encryption::ParquetEncryptionOptions> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        use polars_io::parquet::encryption::{ParquetEncryptionOptions, ParquetKeyRetriever};
+
+        let parsed = ob.extract::<pyo3::Bound<'_, PyDict>>()?;
+        let get = |key: &str| {
+            PyDictMethods::get_item(&parsed, key)?
+                .ok_or_else(|| PyTypeError::new_err(format!("'{key}' is missing")))
+        };
+
+        let footer_key_id = get("footer_key_id")?.extract::<String>()?.into();
+        let column_key_ids = get("column_key_ids")?
+            .extract::<Vec<(String, String)>>()?
+            .into_iter()
+            .map(|(column, key_id)| (column.into(), key_id.into()))
+            .collect();
+        let keys = get("keys")?.extract::<Wrap<ParquetKeyRetriever>>()?.0;
+        let aad_prefix = get("aad_prefix")?.extract::<Option<Vec<u8>>>()?;
+        let store_aad_prefix = get("store_aad_prefix")?.extract::<bool>()?;
+
+        Ok(Wrap(ParquetEncryptionOptions {
+            footer_key_id,
+            column_key_ids,
+            keys,
+            aad_prefix,
+            store_aad_prefix,
+        }))
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl<'py> FromPyObject<'py> for Wrap<polars_io::parquet::encryption::ParquetDecryptionOptions> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        use polars_io::parquet::encryption::{ParquetDecryptionOptions, ParquetKeyRetriever};
+
+        let parsed = ob.extract::<pyo3::Bound<'_, PyDict>>()?;
+
+        let keys = PyDictMethods::get_item(&parsed, "keys")?
+            .ok_or_else(|| PyTypeError::new_err("'keys' is missing"))?
+            .extract::<Wrap<ParquetKeyRetriever>>()?
+            .0;
+        let aad_prefix = PyDictMethods::get_item(&parsed, "aad_prefix")?
+            .map(|v| v.extract::<Option<Vec<u8>>>())
+            .transpose()?
+            .flatten();
+
+        Ok(Wrap(ParquetDecryptionOptions { keys, aad_prefix }))
+    }
+}
@@ -58,6 +58,7 @@ flag_getter_setters! {
     (COLLAPSE_JOINS, get_collapse_joins, set_collapse_joins, clear=true)
     (CHECK_ORDER_OBSERVE, get_check_order_observe, set_check_order_observe, clear=true)
     (FAST_PROJECTION, get_fast_projection, set_fast_projection, clear=true)
+    (SORTEDNESS, get_sortedness, set_sortedness, clear=true)
 
     (EAGER, get_eager, set_eager, clear=true)
     (NEW_STREAMING, get_streaming, set_streaming, clear=true)
@@ -1,7 +1,9 @@
 use polars::prelude::ColumnMapping;
 #[cfg(feature = "iejoin")]
 use polars::prelude::JoinTypeOptionsIR;
-use polars::prelude::deletion::DeletionFilesList;
+use polars::prelude::deletion::{
+    DeletionFilesList, DeltaDeletionVectorStorage, IcebergDeleteContent,
+};
 use polars::prelude::python_dsl::PythonScanSource;
 use polars_core::prelude::IdxSize;
 use polars_io::cloud::CloudOptions;
@@ -41,6 +43,8 @@ fn scan_type_to_pyobject(
         },
         #[cfg(feature = "ipc")]
         FileScanIR::Ipc { .. } => Err(PyNotImplementedError::new_err("ipc scan")),
+        #[cfg(feature = "avro")]
+        FileScanIR::Avro { .. } => Err(PyNotImplementedError::new_err("avro scan")),
         #[cfg(feature = "json")]
         FileScanIR::NDJson { options, .. } => {
             let options = serde_json::to_string(options)
@@ -133,6 +137,8 @@ impl PyFileOptions {
     /// One of:
     /// * None
     /// * ("iceberg-position-delete", dict[int, list[str]])
+    /// * ("iceberg-delete", dict[int, tuple[str, int, list[tuple[str, int, list[int] | None]]]])
+    /// * ("delta-deletion-vector", dict[int, tuple[tuple[str, str] | tuple[str, str, int], int, int]])
     #[getter]
     fn deletion_files(&self, py: Python<'_>) -> PyResult<PyObject> {
         Ok(match &self.inner.deletion_files {
@@ -150,6 +156,63 @@ impl PyFileOptions {
                     .into_any()
                     .unbind()
             },
+
+            Some(DeletionFilesList::IcebergDelete(files)) => {
+                let out = PyDict::new(py);
+
+                for (k, v) in files.iter() {
+                    let delete_files = v
+                        .files
+                        .iter()
+                        .map(|file| {
+                            let equality_ids = match &file.content {
+                                IcebergDeleteContent::Position => None,
+                                IcebergDeleteContent::Equality { equality_ids } => {
+                                    Some(equality_ids.as_ref())
+                                },
+                            };
+
+                            (file.path.as_str(), file.data_sequence_number, equality_ids)
+                        })
+                        .collect::<Vec<_>>();
+
+                    out.set_item(
+                        *k,
+                        (
+                            v.data_file_path.as_str(),
+                            v.data_sequence_number,
+                            delete_files,
+                        ),
+                    )?;
+                }
+
+                ("iceberg-delete", out)
+                    .into_pyobject(py)?
+                    .into_any()
+                    .unbind()
+            },
+
+            Some(DeletionFilesList::DeltaDeletionVector(vectors)) => {
+                let out = PyDict::new(py);
+
+                for (k, v) in vectors.iter() {
+                    let storage = match &v.storage {
+                        DeltaDeletionVectorStorage::Inline(encoded) => {
+                            ("inline", encoded.as_str()).into_bound_py_any(py)?
+                        },
+                        DeltaDeletionVectorStorage::File { path, offset } => {
+                            ("file", path.as_str(), *offset).into_bound_py_any(py)?
+                        },
+                    };
+
+                    out.set_item(*k, (storage, v.size_in_bytes, v.cardinality))?;
+                }
+
+                ("delta-deletion-vector", out)
+                    .into_pyobject(py)?
+                    .into_any()
+                    .unbind()
+            },
         })
     }
 
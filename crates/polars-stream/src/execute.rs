@@ -10,6 +10,7 @@ use slotmap::{SecondaryMap, SparseSecondaryMap};
 
 use crate::async_executor;
 use crate::graph::{Graph, GraphNode, GraphNodeKey, LogicalPipeKey, PortState};
+use crate::memory::{MemoryManager, get_memory_limit};
 use crate::pipe::PhysicalPipe;
 
 #[derive(Clone)]
@@ -19,6 +20,9 @@ pub struct StreamingExecutionState {
 
     // The ExecutionState passed to any non-streaming operations.
     pub in_memory_exec_state: ExecutionState,
+
+    // Tracks the memory buffered by the nodes against the query's budget.
+    pub memory: Arc<MemoryManager>,
 }
 
 /// Finds all runnable pipeline blockers in the graph, that is, nodes which:
@@ -267,6 +271,7 @@ pub fn execute_graph(
     let state = StreamingExecutionState {
         num_pipelines,
         in_memory_exec_state: ExecutionState::default(),
+        memory: MemoryManager::new(get_memory_limit()?),
     };
 
     // Ensure everything is properly connected.
@@ -9,6 +9,7 @@ pub use skeleton::{run_query, visualize_physical_plan};
 mod execute;
 pub(crate) mod expression;
 mod graph;
+mod memory;
 pub use skeleton::{QueryResult, StreamingQuery};
 mod morsel;
 mod nodes;
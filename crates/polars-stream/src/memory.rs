@@ -0,0 +1,160 @@
+//! Accounting of the memory buffered by the nodes of a streaming query against
+//! an optional budget for the query as a whole.
+//!
+//! Nodes which buffer morsels register a [`MemoryReservation`], which they grow
+//! and shrink as they buffer and release data. Once the total exceeds the
+//! budget sources hold back new morsels until their previous ones have been
+//! consumed, and spill-capable nodes move their state to disk. If the memory
+//! which can't be spilled exceeds the budget on its own, the query fails.
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use parking_lot::Mutex;
+use polars_error::{PolarsResult, polars_bail, polars_err};
+
+/// Returns the memory budget in bytes for a single streaming query, if any,
+/// as set by `POLARS_STREAMING_MEMORY_LIMIT`.
+pub fn get_memory_limit() -> PolarsResult<Option<usize>> {
+    let Ok(v) = std::env::var("POLARS_STREAMING_MEMORY_LIMIT") else {
+        return Ok(None);
+    };
+    let limit = v.trim().parse::<usize>().map_err(|_| {
+        polars_err!(
+            InvalidOperation: "invalid value for POLARS_STREAMING_MEMORY_LIMIT: '{v}', \
+            expected a number of bytes"
+        )
+    })?;
+    Ok(Some(limit))
+}
+
+struct Consumer {
+    name: &'static str,
+    spillable: bool,
+    used: AtomicUsize,
+}
+
+pub struct MemoryManager {
+    limit: Option<usize>,
+    used: AtomicUsize,
+    consumers: Mutex<Vec<Arc<Consumer>>>,
+}
+
+impl MemoryManager {
+    pub fn new(limit: Option<usize>) -> Arc<Self> {
+        Arc::new(Self {
+            limit,
+            used: AtomicUsize::new(0),
+            consumers: Mutex::default(),
+        })
+    }
+
+    /// Registers a new consumer of memory, named after the node it belongs
+    /// to. Spillable consumers must move their memory to disk once
+    /// [`MemoryReservation::should_spill`] returns true.
+    pub fn reserve(self: &Arc<Self>, name: &'static str, spillable: bool) -> MemoryReservation {
+        let consumer = Arc::new(Consumer {
+            name,
+            spillable,
+            used: AtomicUsize::new(0),
+        });
+        self.consumers.lock().push(consumer.clone());
+        MemoryReservation {
+            manager: self.clone(),
+            consumer,
+        }
+    }
+
+    pub fn used(&self) -> usize {
+        self.used.load(Ordering::Relaxed)
+    }
+
+    /// Whether the memory in use exceeds the budget.
+    pub fn is_over_budget(&self) -> bool {
+        self.limit.is_some_and(|limit| self.used() > limit)
+    }
+
+    /// Errors if the memory in use exceeds the budget, even if all spillable
+    /// consumers were to spill.
+    fn check_budget(&self) -> PolarsResult<()> {
+        let Some(limit) = self.limit else {
+            return Ok(());
+        };
+        let used = self.used();
+        if used <= limit {
+            return Ok(());
+        }
+
+        let mut consumers = self
+            .consumers
+            .lock()
+            .iter()
+            .map(|c| (c.name, c.spillable, c.used.load(Ordering::Relaxed)))
+            .filter(|(_, _, used)| *used > 0)
+            .collect::<Vec<_>>();
+        let spillable: usize = consumers.iter().filter(|c| c.1).map(|c| c.2).sum();
+        if used.saturating_sub(spillable) <= limit {
+            return Ok(());
+        }
+
+        consumers.sort_by_key(|c| std::cmp::Reverse(c.2));
+        let usage = consumers
+            .iter()
+            .map(|(name, _, used)| format!("{name}: {used}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        polars_bail!(
+            ComputeError:
+            "streaming query exceeds its memory budget of {limit} bytes, \
+            {used} bytes are buffered in memory ({usage})\n\n\
+            You may want to try:\n\
+            - raising POLARS_STREAMING_MEMORY_LIMIT\n\
+            - sinking the result to a file instead of collecting it"
+        )
+    }
+}
+
+/// The memory a single node accounts for, released when this is dropped.
+pub struct MemoryReservation {
+    manager: Arc<MemoryManager>,
+    consumer: Arc<Consumer>,
+}
+
+impl MemoryReservation {
+    /// Adds to the memory of this reservation, erroring if the query can no
+    /// longer stay within its budget.
+    pub fn grow(&self, bytes: usize) -> PolarsResult<()> {
+        self.consumer.used.fetch_add(bytes, Ordering::Relaxed);
+        self.manager.used.fetch_add(bytes, Ordering::Relaxed);
+        if self.consumer.spillable {
+            Ok(())
+        } else {
+            self.manager.check_budget()
+        }
+    }
+
+    pub fn shrink(&self, bytes: usize) {
+        let prev = self.consumer.used.fetch_sub(bytes, Ordering::Relaxed);
+        debug_assert!(prev >= bytes);
+        self.manager.used.fetch_sub(bytes, Ordering::Relaxed);
+    }
+
+    pub fn size(&self) -> usize {
+        self.consumer.used.load(Ordering::Relaxed)
+    }
+
+    /// Whether this reservation should be spilled to bring the query back
+    /// within its memory budget.
+    pub fn should_spill(&self) -> bool {
+        self.consumer.spillable && self.size() > 0 && self.manager.is_over_budget()
+    }
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        self.shrink(self.size());
+        self.manager
+            .consumers
+            .lock()
+            .retain(|c| !Arc::ptr_eq(c, &self.consumer));
+    }
+}
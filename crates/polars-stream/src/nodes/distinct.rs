@@ -0,0 +1,442 @@
+use std::sync::Arc;
+
+use polars_core::prelude::{IdxSize, PlRandomState, Schema, UniqueKeepStrategy};
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_expr::groups::Grouper;
+use polars_expr::hash_keys::HashKeys;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::compute_node_prelude::*;
+use super::in_memory_source::InMemorySourceNode;
+use crate::memory::{MemoryManager, MemoryReservation};
+
+/// The minimum number of rows we buffer before compacting the kept rows.
+const MIN_ROWS_BEFORE_COMPACT: usize = 1 << 16;
+
+/// Removes duplicate rows, only keeping the rows the keep strategy asks for.
+///
+/// For `first` (and `any` if order is maintained) the first occurrence of each
+/// key is sent as soon as we see it. For `last` and `none` we can only know
+/// which rows to keep once the input is done, so we keep (at most) one row per
+/// key around and send those at the end. Either way we only need memory in the
+/// order of the number of distinct keys, not the number of input rows.
+///
+/// If order is not maintained the input isn't processed in order, so `first`
+/// and `last` keep whichever row of a key arrives first or last. For `any` and
+/// `none` every pipeline then has its own grouper and these are merged at the
+/// end.
+pub struct DistinctNode {
+    state: DistinctState,
+    input_schema: Arc<Schema>,
+    key_names: Arc<[PlSmallStr]>,
+    keep: UniqueKeepStrategy,
+    maintain_order: bool,
+    random_state: PlRandomState,
+}
+
+enum DistinctState {
+    /// One set of buffers per pipeline if the node is parallel, otherwise one.
+    Running(Vec<DistinctBuffers>),
+    Source(InMemorySourceNode),
+    Done,
+}
+
+struct DistinctBuffers {
+    grouper: Box<dyn Grouper>,
+
+    // Row i of compacted is the last row of group i at the last compaction,
+    // rows received afterwards are in buffered. Only used for `last` and
+    // `none`.
+    compacted: DataFrame,
+    buffered: Vec<DataFrame>,
+    buffered_rows: usize,
+    // The estimated size of compacted and buffered, which is accounted against
+    // the query's memory budget.
+    buffered_bytes: usize,
+    reservation: Option<MemoryReservation>,
+
+    // For each group the index of its last row in compacted ++ buffered, its
+    // position in the input and its number of rows (saturating).
+    last_idx: Vec<IdxSize>,
+    last_pos: Vec<u64>,
+    count: Vec<u8>,
+    num_rows_seen: u64,
+}
+
+impl DistinctNode {
+    pub fn new(
+        input_schema: Arc<Schema>,
+        key_names: Arc<[PlSmallStr]>,
+        grouper: Box<dyn Grouper>,
+        keep: UniqueKeepStrategy,
+        maintain_order: bool,
+        random_state: PlRandomState,
+    ) -> Self {
+        Self {
+            state: DistinctState::Running(vec![DistinctBuffers::new(grouper, &input_schema)]),
+            input_schema,
+            key_names,
+            keep,
+            maintain_order,
+            random_state,
+        }
+    }
+
+    /// Whether we can send rows as they come in, or only once the input is done.
+    fn is_streaming(&self) -> bool {
+        match self.keep {
+            UniqueKeepStrategy::First => true,
+            UniqueKeepStrategy::Any => self.maintain_order,
+            UniqueKeepStrategy::Last | UniqueKeepStrategy::None => false,
+        }
+    }
+
+    /// Whether every pipeline can collect the rows to keep on its own.
+    fn is_parallel(&self) -> bool {
+        !self.maintain_order
+            && matches!(
+                self.keep,
+                UniqueKeepStrategy::Any | UniqueKeepStrategy::None
+            )
+    }
+}
+
+impl DistinctBuffers {
+    fn new(grouper: Box<dyn Grouper>, input_schema: &Schema) -> Self {
+        Self {
+            grouper,
+            compacted: DataFrame::empty_with_schema(input_schema),
+            buffered: Vec::new(),
+            buffered_rows: 0,
+            buffered_bytes: 0,
+            reservation: None,
+            last_idx: Vec::new(),
+            last_pos: Vec::new(),
+            count: Vec::new(),
+            num_rows_seen: 0,
+        }
+    }
+
+    /// Accounts the kept rows against the memory budget of the query. These
+    /// can't be spilled, if they don't fit the query fails.
+    fn track_memory(&mut self, memory: &Arc<MemoryManager>) {
+        if self.reservation.is_none() {
+            debug_assert!(self.buffered_bytes == 0);
+            self.reservation = Some(memory.reserve("distinct", false));
+        }
+    }
+
+    fn set_buffered_bytes(&mut self, bytes: usize) -> PolarsResult<()> {
+        if let Some(reservation) = &self.reservation {
+            if bytes < self.buffered_bytes {
+                reservation.shrink(self.buffered_bytes - bytes);
+            } else {
+                reservation.grow(bytes - self.buffered_bytes)?;
+            }
+        }
+        self.buffered_bytes = bytes;
+        Ok(())
+    }
+
+    /// Inserts the keys of df into the grouper, returning the group index of
+    /// each row.
+    fn insert_keys(
+        &mut self,
+        df: &DataFrame,
+        key_names: &[PlSmallStr],
+        random_state: PlRandomState,
+        group_idxs: &mut Vec<IdxSize>,
+    ) -> PolarsResult<()> {
+        let keys = df.select(key_names.iter().cloned())?;
+        let hash_keys = HashKeys::from_df(&keys, random_state, true, false);
+        let subset = (0..df.height() as IdxSize).collect::<Vec<_>>();
+        group_idxs.clear();
+        unsafe {
+            self.grouper
+                .insert_keys_subset(&hash_keys, &subset, Some(group_idxs));
+        }
+        Ok(())
+    }
+
+    /// Returns the rows of df which are the first occurrence of their key.
+    fn first_rows(
+        &mut self,
+        df: DataFrame,
+        key_names: &[PlSmallStr],
+        random_state: PlRandomState,
+        group_idxs: &mut Vec<IdxSize>,
+    ) -> PolarsResult<DataFrame> {
+        let num_groups_before = self.grouper.num_groups();
+        self.insert_keys(&df, key_names, random_state, group_idxs)?;
+
+        // New groups are numbered in order of their first occurrence.
+        let mut next_new_group = num_groups_before;
+        let mut first_idxs = Vec::new();
+        for (i, g) in group_idxs.iter().enumerate() {
+            if *g == next_new_group {
+                first_idxs.push(i as IdxSize);
+                next_new_group += 1;
+            }
+        }
+        if first_idxs.len() == df.height() {
+            return Ok(df);
+        }
+        Ok(unsafe { df.take_slice_unchecked(&first_idxs) })
+    }
+
+    /// Remembers the last row of each key in df. If given, row i of df stands
+    /// for counts[i] rows of its key.
+    fn push_last_rows(
+        &mut self,
+        df: DataFrame,
+        counts: Option<&[u8]>,
+        key_names: &[PlSmallStr],
+        random_state: PlRandomState,
+        group_idxs: &mut Vec<IdxSize>,
+    ) -> PolarsResult<()> {
+        if df.height() == 0 {
+            return Ok(());
+        }
+        self.insert_keys(&df, key_names, random_state, group_idxs)?;
+
+        let base = self.compacted.height() + self.buffered_rows;
+        for (i, g) in group_idxs.iter().enumerate() {
+            let g = *g as usize;
+            if g == self.last_idx.len() {
+                self.last_idx.push(0);
+                self.last_pos.push(0);
+                self.count.push(0);
+            }
+            self.last_idx[g] = (base + i) as IdxSize;
+            self.last_pos[g] = self.num_rows_seen + i as u64;
+            self.count[g] = self.count[g].saturating_add(counts.map_or(1, |c| c[i]));
+        }
+        self.num_rows_seen += df.height() as u64;
+        self.buffered_rows += df.height();
+        let bytes = self.buffered_bytes + df.estimated_size();
+        self.buffered.push(df);
+        self.set_buffered_bytes(bytes)?;
+
+        if self.buffered_rows > self.compacted.height().max(MIN_ROWS_BEFORE_COMPACT) {
+            self.compact()?;
+        }
+        Ok(())
+    }
+
+    /// Drops all rows which are no longer the last row of their key, such that
+    /// row i of compacted is the last row of group i.
+    fn compact(&mut self) -> PolarsResult<()> {
+        if self.buffered.is_empty() {
+            return Ok(());
+        }
+        let mut dfs = Vec::with_capacity(self.buffered.len() + 1);
+        dfs.push(core::mem::take(&mut self.compacted));
+        dfs.append(&mut self.buffered);
+        let mut all = accumulate_dataframes_vertical_unchecked(dfs);
+        all.as_single_chunk_par();
+        self.compacted = unsafe { all.take_slice_unchecked(&self.last_idx) };
+        for (g, idx) in self.last_idx.iter_mut().enumerate() {
+            *idx = g as IdxSize;
+        }
+        self.buffered_rows = 0;
+        self.set_buffered_bytes(self.compacted.estimated_size())
+    }
+
+    /// Merges the buffers of all pipelines into one.
+    fn merge(
+        mut all: Vec<Self>,
+        key_names: &[PlSmallStr],
+        random_state: PlRandomState,
+    ) -> PolarsResult<Self> {
+        if all.len() == 1 {
+            return Ok(all.pop().unwrap());
+        }
+
+        let mut merged = Self::new(all[0].grouper.new_empty(), all[0].compacted.schema());
+        let mut group_idxs = Vec::new();
+        for mut buffers in all {
+            buffers.compact()?;
+            merged.push_last_rows(
+                buffers.compacted,
+                Some(&buffers.count),
+                key_names,
+                random_state,
+                &mut group_idxs,
+            )?;
+        }
+        Ok(merged)
+    }
+
+    /// Returns the rows to keep once all input is seen.
+    fn finish(mut self, keep: UniqueKeepStrategy, maintain_order: bool) -> PolarsResult<DataFrame> {
+        self.compact()?;
+        let mut groups: Vec<IdxSize> = if keep == UniqueKeepStrategy::None {
+            (0..self.count.len() as IdxSize)
+                .filter(|g| self.count[*g as usize] == 1)
+                .collect()
+        } else {
+            (0..self.count.len() as IdxSize).collect()
+        };
+        if maintain_order {
+            groups.sort_unstable_by_key(|g| self.last_pos[*g as usize]);
+        }
+        if groups.len() == self.compacted.height() && !maintain_order {
+            return Ok(core::mem::take(&mut self.compacted));
+        }
+        Ok(unsafe { self.compacted.take_slice_unchecked(&groups) })
+    }
+}
+
+impl ComputeNode for DistinctNode {
+    fn name(&self) -> &str {
+        "distinct"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        if self.is_streaming() {
+            if send[0] == PortState::Done {
+                self.state = DistinctState::Done;
+            }
+            recv.swap_with_slice(send);
+            return Ok(());
+        }
+
+        // If the output doesn't want any more data, transition to being done.
+        if send[0] == PortState::Done && !matches!(self.state, DistinctState::Done) {
+            self.state = DistinctState::Done;
+        }
+
+        // If the input is done, transition to being a source.
+        if recv[0] == PortState::Done && matches!(self.state, DistinctState::Running(_)) {
+            let DistinctState::Running(buffers) =
+                core::mem::replace(&mut self.state, DistinctState::Done)
+            else {
+                unreachable!()
+            };
+            let buffers = DistinctBuffers::merge(buffers, &self.key_names, self.random_state)?;
+            let df = buffers.finish(self.keep, self.maintain_order)?;
+            let source_node = InMemorySourceNode::new(Arc::new(df), MorselSeq::default());
+            self.state = DistinctState::Source(source_node);
+        }
+
+        match &mut self.state {
+            DistinctState::Running(_) => {
+                send[0] = PortState::Blocked;
+            },
+            DistinctState::Source(source_node) => {
+                recv[0] = PortState::Done;
+                source_node.update_state(&mut [], send, state)?;
+            },
+            DistinctState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        !self.is_streaming() && matches!(self.state, DistinctState::Running(_))
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        let is_streaming = self.is_streaming();
+        let is_parallel = self.is_parallel();
+        let maintain_order = self.maintain_order;
+
+        let all_buffers = match &mut self.state {
+            DistinctState::Running(buffers) => buffers,
+            DistinctState::Source(source_node) => {
+                assert!(recv_ports[0].is_none());
+                source_node.spawn(scope, &mut [], send_ports, state, join_handles);
+                return;
+            },
+            DistinctState::Done => unreachable!(),
+        };
+        let key_names = &self.key_names;
+        let random_state = self.random_state;
+
+        if is_parallel {
+            assert!(send_ports[0].is_none());
+            let receivers = recv_ports[0].take().unwrap().parallel();
+            let grouper = all_buffers[0].grouper.new_empty();
+            let input_schema = &self.input_schema;
+            all_buffers.resize_with(receivers.len().max(all_buffers.len()), || {
+                DistinctBuffers::new(grouper.new_empty(), input_schema)
+            });
+            for (mut recv, buffers) in receivers.into_iter().zip(all_buffers.iter_mut()) {
+                buffers.track_memory(&state.memory);
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    let mut group_idxs = Vec::new();
+                    while let Ok(morsel) = recv.recv().await {
+                        buffers.push_last_rows(
+                            morsel.into_df(),
+                            None,
+                            key_names,
+                            random_state,
+                            &mut group_idxs,
+                        )?;
+                    }
+                    Ok(())
+                }));
+            }
+            return;
+        }
+
+        let buffers = &mut all_buffers[0];
+        let mut recv = recv_ports[0]
+            .take()
+            .unwrap()
+            .serial_with_maintain_order(maintain_order);
+
+        if is_streaming {
+            let mut send = send_ports[0].take().unwrap().serial();
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                let mut group_idxs = Vec::new();
+                while let Ok(morsel) = recv.recv().await {
+                    let morsel = morsel.try_map(|df| {
+                        buffers.first_rows(df, key_names, random_state, &mut group_idxs)
+                    })?;
+                    if morsel.df().height() == 0 {
+                        continue;
+                    }
+                    if send.send(morsel).await.is_err() {
+                        break;
+                    }
+                }
+                Ok(())
+            }));
+        } else {
+            assert!(send_ports[0].is_none());
+            buffers.track_memory(&state.memory);
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                let mut group_idxs = Vec::new();
+                while let Ok(morsel) = recv.recv().await {
+                    buffers.push_last_rows(
+                        morsel.into_df(),
+                        None,
+                        key_names,
+                        random_state,
+                        &mut group_idxs,
+                    )?;
+                }
+                Ok(())
+            }));
+        }
+    }
+}
@@ -0,0 +1,365 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_expr::prelude::PhysicalExpr;
+use polars_expr::state::ExecutionState;
+use polars_time::prelude::*;
+
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::async_primitives::connector::Receiver;
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::nodes::compute_node_prelude::*;
+
+/// The windows to group a sorted input by.
+pub enum TemporalWindows {
+    Dynamic(DynamicGroupOptions),
+    Rolling(RollingGroupOptions),
+}
+
+/// Groups an input sorted by its index column into temporal windows, like
+/// `group_by_dynamic` and `rolling` do, without materializing the input.
+///
+/// Rows are buffered until the input has progressed beyond the end of their
+/// window, at which point we know the window is complete and send it off to
+/// be aggregated. Rows no later window can contain are then dropped.
+pub struct DynamicGroupByNode {
+    buffers: WindowBuffers,
+    aggs: Arc<[Arc<dyn PhysicalExpr>]>,
+    seq: MorselSeq,
+}
+
+impl DynamicGroupByNode {
+    pub fn new(
+        windows: TemporalWindows,
+        aggs: Vec<Arc<dyn PhysicalExpr>>,
+        input_schema: &Schema,
+    ) -> Self {
+        Self {
+            buffers: WindowBuffers {
+                windows,
+                buffer: DataFrame::empty_with_schema(input_schema),
+                rows_at_last_attempt: 0,
+                next_window_start: None,
+                last_sent_window_start: None,
+                num_sent_rows: 0,
+                input_done: false,
+                finished: false,
+            },
+            aggs: aggs.into(),
+            seq: MorselSeq::default(),
+        }
+    }
+}
+
+/// Complete windows ready to be aggregated.
+struct ClosedWindows {
+    df: DataFrame,
+    groups: GroupPositions,
+    // The bounds and/or time key columns of the windows.
+    keys: Vec<Column>,
+}
+
+struct WindowBuffers {
+    windows: TemporalWindows,
+
+    // The rows which can still be part of windows we haven't sent.
+    buffer: DataFrame,
+    rows_at_last_attempt: usize,
+
+    // For group_by_dynamic, the start of the first window in the buffer and
+    // the start of the last window we sent, if it is still in the buffer.
+    next_window_start: Option<i64>,
+    last_sent_window_start: Option<i64>,
+
+    // For rolling, the number of leading rows in the buffer whose window we
+    // already sent.
+    num_sent_rows: usize,
+
+    input_done: bool,
+    finished: bool,
+}
+
+/// Shifts the given groups such that they index into the rows spanned by
+/// them, returning the offset and length of those rows.
+fn rebase_groups(groups: &[[IdxSize; 2]], rolling: bool) -> (usize, usize, GroupPositions) {
+    let offset = groups.iter().map(|[s, _]| *s).min().unwrap();
+    let end = groups.iter().map(|[s, l]| s + l).max().unwrap();
+    let groups = groups.iter().map(|[s, l]| [s - offset, *l]).collect();
+    let groups = GroupsType::Slice { groups, rolling }.into_sliceable();
+    (offset as usize, (end - offset) as usize, groups)
+}
+
+impl WindowBuffers {
+    fn push(&mut self, df: DataFrame) -> PolarsResult<()> {
+        if df.height() > 0 {
+            self.buffer.vstack_mut_owned(df)?;
+        }
+        Ok(())
+    }
+
+    /// Whether enough rows came in since we last looked for complete windows
+    /// to look again. Doubling keeps the total work linear if windows are
+    /// large compared to the morsels.
+    fn should_attempt(&self) -> bool {
+        self.buffer.height() >= (2 * self.rows_at_last_attempt).max(get_ideal_morsel_size())
+    }
+
+    /// Returns the windows which can no longer change, dropping the rows no
+    /// later window can contain.
+    fn close_windows(&mut self) -> PolarsResult<Option<ClosedWindows>> {
+        if self.buffer.height() == 0 {
+            return Ok(None);
+        }
+        self.buffer.as_single_chunk_par();
+        let out = match &self.windows {
+            TemporalWindows::Dynamic(options) => {
+                let options = options.clone();
+                self.close_dynamic_windows(&options)?
+            },
+            TemporalWindows::Rolling(options) => {
+                let options = options.clone();
+                self.close_rolling_windows(&options)?
+            },
+        };
+        self.rows_at_last_attempt = self.buffer.height();
+        Ok(out)
+    }
+
+    fn close_dynamic_windows(
+        &mut self,
+        options: &DynamicGroupOptions,
+    ) -> PolarsResult<Option<ClosedWindows>> {
+        let height = self.buffer.height();
+        let (time_key, bounds, groups, window_starts) = self
+            .buffer
+            .group_by_dynamic_from(options, self.next_window_start)?;
+        let GroupsType::Slice { groups, rolling } = &*groups else {
+            unreachable!()
+        };
+
+        // A window is complete once a row beyond its end came in. As windows
+        // are computed from the start of the last sent window, that one might
+        // be here again.
+        let num_sent = window_starts
+            .iter()
+            .take_while(|s| self.last_sent_window_start.is_some_and(|l| **s <= l))
+            .count();
+        let num_closed = if self.input_done {
+            groups.len()
+        } else {
+            groups
+                .iter()
+                .take_while(|[s, l]| ((s + l) as usize) < height)
+                .count()
+        };
+        if num_closed <= num_sent {
+            return Ok(None);
+        }
+
+        // Continue at the first open window, or otherwise the last window we
+        // sent now, a later window can't contain earlier rows.
+        let (resume, last_sent) = if num_closed < groups.len() {
+            (num_closed, None)
+        } else {
+            (num_closed - 1, Some(window_starts[num_closed - 1]))
+        };
+        self.next_window_start = Some(window_starts[resume]);
+        self.last_sent_window_start = last_sent;
+
+        let (offset, len, closed_groups) = rebase_groups(&groups[num_sent..num_closed], *rolling);
+        let df = self.buffer.slice(offset as i64, len);
+        let num_windows = num_closed - num_sent;
+        let mut keys = bounds
+            .iter()
+            .map(|c| c.slice(num_sent as i64, num_windows))
+            .collect::<Vec<_>>();
+        keys.push(time_key.slice(num_sent as i64, num_windows));
+
+        let prune = groups[resume][0] as usize;
+        self.buffer = self.buffer.slice(prune as i64, usize::MAX);
+        Ok(Some(ClosedWindows {
+            df,
+            groups: closed_groups,
+            keys,
+        }))
+    }
+
+    fn close_rolling_windows(
+        &mut self,
+        options: &RollingGroupOptions,
+    ) -> PolarsResult<Option<ClosedWindows>> {
+        let height = self.buffer.height();
+        let (time_key, groups) = self.buffer.rolling(None, options)?;
+        let GroupsType::Slice { groups, rolling } = &*groups else {
+            unreachable!()
+        };
+
+        // Every row has its own window, which is complete once a row beyond
+        // its end came in.
+        let num_sent = self.num_sent_rows;
+        let num_closed = if self.input_done {
+            height
+        } else {
+            num_sent
+                + groups[num_sent..]
+                    .iter()
+                    .take_while(|[s, l]| ((s + l) as usize) < height)
+                    .count()
+        };
+        if num_closed == num_sent {
+            return Ok(None);
+        }
+
+        let (offset, len, closed_groups) = rebase_groups(&groups[num_sent..num_closed], *rolling);
+        let df = self.buffer.slice(offset as i64, len);
+        let keys = vec![time_key.slice(num_sent as i64, num_closed - num_sent)];
+
+        // The windows of later rows can't start before that of the first
+        // open row (or the last row), but we do need the open rows themselves.
+        let next = num_closed.min(height - 1);
+        let prune = (groups[next][0] as usize).min(next);
+        self.buffer = self.buffer.slice(prune as i64, usize::MAX);
+        self.num_sent_rows = num_closed - prune;
+        Ok(Some(ClosedWindows {
+            df,
+            groups: closed_groups,
+            keys,
+        }))
+    }
+}
+
+impl ComputeNode for DynamicGroupByNode {
+    fn name(&self) -> &str {
+        match self.buffers.windows {
+            TemporalWindows::Dynamic(_) => "group-by-dynamic",
+            TemporalWindows::Rolling(_) => "rolling",
+        }
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        if send[0] == PortState::Done || self.buffers.finished {
+            recv[0] = PortState::Done;
+            send[0] = PortState::Done;
+        } else if recv[0] == PortState::Done {
+            // We still have to send the windows left in the buffer.
+            self.buffers.input_done = true;
+        } else {
+            recv.swap_with_slice(send);
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+
+        let senders = send_ports[0].take().unwrap().parallel();
+        let mut recv = recv_ports[0].take().map(|p| p.serial());
+
+        let (mut distributor, dist_recv) =
+            distributor_channel(senders.len(), *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+
+        let buffers = &mut self.buffers;
+        let seq = &mut self.seq;
+        join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+            // If a stop was requested, we need to buffer the remaining morsels
+            // and trigger a phase transition.
+            async fn buffer_input(
+                recv: &mut Receiver<Morsel>,
+                buffers: &mut WindowBuffers,
+            ) -> PolarsResult<()> {
+                if let Ok(morsel) = recv.recv().await {
+                    morsel.source_token().stop();
+                    buffers.push(morsel.into_df())?;
+                    while let Ok(morsel) = recv.recv().await {
+                        buffers.push(morsel.into_df())?;
+                    }
+                }
+                Ok(())
+            }
+
+            let source_token = SourceToken::new();
+            let Some(recv) = &mut recv else {
+                // The input is done, send all remaining windows.
+                if let Some(closed) = buffers.close_windows()? {
+                    let morsel = Morsel::new(DataFrame::empty(), *seq, source_token.clone());
+                    *seq = seq.successor();
+                    let _ = distributor.send((morsel, closed)).await;
+                }
+                buffers.buffer = buffers.buffer.clear();
+                buffers.finished = true;
+                return Ok(());
+            };
+
+            loop {
+                if source_token.stop_requested() {
+                    return buffer_input(recv, buffers).await;
+                }
+                let Ok(morsel) = recv.recv().await else {
+                    return Ok(());
+                };
+                buffers.push(morsel.into_df())?;
+                if !buffers.should_attempt() {
+                    continue;
+                }
+                if let Some(closed) = buffers.close_windows()? {
+                    let morsel = Morsel::new(DataFrame::empty(), *seq, source_token.clone());
+                    *seq = seq.successor();
+                    if distributor.send((morsel, closed)).await.is_err() {
+                        return Ok(());
+                    }
+                }
+            }
+        }));
+
+        join_handles.extend(
+            dist_recv
+                .into_iter()
+                .zip(senders)
+                .map(|(mut recv, mut send)| {
+                    let aggs = self.aggs.clone();
+                    scope.spawn_task(TaskPriority::High, async move {
+                        let state = ExecutionState::new();
+                        while let Ok((morsel, closed)) = recv.recv().await {
+                            let (_, seq, source_token, wg) = morsel.into_inner();
+                            assert!(wg.is_none());
+                            let ClosedWindows {
+                                df,
+                                groups,
+                                mut keys,
+                            } = closed;
+                            for agg in aggs.iter() {
+                                let c = agg.evaluate_on_groups(&df, &groups, &state)?.finalize();
+                                polars_ensure!(
+                                    c.len() == groups.len(),
+                                    agg_len = c.len(),
+                                    groups.len()
+                                );
+                                keys.push(c);
+                            }
+                            let out = DataFrame::new(keys)?;
+                            let morsel = Morsel::new(out, seq, source_token);
+                            if send.send(morsel).await.is_err() {
+                                break;
+                            }
+                        }
+                        Ok(())
+                    })
+                }),
+        );
+    }
+}
@@ -1,27 +1,30 @@
+use std::ops::Range;
 use std::sync::Arc;
 
-use polars_core::POOL;
 use polars_core::prelude::{IntoColumn, PlHashSet, PlRandomState};
 use polars_core::schema::Schema;
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::{POOL, config};
 use polars_expr::groups::Grouper;
 use polars_expr::hash_keys::HashKeys;
 use polars_expr::hot_groups::{HotGrouper, new_hash_hot_grouper};
 use polars_expr::reduce::GroupedReduction;
-use polars_utils::IdxSize;
 use polars_utils::cardinality_sketch::CardinalitySketch;
 use polars_utils::hashing::HashPartitioner;
 use polars_utils::itertools::Itertools;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::sparse_init_vec::SparseInitVec;
+use polars_utils::{IdxSize, format_pl_smallstr};
 use rayon::prelude::*;
 
 use super::compute_node_prelude::*;
 use crate::async_executor;
 use crate::async_primitives::connector::Receiver;
 use crate::expression::StreamExpr;
+use crate::memory::MemoryReservation;
 use crate::morsel::get_ideal_morsel_size;
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::spill::{SpillDir, SpillFile, get_spill_memory_budget};
 
 #[cfg(debug_assertions)]
 const DEFAULT_HOT_TABLE_SIZE: usize = 4;
@@ -47,6 +50,28 @@ struct LocalGroupBySinkState {
     pre_aggs: Vec<(HashKeys, Vec<Box<dyn GroupedReduction>>)>,
     pre_agg_idxs_values_per_p: Vec<Vec<IdxSize>>,
     pre_agg_idxs_offsets_per_p: Vec<usize>,
+
+    // Only used if spilling is enabled. The key columns of cold_morsels[i] are
+    // stored in cold_key_dfs[i] so they can be written to disk, cold_bytes is
+    // the estimated size of the cold morsels and keys.
+    cold_key_dfs: Vec<DataFrame>,
+    cold_bytes: usize,
+    // Accounts the cold bytes against the query's memory budget.
+    reservation: Option<MemoryReservation>,
+
+    // Once we have spilled we no longer pre-aggregate in the hot table, as
+    // those pre-aggregates are kept in memory. All rows then go to disk.
+    spilling: bool,
+    spilled_per_p: Vec<Vec<SpilledMorsels>>,
+}
+
+/// A set of cold morsels for a single partition written to disk, one block
+/// per morsel. Each block contains the key columns followed by the reduction
+/// input columns.
+struct SpilledMorsels {
+    file: Arc<SpillFile>,
+    blocks: Range<usize>,
+    seqs: Vec<u64>,
 }
 
 impl LocalGroupBySinkState {
@@ -70,6 +95,13 @@ impl LocalGroupBySinkState {
             pre_aggs: Vec::new(),
             pre_agg_idxs_values_per_p: vec![Vec::new(); num_partitions],
             pre_agg_idxs_offsets_per_p: vec![0; num_partitions],
+
+            cold_key_dfs: Vec::new(),
+            cold_bytes: 0,
+            reservation: None,
+
+            spilling: false,
+            spilled_per_p: (0..num_partitions).map(|_| Vec::new()).collect(),
         }
     }
 
@@ -99,6 +131,95 @@ impl LocalGroupBySinkState {
             .extend(self.pre_agg_idxs_values_per_p.iter().map(|vp| vp.len()));
         self.pre_aggs.push((hash_keys, reductions));
     }
+
+    fn finalize_pre_aggs(&mut self, partitioner: &HashPartitioner) {
+        if self.hot_grouper.num_evictions() > 0 {
+            self.flush_evictions(partitioner);
+        }
+        let hot_keys = self.hot_grouper.keys();
+        let hot_reductions = core::mem::take(&mut self.hot_grouped_reductions);
+        self.add_pre_agg(hot_keys, hot_reductions, partitioner);
+    }
+
+    /// Writes all cold morsels to a single spill file, partition by partition.
+    fn spill_cold_morsels(&mut self, spill_dir: &Arc<SpillDir>) -> PolarsResult<()> {
+        self.spilling = true;
+        if self.cold_morsels.is_empty() {
+            return Ok(());
+        }
+
+        let num_partitions = self.spilled_per_p.len();
+        let morsel_idxs = |i: usize, p: usize| {
+            let start = self.morsel_idxs_offsets_per_p[i * num_partitions + p];
+            let stop = self.morsel_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+            &self.morsel_idxs_values_per_p[p][start..stop]
+        };
+
+        let mut seqs_per_p = Vec::with_capacity(num_partitions);
+        for p in 0..num_partitions {
+            let seqs = (0..self.cold_morsels.len())
+                .filter(|i| !morsel_idxs(*i, p).is_empty())
+                .map(|i| self.cold_morsels[i].0)
+                .collect_vec();
+            seqs_per_p.push(seqs);
+        }
+
+        let gather_block = |i: usize, p: usize| {
+            let idxs = morsel_idxs(i, p);
+            let (_seq, _keys, values) = &self.cold_morsels[i];
+            unsafe {
+                let mut block = self.cold_key_dfs[i].take_slice_unchecked_impl(idxs, false);
+                let values = values.take_slice_unchecked_impl(idxs, false);
+                block.hstack_mut_unchecked(values.get_columns());
+                block
+            }
+        };
+        let gather_block = &gather_block;
+        let morsel_idxs = &morsel_idxs;
+        let schema = gather_block(0, 0).schema().clone();
+        let blocks = (0..num_partitions).flat_map(|p| {
+            (0..self.cold_morsels.len())
+                .filter(move |i| !morsel_idxs(*i, p).is_empty())
+                .map(move |i| gather_block(i, p))
+        });
+        let file = Arc::new(SpillFile::write(spill_dir, schema, blocks)?);
+
+        let mut block_offset = 0;
+        for (p, seqs) in seqs_per_p.into_iter().enumerate() {
+            let blocks = block_offset..block_offset + seqs.len();
+            block_offset = blocks.end;
+            if !seqs.is_empty() {
+                self.spilled_per_p[p].push(SpilledMorsels {
+                    file: file.clone(),
+                    blocks,
+                    seqs,
+                });
+            }
+        }
+        debug_assert_eq!(block_offset, file.num_blocks());
+
+        self.cold_morsels.clear();
+        self.cold_key_dfs.clear();
+        for idxs in &mut self.morsel_idxs_values_per_p {
+            idxs.clear();
+        }
+        self.morsel_idxs_offsets_per_p.clear();
+        self.morsel_idxs_offsets_per_p.resize(num_partitions, 0);
+        if let Some(reservation) = &self.reservation {
+            reservation.shrink(self.cold_bytes);
+        }
+        self.cold_bytes = 0;
+        Ok(())
+    }
+}
+
+struct SpillState {
+    // The memory budget for the cold morsels of a single local sink.
+    local_budget: usize,
+    dir: Arc<SpillDir>,
+    // The names under which the key columns are written to disk, these can't
+    // collide with the reduction input columns.
+    key_names: Vec<PlSmallStr>,
 }
 
 struct GroupBySinkState {
@@ -110,6 +231,7 @@ struct GroupBySinkState {
     locals: Vec<LocalGroupBySinkState>,
     random_state: PlRandomState,
     partitioner: HashPartitioner,
+    spill: Option<SpillState>,
 }
 
 impl GroupBySinkState {
@@ -126,6 +248,11 @@ impl GroupBySinkState {
             let grouped_reduction_cols = &self.grouped_reduction_cols;
             let random_state = &self.random_state;
             let partitioner = self.partitioner.clone();
+            let spill = self.spill.as_ref();
+            if spill.is_some() && local.reservation.is_none() {
+                debug_assert!(local.cold_bytes == 0);
+                local.reservation = Some(state.memory.reserve("group-by", true));
+            }
             join_handles.push(scope.spawn_task(TaskPriority::High, async move {
                 let mut hot_idxs = Vec::new();
                 let mut hot_group_idxs = Vec::new();
@@ -145,12 +272,16 @@ impl GroupBySinkState {
                     hot_idxs.clear();
                     hot_group_idxs.clear();
                     cold_idxs.clear();
-                    local.hot_grouper.insert_keys(
-                        &hash_keys,
-                        &mut hot_idxs,
-                        &mut hot_group_idxs,
-                        &mut cold_idxs,
-                    );
+                    if local.spilling {
+                        cold_idxs.extend(0..df.height() as IdxSize);
+                    } else {
+                        local.hot_grouper.insert_keys(
+                            &hash_keys,
+                            &mut hot_idxs,
+                            &mut hot_group_idxs,
+                            &mut cold_idxs,
+                        );
+                    }
 
                     // Drop columns not used for reductions (key-only columns).
                     if uniq_grouped_reduction_cols.len() < grouped_reduction_cols.len() {
@@ -191,10 +322,34 @@ impl GroupBySinkState {
                             local
                                 .morsel_idxs_offsets_per_p
                                 .extend(local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()));
+
+                            if let Some(spill) = spill {
+                                let mut cold_key_df =
+                                    keys.take_slice_unchecked_impl(&cold_idxs, false);
+                                cold_key_df.set_column_names(spill.key_names.iter().cloned())?;
+                                let bytes = cold_key_df.estimated_size() + cold_df.estimated_size();
+                                local.cold_bytes += bytes;
+                                if let Some(reservation) = &local.reservation {
+                                    reservation.grow(bytes)?;
+                                }
+                                local.cold_key_dfs.push(cold_key_df);
+                            }
                             local.cold_morsels.push((seq, cold_keys, cold_df));
                         }
                     }
 
+                    // If we're over our memory budget, move the cold morsels to disk.
+                    if let Some(spill) = spill {
+                        let should_spill =
+                            local.reservation.as_ref().is_some_and(|r| r.should_spill());
+                        if local.cold_bytes > spill.local_budget || should_spill {
+                            if !local.spilling && config::verbose() {
+                                eprintln!("[group-by]: memory budget exceeded, spilling to disk");
+                            }
+                            local.spill_cold_morsels(&spill.dir)?;
+                        }
+                    }
+
                     // If we have too many evicted rows, flush them.
                     if local.hot_grouper.num_evictions() >= get_ideal_morsel_size() {
                         local.flush_evictions(&partitioner);
@@ -212,14 +367,7 @@ impl GroupBySinkState {
                 .as_mut_slice()
                 .into_par_iter()
                 .with_max_len(1)
-                .for_each(|l| {
-                    if l.hot_grouper.num_evictions() > 0 {
-                        l.flush_evictions(&self.partitioner);
-                    }
-                    let hot_keys = l.hot_grouper.keys();
-                    let hot_reductions = core::mem::take(&mut l.hot_grouped_reductions);
-                    l.add_pre_agg(hot_keys, hot_reductions, &self.partitioner);
-                });
+                .for_each(|l| l.finalize_pre_aggs(&self.partitioner));
         });
 
         // To reduce maximum memory usage we want to drop the morsels
@@ -423,6 +571,129 @@ impl GroupBySinkState {
 
         Ok(output_per_partition.try_assume_init().ok().unwrap())
     }
+
+    fn has_spilled(&self) -> bool {
+        self.locals.iter().any(|l| l.spilling)
+    }
+
+    /// Moves all remaining cold morsels to disk, after which the partitions
+    /// can be combined one at a time.
+    fn into_spilled_partitions(mut self) -> PolarsResult<SpilledGroupByPartitions> {
+        let spill = self.spill.take().unwrap();
+        POOL.install(|| {
+            self.locals
+                .as_mut_slice()
+                .into_par_iter()
+                .with_max_len(1)
+                .try_for_each(|l| {
+                    l.finalize_pre_aggs(&self.partitioner);
+                    l.spill_cold_morsels(&spill.dir)
+                })
+        })?;
+
+        Ok(SpilledGroupByPartitions {
+            num_keys: spill.key_names.len(),
+            grouper: self.grouper,
+            grouped_reduction_cols: self.grouped_reduction_cols,
+            grouped_reductions: self.grouped_reductions,
+            locals: self.locals,
+            random_state: self.random_state,
+            next_partition: 0,
+            _spill_dir: spill.dir,
+        })
+    }
+}
+
+/// The state of a group-by that spilled to disk. Each partition is combined
+/// from its spilled morsels and the in-memory pre-aggregates only when it is
+/// needed for output, so that only a single partition is resident at a time.
+struct SpilledGroupByPartitions {
+    num_keys: usize,
+    grouper: Box<dyn Grouper>,
+    grouped_reduction_cols: Vec<PlSmallStr>,
+    grouped_reductions: Vec<Box<dyn GroupedReduction>>,
+    locals: Vec<LocalGroupBySinkState>,
+    random_state: PlRandomState,
+    next_partition: usize,
+    _spill_dir: Arc<SpillDir>,
+}
+
+impl SpilledGroupByPartitions {
+    fn next_partition(&mut self) -> PolarsResult<Option<GroupByPartition>> {
+        let p = self.next_partition;
+        let num_partitions = self.locals[0].sketch_per_p.len();
+        if p >= num_partitions {
+            return Ok(None);
+        }
+        self.next_partition += 1;
+
+        let mut sketch = CardinalitySketch::new();
+        for l in &self.locals {
+            sketch.combine(&l.sketch_per_p[p]);
+        }
+
+        // Allocate grouper and reductions.
+        let est_num_groups = sketch.estimate() * 5 / 4;
+        let mut p_grouper = self.grouper.new_empty();
+        let mut p_reductions = self
+            .grouped_reductions
+            .iter()
+            .map(|gr| gr.new_empty())
+            .collect_vec();
+        p_grouper.reserve(est_num_groups);
+        for r in &mut p_reductions {
+            r.reserve(est_num_groups);
+        }
+
+        // Insert spilled morsels.
+        let mut subset = Vec::new();
+        let mut group_idxs = Vec::new();
+        for l in &mut self.locals {
+            for spilled in core::mem::take(&mut l.spilled_per_p[p]) {
+                let blocks = spilled.file.read_blocks(spilled.blocks.clone())?;
+                for (block, seq_id) in blocks.into_iter().zip(spilled.seqs) {
+                    let keys = block.select_by_range(0..self.num_keys)?;
+                    let hash_keys = HashKeys::from_df(&keys, self.random_state, true, false);
+                    subset.clear();
+                    subset.extend(0..block.height() as IdxSize);
+                    group_idxs.clear();
+                    unsafe {
+                        p_grouper.insert_keys_subset(&hash_keys, &subset, Some(&mut group_idxs));
+                        for (c, r) in self.grouped_reduction_cols.iter().zip(&mut p_reductions) {
+                            let values = block.column(c.as_str()).unwrap();
+                            r.resize(p_grouper.num_groups());
+                            r.update_groups_subset(values, &subset, &group_idxs, seq_id)?;
+                        }
+                    }
+                }
+            }
+        }
+
+        // Insert pre-aggregates.
+        for l in &self.locals {
+            for (i, (keys, pre_aggs)) in l.pre_aggs.iter().enumerate() {
+                unsafe {
+                    let p_pre_agg_idxs_start = l.pre_agg_idxs_offsets_per_p[i * num_partitions + p];
+                    let p_pre_agg_idxs_stop =
+                        l.pre_agg_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                    let p_pre_agg_idxs =
+                        &l.pre_agg_idxs_values_per_p[p][p_pre_agg_idxs_start..p_pre_agg_idxs_stop];
+
+                    group_idxs.clear();
+                    p_grouper.insert_keys_subset(keys, p_pre_agg_idxs, Some(&mut group_idxs));
+                    for (pre_agg, r) in pre_aggs.iter().zip(&mut p_reductions) {
+                        r.resize(p_grouper.num_groups());
+                        r.combine_subset(&**pre_agg, p_pre_agg_idxs, &group_idxs)?;
+                    }
+                }
+            }
+        }
+
+        Ok(Some(GroupByPartition {
+            grouper: p_grouper,
+            grouped_reductions: p_reductions,
+        }))
+    }
 }
 
 struct GroupByPartition {
@@ -446,6 +717,11 @@ impl GroupByPartition {
 enum GroupByState {
     Sink(GroupBySinkState),
     Source(InMemorySourceNode),
+    SpilledSource {
+        partitions: SpilledGroupByPartitions,
+        source: InMemorySourceNode,
+        seq_offset: MorselSeq,
+    },
     Done,
 }
 
@@ -466,7 +742,7 @@ impl GroupByNode {
         output_schema: Arc<Schema>,
         random_state: PlRandomState,
         num_pipelines: usize,
-    ) -> Self {
+    ) -> PolarsResult<Self> {
         let hot_table_size = std::env::var("POLARS_HOT_TABLE_SIZE")
             .map(|sz| sz.parse::<usize>().unwrap())
             .unwrap_or(DEFAULT_HOT_TABLE_SIZE);
@@ -489,7 +765,14 @@ impl GroupByNode {
             })
             .collect();
         let partitioner = HashPartitioner::new(num_partitions, 0);
-        Self {
+        let spill = get_spill_memory_budget()?.map(|budget| SpillState {
+            local_budget: budget / num_pipelines,
+            dir: SpillDir::new("group-by"),
+            key_names: (0..key_schema.len())
+                .map(|i| format_pl_smallstr!("__POLARS_GB_SPILL_KEY_{i}"))
+                .collect(),
+        });
+        Ok(Self {
             state: GroupByState::Sink(GroupBySinkState {
                 key_selectors,
                 grouped_reductions,
@@ -499,10 +782,33 @@ impl GroupByNode {
                 grouped_reduction_cols,
                 locals,
                 partitioner,
+                spill,
             }),
             key_schema,
             output_schema,
-        }
+        })
+    }
+
+    fn load_spilled_partition(
+        partitions: &mut SpilledGroupByPartitions,
+        seq_offset: &mut MorselSeq,
+        key_schema: &Schema,
+        output_schema: &Schema,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<Option<InMemorySourceNode>> {
+        let Some(partition) = partitions.next_partition()? else {
+            return Ok(None);
+        };
+        let df = partition.into_df(key_schema, output_schema)?;
+
+        // The source sends at most one morsel per row plus one per pipeline,
+        // so the next partition must start after that.
+        let source_seq_offset = *seq_offset;
+        *seq_offset = seq_offset.offset_by_u64(df.height() as u64 + state.num_pipelines as u64 + 1);
+        Ok(Some(InMemorySourceNode::new(
+            Arc::new(df),
+            source_seq_offset,
+        )))
     }
 }
 
@@ -532,6 +838,25 @@ impl ComputeNode for GroupByNode {
                 else {
                     unreachable!()
                 };
+                if sink.has_spilled() {
+                    let mut partitions = sink.into_spilled_partitions()?;
+                    let mut seq_offset = MorselSeq::new(0);
+                    self.state = match Self::load_spilled_partition(
+                        &mut partitions,
+                        &mut seq_offset,
+                        &self.key_schema,
+                        &self.output_schema,
+                        state,
+                    )? {
+                        Some(source) => GroupByState::SpilledSource {
+                            partitions,
+                            source,
+                            seq_offset,
+                        },
+                        None => GroupByState::Done,
+                    };
+                    return self.update_state(recv, send, state);
+                }
                 let partitions = sink.combine_locals()?;
                 let dfs = POOL.install(|| {
                     partitions
@@ -551,6 +876,37 @@ impl ComputeNode for GroupByNode {
                     self.state = GroupByState::Done;
                 }
             },
+            // Defer to the source of the current partition, moving on to the
+            // next partition once it is exhausted.
+            GroupByState::SpilledSource {
+                partitions,
+                source,
+                seq_offset,
+            } => {
+                let downstream_state = send[0];
+                loop {
+                    source.update_state(&mut [], send, state)?;
+                    if send[0] != PortState::Done {
+                        break;
+                    }
+                    match Self::load_spilled_partition(
+                        partitions,
+                        seq_offset,
+                        &self.key_schema,
+                        &self.output_schema,
+                        state,
+                    )? {
+                        Some(next_source) => {
+                            *source = next_source;
+                            send[0] = downstream_state;
+                        },
+                        None => break,
+                    }
+                }
+                if send[0] == PortState::Done {
+                    self.state = GroupByState::Done;
+                }
+            },
             // Nothing to change.
             GroupByState::Done | GroupByState::Sink(_) => {},
         }
@@ -561,7 +917,7 @@ impl ComputeNode for GroupByNode {
                 send[0] = PortState::Blocked;
                 recv[0] = PortState::Ready;
             },
-            GroupByState::Source(..) => {
+            GroupByState::Source(..) | GroupByState::SpilledSource { .. } => {
                 recv[0] = PortState::Done;
                 send[0] = PortState::Ready;
             },
@@ -596,6 +952,10 @@ impl ComputeNode for GroupByNode {
                 assert!(recv_ports[0].is_none());
                 source.spawn(scope, &mut [], send_ports, state, join_handles);
             },
+            GroupByState::SpilledSource { source, .. } => {
+                assert!(recv_ports[0].is_none());
+                source.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
             GroupByState::Done => unreachable!(),
         }
     }
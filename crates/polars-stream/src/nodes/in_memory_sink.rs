@@ -5,11 +5,13 @@ use polars_core::schema::Schema;
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 
 use super::compute_node_prelude::*;
+use crate::memory::MemoryReservation;
 use crate::utils::in_memory_linearize::linearize;
 
 pub struct InMemorySinkNode {
     morsels_per_pipe: Mutex<Vec<Vec<(MorselSeq, DataFrame)>>>,
     schema: Arc<Schema>,
+    reservation: Option<MemoryReservation>,
 }
 
 impl InMemorySinkNode {
@@ -17,6 +19,7 @@ impl InMemorySinkNode {
         Self {
             morsels_per_pipe: Mutex::default(),
             schema,
+            reservation: None,
         }
     }
 }
@@ -52,18 +55,23 @@ impl ComputeNode for InMemorySinkNode {
         scope: &'s TaskScope<'s, 'env>,
         recv_ports: &mut [Option<RecvPort<'_>>],
         send_ports: &mut [Option<SendPort<'_>>],
-        _state: &'s StreamingExecutionState,
+        state: &'s StreamingExecutionState,
         join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
     ) {
         assert!(recv_ports.len() == 1 && send_ports.is_empty());
         let receivers = recv_ports[0].take().unwrap().parallel();
 
+        // Collected morsels can't be spilled, if they don't fit the query fails.
+        self.reservation
+            .get_or_insert_with(|| state.memory.reserve("in-memory-sink", false));
         for mut recv in receivers {
             let slf = &*self;
             join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                let reservation = slf.reservation.as_ref().unwrap();
                 let mut morsels = Vec::new();
                 while let Ok(mut morsel) = recv.recv().await {
                     morsel.take_consume_token();
+                    reservation.grow(morsel.df().estimated_size())?;
                     morsels.push((morsel.seq(), morsel.into_df()));
                 }
 
@@ -75,6 +83,8 @@ impl ComputeNode for InMemorySinkNode {
 
     fn get_output(&mut self) -> PolarsResult<Option<DataFrame>> {
         let morsels_per_pipe = core::mem::take(&mut *self.morsels_per_pipe.get_mut());
+        // The output is no longer ours to account for.
+        self.reservation = None;
         let dataframes = linearize(morsels_per_pipe);
         if dataframes.is_empty() {
             Ok(Some(DataFrame::empty_with_schema(&self.schema)))
@@ -6,6 +6,7 @@ use polars_error::PolarsResult;
 use polars_io::SerWriter;
 use polars_io::cloud::CloudOptions;
 use polars_io::prelude::{CsvWriter, CsvWriterOptions};
+use polars_io::utils::compression::StreamCompressor;
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
 
@@ -88,6 +89,7 @@ impl SinkNode for CsvSinkNode {
                         let mut writer = CsvWriter::new(&mut buffer)
                             .include_bom(false) // Handled once in the IO task.
                             .include_header(false) // Handled once in the IO task.
+                            .with_encoding(options.encoding)
                             .with_separator(options.serialize_options.separator)
                             .with_line_terminator(options.serialize_options.line_terminator.clone())
                             .with_quote_char(options.serialize_options.quote_char)
@@ -103,6 +105,7 @@ impl SinkNode for CsvSinkNode {
                             .batched(&schema)?;
 
                         writer.write_batch(&df)?;
+                        drop(writer);
 
                         allocation_size = allocation_size.max(buffer.len());
                         if lin_tx.insert(Priority(Reverse(seq), buffer)).await.is_err() {
@@ -132,11 +135,21 @@ impl SinkNode for CsvSinkNode {
                 .open_into_writeable_async(&sink_options, cloud_options.as_ref())
                 .await?;
 
+            // The whole file is compressed into a single stream, the encode tasks write their
+            // batches uncompressed.
+            let mut compressor = options
+                .compression
+                .is_compressed()
+                .then(|| StreamCompressor::try_new(options.compression))
+                .transpose()?;
+
             // Write the header
             if options.include_header || options.include_bom {
-                let mut writer = CsvWriter::new(&mut *file)
+                let mut header = Vec::new();
+                let mut writer = CsvWriter::new(&mut header)
                     .include_bom(options.include_bom)
                     .include_header(options.include_header)
+                    .with_encoding(options.encoding)
                     .with_separator(options.serialize_options.separator)
                     .with_line_terminator(options.serialize_options.line_terminator.clone())
                     .with_quote_char(options.serialize_options.quote_char)
@@ -151,16 +164,28 @@ impl SinkNode for CsvSinkNode {
                     .n_threads(1) // Disable rayon parallelism
                     .batched(&schema)?;
                 writer.write_batch(&DataFrame::empty_with_schema(&schema))?;
+                drop(writer);
+                match &mut compressor {
+                    Some(compressor) => file.write_all(&compressor.compress(&header)?)?,
+                    None => file.write_all(&header)?,
+                }
             }
 
             let mut file = file.try_into_async_writeable()?;
 
             while let Ok(mut lin_rx) = io_rx.recv().await {
-                while let Some(Priority(_, buffer)) = lin_rx.get().await {
+                while let Some(Priority(_, mut buffer)) = lin_rx.get().await {
+                    if let Some(compressor) = &mut compressor {
+                        buffer = compressor.compress(&buffer)?;
+                    }
                     file.write_all(&buffer).await?;
                 }
             }
 
+            if let Some(compressor) = compressor {
+                file.write_all(&compressor.finish()?).await?;
+            }
+
             file.sync_on_close(sink_options.sync_on_close).await?;
             file.close().await?;
 
@@ -2,7 +2,8 @@ use std::cmp::Reverse;
 
 use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
-use polars_io::json::BatchedWriter;
+use polars_io::json::{BatchedWriter, JsonWriterOptions};
+use polars_io::utils::compression::StreamCompressor;
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
 
@@ -17,17 +18,20 @@ use crate::nodes::{JoinHandle, TaskPriority};
 pub struct NDJsonSinkNode {
     target: SinkTarget,
     sink_options: SinkOptions,
+    write_options: JsonWriterOptions,
     cloud_options: Option<CloudOptions>,
 }
 impl NDJsonSinkNode {
     pub fn new(
         target: SinkTarget,
         sink_options: SinkOptions,
+        write_options: JsonWriterOptions,
         cloud_options: Option<CloudOptions>,
     ) -> Self {
         Self {
             target,
             sink_options,
+            write_options,
             cloud_options,
         }
     }
@@ -75,9 +79,7 @@ impl SinkNode for NDJsonSinkNode {
                         let (df, seq, _, consume_token) = morsel.into_inner();
 
                         let mut buffer = Vec::with_capacity(allocation_size);
-                        let mut writer = BatchedWriter::new(&mut buffer);
-
-                        writer.write_batch(&df)?;
+                        BatchedWriter::new(&mut buffer).write_batch(&df)?;
 
                         allocation_size = allocation_size.max(buffer.len());
                         if lin_tx.insert(Priority(Reverse(seq), buffer)).await.is_err() {
@@ -98,6 +100,7 @@ impl SinkNode for NDJsonSinkNode {
         // Task that will actually do write to the target file.
         let sink_options = self.sink_options.clone();
         let target = self.target.clone();
+        let compression = self.write_options.compression;
         let io_task = polars_io::pl_async::get_runtime().spawn(async move {
             use tokio::io::AsyncWriteExt;
 
@@ -106,12 +109,26 @@ impl SinkNode for NDJsonSinkNode {
                 .await?
                 .try_into_async_writeable()?;
 
+            // The whole file is compressed into a single stream, the encode tasks write their
+            // batches uncompressed.
+            let mut compressor = compression
+                .is_compressed()
+                .then(|| StreamCompressor::try_new(compression))
+                .transpose()?;
+
             while let Ok(mut lin_rx) = io_rx.recv().await {
-                while let Some(Priority(_, buffer)) = lin_rx.get().await {
+                while let Some(Priority(_, mut buffer)) = lin_rx.get().await {
+                    if let Some(compressor) = &mut compressor {
+                        buffer = compressor.compress(&buffer)?;
+                    }
                     file.write_all(&buffer).await?;
                 }
             }
 
+            if let Some(compressor) = compressor {
+                file.write_all(&compressor.finish()?).await?;
+            }
+
             file.sync_on_close(sink_options.sync_on_close).await?;
             file.close().await?;
 
@@ -7,13 +7,13 @@ use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::write::BatchedWriter;
-use polars_io::prelude::{ParquetWriteOptions, get_column_write_options};
+use polars_io::prelude::{ParquetWriteOptions, get_column_write_options, get_sorting_columns};
 use polars_io::schema_to_arrow_checked;
 use polars_parquet::parquet::error::ParquetResult;
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
     ColumnWriteOptions, CompressedPage, Compressor, FileWriter, SchemaDescriptor, Version,
-    WriteOptions, array_to_columns, to_parquet_schema,
+    WriteOptions, array_to_bloom_filters, array_to_columns, to_parquet_schema,
 };
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
@@ -59,7 +59,7 @@ impl ParquetSinkNode {
     ) -> PolarsResult<Self> {
         let schema = schema_to_arrow_checked(&input_schema, CompatLevel::newest(), "parquet")?;
         let column_options: Vec<ColumnWriteOptions> =
-            get_column_write_options(&schema, &write_options.field_overwrites);
+            get_column_write_options(&schema, &write_options.field_overwrites)?;
         let parquet_schema = to_parquet_schema(&schema, &column_options)?;
         let metrics =
             Arc::new(Mutex::new(collect_metrics.then(|| {
@@ -84,6 +84,9 @@ impl ParquetSinkNode {
     }
 }
 
+/// The bloom filter bitsets of the Parquet columns of a column or row group.
+type BloomFilters = Vec<Option<Vec<u8>>>;
+
 // 512 ^ 2
 const DEFAULT_ROW_GROUP_SIZE: usize = 1 << 18;
 
@@ -112,7 +115,7 @@ impl SinkNode for ParquetSinkNode {
         let (mut lin_rx, lin_txs) =
             Linearizer::new(state.num_pipelines, *DEFAULT_SINK_LINEARIZER_BUFFER_SIZE);
         // Collect task -> IO task
-        let (mut io_tx, mut io_rx) = connector::<Vec<Vec<CompressedPage>>>();
+        let (mut io_tx, mut io_rx) = connector::<(Vec<Vec<CompressedPage>>, BloomFilters)>();
 
         let write_options = &self.write_options;
 
@@ -159,14 +162,23 @@ impl SinkNode for ParquetSinkNode {
                             // @NOTE: Since one Polars column might contain multiple Parquet columns (when
                             // it has a struct datatype), we return a Vec<Vec<CompressedPage>>.
 
+                            let bloom_filters = array_to_bloom_filters(
+                                array.as_ref(),
+                                type_.clone(),
+                                column_options,
+                            );
+
                             // Array -> Parquet pages.
                             let encoded_columns =
                                 array_to_columns(array, type_.clone(), column_options, options)?;
 
                             // Compress the pages.
+                            let compressions =
+                                column_options.leaf_compressions(options.compression);
                             let compressed_pages = encoded_columns
                                 .into_iter()
-                                .map(|encoded_pages| {
+                                .zip(compressions)
+                                .map(|(encoded_pages, compression)| {
                                     Compressor::new_from_vec(
                                         encoded_pages.map(|result| {
                                             result.map_err(|e| {
@@ -175,7 +187,7 @@ impl SinkNode for ParquetSinkNode {
                                                 ))
                                             })
                                         }),
-                                        options.compression,
+                                        compression,
                                         vec![],
                                     )
                                     .collect::<ParquetResult<Vec<_>>>()
@@ -183,7 +195,10 @@ impl SinkNode for ParquetSinkNode {
                                 .collect::<ParquetResult<Vec<_>>>()?;
 
                             if lin_tx
-                                .insert(Priority(Reverse(rg_idx), (col_idx, compressed_pages)))
+                                .insert(Priority(
+                                    Reverse(rg_idx),
+                                    (col_idx, compressed_pages, bloom_filters),
+                                ))
                                 .await
                                 .is_err()
                             {
@@ -205,7 +220,7 @@ impl SinkNode for ParquetSinkNode {
             struct Current {
                 seq: usize,
                 num_columns_seen: usize,
-                columns: Vec<Option<Vec<Vec<CompressedPage>>>>,
+                columns: Vec<Option<(Vec<Vec<CompressedPage>>, BloomFilters)>>,
             }
 
             let mut current = Current {
@@ -215,14 +230,16 @@ impl SinkNode for ParquetSinkNode {
             };
 
             // Linearize from all the Encoder tasks.
-            while let Some(Priority(Reverse(seq), (i, compressed_pages))) = lin_rx.get().await {
+            while let Some(Priority(Reverse(seq), (i, compressed_pages, bloom_filters))) =
+                lin_rx.get().await
+            {
                 if current.num_columns_seen == 0 {
                     current.seq = seq;
                 }
 
                 debug_assert_eq!(current.seq, seq);
                 debug_assert!(current.columns[i].is_none());
-                current.columns[i] = Some(compressed_pages);
+                current.columns[i] = Some((compressed_pages, bloom_filters));
                 current.num_columns_seen += 1;
 
                 if current.num_columns_seen == input_schema.len() {
@@ -230,11 +247,18 @@ impl SinkNode for ParquetSinkNode {
                     // them.
                     let mut current_row_group: Vec<Vec<CompressedPage>> =
                         Vec::with_capacity(num_parquet_columns);
+                    let mut bloom_filters: BloomFilters = Vec::with_capacity(num_parquet_columns);
                     for column in current.columns.iter_mut() {
-                        current_row_group.extend(column.take().unwrap());
+                        let (compressed_pages, column_bloom_filters) = column.take().unwrap();
+                        current_row_group.extend(compressed_pages);
+                        bloom_filters.extend(column_bloom_filters);
                     }
 
-                    if io_tx.send(current_row_group).await.is_err() {
+                    if io_tx
+                        .send((current_row_group, bloom_filters))
+                        .await
+                        .is_err()
+                    {
                         return Ok(());
                     }
                     current.num_columns_seen = 0;
@@ -262,19 +286,34 @@ impl SinkNode for ParquetSinkNode {
                 .await?;
 
             let writer = BufWriter::new(&mut *file);
+            let sorting_columns = get_sorting_columns(
+                &arrow_schema,
+                &parquet_schema,
+                &write_options.sorting_columns,
+            );
             let key_value_metadata = write_options.key_value_metadata;
+            let encryption = write_options
+                .encryption
+                .as_ref()
+                .map(|e| e.to_properties(&arrow_schema))
+                .transpose()?;
             let write_options = WriteOptions {
                 statistics: write_options.statistics,
                 compression: write_options.compression.into(),
                 version: Version::V1,
                 data_page_size: write_options.data_page_size,
             };
-            let file_writer = Mutex::new(FileWriter::new_with_parquet_schema(
+            let mut file_writer = FileWriter::new_with_parquet_schema(
                 writer,
                 arrow_schema,
                 parquet_schema,
                 write_options,
-            ));
+            )
+            .with_sorting_columns(sorting_columns);
+            if let Some(encryption) = encryption {
+                file_writer = file_writer.with_encryption(encryption)?;
+            }
+            let file_writer = Mutex::new(file_writer);
             let mut writer = BatchedWriter::new(
                 file_writer,
                 column_options,
@@ -284,11 +323,11 @@ impl SinkNode for ParquetSinkNode {
             );
 
             let num_parquet_columns = writer.parquet_schema().leaves().len();
-            while let Ok(current_row_group) = io_rx.recv().await {
+            while let Ok((current_row_group, bloom_filters)) = io_rx.recv().await {
                 // @TODO: At the moment this is a sync write, this is not ideal because we can only
                 // have so many blocking threads in the tokio threadpool.
                 assert_eq!(current_row_group.len(), num_parquet_columns);
-                writer.write_row_group(&current_row_group)?;
+                writer.write_row_group(&current_row_group, bloom_filters)?;
             }
 
             let file_size = writer.finish()?;
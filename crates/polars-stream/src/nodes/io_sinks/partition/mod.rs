@@ -60,10 +60,11 @@ pub fn get_create_new_fn(
             Ok(sink)
         }) as _,
         #[cfg(feature = "json")]
-        FileType::Json(_ndjson_writer_options) => Arc::new(move |_input_schema, target| {
+        FileType::Json(ndjson_writer_options) => Arc::new(move |_input_schema, target| {
             let sink = Box::new(super::json::NDJsonSinkNode::new(
                 target,
                 sink_options.clone(),
+                ndjson_writer_options,
                 cloud_options.clone(),
             )) as Box<dyn SinkNode + Send + Sync>;
             Ok(sink)
@@ -93,11 +94,23 @@ pub fn get_create_new_fn(
             )) as Box<dyn SinkNode + Send + Sync>;
             Ok(sink)
         }) as _,
+        #[cfg(feature = "avro")]
+        FileType::Avro(avro_writer_options) => Arc::new(move |input_schema, target| {
+            let sink = Box::new(super::avro::AvroSinkNode::new(
+                target,
+                input_schema,
+                sink_options.clone(),
+                avro_writer_options.clone(),
+                cloud_options.clone(),
+            )) as Box<dyn SinkNode + Send + Sync>;
+            Ok(sink)
+        }) as _,
         #[cfg(not(any(
             feature = "csv",
             feature = "parquet",
             feature = "json",
-            feature = "ipc"
+            feature = "ipc",
+            feature = "avro"
         )))]
         _ => {
             panic!("activate source feature")
@@ -0,0 +1,436 @@
+use std::cmp::Reverse;
+use std::io::Cursor;
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::array::TryExtend;
+use arrow::io::avro::avro_schema::file::FileMetadata;
+use arrow::io::avro::avro_schema::read::block_iterator;
+use arrow::io::avro::avro_schema::read::fallible_streaming_iterator::FallibleStreamingIterator;
+use arrow::io::avro::read::{deserialize, infer_schema};
+use async_trait::async_trait;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::{ArrowSchema, DataType};
+use polars_core::schema::{Schema, SchemaExt};
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::avro::{AvroBlockInfo, read_file_index};
+use polars_io::cloud::CloudOptions;
+use polars_plan::dsl::{ScanSource, ScanSourceRef};
+use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
+use polars_utils::priority::Priority;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_file_reader::reader_interface::output::FileReaderOutputRecv;
+use super::multi_file_reader::reader_interface::{BeginReadArgs, calc_row_position_after_slice};
+use crate::async_executor::{AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::async_primitives::linearizer::Linearizer;
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::nodes::io_sources::multi_file_reader::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::io_sources::multi_file_reader::reader_interface::{
+    FileReader, FileReaderCallbacks, Projection,
+};
+use crate::{DEFAULT_DISTRIBUTOR_BUFFER_SIZE, DEFAULT_LINEARIZER_BUFFER_SIZE};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_plan::dsl::ScanSource;
+
+    use super::AvroFileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct AvroReaderBuilder;
+
+    impl FileReaderBuilder for AvroReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "avro"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            RC::ROW_INDEX | RC::PRE_SLICE | RC::NEGATIVE_PRE_SLICE
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            #[expect(unused)] scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let reader = AvroFileReader {
+                scan_source: source,
+                cloud_options,
+                verbose: config::verbose(),
+                init_data: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct AvroFileReader {
+    scan_source: ScanSource,
+    cloud_options: Option<Arc<CloudOptions>>,
+    verbose: bool,
+
+    init_data: Option<InitializedState>,
+}
+
+#[derive(Clone)]
+struct InitializedState {
+    memslice: MemSlice,
+    metadata: Arc<FileMetadata>,
+    file_schema: Arc<ArrowSchema>,
+    blocks: Arc<[AvroBlockInfo]>,
+    n_rows_in_file: IdxSize,
+}
+
+#[async_trait]
+impl FileReader for AvroFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        // TODO: Streaming reads
+        if let ScanSourceRef::Path(addr) = self.scan_source.as_scan_source_ref() {
+            polars_io::file_cache::init_entries_from_uri_list(
+                &[Arc::from(addr.to_str())],
+                self.cloud_options.as_deref(),
+            )?;
+        }
+
+        let memslice = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_check_latest(self.scan_source.run_async())?;
+
+        let (metadata, blocks) = read_file_index(memslice.as_ref())?;
+        let file_schema = Arc::new(infer_schema(&metadata.record)?);
+
+        let n_rows: usize = blocks.iter().map(|block| block.num_rows).sum();
+        let n_rows_in_file = IdxSize::try_from(n_rows)
+            .map_err(|_| polars_err!(bigidx, ctx = "avro file", size = n_rows))?;
+
+        self.init_data = Some(InitializedState {
+            memslice,
+            metadata: Arc::new(metadata),
+            file_schema,
+            blocks: blocks.into(),
+            n_rows_in_file,
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let InitializedState {
+            memslice,
+            metadata,
+            file_schema,
+            blocks,
+            n_rows_in_file,
+        } = self.init_data.clone().unwrap();
+
+        let BeginReadArgs {
+            projection: Projection::Plain(projected_schema),
+            row_index,
+            pre_slice: pre_slice_arg,
+            predicate: None,
+            cast_columns_policy: _,
+            num_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        let normalized_pre_slice = pre_slice_arg
+            .clone()
+            .map(|pre_slice| pre_slice.restrict_to_bounds(n_rows_in_file as usize));
+
+        if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+            _ = n_rows_in_file_tx.try_send(n_rows_in_file);
+        }
+
+        if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+            _ = row_position_on_end_tx.try_send(calc_row_position_after_slice(
+                n_rows_in_file,
+                normalized_pre_slice.clone(),
+            ));
+        }
+
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(Arc::new(Schema::from_arrow_schema(file_schema.as_ref())));
+        }
+
+        if normalized_pre_slice.as_ref().is_some_and(|x| x.len() == 0) {
+            let (_, rx) = FileReaderOutputSend::new_serial();
+
+            if verbose {
+                eprintln!(
+                    "[AvroFileReader]: early return: \
+                    n_rows_in_file: {n_rows_in_file}, \
+                    pre_slice: {pre_slice_arg:?}, \
+                    resolved_pre_slice: {normalized_pre_slice:?}"
+                )
+            }
+
+            return Ok((rx, spawn(TaskPriority::Low, std::future::ready(Ok(())))));
+        }
+
+        // Always create a slice. If no slice was given, just make the biggest slice possible.
+        let slice: Range<usize> = normalized_pre_slice
+            .clone()
+            .map_or(0..usize::MAX, Range::<usize>::from);
+
+        let projection: Arc<[bool]> = file_schema
+            .iter_names()
+            .map(|name| projected_schema.contains(name))
+            .collect();
+        let n_projected = projection.iter().filter(|x| **x).count();
+
+        if verbose {
+            eprintln!(
+                "[AvroFileReader]: \
+                project: {} / {}, \
+                pre_slice: {:?}, \
+                resolved_pre_slice: {:?}, \
+                n_blocks: {}",
+                n_projected,
+                file_schema.len(),
+                pre_slice_arg,
+                normalized_pre_slice,
+                blocks.len(),
+            )
+        }
+
+        let max_morsel_size = get_ideal_morsel_size();
+
+        /// Messages sent from Walker task to Decoder tasks.
+        struct BatchMessage {
+            row_idx_offset: IdxSize,
+            /// Rows to take from the decoded blocks.
+            slice: Range<usize>,
+            block_range: Range<usize>,
+            morsel_seq_base: u64,
+        }
+
+        let (mut morsel_sender, morsel_rx) = FileReaderOutputSend::new_serial();
+
+        // Walker task -> Decoder tasks.
+        let (mut batch_tx, batch_rxs) =
+            distributor_channel::<BatchMessage>(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+        // Decoder tasks -> Distributor task.
+        let (mut decoded_rx, decoded_tx) =
+            Linearizer::<Priority<Reverse<MorselSeq>, DataFrame>>::new(
+                num_pipelines,
+                *DEFAULT_LINEARIZER_BUFFER_SIZE,
+            );
+
+        let distributor_handle = AbortOnDropHandle::new(spawn(TaskPriority::High, async move {
+            // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+            let source_token = SourceToken::new();
+
+            while let Some(Priority(Reverse(seq), df)) = decoded_rx.get().await {
+                let morsel = Morsel::new(df, seq, source_token.clone());
+
+                if morsel_sender.send_morsel(morsel).await.is_err() {
+                    break;
+                }
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        // Decoder tasks.
+        //
+        // Decompresses and deserializes a range of blocks into a DataFrame, which is split into
+        // morsels.
+        let decoder_handles = decoded_tx
+            .into_iter()
+            .zip(batch_rxs)
+            .map(|(mut send, mut rx)| {
+                let memslice = memslice.clone();
+                let metadata = metadata.clone();
+                let file_schema = file_schema.clone();
+                let blocks = blocks.clone();
+                let projection = projection.clone();
+                let row_index = row_index.clone();
+
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    let pl_schema = file_schema
+                        .iter_values()
+                        .zip(projection.iter())
+                        .filter(|(_, is_projected)| **is_projected)
+                        .map(|(field, _)| (field.name.clone(), DataType::from_arrow_field(field)))
+                        .collect::<Schema>();
+
+                    while let Ok(m) = rx.recv().await {
+                        let BatchMessage {
+                            row_idx_offset,
+                            slice,
+                            block_range,
+                            morsel_seq_base,
+                        } = m;
+
+                        // Blocks without any projected columns do not have to be decoded.
+                        let mut df = if n_projected == 0 {
+                            DataFrame::empty_with_height(slice.len())
+                        } else {
+                            let offset = blocks[block_range.start].offset;
+                            let mut block_iter = block_iterator(
+                                Cursor::new(&memslice[offset..]),
+                                metadata.compression,
+                                metadata.marker,
+                            );
+
+                            let mut df = DataFrame::empty_with_schema(&pl_schema);
+                            for _ in block_range {
+                                let block = block_iter.next()?.ok_or_else(
+                                    || polars_err!(ComputeError: "unexpected end of avro file"),
+                                )?;
+                                let record_batch = deserialize(
+                                    block,
+                                    &file_schema,
+                                    &metadata.record.fields,
+                                    &projection,
+                                )?;
+                                // This will perform schema validation as well.
+                                df.try_extend([record_batch])?;
+                            }
+
+                            df.slice(slice.start as i64, slice.len())
+                        };
+
+                        if let Some(RowIndex { name, offset: _ }) = &row_index {
+                            let offset = row_idx_offset + slice.start as IdxSize;
+                            df = df.with_row_index(name.clone(), Some(offset))?;
+                        }
+
+                        for i in 0..df.height().div_ceil(max_morsel_size) {
+                            let morsel_df = df.slice((i * max_morsel_size) as i64, max_morsel_size);
+                            let seq = MorselSeq::new(morsel_seq_base + i as u64);
+                            if send
+                                .insert(Priority(Reverse(seq), morsel_df))
+                                .await
+                                .is_err()
+                            {
+                                break;
+                            }
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        // Walker task.
+        //
+        // Groups the blocks that overlap with the slice into batches for the decoder tasks.
+        let walker_handle = AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+            let batch_size_limit = get_ideal_morsel_size()
+                .min(slice.len().div_ceil(num_pipelines))
+                .max(1);
+            let base_row_idx_offset = row_index.as_ref().map_or(0, |ri| ri.offset);
+
+            let mut morsel_seq: u64 = 0;
+            // Row position of the start of the current block in the file.
+            let mut block_row_start = 0;
+            let mut batch_start: Option<(usize, usize)> = None;
+
+            for (block_idx, block) in blocks.iter().enumerate() {
+                let block_row_end = block_row_start + block.num_rows;
+
+                if block_row_end <= slice.start {
+                    block_row_start = block_row_end;
+                    continue;
+                }
+
+                let (batch_block_start, batch_row_start) =
+                    *batch_start.get_or_insert((block_idx, block_row_start));
+
+                let batch_rows = block_row_end - batch_row_start;
+                let is_last = block_row_end >= slice.end || block_idx + 1 == blocks.len();
+
+                if batch_rows >= batch_size_limit || is_last {
+                    let rows_start = slice.start.max(batch_row_start);
+                    let rows_end = slice.end.min(block_row_end);
+
+                    let message = BatchMessage {
+                        row_idx_offset: base_row_idx_offset + batch_row_start as IdxSize,
+                        slice: rows_start - batch_row_start..rows_end - batch_row_start,
+                        block_range: batch_block_start..block_idx + 1,
+                        morsel_seq_base: morsel_seq,
+                    };
+
+                    if batch_tx.send(message).await.is_err() {
+                        // This should only happen if the receiver of the decoder
+                        // has broken off, meaning no further input will be needed.
+                        break;
+                    }
+
+                    morsel_seq += (rows_end - rows_start).div_ceil(max_morsel_size) as u64;
+                    batch_start = None;
+                }
+
+                if block_row_end >= slice.end {
+                    break;
+                }
+
+                block_row_start = block_row_end;
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        Ok((
+            morsel_rx,
+            spawn(TaskPriority::Low, async move {
+                distributor_handle.await?;
+
+                for handle in decoder_handles {
+                    handle.await?;
+                }
+
+                walker_handle.await?;
+                Ok(())
+            }),
+        ))
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        Ok(self.init_data.as_ref().unwrap().n_rows_in_file)
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> PolarsResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self.init_data.as_ref().unwrap().n_rows_in_file,
+            pre_slice,
+        ))
+    }
+}
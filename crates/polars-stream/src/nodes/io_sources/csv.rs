@@ -4,7 +4,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use polars_core::prelude::{Column, Field};
 use polars_core::schema::{SchemaExt, SchemaRef};
-use polars_error::{PolarsResult, polars_bail, polars_err, polars_warn};
+use polars_error::{PolarsError, PolarsResult, polars_bail, polars_err, polars_warn};
 use polars_io::RowIndex;
 use polars_io::cloud::CloudOptions;
 use polars_io::prelude::_csv_read_internal::{
@@ -12,10 +12,12 @@ use polars_io::prelude::_csv_read_internal::{
     read_chunk,
 };
 use polars_io::prelude::buffer::validate_utf8;
+use polars_io::prelude::schema_inference::decompress_schema_inference_prefix;
 use polars_io::prelude::{
-    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, count_rows_from_slice,
+    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, CsvSourceReader,
+    count_rows_from_slice,
 };
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::CompressedReader;
 use polars_io::utils::slice::SplitSlicePosition;
 use polars_plan::dsl::ScanSource;
 use polars_utils::IdxSize;
@@ -91,8 +93,7 @@ const NO_SLICE: (usize, usize) = (0, usize::MAX);
 const SLICE_ENDED: (usize, usize) = (usize::MAX, 0);
 
 struct LineBatch {
-    // Safety: All receivers (LineBatchProcessors) hold a MemSlice ref to this.
-    bytes: &'static [u8],
+    mem_slice: MemSlice,
     n_lines: usize,
     slice: (usize, usize),
     /// Position of this chunk relative to the start of the file according to CountLines.
@@ -105,7 +106,8 @@ struct CsvFileReader {
     #[expect(unused)] // Will be used when implementing cloud streaming.
     cloud_options: Option<Arc<CloudOptions>>,
     options: Arc<CsvReadOptions>,
-    // Cached on first access - we may be called multiple times e.g. on negative slice.
+    // Cached on first access - we may be called multiple times e.g. on negative slice. This holds
+    // the (possibly compressed) bytes of the file.
     cached_bytes: Option<MemSlice>,
     verbose: bool,
 }
@@ -130,8 +132,6 @@ impl FileReader for CsvFileReader {
     ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
         let verbose = self.verbose;
 
-        let memslice = self.get_bytes_maybe_decompress()?;
-
         let BeginReadArgs {
             projection: Projection::Plain(projected_schema),
             // Because we currently only support PRE_SLICE we don't need to handle row index here.
@@ -175,24 +175,50 @@ impl FileReader for CsvFileReader {
             self.options.infer_schema_length
         };
 
+        // Compressed and non-UTF-8 files, and files with a multi-byte dialect, are decoded in
+        // slices while splitting lines, we only decode the prefix that is needed to infer the
+        // schema here.
+        let mut reader = CsvSourceReader::try_new(
+            self.cached_bytes.clone().unwrap(),
+            &self.options.parse_options,
+        )?;
+        // The decoded bytes are parsed in the normalized dialect.
+        let options = if self.options.parse_options.needs_dialect_normalization() {
+            Arc::new(
+                self.options
+                    .as_ref()
+                    .clone()
+                    .map_parse_options(CsvParseOptions::into_normalized),
+            )
+        } else {
+            self.options.clone()
+        };
+        let (memslice, read_size) = if reader.is_decoded() {
+            let prefix =
+                decompress_schema_inference_prefix(&mut reader, &options, infer_schema_length)?;
+            (prefix, CompressedReader::DEFAULT_READ_SIZE)
+        } else {
+            let (memslice, _) = reader.read_next_slice(&MemSlice::default(), usize::MAX)?;
+            (memslice, usize::MAX)
+        };
+
         let (mut inferred_schema, ..) = polars_io::csv::read::infer_file_schema(
             &polars_io::mmap::ReaderBytes::Owned(memslice.clone()),
-            &self.options.parse_options,
+            &options.parse_options,
             infer_schema_length,
-            self.options.has_header,
-            self.options.schema_overwrite.as_deref(),
-            self.options.skip_rows,
-            self.options.skip_lines,
-            self.options.skip_rows_after_header,
-            self.options.raise_if_empty,
+            options.has_header,
+            options.schema_overwrite.as_deref(),
+            options.skip_rows,
+            options.skip_lines,
+            options.skip_rows_after_header,
+            options.raise_if_empty,
         )?;
 
-        if let Some(schema) = &self.options.schema {
+        if let Some(schema) = &options.schema {
             // Note: User can provide schema with more columns, they will simply
             // be projected as NULL.
             // TODO: Should maybe expose a missing_columns parameter to the API for this.
-            if schema.len() < inferred_schema.len()
-                && !self.options.parse_options.truncate_ragged_lines
+            if schema.len() < inferred_schema.len() && !options.parse_options.truncate_ragged_lines
             {
                 polars_bail!(
                     SchemaMismatch:
@@ -202,7 +228,7 @@ impl FileReader for CsvFileReader {
                 );
             }
 
-            if self.options.parse_options.truncate_ragged_lines {
+            if options.parse_options.truncate_ragged_lines {
                 inferred_schema = Arc::unwrap_or_clone(schema.clone());
             } else {
                 inferred_schema = schema
@@ -213,7 +239,7 @@ impl FileReader for CsvFileReader {
             }
         }
 
-        if let Some(dtypes) = self.options.dtype_overwrite.as_deref() {
+        if let Some(dtypes) = options.dtype_overwrite.as_deref() {
             for (i, dtype) in dtypes.iter().enumerate() {
                 inferred_schema.set_dtype_at_index(i, dtype.clone());
             }
@@ -242,19 +268,22 @@ impl FileReader for CsvFileReader {
 
         if verbose {
             eprintln!(
-                "[CsvFileReader]: project: {} / {}, slice: {:?}, row_index: {:?}",
+                "[CsvFileReader]: project: {} / {}, slice: {:?}, row_index: {:?}, compressed: {}, \
+                encoding: {:?}",
                 projection.len(),
                 inferred_schema.len(),
                 &pre_slice,
                 row_index,
+                reader.is_compressed(),
+                options.parse_options.encoding,
             )
         }
 
         // Only used on empty projection, or if we need the exact row count.
         let alt_count_lines: Option<Arc<CountLinesWithComments>> =
-            CountLinesWithComments::opt_new(&self.options.parse_options).map(Arc::new);
+            CountLinesWithComments::opt_new(&options.parse_options).map(Arc::new);
         let chunk_reader = Arc::new(ChunkReader::try_new(
-            self.options.clone(),
+            options.clone(),
             inferred_schema.clone(),
             projection,
             row_index,
@@ -269,13 +298,15 @@ impl FileReader for CsvFileReader {
         let line_batch_source_handle = AbortOnDropHandle::new(spawn(
             TaskPriority::Low,
             LineBatchSource {
-                memslice: memslice.clone(),
+                memslice,
+                reader,
+                read_size,
                 line_counter: CountLines::new(
-                    self.options.parse_options.quote_char,
-                    self.options.parse_options.eol_char,
+                    options.parse_options.quote_char,
+                    options.parse_options.eol_char,
                 ),
                 line_batch_tx,
-                options: self.options.clone(),
+                options: options.clone(),
                 file_schema_len: inferred_schema.len(),
                 pre_slice,
                 needs_full_row_count,
@@ -294,8 +325,6 @@ impl FileReader for CsvFileReader {
             .zip(morsel_senders)
             .enumerate()
             .map(|(worker_idx, (mut line_batch_rx, mut morsel_tx))| {
-                // Hold a ref as we are receiving `&'static [u8]`s pointing to this.
-                let global_memslice = memslice.clone();
                 // Only verbose log from the last worker to avoid flooding output.
                 let verbose = verbose && worker_idx == n_workers - 1;
                 let mut n_rows_processed: usize = 0;
@@ -306,26 +335,24 @@ impl FileReader for CsvFileReader {
 
                 AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
                     while let Ok(LineBatch {
-                        bytes,
+                        mem_slice,
                         n_lines,
                         slice,
                         row_offset,
                         morsel_seq,
                     }) = line_batch_rx.recv().await
                     {
-                        debug_assert!(bytes.as_ptr() as usize >= global_memslice.as_ptr() as usize);
-                        debug_assert!(
-                            bytes.as_ptr() as usize + bytes.len()
-                                <= global_memslice.as_ptr() as usize + global_memslice.len()
-                        );
-
                         let (offset, len) = match slice {
                             SLICE_ENDED => (0, 1),
                             v => v,
                         };
 
-                        let (df, n_rows_in_chunk) =
-                            chunk_reader.read_chunk(bytes, n_lines, (offset, len), row_offset)?;
+                        let (df, n_rows_in_chunk) = chunk_reader.read_chunk(
+                            &mem_slice,
+                            n_lines,
+                            (offset, len),
+                            row_offset,
+                        )?;
 
                         n_rows_processed = n_rows_processed.saturating_add(n_rows_in_chunk);
 
@@ -350,7 +377,7 @@ impl FileReader for CsvFileReader {
                         }
 
                         while let Ok(LineBatch {
-                            bytes,
+                            mem_slice,
                             n_lines,
                             slice,
                             row_offset: _,
@@ -360,7 +387,7 @@ impl FileReader for CsvFileReader {
                             assert_eq!(slice, SLICE_ENDED);
 
                             let n_lines = if let Some(v) = alt_count_lines.as_deref() {
-                                v.count_lines(bytes)?
+                                v.count_lines(&mem_slice)?
                             } else {
                                 n_lines
                             };
@@ -407,23 +434,13 @@ impl FileReader for CsvFileReader {
     }
 }
 
-impl CsvFileReader {
-    /// # Panics
-    /// Panics if `self.cached_bytes` is None.
-    fn get_bytes_maybe_decompress(&mut self) -> PolarsResult<MemSlice> {
-        let mut out = vec![];
-        maybe_decompress_bytes(self.cached_bytes.as_deref().unwrap(), &mut out)?;
-
-        if !out.is_empty() {
-            self.cached_bytes = Some(MemSlice::from_vec(out));
-        }
-
-        Ok(self.cached_bytes.clone().unwrap())
-    }
-}
-
 struct LineBatchSource {
+    /// Bytes from the start of the file. For compressed or transcoded files this is only a prefix,
+    /// the rest is read from `reader`.
     memslice: MemSlice,
+    reader: CsvSourceReader,
+    /// Number of decoded bytes to read at a time.
+    read_size: usize,
     line_counter: CountLines,
     line_batch_tx: distributor_channel::Sender<LineBatch>,
     options: Arc<CsvReadOptions>,
@@ -438,7 +455,9 @@ impl LineBatchSource {
     /// Returns the number of rows skipped from the start of the file according to CountLines.
     async fn run(self) -> PolarsResult<usize> {
         let LineBatchSource {
-            memslice,
+            mut memslice,
+            mut reader,
+            read_size,
             line_counter,
             mut line_batch_tx,
             options,
@@ -469,10 +488,18 @@ impl LineBatchSource {
             eprintln!("[CsvSource]: Start line splitting",);
         }
 
-        let global_bytes: &[u8] = memslice.as_ref();
-        let global_bytes: &'static [u8] = unsafe { std::mem::transmute(global_bytes) };
+        let is_decoded = reader.is_decoded();
+        let mut is_eof = !is_decoded;
 
-        let i = {
+        // Replaces `memslice` with its bytes from `offset` followed by the next decoded bytes.
+        let mut read_next_slice = |memslice: &mut MemSlice, offset: usize| {
+            let leftover = memslice.slice(offset..memslice.len());
+            let (next, n_read) = reader.read_next_slice(&leftover, read_size)?;
+            *memslice = next;
+            PolarsResult::Ok(n_read < read_size)
+        };
+
+        let mut offset = loop {
             let parse_options = options.parse_options.as_ref();
 
             let quote_char = parse_options.quote_char;
@@ -484,8 +511,8 @@ impl LineBatchSource {
             let comment_prefix = parse_options.comment_prefix.clone();
             let has_header = options.has_header;
 
-            find_starting_point(
-                global_bytes,
+            let result = find_starting_point(
+                &memslice,
                 quote_char,
                 eol_char,
                 file_schema_len,
@@ -494,17 +521,27 @@ impl LineBatchSource {
                 skip_rows_after_header,
                 comment_prefix.as_ref(),
                 has_header,
-            )?
-        };
+            );
 
-        let mut bytes = &global_bytes[i..];
+            // The lines to skip may not be fully decoded yet.
+            match result {
+                Ok(i) if i < memslice.len() || is_eof => break i,
+                Err(e) if is_eof || !matches!(e, PolarsError::NoData(_)) => return Err(e),
+                _ => is_eof = read_next_slice(&mut memslice, 0)?,
+            }
+        };
 
         let mut chunk_size = {
             let max_chunk_size = 16 * 1024 * 1024;
             let chunk_size = if global_slice.is_some() {
                 max_chunk_size
+            } else if is_decoded {
+                std::cmp::min(read_size / num_pipelines, max_chunk_size)
             } else {
-                std::cmp::min(bytes.len() / (16 * num_pipelines), max_chunk_size)
+                std::cmp::min(
+                    (memslice.len() - offset) / (16 * num_pipelines),
+                    max_chunk_size,
+                )
             };
 
             // Use a small min chunk size to catch failures in tests.
@@ -516,21 +553,30 @@ impl LineBatchSource {
         };
 
         loop {
-            if bytes.is_empty() {
+            let bytes = &memslice[offset..];
+
+            if bytes.is_empty() && is_eof {
                 break;
             }
 
             let (count, position) = line_counter.find_next(bytes, &mut chunk_size);
             let (count, position) = if count == 0 {
+                if !is_eof {
+                    // There is no complete line left, decode more bytes.
+                    is_eof = read_next_slice(&mut memslice, offset)?;
+                    offset = 0;
+                    continue;
+                }
+
                 (1, bytes.len())
             } else {
                 let pos = (position + 1).min(bytes.len()); // +1 for '\n'
                 (count, pos)
             };
 
-            let slice_start = bytes.as_ptr() as usize - global_bytes.as_ptr() as usize;
+            let slice_start = offset;
 
-            bytes = &bytes[position..];
+            offset += position;
 
             let current_row_offset = *current_row_offset_ref;
             *current_row_offset_ref += count;
@@ -562,13 +608,11 @@ impl LineBatchSource {
                 NO_SLICE
             };
 
-            let bytes_this_chunk = &global_bytes[slice_start..slice_start + position];
-
             let morsel_seq = *morsel_seq_ref;
             *morsel_seq_ref = morsel_seq.successor();
 
             let batch = LineBatch {
-                bytes: bytes_this_chunk,
+                mem_slice: memslice.slice(slice_start..slice_start + position),
                 n_lines: count,
                 slice,
                 row_offset: current_row_offset,
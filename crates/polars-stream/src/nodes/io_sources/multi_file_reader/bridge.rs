@@ -4,12 +4,14 @@ use super::reader_interface::output::FileReaderOutputRecv;
 use crate::async_executor::{self, JoinHandle, TaskPriority};
 use crate::async_primitives::connector;
 use crate::async_primitives::morsel_linearizer::MorselLinearizer;
-use crate::async_primitives::wait_group::WaitToken;
+use crate::async_primitives::wait_group::{WaitGroup, WaitToken};
+use crate::memory::MemoryManager;
 use crate::morsel::{Morsel, MorselSeq, SourceToken};
 
 #[expect(clippy::type_complexity)]
 pub fn spawn_bridge(
     bridge_state: Arc<Mutex<BridgeState>>,
+    memory: Arc<MemoryManager>,
 ) -> (
     JoinHandle<()>,
     // For attaching file reader output port
@@ -27,6 +29,7 @@ pub fn spawn_bridge(
             outgoing,
             bridge_state,
             source_token: SourceToken::new(),
+            memory,
         }
         .run(),
     );
@@ -41,6 +44,7 @@ struct Bridge {
     outgoing: connector::Receiver<(connector::Sender<Morsel>, WaitToken)>,
     bridge_state: Arc<Mutex<BridgeState>>,
     source_token: SourceToken,
+    memory: Arc<MemoryManager>,
 }
 
 #[derive(Copy, Clone)]
@@ -146,6 +150,14 @@ impl Bridge {
 
             morsel_seq = morsel_seq.saturating_add(1);
 
+            // Apply backpressure if the query exceeds its memory budget: don't
+            // read ahead until this morsel has been consumed downstream.
+            let consumed = self.memory.is_over_budget().then(|| {
+                let wait_group = WaitGroup::default();
+                morsel.set_consume_token(wait_group.token());
+                wait_group
+            });
+
             while let Err(v) = tx.send(morsel).await {
                 drop(tx);
                 drop(current_phase_wait_token);
@@ -158,6 +170,10 @@ impl Bridge {
                 morsel = v;
             }
 
+            if let Some(wait_group) = consumed {
+                wait_group.wait().await;
+            }
+
             if self.source_token.stop_requested() {
                 drop(tx);
                 drop(current_phase_wait_token);
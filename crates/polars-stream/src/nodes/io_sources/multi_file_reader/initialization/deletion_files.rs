@@ -3,12 +3,14 @@ use std::sync::Arc;
 use arrow::bitmap::bitmask::BitMask;
 use arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_core::frame::DataFrame;
-use polars_core::prelude::{BooleanChunked, ChunkAgg, DataType, PlIndexMap};
+use polars_core::prelude::{
+    BooleanChunked, ChunkAgg, ChunkCompareEq, Column, DataType, PlHashMap, PlIndexMap,
+};
 use polars_core::schema::{Schema, SchemaRef};
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
-use polars_error::{PolarsResult, feature_gated};
+use polars_error::{PolarsResult, feature_gated, polars_ensure, polars_err};
 use polars_io::cloud::CloudOptions;
-use polars_plan::dsl::deletion::DeletionFilesList;
+use polars_plan::dsl::deletion::{DeletionFilesList, IcebergDeleteFiles};
 use polars_plan::dsl::{CastColumnsPolicy, ScanSource};
 use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
@@ -18,7 +20,7 @@ use polars_utils::slice_enum::Slice;
 use crate::async_executor::{self, AbortOnDropHandle, TaskPriority};
 use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
 use crate::nodes::io_sources::multi_file_reader::reader_interface::{
-    BeginReadArgs, FileReaderCallbacks,
+    BeginReadArgs, FileReader, FileReaderCallbacks,
 };
 #[cfg(feature = "parquet")]
 use crate::nodes::io_sources::parquet::builder::ParquetReaderBuilder;
@@ -34,6 +36,25 @@ pub enum DeletionFilesProvider {
         reader_builder: ParquetReaderBuilder,
         projected_schema: SchemaRef,
     },
+
+    #[cfg(feature = "parquet")]
+    IcebergDelete {
+        files: Arc<PlIndexMap<usize, IcebergDeleteFiles>>,
+        // Amortized allocations
+        position_delete_reader_builder: ParquetReaderBuilder,
+        position_delete_schema: SchemaRef,
+        /// Reads files using their own schema. Used for equality delete files, and for the
+        /// equality key columns of data files.
+        reader_builder: ParquetReaderBuilder,
+        /// Equality delete files usually apply to many data files, so their key columns are
+        /// loaded once and shared between the scan sources.
+        equality_delete_keys: EqualityDeleteKeysCache,
+    },
+
+    #[cfg(feature = "delta")]
+    DeltaDeletionVector {
+        vectors: Arc<PlIndexMap<usize, polars_plan::dsl::deletion::DeltaDeletionVector>>,
+    },
 }
 
 impl DeletionFilesProvider {
@@ -47,31 +68,34 @@ impl DeletionFilesProvider {
                 "parquet",
                 Self::IcebergPositionDelete {
                     paths,
-                    reader_builder: ParquetReaderBuilder {
-                        first_metadata: None,
-                        options: Arc::new(polars_io::prelude::ParquetOptions {
-                            schema: Some(Arc::new(Schema::from_iter([
-                                (PlSmallStr::from_static("file_path"), DataType::String),
-                                (PlSmallStr::from_static("pos"), DataType::Int64),
-                            ]))),
-
-                            parallel: polars_io::prelude::ParallelStrategy::Auto,
-                            low_memory: false,
-                            use_statistics: false,
-                        }),
-                    },
-                    projected_schema: Arc::new(Schema::from_iter([
-                        (PlSmallStr::from_static("file_path"), DataType::String),
-                        (PlSmallStr::from_static("pos"), DataType::Int64),
-                    ])),
+                    reader_builder: parquet_reader_builder(Some(iceberg_position_delete_schema())),
+                    projected_schema: iceberg_position_delete_schema(),
+                }
+            ),
+
+            DeletionFilesList::IcebergDelete(files) => feature_gated!(
+                "parquet",
+                Self::IcebergDelete {
+                    files,
+                    position_delete_reader_builder: parquet_reader_builder(Some(
+                        iceberg_position_delete_schema()
+                    )),
+                    position_delete_schema: iceberg_position_delete_schema(),
+                    reader_builder: parquet_reader_builder(None),
+                    equality_delete_keys: Default::default(),
                 }
             ),
+
+            DeletionFilesList::DeltaDeletionVector(vectors) => {
+                feature_gated!("delta", Self::DeltaDeletionVector { vectors })
+            },
         }
     }
 
     pub fn spawn_row_deletions_init(
         &self,
         scan_source_idx: usize,
+        scan_source: &ScanSource,
         cloud_options: Option<Arc<CloudOptions>>,
         num_pipelines: usize,
         verbose: bool,
@@ -95,126 +119,245 @@ impl DeletionFilesProvider {
                     )
                 }
 
-                // We create the readers and immediately spawn off tasks to initialize all of them.
-                let file_readers = paths
-                    .iter()
-                    .enumerate()
-                    .map(|(deletion_file_idx, path)| {
-                        let source = ScanSource::Path(PlPath::new(path));
-                        let mut reader = reader_builder.build_file_reader(
-                            source,
-                            cloud_options.clone(),
-                            deletion_file_idx,
-                        );
+                // We choose to load deletion files immediately during the initialization phase -
+                // the main driver loop of the multi file may need to serially `.await` on this
+                // between initializing readers when there is a slice.
+                //
+                // This does mean deletion file loads are tied to `NUM_READERS_PRE_INIT`, but this
+                // should be fine as the size of the data should not be too big.
+                let handles = spawn_load_iceberg_position_deletes(
+                    paths.iter().map(|x| x.as_str()),
+                    LoadIcebergPositionDeletesArgs {
+                        data_file_path: None,
+                        reader_builder,
+                        projected_schema,
+                        scan_source_idx,
+                        cloud_options,
+                        num_pipelines,
+                        verbose,
+                    },
+                );
+
+                let handle =
+                    AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
+                        let bitmap = collect_iceberg_position_deletes(handles).await?.freeze();
+
+                        // Also trigger the bitcount to reduce blocking later down.
+                        bitmap.unset_bits();
+                        debug_assert!(bitmap.lazy_unset_bits().is_some());
+
+                        let mask = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, bitmap);
+                        let mask = ExternalFilterMask::IcebergPositionDelete { mask };
 
                         if verbose {
+                            let num_deleted_rows = mask.num_deleted_rows();
+                            let max_index = mask.len().checked_sub(1);
+
                             eprintln!(
-                                "[DeletionFilesProvider[Iceberg]]: scan_source_idx: {scan_source_idx}, \
-                                deletion_file_idx: {deletion_file_idx}, \
-                                deletion_file_path: {path}"
+                                "[DeletionFilesProvider[Iceberg]]: \
+                                scan_source_idx: {scan_source_idx}, \
+                                num_deleted_rows: {num_deleted_rows}, \
+                                max_index: {max_index:?}",
                             )
                         }
 
-                        AbortOnDropHandle::new(async_executor::spawn(
-                            TaskPriority::Low,
-                            async move {
-                                reader.initialize().await?;
-                                PolarsResult::Ok(reader)
-                            },
-                        ))
-                    })
-                    .collect::<Vec<_>>();
+                        Ok(mask)
+                    }));
+
+                Some(RowDeletionsInit::Initializing(handle))
+            },
 
-                let projected_schema = projected_schema.clone();
+            #[cfg(feature = "parquet")]
+            Self::IcebergDelete {
+                files,
+                position_delete_reader_builder,
+                position_delete_schema,
+                reader_builder,
+                equality_delete_keys,
+            } => {
+                use polars_plan::dsl::deletion::IcebergDeleteContent;
 
-                // We choose to load deletion files immediately during the initialization phase -
-                // the main driver loop of the multi file may need to serially `.await` on this
-                // between initializing readers when there is a slice.
-                //
-                // This does mean deletion file loads are tied to `NUM_READERS_PRE_INIT`, but this
-                // should be fine as the size of the data should not be too big.
-                let handle = AbortOnDropHandle::new(async_executor::spawn(
-                    TaskPriority::Low,
-                    async move {
-                        let handles = file_readers
-                            .into_iter()
-                            .map(|init_fut| {
-                                use crate::nodes::io_sources::multi_file_reader::reader_interface::Projection;
-
-                                let begin_read_args = BeginReadArgs {
-                                    projection: Projection::Plain(projected_schema.clone()),
-                                    row_index: None,
-                                    pre_slice: None,
-                                    predicate: None,
-                                    cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
-                                    num_pipelines,
-                                    callbacks: FileReaderCallbacks {
-                                        file_schema_tx: None,
-                                        n_rows_in_file_tx: None,
-                                        row_position_on_end_tx: None,
-                                    },
-                                };
+                let IcebergDeleteFiles {
+                    data_file_path,
+                    data_sequence_number,
+                    files,
+                } = files.get(&scan_source_idx)?;
+
+                let mut position_delete_paths = vec![];
+                let mut equality_deletes = vec![];
 
-                                AbortOnDropHandle::new(async_executor::spawn(
-                                    TaskPriority::Low,
-                                    async move {
-                                        let mut reader = init_fut.await?;
+                for file in files.iter() {
+                    if !file.applies_to(*data_sequence_number) {
+                        continue;
+                    }
+
+                    match &file.content {
+                        IcebergDeleteContent::Position => {
+                            position_delete_paths.push(file.path.as_str())
+                        },
+                        IcebergDeleteContent::Equality { equality_ids } => {
+                            equality_deletes.push((file.path.clone(), equality_ids.clone()))
+                        },
+                    }
+                }
 
-                                        let (mut rx, handle) =
-                                            reader.begin_read(begin_read_args)?;
+                if verbose {
+                    eprintln!(
+                        "[DeletionFilesProvider[Iceberg]]: scan_source_idx: {}, \
+                        data_sequence_number: {}, {} files, {} position delete files, \
+                        {} equality delete files",
+                        scan_source_idx,
+                        data_sequence_number,
+                        files.len(),
+                        position_delete_paths.len(),
+                        equality_deletes.len(),
+                    )
+                }
+
+                if position_delete_paths.is_empty() && equality_deletes.is_empty() {
+                    return None;
+                }
+
+                let position_handles = spawn_load_iceberg_position_deletes(
+                    position_delete_paths.into_iter(),
+                    LoadIcebergPositionDeletesArgs {
+                        data_file_path: Some(data_file_path.as_str()),
+                        reader_builder: position_delete_reader_builder,
+                        projected_schema: position_delete_schema,
+                        scan_source_idx,
+                        cloud_options: cloud_options.clone(),
+                        num_pipelines,
+                        verbose,
+                    },
+                );
 
-                                        let mut dfs = vec![];
+                // Union of the equality field IDs of all delete files, these are read from the
+                // data file.
+                let mut data_key_ids: Vec<u32> = vec![];
 
-                                        while let Ok(morsel) = rx.recv().await {
-                                            dfs.push(morsel.into_df());
-                                        }
+                for (_, equality_ids) in &equality_deletes {
+                    for id in equality_ids.iter() {
+                        if !data_key_ids.contains(id) {
+                            data_key_ids.push(*id);
+                        }
+                    }
+                }
 
-                                        handle.await?;
+                let equality_handles = equality_deletes
+                    .into_iter()
+                    .enumerate()
+                    .map(|(deletion_file_idx, (path, equality_ids))| {
+                        if verbose {
+                            eprintln!(
+                                "[DeletionFilesProvider[Iceberg]]: scan_source_idx: {scan_source_idx}, \
+                                deletion_file_idx: {deletion_file_idx}, \
+                                equality_ids: {equality_ids:?}, \
+                                deletion_file_path: {path}"
+                            )
+                        }
 
-                                        let df = accumulate_dataframes_vertical_unchecked(dfs);
+                        let delete_keys = equality_delete_keys
+                            .lock()
+                            .entry(path.clone())
+                            .or_default()
+                            .clone();
+                        let reader_builder = reader_builder.clone();
+                        let cloud_options = cloud_options.clone();
 
-                                        // Some quick testing on AWS Athena showed that it doesn't
-                                        // write deletion files that reference multiple distinct
-                                        // file paths, so we don't handle that for now.
-                                        assert!(
-                                            df.column("file_path")?.n_unique()? <= 1,
-                                            "assertion failed: iceberg position delete file: \
-                                            n_unique(data_file_paths) <= 1. \
-                                            This is a bug, please open an issue"
+                        AbortOnDropHandle::new(async_executor::spawn(
+                            TaskPriority::Low,
+                            async move {
+                                let columns = delete_keys
+                                    .get_or_try_init(|| async {
+                                        let reader = reader_builder.build_file_reader(
+                                            ScanSource::Path(PlPath::new(&path)),
+                                            cloud_options,
+                                            deletion_file_idx,
                                         );
 
-                                        let positions_col = df.column("pos")?.clone();
-                                        let max_idx = usize::try_from(
-                                            positions_col
-                                                .as_materialized_series_maintain_scalar()
-                                                .i64()
-                                                .unwrap()
-                                                .max()
-                                                .unwrap_or(0),
+                                        let (columns, _) = read_columns_by_field_id(
+                                            reader,
+                                            &equality_ids,
+                                            num_pipelines,
                                         )
-                                        .unwrap();
+                                        .await?;
+
+                                        columns
+                                            .into_iter()
+                                            .zip(equality_ids.iter())
+                                            .map(|(c, id)| {
+                                                c.ok_or_else(|| {
+                                                    polars_err!(
+                                                        ComputeError:
+                                                        "equality field ID {} was not found in \
+                                                        the top-level columns of the Iceberg \
+                                                        equality delete file '{}'",
+                                                        id, path
+                                                    )
+                                                })
+                                            })
+                                            .collect::<PolarsResult<Arc<[Column]>>>()
+                                    })
+                                    .await?
+                                    .clone();
+
+                                PolarsResult::Ok((equality_ids, columns))
+                            },
+                        ))
+                    })
+                    .collect::<Vec<_>>();
 
-                                        PolarsResult::Ok((positions_col, max_idx))
-                                    },
-                                ))
-                            })
-                            .collect::<Vec<_>>();
+                // The equality key columns of the data file itself. This does mean these columns
+                // are read twice, but it lets the deletions be applied as a positional mask.
+                let data_keys_handle = (!data_key_ids.is_empty()).then(|| {
+                    let reader = reader_builder.build_file_reader(
+                        scan_source.clone(),
+                        cloud_options.clone(),
+                        scan_source_idx,
+                    );
 
-                        let mut position_columns = Vec::with_capacity(handles.len());
-                        let mut filter_mask_len: usize = 0;
+                    AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
+                        let (columns, height) =
+                            read_columns_by_field_id(reader, &data_key_ids, num_pipelines).await?;
 
-                        for handle in handles {
-                            let (positions_col, max_idx) = handle.await?;
-                            filter_mask_len = filter_mask_len.max(max_idx.saturating_add(1));
-                            position_columns.push(positions_col);
-                        }
+                        PolarsResult::Ok((data_key_ids, columns, height))
+                    }))
+                });
+
+                let handle =
+                    AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
+                        let mut filter_mask =
+                            collect_iceberg_position_deletes(position_handles).await?;
+
+                        if let Some(data_keys_handle) = data_keys_handle {
+                            let (data_key_ids, data_key_columns, height) = data_keys_handle.await?;
 
-                        let mut filter_mask = MutableBitmap::from_len_set(filter_mask_len);
+                            if filter_mask.len() < height {
+                                filter_mask.extend_constant(height - filter_mask.len(), true);
+                            }
 
-                        for c in position_columns {
-                            for idx in c.as_materialized_series_maintain_scalar().i64().unwrap() {
-                                let idx = usize::try_from(idx.unwrap()).unwrap();
-                                filter_mask.set(idx, false);
+                            for handle in equality_handles {
+                                let (equality_ids, delete_keys) = handle.await?;
+
+                                let data_keys = equality_ids
+                                    .iter()
+                                    .zip(delete_keys.iter())
+                                    .map(|(id, delete_key)| {
+                                        let idx =
+                                            data_key_ids.iter().position(|x| x == id).unwrap();
+
+                                        // Columns added after the data file was written are null.
+                                        data_key_columns[idx].clone().unwrap_or_else(|| {
+                                            Column::full_null(
+                                                delete_key.name().clone(),
+                                                height,
+                                                delete_key.dtype(),
+                                            )
+                                        })
+                                    })
+                                    .collect::<Vec<_>>();
+
+                                apply_equality_deletes(&mut filter_mask, &data_keys, &delete_keys)?;
                             }
                         }
 
@@ -225,23 +368,103 @@ impl DeletionFilesProvider {
                         debug_assert!(bitmap.lazy_unset_bits().is_some());
 
                         let mask = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, bitmap);
-                        let mask = ExternalFilterMask::IcebergPositionDelete { mask };
+                        let mask = ExternalFilterMask::IcebergDelete { mask };
 
                         if verbose {
                             let num_deleted_rows = mask.num_deleted_rows();
-                            let max_index = mask.len().checked_sub(1);
 
                             eprintln!(
                                 "[DeletionFilesProvider[Iceberg]]: \
                                 scan_source_idx: {scan_source_idx}, \
-                                num_deleted_rows: {num_deleted_rows}, \
-                                max_index: {max_index:?}",
+                                num_deleted_rows: {num_deleted_rows}",
                             )
                         }
 
                         Ok(mask)
-                    },
-                ));
+                    }));
+
+                Some(RowDeletionsInit::Initializing(handle))
+            },
+
+            #[cfg(feature = "delta")]
+            Self::DeltaDeletionVector { vectors } => {
+                use polars_io::delta::deletion_vector::{
+                    decode_deletion_vector_file_range, decode_inline_deletion_vector,
+                    deletion_vector_file_range,
+                };
+                use polars_io::utils::byte_source::{ByteSource, DynByteSourceBuilder};
+                use polars_plan::dsl::deletion::{DeltaDeletionVector, DeltaDeletionVectorStorage};
+
+                let DeltaDeletionVector {
+                    storage,
+                    size_in_bytes,
+                    cardinality,
+                    num_records,
+                } = vectors.get(&scan_source_idx)?.clone();
+
+                if verbose {
+                    let location = match &storage {
+                        DeltaDeletionVectorStorage::Inline(_) => "inline",
+                        DeltaDeletionVectorStorage::File { path, .. } => path.as_str(),
+                    };
+
+                    eprintln!(
+                        "[DeletionFilesProvider[Delta]]: scan_source_idx: {scan_source_idx}, \
+                        cardinality: {cardinality}, \
+                        location: {location}"
+                    )
+                }
+
+                let handle =
+                    AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
+                        let bitmap = match storage {
+                            DeltaDeletionVectorStorage::Inline(encoded) => {
+                                decode_inline_deletion_vector(&encoded, size_in_bytes, num_records)?
+                            },
+                            DeltaDeletionVectorStorage::File { path, offset } => {
+                                let byte_source_builder = if PlPath::new(&path).is_cloud_url()
+                                    || polars_core::config::force_async()
+                                {
+                                    DynByteSourceBuilder::ObjectStore
+                                } else {
+                                    DynByteSourceBuilder::Mmap
+                                };
+
+                                let byte_source = byte_source_builder
+                                    .try_build_from_path(&path, cloud_options.as_deref())
+                                    .await?;
+
+                                let range = deletion_vector_file_range(offset, size_in_bytes);
+                                let file_size = byte_source.get_size().await?;
+                                polars_ensure!(
+                                    range.end <= file_size,
+                                    ComputeError: "Delta deletion vector at offset {} exceeds \
+                                    the size of the file '{}'",
+                                    offset, path
+                                );
+                                let bytes = byte_source.get_range(range).await?;
+
+                                decode_deletion_vector_file_range(
+                                    &bytes,
+                                    size_in_bytes,
+                                    num_records,
+                                )?
+                            },
+                        };
+
+                        // Also trigger the bitcount to reduce blocking later down.
+                        let num_deleted_rows = bitmap.unset_bits();
+                        polars_ensure!(
+                            num_deleted_rows as u64 == cardinality,
+                            ComputeError: "Delta deletion vector of scan source {} contains {} \
+                            rows, expected {}",
+                            scan_source_idx, num_deleted_rows, cardinality
+                        );
+
+                        let mask = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, bitmap);
+
+                        Ok(ExternalFilterMask::DeltaDeletionVector { mask })
+                    }));
 
                 Some(RowDeletionsInit::Initializing(handle))
             },
@@ -272,6 +495,11 @@ impl RowDeletionsInit {
 pub enum ExternalFilterMask {
     /// Note: Iceberg positional deletes can have a mask length shorter than the actual data.
     IcebergPositionDelete { mask: BooleanChunked },
+    /// Iceberg positional and equality deletes. Equality deletes are resolved to the positions of
+    /// the matching rows in the data file.
+    IcebergDelete { mask: BooleanChunked },
+    /// Note: The mask ends at the last deleted row, so it can also be shorter than the data.
+    DeltaDeletionVector { mask: BooleanChunked },
 }
 
 impl ExternalFilterMask {
@@ -279,6 +507,8 @@ impl ExternalFilterMask {
         use ExternalFilterMask::*;
         match self {
             IcebergPositionDelete { .. } => "IcebergPositionDelete",
+            IcebergDelete { .. } => "IcebergDelete",
+            DeltaDeletionVector { .. } => "DeltaDeletionVector",
         }
     }
 
@@ -297,7 +527,9 @@ impl ExternalFilterMask {
 
     pub fn filter_df(&self, df: &mut DataFrame) -> PolarsResult<()> {
         match self {
-            Self::IcebergPositionDelete { mask } => {
+            Self::IcebergPositionDelete { mask }
+            | Self::IcebergDelete { mask }
+            | Self::DeltaDeletionVector { mask } => {
                 if !mask.is_empty() {
                     *df = if mask.len() < df.height() {
                         accumulate_dataframes_vertical_unchecked([
@@ -315,23 +547,33 @@ impl ExternalFilterMask {
     }
 
     pub fn slice(&self, offset: usize, len: usize) -> Self {
-        match self {
-            Self::IcebergPositionDelete { mask } => {
-                // This is not a valid offset, it's also a sentinel value from `RowCounter::MAX`.
-                assert_ne!(offset, usize::MAX);
-                let offset = offset.min(mask.len());
-                let len = len.min(mask.len() - offset);
+        let slice_mask = |mask: &BooleanChunked| {
+            // This is not a valid offset, it's also a sentinel value from `RowCounter::MAX`.
+            assert_ne!(offset, usize::MAX);
+            let offset = offset.min(mask.len());
+            let len = len.min(mask.len() - offset);
 
-                let mask = mask.slice(i64::try_from(offset).unwrap(), len);
+            mask.slice(i64::try_from(offset).unwrap(), len)
+        };
 
-                Self::IcebergPositionDelete { mask }
+        match self {
+            Self::IcebergPositionDelete { mask } => Self::IcebergPositionDelete {
+                mask: slice_mask(mask),
+            },
+            Self::IcebergDelete { mask } => Self::IcebergDelete {
+                mask: slice_mask(mask),
+            },
+            Self::DeltaDeletionVector { mask } => Self::DeltaDeletionVector {
+                mask: slice_mask(mask),
             },
         }
     }
 
     pub fn num_deleted_rows(&self) -> usize {
         match self {
-            Self::IcebergPositionDelete { mask } => mask
+            Self::IcebergPositionDelete { mask }
+            | Self::IcebergDelete { mask }
+            | Self::DeltaDeletionVector { mask } => mask
                 .rechunk()
                 .downcast_get(0)
                 .unwrap()
@@ -388,7 +630,9 @@ impl ExternalFilterMask {
 
     fn get_mask(&self) -> Bitmap {
         match self {
-            Self::IcebergPositionDelete { mask } => {
+            Self::IcebergPositionDelete { mask }
+            | Self::IcebergDelete { mask }
+            | Self::DeltaDeletionVector { mask } => {
                 mask.rechunk().downcast_get(0).unwrap().values().clone()
             },
         }
@@ -396,9 +640,297 @@ impl ExternalFilterMask {
 
     pub fn len(&self) -> usize {
         match self {
-            Self::IcebergPositionDelete { mask } => mask.len(),
+            Self::IcebergPositionDelete { mask }
+            | Self::IcebergDelete { mask }
+            | Self::DeltaDeletionVector { mask } => mask.len(),
+        }
+    }
+}
+
+#[cfg(feature = "parquet")]
+fn parquet_reader_builder(schema: Option<SchemaRef>) -> ParquetReaderBuilder {
+    ParquetReaderBuilder {
+        first_metadata: None,
+        options: Arc::new(polars_io::prelude::ParquetOptions {
+            schema,
+
+            parallel: polars_io::prelude::ParallelStrategy::Auto,
+            low_memory: false,
+            use_statistics: false,
+            decryption: None,
+        }),
+    }
+}
+
+/// Key columns of Iceberg equality delete files by path, each loaded by the first scan source
+/// it applies to.
+#[cfg(feature = "parquet")]
+type EqualityDeleteKeysCache =
+    Arc<parking_lot::Mutex<PlHashMap<String, Arc<tokio::sync::OnceCell<Arc<[Column]>>>>>>;
+
+#[cfg(feature = "parquet")]
+fn iceberg_position_delete_schema() -> SchemaRef {
+    Arc::new(Schema::from_iter([
+        (PlSmallStr::from_static("file_path"), DataType::String),
+        (PlSmallStr::from_static("pos"), DataType::Int64),
+    ]))
+}
+
+#[cfg(feature = "parquet")]
+struct LoadIcebergPositionDeletesArgs<'a> {
+    /// If given, only the deletes whose `file_path` is equal to it are loaded, as a single
+    /// position delete file can contain deletes for multiple data files.
+    data_file_path: Option<&'a str>,
+    reader_builder: &'a ParquetReaderBuilder,
+    projected_schema: &'a SchemaRef,
+    scan_source_idx: usize,
+    cloud_options: Option<Arc<CloudOptions>>,
+    num_pipelines: usize,
+    verbose: bool,
+}
+
+/// Creates the readers for the Iceberg position delete files at `paths` and immediately spawns off
+/// tasks to load all of them.
+///
+/// # Returns
+/// A handle resolving to `(positions_col, max_idx)` per file.
+#[cfg(feature = "parquet")]
+fn spawn_load_iceberg_position_deletes<'a>(
+    paths: impl Iterator<Item = &'a str>,
+    args: LoadIcebergPositionDeletesArgs<'_>,
+) -> Vec<AbortOnDropHandle<PolarsResult<(Column, usize)>>> {
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::Projection;
+
+    let LoadIcebergPositionDeletesArgs {
+        data_file_path,
+        reader_builder,
+        projected_schema,
+        scan_source_idx,
+        cloud_options,
+        num_pipelines,
+        verbose,
+    } = args;
+
+    paths
+        .enumerate()
+        .map(|(deletion_file_idx, path)| {
+            let source = ScanSource::Path(PlPath::new(path));
+            let mut reader =
+                reader_builder.build_file_reader(source, cloud_options.clone(), deletion_file_idx);
+
+            if verbose {
+                eprintln!(
+                    "[DeletionFilesProvider[Iceberg]]: scan_source_idx: {scan_source_idx}, \
+                    deletion_file_idx: {deletion_file_idx}, \
+                    deletion_file_path: {path}"
+                )
+            }
+
+            let begin_read_args = BeginReadArgs {
+                projection: Projection::Plain(projected_schema.clone()),
+                row_index: None,
+                pre_slice: None,
+                predicate: None,
+                cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+                num_pipelines,
+                callbacks: FileReaderCallbacks {
+                    file_schema_tx: None,
+                    n_rows_in_file_tx: None,
+                    row_position_on_end_tx: None,
+                },
+            };
+
+            let data_file_path = data_file_path.map(PlSmallStr::from_str);
+
+            AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
+                reader.initialize().await?;
+
+                let (mut rx, handle) = reader.begin_read(begin_read_args)?;
+
+                let mut dfs = vec![];
+
+                while let Ok(morsel) = rx.recv().await {
+                    dfs.push(morsel.into_df());
+                }
+
+                handle.await?;
+
+                let mut df = accumulate_dataframes_vertical_unchecked(dfs);
+
+                if let Some(data_file_path) = data_file_path {
+                    let mask = df
+                        .column("file_path")?
+                        .as_materialized_series()
+                        .str()?
+                        .equal(data_file_path.as_str());
+
+                    df = df.filter(&mask)?;
+                } else {
+                    // Some quick testing on AWS Athena showed that it doesn't write deletion files
+                    // that reference multiple distinct file paths, so we don't handle that for now.
+                    assert!(
+                        df.column("file_path")?.n_unique()? <= 1,
+                        "assertion failed: iceberg position delete file: \
+                        n_unique(data_file_paths) <= 1. \
+                        This is a bug, please open an issue"
+                    );
+                }
+
+                let positions_col = df.column("pos")?.clone();
+                let max_idx = usize::try_from(
+                    positions_col
+                        .as_materialized_series_maintain_scalar()
+                        .i64()
+                        .unwrap()
+                        .max()
+                        .unwrap_or(0),
+                )
+                .unwrap();
+
+                PolarsResult::Ok((positions_col, max_idx))
+            }))
+        })
+        .collect()
+}
+
+/// Builds a filter mask from the loaded Iceberg position deletes.
+#[cfg(feature = "parquet")]
+async fn collect_iceberg_position_deletes(
+    handles: Vec<AbortOnDropHandle<PolarsResult<(Column, usize)>>>,
+) -> PolarsResult<MutableBitmap> {
+    let mut position_columns = Vec::with_capacity(handles.len());
+    let mut filter_mask_len: usize = 0;
+
+    for handle in handles {
+        let (positions_col, max_idx) = handle.await?;
+        filter_mask_len = filter_mask_len.max(max_idx.saturating_add(1));
+        position_columns.push(positions_col);
+    }
+
+    let mut filter_mask = MutableBitmap::from_len_set(filter_mask_len);
+
+    for c in position_columns {
+        for idx in c.as_materialized_series_maintain_scalar().i64().unwrap() {
+            let idx = usize::try_from(idx.unwrap()).unwrap();
+            filter_mask.set(idx, false);
+        }
+    }
+
+    Ok(filter_mask)
+}
+
+/// Reads the top-level columns with the given Iceberg field IDs from a file. Field IDs that are
+/// not in the file are returned as `None`.
+///
+/// # Returns
+/// `(columns, n_rows_in_file)`
+#[cfg(feature = "parquet")]
+async fn read_columns_by_field_id(
+    mut reader: Box<dyn FileReader>,
+    field_ids: &[u32],
+    num_pipelines: usize,
+) -> PolarsResult<(Vec<Option<Column>>, usize)> {
+    use polars_core::schema::iceberg::IcebergSchema;
+
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::Projection;
+
+    reader.initialize().await?;
+
+    let n_rows_in_file = reader.n_rows_in_file().await? as usize;
+    let file_schema = reader.file_schema().await?;
+    let file_arrow_schema = reader.file_arrow_schema().await?.unwrap();
+    let file_iceberg_schema = IcebergSchema::from_arrow_schema(&file_arrow_schema)?;
+
+    let names = field_ids
+        .iter()
+        .map(|id| file_iceberg_schema.get(id).map(|col| col.name.clone()))
+        .collect::<Vec<_>>();
+
+    let projected_schema: SchemaRef =
+        Arc::new(Schema::from_iter(names.iter().flatten().map(|name| {
+            (name.clone(), file_schema.get(name).unwrap().clone())
+        })));
+
+    if projected_schema.is_empty() {
+        return Ok((vec![None; names.len()], n_rows_in_file));
+    }
+
+    let (mut rx, handle) = reader.begin_read(BeginReadArgs {
+        projection: Projection::Plain(projected_schema.clone()),
+        row_index: None,
+        pre_slice: None,
+        predicate: None,
+        cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+        num_pipelines,
+        callbacks: FileReaderCallbacks {
+            file_schema_tx: None,
+            n_rows_in_file_tx: None,
+            row_position_on_end_tx: None,
+        },
+    })?;
+
+    let mut dfs = vec![];
+
+    while let Ok(morsel) = rx.recv().await {
+        dfs.push(morsel.into_df());
+    }
+
+    handle.await?;
+
+    let df = if dfs.is_empty() {
+        DataFrame::empty_with_schema(&projected_schema)
+    } else {
+        accumulate_dataframes_vertical_unchecked(dfs)
+    };
+
+    let columns = names
+        .into_iter()
+        .map(|name| name.map(|name| df.column(&name).cloned()).transpose())
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    Ok((columns, n_rows_in_file))
+}
+
+/// Unsets the bits of `filter_mask` for the rows of `data_keys` that are equal to a row of
+/// `delete_keys`. Null values compare equal.
+#[cfg(feature = "parquet")]
+fn apply_equality_deletes(
+    filter_mask: &mut MutableBitmap,
+    data_keys: &[Column],
+    delete_keys: &[Column],
+) -> PolarsResult<()> {
+    use polars_core::chunked_array::ops::row_encode::encode_rows_unordered;
+    use polars_core::prelude::PlHashSet;
+
+    let delete_keys = delete_keys
+        .iter()
+        .zip(data_keys)
+        .map(|(delete_key, data_key)| delete_key.strict_cast(data_key.dtype()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    if delete_keys.first().is_none_or(|c| c.is_empty()) {
+        return Ok(());
+    }
+
+    let delete_rows = encode_rows_unordered(&delete_keys)?;
+    let deleted_rows: PlHashSet<&[u8]> = delete_rows
+        .downcast_iter()
+        .flat_map(|arr| arr.values_iter())
+        .collect();
+
+    let data_rows = encode_rows_unordered(data_keys)?;
+
+    for (idx, row) in data_rows
+        .downcast_iter()
+        .flat_map(|arr| arr.values_iter())
+        .enumerate()
+    {
+        if deleted_rows.contains(row) {
+            filter_mask.set(idx, false);
         }
     }
+
+    Ok(())
 }
 
 /// Calculates the nth set bit as though `mask` were extended infinitely with trues.
@@ -439,6 +971,38 @@ mod tests {
         (mask.into_iter().collect(), slice)
     }
 
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_apply_equality_deletes() {
+        use arrow::bitmap::MutableBitmap;
+        use polars_core::prelude::Column;
+
+        use super::apply_equality_deletes;
+
+        const T: bool = true;
+        const F: bool = false;
+
+        let data_keys = [
+            Column::new("id".into(), [Some(1i64), Some(2), None, Some(2), Some(3)]),
+            Column::new(
+                "name".into(),
+                [Some("a"), Some("b"), Some("c"), Some("x"), None],
+            ),
+        ];
+
+        // The delete file can have a narrower type than the data file after type promotion.
+        let delete_keys = [
+            Column::new("id".into(), [Some(2i32), None, Some(3), Some(4)]),
+            Column::new("name".into(), [Some("b"), Some("c"), None, Some("d")]),
+        ];
+
+        // The mask may already be longer than the data from position deletes.
+        let mut mask = MutableBitmap::from_len_set(6);
+        apply_equality_deletes(&mut mask, &data_keys, &delete_keys).unwrap();
+
+        assert_eq!(mask.freeze().iter().collect::<Vec<_>>(), [T, F, F, T, F, T]);
+    }
+
     #[test]
     fn test_split_slice_positive() {
         const T: bool = true;
@@ -12,6 +12,7 @@ use super::bridge::BridgeState;
 use crate::async_executor::{self, AbortOnDropHandle, TaskPriority};
 use crate::async_primitives::connector::{self};
 use crate::async_primitives::wait_group::WaitToken;
+use crate::memory::MemoryManager;
 use crate::morsel::Morsel;
 use crate::nodes::io_sources::multi_file_reader::bridge::spawn_bridge;
 
@@ -27,6 +28,7 @@ impl MultiScanTaskInitializer {
     #[expect(clippy::type_complexity)]
     pub fn spawn_background_tasks(
         self,
+        memory: Arc<MemoryManager>,
     ) -> (
         AbortOnDropHandle<PolarsResult<()>>,
         connector::Sender<(connector::Sender<Morsel>, WaitToken)>,
@@ -53,7 +55,7 @@ impl MultiScanTaskInitializer {
         let bridge_state = Arc::new(Mutex::new(BridgeState::NotYetStarted));
 
         let (bridge_handle, bridge_recv_port_tx, send_phase_chan_to_bridge) =
-            spawn_bridge(bridge_state.clone());
+            spawn_bridge(bridge_state.clone(), memory);
 
         let verbose = self.config.verbose;
 
@@ -1,6 +1,9 @@
 use arrow::bitmap::Bitmap;
+use polars_core::prelude::{Column, DataFrame, IDX_DTYPE, IdxCa, IntoColumn};
 use polars_error::PolarsResult;
-use polars_io::predicates::ScanIOPredicate;
+use polars_io::predicates::{ScanIOPredicate, SkipBatchPredicate};
+use polars_plan::dsl::file_statistics::FileStatistics;
+use polars_utils::format_pl_smallstr;
 
 use super::MultiScanTaskInitializer;
 
@@ -10,45 +13,144 @@ impl MultiScanTaskInitializer {
     ///
     /// TODO: Move logic here, rename to `evaluate_on_constant_columns`.
     pub fn initialize_predicate(&self) -> PolarsResult<(Option<Bitmap>, Option<&ScanIOPredicate>)> {
-        if let Some(predicate) = &self.config.predicate {
-            if let Some(hive_parts) = self.config.hive_parts.as_ref() {
-                let mut skip_files_mask = None;
-
-                if let Some(predicate) = &predicate.hive_predicate {
-                    let mask = predicate
-                        .evaluate_io(hive_parts.df())?
-                        .bool()?
-                        .rechunk()
-                        .into_owned()
-                        .downcast_into_iter()
-                        .next()
-                        .unwrap()
-                        .values()
-                        .clone();
-
-                    // TODO: Optimize to avoid doing this
-                    let mask = !&mask;
-
-                    if self.config.verbose {
-                        eprintln!(
-                            "[MultiScan]: Predicate pushdown allows skipping {} / {} files",
-                            mask.set_bits(),
-                            mask.len()
-                        );
-                    }
-
-                    skip_files_mask = Some(mask);
+        let Some(predicate) = &self.config.predicate else {
+            return Ok((None, None));
+        };
+
+        let mut skip_files_mask = None;
+        let mut need_pred_for_inner_readers = true;
+
+        if let Some(hive_parts) = self.config.hive_parts.as_ref() {
+            if let Some(predicate) = &predicate.hive_predicate {
+                let mask = predicate
+                    .evaluate_io(hive_parts.df())?
+                    .bool()?
+                    .rechunk()
+                    .into_owned()
+                    .downcast_into_iter()
+                    .next()
+                    .unwrap()
+                    .values()
+                    .clone();
+
+                // TODO: Optimize to avoid doing this
+                let mask = !&mask;
+
+                if self.config.verbose {
+                    eprintln!(
+                        "[MultiScan]: Predicate pushdown allows skipping {} / {} files",
+                        mask.set_bits(),
+                        mask.len()
+                    );
                 }
 
-                let need_pred_for_inner_readers = !predicate.hive_predicate_is_full_predicate;
+                skip_files_mask = Some(mask);
+            }
+
+            need_pred_for_inner_readers = !predicate.hive_predicate_is_full_predicate;
+        }
 
-                return Ok((
-                    skip_files_mask,
-                    need_pred_for_inner_readers.then_some(predicate),
-                ));
+        if let (Some(file_statistics), Some(skip_batch_predicate)) = (
+            self.config.file_statistics.as_ref(),
+            predicate.skip_batch_predicate.as_ref(),
+        ) {
+            if let Some(mask) =
+                self.skip_files_from_statistics(predicate, skip_batch_predicate, file_statistics)?
+            {
+                if self.config.verbose {
+                    eprintln!(
+                        "[MultiScan]: File statistics allow skipping {} / {} files",
+                        mask.set_bits(),
+                        mask.len()
+                    );
+                }
+
+                skip_files_mask = Some(match skip_files_mask {
+                    Some(hive_mask) => &hive_mask | &mask,
+                    None => mask,
+                });
             }
         }
 
-        Ok((None, self.config.predicate.as_ref()))
+        Ok((
+            skip_files_mask,
+            need_pred_for_inner_readers.then_some(predicate),
+        ))
+    }
+
+    /// Evaluates the skip batch predicate on the statistics of each file. Hive columns are
+    /// constant within a file, their values are used as the minimum and maximum.
+    ///
+    /// Returns `None` if the statistics cannot be used for the predicate.
+    fn skip_files_from_statistics(
+        &self,
+        predicate: &ScanIOPredicate,
+        skip_batch_predicate: &std::sync::Arc<dyn SkipBatchPredicate>,
+        file_statistics: &FileStatistics,
+    ) -> PolarsResult<Option<Bitmap>> {
+        let stats = file_statistics.df();
+        let num_files = stats.height();
+
+        if num_files != self.config.sources.len() {
+            return Ok(None);
+        }
+
+        let mut columns = Vec::with_capacity(1 + predicate.live_columns.len() * 3);
+        let len = stats.column("len")?.clone();
+        columns.push(len.clone());
+
+        for name in predicate.live_columns.iter() {
+            let min_name = format_pl_smallstr!("{name}_min");
+            let max_name = format_pl_smallstr!("{name}_max");
+            let nc_name = format_pl_smallstr!("{name}_nc");
+
+            if let Ok(min) = stats.column(&min_name) {
+                columns.extend([
+                    min.clone(),
+                    stats.column(&max_name)?.clone(),
+                    stats.column(&nc_name)?.clone(),
+                ]);
+                continue;
+            }
+
+            if let Some(values) = self
+                .config
+                .hive_parts
+                .as_ref()
+                .and_then(|hive_parts| hive_parts.df().column(name).ok())
+            {
+                // A null partition value means all rows are null.
+                let null_count = values
+                    .is_null()
+                    .into_iter()
+                    .zip(len.idx()?.iter())
+                    .map(|(is_null, len)| if is_null.unwrap() { len } else { Some(0) })
+                    .collect::<IdxCa>();
+
+                columns.extend([
+                    values.clone().with_name(min_name),
+                    values.clone().with_name(max_name),
+                    null_count.with_name(nc_name).into_column(),
+                ]);
+                continue;
+            }
+
+            let Some(dtype) = skip_batch_predicate
+                .schema()
+                .get(name)
+                .or_else(|| self.config.final_output_schema.get(name))
+            else {
+                return Ok(None);
+            };
+
+            columns.extend([
+                Column::full_null(min_name, num_files, dtype),
+                Column::full_null(max_name, num_files, dtype),
+                Column::full_null(nc_name, num_files, &IDX_DTYPE),
+            ]);
+        }
+
+        let df = DataFrame::new_with_height(num_files, columns)?;
+        skip_batch_predicate.evaluate_with_stat_df(&df).map(Some)
     }
 }
@@ -108,18 +108,12 @@ async fn resolve_negative_slice(config: &MultiFileReaderConfig) -> PolarsResult<
             let deletion_files_provider = deletion_files_provider.clone();
 
             AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
-                let mut reader =
-                    sources
-                        .get(scan_source_idx)
-                        .unwrap()
-                        .into_owned()
-                        .map(|source| {
-                            file_reader_builder.build_file_reader(
-                                source,
-                                cloud_options.clone(),
-                                scan_source_idx,
-                            )
-                        })?;
+                let scan_source = sources.get(scan_source_idx).unwrap().into_owned()?;
+                let mut reader = file_reader_builder.build_file_reader(
+                    scan_source.clone(),
+                    cloud_options.clone(),
+                    scan_source_idx,
+                );
 
                 if verbose {
                     eprintln!("resolve_negative_slice(): init scan source {scan_source_idx}");
@@ -127,6 +121,7 @@ async fn resolve_negative_slice(config: &MultiFileReaderConfig) -> PolarsResult<
 
                 let row_deletions = deletion_files_provider.spawn_row_deletions_init(
                     scan_source_idx,
+                    &scan_source,
                     cloud_options,
                     num_pipelines,
                     verbose,
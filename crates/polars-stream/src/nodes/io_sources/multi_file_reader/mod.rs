@@ -16,6 +16,7 @@ use polars_io::cloud::CloudOptions;
 use polars_io::predicates::ScanIOPredicate;
 use polars_io::{RowIndex, pl_async};
 use polars_plan::dsl::deletion::DeletionFilesList;
+use polars_plan::dsl::file_statistics::FileStatistics;
 use polars_plan::dsl::{CastColumnsPolicy, MissingColumnsPolicy, ScanSources};
 use polars_plan::plans::hive::HivePartitionsDf;
 use polars_utils::format_pl_smallstr;
@@ -57,6 +58,8 @@ pub struct MultiFileReaderConfig {
     pub cast_columns_policy: CastColumnsPolicy,
     pub forbid_extra_columns: Option<ForbidExtraColumns>,
     pub deletion_files: Option<DeletionFilesList>,
+    /// Statistics of the sources, used to skip files with the predicate.
+    pub file_statistics: Option<FileStatistics>,
 
     pub num_pipelines: RelaxedCell<usize>,
     /// Number of readers to initialize concurrently. e.g. Parquet will want to fetch metadata in this
@@ -300,7 +303,8 @@ impl MultiScanState {
             .store(calc_max_concurrent_scans(num_pipelines, &config));
 
         let (join_handle, send_phase_tx_to_bridge, bridge_state) =
-            MultiScanTaskInitializer::new(config).spawn_background_tasks();
+            MultiScanTaskInitializer::new(config)
+                .spawn_background_tasks(execution_state.memory.clone());
 
         let wait_group = WaitGroup::default();
 
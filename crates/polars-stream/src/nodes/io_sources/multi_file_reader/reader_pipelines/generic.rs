@@ -251,6 +251,7 @@ impl MultiScanTaskInitializer {
                             .or_else(|| {
                                 deletion_files_provider.spawn_row_deletions_init(
                                     scan_source_idx,
+                                    &scan_source,
                                     cloud_options,
                                     num_pipelines,
                                     verbose,
@@ -1,6 +1,7 @@
 use polars_core::config;
 use polars_error::PolarsResult;
 use polars_io::prelude::json_lines;
+use polars_io::utils::compression::CompressedReader;
 use polars_utils::idx_mapper::IdxMapper;
 use polars_utils::mmap::MemSlice;
 
@@ -8,7 +9,10 @@ use super::line_batch_processor::LineBatch;
 use crate::async_primitives::distributor_channel;
 
 pub(super) struct LineBatchDistributor {
+    /// The bytes of the file, or the empty slice if the file is decompressed from `reader`.
     pub(super) global_bytes: MemSlice,
+    /// Reader to incrementally decompress the file from. Only used in the forward direction.
+    pub(super) reader: Option<CompressedReader>,
     pub(super) chunk_size: usize,
     pub(super) n_rows_to_skip: usize,
     pub(super) reverse: bool,
@@ -19,17 +23,17 @@ impl LineBatchDistributor {
     /// Returns the number of rows skipped (i.e. were not sent to LineBatchProcessors).
     pub(super) async fn run(self) -> PolarsResult<usize> {
         let LineBatchDistributor {
-            global_bytes: global_bytes_mem_slice,
+            global_bytes: mut global_bytes_mem_slice,
+            mut reader,
             chunk_size,
             n_rows_to_skip,
             reverse,
             mut line_batch_distribute_tx,
         } = self;
 
-        // Safety: All receivers (LineBatchProcessors) hold a MemSlice ref to this.
-        let global_bytes: &'static [u8] =
-            unsafe { std::mem::transmute(global_bytes_mem_slice.as_ref()) };
-        let n_chunks = global_bytes.len().div_ceil(chunk_size);
+        assert!(!(reverse && reader.is_some()));
+
+        let read_size = CompressedReader::DEFAULT_READ_SIZE;
         let verbose = config::verbose();
 
         if verbose {
@@ -40,92 +44,127 @@ impl LineBatchDistributor {
                 chunk_size: {}, \
                 n_chunks: {}, \
                 n_rows_to_skip: {}, \
-                reverse: {} \
+                reverse: {}, \
+                decompress_read_size: {:?} \
                 ",
-                global_bytes.len(),
+                global_bytes_mem_slice.len(),
                 chunk_size,
-                n_chunks,
+                global_bytes_mem_slice.len().div_ceil(chunk_size),
                 n_rows_to_skip,
-                reverse
+                reverse,
+                reader.is_some().then_some(read_size),
             )
         }
 
-        // The logic below processes in fixed chunks with remainder handling so that in the future
-        // we can handle receiving data in a batched manner.
-
-        let mut prev_remainder: &'static [u8] = &[];
-
-        let global_idx_map = IdxMapper::new(global_bytes.len(), reverse);
-
         let mut row_skipper = RowSkipper {
             remaining_rows_to_skip: n_rows_to_skip,
             reverse,
         };
 
-        for chunk_idx in 0..n_chunks {
-            let offset = chunk_idx.saturating_mul(chunk_size);
-            let range = offset..offset.saturating_add(chunk_size).min(global_bytes.len());
-            let range = global_idx_map.map_range(range);
+        // Offset of the chunk indices of the current slice.
+        let mut chunk_idx_offset: usize = 0;
+        // Whether `global_bytes_mem_slice` holds the end of the file.
+        let mut is_eof = reader.is_none();
 
-            let chunk = &global_bytes[range];
+        'read_slices: loop {
+            let global_bytes: &[u8] = global_bytes_mem_slice.as_ref();
+            let n_chunks = global_bytes.len().div_ceil(chunk_size);
 
-            // Split off the chunk occurring after the last newline char.
-            let chunk_remainder = if chunk_idx == n_chunks - 1 {
-                // Last chunk, send everything.
-                &[]
-            } else if reverse {
-                // Remainder is on the left because we are parsing lines in reverse:
-                // chunk:     ---\n---------
-                // remainder: ---
-                &chunk[..chunk.split(|&c| c == b'\n').next().unwrap().len()]
-            } else {
-                // chunk:     ---------\n---
-                // remainder:            ---
-                &chunk[chunk.len() - chunk.rsplit(|&c| c == b'\n').next().unwrap().len()..]
+            let to_mem_slice = |bytes: &[u8]| {
+                let offset = bytes.as_ptr() as usize - global_bytes.as_ptr() as usize;
+                global_bytes_mem_slice.slice(offset..offset + bytes.len())
             };
 
-            let n_chars_without_remainder = chunk.len() - chunk_remainder.len();
+            // The logic below processes in fixed chunks with remainder handling. For compressed
+            // files, the remainder of the last chunk is carried over to the next slice.
+
+            let mut prev_remainder: &[u8] = &[];
 
-            if n_chars_without_remainder > 0 {
-                let range = 0..n_chars_without_remainder;
-                let range = IdxMapper::new(chunk.len(), reverse).map_range(range);
+            let global_idx_map = IdxMapper::new(global_bytes.len(), reverse);
 
-                let full_chunk = &chunk[range];
+            for chunk_idx in 0..n_chunks {
+                let offset = chunk_idx.saturating_mul(chunk_size);
+                let range = offset..offset.saturating_add(chunk_size).min(global_bytes.len());
+                let range = global_idx_map.map_range(range);
 
-                let mut full_chunk = if prev_remainder.is_empty() {
-                    full_chunk
+                let chunk = &global_bytes[range];
+
+                // Split off the chunk occurring after the last newline char.
+                let chunk_remainder = if is_eof && chunk_idx == n_chunks - 1 {
+                    // Last chunk, send everything.
+                    &[]
                 } else if reverse {
-                    unsafe { merge_adjacent_non_empty_slices(full_chunk, prev_remainder) }
+                    // Remainder is on the left because we are parsing lines in reverse:
+                    // chunk:     ---\n---------
+                    // remainder: ---
+                    &chunk[..chunk.split(|&c| c == b'\n').next().unwrap().len()]
                 } else {
-                    unsafe { merge_adjacent_non_empty_slices(prev_remainder, full_chunk) }
+                    // chunk:     ---------\n---
+                    // remainder:            ---
+                    &chunk[chunk.len() - chunk.rsplit(|&c| c == b'\n').next().unwrap().len()..]
                 };
 
-                prev_remainder = &[];
-                row_skipper.skip_rows(&mut full_chunk);
-
-                if !full_chunk.is_empty()
-                    && line_batch_distribute_tx
-                        .send(LineBatch {
-                            bytes: full_chunk,
-                            chunk_idx,
-                        })
-                        .await
-                        .is_err()
-                {
-                    break;
+                let n_chars_without_remainder = chunk.len() - chunk_remainder.len();
+
+                if n_chars_without_remainder > 0 {
+                    let range = 0..n_chars_without_remainder;
+                    let range = IdxMapper::new(chunk.len(), reverse).map_range(range);
+
+                    let full_chunk = &chunk[range];
+
+                    let mut full_chunk = if prev_remainder.is_empty() {
+                        full_chunk
+                    } else if reverse {
+                        unsafe { merge_adjacent_non_empty_slices(full_chunk, prev_remainder) }
+                    } else {
+                        unsafe { merge_adjacent_non_empty_slices(prev_remainder, full_chunk) }
+                    };
+
+                    prev_remainder = &[];
+                    row_skipper.skip_rows(&mut full_chunk);
+
+                    if !full_chunk.is_empty()
+                        && line_batch_distribute_tx
+                            .send(LineBatch {
+                                mem_slice: to_mem_slice(full_chunk),
+                                chunk_idx: chunk_idx_offset + chunk_idx,
+                            })
+                            .await
+                            .is_err()
+                    {
+                        break 'read_slices;
+                    }
                 }
+
+                // Note: If `prev_remainder` is non-empty at this point, it means the entire current
+                // chunk does not contain a newline.
+                prev_remainder = if prev_remainder.is_empty() {
+                    chunk_remainder
+                } else if reverse {
+                    // Current chunk comes before the previous remainder in memory when reversed.
+                    unsafe { merge_adjacent_non_empty_slices(chunk_remainder, prev_remainder) }
+                } else {
+                    unsafe { merge_adjacent_non_empty_slices(prev_remainder, chunk_remainder) }
+                };
+            }
+
+            if is_eof {
+                break;
             }
 
-            // Note: If `prev_remainder` is non-empty at this point, it means the entire current
-            // chunk does not contain a newline.
-            prev_remainder = if prev_remainder.is_empty() {
-                chunk_remainder
-            } else if reverse {
-                // Current chunk comes before the previous remainder in memory when reversed.
-                unsafe { merge_adjacent_non_empty_slices(chunk_remainder, prev_remainder) }
+            let leftover = if prev_remainder.is_empty() {
+                MemSlice::default()
             } else {
-                unsafe { merge_adjacent_non_empty_slices(prev_remainder, chunk_remainder) }
+                to_mem_slice(prev_remainder)
             };
+            chunk_idx_offset += n_chunks;
+
+            let (next, n_read) = reader
+                .as_mut()
+                .unwrap()
+                .read_next_slice(&leftover, read_size)?;
+            global_bytes_mem_slice = next;
+            is_eof = n_read < read_size;
         }
 
         if verbose {
@@ -19,9 +19,6 @@ pub(super) struct LineBatchProcessor {
     /// Mainly for logging
     pub(super) worker_idx: usize,
 
-    /// We need to hold a ref to this as `LineBatch` we receive contains `&[u8]`
-    /// references to it.
-    pub(super) global_bytes: MemSlice,
     pub(super) chunk_reader: Arc<ChunkReader>,
 
     // Input
@@ -41,7 +38,6 @@ impl LineBatchProcessor {
     pub(super) async fn run(self) -> PolarsResult<usize> {
         let LineBatchProcessor {
             worker_idx,
-            global_bytes: _global_bytes,
             chunk_reader,
             mut line_batch_rx,
             mut output_port,
@@ -59,8 +55,12 @@ impl LineBatchProcessor {
 
         let mut n_rows_processed: usize = 0;
 
-        while let Ok(LineBatch { bytes, chunk_idx }) = line_batch_rx.recv().await {
-            let df = chunk_reader.read_chunk(bytes)?;
+        while let Ok(LineBatch {
+            mem_slice,
+            chunk_idx,
+        }) = line_batch_rx.recv().await
+        {
+            let df = chunk_reader.read_chunk(&mem_slice)?;
 
             n_rows_processed = n_rows_processed.saturating_add(df.height());
 
@@ -77,11 +77,11 @@ impl LineBatchProcessor {
             }
 
             while let Ok(LineBatch {
-                bytes,
+                mem_slice,
                 chunk_idx: _,
             }) = line_batch_rx.recv().await
             {
-                n_rows_processed = n_rows_processed.saturating_add(ndjson::count_rows(bytes));
+                n_rows_processed = n_rows_processed.saturating_add(ndjson::count_rows(&mem_slice));
             }
         }
 
@@ -95,8 +95,7 @@ impl LineBatchProcessor {
 
 /// Represents a complete chunk of NDJSON data (i.e. no partial lines).
 pub(super) struct LineBatch {
-    /// Safety: This is sent between 2 places that both hold a reference to the underlying MemSlice.
-    pub(super) bytes: &'static [u8],
+    pub(super) mem_slice: MemSlice,
     pub(super) chunk_idx: usize,
 }
 
@@ -12,7 +12,7 @@ use polars_core::schema::SchemaRef;
 use polars_error::{PolarsResult, polars_bail, polars_err};
 use polars_io::cloud::CloudOptions;
 use polars_io::prelude::estimate_n_lines_in_file;
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::CompressedReader;
 use polars_plan::dsl::{NDJsonReadOptions, ScanSource};
 use polars_utils::IdxSize;
 use polars_utils::mem::prefetch::get_memory_prefetch_func;
@@ -44,7 +44,8 @@ pub struct NDJsonFileReader {
     cloud_options: Option<Arc<CloudOptions>>,
     options: Arc<NDJsonReadOptions>,
     verbose: bool,
-    // Cached on first access - we may be called multiple times e.g. on negative slice.
+    // Cached on first access - we may be called multiple times e.g. on negative slice. This holds
+    // the (possibly compressed) bytes of the file.
     cached_bytes: Option<MemSlice>,
 }
 
@@ -80,10 +81,6 @@ impl FileReader for NDJsonFileReader {
             panic!("unsupported args: {:?}", &args)
         };
 
-        // TODO: This currently downloads and decompresses everything upfront in a blocking manner.
-        // Ideally we have a streaming download/decompression.
-        let global_bytes = self.get_bytes_maybe_decompress()?;
-
         // NDJSON: We just use the projected schema - the parser will automatically append NULL if
         // the field is not found.
         //
@@ -99,6 +96,26 @@ impl FileReader for NDJsonFileReader {
 
         let is_negative_slice = matches!(pre_slice, Some(Slice::Negative { .. }));
 
+        // TODO: This currently downloads everything upfront in a blocking manner.
+        // Ideally we have a streaming download.
+        let mut reader = CompressedReader::try_new(self.get_bytes()?)?;
+
+        // Compressed files are decompressed in slices by the line batch distributor. Negative
+        // slices are processed from the end of the file, so we decompress everything upfront.
+        let (global_bytes, opt_reader) = if reader.is_compressed() && !is_negative_slice {
+            (MemSlice::default(), Some(reader))
+        } else {
+            let (global_bytes, _) = reader.read_next_slice(&MemSlice::default(), usize::MAX)?;
+            (global_bytes, None)
+        };
+
+        // Number of bytes that are split into chunks at a time.
+        let n_bytes_per_split = if opt_reader.is_some() {
+            CompressedReader::DEFAULT_READ_SIZE
+        } else {
+            global_bytes.len()
+        };
+
         // Convert (offset, len) to Range
         // Note: This is converted to right-to-left for negative slice (i.e. range.start is position
         // from end).
@@ -134,8 +151,8 @@ impl FileReader for NDJsonFileReader {
 
         let chunk_size: usize = {
             let n_bytes_to_split = if let Some(x) = global_slice.as_ref() {
-                if needs_total_row_count {
-                    global_bytes.len()
+                if needs_total_row_count || opt_reader.is_some() {
+                    n_bytes_per_split
                 } else {
                     // There may be early stopping, try to heuristically use a smaller chunk size to stop faster.
                     let n_rows_to_sample = 8;
@@ -153,7 +170,7 @@ impl FileReader for NDJsonFileReader {
                     x.end.saturating_mul(line_length_estimate)
                 }
             } else {
-                global_bytes.len()
+                n_bytes_per_split
             };
 
             let chunk_size = n_bytes_to_split.div_ceil(16 * num_pipelines);
@@ -181,13 +198,15 @@ impl FileReader for NDJsonFileReader {
                 row_index: {:?}, \
                 chunk_size: {}, \
                 n_chunks: {}, \
-                is_negative_slice: {}",
+                is_negative_slice: {}, \
+                decompress_in_slices: {}",
                 schema.len(),
                 &global_slice,
                 &row_index,
                 chunk_size,
                 global_bytes.len().div_ceil(chunk_size),
                 is_negative_slice,
+                opt_reader.is_some(),
             );
         }
 
@@ -308,7 +327,6 @@ impl FileReader for NDJsonFileReader {
             .enumerate()
             .rev()
             .map(|(worker_idx, line_batch_rx)| {
-                let global_bytes = global_bytes.clone();
                 let chunk_reader = chunk_reader.clone();
                 // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
                 let source_token = SourceToken::new();
@@ -318,7 +336,6 @@ impl FileReader for NDJsonFileReader {
                     LineBatchProcessor {
                         worker_idx,
 
-                        global_bytes,
                         chunk_reader,
 
                         line_batch_rx,
@@ -346,6 +363,7 @@ impl FileReader for NDJsonFileReader {
             TaskPriority::Low,
             line_batch_distributor::LineBatchDistributor {
                 global_bytes,
+                reader: opt_reader,
                 chunk_size,
                 n_rows_to_skip,
                 reverse: is_negative_slice,
@@ -439,25 +457,14 @@ impl NDJsonFileReader {
         ChunkReader::try_new(&self.options, schema)
     }
 
-    fn get_bytes_maybe_decompress(&mut self) -> PolarsResult<MemSlice> {
+    fn get_bytes(&mut self) -> PolarsResult<MemSlice> {
         if self.cached_bytes.is_none() {
             let run_async = self.scan_source.run_async();
-            let source = self
+            let memslice = self
                 .scan_source
                 .as_scan_source_ref()
                 .to_memslice_async_assume_latest(run_async)?;
 
-            let memslice = {
-                let mut out = vec![];
-                maybe_decompress_bytes(&source, &mut out)?;
-
-                if out.is_empty() {
-                    source
-                } else {
-                    MemSlice::from_vec(out)
-                }
-            };
-
             self.cached_bytes = Some(memslice);
         }
 
@@ -0,0 +1,157 @@
+use std::ops::Range;
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_error::{PolarsResult, polars_err};
+use polars_io::predicates::ScanIOPredicate;
+use polars_io::prelude::FileMetadata;
+use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+use polars_parquet::parquet::bloom_filter::{is_in_set, read_from_bytes};
+
+use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
+
+/// A bloom filter that can rule out a row group.
+struct BloomFilterCheck {
+    /// Position of the row group in the row group slice.
+    row_group: usize,
+    /// Byte range of the filter in the file.
+    range: Range<usize>,
+    /// Hashes of the values the predicate on the column can be true for.
+    hashes: Vec<u64>,
+}
+
+/// The file and predicate to check the bloom filters of.
+pub(super) struct BloomFilterPruneContext<'a> {
+    pub row_group_slice: Range<usize>,
+    pub use_statistics: bool,
+    pub predicate: Option<&'a ScanIOPredicate>,
+    pub metadata: &'a FileMetadata,
+    pub projected_arrow_fields: &'a [ArrowFieldProjection],
+    pub byte_source: &'a DynByteSource,
+    pub verbose: bool,
+}
+
+/// Extends `skip_mask` with the row groups in which the bloom filter of a column rules out all
+/// values the predicate on that column can be true for, i.e. the literals of `==` or `is_in`.
+pub(super) async fn calculate_row_group_bloom_filter_skip_mask(
+    ctx: BloomFilterPruneContext<'_>,
+    skip_mask: Option<Bitmap>,
+) -> PolarsResult<Option<Bitmap>> {
+    let BloomFilterPruneContext {
+        row_group_slice,
+        use_statistics,
+        predicate,
+        metadata,
+        projected_arrow_fields,
+        byte_source,
+        verbose,
+    } = ctx;
+
+    if !use_statistics {
+        return Ok(skip_mask);
+    }
+
+    let Some(predicate) = predicate else {
+        return Ok(skip_mask);
+    };
+
+    // Casted or renamed columns are not encoded as the predicate expects.
+    let column_predicates = projected_arrow_fields
+        .iter()
+        .filter_map(|projection| {
+            let ArrowFieldProjection::Plain(field) = projection else {
+                return None;
+            };
+            let (_, specialized) = predicate.column_predicates.predicates.get(&field.name)?;
+            Some((&field.name, specialized.as_ref()?))
+        })
+        .collect::<Vec<_>>();
+
+    if column_predicates.is_empty() {
+        return Ok(skip_mask);
+    }
+
+    let row_groups = &metadata.row_groups[row_group_slice.clone()];
+    let mut checks = Vec::new();
+    for (i, rg) in row_groups.iter().enumerate() {
+        if skip_mask.as_ref().is_some_and(|m| m.get_bit(i)) {
+            continue;
+        }
+
+        for (name, specialized) in column_predicates.iter() {
+            // Bloom filters of nested columns can't tell which rows contain a value.
+            let Some(&[idx]) = rg.columns_idxs_under_root_iter(name) else {
+                continue;
+            };
+            let column = &rg.parquet_columns()[idx];
+            // The bloom filters of encrypted columns are encrypted as well.
+            if column.is_encrypted() {
+                continue;
+            }
+            let column_metadata = column.metadata();
+
+            // Older writers don't record the length, we don't bother guessing it.
+            let (Some(offset), Some(length)) = (
+                column_metadata.bloom_filter_offset,
+                column_metadata.bloom_filter_length,
+            ) else {
+                continue;
+            };
+            let Some(hashes) = specialized.bloom_filter_hashes(column.physical_type()) else {
+                continue;
+            };
+
+            let offset = offset as usize;
+            checks.push(BloomFilterCheck {
+                row_group: i,
+                range: offset..offset + length as usize,
+                hashes,
+            });
+        }
+    }
+
+    if checks.is_empty() {
+        return Ok(skip_mask);
+    }
+
+    // The byte source returns one buffer per start offset, so we fetch the
+    // longest range per offset and slice the filters from it.
+    let mut ranges = checks.iter().map(|c| c.range.clone()).collect::<Vec<_>>();
+    ranges.sort_unstable_by_key(|r| (r.start, std::cmp::Reverse(r.end)));
+    ranges.dedup_by_key(|r| r.start);
+    let bytes_map = byte_source.get_ranges(&mut ranges).await?;
+
+    let mut skip_mask = match skip_mask {
+        Some(mask) => mask.make_mut(),
+        None => MutableBitmap::from_len_zeroed(row_groups.len()),
+    };
+    let mut bitset = Vec::new();
+    for check in checks {
+        let bytes = bytes_map
+            .get(&check.range.start)
+            .filter(|bytes| bytes.len() >= check.range.len())
+            .ok_or_else(|| {
+                polars_err!(
+                    ComputeError: "failed to fetch the bloom filter at bytes {:?} of the parquet file",
+                    check.range
+                )
+            })?;
+        read_from_bytes(&bytes[..check.range.len()], &mut bitset)?;
+
+        // Unsupported filters are read as empty.
+        if !bitset.is_empty() && !check.hashes.iter().any(|h| is_in_set(&bitset, *h)) {
+            skip_mask.set(check.row_group, true);
+        }
+    }
+    let skip_mask: Bitmap = skip_mask.freeze();
+
+    if verbose {
+        eprintln!(
+            "[ParquetFileReader]: Bloom filter pushdown: \
+            reading {} / {} row groups",
+            skip_mask.unset_bits(),
+            row_groups.len(),
+        );
+    }
+
+    Ok(Some(skip_mask))
+}
@@ -6,6 +6,7 @@ use polars_io::prelude::_internal::PrefilterMaskSetting;
 use polars_io::prelude::ParallelStrategy;
 use polars_utils::IdxSize;
 
+use super::bloom_filter::{BloomFilterPruneContext, calculate_row_group_bloom_filter_skip_mask};
 use super::row_group_data_fetch::RowGroupDataFetcher;
 use super::row_group_decode::RowGroupDecoder;
 use super::{AsyncTaskData, ParquetReadImpl};
@@ -60,6 +61,7 @@ impl ParquetReadImpl {
             tokio::sync::mpsc::channel(row_group_prefetch_size);
 
         let row_index = self.row_index.clone();
+        let fetcher_row_index = self.row_index.clone();
 
         let prefetch_task = AbortOnDropHandle(io_runtime.spawn(async move {
             polars_ensure!(
@@ -116,6 +118,13 @@ impl ParquetReadImpl {
                 }
             }
 
+            // The encrypted columns we read are only decrypted now that we know which they are.
+            for rg in &metadata.row_groups[row_group_slice.clone()] {
+                for projection in projected_arrow_fields.iter() {
+                    rg.decrypt_columns_under_root(&projection.arrow_field().name)?;
+                }
+            }
+
             let row_group_mask = calculate_row_group_pred_pushdown_skip_mask(
                 row_group_slice.clone(),
                 use_statistics,
@@ -127,10 +136,26 @@ impl ParquetReadImpl {
             )
             .await?;
 
+            let row_group_mask = calculate_row_group_bloom_filter_skip_mask(
+                BloomFilterPruneContext {
+                    row_group_slice: row_group_slice.clone(),
+                    use_statistics,
+                    predicate: predicate.as_ref(),
+                    metadata: &metadata,
+                    projected_arrow_fields: &projected_arrow_fields,
+                    byte_source: &byte_source,
+                    verbose,
+                },
+                row_group_mask,
+            )
+            .await?;
+
             let mut row_group_data_fetcher = RowGroupDataFetcher {
                 projection: projected_arrow_fields.clone(),
                 is_full_projection,
                 predicate,
+                use_page_index: use_statistics,
+                row_index: fetcher_row_index,
                 slice_range,
                 memory_prefetch_func,
                 metadata,
@@ -138,6 +163,7 @@ impl ParquetReadImpl {
                 row_group_slice,
                 row_group_mask,
                 row_offset,
+                verbose,
             };
 
             while let Some(prefetch) = row_group_data_fetcher.next().await {
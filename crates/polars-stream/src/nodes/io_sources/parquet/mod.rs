@@ -30,9 +30,11 @@ use crate::nodes::io_sources::parquet::projection::{
 use crate::nodes::{TaskPriority, io_sources};
 use crate::utils::task_handles_ext;
 
+mod bloom_filter;
 pub mod builder;
 mod init;
 mod metadata_utils;
+mod page_index;
 mod projection;
 mod row_group_data_fetch;
 mod row_group_decode;
@@ -102,10 +104,14 @@ impl FileReader for ParquetFileReader {
                 byte_source = Arc::new(DynByteSource::MemSlice(MemSliceByteSource(full_bytes)));
             }
 
-            Arc::new(polars_parquet::parquet::read::deserialize_metadata(
-                metadata_bytes.as_ref(),
-                metadata_bytes.len() * 2 + 1024,
-            )?)
+            let decryption = self.config.decryption.as_ref().map(|d| d.to_properties());
+            Arc::new(
+                polars_parquet::parquet::read::deserialize_metadata_with_decryption(
+                    metadata_bytes.as_ref(),
+                    metadata_bytes.len() * 2 + 1024,
+                    decryption.as_ref(),
+                )?,
+            )
         };
 
         let file_schema = Arc::new(infer_schema_with_options(&file_metadata, &None)?);
@@ -0,0 +1,311 @@
+use std::ops::Range;
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_core::prelude::*;
+use polars_io::RowIndex;
+use polars_io::predicates::ScanIOPredicate;
+use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+use polars_parquet::parquet::read::{
+    OffsetIndex, deserialize_column_index, deserialize_offset_index,
+};
+use polars_parquet::read::RowGroupMetadata;
+use polars_parquet::read::statistics::deserialize_page_statistics;
+
+use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
+use crate::nodes::io_sources::parquet::statistics::StatisticsColumns;
+
+/// The rows of a row group that remain after skipping the pages which, according to the page
+/// index, cannot match the predicate.
+pub(super) struct PageSelection {
+    /// Mask of the rows of the row group that are read.
+    pub(super) rows: Bitmap,
+    /// The pages to read of the columns of which not all pages are needed, by the index of the
+    /// column in the row group. Other columns are read in full.
+    pub(super) columns: PlHashMap<usize, ColumnPageSelection>,
+}
+
+/// The pages of a column chunk that contain the selected rows.
+pub(super) struct ColumnPageSelection {
+    /// Byte ranges of the dictionary page, if any, and the selected data pages. Adjacent pages
+    /// share a range.
+    pub(super) byte_ranges: Vec<Range<usize>>,
+    /// [`PageSelection::rows`] restricted to the rows of the selected pages.
+    pub(super) rows: Bitmap,
+    /// The number of rows in the selected pages.
+    pub(super) num_values: usize,
+}
+
+/// Evaluates the skip batch predicate on the statistics of the pages of the columns in the
+/// predicate, to find the rows of the row group that can match it. Returns `None` if no pages can
+/// be skipped, e.g. because the file has no page index.
+///
+/// As the pages of different columns start at different rows, the row group is split at the
+/// start of every page of every column and each segment gets the statistics of the pages it lies
+/// in.
+pub(super) async fn select_row_group_pages(
+    row_group: &RowGroupMetadata,
+    predicate: &ScanIOPredicate,
+    projection: &[ArrowFieldProjection],
+    // The offset is that of the first row of the row group.
+    row_index: Option<RowIndex>,
+    byte_source: &DynByteSource,
+) -> PolarsResult<Option<PageSelection>> {
+    let Some(sbp) = predicate.skip_batch_predicate.as_ref() else {
+        return Ok(None);
+    };
+
+    let num_rows = row_group.num_rows();
+
+    // Only the pages of non-nested columns are guaranteed to start and end at the same rows for
+    // every value.
+    let flat_column_idx = |projection: &ArrowFieldProjection| {
+        let arrow_field = projection.arrow_field();
+        if arrow_field.dtype().is_nested() {
+            return None;
+        }
+        match row_group.columns_idxs_under_root_iter(&arrow_field.name)? {
+            // The page indexes of encrypted columns are encrypted as well.
+            &[idx] if !row_group.parquet_columns()[idx].is_encrypted() => Some(idx),
+            _ => None,
+        }
+    };
+
+    let indexed_live_columns = projection
+        .iter()
+        .filter(|p| predicate.live_columns.contains(p.output_name()))
+        .filter_map(|p| {
+            let idx = flat_column_idx(p)?;
+            let column = &row_group.parquet_columns()[idx];
+            column.column_index_byte_range()?;
+            column.offset_index_byte_range()?;
+            Some(idx)
+        })
+        .collect::<PlHashSet<_>>();
+
+    if indexed_live_columns.is_empty() {
+        return Ok(None);
+    }
+
+    let offset_indexed_columns = projection
+        .iter()
+        .filter_map(|p| {
+            let idx = flat_column_idx(p)?;
+            row_group.parquet_columns()[idx].offset_index_byte_range()?;
+            Some(idx)
+        })
+        .collect::<PlHashSet<_>>();
+
+    let to_range = |r: Range<u64>| r.start as usize..r.end as usize;
+    let column_index_range = |idx: usize| {
+        to_range(
+            row_group.parquet_columns()[idx]
+                .column_index_byte_range()
+                .unwrap(),
+        )
+    };
+    let offset_index_range = |idx: usize| {
+        to_range(
+            row_group.parquet_columns()[idx]
+                .offset_index_byte_range()
+                .unwrap(),
+        )
+    };
+
+    let mut ranges = indexed_live_columns
+        .iter()
+        .map(|&idx| column_index_range(idx))
+        .chain(
+            offset_indexed_columns
+                .iter()
+                .map(|&idx| offset_index_range(idx)),
+        )
+        .collect::<Vec<_>>();
+    let bytes_map = byte_source.get_ranges(&mut ranges).await?;
+
+    let mut page_starts = PlHashMap::with_capacity(offset_indexed_columns.len());
+    for &idx in offset_indexed_columns.iter() {
+        let offset_index = deserialize_offset_index(&bytes_map[&offset_index_range(idx).start])?;
+        if let Some(starts) = get_page_starts(&offset_index, num_rows) {
+            page_starts.insert(idx, (offset_index, starts));
+        }
+    }
+
+    let mut boundaries = indexed_live_columns
+        .iter()
+        .filter_map(|idx| page_starts.get(idx))
+        .flat_map(|(_, starts)| starts.iter().copied())
+        .chain([0, num_rows])
+        .collect::<Vec<_>>();
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    let segments = boundaries
+        .windows(2)
+        .map(|w| w[0]..w[1])
+        .collect::<Vec<_>>();
+    if segments.len() <= 1 {
+        return Ok(None);
+    }
+
+    let mut columns = Vec::with_capacity(1 + predicate.live_columns.len() * 3);
+
+    let lengths: Vec<IdxSize> = segments.iter().map(|s| s.len() as IdxSize).collect();
+    columns.push(Column::new("len".into(), lengths));
+
+    for p in projection.iter() {
+        let c = p.output_name();
+
+        if !predicate.live_columns.contains(c) {
+            continue;
+        }
+
+        let arrow_field = p.arrow_field();
+        let mut statistics = 'statistics: {
+            if let Some(idx) = flat_column_idx(p)
+                && indexed_live_columns.contains(&idx)
+                && let Some((_, starts)) = page_starts.get(&idx)
+            {
+                let column = &row_group.parquet_columns()[idx];
+                let column_index =
+                    deserialize_column_index(&bytes_map[&column_index_range(idx).start])?;
+
+                if column_index.null_pages.len() == starts.len()
+                    && let Some(page_statistics) =
+                        deserialize_page_statistics(arrow_field, column, &column_index)?
+                {
+                    let page_statistics =
+                        StatisticsColumns::from_arrow_statistics(page_statistics, arrow_field)?;
+
+                    // The page each segment lies in.
+                    let page_idxs = segments
+                        .iter()
+                        .map(|s| (starts.partition_point(|&start| start <= s.start) - 1) as IdxSize)
+                        .collect::<Vec<_>>();
+
+                    break 'statistics StatisticsColumns {
+                        min: page_statistics.min.take_slice(&page_idxs)?,
+                        max: page_statistics.max.take_slice(&page_idxs)?,
+                        null_count: page_statistics.null_count.take_slice(&page_idxs)?,
+                    };
+                }
+            }
+
+            StatisticsColumns::new_null(&DataType::from_arrow_field(arrow_field), segments.len())
+        };
+
+        // Note: Order is important here. We re-use the transform for the output column, meaning
+        // that it may set the column name.
+        statistics.min = p.apply_transform(statistics.min)?;
+        statistics.max = p.apply_transform(statistics.max)?;
+
+        let statistics = statistics.with_base_column_name(c);
+
+        columns.extend([statistics.min, statistics.max, statistics.null_count]);
+    }
+
+    if let Some(row_index) = row_index {
+        let offset = |i: usize| {
+            row_index
+                .offset
+                .saturating_add(IdxSize::try_from(i).unwrap_or(IdxSize::MAX))
+        };
+        let statistics = StatisticsColumns {
+            min: Column::new(
+                PlSmallStr::EMPTY,
+                segments.iter().map(|s| offset(s.start)).collect::<Vec<_>>(),
+            ),
+            max: Column::new(
+                PlSmallStr::EMPTY,
+                segments
+                    .iter()
+                    .map(|s| offset(s.end - 1))
+                    .collect::<Vec<_>>(),
+            ),
+            null_count: Column::new(PlSmallStr::EMPTY, vec![0 as IdxSize; segments.len()]),
+        }
+        .with_base_column_name(&row_index.name);
+
+        columns.extend([statistics.min, statistics.max, statistics.null_count]);
+    }
+
+    let statistics_df = DataFrame::new_with_height(segments.len(), columns)?;
+    let skip_segment_mask = sbp.evaluate_with_stat_df(&statistics_df)?;
+
+    if skip_segment_mask.set_bits() == 0 {
+        return Ok(None);
+    }
+
+    let mut rows = MutableBitmap::with_capacity(num_rows);
+    for (segment, skip) in segments.iter().zip(skip_segment_mask.iter()) {
+        rows.extend_constant(segment.len(), !skip);
+    }
+    let rows = rows.freeze();
+
+    let columns = page_starts
+        .into_iter()
+        .filter_map(|(idx, (offset_index, starts))| {
+            let column_start = row_group.parquet_columns()[idx].byte_range().start as usize;
+            select_column_pages(&offset_index, &starts, column_start, &rows)
+                .map(|pages| (idx, pages))
+        })
+        .collect();
+
+    Ok(Some(PageSelection { rows, columns }))
+}
+
+/// Returns the first row of every page, or `None` if the offset index doesn't fit the row group.
+fn get_page_starts(offset_index: &OffsetIndex, num_rows: usize) -> Option<Vec<usize>> {
+    let starts = offset_index
+        .page_locations
+        .iter()
+        .map(|l| usize::try_from(l.first_row_index).ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    (!starts.is_empty() && starts.last().is_some_and(|&s| s < num_rows)).then_some(starts)
+}
+
+/// Returns the pages of a column that contain any of `rows`, or `None` if that is all of them.
+fn select_column_pages(
+    offset_index: &OffsetIndex,
+    starts: &[usize],
+    column_start: usize,
+    rows: &Bitmap,
+) -> Option<ColumnPageSelection> {
+    let locations = &offset_index.page_locations;
+
+    let mut byte_ranges = Vec::new();
+    let mut column_rows = MutableBitmap::new();
+    let mut num_values = 0;
+    let mut num_selected_pages = 0;
+
+    // The dictionary page precedes the data pages.
+    let first_page_start = locations[0].offset as usize;
+    if first_page_start > column_start {
+        byte_ranges.push(column_start..first_page_start);
+    }
+
+    for (i, location) in locations.iter().enumerate() {
+        let page_end = starts.get(i + 1).copied().unwrap_or(rows.len());
+        let page_rows = rows.clone().sliced(starts[i], page_end - starts[i]);
+        if page_rows.set_bits() == 0 {
+            continue;
+        }
+
+        num_selected_pages += 1;
+        num_values += page_rows.len();
+        column_rows.extend_from_bitmap(&page_rows);
+
+        let start = location.offset as usize;
+        let end = start + location.compressed_page_size as usize;
+        match byte_ranges.last_mut() {
+            Some(range) if range.end == start => range.end = end,
+            _ => byte_ranges.push(start..end),
+        }
+    }
+
+    (num_selected_pages < locations.len()).then(|| ColumnPageSelection {
+        byte_ranges,
+        rows: column_rows.freeze(),
+        num_values,
+    })
+}
@@ -5,13 +5,16 @@ use polars_core::prelude::PlHashMap;
 use polars_core::series::IsSorted;
 use polars_core::utils::arrow::bitmap::Bitmap;
 use polars_error::PolarsResult;
+use polars_io::RowIndex;
 use polars_io::predicates::ScanIOPredicate;
 use polars_io::prelude::{FileMetadata, create_sorting_map};
 use polars_io::utils::byte_source::{ByteSource, DynByteSource};
 use polars_parquet::read::RowGroupMetadata;
+use polars_utils::IdxSize;
 use polars_utils::mmap::MemSlice;
 use polars_utils::pl_str::PlSmallStr;
 
+use crate::nodes::io_sources::parquet::page_index::{PageSelection, select_row_group_pages};
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
 use crate::utils::task_handles_ext;
 
@@ -22,13 +25,17 @@ pub(super) struct RowGroupData {
     pub(super) slice: Option<(usize, usize)>,
     pub(super) row_group_metadata: RowGroupMetadata,
     pub(super) sorting_map: Vec<(usize, IsSorted)>,
+    /// Set if pages were skipped using the page index, only the selected rows are decoded.
+    pub(super) page_selection: Option<PageSelection>,
 }
 
 pub(super) struct RowGroupDataFetcher {
     pub(super) projection: Arc<[ArrowFieldProjection]>,
     pub(super) is_full_projection: bool,
-    #[allow(unused)] // TODO: Fix!
     pub(super) predicate: Option<ScanIOPredicate>,
+    /// Whether to skip pages using the page index.
+    pub(super) use_page_index: bool,
+    pub(super) row_index: Option<RowIndex>,
     pub(super) slice_range: Option<Range<usize>>,
     pub(super) memory_prefetch_func: fn(&[u8]) -> (),
     pub(super) metadata: Arc<FileMetadata>,
@@ -38,6 +45,7 @@ pub(super) struct RowGroupDataFetcher {
     pub(super) row_group_mask: Option<Bitmap>,
 
     pub(super) row_offset: usize,
+    pub(super) verbose: bool,
 }
 
 impl RowGroupDataFetcher {
@@ -77,6 +85,19 @@ impl RowGroupDataFetcher {
                 }
             }
 
+            // Pages can't be skipped in a slice, as it is taken before the predicate is applied.
+            let page_index_predicate = self
+                .predicate
+                .clone()
+                .filter(|_| self.use_page_index && slice.is_none());
+            let row_index = self.row_index.clone().map(|mut ri| {
+                ri.offset = ri
+                    .offset
+                    .saturating_add(IdxSize::try_from(current_row_offset).unwrap_or(IdxSize::MAX));
+                ri
+            });
+            let verbose = self.verbose;
+
             let metadata = self.metadata.clone();
             let current_byte_source = self.byte_source.clone();
             let projection = self.projection.clone();
@@ -86,70 +107,101 @@ impl RowGroupDataFetcher {
 
             let handle = io_runtime.spawn(async move {
                 let row_group_metadata = &metadata.row_groups[idx];
-                let fetched_bytes =
-                    if let DynByteSource::MemSlice(mem_slice) = current_byte_source.as_ref() {
-                        // Skip byte range calculation for `no_prefetch`.
-                        if memory_prefetch_func as usize
-                            != polars_utils::mem::prefetch::no_prefetch as usize
-                        {
-                            let slice = mem_slice.0.as_ref();
-
-                            if !is_full_projection {
-                                for range in get_row_group_byte_ranges_for_projection(
-                                    row_group_metadata,
-                                    &mut projection.iter().map(|x| &x.arrow_field().name),
-                                ) {
-                                    memory_prefetch_func(unsafe { slice.get_unchecked(range) })
-                                }
-                            } else {
-                                let range = row_group_metadata.full_byte_range();
-                                let range = range.start as usize..range.end as usize;
 
-                                memory_prefetch_func(unsafe { slice.get_unchecked(range) })
-                            };
-                        }
-
-                        // We have a mmapped or in-memory slice representing the entire
-                        // file that can be sliced directly, so we can skip the byte-range
-                        // calculations and HashMap allocation.
-                        let mem_slice = mem_slice.0.clone();
-                        FetchedBytes::MemSlice {
-                            offset: 0,
-                            mem_slice,
-                        }
-                    } else if !is_full_projection {
-                        let mut ranges = get_row_group_byte_ranges_for_projection(
+                let page_selection = match &page_index_predicate {
+                    Some(predicate) => {
+                        select_row_group_pages(
                             row_group_metadata,
-                            &mut projection.iter().map(|x| &x.arrow_field().name),
+                            predicate,
+                            &projection,
+                            row_index,
+                            &current_byte_source,
                         )
-                        .collect::<Vec<_>>();
-
-                        let n_ranges = ranges.len();
-
-                        let bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
-
-                        assert_eq!(bytes_map.len(), n_ranges);
+                        .await?
+                    },
+                    None => None,
+                };
+
+                if verbose && let Some(page_selection) = &page_selection {
+                    eprintln!(
+                        "[ParquetFileReader]: Page index pushdown: \
+                        reading {} / {} rows of row group {}",
+                        page_selection.rows.set_bits(),
+                        row_group_metadata.num_rows(),
+                        idx,
+                    );
+                }
 
-                        FetchedBytes::BytesMap(bytes_map)
-                    } else {
-                        // We still prefer `get_ranges()` over a single `get_range()` for downloading
-                        // the entire row group, as it can have less memory-copying. A single `get_range()`
-                        // would naively concatenate the memory blocks of the entire row group, while
-                        // `get_ranges()` can skip concatenation since the downloaded blocks are
-                        // aligned to the columns.
-                        let mut ranges = row_group_metadata
-                            .byte_ranges_iter()
-                            .map(|x| x.start as usize..x.end as usize)
-                            .collect::<Vec<_>>();
+                let fetched_bytes = if page_selection
+                    .as_ref()
+                    .is_some_and(|s| s.rows.set_bits() == 0)
+                {
+                    // All pages were skipped.
+                    FetchedBytes::BytesMap(PlHashMap::default())
+                } else if let DynByteSource::MemSlice(mem_slice) = current_byte_source.as_ref() {
+                    // Skip byte range calculation for `no_prefetch`.
+                    if memory_prefetch_func as usize
+                        != polars_utils::mem::prefetch::no_prefetch as usize
+                    {
+                        let slice = mem_slice.0.as_ref();
+
+                        if !is_full_projection || page_selection.is_some() {
+                            for range in get_row_group_byte_ranges_for_projection(
+                                row_group_metadata,
+                                &mut projection.iter().map(|x| &x.arrow_field().name),
+                                page_selection.as_ref(),
+                            ) {
+                                memory_prefetch_func(unsafe { slice.get_unchecked(range) })
+                            }
+                        } else {
+                            let range = row_group_metadata.full_byte_range();
+                            let range = range.start as usize..range.end as usize;
+
+                            memory_prefetch_func(unsafe { slice.get_unchecked(range) })
+                        };
+                    }
+
+                    // We have a mmapped or in-memory slice representing the entire
+                    // file that can be sliced directly, so we can skip the byte-range
+                    // calculations and HashMap allocation.
+                    let mem_slice = mem_slice.0.clone();
+                    FetchedBytes::MemSlice {
+                        offset: 0,
+                        mem_slice,
+                    }
+                } else if !is_full_projection || page_selection.is_some() {
+                    let mut ranges = get_row_group_byte_ranges_for_projection(
+                        row_group_metadata,
+                        &mut projection.iter().map(|x| &x.arrow_field().name),
+                        page_selection.as_ref(),
+                    );
+
+                    let n_ranges = ranges.len();
+
+                    let bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
+
+                    assert_eq!(bytes_map.len(), n_ranges);
+
+                    FetchedBytes::BytesMap(bytes_map)
+                } else {
+                    // We still prefer `get_ranges()` over a single `get_range()` for downloading
+                    // the entire row group, as it can have less memory-copying. A single `get_range()`
+                    // would naively concatenate the memory blocks of the entire row group, while
+                    // `get_ranges()` can skip concatenation since the downloaded blocks are
+                    // aligned to the columns.
+                    let mut ranges = row_group_metadata
+                        .byte_ranges_iter()
+                        .map(|x| x.start as usize..x.end as usize)
+                        .collect::<Vec<_>>();
 
-                        let n_ranges = ranges.len();
+                    let n_ranges = ranges.len();
 
-                        let bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
+                    let bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
 
-                        assert_eq!(bytes_map.len(), n_ranges);
+                    assert_eq!(bytes_map.len(), n_ranges);
 
-                        FetchedBytes::BytesMap(bytes_map)
-                    };
+                    FetchedBytes::BytesMap(bytes_map)
+                };
 
                 PolarsResult::Ok(RowGroupData {
                     fetched_bytes,
@@ -158,6 +210,7 @@ impl RowGroupDataFetcher {
                     // @TODO: Remove clone
                     row_group_metadata: row_group_metadata.clone(),
                     sorting_map,
+                    page_selection,
                 })
             });
 
@@ -191,20 +244,31 @@ impl FetchedBytes {
     }
 }
 
-fn get_row_group_byte_ranges_for_projection<'a>(
-    row_group_metadata: &'a RowGroupMetadata,
-    columns: &'a mut dyn Iterator<Item = &PlSmallStr>,
-) -> impl Iterator<Item = std::ops::Range<usize>> + 'a {
-    columns.flat_map(|col_name| {
-        row_group_metadata
-            .columns_under_root_iter(col_name)
-            // `Option::into_iter` so that we return an empty iterator for the
-            // `allow_missing_columns` case
+/// Returns the byte ranges of the columns in the projection, or only those of the selected pages
+/// if pages were skipped using the page index.
+fn get_row_group_byte_ranges_for_projection(
+    row_group_metadata: &RowGroupMetadata,
+    columns: &mut dyn Iterator<Item = &PlSmallStr>,
+    page_selection: Option<&PageSelection>,
+) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+
+    for col_name in columns {
+        // `Option::into_iter` so that we return an empty iterator for the
+        // `allow_missing_columns` case
+        for &idx in row_group_metadata
+            .columns_idxs_under_root_iter(col_name)
             .into_iter()
             .flatten()
-            .map(|col| {
-                let byte_range = col.byte_range();
-                byte_range.start as usize..byte_range.end as usize
-            })
-    })
+        {
+            if let Some(pages) = page_selection.and_then(|s| s.columns.get(&idx)) {
+                ranges.extend(pages.byte_ranges.iter().cloned());
+            } else {
+                let byte_range = row_group_metadata.parquet_columns()[idx].byte_range();
+                ranges.push(byte_range.start as usize..byte_range.end as usize);
+            }
+        }
+    }
+
+    ranges
 }
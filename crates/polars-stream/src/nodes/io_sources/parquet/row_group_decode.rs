@@ -15,6 +15,7 @@ use polars_io::prelude::_internal::calc_prefilter_cost;
 use polars_io::prelude::try_set_sorted_flag;
 use polars_parquet::read::{Filter, ParquetType, PredicateFilter, PrimitiveLogicalType};
 use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
 use polars_utils::pl_str::PlSmallStr;
 
 use super::row_group_data_fetch::RowGroupData;
@@ -47,8 +48,17 @@ impl RowGroupDecoder {
             slice.0 == 0 && slice.1 >= row_group_data.row_group_metadata.num_rows()
         });
 
+        if row_group_data
+            .page_selection
+            .as_ref()
+            .is_some_and(|s| s.rows.set_bits() == 0)
+        {
+            return Ok(DataFrame::empty());
+        }
+
         if self.use_prefiltered.is_some()
             && row_group_data.slice.is_none()
+            && row_group_data.page_selection.is_none()
             && !self.predicate_field_indices.is_empty()
         {
             self.row_group_data_to_df_prefiltered(row_group_data).await
@@ -74,19 +84,27 @@ impl RowGroupDecoder {
 
         assert!(slice_range.end <= row_group_data.row_group_metadata.num_rows());
 
+        // Only the rows of the pages selected using the page index are decoded.
+        let filter = match &row_group_data.page_selection {
+            Some(page_selection) => Filter::Mask(page_selection.rows.clone()),
+            None => Filter::Range(slice_range.clone()),
+        };
+        let projection_height = filter.num_rows(row_group_data.row_group_metadata.num_rows());
+
         if let Some(s) = self.materialize_row_index(row_group_data.as_ref(), slice_range.clone())? {
+            let s = match &filter {
+                Filter::Mask(mask) => s.filter(&BooleanChunked::from_bitmap(
+                    PlSmallStr::EMPTY,
+                    mask.clone(),
+                ))?,
+                _ => s,
+            };
             out_columns.push(s);
         }
 
         let mut decoded_cols = Vec::with_capacity(row_group_data.row_group_metadata.n_columns());
-        self.decode_projected_columns(
-            &mut decoded_cols,
-            &row_group_data,
-            Some(polars_parquet::read::Filter::Range(slice_range.clone())),
-        )
-        .await?;
-
-        let projection_height = slice_range.len();
+        self.decode_projected_columns(&mut decoded_cols, &row_group_data, Some(filter))
+            .await?;
 
         out_columns.extend(decoded_cols);
 
@@ -270,6 +288,43 @@ fn decode_column(
         ));
     };
 
+    // Only some pages of the column were fetched.
+    if let Some(&[idx]) = row_group_data
+        .row_group_metadata
+        .columns_idxs_under_root_iter(&arrow_field.name)
+        && let Some(pages) = row_group_data
+            .page_selection
+            .as_ref()
+            .and_then(|s| s.columns.get(&idx))
+    {
+        let fetched =
+            |range: &core::ops::Range<usize>| row_group_data.fetched_bytes.get_range(range.clone());
+        let bytes = if let [range] = pages.byte_ranges.as_slice() {
+            fetched(range)
+        } else {
+            let len = pages.byte_ranges.iter().map(|r| r.len()).sum();
+            let mut buf = Vec::with_capacity(len);
+            for range in &pages.byte_ranges {
+                buf.extend_from_slice(&fetched(range));
+            }
+            MemSlice::from_vec(buf)
+        };
+
+        let (array, pred_true_mask) = polars_io::prelude::_internal::pages_to_deserializer(
+            &row_group_data.row_group_metadata.parquet_columns()[idx],
+            bytes,
+            pages.num_values,
+            arrow_field.clone(),
+            Some(Filter::Mask(pages.rows.clone())),
+        )?;
+        assert_eq!(array.len(), expected_num_rows);
+
+        let mut series = Series::try_from((arrow_field, array))?;
+        try_set_sorted_flag(&mut series, idx, &row_group_data.sorting_map);
+
+        return Ok((series.into_column(), pred_true_mask));
+    }
+
     let columns_to_deserialize = iter
         .map(|col_md| {
             let byte_range = col_md.byte_range();
@@ -0,0 +1,537 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_expr::groups::Grouper;
+use polars_expr::hash_keys::HashKeys;
+use polars_ops::series::SeriesMethods;
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::async_primitives::connector::Receiver;
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::memory::MemoryReservation;
+use crate::morsel::SourceToken;
+use crate::nodes::compute_node_prelude::*;
+
+pub type AsOfJoiner = Arc<dyn Fn(DataFrame, DataFrame) -> PolarsResult<DataFrame> + Send + Sync>;
+
+pub struct AsOfJoinParams {
+    pub left_key: PlSmallStr,
+    pub right_key: PlSmallStr,
+    pub check_sortedness: bool,
+}
+
+/// The 'by' columns of an as-of join, with a grouper for their values.
+pub struct AsOfJoinByParams {
+    pub left_by: Vec<PlSmallStr>,
+    pub right_by: Vec<PlSmallStr>,
+    pub grouper: Box<dyn Grouper>,
+}
+
+/// Joins two inputs sorted by their key as-of, without materializing either.
+///
+/// Left rows are buffered until the right input has progressed beyond their
+/// key, at which point all right rows they can match have been seen. Such a
+/// left chunk is then joined against the window of right rows we still keep,
+/// using the in-memory engine. After that, right rows that no later left row
+/// can match anymore are dropped from the window.
+///
+/// With 'by' groups the inputs only need to be sorted within each group, so we
+/// track the progress of both inputs per group instead.
+pub struct AsOfJoinNode {
+    buffers: AsOfJoinBuffers,
+    joiner: AsOfJoiner,
+    seq: MorselSeq,
+}
+
+impl AsOfJoinNode {
+    /// Creates a new as-of join node, the joiner does the actual join of a
+    /// left chunk and right window.
+    pub fn new(
+        params: AsOfJoinParams,
+        by_params: Option<AsOfJoinByParams>,
+        left_input_schema: &Schema,
+        right_input_schema: Arc<Schema>,
+        joiner: AsOfJoiner,
+    ) -> Self {
+        let by = by_params.map(|by_params| {
+            let empty_key = |schema: &Schema, key: &PlSmallStr| {
+                let dtype = schema.get(key).unwrap().to_physical();
+                Series::new_empty(key.clone(), &dtype)
+            };
+            ByGroups {
+                right_last_key: empty_key(&right_input_schema, &params.right_key),
+                left_last_key: empty_key(left_input_schema, &params.left_key),
+                params: by_params,
+                random_state: PlRandomState::default(),
+                right_window_groups: Vec::new(),
+            }
+        });
+        Self {
+            buffers: AsOfJoinBuffers {
+                params,
+                by,
+                left_unjoined: VecDeque::new(),
+                right_window: DataFrame::empty_with_schema(&right_input_schema),
+                left_last_key: None,
+                right_last_key: None,
+                right_done: false,
+                buffered_bytes: 0,
+                reservation: None,
+            },
+            joiner,
+            seq: MorselSeq::default(),
+        }
+    }
+}
+
+struct AsOfJoinBuffers {
+    params: AsOfJoinParams,
+    by: Option<ByGroups>,
+
+    // Left rows which can not be joined yet.
+    left_unjoined: VecDeque<DataFrame>,
+
+    // Right rows which can still be matched by future left rows.
+    right_window: DataFrame,
+
+    // The last non-null (physical) key we have seen on each side.
+    left_last_key: Option<Series>,
+    right_last_key: Option<Series>,
+
+    right_done: bool,
+
+    // The estimated size of the buffered left and right rows, which is
+    // accounted against the query's memory budget.
+    buffered_bytes: usize,
+    reservation: Option<MemoryReservation>,
+}
+
+/// Returns the number of leading values in key which are smaller than bound,
+/// counting nulls as smaller.
+fn num_leading_lt(key: &Series, bound: &Series) -> PolarsResult<usize> {
+    let mask = key.lt(bound)?.fill_null_with_values(true)?;
+    Ok(mask.rechunk().downcast_as_array().values().leading_ones())
+}
+
+/// The progress of both inputs per 'by' group.
+struct ByGroups {
+    params: AsOfJoinByParams,
+    random_state: PlRandomState,
+
+    // For each group the last non-null (physical) key we have seen on the
+    // right, and the last one we have joined on the left, null if none.
+    right_last_key: Series,
+    left_last_key: Series,
+
+    // The group of each row in the right window.
+    right_window_groups: Vec<IdxSize>,
+}
+
+impl ByGroups {
+    /// Returns the group of each row of df, a left or right chunk.
+    fn group_idxs(&mut self, df: &DataFrame, is_left: bool) -> PolarsResult<Vec<IdxSize>> {
+        let by = if is_left {
+            &self.params.left_by
+        } else {
+            &self.params.right_by
+        };
+        let keys = df.select(by.iter().cloned())?;
+        let hash_keys = HashKeys::from_df(&keys, self.random_state, true, false);
+        let subset = (0..df.height() as IdxSize).collect::<Vec<_>>();
+        let mut group_idxs = Vec::with_capacity(df.height());
+        unsafe {
+            self.params
+                .grouper
+                .insert_keys_subset(&hash_keys, &subset, Some(&mut group_idxs));
+        }
+
+        // Make room for the new groups.
+        let num_groups = self.params.grouper.num_groups() as usize;
+        for last_key in [&mut self.right_last_key, &mut self.left_last_key] {
+            let num_new = num_groups - last_key.len();
+            if num_new > 0 {
+                let nulls = Series::full_null(last_key.name().clone(), num_new, last_key.dtype());
+                last_key.append_owned(nulls)?;
+            }
+        }
+        Ok(group_idxs)
+    }
+
+    /// Sets the last key of every group to the last non-null key of that group
+    /// in key, optionally checking the keys are sorted within each group.
+    fn update_last_key(
+        last_key: &mut Series,
+        key: &Series,
+        group_idxs: &[IdxSize],
+        check_sortedness: bool,
+    ) -> PolarsResult<()> {
+        let num_groups = last_key.len();
+        let mut take_idxs = (0..num_groups as IdxSize).collect::<Vec<_>>();
+        // The previous non-null key in the same group of every row.
+        let mut prev_idxs = Vec::with_capacity(if check_sortedness { key.len() } else { 0 });
+        let is_valid = key.is_not_null();
+        for (i, (g, valid)) in group_idxs.iter().zip(is_valid.into_iter()).enumerate() {
+            if check_sortedness {
+                prev_idxs.push(take_idxs[*g as usize]);
+            }
+            if valid == Some(true) {
+                take_idxs[*g as usize] = (num_groups + i) as IdxSize;
+            }
+        }
+        let mut all = last_key.clone();
+        all.append(key)?;
+        if check_sortedness {
+            let prev = all.take_slice(&prev_idxs)?;
+            polars_ensure!(
+                !key.lt(&prev)?.any(),
+                InvalidOperation: "argument in operation 'asof_join' is not sorted within its 'by' groups, please sort the 'expr/series/column' first"
+            );
+        }
+        *last_key = all.take_slice(&take_idxs)?;
+        Ok(())
+    }
+}
+
+impl AsOfJoinBuffers {
+    fn physical_key(df: &DataFrame, name: &str) -> Series {
+        df.column(name)
+            .unwrap()
+            .as_materialized_series()
+            .to_physical_repr()
+            .into_owned()
+    }
+
+    /// Updates the last key seen on a side, checking the input is sorted.
+    fn update_last_key(&self, key: &Series, last_key: &mut Option<Series>) -> PolarsResult<()> {
+        let non_null = key.drop_nulls();
+        if self.params.check_sortedness {
+            let mut is_sorted = key.is_sorted(SortOptions::default())?;
+            if let (Some(last_key), false) = (last_key.as_ref(), non_null.is_empty()) {
+                is_sorted &= !non_null.head(Some(1)).lt(last_key)?.any();
+            }
+            polars_ensure!(
+                is_sorted,
+                InvalidOperation: "argument in operation 'asof_join' is not sorted, please sort the 'expr/series/column' first"
+            );
+        }
+        if !non_null.is_empty() {
+            *last_key = Some(non_null.tail(Some(1)));
+        }
+        Ok(())
+    }
+
+    /// Updates the memory accounted for to the rows we currently buffer.
+    fn update_buffered_bytes(&mut self) -> PolarsResult<()> {
+        let bytes = self
+            .left_unjoined
+            .iter()
+            .map(|df| df.estimated_size())
+            .sum::<usize>()
+            + self.right_window.estimated_size();
+        if let Some(reservation) = &self.reservation {
+            if bytes < self.buffered_bytes {
+                reservation.shrink(self.buffered_bytes - bytes);
+            } else {
+                reservation.grow(bytes - self.buffered_bytes)?;
+            }
+        }
+        self.buffered_bytes = bytes;
+        Ok(())
+    }
+
+    fn push_left(&mut self, df: DataFrame) -> PolarsResult<()> {
+        if df.height() == 0 {
+            return Ok(());
+        }
+        if self.by.is_none() {
+            let key = Self::physical_key(&df, &self.params.left_key);
+            let mut last_key = self.left_last_key.take();
+            self.update_last_key(&key, &mut last_key)?;
+            self.left_last_key = last_key;
+        }
+        self.left_unjoined.push_back(df);
+        self.update_buffered_bytes()
+    }
+
+    fn push_right(&mut self, df: DataFrame) -> PolarsResult<()> {
+        if df.height() == 0 {
+            return Ok(());
+        }
+        let key = Self::physical_key(&df, &self.params.right_key);
+        if let Some(by) = &mut self.by {
+            let group_idxs = by.group_idxs(&df, false)?;
+            ByGroups::update_last_key(
+                &mut by.right_last_key,
+                &key,
+                &group_idxs,
+                self.params.check_sortedness,
+            )?;
+            by.right_window_groups.extend(group_idxs);
+        } else {
+            let mut last_key = self.right_last_key.take();
+            self.update_last_key(&key, &mut last_key)?;
+            self.right_last_key = last_key;
+        }
+        self.right_window.vstack_mut_owned(df)?;
+        self.update_buffered_bytes()
+    }
+
+    /// Returns the number of leading rows of left for which we have seen all
+    /// right rows they can match.
+    fn num_resolvable(&mut self, left: &DataFrame) -> PolarsResult<usize> {
+        if self.right_done {
+            return Ok(left.height());
+        }
+        if let Some(by) = &mut self.by {
+            // Left rows with a null key never match anything.
+            let left_key = Self::physical_key(left, &self.params.left_key);
+            let group_idxs = by.group_idxs(left, true)?;
+            let right_last_key = by.right_last_key.take_slice(&group_idxs)?;
+            let mask =
+                left_key.lt(&right_last_key)?.fill_null_with_values(false)? | left_key.is_null();
+            return Ok(mask.rechunk().downcast_as_array().values().leading_ones());
+        }
+        let Some(right_last_key) = &self.right_last_key else {
+            return Ok(0);
+        };
+        let left_key = Self::physical_key(left, &self.params.left_key);
+        num_leading_lt(&left_key, right_last_key)
+    }
+
+    /// Drops the right rows which can't be matched by left rows following the
+    /// given left chunk, only the last smaller row can still be a match.
+    fn prune_right(&mut self, left: &DataFrame) -> PolarsResult<()> {
+        if let Some(by) = &mut self.by {
+            let left_key = Self::physical_key(left, &self.params.left_key);
+            let group_idxs = by.group_idxs(left, true)?;
+            ByGroups::update_last_key(
+                &mut by.left_last_key,
+                &left_key,
+                &group_idxs,
+                self.params.check_sortedness,
+            )?;
+
+            // Per group only the last right row smaller than the last left key
+            // and the rows after it can still be matched.
+            let right_key = Self::physical_key(&self.right_window, &self.params.right_key);
+            let left_last_key = by.left_last_key.take_slice(&by.right_window_groups)?;
+            let is_lt = right_key.lt(&left_last_key)?.rechunk().into_owned();
+            let is_valid = right_key.is_not_null().rechunk().into_owned();
+            let mut seen_lt = vec![false; by.left_last_key.len()];
+            let mut keep = vec![false; right_key.len()];
+            for i in (0..right_key.len()).rev() {
+                let g = by.right_window_groups[i] as usize;
+                keep[i] = match (is_valid.get(i), is_lt.get(i)) {
+                    (Some(true), Some(true)) => !std::mem::replace(&mut seen_lt[g], true),
+                    (Some(true), _) => true,
+                    _ => false,
+                };
+            }
+            let mut idx = 0;
+            by.right_window_groups.retain(|_| {
+                idx += 1;
+                keep[idx - 1]
+            });
+            let keep = BooleanChunked::from_slice(PlSmallStr::EMPTY, &keep);
+            self.right_window = self.right_window.filter(&keep)?;
+            return Ok(());
+        }
+
+        let left_key = Self::physical_key(left, &self.params.left_key).drop_nulls();
+        if left_key.is_empty() {
+            return Ok(());
+        }
+        let left_last_key = left_key.tail(Some(1));
+        let right_key = Self::physical_key(&self.right_window, &self.params.right_key);
+        let offset = num_leading_lt(&right_key, &left_last_key)?.saturating_sub(1);
+        self.right_window = self.right_window.slice(offset as i64, usize::MAX);
+        Ok(())
+    }
+
+    /// Returns the next left chunk which can be joined, together with the
+    /// right window to join it with.
+    fn next_window(&mut self) -> PolarsResult<Option<(DataFrame, DataFrame)>> {
+        let Some(mut left) = self.left_unjoined.pop_front() else {
+            return Ok(None);
+        };
+        let num_resolvable = self.num_resolvable(&left)?;
+        if num_resolvable == 0 {
+            self.left_unjoined.push_front(left);
+            return Ok(None);
+        }
+        if num_resolvable < left.height() {
+            let rest;
+            (left, rest) = left.split_at(num_resolvable as i64);
+            self.left_unjoined.push_front(rest);
+        }
+
+        let right = self.right_window.clone();
+        self.prune_right(&left)?;
+        self.update_buffered_bytes()?;
+        Ok(Some((left, right)))
+    }
+}
+
+impl ComputeNode for AsOfJoinNode {
+    fn name(&self) -> &str {
+        "asof-join"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        if recv[1] == PortState::Done {
+            self.buffers.right_done = true;
+        }
+
+        // We are done once all left rows are joined.
+        let left_done = recv[0] == PortState::Done && self.buffers.left_unjoined.is_empty();
+        if send[0] == PortState::Done || left_done {
+            recv[0] = PortState::Done;
+            recv[1] = PortState::Done;
+            send[0] = PortState::Done;
+            return Ok(());
+        }
+
+        let send_blocked = send[0] == PortState::Blocked;
+        let left_blocked = recv[0] == PortState::Blocked && self.buffers.left_unjoined.is_empty();
+        let right_blocked = recv[1] == PortState::Blocked;
+        send[0] = if left_blocked || right_blocked {
+            PortState::Blocked
+        } else {
+            PortState::Ready
+        };
+        if recv[0] != PortState::Done {
+            recv[0] = if send_blocked || right_blocked {
+                PortState::Blocked
+            } else {
+                PortState::Ready
+            };
+        }
+        if recv[1] != PortState::Done {
+            recv[1] = if send_blocked || left_blocked {
+                PortState::Blocked
+            } else {
+                PortState::Ready
+            };
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 2 && send_ports.len() == 1);
+
+        // Buffered rows can't be spilled, if they don't fit the query fails.
+        if self.buffers.reservation.is_none() {
+            self.buffers.reservation = Some(state.memory.reserve("asof-join", false));
+        }
+
+        let senders = send_ports[0].take().unwrap().parallel();
+        let mut left = recv_ports[0].take().map(|p| p.serial());
+        let mut right = recv_ports[1].take().map(|p| p.serial());
+
+        let (mut distributor, dist_recv) =
+            distributor_channel(senders.len(), *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+
+        let buffers = &mut self.buffers;
+        let seq = &mut self.seq;
+        join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+            // If a stop was requested, we need to buffer the remaining morsels
+            // of the input ports and trigger a phase transition.
+            async fn buffer_inputs(
+                left: &mut Option<Receiver<Morsel>>,
+                right: &mut Option<Receiver<Morsel>>,
+                buffers: &mut AsOfJoinBuffers,
+            ) -> PolarsResult<()> {
+                if let Some(port) = left {
+                    if let Ok(morsel) = port.recv().await {
+                        morsel.source_token().stop();
+                        buffers.push_left(morsel.into_df())?;
+                        while let Ok(morsel) = port.recv().await {
+                            buffers.push_left(morsel.into_df())?;
+                        }
+                    }
+                }
+                if let Some(port) = right {
+                    if let Ok(morsel) = port.recv().await {
+                        morsel.source_token().stop();
+                        buffers.push_right(morsel.into_df())?;
+                        while let Ok(morsel) = port.recv().await {
+                            buffers.push_right(morsel.into_df())?;
+                        }
+                    }
+                }
+                Ok(())
+            }
+
+            let source_token = SourceToken::new();
+            loop {
+                while let Some((left_chunk, right_window)) = buffers.next_window()? {
+                    let morsel = Morsel::new(left_chunk, *seq, source_token.clone());
+                    *seq = seq.successor();
+                    if distributor.send((morsel, right_window)).await.is_err() {
+                        return Ok(());
+                    }
+                }
+
+                if source_token.stop_requested() {
+                    return buffer_inputs(&mut left, &mut right, buffers).await;
+                }
+
+                // Get more left rows if we joined all of them, otherwise get
+                // more right rows to be able to join them.
+                let (port, is_left) = if buffers.left_unjoined.is_empty() {
+                    (left.as_mut(), true)
+                } else {
+                    (right.as_mut(), false)
+                };
+                let Some(port) = port else {
+                    return buffer_inputs(&mut left, &mut right, buffers).await;
+                };
+                let Ok(morsel) = port.recv().await else {
+                    return buffer_inputs(&mut left, &mut right, buffers).await;
+                };
+                if is_left {
+                    buffers.push_left(morsel.into_df())?;
+                } else {
+                    buffers.push_right(morsel.into_df())?;
+                }
+            }
+        }));
+
+        join_handles.extend(
+            dist_recv
+                .into_iter()
+                .zip(senders)
+                .map(|(mut recv, mut send)| {
+                    let joiner = self.joiner.clone();
+                    scope.spawn_task(TaskPriority::High, async move {
+                        while let Ok((morsel, right_window)) = recv.recv().await {
+                            let (left_chunk, seq, source_token, wg) = morsel.into_inner();
+                            assert!(wg.is_none());
+                            let out = joiner(left_chunk, right_window)?;
+                            let morsel = Morsel::new(out, seq, source_token);
+                            if send.send(morsel).await.is_err() {
+                                break;
+                            }
+                        }
+                        Ok(())
+                    })
+                }),
+        );
+    }
+}
@@ -1,12 +1,14 @@
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 
+use arrow::array::MutableBinaryValuesArray;
 use arrow::array::builder::ShareStrategy;
 use polars_core::frame::builder::DataFrameBuilder;
 use polars_core::prelude::*;
 use polars_core::schema::{Schema, SchemaExt};
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_core::{POOL, config};
 use polars_expr::hash_keys::HashKeys;
 use polars_expr::idx_table::{IdxTable, new_idx_table};
@@ -28,9 +30,19 @@ use crate::async_executor;
 use crate::async_primitives::connector::{Receiver, Sender};
 use crate::async_primitives::wait_group::WaitGroup;
 use crate::expression::StreamExpr;
+use crate::memory::MemoryReservation;
 use crate::morsel::{SourceToken, get_ideal_morsel_size};
 use crate::nodes::compute_node_prelude::*;
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::nodes::sort::{SortMergeSource, SortedRunsBuilder};
+use crate::utils::spill::{SpillDir, SpillFile, get_spill_memory_budget};
+
+// In grace-hash mode we use this many spill partitions per pipeline, so that
+// a single partition of the build side is likely to fit in memory.
+const GRACE_PARTITIONS_PER_PIPELINE: usize = 8;
+const GRACE_SEQ_NAME: PlSmallStr = PlSmallStr::from_static("__POLARS_JOIN_SEQ");
+const GRACE_ROW_NAME: PlSmallStr = PlSmallStr::from_static("__POLARS_JOIN_ROW");
+const GRACE_ORDER_KEY_NAME: PlSmallStr = PlSmallStr::from_static("__POLARS_JOIN_ORDER_KEY");
 
 struct EquiJoinParams {
     left_is_build: Option<bool>,
@@ -47,6 +59,18 @@ struct EquiJoinParams {
     right_payload_schema: Arc<Schema>,
     args: JoinArgs,
     random_state: PlRandomState,
+    // Set if we switch to a grace-hash join when running out of memory.
+    spill: Option<GraceSpillParams>,
+}
+
+/// When the build side exceeds its memory budget we switch to a grace-hash
+/// join: both inputs are partitioned to disk and joined partition by partition.
+struct GraceSpillParams {
+    // The memory budget of a single pipeline.
+    local_budget: usize,
+    partitioner: HashPartitioner,
+    key_names: Vec<PlSmallStr>,
+    dir: Arc<SpillDir>,
 }
 
 impl EquiJoinParams {
@@ -171,17 +195,25 @@ fn select_schema(schema: &Schema, selector: &[Option<PlSmallStr>]) -> Schema {
         .collect()
 }
 
-async fn select_keys(
+async fn select_key_df(
     df: &DataFrame,
     key_selectors: &[StreamExpr],
-    params: &EquiJoinParams,
     state: &ExecutionState,
-) -> PolarsResult<HashKeys> {
+) -> PolarsResult<DataFrame> {
     let mut key_columns = Vec::new();
     for selector in key_selectors {
         key_columns.push(selector.evaluate(df, state).await?.into_column());
     }
-    let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
+    DataFrame::new_with_broadcast_len(key_columns, df.height())
+}
+
+async fn select_keys(
+    df: &DataFrame,
+    key_selectors: &[StreamExpr],
+    params: &EquiJoinParams,
+    state: &ExecutionState,
+) -> PolarsResult<HashKeys> {
+    let keys = select_key_df(df, key_selectors, state).await?;
     Ok(HashKeys::from_df(
         &keys,
         params.random_state,
@@ -421,6 +453,44 @@ struct LocalBuilder {
     // let stop = morsel_idxs_offsets[(i + 1) * num_partitions + p];
     morsel_idxs_values_per_p: Vec<Vec<IdxSize>>,
     morsel_idxs_offsets_per_p: Vec<usize>,
+
+    // The key columns of each morsel and their estimated size in memory,
+    // only tracked if we can spill.
+    key_dfs: Vec<DataFrame>,
+    bytes: usize,
+    // Accounts the bytes against the query's memory budget.
+    reservation: Option<MemoryReservation>,
+
+    // Set once we exceeded our memory budget, all rows then go to disk.
+    grace: Option<GracePartitionBuffer>,
+}
+
+impl LocalBuilder {
+    /// Moves all rows into the grace-hash partitions and spills them to disk.
+    fn spill_to_grace(&mut self, params: &EquiJoinParams) -> PolarsResult<()> {
+        let spill = params.spill.as_ref().unwrap();
+        let track_unmatchable = params.emit_unmatched_build();
+        let grace = self.grace.get_or_insert_default();
+        for ((seq, payload, hash_keys), key_df) in
+            self.morsels.drain(..).zip(self.key_dfs.drain(..))
+        {
+            let frame = grace_spill_frame(payload, &key_df, seq, spill);
+            grace.push(&frame, &hash_keys, track_unmatchable, spill)?;
+        }
+        grace.spill(spill)?;
+
+        let num_partitions = self.sketch_per_p.len();
+        self.sketch_per_p.fill(CardinalitySketch::default());
+        self.morsel_idxs_values_per_p
+            .iter_mut()
+            .for_each(Vec::clear);
+        self.morsel_idxs_offsets_per_p = vec![0; num_partitions];
+        if let Some(reservation) = &self.reservation {
+            reservation.shrink(self.bytes);
+        }
+        self.bytes = 0;
+        Ok(())
+    }
 }
 
 struct BuildState {
@@ -440,6 +510,10 @@ impl BuildState {
                 sketch_per_p: vec![CardinalitySketch::default(); num_partitions],
                 morsel_idxs_values_per_p: vec![Vec::new(); num_partitions],
                 morsel_idxs_offsets_per_p: vec![0; num_partitions],
+                key_dfs: Vec::new(),
+                bytes: 0,
+                reservation: None,
+                grace: None,
             })
             .collect();
         Self {
@@ -464,20 +538,33 @@ impl BuildState {
             payload_selector = &params.right_payload_select;
             key_selectors = &params.right_key_selectors;
         };
+        if params.spill.is_some() && local.reservation.is_none() {
+            local.reservation = Some(state.memory.reserve("equi-join", true));
+        }
 
         while let Ok(morsel) = recv.recv().await {
             // Compute hashed keys and payload. We must rechunk the payload for
             // later gathers.
-            let hash_keys = select_keys(
-                morsel.df(),
-                key_selectors,
-                params,
-                &state.in_memory_exec_state,
-            )
-            .await?;
+            let key_df =
+                select_key_df(morsel.df(), key_selectors, &state.in_memory_exec_state).await?;
+            let hash_keys =
+                HashKeys::from_df(&key_df, params.random_state, params.args.nulls_equal, false);
             let mut payload = select_payload(morsel.df().clone(), payload_selector);
             payload.rechunk_mut();
 
+            if let Some(spill) = &params.spill {
+                if let Some(grace) = &mut local.grace {
+                    let frame = grace_spill_frame(payload, &key_df, morsel.seq(), spill);
+                    grace.push(&frame, &hash_keys, track_unmatchable, spill)?;
+                    continue;
+                }
+
+                let bytes = payload.estimated_size() + key_df.estimated_size();
+                local.bytes += bytes;
+                local.reservation.as_ref().unwrap().grow(bytes)?;
+                local.key_dfs.push(key_df);
+            }
+
             hash_keys.gen_idxs_per_partition(
                 &partitioner,
                 &mut local.morsel_idxs_values_per_p,
@@ -489,10 +576,81 @@ impl BuildState {
                 .morsel_idxs_offsets_per_p
                 .extend(local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()));
             local.morsels.push((morsel.seq(), payload, hash_keys));
+
+            if let Some(spill) = &params.spill {
+                let should_spill = local.reservation.as_ref().unwrap().should_spill();
+                if local.bytes > spill.local_budget || should_spill {
+                    local.spill_to_grace(params)?;
+                }
+            }
         }
         Ok(())
     }
 
+    /// Whether we ran out of memory and must do a grace-hash join.
+    fn has_spilled(&self) -> bool {
+        self.local_builders.iter().any(|l| l.grace.is_some())
+    }
+
+    /// Spills the remainder of the build side and partitions the sampled probe
+    /// morsels, preparing for a grace-hash join.
+    fn start_grace_probe(
+        &mut self,
+        params: &EquiJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<GraceProbeState> {
+        let build_files = POOL.install(|| {
+            self.local_builders
+                .par_iter_mut()
+                .map(|l| {
+                    l.spill_to_grace(params)?;
+                    Ok(core::mem::take(&mut l.grace.as_mut().unwrap().files))
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+
+        let sampled_probe_morsels = core::mem::take(&mut self.sampled_probe_morsels);
+        let mut probe_state = GraceProbeState {
+            build_files: build_files.into_iter().flatten().collect(),
+            locals: (0..state.num_pipelines)
+                .map(|_| GracePartitionBuffer::default())
+                .collect(),
+            seq_offset: sampled_probe_morsels.post_buffer_offset,
+        };
+
+        // Simulate the sampled probe morsels flowing into the probe side.
+        if !sampled_probe_morsels.is_empty() {
+            crate::async_executor::task_scope(|scope| {
+                let mut join_handles = Vec::new();
+                let receivers = sampled_probe_morsels
+                    .reinsert(state.num_pipelines, None, scope, &mut join_handles)
+                    .unwrap();
+
+                for (local, recv) in probe_state.locals.iter_mut().zip(receivers) {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        GraceProbeState::partition_and_sink(
+                            recv,
+                            local,
+                            MorselSeq::default(),
+                            params,
+                            state,
+                        ),
+                    ));
+                }
+
+                polars_io::pl_async::get_runtime().block_on(async move {
+                    for handle in join_handles {
+                        handle.await?;
+                    }
+                    PolarsResult::Ok(())
+                })
+            })?;
+        }
+
+        Ok(probe_state)
+    }
+
     fn finalize_ordered(&mut self, params: &EquiJoinParams, table: &dyn IdxTable) -> ProbeState {
         let track_unmatchable = params.emit_unmatched_build();
         let payload_schema = if params.left_is_build.unwrap() {
@@ -1154,12 +1312,499 @@ impl EmitUnmatchedState {
     }
 }
 
+/// Combines the payload, the keys and the position of each row in the input
+/// into the layout of the blocks we spill in a grace-hash join.
+fn grace_spill_frame(
+    payload: DataFrame,
+    key_df: &DataFrame,
+    seq: MorselSeq,
+    spill: &GraceSpillParams,
+) -> DataFrame {
+    let height = key_df.height();
+    let mut columns = payload.take_columns();
+    columns.extend(
+        key_df
+            .get_columns()
+            .iter()
+            .zip(&spill.key_names)
+            .map(|(c, name)| c.clone().with_name(name.clone())),
+    );
+    columns.push(UInt64Chunked::full(GRACE_SEQ_NAME, seq.to_u64(), height).into_column());
+    columns.push(UInt64Chunked::from_iter_values(GRACE_ROW_NAME, 0..height as u64).into_column());
+    let mut frame = unsafe { DataFrame::new_no_checks(height, columns) };
+    frame.rechunk_mut();
+    frame
+}
+
+/// Splits a spilled block into its keys and its payload, where the payload
+/// keeps the position of each row in the input as its last two columns.
+fn split_grace_block(
+    block: DataFrame,
+    payload_width: usize,
+    params: &EquiJoinParams,
+) -> (DataFrame, HashKeys) {
+    let height = block.height();
+    let num_keys = params.left_key_selectors.len();
+    let mut columns = block.take_columns();
+    let order = columns.split_off(payload_width + num_keys);
+    let keys = columns.split_off(payload_width);
+    columns.extend(order);
+    let payload = unsafe { DataFrame::new_no_checks(height, columns) };
+    let keys = unsafe { DataFrame::new_no_checks(height, keys) };
+    let hash_keys = HashKeys::from_df(&keys, params.random_state, params.args.nulls_equal, false);
+    (payload, hash_keys)
+}
+
+/// Splits off the position of each row in the input from a gathered payload.
+fn split_grace_order(df: DataFrame) -> (DataFrame, Vec<Column>) {
+    let height = df.height();
+    let mut columns = df.take_columns();
+    let order = columns.split_off(columns.len() - 2);
+    (unsafe { DataFrame::new_no_checks(height, columns) }, order)
+}
+
+/// Creates a binary key which sorts the output rows of a grace-hash join in
+/// the order an in-memory join produces them: by position in the probe input,
+/// then by position in the build input, with unmatched build rows last.
+fn grace_order_key(
+    probe_order: Option<&[Column]>,
+    build_order: &[Column],
+    height: usize,
+) -> Column {
+    let values =
+        |c: &Column| -> Vec<u64> { c.u64().unwrap().iter().map(|v| v.unwrap_or(0)).collect() };
+    let (probe_seq, probe_row) = match probe_order {
+        Some(order) => (values(&order[0]), values(&order[1])),
+        None => (vec![u64::MAX; height], vec![0; height]),
+    };
+    let (build_seq, build_row) = (values(&build_order[0]), values(&build_order[1]));
+
+    let mut keys =
+        MutableBinaryValuesArray::<i64>::with_capacities(height, height * 4 * size_of::<u64>());
+    let mut key = [0u8; 4 * size_of::<u64>()];
+    for i in 0..height {
+        key[0..8].copy_from_slice(&probe_seq[i].to_be_bytes());
+        key[8..16].copy_from_slice(&probe_row[i].to_be_bytes());
+        key[16..24].copy_from_slice(&build_seq[i].to_be_bytes());
+        key[24..32].copy_from_slice(&build_row[i].to_be_bytes());
+        keys.push(key);
+    }
+    BinaryOffsetChunked::with_chunk(GRACE_ORDER_KEY_NAME, keys.into()).into_column()
+}
+
+/// Rows partitioned for a grace-hash join, buffered in memory until they are
+/// spilled to disk. Each spill file contains one block per partition.
+#[derive(Default)]
+struct GracePartitionBuffer {
+    schema: Option<Arc<Schema>>,
+    dfs_per_p: Vec<Vec<DataFrame>>,
+    idxs_per_p: Vec<Vec<IdxSize>>,
+    bytes: usize,
+    files: Vec<SpillFile>,
+}
+
+impl GracePartitionBuffer {
+    fn push(
+        &mut self,
+        frame: &DataFrame,
+        hash_keys: &HashKeys,
+        partition_nulls: bool,
+        spill: &GraceSpillParams,
+    ) -> PolarsResult<()> {
+        let num_partitions = spill.partitioner.num_partitions();
+        self.schema.get_or_insert_with(|| frame.schema().clone());
+        if self.dfs_per_p.is_empty() {
+            self.dfs_per_p = vec![Vec::new(); num_partitions];
+            self.idxs_per_p = vec![Vec::new(); num_partitions];
+        }
+
+        self.idxs_per_p.iter_mut().for_each(Vec::clear);
+        hash_keys.gen_idxs_per_partition(
+            &spill.partitioner,
+            &mut self.idxs_per_p,
+            &mut [],
+            partition_nulls,
+        );
+        for (dfs, idxs) in self.dfs_per_p.iter_mut().zip(&self.idxs_per_p) {
+            if !idxs.is_empty() {
+                dfs.push(unsafe { frame.take_slice_unchecked_impl(idxs, false) });
+            }
+        }
+
+        self.bytes += frame.estimated_size();
+        if self.bytes > spill.local_budget {
+            self.spill(spill)?;
+        }
+        Ok(())
+    }
+
+    fn spill(&mut self, spill: &GraceSpillParams) -> PolarsResult<()> {
+        if self.dfs_per_p.iter().all(|dfs| dfs.is_empty()) {
+            return Ok(());
+        }
+        let schema = self.schema.clone().unwrap();
+        let blocks = self.dfs_per_p.iter_mut().map(|dfs| {
+            if dfs.is_empty() {
+                DataFrame::empty_with_schema(&schema)
+            } else {
+                accumulate_dataframes_vertical_unchecked(dfs.drain(..))
+            }
+        });
+        let file = SpillFile::write(&spill.dir, schema.clone(), blocks)?;
+        self.files.push(file);
+        self.bytes = 0;
+        Ok(())
+    }
+}
+
+struct GraceProbeState {
+    build_files: Vec<SpillFile>,
+    locals: Vec<GracePartitionBuffer>,
+    // The offset for the sequence ids of morsels following the sampled ones.
+    seq_offset: MorselSeq,
+}
+
+impl GraceProbeState {
+    async fn partition_and_sink(
+        mut recv: Receiver<Morsel>,
+        local: &mut GracePartitionBuffer,
+        seq_offset: MorselSeq,
+        params: &EquiJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        let spill = params.spill.as_ref().unwrap();
+        let partition_nulls = params.emit_unmatched_probe();
+        let (key_selectors, payload_selector);
+        if params.left_is_build.unwrap() {
+            key_selectors = &params.right_key_selectors;
+            payload_selector = &params.right_payload_select;
+        } else {
+            key_selectors = &params.left_key_selectors;
+            payload_selector = &params.left_payload_select;
+        };
+
+        while let Ok(morsel) = recv.recv().await {
+            let key_df =
+                select_key_df(morsel.df(), key_selectors, &state.in_memory_exec_state).await?;
+            let hash_keys =
+                HashKeys::from_df(&key_df, params.random_state, params.args.nulls_equal, false);
+            let payload = select_payload(morsel.df().clone(), payload_selector);
+            let seq = morsel.seq().offset_by(seq_offset);
+            let frame = grace_spill_frame(payload, &key_df, seq, spill);
+            local.push(&frame, &hash_keys, partition_nulls, spill)?;
+        }
+        Ok(())
+    }
+
+    fn start_grace_join(&mut self, params: &EquiJoinParams) -> PolarsResult<GraceJoinState> {
+        let spill = params.spill.as_ref().unwrap();
+        let probe_files = POOL.install(|| {
+            self.locals
+                .par_iter_mut()
+                .map(|l| {
+                    l.spill(spill)?;
+                    Ok(core::mem::take(&mut l.files))
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+
+        Ok(GraceJoinState {
+            build_files: core::mem::take(&mut self.build_files),
+            probe_files: probe_files.into_iter().flatten().collect(),
+            num_partitions: spill.partitioner.num_partitions(),
+            next_partition: AtomicUsize::new(0),
+            morsel_seq: AtomicU64::new(0),
+        })
+    }
+}
+
+/// Joins a single partition of a grace-hash join.
+struct GracePartitionJoin<'a> {
+    params: &'a EquiJoinParams,
+    probe_files: &'a [SpillFile],
+    partition: usize,
+    table: Box<dyn IdxTable>,
+    // The payloads end with the position of each row in the input.
+    build_payload: DataFrame,
+    probe_payload: DataFrame,
+    probe_keys: Option<HashKeys>,
+    probe_idxs: Vec<IdxSize>,
+    probe_offset: usize,
+    next_probe_file: usize,
+    unmatched_offset: IdxSize,
+    table_match: Vec<IdxSize>,
+    probe_match: Vec<IdxSize>,
+}
+
+impl<'a> GracePartitionJoin<'a> {
+    fn new(
+        partition: usize,
+        build_files: &[SpillFile],
+        probe_files: &'a [SpillFile],
+        params: &'a EquiJoinParams,
+        table: &dyn IdxTable,
+    ) -> PolarsResult<Self> {
+        let mut blocks = Vec::with_capacity(build_files.len());
+        for file in build_files {
+            blocks.extend(file.read_blocks(partition..partition + 1)?);
+        }
+        let mut build = accumulate_dataframes_vertical_unchecked(blocks);
+        if params.preserve_order_build {
+            build.sort_in_place(
+                [GRACE_SEQ_NAME, GRACE_ROW_NAME],
+                SortMultipleOptions::default().with_multithreaded(false),
+            )?;
+        }
+        build.rechunk_mut();
+
+        let (build_payload, build_keys) =
+            split_grace_block(build, build_payload_schema(params).len(), params);
+        let mut table = table.new_empty();
+        let build_idxs: Vec<IdxSize> = (0..build_payload.height() as IdxSize).collect();
+        table.reserve(build_idxs.len());
+        unsafe {
+            table.insert_keys_subset(&build_keys, &build_idxs, params.emit_unmatched_build())
+        };
+
+        Ok(Self {
+            params,
+            probe_files,
+            partition,
+            table,
+            build_payload,
+            probe_payload: DataFrame::empty(),
+            probe_keys: None,
+            probe_idxs: Vec::new(),
+            probe_offset: 0,
+            next_probe_file: 0,
+            unmatched_offset: 0,
+            table_match: Vec::new(),
+            probe_match: Vec::new(),
+        })
+    }
+
+    /// Returns the next output morsel, if any.
+    fn next(&mut self) -> PolarsResult<Option<DataFrame>> {
+        let limit = get_ideal_morsel_size() as IdxSize;
+        let mark_matches = self.params.emit_unmatched_build();
+        let emit_unmatched = self.params.emit_unmatched_probe();
+        loop {
+            if let Some(probe_keys) = &self.probe_keys {
+                if self.probe_offset < self.probe_idxs.len() {
+                    self.table_match.clear();
+                    self.probe_match.clear();
+                    self.probe_offset += unsafe {
+                        self.table.probe_subset(
+                            probe_keys,
+                            &self.probe_idxs[self.probe_offset..],
+                            &mut self.table_match,
+                            &mut self.probe_match,
+                            mark_matches,
+                            emit_unmatched,
+                            limit,
+                        )
+                    } as usize;
+                    if self.table_match.is_empty() {
+                        continue;
+                    }
+
+                    let build_schema = self.build_payload.schema().clone();
+                    let mut build_out = DataFrameBuilder::new(build_schema);
+                    let mut probe_out = DataFrameBuilder::new(self.probe_payload.schema().clone());
+                    unsafe {
+                        if emit_unmatched {
+                            build_out.opt_gather_extend(
+                                &self.build_payload,
+                                &self.table_match,
+                                ShareStrategy::Always,
+                            );
+                        } else {
+                            build_out.gather_extend(
+                                &self.build_payload,
+                                &self.table_match,
+                                ShareStrategy::Always,
+                            );
+                        }
+                        probe_out.gather_extend(
+                            &self.probe_payload,
+                            &self.probe_match,
+                            ShareStrategy::Always,
+                        );
+                    }
+                    return Ok(Some(
+                        self.finish_output(build_out.freeze(), Some(probe_out.freeze())),
+                    ));
+                }
+            }
+
+            if let Some(file) = self.probe_files.get(self.next_probe_file) {
+                self.next_probe_file += 1;
+                let range = self.partition..self.partition + 1;
+                let mut block = file.read_blocks(range)?.pop().unwrap();
+                block.rechunk_mut();
+                let payload_width = probe_payload_schema(self.params).len();
+                let (probe_payload, probe_keys) =
+                    split_grace_block(block, payload_width, self.params);
+                self.probe_idxs.clear();
+                self.probe_idxs.extend(0..probe_payload.height() as IdxSize);
+                self.probe_offset = 0;
+                self.probe_payload = probe_payload;
+                self.probe_keys = Some(probe_keys);
+                continue;
+            }
+
+            // The probe side is done, emit the unmatched rows of the build side.
+            if !self.params.emit_unmatched_build() {
+                return Ok(None);
+            }
+            self.unmatched_offset +=
+                self.table
+                    .unmarked_keys(&mut self.table_match, self.unmatched_offset, limit);
+            if self.table_match.is_empty() {
+                return Ok(None);
+            }
+            let build_df = unsafe {
+                self.build_payload
+                    .take_slice_unchecked_impl(&self.table_match, false)
+            };
+            return Ok(Some(self.finish_output(build_df, None)));
+        }
+    }
+
+    /// Combines the gathered payloads into an output morsel. If we must
+    /// maintain the order the sort key for the output rows is appended.
+    fn finish_output(&self, build_df: DataFrame, probe_df: Option<DataFrame>) -> DataFrame {
+        let height = build_df.height();
+        let (mut build_df, build_order) = split_grace_order(build_df);
+        let (mut probe_df, probe_order) = match probe_df {
+            Some(probe_df) => {
+                let (probe_df, probe_order) = split_grace_order(probe_df);
+                (probe_df, Some(probe_order))
+            },
+            None => (
+                DataFrame::full_null(probe_payload_schema(self.params), height),
+                None,
+            ),
+        };
+
+        let out_df = unsafe {
+            if self.params.left_is_build.unwrap() {
+                build_df.hstack_mut_unchecked(probe_df.get_columns());
+                build_df
+            } else {
+                probe_df.hstack_mut_unchecked(build_df.get_columns());
+                probe_df
+            }
+        };
+        let mut out_df = postprocess_join(out_df, self.params);
+        if self.params.preserve_order_probe {
+            let key = grace_order_key(probe_order.as_deref(), &build_order, height);
+            unsafe { out_df.with_column_unchecked(key) };
+        }
+        out_df
+    }
+}
+
+fn build_payload_schema(params: &EquiJoinParams) -> &Arc<Schema> {
+    if params.left_is_build.unwrap() {
+        &params.left_payload_schema
+    } else {
+        &params.right_payload_schema
+    }
+}
+
+fn probe_payload_schema(params: &EquiJoinParams) -> &Arc<Schema> {
+    if params.left_is_build.unwrap() {
+        &params.right_payload_schema
+    } else {
+        &params.left_payload_schema
+    }
+}
+
+struct GraceJoinState {
+    build_files: Vec<SpillFile>,
+    probe_files: Vec<SpillFile>,
+    num_partitions: usize,
+    next_partition: AtomicUsize,
+    morsel_seq: AtomicU64,
+}
+
+impl GraceJoinState {
+    fn is_finished(&self) -> bool {
+        self.next_partition.load(Ordering::Relaxed) >= self.num_partitions
+    }
+
+    /// Joins partitions one at a time until all partitions are claimed.
+    async fn join_partitions(
+        &self,
+        mut send: Sender<Morsel>,
+        source_token: SourceToken,
+        params: &EquiJoinParams,
+        table: &dyn IdxTable,
+    ) -> PolarsResult<()> {
+        while !source_token.stop_requested() {
+            let p = self.next_partition.fetch_add(1, Ordering::Relaxed);
+            if p >= self.num_partitions {
+                break;
+            }
+
+            let mut join =
+                GracePartitionJoin::new(p, &self.build_files, &self.probe_files, params, table)?;
+            while let Some(df) = join.next()? {
+                let seq = MorselSeq::new(self.morsel_seq.fetch_add(1, Ordering::Relaxed));
+                let morsel = Morsel::new(df, seq, source_token.clone());
+                if send.send(morsel).await.is_err() {
+                    return Ok(());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Joins all partitions into sorted runs, which are merged to restore the
+    /// order of the probe side.
+    fn into_ordered_source(
+        self,
+        params: &EquiJoinParams,
+        table: &dyn IdxTable,
+    ) -> PolarsResult<SortMergeSource> {
+        let spill = params.spill.as_ref().unwrap();
+        let runs = POOL.install(|| {
+            (0..self.num_partitions)
+                .into_par_iter()
+                .map(|p| {
+                    let mut runs = SortedRunsBuilder::default();
+                    let mut join = GracePartitionJoin::new(
+                        p,
+                        &self.build_files,
+                        &self.probe_files,
+                        params,
+                        table,
+                    )?;
+                    while let Some(df) = join.next()? {
+                        runs.push(df, spill.local_budget, &spill.dir, None)?;
+                    }
+                    runs.finish(Some(&spill.dir), None)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        SortMergeSource::new(
+            runs.into_iter().flatten().collect(),
+            None,
+            spill.dir.clone(),
+        )
+    }
+}
+
 enum EquiJoinState {
     Sample(SampleState),
     Build(BuildState),
     Probe(ProbeState),
     EmitUnmatchedBuild(EmitUnmatchedState),
     EmitUnmatchedBuildInOrder(InMemorySourceNode),
+    GraceProbe(GraceProbeState),
+    GraceJoin(GraceJoinState),
+    GraceJoinInOrder(SortMergeSource),
     Done,
 }
 
@@ -1230,6 +1875,16 @@ impl EquiJoinNode {
         let left_payload_schema = Arc::new(select_schema(&left_input_schema, &left_payload_select));
         let right_payload_schema =
             Arc::new(select_schema(&right_input_schema, &right_payload_select));
+
+        let spill = get_spill_memory_budget()?.map(|memory_budget| GraceSpillParams {
+            local_budget: memory_budget / num_pipelines,
+            partitioner: HashPartitioner::new(num_pipelines * GRACE_PARTITIONS_PER_PIPELINE, 0),
+            key_names: (0..left_key_selectors.len())
+                .map(|i| format_pl_smallstr!("__POLARS_JOIN_KEY_{i}"))
+                .collect(),
+            dir: SpillDir::new("equi-join"),
+        });
+
         Ok(Self {
             state,
             params: EquiJoinParams {
@@ -1246,6 +1901,7 @@ impl EquiJoinNode {
                 right_payload_schema,
                 args,
                 random_state: PlRandomState::default(),
+                spill,
             },
             table: new_idx_table(unique_key_schema),
         })
@@ -1288,7 +1944,13 @@ impl ComputeNode for EquiJoinNode {
 
         // If we are building and the build input is done, transition to probing.
         if let EquiJoinState::Build(build_state) = &mut self.state {
-            if recv[build_idx] == PortState::Done {
+            if recv[build_idx] == PortState::Done && build_state.has_spilled() {
+                if config::verbose() {
+                    eprintln!("[equi-join]: memory budget exceeded, using grace-hash join");
+                }
+                let probe_state = build_state.start_grace_probe(&self.params, state)?;
+                self.state = EquiJoinState::GraceProbe(probe_state);
+            } else if recv[build_idx] == PortState::Done {
                 let probe_state = if self.params.preserve_order_build {
                     build_state.finalize_ordered(&self.params, &*self.table)
                 } else {
@@ -1325,6 +1987,29 @@ impl ComputeNode for EquiJoinNode {
             }
         }
 
+        // If we partitioned the probe side to disk, join the partitions.
+        if let EquiJoinState::GraceProbe(probe_state) = &mut self.state {
+            if recv[probe_idx] == PortState::Done {
+                let join_state = probe_state.start_grace_join(&self.params)?;
+                self.state = if self.params.preserve_order_probe {
+                    let source = join_state.into_ordered_source(&self.params, &*self.table)?;
+                    EquiJoinState::GraceJoinInOrder(source)
+                } else {
+                    EquiJoinState::GraceJoin(join_state)
+                };
+            }
+        }
+
+        match &self.state {
+            EquiJoinState::GraceJoin(join_state) if join_state.is_finished() => {
+                self.state = EquiJoinState::Done;
+            },
+            EquiJoinState::GraceJoinInOrder(source) if source.is_finished() => {
+                self.state = EquiJoinState::Done;
+            },
+            _ => {},
+        }
+
         // Finally, check if we are done emitting unmatched keys.
         if let EquiJoinState::EmitUnmatchedBuild(emit_state) = &mut self.state {
             if emit_state.active_partition_idx >= emit_state.partitions.len() {
@@ -1385,6 +2070,18 @@ impl ComputeNode for EquiJoinNode {
                     self.state = EquiJoinState::Done;
                 }
             },
+            EquiJoinState::GraceProbe(_) => {
+                send[0] = PortState::Blocked;
+                recv[build_idx] = PortState::Done;
+                if recv[probe_idx] != PortState::Done {
+                    recv[probe_idx] = PortState::Ready;
+                }
+            },
+            EquiJoinState::GraceJoin(_) | EquiJoinState::GraceJoinInOrder(_) => {
+                send[0] = PortState::Ready;
+                recv[build_idx] = PortState::Done;
+                recv[probe_idx] = PortState::Done;
+            },
             EquiJoinState::Done => {
                 send[0] = PortState::Done;
                 recv[0] = PortState::Done;
@@ -1397,7 +2094,9 @@ impl ComputeNode for EquiJoinNode {
     fn is_memory_intensive_pipeline_blocker(&self) -> bool {
         matches!(
             self.state,
-            EquiJoinState::Sample { .. } | EquiJoinState::Build { .. }
+            EquiJoinState::Sample { .. }
+                | EquiJoinState::Build { .. }
+                | EquiJoinState::GraceProbe { .. }
         )
     }
 
@@ -1532,6 +2231,48 @@ impl ComputeNode for EquiJoinNode {
                 assert!(recv_ports[probe_idx].is_none());
                 src_node.spawn(scope, &mut [], send_ports, state, join_handles);
             },
+            EquiJoinState::GraceProbe(probe_state) => {
+                assert!(send_ports[0].is_none());
+                assert!(recv_ports[build_idx].is_none());
+                let receivers = recv_ports[probe_idx].take().unwrap().parallel();
+
+                let seq_offset = probe_state.seq_offset;
+                for (local, recv) in probe_state.locals.iter_mut().zip(receivers) {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        GraceProbeState::partition_and_sink(
+                            recv,
+                            local,
+                            seq_offset,
+                            &self.params,
+                            state,
+                        ),
+                    ));
+                }
+            },
+            EquiJoinState::GraceJoin(join_state) => {
+                assert!(recv_ports[build_idx].is_none());
+                assert!(recv_ports[probe_idx].is_none());
+                let senders = send_ports[0].take().unwrap().parallel();
+                let source_token = SourceToken::new();
+                for send in senders {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        join_state.join_partitions(
+                            send,
+                            source_token.clone(),
+                            &self.params,
+                            &*self.table,
+                        ),
+                    ));
+                }
+            },
+            EquiJoinState::GraceJoinInOrder(source) => {
+                assert!(recv_ports[build_idx].is_none());
+                assert!(recv_ports[probe_idx].is_none());
+                let send = send_ports[0].take().unwrap().serial();
+                join_handles.push(scope.spawn_task(TaskPriority::Low, source.send_morsels(send)));
+            },
             EquiJoinState::Done => unreachable!(),
         }
     }
@@ -0,0 +1,451 @@
+use std::sync::Arc;
+
+use arrow::array::BinaryArray;
+use polars_core::chunked_array::ops::row_encode::_get_rows_encoded_arr;
+use polars_core::prelude::*;
+use polars_ops::frame::{
+    DataFrameJoinOps, IEJoinOptions, InequalityOperator, JoinArgs, JoinTypeOptions,
+};
+use polars_utils::format_pl_smallstr;
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::async_primitives::connector::{Receiver, Sender};
+use crate::expression::StreamExpr;
+use crate::morsel::get_ideal_morsel_size;
+use crate::nodes::compute_node_prelude::*;
+use crate::nodes::sort::{SortedRun, SortedRunsBuilder};
+use crate::utils::spill::{SpillDir, SpillFile};
+
+const SORT_KEY_NAME: PlSmallStr = PlSmallStr::from_static("__POLARS_IEJOIN_SORT_KEY");
+
+struct IEJoinParams {
+    left_key_selectors: Vec<StreamExpr>,
+    right_key_selectors: Vec<StreamExpr>,
+    // The number of columns of the right input, the key columns and the sort
+    // key are stored after these in the build blocks.
+    right_payload_width: usize,
+    args: JoinArgs,
+    options: IEJoinOptions,
+}
+
+/// A block of build rows sorted on the first inequality key, with the first
+/// and last (row-encoded) key in the block.
+struct BuildBlock {
+    first_key: Vec<u8>,
+    last_key: Vec<u8>,
+    data: BuildBlockData,
+}
+
+enum BuildBlockData {
+    InMemory(DataFrame),
+    Spilled { file: Arc<SpillFile>, block: usize },
+}
+
+impl BuildBlock {
+    fn new(df: &DataFrame, data: BuildBlockData) -> Self {
+        let keys = sort_keys(df);
+        Self {
+            first_key: keys.value(0).to_vec(),
+            last_key: keys.value(keys.len() - 1).to_vec(),
+            data,
+        }
+    }
+
+    /// Whether any left row with a key between the given (row-encoded) bounds
+    /// can satisfy the first inequality with a row in this block.
+    fn may_match(&self, op: InequalityOperator, min_left: &[u8], max_left: &[u8]) -> bool {
+        match op {
+            InequalityOperator::Lt => min_left < self.last_key.as_slice(),
+            InequalityOperator::LtEq => min_left <= self.last_key.as_slice(),
+            InequalityOperator::Gt => max_left > self.first_key.as_slice(),
+            InequalityOperator::GtEq => max_left >= self.first_key.as_slice(),
+        }
+    }
+
+    fn is_spilled(&self) -> bool {
+        matches!(self.data, BuildBlockData::Spilled { .. })
+    }
+
+    fn load(&self) -> PolarsResult<DataFrame> {
+        match &self.data {
+            BuildBlockData::InMemory(df) => Ok(df.clone()),
+            BuildBlockData::Spilled { file, block } => {
+                Ok(file.read_blocks(*block..*block + 1)?.pop().unwrap())
+            },
+        }
+    }
+}
+
+fn sort_keys(df: &DataFrame) -> BinaryArray<i64> {
+    let key = df.get_columns().last().unwrap();
+    let key_ca = key.as_materialized_series().binary_offset().unwrap();
+    key_ca.rechunk().downcast_as_array().clone()
+}
+
+async fn select_keys(
+    df: &DataFrame,
+    key_selectors: &[StreamExpr],
+    state: &StreamingExecutionState,
+) -> PolarsResult<Vec<Column>> {
+    let mut keys = Vec::with_capacity(key_selectors.len());
+    for selector in key_selectors {
+        let key = selector.evaluate(df, &state.in_memory_exec_state).await?;
+        keys.push(if key.len() == 1 && df.height() != 1 {
+            key.new_from_index(0, df.height())
+        } else {
+            key
+        });
+    }
+    Ok(keys)
+}
+
+struct BuildState {
+    // The memory budget for the buffered morsels of a single local builder.
+    local_budget: usize,
+    spill_dir: Arc<SpillDir>,
+    locals: Vec<SortedRunsBuilder>,
+}
+
+impl BuildState {
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        receivers: Vec<Receiver<Morsel>>,
+        params: &'s IEJoinParams,
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        for (mut recv, local) in receivers.into_iter().zip(&mut self.locals) {
+            let local_budget = self.local_budget;
+            let spill_dir = &self.spill_dir;
+            local.track_memory(&state.memory, "iejoin");
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                while let Ok(morsel) = recv.recv().await {
+                    let mut df = morsel.into_df();
+                    let keys = select_keys(&df, &params.right_key_selectors, state).await?;
+
+                    // Rows with a null key can never match.
+                    let has_nulls = keys.iter().any(|k| k.has_nulls());
+                    for (i, key) in keys.into_iter().enumerate() {
+                        let name = format_pl_smallstr!("__POLARS_IEJOIN_KEY_{i}");
+                        unsafe { df.with_column_unchecked(key.with_name(name)) };
+                    }
+                    if has_nulls {
+                        let key_columns = &df.get_columns()[params.right_payload_width..];
+                        let mask = key_columns
+                            .iter()
+                            .map(|k| k.is_not_null())
+                            .reduce(|a, b| a & b)
+                            .unwrap();
+                        df = df._filter_seq(&mask)?;
+                    }
+                    if df.height() == 0 {
+                        continue;
+                    }
+
+                    let first_key = &df.get_columns()[params.right_payload_width];
+                    let sort_key =
+                        _get_rows_encoded_arr(std::slice::from_ref(first_key), &[false], &[false])?;
+                    let sort_key = BinaryOffsetChunked::with_chunk(SORT_KEY_NAME, sort_key);
+                    unsafe { df.with_column_unchecked(sort_key.into_column()) };
+                    local.push(df, local_budget, spill_dir, None)?;
+                }
+                Ok(())
+            }));
+        }
+    }
+
+    /// Splits the sorted runs of the build side into blocks.
+    fn into_probe_state(self) -> PolarsResult<ProbeState> {
+        let morsel_size = get_ideal_morsel_size();
+        let mut blocks = Vec::new();
+        for local in self.locals {
+            for run in local.finish(None, None)? {
+                match run {
+                    SortedRun::InMemory(df) => {
+                        let mut offset = 0;
+                        while offset < df.height() {
+                            let block = df.slice(offset as i64, morsel_size);
+                            offset += block.height();
+                            blocks.push(BuildBlock::new(
+                                &block,
+                                BuildBlockData::InMemory(block.clone()),
+                            ));
+                        }
+                    },
+                    SortedRun::Spilled { file, .. } => {
+                        let file = Arc::new(file);
+                        for block in 0..file.num_blocks() {
+                            let df = file.read_blocks(block..block + 1)?.pop().unwrap();
+                            if df.height() > 0 {
+                                let data = BuildBlockData::Spilled {
+                                    file: file.clone(),
+                                    block,
+                                };
+                                blocks.push(BuildBlock::new(&df, data));
+                            }
+                        }
+                    },
+                }
+            }
+        }
+
+        // Spilled blocks are read once for a batch of left morsels rather than
+        // for every left morsel, the batches use the memory budget of the build.
+        let left_batch_budget = if blocks.iter().any(BuildBlock::is_spilled) {
+            self.local_budget
+        } else {
+            0
+        };
+        Ok(ProbeState {
+            blocks,
+            left_batch_budget,
+        })
+    }
+}
+
+struct ProbeState {
+    blocks: Vec<BuildBlock>,
+    // The estimated size up to which a pipeline batches left morsels.
+    left_batch_budget: usize,
+}
+
+/// A left morsel with its keys and the (row-encoded) range of its first key.
+struct ProbeMorsel {
+    morsel: Morsel,
+    keys: Vec<Series>,
+    min_key: Vec<u8>,
+    max_key: Vec<u8>,
+}
+
+impl ProbeState {
+    /// Joins a batch of left morsels, loading every block at most once.
+    async fn probe_batch(
+        &self,
+        batch: &mut Vec<ProbeMorsel>,
+        params: &IEJoinParams,
+        send: &mut Sender<Morsel>,
+    ) -> PolarsResult<bool> {
+        let mut outputs = vec![Vec::new(); batch.len()];
+        for block in &self.blocks {
+            let mut build = None;
+            for (left, out) in batch.iter().zip(&mut outputs) {
+                if !block.may_match(params.options.operator1, &left.min_key, &left.max_key) {
+                    continue;
+                }
+                if build.is_none() {
+                    let build_df = block.load()?;
+                    let key_end = build_df.width() - 1;
+                    let right_keys = build_df.get_columns()[params.right_payload_width..key_end]
+                        .iter()
+                        .map(|k| k.as_materialized_series().clone())
+                        .collect::<Vec<_>>();
+                    let build_df = build_df.select_by_range(0..params.right_payload_width)?;
+                    build = Some((build_df, right_keys));
+                }
+                let (build_df, right_keys) = build.as_ref().unwrap();
+
+                let df = left.morsel.df()._join_impl(
+                    build_df,
+                    left.keys.clone(),
+                    right_keys.clone(),
+                    params.args.clone(),
+                    Some(JoinTypeOptions::IEJoin(params.options.clone())),
+                    false,
+                    false,
+                )?;
+                if df.height() > 0 {
+                    out.push(df);
+                }
+            }
+        }
+
+        // Send the output in the order of the left morsels.
+        for (left, out) in batch.drain(..).zip(outputs) {
+            for df in out {
+                let morsel = Morsel::new(df, left.morsel.seq(), left.morsel.source_token().clone());
+                if send.send(morsel).await.is_err() {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+}
+
+enum IEJoinState {
+    Build(BuildState),
+    Probe(ProbeState),
+    Done,
+}
+
+/// Inequality join which sorts the right input on the first inequality key
+/// into runs, which are spilled to disk once they exceed the memory budget.
+/// The sorted runs are split into blocks, and every left morsel is joined with
+/// the blocks whose key range can satisfy the first inequality.
+pub struct IEJoinNode {
+    state: IEJoinState,
+    params: IEJoinParams,
+}
+
+impl IEJoinNode {
+    pub fn new(
+        right_input_schema: Arc<Schema>,
+        left_key_selectors: Vec<StreamExpr>,
+        right_key_selectors: Vec<StreamExpr>,
+        args: JoinArgs,
+        options: IEJoinOptions,
+        memory_budget: Option<usize>,
+        num_pipelines: usize,
+    ) -> Self {
+        let local_budget = memory_budget.map_or(usize::MAX, |b| b / num_pipelines);
+        let locals = (0..num_pipelines)
+            .map(|_| SortedRunsBuilder::default())
+            .collect();
+
+        Self {
+            state: IEJoinState::Build(BuildState {
+                local_budget,
+                spill_dir: SpillDir::new("iejoin"),
+                locals,
+            }),
+            params: IEJoinParams {
+                left_key_selectors,
+                right_key_selectors,
+                right_payload_width: right_input_schema.len(),
+                args,
+                options,
+            },
+        }
+    }
+}
+
+impl ComputeNode for IEJoinNode {
+    fn name(&self) -> &str {
+        "iejoin"
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, IEJoinState::Build(_))
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        // Are we done?
+        if send[0] == PortState::Done || recv[0] == PortState::Done {
+            self.state = IEJoinState::Done;
+        }
+
+        // Transition to probe?
+        if recv[1] == PortState::Done && matches!(self.state, IEJoinState::Build(_)) {
+            let IEJoinState::Build(build) = core::mem::replace(&mut self.state, IEJoinState::Done)
+            else {
+                unreachable!()
+            };
+            let probe = build.into_probe_state()?;
+            if !probe.blocks.is_empty() {
+                self.state = IEJoinState::Probe(probe);
+            }
+        }
+
+        match &self.state {
+            IEJoinState::Build(_) => {
+                recv[0] = PortState::Blocked;
+                recv[1] = PortState::Ready;
+                send[0] = PortState::Blocked;
+            },
+            IEJoinState::Probe(_) => {
+                recv[1] = PortState::Done;
+                core::mem::swap(&mut recv[0], &mut send[0]);
+            },
+            IEJoinState::Done => {
+                recv[0] = PortState::Done;
+                recv[1] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 2 && send_ports.len() == 1);
+        let params = &self.params;
+        match &mut self.state {
+            IEJoinState::Build(build) => {
+                assert!(send_ports[0].is_none());
+                assert!(recv_ports[0].is_none());
+                let receivers = recv_ports[1].take().unwrap().parallel();
+                build.spawn(scope, receivers, params, state, join_handles);
+            },
+            IEJoinState::Probe(probe) => {
+                assert!(recv_ports[1].is_none());
+                let receivers = recv_ports[0].take().unwrap().parallel();
+                let senders = send_ports[0].take().unwrap().parallel();
+                let probe = &*probe;
+                for (mut recv, mut send) in receivers.into_iter().zip(senders) {
+                    join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        let mut batch = Vec::new();
+                        let mut batch_size = 0;
+                        loop {
+                            let morsel = recv.recv().await.ok();
+                            let is_done = morsel.is_none();
+                            if let Some(morsel) = morsel {
+                                let df = morsel.df();
+                                let keys = select_keys(df, &params.left_key_selectors, state)
+                                    .await?
+                                    .into_iter()
+                                    .map(|k| k.take_materialized_series())
+                                    .collect::<Vec<_>>();
+
+                                // The range of the first key in this morsel.
+                                let first_key = keys[0].drop_nulls().into_column();
+                                if first_key.is_empty() {
+                                    continue;
+                                }
+                                let encoded =
+                                    _get_rows_encoded_arr(&[first_key], &[false], &[false])?;
+                                let min_key = encoded.values_iter().min().unwrap().to_vec();
+                                let max_key = encoded.values_iter().max().unwrap().to_vec();
+
+                                batch_size += df.estimated_size();
+                                batch.push(ProbeMorsel {
+                                    morsel,
+                                    keys,
+                                    min_key,
+                                    max_key,
+                                });
+                                if batch_size < probe.left_batch_budget {
+                                    continue;
+                                }
+                            }
+
+                            if !batch.is_empty()
+                                && !probe.probe_batch(&mut batch, params, &mut send).await?
+                            {
+                                return Ok(());
+                            }
+                            batch_size = 0;
+                            if is_done {
+                                return Ok(());
+                            }
+                        }
+                    }));
+                }
+            },
+            IEJoinState::Done => unreachable!(),
+        }
+    }
+}
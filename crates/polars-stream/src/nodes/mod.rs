@@ -1,3 +1,6 @@
+pub mod distinct;
+#[cfg(feature = "dynamic_group_by")]
+pub mod dynamic_group_by;
 pub mod dynamic_slice;
 pub mod filter;
 pub mod group_by;
@@ -18,6 +21,8 @@ pub mod reduce;
 pub mod repeat;
 pub mod select;
 pub mod simple_projection;
+pub mod sort;
+pub mod sorted_window;
 pub mod streaming_slice;
 pub mod with_row_index;
 pub mod zip;
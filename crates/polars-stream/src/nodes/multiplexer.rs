@@ -4,6 +4,7 @@ use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
 
 use super::compute_node_prelude::*;
 use crate::async_primitives::wait_group::WaitGroup;
+use crate::memory::MemoryReservation;
 use crate::morsel::SourceToken;
 
 // TODO: replace this with an out-of-core buffering solution.
@@ -20,12 +21,15 @@ impl BufferedStream {
 
 pub struct MultiplexerNode {
     buffers: Vec<BufferedStream>,
+    // Accounts the buffered morsels against the query's memory budget.
+    reservation: Option<MemoryReservation>,
 }
 
 impl MultiplexerNode {
     pub fn new() -> Self {
         Self {
             buffers: Vec::default(),
+            reservation: None,
         }
     }
 }
@@ -48,6 +52,10 @@ impl ComputeNode for MultiplexerNode {
         self.buffers.resize_with(send.len(), BufferedStream::new);
         for (s, b) in send.iter().zip(&mut self.buffers) {
             if *s == PortState::Done {
+                if let (BufferedStream::Open(buf), Some(reservation)) = (&*b, &self.reservation) {
+                    let bytes = buf.iter().map(|m| m.df().estimated_size()).sum();
+                    reservation.shrink(bytes);
+                }
                 *b = BufferedStream::Closed;
             }
         }
@@ -100,12 +108,17 @@ impl ComputeNode for MultiplexerNode {
         scope: &'s TaskScope<'s, 'env>,
         recv_ports: &mut [Option<RecvPort<'_>>],
         send_ports: &mut [Option<SendPort<'_>>],
-        _state: &'s StreamingExecutionState,
+        state: &'s StreamingExecutionState,
         join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
     ) {
         assert!(recv_ports.len() == 1 && !send_ports.is_empty());
         assert!(self.buffers.len() == send_ports.len());
 
+        // Buffered morsels can't be spilled, if they don't fit the query fails.
+        let reservation = &*self
+            .reservation
+            .get_or_insert_with(|| state.memory.reserve("multiplexer", false));
+
         enum Listener<'a> {
             Active(UnboundedSender<Morsel>),
             Buffering(&'a mut VecDeque<Morsel>),
@@ -146,17 +159,27 @@ impl ComputeNode for MultiplexerNode {
 
                     let mut anyone_interested = false;
                     let mut active_listener_interested = false;
+                    let morsel_size = morsel.df().estimated_size();
                     for buf_sender in &mut buf_senders {
                         match buf_sender {
-                            Listener::Active(s) => match s.send(morsel.clone()) {
-                                Ok(_) => {
-                                    anyone_interested = true;
-                                    active_listener_interested = true;
-                                },
-                                Err(_) => *buf_sender = Listener::Inactive,
+                            Listener::Active(s) => {
+                                // Grow before sending, the receiver shrinks
+                                // the reservation again once it takes it.
+                                reservation.grow(morsel_size)?;
+                                match s.send(morsel.clone()) {
+                                    Ok(_) => {
+                                        anyone_interested = true;
+                                        active_listener_interested = true;
+                                    },
+                                    Err(_) => {
+                                        reservation.shrink(morsel_size);
+                                        *buf_sender = Listener::Inactive;
+                                    },
+                                }
                             },
                             Listener::Buffering(b) => {
                                 b.push_front(morsel.clone());
+                                reservation.grow(morsel_size)?;
                                 anyone_interested = true;
                             },
                             Listener::Inactive => {},
@@ -188,6 +211,7 @@ impl ComputeNode for MultiplexerNode {
                 join_handles.push(scope.spawn_task(TaskPriority::High, async move {
                     // First we try to flush all the old buffered data.
                     while let Some(mut morsel) = buf.pop_back() {
+                        reservation.shrink(morsel.df().estimated_size());
                         morsel.replace_source_token(buffered_source_token.clone());
                         morsel.set_consume_token(wait_group.token());
                         if sender.send(morsel).await.is_err()
@@ -200,12 +224,19 @@ impl ComputeNode for MultiplexerNode {
 
                     // Then send along data from the multiplexer.
                     while let Some(mut morsel) = rx.recv().await {
+                        reservation.shrink(morsel.df().estimated_size());
                         morsel.set_consume_token(wait_group.token());
                         if sender.send(morsel).await.is_err() {
                             break;
                         }
                         wait_group.wait().await;
                     }
+
+                    // Release the morsels we will no longer take.
+                    rx.close();
+                    while let Ok(morsel) = rx.try_recv() {
+                        reservation.shrink(morsel.df().estimated_size());
+                    }
                     Ok(())
                 }));
             }
@@ -0,0 +1,604 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::sync::Arc;
+
+use arrow::array::{BinaryArray, MutableBinaryValuesArray};
+use polars_core::chunked_array::ops::row_encode::_get_rows_encoded_arr;
+use polars_core::prelude::{
+    BinaryOffsetChunked, ChunkSort, IntoColumn, SortMultipleOptions, SortOptions,
+};
+use polars_core::utils::{accumulate_dataframes_vertical_unchecked, slice_offsets};
+use polars_utils::IdxSize;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::compute_node_prelude::*;
+use crate::async_primitives::connector::{Receiver, Sender};
+use crate::async_primitives::wait_group::WaitGroup;
+use crate::expression::StreamExpr;
+use crate::memory::{MemoryManager, MemoryReservation};
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::utils::spill::{SpillDir, SpillFile};
+
+const SORT_KEY_NAME: PlSmallStr = PlSmallStr::from_static("__POLARS_SORT_KEY");
+
+/// A sorted run, either still in memory or spilled to disk in blocks of
+/// (roughly) morsel size. The last column is the binary sort key.
+pub(crate) enum SortedRun {
+    InMemory(DataFrame),
+    Spilled { file: SpillFile, num_rows: usize },
+}
+
+/// Buffers rows which have a binary sort key as their last column, and turns
+/// them into sorted runs.
+#[derive(Default)]
+pub(crate) struct SortedRunsBuilder {
+    buffer: Vec<DataFrame>,
+    buffered_rows: usize,
+    buffered_bytes: usize,
+    runs: Vec<SortedRun>,
+    // Accounts the buffered bytes against the query's memory budget.
+    reservation: Option<MemoryReservation>,
+}
+
+impl SortedRunsBuilder {
+    /// Accounts the buffered rows against the memory budget of the query,
+    /// spilling them once the query exceeds it.
+    pub(crate) fn track_memory(&mut self, memory: &Arc<MemoryManager>, node_name: &'static str) {
+        if self.reservation.is_none() {
+            debug_assert!(self.buffered_bytes == 0);
+            self.reservation = Some(memory.reserve(node_name, true));
+        }
+    }
+
+    fn set_buffered_bytes(&mut self, bytes: usize) -> PolarsResult<()> {
+        if let Some(reservation) = &self.reservation {
+            if bytes < self.buffered_bytes {
+                reservation.shrink(self.buffered_bytes - bytes);
+            } else {
+                reservation.grow(bytes - self.buffered_bytes)?;
+            }
+        }
+        self.buffered_bytes = bytes;
+        Ok(())
+    }
+
+    /// Sorts the buffered morsels into a single run, keeping only the first
+    /// `top_k` rows if given.
+    fn sort_buffer(&mut self, top_k: Option<usize>) -> Option<DataFrame> {
+        if self.buffer.is_empty() {
+            return None;
+        }
+        self.buffered_rows = 0;
+        // Shrinking can't fail.
+        self.set_buffered_bytes(0).unwrap();
+        let mut df = accumulate_dataframes_vertical_unchecked(self.buffer.drain(..));
+        df.rechunk_mut();
+        Some(sort_by_key(&df, top_k))
+    }
+
+    /// Sorts the buffered morsels and writes them to disk as a new run.
+    fn spill_buffer(
+        &mut self,
+        spill_dir: &Arc<SpillDir>,
+        top_k: Option<usize>,
+    ) -> PolarsResult<()> {
+        let Some(run) = self.sort_buffer(top_k) else {
+            return Ok(());
+        };
+        let morsel_size = get_ideal_morsel_size();
+        let num_blocks = run.height().div_ceil(morsel_size).max(1);
+        let blocks = (0..num_blocks).map(|i| run.slice((i * morsel_size) as i64, morsel_size));
+        let file = SpillFile::write(spill_dir, run.schema().clone(), blocks)?;
+        self.runs.push(SortedRun::Spilled {
+            file,
+            num_rows: run.height(),
+        });
+        Ok(())
+    }
+
+    /// Adds the given rows, sorting the buffered rows into a run on disk once
+    /// they exceed the memory budget.
+    pub(crate) fn push(
+        &mut self,
+        df: DataFrame,
+        memory_budget: usize,
+        spill_dir: &Arc<SpillDir>,
+        top_k: Option<usize>,
+    ) -> PolarsResult<()> {
+        self.buffered_rows += df.height();
+        self.set_buffered_bytes(self.buffered_bytes + df.estimated_size())?;
+        self.buffer.push(df);
+
+        let should_spill = self.reservation.as_ref().is_some_and(|r| r.should_spill());
+        if self.buffered_bytes > memory_budget || should_spill {
+            self.spill_buffer(spill_dir, top_k)?;
+        } else if let Some(k) = top_k {
+            // Keep the buffer small if we only need the first k rows.
+            if self.buffered_rows > 2 * k.max(get_ideal_morsel_size()) {
+                let run = self.sort_buffer(top_k).unwrap();
+                self.buffered_rows = run.height();
+                self.set_buffered_bytes(run.estimated_size())?;
+                self.buffer.push(run);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns all sorted runs. The remaining buffered rows are kept in memory
+    /// unless a spill directory is given.
+    pub(crate) fn finish(
+        mut self,
+        spill_dir: Option<&Arc<SpillDir>>,
+        top_k: Option<usize>,
+    ) -> PolarsResult<Vec<SortedRun>> {
+        if let Some(spill_dir) = spill_dir {
+            self.spill_buffer(spill_dir, top_k)?;
+        } else if let Some(run) = self.sort_buffer(top_k) {
+            self.runs.push(SortedRun::InMemory(run));
+        }
+        Ok(self.runs)
+    }
+}
+
+fn sort_by_key(df: &DataFrame, top_k: Option<usize>) -> DataFrame {
+    let key = df.get_columns().last().unwrap();
+    let key_ca = key.as_materialized_series().binary_offset().unwrap();
+    let idxs = key_ca.arg_sort(SortOptions {
+        multithreaded: false,
+        limit: top_k.map(|k| k as IdxSize),
+        ..Default::default()
+    });
+    let mut out = unsafe { df.take_unchecked_impl(&idxs, false) };
+    if let Some(k) = top_k {
+        out = out.slice(0, k);
+    }
+    out.rechunk_mut();
+    out
+}
+
+struct SortSinkState {
+    by_column: Vec<StreamExpr>,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+    maintain_order: bool,
+    // If set, only the first top_k rows of the sorted output are needed.
+    top_k: Option<usize>,
+    // The memory budget for the buffered morsels of a single local sink.
+    local_budget: usize,
+    spill_dir: Arc<SpillDir>,
+    locals: Vec<SortedRunsBuilder>,
+}
+
+impl SortSinkState {
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        receivers: Vec<Receiver<Morsel>>,
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        for (mut recv, local) in receivers.into_iter().zip(&mut self.locals) {
+            let by_column = &self.by_column;
+            let descending = &self.descending;
+            let nulls_last = &self.nulls_last;
+            let maintain_order = self.maintain_order;
+            let top_k = self.top_k;
+            let local_budget = self.local_budget;
+            let spill_dir = &self.spill_dir;
+            local.track_memory(&state.memory, "sort");
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                while let Ok(morsel) = recv.recv().await {
+                    let seq = morsel.seq().to_u64();
+                    let mut df = morsel.into_df();
+                    let height = df.height();
+
+                    let mut by = Vec::with_capacity(by_column.len());
+                    for selector in by_column {
+                        let c = selector.evaluate(&df, &state.in_memory_exec_state).await?;
+                        by.push(if c.len() == 1 && height != 1 {
+                            c.new_from_index(0, height)
+                        } else {
+                            c
+                        });
+                    }
+                    let mut keys = _get_rows_encoded_arr(&by, descending, nulls_last)?;
+                    if maintain_order {
+                        keys = append_seq_to_keys(&keys, seq);
+                    }
+                    let key_ca = BinaryOffsetChunked::with_chunk(SORT_KEY_NAME, keys);
+                    unsafe { df.with_column_unchecked(key_ca.into_column()) };
+                    local.push(df, local_budget, spill_dir, top_k)?;
+                }
+
+                Ok(())
+            }));
+        }
+    }
+
+    fn into_merge_source(self, slice: Option<(i64, usize)>) -> PolarsResult<SortMergeSource> {
+        let mut runs = Vec::new();
+        for local in self.locals {
+            runs.extend(local.finish(None, self.top_k)?);
+        }
+        SortMergeSource::new(runs, slice, self.spill_dir)
+    }
+}
+
+/// Appends the morsel sequence id and row index to each key, making the keys
+/// unique and sorting equal keys in the order they were received.
+fn append_seq_to_keys(keys: &BinaryArray<i64>, seq: u64) -> BinaryArray<i64> {
+    let mut out = MutableBinaryValuesArray::<i64>::with_capacities(
+        keys.len(),
+        keys.values().len() + 2 * size_of::<u64>() * keys.len(),
+    );
+    let mut scratch = Vec::new();
+    for (i, key) in keys.values_iter().enumerate() {
+        scratch.clear();
+        scratch.extend_from_slice(key);
+        scratch.extend_from_slice(&seq.to_be_bytes());
+        scratch.extend_from_slice(&(i as u64).to_be_bytes());
+        out.push(&scratch);
+    }
+    out.into()
+}
+
+struct RunCursor {
+    run: SortedRun,
+    num_rows: usize,
+    next_block: usize,
+    block: DataFrame,
+    keys: BinaryArray<i64>,
+    row: usize,
+}
+
+impl RunCursor {
+    fn new(run: SortedRun) -> PolarsResult<Option<Self>> {
+        let num_rows = match &run {
+            SortedRun::InMemory(df) => df.height(),
+            SortedRun::Spilled { num_rows, .. } => *num_rows,
+        };
+        let mut cursor = Self {
+            run,
+            num_rows,
+            next_block: 0,
+            block: DataFrame::empty(),
+            keys: BinaryArray::new_empty(arrow::datatypes::ArrowDataType::LargeBinary),
+            row: 0,
+        };
+        Ok(cursor.load_next_block()?.then_some(cursor))
+    }
+
+    /// Loads the next non-empty block, returns false if the run is exhausted.
+    fn load_next_block(&mut self) -> PolarsResult<bool> {
+        loop {
+            let block = match &mut self.run {
+                SortedRun::InMemory(df) => {
+                    if self.next_block > 0 {
+                        return Ok(false);
+                    }
+                    core::mem::take(df)
+                },
+                SortedRun::Spilled { file, .. } => {
+                    if self.next_block >= file.num_blocks() {
+                        return Ok(false);
+                    }
+                    let range = self.next_block..self.next_block + 1;
+                    let mut block = file.read_blocks(range)?.pop().unwrap();
+                    block.rechunk_mut();
+                    block
+                },
+            };
+            self.next_block += 1;
+            if block.height() == 0 {
+                continue;
+            }
+
+            let key = block.get_columns().last().unwrap();
+            let key_ca = key.as_materialized_series().binary_offset().unwrap();
+            self.keys = key_ca.downcast_as_array().clone();
+            self.block = block;
+            self.row = 0;
+            return Ok(true);
+        }
+    }
+
+    fn head_key(&self) -> &[u8] {
+        unsafe { self.keys.value_unchecked(self.row) }
+    }
+}
+
+/// K-way merge of the sorted runs.
+pub(crate) struct SortMergeSource {
+    cursors: Vec<RunCursor>,
+    // The key of the next row of each non-exhausted run, together with the
+    // index of that run.
+    heap: BinaryHeap<Reverse<(Vec<u8>, usize)>>,
+    rows_to_skip: usize,
+    rows_left: usize,
+    seq: MorselSeq,
+    _spill_dir: Arc<SpillDir>,
+}
+
+impl SortMergeSource {
+    /// Creates a source merging the given runs, keeping only the given slice
+    /// of the merged rows.
+    pub(crate) fn new(
+        runs: Vec<SortedRun>,
+        slice: Option<(i64, usize)>,
+        spill_dir: Arc<SpillDir>,
+    ) -> PolarsResult<Self> {
+        let mut cursors = Vec::with_capacity(runs.len());
+        for run in runs {
+            if let Some(cursor) = RunCursor::new(run)? {
+                cursors.push(cursor);
+            }
+        }
+
+        let (rows_to_skip, rows_left) = match slice {
+            Some((offset, len)) => {
+                let total_rows = cursors.iter().map(|c| c.num_rows).sum();
+                slice_offsets(offset, len, total_rows)
+            },
+            None => (0, usize::MAX),
+        };
+
+        let mut heap = BinaryHeap::with_capacity(cursors.len());
+        for (i, c) in cursors.iter().enumerate() {
+            heap.push(Reverse((c.head_key().to_vec(), i)));
+        }
+
+        Ok(SortMergeSource {
+            cursors,
+            heap,
+            rows_to_skip,
+            rows_left,
+            seq: MorselSeq::default(),
+            _spill_dir: spill_dir,
+        })
+    }
+
+    pub(crate) fn is_finished(&self) -> bool {
+        self.rows_left == 0 || self.heap.is_empty()
+    }
+
+    /// Returns the next (at most) max_rows merged rows.
+    fn merge_next(&mut self, max_rows: usize) -> PolarsResult<Option<DataFrame>> {
+        let mut out = Vec::new();
+        let mut num_rows = 0;
+        while num_rows < max_rows {
+            let Some(Reverse((_, r))) = self.heap.pop() else {
+                break;
+            };
+            let cursor = &mut self.cursors[r];
+
+            // Take all rows of this run which sort before the next run's head,
+            // on ties the run with the lowest index goes first.
+            let end = match self.heap.peek() {
+                Some(Reverse((bound, bound_run))) => {
+                    let bound = bound.as_slice();
+                    let sorts_before = |key: &[u8]| key < bound || (key == bound && r < *bound_run);
+                    let (mut lo, mut hi) = (cursor.row, cursor.keys.len());
+                    while lo < hi {
+                        let mid = lo + (hi - lo) / 2;
+                        if sorts_before(unsafe { cursor.keys.value_unchecked(mid) }) {
+                            lo = mid + 1;
+                        } else {
+                            hi = mid;
+                        }
+                    }
+                    lo
+                },
+                None => cursor.keys.len(),
+            };
+            let end = end.min(cursor.row + (max_rows - num_rows));
+            debug_assert!(end > cursor.row);
+
+            out.push(cursor.block.slice(cursor.row as i64, end - cursor.row));
+            num_rows += end - cursor.row;
+            cursor.row = end;
+
+            if cursor.row < cursor.keys.len() || cursor.load_next_block()? {
+                self.heap.push(Reverse((cursor.head_key().to_vec(), r)));
+            }
+        }
+
+        if out.is_empty() {
+            return Ok(None);
+        }
+        let mut df = accumulate_dataframes_vertical_unchecked(out);
+        df = df.select_by_range(0..df.width() - 1)?;
+        Ok(Some(df))
+    }
+
+    /// Returns the next output morsel, taking the slice into account.
+    fn next_morsel(&mut self) -> PolarsResult<Option<DataFrame>> {
+        let morsel_size = get_ideal_morsel_size();
+        while self.rows_to_skip > 0 {
+            let Some(df) = self.merge_next(self.rows_to_skip.min(morsel_size))? else {
+                return Ok(None);
+            };
+            self.rows_to_skip -= df.height();
+        }
+
+        if self.rows_left == 0 {
+            return Ok(None);
+        }
+        let Some(df) = self.merge_next(self.rows_left.min(morsel_size))? else {
+            return Ok(None);
+        };
+        self.rows_left -= df.height();
+        Ok(Some(df))
+    }
+
+    /// Sends the merged rows until we are finished or a stop is requested.
+    pub(crate) async fn send_morsels(&mut self, mut send: Sender<Morsel>) -> PolarsResult<()> {
+        let source_token = SourceToken::new();
+        let wait_group = WaitGroup::default();
+        while !source_token.stop_requested() {
+            let Some(df) = self.next_morsel()? else {
+                break;
+            };
+            let mut morsel = Morsel::new(df, self.seq, source_token.clone());
+            morsel.set_consume_token(wait_group.token());
+            self.seq = self.seq.successor();
+
+            if send.send(morsel).await.is_err() {
+                break;
+            }
+            wait_group.wait().await;
+        }
+
+        Ok(())
+    }
+}
+
+enum SortState {
+    Sink(SortSinkState),
+    Source(SortMergeSource),
+    Done,
+}
+
+/// A sort which sorts morsels into runs, spilling runs to disk once the memory
+/// budget is exceeded, and produces its output by merging the runs.
+pub struct SortNode {
+    state: SortState,
+    slice: Option<(i64, usize)>,
+}
+
+impl SortNode {
+    pub fn new(
+        by_column: Vec<StreamExpr>,
+        slice: Option<(i64, usize)>,
+        sort_options: SortMultipleOptions,
+        memory_budget: usize,
+        num_pipelines: usize,
+    ) -> Self {
+        let broadcast = |v: &[bool]| {
+            if v.len() == 1 {
+                vec![v[0]; by_column.len()]
+            } else {
+                v.to_vec()
+            }
+        };
+        let descending = broadcast(&sort_options.descending);
+        let nulls_last = broadcast(&sort_options.nulls_last);
+
+        // If we only need a prefix of the output each run only needs to store
+        // that prefix.
+        let slice_end = slice
+            .and_then(|(offset, len)| (offset >= 0).then(|| (offset as usize).saturating_add(len)));
+        let limit = sort_options.limit.map(|l| l as usize);
+        let top_k = match (slice_end, limit) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+
+        let locals = (0..num_pipelines)
+            .map(|_| SortedRunsBuilder::default())
+            .collect();
+
+        Self {
+            state: SortState::Sink(SortSinkState {
+                by_column,
+                descending,
+                nulls_last,
+                maintain_order: sort_options.maintain_order,
+                top_k,
+                local_budget: memory_budget / num_pipelines,
+                spill_dir: SpillDir::new("sort"),
+                locals,
+            }),
+            slice,
+        }
+    }
+}
+
+impl ComputeNode for SortNode {
+    fn name(&self) -> &str {
+        "sort"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // State transitions.
+        match &mut self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => {
+                self.state = SortState::Done;
+            },
+            // Input is done, transition to merging the sorted runs.
+            SortState::Sink(_) if recv[0] == PortState::Done => {
+                let SortState::Sink(sink) = core::mem::replace(&mut self.state, SortState::Done)
+                else {
+                    unreachable!()
+                };
+                let source = sink.into_merge_source(self.slice)?;
+                if !source.is_finished() {
+                    self.state = SortState::Source(source);
+                }
+            },
+            SortState::Source(source) => {
+                if source.is_finished() {
+                    self.state = SortState::Done;
+                }
+            },
+            // Nothing to change.
+            SortState::Done | SortState::Sink(_) => {},
+        }
+
+        // Communicate our state.
+        match &self.state {
+            SortState::Sink(_) => {
+                send[0] = PortState::Blocked;
+                recv[0] = PortState::Ready;
+            },
+            SortState::Source(_) => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            SortState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, SortState::Sink(_))
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(send_ports.len() == 1 && recv_ports.len() == 1);
+        match &mut self.state {
+            SortState::Sink(sink) => {
+                assert!(send_ports[0].is_none());
+                sink.spawn(
+                    scope,
+                    recv_ports[0].take().unwrap().parallel(),
+                    state,
+                    join_handles,
+                )
+            },
+            SortState::Source(source) => {
+                assert!(recv_ports[0].is_none());
+                let send = send_ports[0].take().unwrap().serial();
+                join_handles.push(scope.spawn_task(TaskPriority::Low, source.send_morsels(send)));
+            },
+            SortState::Done => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_expr::prelude::PhysicalExpr;
+use polars_utils::itertools::Itertools;
+
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::async_primitives::connector::Receiver;
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::nodes::compute_node_prelude::*;
+
+/// Evaluates window expressions on an input whose rows are already grouped by
+/// the partition keys, e.g. because it is sorted by them.
+///
+/// Rows are buffered until a row with different keys came in, at which point
+/// we know the partitions before it are complete and can evaluate the window
+/// expressions on just those rows.
+pub struct SortedWindowNode {
+    buffers: PartitionBuffers,
+    exprs: Arc<[Arc<dyn PhysicalExpr>]>,
+    seq: MorselSeq,
+}
+
+impl SortedWindowNode {
+    pub fn new(
+        key: Arc<[PlSmallStr]>,
+        exprs: Vec<Arc<dyn PhysicalExpr>>,
+        input_schema: &Schema,
+    ) -> Self {
+        Self {
+            buffers: PartitionBuffers {
+                key,
+                buffer: DataFrame::empty_with_schema(input_schema),
+                rows_at_last_attempt: 0,
+                finished: false,
+            },
+            exprs: exprs.into(),
+            seq: MorselSeq::default(),
+        }
+    }
+}
+
+struct PartitionBuffers {
+    key: Arc<[PlSmallStr]>,
+
+    // The rows of the partitions we haven't seen the end of yet.
+    buffer: DataFrame,
+    rows_at_last_attempt: usize,
+
+    finished: bool,
+}
+
+impl PartitionBuffers {
+    fn push(&mut self, df: DataFrame) -> PolarsResult<()> {
+        if df.height() > 0 {
+            self.buffer.vstack_mut_owned(df)?;
+        }
+        Ok(())
+    }
+
+    /// Whether enough rows came in since we last looked for complete
+    /// partitions to look again. Doubling keeps the total work linear if
+    /// partitions are large compared to the morsels.
+    fn should_attempt(&self) -> bool {
+        self.buffer.height() >= (2 * self.rows_at_last_attempt).max(get_ideal_morsel_size())
+    }
+
+    /// Returns the rows of the partitions which can no longer grow, that is
+    /// all but those with the keys of the last row.
+    fn take_complete_partitions(&mut self) -> PolarsResult<Option<DataFrame>> {
+        let height = self.buffer.height();
+        if height == 0 {
+            return Ok(None);
+        }
+
+        let mut is_last_partition = BooleanChunked::full(PlSmallStr::EMPTY, true, height);
+        for name in self.key.iter() {
+            let column = self.buffer.column(name)?;
+            is_last_partition = is_last_partition & column.equal_missing(&column.tail(Some(1)))?;
+        }
+        // As partitions are contiguous, this is the start of the last one.
+        let split = is_last_partition.first_true_idx().unwrap();
+        self.rows_at_last_attempt = height - split;
+        if split == 0 {
+            return Ok(None);
+        }
+
+        let (complete, rest) = self.buffer.split_at(split as i64);
+        self.buffer = rest;
+        Ok(Some(complete))
+    }
+
+    fn take_all(&mut self) -> Option<DataFrame> {
+        let empty = self.buffer.clear();
+        let df = std::mem::replace(&mut self.buffer, empty);
+        (df.height() > 0).then_some(df)
+    }
+}
+
+impl ComputeNode for SortedWindowNode {
+    fn name(&self) -> &str {
+        "sorted-window"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        if send[0] == PortState::Done || self.buffers.finished {
+            recv[0] = PortState::Done;
+            send[0] = PortState::Done;
+        } else if recv[0] == PortState::Done {
+            // We still have to send the partitions left in the buffer.
+        } else {
+            recv.swap_with_slice(send);
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+
+        let senders = send_ports[0].take().unwrap().parallel();
+        let mut recv = recv_ports[0].take().map(|p| p.serial());
+
+        let (mut distributor, dist_recv) =
+            distributor_channel(senders.len(), *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+
+        let buffers = &mut self.buffers;
+        let seq = &mut self.seq;
+        join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+            // If a stop was requested, we need to buffer the remaining morsels
+            // and trigger a phase transition.
+            async fn buffer_input(
+                recv: &mut Receiver<Morsel>,
+                buffers: &mut PartitionBuffers,
+            ) -> PolarsResult<()> {
+                if let Ok(morsel) = recv.recv().await {
+                    morsel.source_token().stop();
+                    buffers.push(morsel.into_df())?;
+                    while let Ok(morsel) = recv.recv().await {
+                        buffers.push(morsel.into_df())?;
+                    }
+                }
+                Ok(())
+            }
+
+            let source_token = SourceToken::new();
+            let Some(recv) = &mut recv else {
+                // The input is done, so the partitions left are complete.
+                if let Some(df) = buffers.take_all() {
+                    let morsel = Morsel::new(df, *seq, source_token.clone());
+                    *seq = seq.successor();
+                    let _ = distributor.send(morsel).await;
+                }
+                buffers.finished = true;
+                return Ok(());
+            };
+
+            loop {
+                if source_token.stop_requested() {
+                    return buffer_input(recv, buffers).await;
+                }
+                let Ok(morsel) = recv.recv().await else {
+                    return Ok(());
+                };
+                buffers.push(morsel.into_df())?;
+                if !buffers.should_attempt() {
+                    continue;
+                }
+                if let Some(df) = buffers.take_complete_partitions()? {
+                    let morsel = Morsel::new(df, *seq, source_token.clone());
+                    *seq = seq.successor();
+                    if distributor.send(morsel).await.is_err() {
+                        return Ok(());
+                    }
+                }
+            }
+        }));
+
+        join_handles.extend(
+            dist_recv
+                .into_iter()
+                .zip(senders)
+                .map(|(mut recv, mut send)| {
+                    let exprs = self.exprs.clone();
+                    scope.spawn_task(TaskPriority::High, async move {
+                        while let Ok(morsel) = recv.recv().await {
+                            let morsel = morsel.try_map(|df| {
+                                // Window expressions cache their groups in the
+                                // state, which are only valid for this morsel.
+                                let state = ExecutionState::new();
+                                let columns = exprs
+                                    .iter()
+                                    .map(|e| e.evaluate(&df, &state))
+                                    .try_collect_vec()?;
+                                DataFrame::new_with_broadcast(columns)
+                            })?;
+                            if send.send(morsel).await.is_err() {
+                                break;
+                            }
+                        }
+                        Ok(())
+                    })
+                }),
+        );
+    }
+}
@@ -32,12 +32,19 @@ impl NodeStyle {
             | K::InMemorySink { .. }
             | K::Sort { .. }
             | K::GroupBy { .. }
+            | K::Distinct { .. }
             | K::EquiJoin { .. }
             | K::SemiAntiJoin { .. }
             | K::InMemoryJoin { .. }
             | K::Multiplexer { .. } => Self::MemoryIntensive,
             #[cfg(feature = "merge_sorted")]
             K::MergeSorted { .. } => Self::MemoryIntensive,
+            #[cfg(feature = "asof_join")]
+            K::AsOfJoin { .. } => Self::MemoryIntensive,
+            #[cfg(feature = "iejoin")]
+            K::IEJoin { .. } => Self::MemoryIntensive,
+            #[cfg(feature = "dynamic_group_by")]
+            K::DynamicGroupBy { .. } => Self::MemoryIntensive,
             _ => Self::Generic,
         }
     }
@@ -261,6 +268,8 @@ fn visualize_plan_rec(
             FileType::Csv(_) => ("csv-sink".to_string(), from_ref(input)),
             #[cfg(feature = "json")]
             FileType::Json(_) => ("ndjson-sink".to_string(), from_ref(input)),
+            #[cfg(feature = "avro")]
+            FileType::Avro(_) => ("avro-sink".to_string(), from_ref(input)),
             #[allow(unreachable_patterns)]
             _ => todo!(),
         },
@@ -285,6 +294,8 @@ fn visualize_plan_rec(
                 FileType::Csv(_) => (format!("{variant}[csv]"), from_ref(input)),
                 #[cfg(feature = "json")]
                 FileType::Json(_) => (format!("{variant}[ndjson]"), from_ref(input)),
+                #[cfg(feature = "avro")]
+                FileType::Avro(_) => (format!("{variant}[avro]"), from_ref(input)),
                 #[allow(unreachable_patterns)]
                 _ => todo!(),
             }
@@ -346,6 +357,7 @@ fn visualize_plan_rec(
             missing_columns_policy: _,
             forbid_extra_columns: _,
             deletion_files,
+            file_statistics,
             file_schema: _,
         } => {
             let mut out = format!("multi-scan[{}]", file_reader_builder.reader_name());
@@ -404,6 +416,10 @@ fn visualize_plan_rec(
                 write!(f, "\n{deletion_files}").unwrap();
             }
 
+            if let Some(file_statistics) = file_statistics {
+                write!(f, "\n{file_statistics}").unwrap();
+            }
+
             (out, &[][..])
         },
         PhysNodeKind::GroupBy { input, key, aggs } => (
@@ -414,6 +430,42 @@ fn visualize_plan_rec(
             ),
             from_ref(input),
         ),
+        PhysNodeKind::Distinct {
+            input,
+            key,
+            keep,
+            maintain_order: _,
+        } => (
+            format!("distinct\\nkeep: {keep:?}\\nkey:\\n{}", key.join("\\n")),
+            from_ref(input),
+        ),
+        PhysNodeKind::SortedWindow { input, key, exprs } => (
+            format!(
+                "sorted-window\\nkey: {}\\n{}",
+                key.join(", "),
+                fmt_exprs_to_label(exprs, expr_arena, FormatExprStyle::Select)
+            ),
+            from_ref(input),
+        ),
+        #[cfg(feature = "dynamic_group_by")]
+        PhysNodeKind::DynamicGroupBy {
+            input,
+            aggs,
+            options,
+        } => {
+            let (name, index_column) = match (&options.dynamic, &options.rolling) {
+                (Some(dynamic), _) => ("group-by-dynamic", &dynamic.index_column),
+                (_, Some(rolling)) => ("rolling", &rolling.index_column),
+                _ => unreachable!(),
+            };
+            (
+                format!(
+                    "{name}\\nindex column: {index_column}\\naggs:\\n{}",
+                    fmt_exprs_to_label(aggs, expr_arena, FormatExprStyle::Select)
+                ),
+                from_ref(input),
+            )
+        },
         PhysNodeKind::InMemoryJoin {
             input_left,
             input_right,
@@ -486,6 +538,53 @@ fn visualize_plan_rec(
             input_right,
             args: _,
         } => ("cross-join".to_string(), &[*input_left, *input_right][..]),
+        #[cfg(feature = "asof_join")]
+        PhysNodeKind::AsOfJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args: _,
+        } => {
+            let mut label = "asof-join".to_string();
+            write!(
+                label,
+                r"\nleft_on:\n{}",
+                fmt_exprs_to_label(left_on, expr_arena, FormatExprStyle::NoAliases)
+            )
+            .unwrap();
+            write!(
+                label,
+                r"\nright_on:\n{}",
+                fmt_exprs_to_label(right_on, expr_arena, FormatExprStyle::NoAliases)
+            )
+            .unwrap();
+            (label, &[*input_left, *input_right][..])
+        },
+        #[cfg(feature = "iejoin")]
+        PhysNodeKind::IEJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args: _,
+            options: _,
+        } => {
+            let mut label = "iejoin".to_string();
+            write!(
+                label,
+                r"\nleft_on:\n{}",
+                fmt_exprs_to_label(left_on, expr_arena, FormatExprStyle::NoAliases)
+            )
+            .unwrap();
+            write!(
+                label,
+                r"\nright_on:\n{}",
+                fmt_exprs_to_label(right_on, expr_arena, FormatExprStyle::NoAliases)
+            )
+            .unwrap();
+            (label, &[*input_left, *input_right][..])
+        },
         #[cfg(feature = "merge_sorted")]
         PhysNodeKind::MergeSorted {
             input_left,
@@ -0,0 +1,82 @@
+use std::sync::{Arc, Mutex};
+
+use polars_core::config;
+use polars_core::schema::SchemaRef;
+use polars_error::PolarsResult;
+use polars_plan::dsl::Expr;
+use polars_plan::plans::{AnonymousScan, AnonymousScanArgs};
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::execute::StreamingExecutionState;
+use crate::nodes::io_sources::batch::GetBatchFn;
+use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
+
+/// Creates a reader for an anonymous scan. The projection, predicate and end of the
+/// slice are passed on to the scan function, the multiscan applies the predicate and
+/// slice again in case the function ignored them.
+pub fn anonymous_scan_to_reader_builder(
+    function: Arc<dyn AnonymousScan>,
+    name: &str,
+    file_schema: SchemaRef,
+    output_schema: Option<SchemaRef>,
+    with_columns: Option<Arc<[PlSmallStr]>>,
+    predicate: Option<Expr>,
+    n_rows: Option<usize>,
+) -> PolarsResult<Arc<dyn FileReaderBuilder>> {
+    let reader_schema = match &with_columns {
+        Some(columns) => Arc::new(file_schema.try_project(columns.iter())?),
+        None => file_schema.clone(),
+    };
+
+    // The number of rows we still need, and whether the function is exhausted.
+    let state = Mutex::new((n_rows, false));
+    let get_batch_fn = Box::new(move |_state: &StreamingExecutionState| {
+        let mut state = state.lock().unwrap();
+        let (rows_left, finished) = &mut *state;
+        if *finished || *rows_left == Some(0) {
+            return Ok(None);
+        }
+
+        let args = AnonymousScanArgs {
+            n_rows: *rows_left,
+            with_columns: with_columns.clone(),
+            schema: file_schema.clone(),
+            output_schema: output_schema.clone(),
+            predicate: predicate.clone(),
+        };
+        let df = if function.allows_batched_scan() {
+            function.next_batch(args)?
+        } else {
+            *finished = true;
+            Some(function.scan(args)?)
+        };
+        let Some(mut df) = df else {
+            *finished = true;
+            return Ok(None);
+        };
+
+        if let Some(rows_left) = rows_left {
+            df = df.slice(0, *rows_left);
+            *rows_left -= df.height();
+        }
+        Ok(Some(df))
+    }) as GetBatchFn;
+
+    use crate::nodes::io_sources::batch::builder::BatchFnReaderBuilder;
+    use crate::nodes::io_sources::batch::{BatchFnReader, GetBatchState};
+
+    let name = PlSmallStr::from_str(name);
+    let reader = BatchFnReader {
+        name: name.clone(),
+        output_schema: Some(reader_schema),
+        get_batch_state: Some(GetBatchState::from(get_batch_fn)),
+        execution_state: None,
+        verbose: config::verbose(),
+    };
+
+    Ok(Arc::new(BatchFnReaderBuilder {
+        name,
+        reader: Mutex::new(Some(reader)),
+        execution_state: Default::default(),
+    }) as Arc<dyn FileReaderBuilder>)
+}
@@ -2,12 +2,12 @@ use std::sync::Arc;
 
 use polars_core::chunked_array::cast::CastOptions;
 use polars_core::frame::DataFrame;
-use polars_core::prelude::{DataType, Field, InitHashMaps, PlHashMap, PlHashSet};
+use polars_core::prelude::{DataType, Field, InitHashMaps, PlHashMap, PlHashSet, PlIndexSet};
 use polars_core::schema::{Schema, SchemaExt};
 use polars_error::PolarsResult;
 use polars_expr::state::ExecutionState;
 use polars_expr::{ExpressionConversionState, create_physical_expr};
-use polars_ops::frame::{JoinArgs, JoinType};
+use polars_ops::frame::{JoinArgs, JoinType, MaintainOrderJoin};
 use polars_plan::plans::AExpr;
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
 use polars_plan::prelude::*;
@@ -44,6 +44,9 @@ struct LowerExprContext<'a> {
     expr_arena: &'a mut Arena<AExpr>,
     phys_sm: &'a mut SlotMap<PhysNodeKey, PhysNode>,
     cache: &'a mut ExprCache,
+    // The columns by which the input of the lowered expressions is known to
+    // be sorted, if any.
+    input_sortedness: Option<(PhysStream, &'a [SortedColumn])>,
 }
 
 impl<'a> From<LowerExprContext<'a>> for StreamingLowerIRContext {
@@ -529,6 +532,242 @@ fn lower_unary_reduce_node(
     Ok((reduce_stream, out_node))
 }
 
+/// Returns whether rows with equal values for the key columns are contiguous in
+/// the given stream, e.g. because it is sorted by them.
+fn is_grouped_by(stream: PhysStream, key: &[PlSmallStr], ctx: &LowerExprContext) -> bool {
+    if let Some((sorted_stream, sorted)) = ctx.input_sortedness {
+        if stream == sorted_stream {
+            // The first sort columns have to be exactly the key columns.
+            let Some(prefix) = sorted.get(..key.len()) else {
+                return false;
+            };
+            let prefix: PlHashSet<&PlSmallStr> = prefix
+                .iter()
+                .map(|c| &c.name)
+                .filter(|name| key.contains(name))
+                .collect();
+            return prefix.len() == key.len();
+        }
+    }
+
+    match &ctx.phys_sm[stream.node].kind {
+        PhysNodeKind::Select {
+            input,
+            selectors,
+            extend_original,
+        } => {
+            let key_is_kept =
+                key.iter()
+                    .all(|k| match selectors.iter().find(|e| e.output_name() == k) {
+                        Some(e) => {
+                            matches!(ctx.expr_arena.get(e.node()), AExpr::Column(name) if name == k)
+                        },
+                        None => *extend_original,
+                    });
+            key_is_kept && is_grouped_by(*input, key, ctx)
+        },
+        PhysNodeKind::Filter { input, .. }
+        | PhysNodeKind::SimpleProjection { input, .. }
+        | PhysNodeKind::StreamingSlice { input, .. }
+        | PhysNodeKind::Multiplexer { input } => is_grouped_by(*input, key, ctx),
+        _ => false,
+    }
+}
+
+/// Returns the partition columns of a window expression that can be evaluated
+/// one partition at a time, if the input is grouped by them.
+fn sorted_window_key(window: &AExpr, ctx: &LowerExprContext) -> Option<Vec<PlSmallStr>> {
+    let AExpr::Window {
+        function,
+        partition_by,
+        order_by: _,
+        options: WindowType::Over(WindowMapping::GroupsToRows | WindowMapping::Join),
+    } = window
+    else {
+        return None;
+    };
+    // A nested window could be partitioned differently.
+    if ctx
+        .expr_arena
+        .iter(*function)
+        .any(|(_, e)| matches!(e, AExpr::Window { .. }))
+    {
+        return None;
+    }
+    let mut key = Vec::with_capacity(partition_by.len());
+    for k in partition_by {
+        let AExpr::Column(name) = ctx.expr_arena.get(*k) else {
+            return None;
+        };
+        if !key.contains(name) {
+            key.push(name.clone());
+        }
+    }
+    (!key.is_empty()).then_some(key)
+}
+
+// Evaluates a window expression on the complete partitions of an input which
+// is grouped by the partition columns, and returns a node corresponding to the
+// column to select from the resulting stream.
+fn lower_sorted_window(
+    input: PhysStream,
+    window: Node,
+    key: Vec<PlSmallStr>,
+    ctx: &mut LowerExprContext,
+) -> PolarsResult<(PhysStream, Node)> {
+    // Only buffer the columns we need.
+    let select_names: PlIndexSet<PlSmallStr> =
+        polars_plan::utils::aexpr_to_leaf_names_iter(window, ctx.expr_arena).collect();
+    let select_exprs = select_names
+        .into_iter()
+        .map(|name| {
+            ExprIR::new(
+                ctx.expr_arena.add(AExpr::Column(name.clone())),
+                OutputName::ColumnLhs(name),
+            )
+        })
+        .collect_vec();
+    let input = build_select_stream_with_ctx(input, &select_exprs, ctx)?;
+
+    let out_name = unique_column_name();
+    let expr_ir = ExprIR::new(window, OutputName::Alias(out_name.clone()));
+    let output_schema = schema_for_select(input, std::slice::from_ref(&expr_ir), ctx)?;
+    let kind = PhysNodeKind::SortedWindow {
+        input,
+        key: key.into(),
+        exprs: vec![expr_ir],
+    };
+    let node_key = ctx.phys_sm.insert(PhysNode::new(output_schema, kind));
+    let out_node = ctx.expr_arena.add(AExpr::Column(out_name));
+    Ok((PhysStream::first(node_key), out_node))
+}
+
+/// Returns whether a window expression is an aggregation mapped back to the
+/// rows of each partition, which we can compute with a group-by.
+fn is_group_by_window(
+    input: PhysStream,
+    window: Node,
+    ctx: &mut LowerExprContext,
+) -> PolarsResult<bool> {
+    let AExpr::Window {
+        function,
+        partition_by,
+        order_by: None,
+        options: WindowType::Over(WindowMapping::GroupsToRows),
+    } = ctx.expr_arena.get(window).clone()
+    else {
+        return Ok(false);
+    };
+    if partition_by.is_empty()
+        || !is_scalar_ae(function, ctx.expr_arena)
+        || is_input_independent_ctx(function, ctx)
+        || partition_by.iter().any(|k| {
+            !is_elementwise_rec_cached(*k, ctx.expr_arena, ctx.cache)
+                || is_input_independent_ctx(*k, ctx)
+        })
+    {
+        return Ok(false);
+    }
+
+    // The aggregation has to produce exactly what the window would broadcast.
+    let input_schema = &ctx.phys_sm[input.node].output_schema;
+    let window_dtype = ExprIR::from_node(window, ctx.expr_arena)
+        .dtype(input_schema, Context::Default, ctx.expr_arena)?
+        .clone();
+    let agg_dtype = ExprIR::from_node(function, ctx.expr_arena)
+        .dtype(input_schema, Context::Aggregation, ctx.expr_arena)?
+        .clone();
+    Ok(window_dtype == agg_dtype)
+}
+
+// Lowers a window expression mapping an aggregation back to the rows of each
+// partition to a group-by on the partition keys, which is joined back onto the
+// keys of the input. Returns the join and a node corresponding to the column
+// to select from it.
+fn lower_group_by_window(
+    input: PhysStream,
+    window: Node,
+    ctx: &mut LowerExprContext,
+) -> PolarsResult<(PhysStream, Node)> {
+    let AExpr::Window {
+        function,
+        partition_by,
+        ..
+    } = ctx.expr_arena.get(window).clone()
+    else {
+        unreachable!()
+    };
+
+    let keys = partition_by
+        .iter()
+        .map(|k| ExprIR::new(*k, OutputName::Alias(unique_column_name())))
+        .collect_vec();
+    let out_name = unique_column_name();
+    let agg = ExprIR::new(function, OutputName::Alias(out_name.clone()));
+    let agg_dtype = agg
+        .dtype(
+            &ctx.phys_sm[input.node].output_schema,
+            Context::Aggregation,
+            ctx.expr_arena,
+        )?
+        .clone();
+    let agg_field = Field::new(out_name.clone(), agg_dtype);
+
+    let key_stream = build_select_stream_with_ctx(input, &keys, ctx)?;
+    let key_schema = ctx.phys_sm[key_stream.node].output_schema.clone();
+    let group_by_output_schema = Arc::new(
+        key_schema
+            .iter_fields()
+            .chain([agg_field.clone()])
+            .collect(),
+    );
+    let group_by_stream = build_group_by_stream(
+        input,
+        &keys,
+        std::slice::from_ref(&agg),
+        group_by_output_schema,
+        false,
+        Arc::new(GroupbyOptions::default()),
+        None,
+        ctx.expr_arena,
+        ctx.phys_sm,
+        ctx.cache,
+        StreamingLowerIRContext::from(&*ctx),
+    )?;
+
+    let key_cols = keys
+        .iter()
+        .map(|k| {
+            let name = k.output_name().clone();
+            ExprIR::new(
+                ctx.expr_arena.add(AExpr::Column(name.clone())),
+                OutputName::ColumnLhs(name),
+            )
+        })
+        .collect_vec();
+    // Null keys form a partition of their own, and every row of the input
+    // gets the aggregate of its partition in the original order.
+    let output_schema = Arc::new(key_schema.iter_fields().chain([agg_field]).collect());
+    let kind = PhysNodeKind::EquiJoin {
+        input_left: key_stream,
+        input_right: group_by_stream,
+        left_on: key_cols.clone(),
+        right_on: key_cols,
+        args: JoinArgs {
+            how: JoinType::Left,
+            validation: Default::default(),
+            suffix: None,
+            slice: None,
+            nulls_equal: true,
+            coalesce: Default::default(),
+            maintain_order: MaintainOrderJoin::Left,
+        },
+    };
+    let node_key = ctx.phys_sm.insert(PhysNode::new(output_schema, kind));
+    let out_node = ctx.expr_arena.add(AExpr::Column(out_name));
+    Ok((PhysStream::first(node_key), out_node))
+}
+
 // In the recursive lowering we don't bother with named expressions at all, so
 // we work directly with Nodes.
 #[recursive::recursive]
@@ -1192,10 +1431,27 @@ fn lower_exprs_with_ctx(
                 transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
             },
 
-            AExpr::AnonymousFunction { .. }
-            | AExpr::Function { .. }
-            | AExpr::Window { .. }
-            | AExpr::Gather { .. } => {
+            ref window @ AExpr::Window { .. } => {
+                let lowered = if let Some(key) =
+                    sorted_window_key(window, ctx).filter(|key| is_grouped_by(input, key, ctx))
+                {
+                    Some(lower_sorted_window(input, expr, key, ctx)?)
+                } else if is_group_by_window(input, expr, ctx)? {
+                    Some(lower_group_by_window(input, expr, ctx)?)
+                } else {
+                    None
+                };
+                if let Some((stream, out_node)) = lowered {
+                    input_streams.insert(stream);
+                    transformed_exprs.push(out_node);
+                } else {
+                    let out_name = unique_column_name();
+                    fallback_subset.push(ExprIR::new(expr, OutputName::Alias(out_name.clone())));
+                    transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
+                }
+            },
+
+            AExpr::AnonymousFunction { .. } | AExpr::Function { .. } | AExpr::Gather { .. } => {
                 let out_name = unique_column_name();
                 fallback_subset.push(ExprIR::new(expr, OutputName::Alias(out_name.clone())));
                 transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
@@ -1340,6 +1596,7 @@ pub fn lower_exprs(
         phys_sm,
         cache: expr_cache,
         prepare_visualization: ctx.prepare_visualization,
+        input_sortedness: None,
     };
     let node_exprs = exprs.iter().map(|e| e.node()).collect_vec();
     let (transformed_input, transformed_exprs) =
@@ -1353,10 +1610,12 @@ pub fn lower_exprs(
 }
 
 /// Builds a new selection node given an input stream and the expressions to
-/// select for, if needed.
+/// select for, if needed. The input is sorted by `input_sortedness`, which may
+/// be empty if that is not known.
 pub fn build_select_stream(
     input: PhysStream,
     exprs: &[ExprIR],
+    input_sortedness: &[SortedColumn],
     expr_arena: &mut Arena<AExpr>,
     phys_sm: &mut SlotMap<PhysNodeKey, PhysNode>,
     expr_cache: &mut ExprCache,
@@ -1367,6 +1626,7 @@ pub fn build_select_stream(
         phys_sm,
         cache: expr_cache,
         prepare_visualization: ctx.prepare_visualization,
+        input_sortedness: Some((input, input_sortedness)),
     };
     build_select_stream_with_ctx(input, exprs, &mut ctx)
 }
@@ -1376,6 +1636,7 @@ pub fn build_select_stream(
 pub fn build_length_preserving_select_stream(
     input: PhysStream,
     exprs: &[ExprIR],
+    input_sortedness: &[SortedColumn],
     expr_arena: &mut Arena<AExpr>,
     phys_sm: &mut SlotMap<PhysNodeKey, PhysNode>,
     expr_cache: &mut ExprCache,
@@ -1386,6 +1647,7 @@ pub fn build_length_preserving_select_stream(
         phys_sm,
         cache: expr_cache,
         prepare_visualization: ctx.prepare_visualization,
+        input_sortedness: Some((input, input_sortedness)),
     };
     let already_length_preserving = exprs
         .iter()
@@ -395,6 +395,33 @@ fn try_lower_elementwise_scalar_agg_expr(
     }
 }
 
+/// Builds a streaming `group_by_dynamic` or `rolling` without keys, which
+/// evaluates its aggregations on each window as soon as it is complete.
+#[cfg(feature = "dynamic_group_by")]
+fn build_dynamic_group_by_stream(
+    input: PhysStream,
+    aggs: &[ExprIR],
+    output_schema: Arc<Schema>,
+    options: Arc<GroupbyOptions>,
+    phys_sm: &mut SlotMap<PhysNodeKey, PhysNode>,
+) -> PolarsResult<PhysStream> {
+    let slice = options.slice;
+    let node = phys_sm.insert(PhysNode::new(
+        output_schema,
+        PhysNodeKind::DynamicGroupBy {
+            input,
+            aggs: aggs.to_vec(),
+            options,
+        },
+    ));
+    let stream = PhysStream::first(node);
+    Ok(if let Some((offset, len)) = slice {
+        build_slice_stream(stream, offset, len, phys_sm)
+    } else {
+        stream
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 fn try_build_streaming_group_by(
     input: PhysStream,
@@ -488,8 +515,16 @@ fn try_build_streaming_group_by(
         input_exprs.push(ExprIR::new(node, OutputName::Alias(name.clone())));
     }
 
-    let pre_select =
-        build_select_stream(input, &input_exprs, expr_arena, phys_sm, expr_cache, ctx).ok()?;
+    let pre_select = build_select_stream(
+        input,
+        &input_exprs,
+        &[],
+        expr_arena,
+        phys_sm,
+        expr_cache,
+        ctx,
+    )
+    .ok()?;
 
     let input_schema = &phys_sm[pre_select.node].output_schema;
     let group_by_output_schema = compute_output_schema(
@@ -510,6 +545,7 @@ fn try_build_streaming_group_by(
     let post_select = build_select_stream(
         PhysStream::first(agg_node),
         &trans_output_exprs,
+        &[],
         expr_arena,
         phys_sm,
         expr_cache,
@@ -537,6 +573,14 @@ pub fn build_group_by_stream(
     expr_cache: &mut ExprCache,
     ctx: StreamingLowerIRContext,
 ) -> PolarsResult<PhysStream> {
+    #[cfg(feature = "dynamic_group_by")]
+    if (options.dynamic.is_some() || options.rolling.is_some())
+        && keys.is_empty()
+        && apply.is_none()
+    {
+        return build_dynamic_group_by_stream(input, aggs, output_schema, options, phys_sm);
+    }
+
     let streaming = try_build_streaming_group_by(
         input,
         keys,
@@ -1,28 +1,29 @@
 use std::sync::Arc;
 
-use parking_lot::Mutex;
 use polars_core::config;
-use polars_core::frame::{DataFrame, UniqueKeepStrategy};
-use polars_core::prelude::{DataType, InitHashMaps, PlHashMap, PlHashSet, PlIndexMap};
+use polars_core::frame::DataFrame;
+use polars_core::prelude::{InitHashMaps, PlHashMap, PlIndexMap};
 use polars_core::schema::Schema;
-use polars_error::{PolarsResult, polars_bail};
-use polars_expr::state::ExecutionState;
-use polars_mem_engine::create_physical_plan;
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
+#[cfg(any(feature = "asof_join", feature = "iejoin"))]
+use polars_ops::frame::{JoinArgs, JoinType};
+#[cfg(any(feature = "asof_join", feature = "iejoin"))]
+use polars_plan::dsl::JoinTypeOptionsIR;
 use polars_plan::dsl::deletion::DeletionFilesList;
 use polars_plan::dsl::{
     ExtraColumnsPolicy, FileScanIR, FileSinkType, PartitionSinkTypeIR, PartitionVariantIR,
-    SinkTypeIR,
+    ScanSources, SinkTypeIR,
 };
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
 use polars_plan::plans::{
-    AExpr, Context, FunctionIR, IR, IRAggExpr, LiteralValue, write_ir_non_recursive,
+    AExpr, Context, FunctionIR, IR, output_sortedness, write_ir_non_recursive,
 };
-use polars_plan::prelude::GroupbyOptions;
+use polars_utils::IdxSize;
 use polars_utils::arena::{Arena, Node};
 use polars_utils::itertools::Itertools;
+use polars_utils::plpath::PlPath;
 use polars_utils::slice_enum::Slice;
 use polars_utils::unique_id::UniqueId;
-use polars_utils::{IdxSize, unique_column_name};
 use slotmap::SlotMap;
 
 use super::{PhysNode, PhysNodeKey, PhysNodeKind, PhysStream};
@@ -34,7 +35,6 @@ use crate::physical_plan::lower_expr::{
     is_elementwise_rec_cached, lower_exprs,
 };
 use crate::physical_plan::lower_group_by::build_group_by_stream;
-use crate::utils::late_materialized_df::LateMaterializedDataFrame;
 
 /// Creates a new PhysStream which outputs a slice of the input stream.
 pub fn build_slice_stream(
@@ -65,6 +65,52 @@ pub fn build_slice_stream(
     }
 }
 
+/// Whether an as-of join can be executed by the streaming as-of join node.
+#[cfg(feature = "asof_join")]
+fn is_streamable_asof_join(
+    args: &JoinArgs,
+    left_on: &[ExprIR],
+    right_on: &[ExprIR],
+    options: &Option<JoinTypeOptionsIR>,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    let JoinType::AsOf(asof_options) = &args.how else {
+        return false;
+    };
+    let is_column =
+        |e: &[ExprIR]| e.len() == 1 && matches!(expr_arena.get(e[0].node()), AExpr::Column(_));
+
+    let by_matches = match (&asof_options.left_by, &asof_options.right_by) {
+        (Some(left_by), Some(right_by)) => !left_by.is_empty() && left_by.len() == right_by.len(),
+        (None, None) => true,
+        _ => false,
+    };
+
+    is_column(left_on)
+        && is_column(right_on)
+        && options.is_none()
+        && by_matches
+        && !args.validation.needs_checks()
+}
+
+/// Whether an inequality join can be executed by the streaming IEJoin node,
+/// which evaluates the keys on each morsel separately.
+#[cfg(feature = "iejoin")]
+fn is_streamable_ie_join(
+    args: &JoinArgs,
+    left_on: &[ExprIR],
+    right_on: &[ExprIR],
+    expr_arena: &Arena<AExpr>,
+    expr_cache: &mut ExprCache,
+) -> bool {
+    args.how == JoinType::IEJoin
+        && !args.validation.needs_checks()
+        && left_on
+            .iter()
+            .chain(right_on)
+            .all(|e| is_elementwise_rec_cached(e.node(), expr_arena, expr_cache))
+}
+
 /// Creates a new PhysStream which is filters the input stream.
 fn build_filter_stream(
     input: PhysStream,
@@ -107,6 +153,7 @@ fn build_filter_stream(
     build_select_stream(
         PhysStream::first(post_filter),
         &trans_cols_and_predicate,
+        &[],
         expr_arena,
         phys_sm,
         expr_cache,
@@ -161,9 +208,16 @@ pub fn lower_ir(
 
         IR::Select { input, expr, .. } => {
             let selectors = expr.clone();
+            let input_sortedness = output_sortedness(*input, ir_arena, expr_arena);
             let phys_input = lower_ir!(*input)?;
             return build_select_stream(
-                phys_input, &selectors, expr_arena, phys_sm, expr_cache, ctx,
+                phys_input,
+                &selectors,
+                &input_sortedness,
+                expr_arena,
+                phys_sm,
+                expr_cache,
+                ctx,
             );
         },
 
@@ -185,6 +239,7 @@ pub fn lower_ir(
             // We already handled the all-streamable case above, so things get more complicated.
             // For simplicity we just do a normal select with all the original columns prepended.
             let exprs = exprs.clone();
+            let input_sortedness = output_sortedness(*input, ir_arena, expr_arena);
             let phys_input = lower_ir!(*input)?;
             let input_schema = &phys_sm[phys_input.node].output_schema;
             let mut selectors = PlIndexMap::with_capacity(input_schema.len() + exprs.len());
@@ -201,7 +256,13 @@ pub fn lower_ir(
             }
             let selectors = selectors.into_values().collect_vec();
             return build_length_preserving_select_stream(
-                phys_input, &selectors, expr_arena, phys_sm, expr_cache, ctx,
+                phys_input,
+                &selectors,
+                &input_sortedness,
+                expr_arena,
+                phys_sm,
+                expr_cache,
+                ctx,
             );
         },
 
@@ -475,10 +536,10 @@ pub fn lower_ir(
 
         v @ IR::Scan { .. } => {
             let IR::Scan {
-                sources: scan_sources,
+                sources: mut scan_sources,
                 file_info,
                 mut hive_parts,
-                output_schema: _,
+                output_schema: scan_output_schema,
                 scan_type,
                 predicate,
                 unified_scan_args,
@@ -487,6 +548,12 @@ pub fn lower_ir(
                 unreachable!();
             };
 
+            if let FileScanIR::Anonymous { .. } = &*scan_type {
+                // Give multiscan a single scan source. (It doesn't actually read from this).
+                scan_sources =
+                    ScanSources::Paths(Arc::from([PlPath::from_str("anonymous-scan-0")]));
+            }
+
             if scan_sources.is_empty()
                 || unified_scan_args
                     .pre_slice
@@ -523,6 +590,12 @@ pub fn lower_ir(
                         first_metadata: first_metadata.clone(),
                     }) as Arc<dyn FileReaderBuilder>,
 
+                    #[cfg(feature = "avro")]
+                    FileScanIR::Avro {
+                        options: polars_io::avro::AvroScanOptions {},
+                    } => Arc::new(crate::nodes::io_sources::avro::builder::AvroReaderBuilder)
+                        as Arc<dyn FileReaderBuilder>,
+
                     #[cfg(feature = "csv")]
                     FileScanIR::Csv { options } => {
                         Arc::new(Arc::new(options.clone())) as Arc<dyn FileReaderBuilder>
@@ -550,7 +623,26 @@ pub fn lower_ir(
                         python_dataset_scan_to_reader_builder(expanded_scan)
                     },
 
-                    FileScanIR::Anonymous { .. } => todo!("unimplemented: AnonymousScan"),
+                    FileScanIR::Anonymous { options, function } => {
+                        use crate::physical_plan::io::anonymous_scan::anonymous_scan_to_reader_builder;
+
+                        anonymous_scan_to_reader_builder(
+                            function.clone(),
+                            options.fmt_str,
+                            file_info.schema.clone(),
+                            scan_output_schema,
+                            unified_scan_args.projection.clone(),
+                            predicate.as_ref().map(|p| p.to_expr(expr_arena)),
+                            unified_scan_args
+                                .pre_slice
+                                .as_ref()
+                                .map(|slice| match slice {
+                                    Slice::Positive { offset, len } => Ok(offset.saturating_add(*len)),
+                                    _ => polars_bail!(ComputeError: "anonymous scan does not support negative slices"),
+                                })
+                                .transpose()?,
+                        )?
+                    },
                 };
 
                 {
@@ -620,6 +712,7 @@ pub fn lower_ir(
                         deletion_files: DeletionFilesList::filter_empty(
                             unified_scan_args.deletion_files,
                         ),
+                        file_statistics: unified_scan_args.file_statistics,
                         file_schema,
                     };
 
@@ -931,6 +1024,51 @@ pub fn lower_ir(
                 }
                 return Ok(stream);
             } else {
+                #[cfg(feature = "iejoin")]
+                if let Some(JoinTypeOptionsIR::IEJoin(ie_options)) = &options {
+                    if is_streamable_ie_join(&args, &left_on, &right_on, expr_arena, expr_cache) {
+                        let mut node_args = args.clone();
+                        node_args.slice = None;
+                        let node = phys_sm.insert(PhysNode::new(
+                            output_schema,
+                            PhysNodeKind::IEJoin {
+                                input_left: phys_left,
+                                input_right: phys_right,
+                                left_on,
+                                right_on,
+                                args: node_args,
+                                options: ie_options.clone(),
+                            },
+                        ));
+                        let mut stream = PhysStream::first(node);
+                        if let Some((offset, len)) = args.slice {
+                            stream = build_slice_stream(stream, offset, len, phys_sm);
+                        }
+                        return Ok(stream);
+                    }
+                }
+
+                #[cfg(feature = "asof_join")]
+                if is_streamable_asof_join(&args, &left_on, &right_on, &options, expr_arena) {
+                    let mut node_args = args.clone();
+                    node_args.slice = None;
+                    let node = phys_sm.insert(PhysNode::new(
+                        output_schema,
+                        PhysNodeKind::AsOfJoin {
+                            input_left: phys_left,
+                            input_right: phys_right,
+                            left_on,
+                            right_on,
+                            args: node_args,
+                        },
+                    ));
+                    let mut stream = PhysStream::first(node);
+                    if let Some((offset, len)) = args.slice {
+                        stream = build_slice_stream(stream, offset, len, phys_sm);
+                    }
+                    return Ok(stream);
+                }
+
                 PhysNodeKind::InMemoryJoin {
                     input_left: phys_left,
                     input_right: phys_right,
@@ -946,150 +1084,32 @@ pub fn lower_ir(
             let options = options.clone();
             let phys_input = lower_ir!(*input)?;
 
-            // We don't have a dedicated distinct operator (yet), lower to group
-            // by with an aggregate for each column.
             let input_schema = &phys_sm[phys_input.node].output_schema;
             if input_schema.is_empty() {
-                // Can't group (or have duplicates) if dataframe has zero-width.
+                // Can't have duplicates if dataframe has zero-width.
                 return Ok(phys_input);
             }
 
-            if options.maintain_order && options.keep_strategy == UniqueKeepStrategy::Last {
-                // Unfortunately the order-preserving groupby always orders by the first occurrence
-                // of the group so we can't lower this and have to fallback.
-                let input_schema = phys_sm[phys_input.node].output_schema.clone();
-                let lmdf = Arc::new(LateMaterializedDataFrame::default());
-                let mut lp_arena = Arena::default();
-                let input_lp_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema));
-                let distinct_lp_node = lp_arena.add(IR::Distinct {
-                    input: input_lp_node,
-                    options,
-                });
-                let executor = Mutex::new(create_physical_plan(
-                    distinct_lp_node,
-                    &mut lp_arena,
-                    expr_arena,
-                    None,
-                )?);
-
-                let format_str = ctx.prepare_visualization.then(|| {
-                    let mut buffer = String::new();
-                    write_ir_non_recursive(
-                        &mut buffer,
-                        ir_arena.get(node),
-                        expr_arena,
-                        phys_sm.get(phys_input.node).unwrap().output_schema.as_ref(),
-                        0,
-                    )
-                    .unwrap();
-                    buffer
-                });
-                let distinct_node = PhysNode {
-                    output_schema,
-                    kind: PhysNodeKind::InMemoryMap {
-                        input: phys_input,
-                        map: Arc::new(move |df| {
-                            lmdf.set_materialized_dataframe(df);
-                            let mut state = ExecutionState::new();
-                            executor.lock().execute(&mut state)
-                        }),
-                        format_str,
-                    },
-                };
-
-                return Ok(PhysStream::first(phys_sm.insert(distinct_node)));
-            }
-
-            // Create the key and aggregate expressions.
-            let all_col_names = input_schema.iter_names().cloned().collect_vec();
-            let key_names = if let Some(subset) = options.subset {
-                subset.to_vec()
-            } else {
-                all_col_names.clone()
+            let key = match options.subset {
+                Some(subset) => subset,
+                None => input_schema.iter_names().cloned().collect(),
             };
-            let key_name_set: PlHashSet<_> = key_names.iter().cloned().collect();
-
-            let mut group_by_output_schema = Schema::with_capacity(all_col_names.len() + 1);
-            let keys = key_names
-                .iter()
-                .map(|name| {
-                    group_by_output_schema
-                        .insert(name.clone(), input_schema.get(name).unwrap().clone());
-                    let col_expr = expr_arena.add(AExpr::Column(name.clone()));
-                    ExprIR::new(col_expr, OutputName::ColumnLhs(name.clone()))
-                })
-                .collect_vec();
-
-            let mut aggs = all_col_names
-                .iter()
-                .filter(|name| !key_name_set.contains(*name))
-                .map(|name| {
-                    group_by_output_schema
-                        .insert(name.clone(), input_schema.get(name).unwrap().clone());
-                    let col_expr = expr_arena.add(AExpr::Column(name.clone()));
-                    use UniqueKeepStrategy::*;
-                    let agg_expr = match options.keep_strategy {
-                        First | None | Any => {
-                            expr_arena.add(AExpr::Agg(IRAggExpr::First(col_expr)))
-                        },
-                        Last => expr_arena.add(AExpr::Agg(IRAggExpr::Last(col_expr))),
-                    };
-                    ExprIR::new(agg_expr, OutputName::ColumnLhs(name.clone()))
-                })
-                .collect_vec();
-
-            if options.keep_strategy == UniqueKeepStrategy::None {
-                // Track the length so we can filter out non-unique keys later.
-                let name = unique_column_name();
-                group_by_output_schema.insert(name.clone(), DataType::IDX_DTYPE);
-                aggs.push(ExprIR::new(
-                    expr_arena.add(AExpr::Len),
-                    OutputName::Alias(name),
-                ));
-            }
-
-            let mut stream = build_group_by_stream(
-                phys_input,
-                &keys,
-                &aggs,
-                Arc::new(group_by_output_schema),
-                options.maintain_order,
-                Arc::new(GroupbyOptions::default()),
-                None,
-                expr_arena,
-                phys_sm,
-                expr_cache,
-                ctx,
-            )?;
-
-            if options.keep_strategy == UniqueKeepStrategy::None {
-                // Filter to keep only those groups with length 1.
-                let unique_name = aggs.last().unwrap().output_name();
-                let left = expr_arena.add(AExpr::Column(unique_name.clone()));
-                let right = expr_arena.add(AExpr::Literal(LiteralValue::new_idxsize(1)));
-                let predicate_aexpr = expr_arena.add(AExpr::BinaryExpr {
-                    left,
-                    op: polars_plan::dsl::Operator::Eq,
-                    right,
-                });
-                let predicate =
-                    ExprIR::new(predicate_aexpr, OutputName::ColumnLhs(unique_name.clone()));
-                stream =
-                    build_filter_stream(stream, predicate, expr_arena, phys_sm, expr_cache, ctx)?;
-            }
+            polars_ensure!(
+                !key.is_empty(),
+                ComputeError: "at least one key is required in a unique operation"
+            );
 
-            // Restore column order and drop the temporary length column if any.
-            let exprs = all_col_names
-                .iter()
-                .map(|name| {
-                    let col_expr = expr_arena.add(AExpr::Column(name.clone()));
-                    ExprIR::new(col_expr, OutputName::ColumnLhs(name.clone()))
-                })
-                .collect_vec();
-            stream = build_select_stream(stream, &exprs, expr_arena, phys_sm, expr_cache, ctx)?;
+            let distinct_node = PhysNode {
+                output_schema,
+                kind: PhysNodeKind::Distinct {
+                    input: phys_input,
+                    key,
+                    keep: options.keep_strategy,
+                    maintain_order: options.maintain_order,
+                },
+            };
+            let mut stream = PhysStream::first(phys_sm.insert(distinct_node));
 
-            // We didn't pass the slice earlier to build_group_by_stream because
-            // we might have the intermediate keep = "none" filter.
             if let Some((offset, length)) = options.slice {
                 stream = build_slice_stream(stream, offset, length, phys_sm);
             }
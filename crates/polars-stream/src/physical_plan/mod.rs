@@ -1,19 +1,26 @@
 use std::sync::Arc;
 
 use polars_core::frame::DataFrame;
-use polars_core::prelude::{IdxSize, InitHashMaps, PlHashMap, SortMultipleOptions};
+use polars_core::prelude::{
+    IdxSize, InitHashMaps, PlHashMap, SortMultipleOptions, UniqueKeepStrategy,
+};
 use polars_core::schema::{Schema, SchemaRef};
 use polars_error::PolarsResult;
 use polars_io::RowIndex;
 use polars_io::cloud::CloudOptions;
+#[cfg(feature = "iejoin")]
+use polars_ops::frame::IEJoinOptions;
 use polars_ops::frame::JoinArgs;
 use polars_plan::dsl::deletion::DeletionFilesList;
+use polars_plan::dsl::file_statistics::FileStatistics;
 use polars_plan::dsl::{
     CastColumnsPolicy, JoinTypeOptionsIR, MissingColumnsPolicy, PartitionTargetCallback,
     PartitionVariantIR, ScanSources, SinkFinishCallback, SinkOptions, SinkTarget, SortColumnIR,
 };
 use polars_plan::plans::hive::HivePartitionsDf;
 use polars_plan::plans::{AExpr, DataFrameUdf, IR};
+#[cfg(feature = "dynamic_group_by")]
+use polars_plan::prelude::GroupbyOptions;
 use polars_plan::prelude::expr_ir::ExprIR;
 
 mod fmt;
@@ -237,6 +244,8 @@ pub enum PhysNodeKind {
         forbid_extra_columns: Option<ForbidExtraColumns>,
 
         deletion_files: Option<DeletionFilesList>,
+        /// Statistics of the scan sources, used to skip files with the predicate.
+        file_statistics: Option<FileStatistics>,
 
         /// Schema of columns contained in the file. Does not contain external columns (e.g. hive / row_index).
         file_schema: SchemaRef,
@@ -254,6 +263,29 @@ pub enum PhysNodeKind {
         aggs: Vec<ExprIR>,
     },
 
+    Distinct {
+        input: PhysStream,
+        key: Arc<[PlSmallStr]>,
+        keep: UniqueKeepStrategy,
+        maintain_order: bool,
+    },
+
+    /// Evaluates window expressions partitioned by key columns the input is
+    /// already grouped by.
+    SortedWindow {
+        input: PhysStream,
+        key: Arc<[PlSmallStr]>,
+        exprs: Vec<ExprIR>,
+    },
+
+    /// Group by (rolling) windows over an index column the input is sorted by.
+    #[cfg(feature = "dynamic_group_by")]
+    DynamicGroupBy {
+        input: PhysStream,
+        aggs: Vec<ExprIR>,
+        options: Arc<GroupbyOptions>,
+    },
+
     EquiJoin {
         input_left: PhysStream,
         input_right: PhysStream,
@@ -289,6 +321,27 @@ pub enum PhysNodeKind {
         options: Option<JoinTypeOptionsIR>,
     },
 
+    /// As-of join of two inputs which are sorted by their (single column) key.
+    #[cfg(feature = "asof_join")]
+    AsOfJoin {
+        input_left: PhysStream,
+        input_right: PhysStream,
+        left_on: Vec<ExprIR>,
+        right_on: Vec<ExprIR>,
+        args: JoinArgs,
+    },
+
+    /// Inequality join on one or two inequality predicates.
+    #[cfg(feature = "iejoin")]
+    IEJoin {
+        input_left: PhysStream,
+        input_right: PhysStream,
+        left_on: Vec<ExprIR>,
+        right_on: Vec<ExprIR>,
+        args: JoinArgs,
+        options: IEJoinOptions,
+    },
+
     #[cfg(feature = "merge_sorted")]
     MergeSorted {
         input_left: PhysStream,
@@ -335,7 +388,15 @@ fn visit_node_inputs_mut(
             | PhysNodeKind::Map { input, .. }
             | PhysNodeKind::Sort { input, .. }
             | PhysNodeKind::Multiplexer { input }
-            | PhysNodeKind::GroupBy { input, .. } => {
+            | PhysNodeKind::GroupBy { input, .. }
+            | PhysNodeKind::Distinct { input, .. }
+            | PhysNodeKind::SortedWindow { input, .. } => {
+                rec!(input.node);
+                visit(input);
+            },
+
+            #[cfg(feature = "dynamic_group_by")]
+            PhysNodeKind::DynamicGroupBy { input, .. } => {
                 rec!(input.node);
                 visit(input);
             },
@@ -366,6 +427,30 @@ fn visit_node_inputs_mut(
                 visit(input_right);
             },
 
+            #[cfg(feature = "asof_join")]
+            PhysNodeKind::AsOfJoin {
+                input_left,
+                input_right,
+                ..
+            } => {
+                rec!(input_left.node);
+                rec!(input_right.node);
+                visit(input_left);
+                visit(input_right);
+            },
+
+            #[cfg(feature = "iejoin")]
+            PhysNodeKind::IEJoin {
+                input_left,
+                input_right,
+                ..
+            } => {
+                rec!(input_left.node);
+                rec!(input_right.node);
+                visit(input_left);
+                visit(input_right);
+            },
+
             #[cfg(feature = "merge_sorted")]
             PhysNodeKind::MergeSorted {
                 input_left,
@@ -1,6 +1,8 @@
 use std::sync::Arc;
 
 use parking_lot::Mutex;
+#[cfg(feature = "asof_join")]
+use polars_core::frame::DataFrame;
 use polars_core::prelude::PlRandomState;
 use polars_core::schema::Schema;
 use polars_core::{POOL, config};
@@ -9,7 +11,11 @@ use polars_expr::groups::new_hash_grouper;
 use polars_expr::planner::{ExpressionConversionState, create_physical_expr};
 use polars_expr::reduce::into_reduction;
 use polars_expr::state::ExecutionState;
+#[cfg(feature = "asof_join")]
+use polars_mem_engine::resolve_asof_tolerance;
 use polars_mem_engine::{create_physical_plan, create_scan_predicate};
+#[cfg(feature = "asof_join")]
+use polars_ops::frame::DataFrameJoinOps;
 use polars_plan::dsl::{JoinOptionsIR, PartitionVariantIR, ScanSources};
 use polars_plan::plans::expr_ir::ExprIR;
 use polars_plan::plans::{AExpr, ArenaExprIter, Context, IR};
@@ -36,6 +42,7 @@ use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::File
 use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities::ReaderCapabilities;
 use crate::physical_plan::lower_expr::compute_output_schema;
 use crate::utils::late_materialized_df::LateMaterializedDataFrame;
+use crate::utils::spill::get_spill_memory_budget;
 
 fn has_potential_recurring_entrance(node: Node, arena: &Arena<AExpr>) -> bool {
     arena.iter(node).any(|(_n, ae)| match ae {
@@ -285,10 +292,11 @@ fn to_graph_rec<'a>(
                     [(input_key, input.port)],
                 ),
                 #[cfg(feature = "json")]
-                FileType::Json(_) => ctx.graph.add_node(
+                FileType::Json(json_writer_options) => ctx.graph.add_node(
                     SinkComputeNode::from(nodes::io_sinks::json::NDJsonSinkNode::new(
                         target.clone(),
                         sink_options,
+                        *json_writer_options,
                         cloud_options.clone(),
                     )),
                     [(input_key, input.port)],
@@ -316,11 +324,23 @@ fn to_graph_rec<'a>(
                     )),
                     [(input_key, input.port)],
                 ),
+                #[cfg(feature = "avro")]
+                FileType::Avro(avro_writer_options) => ctx.graph.add_node(
+                    SinkComputeNode::from(nodes::io_sinks::avro::AvroSinkNode::new(
+                        target.clone(),
+                        input_schema,
+                        sink_options,
+                        avro_writer_options.clone(),
+                        cloud_options.clone(),
+                    )),
+                    [(input_key, input.port)],
+                ),
                 #[cfg(not(any(
                     feature = "csv",
                     feature = "parquet",
                     feature = "json",
-                    feature = "ipc"
+                    feature = "ipc",
+                    feature = "avro"
                 )))]
                 _ => {
                     panic!("activate source feature")
@@ -344,7 +364,7 @@ fn to_graph_rec<'a>(
 
             let base_path = base_path.clone();
             let file_path_cb = file_path_cb.clone();
-            let ext = PlSmallStr::from_static(file_type.extension());
+            let ext = file_type.extension();
             let create_new = nodes::io_sinks::partition::get_create_new_fn(
                 file_type.clone(),
                 sink_options.clone(),
@@ -457,34 +477,55 @@ fn to_graph_rec<'a>(
             sort_options,
         } => {
             let input_schema = ctx.phys_sm[input.node].output_schema.clone();
-            let lmdf = Arc::new(LateMaterializedDataFrame::default());
-            let mut lp_arena = Arena::default();
-            let df_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
-            let sort_node = lp_arena.add(IR::Sort {
-                input: df_node,
-                by_column: by_column.clone(),
-                slice: *slice,
-                sort_options: sort_options.clone(),
-            });
-            let executor = Mutex::new(create_physical_plan(
-                sort_node,
-                &mut lp_arena,
-                ctx.expr_arena,
-                None,
-            )?);
 
-            let input_key = to_graph_rec(input.node, ctx)?;
-            ctx.graph.add_node(
-                nodes::in_memory_map::InMemoryMapNode::new(
-                    input_schema,
-                    Arc::new(move |df| {
-                        lmdf.set_materialized_dataframe(df);
-                        let mut state = ExecutionState::new();
-                        executor.lock().execute(&mut state)
-                    }),
-                ),
-                [(input_key, input.port)],
-            )
+            // With a memory budget we use an external sort which can spill
+            // sorted runs to disk, otherwise we sort everything in memory.
+            if let Some(memory_budget) = get_spill_memory_budget()? {
+                let by_column = by_column
+                    .iter()
+                    .map(|e| create_stream_expr(e, ctx, &input_schema))
+                    .try_collect_vec()?;
+                let input_key = to_graph_rec(input.node, ctx)?;
+                ctx.graph.add_node(
+                    nodes::sort::SortNode::new(
+                        by_column,
+                        *slice,
+                        sort_options.clone(),
+                        memory_budget,
+                        ctx.num_pipelines,
+                    ),
+                    [(input_key, input.port)],
+                )
+            } else {
+                let lmdf = Arc::new(LateMaterializedDataFrame::default());
+                let mut lp_arena = Arena::default();
+                let df_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
+                let sort_node = lp_arena.add(IR::Sort {
+                    input: df_node,
+                    by_column: by_column.clone(),
+                    slice: *slice,
+                    sort_options: sort_options.clone(),
+                });
+                let executor = Mutex::new(create_physical_plan(
+                    sort_node,
+                    &mut lp_arena,
+                    ctx.expr_arena,
+                    None,
+                )?);
+
+                let input_key = to_graph_rec(input.node, ctx)?;
+                ctx.graph.add_node(
+                    nodes::in_memory_map::InMemoryMapNode::new(
+                        input_schema,
+                        Arc::new(move |df| {
+                            lmdf.set_materialized_dataframe(df);
+                            let mut state = ExecutionState::new();
+                            executor.lock().execute(&mut state)
+                        }),
+                    ),
+                    [(input_key, input.port)],
+                )
+            }
         },
 
         Repeat { value, repeats } => {
@@ -548,6 +589,7 @@ fn to_graph_rec<'a>(
             include_file_paths,
             forbid_extra_columns,
             deletion_files,
+            file_statistics,
             file_schema,
         } => {
             let hive_parts = hive_parts.clone();
@@ -585,6 +627,7 @@ fn to_graph_rec<'a>(
             let forbid_extra_columns = forbid_extra_columns.clone();
             let cast_columns_policy = cast_columns_policy.clone();
             let deletion_files = deletion_files.clone();
+            let file_statistics = file_statistics.clone();
 
             let verbose = config::verbose();
 
@@ -605,6 +648,7 @@ fn to_graph_rec<'a>(
                         forbid_extra_columns,
                         cast_columns_policy,
                         deletion_files,
+                        file_statistics,
                         // Initialized later
                         num_pipelines: RelaxedCell::new_usize(0),
                         n_readers_pre_init: RelaxedCell::new_usize(0),
@@ -650,11 +694,93 @@ fn to_graph_rec<'a>(
                     node.output_schema.clone(),
                     PlRandomState::default(),
                     ctx.num_pipelines,
+                )?,
+                [(input_key, input.port)],
+            )
+        },
+
+        Distinct {
+            input,
+            key,
+            keep,
+            maintain_order,
+        } => {
+            let input_key = to_graph_rec(input.node, ctx)?;
+
+            let input_schema = ctx.phys_sm[input.node].output_schema.clone();
+            let key_schema = Arc::new(input_schema.try_project(key.iter())?);
+            let grouper = new_hash_grouper(key_schema);
+
+            ctx.graph.add_node(
+                nodes::distinct::DistinctNode::new(
+                    input_schema,
+                    key.clone(),
+                    grouper,
+                    *keep,
+                    *maintain_order,
+                    PlRandomState::default(),
                 ),
                 [(input_key, input.port)],
             )
         },
 
+        SortedWindow { input, key, exprs } => {
+            let input_key = to_graph_rec(input.node, ctx)?;
+            let input_schema = ctx.phys_sm[input.node].output_schema.clone();
+            let exprs = exprs
+                .iter()
+                .map(|e| {
+                    create_physical_expr(
+                        e,
+                        Context::Default,
+                        ctx.expr_arena,
+                        &input_schema,
+                        &mut ExpressionConversionState::new(false),
+                    )
+                })
+                .try_collect_vec()?;
+
+            ctx.graph.add_node(
+                nodes::sorted_window::SortedWindowNode::new(key.clone(), exprs, &input_schema),
+                [(input_key, input.port)],
+            )
+        },
+
+        #[cfg(feature = "dynamic_group_by")]
+        DynamicGroupBy {
+            input,
+            aggs,
+            options,
+        } => {
+            use nodes::dynamic_group_by::{DynamicGroupByNode, TemporalWindows};
+
+            let input_key = to_graph_rec(input.node, ctx)?;
+            let input_schema = ctx.phys_sm[input.node].output_schema.clone();
+
+            let windows = match (&options.dynamic, &options.rolling) {
+                (Some(dynamic), _) => TemporalWindows::Dynamic(dynamic.clone()),
+                (_, Some(rolling)) => TemporalWindows::Rolling(rolling.clone()),
+                _ => unreachable!(),
+            };
+            let aggs = aggs
+                .iter()
+                .map(|agg| {
+                    create_physical_expr(
+                        agg,
+                        Context::Aggregation,
+                        ctx.expr_arena,
+                        &input_schema,
+                        &mut ExpressionConversionState::new(true),
+                    )
+                })
+                .try_collect_vec()?;
+
+            ctx.graph.add_node(
+                DynamicGroupByNode::new(windows, aggs, &input_schema),
+                [(input_key, input.port)],
+            )
+        },
+
         InMemoryJoin {
             input_left,
             input_right,
@@ -716,6 +842,131 @@ fn to_graph_rec<'a>(
             )
         },
 
+        #[cfg(feature = "asof_join")]
+        AsOfJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+        } => {
+            let left_input_key = to_graph_rec(input_left.node, ctx)?;
+            let right_input_key = to_graph_rec(input_right.node, ctx)?;
+            let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
+            let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
+
+            let polars_ops::frame::JoinType::AsOf(asof_options) = &args.how else {
+                unreachable!()
+            };
+            let key_name = |e: &ExprIR| {
+                let AExpr::Column(name) = ctx.expr_arena.get(e.node()) else {
+                    unreachable!()
+                };
+                name.clone()
+            };
+            let left_key = key_name(&left_on[0]);
+            let right_key = key_name(&right_on[0]);
+
+            let mut asof_options = asof_options.as_ref().clone();
+            resolve_asof_tolerance(&mut asof_options, left_input_schema.get(&left_key).unwrap())?;
+            let by_params = match (&asof_options.left_by, &asof_options.right_by) {
+                (Some(left_by), Some(right_by)) => {
+                    for (l, r) in left_by.iter().zip(right_by) {
+                        let left_dtype = left_input_schema.try_get(l)?;
+                        let right_dtype = right_input_schema.try_get(r)?;
+                        polars_ensure!(left_dtype == right_dtype,
+                            ComputeError: "mismatching dtypes in 'by' parameter of asof-join: `{left_dtype}` and `{right_dtype}`",
+                        );
+                    }
+                    let key_schema = Arc::new(right_input_schema.try_project(right_by.iter())?);
+                    Some(nodes::joins::asof_join::AsOfJoinByParams {
+                        left_by: left_by.clone(),
+                        right_by: right_by.clone(),
+                        grouper: new_hash_grouper(key_schema),
+                    })
+                },
+                _ => None,
+            };
+            let params = nodes::joins::asof_join::AsOfJoinParams {
+                left_key: left_key.clone(),
+                right_key: right_key.clone(),
+                check_sortedness: asof_options.check_sortedness,
+            };
+
+            // The node checks the sortedness of the inputs as a whole, or
+            // within each 'by' group.
+            asof_options.check_sortedness = false;
+            let mut window_args = args.clone();
+            window_args.how = polars_ops::frame::JoinType::AsOf(Box::new(asof_options));
+            let joiner = Arc::new(move |left: DataFrame, right: DataFrame| {
+                let left_s = left.column(&left_key)?.as_materialized_series().clone();
+                let right_s = right.column(&right_key)?.as_materialized_series().clone();
+                left._join_impl(
+                    &right,
+                    vec![left_s],
+                    vec![right_s],
+                    window_args.clone(),
+                    None,
+                    true,
+                    false,
+                )
+            });
+
+            ctx.graph.add_node(
+                nodes::joins::asof_join::AsOfJoinNode::new(
+                    params,
+                    by_params,
+                    &left_input_schema,
+                    right_input_schema,
+                    joiner,
+                ),
+                [
+                    (left_input_key, input_left.port),
+                    (right_input_key, input_right.port),
+                ],
+            )
+        },
+
+        #[cfg(feature = "iejoin")]
+        IEJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+            options,
+        } => {
+            let left_input_key = to_graph_rec(input_left.node, ctx)?;
+            let right_input_key = to_graph_rec(input_right.node, ctx)?;
+            let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
+            let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
+
+            let left_key_selectors = left_on
+                .iter()
+                .map(|e| create_stream_expr(e, ctx, &left_input_schema))
+                .try_collect_vec()?;
+            let right_key_selectors = right_on
+                .iter()
+                .map(|e| create_stream_expr(e, ctx, &right_input_schema))
+                .try_collect_vec()?;
+
+            ctx.graph.add_node(
+                nodes::joins::ie_join::IEJoinNode::new(
+                    right_input_schema,
+                    left_key_selectors,
+                    right_key_selectors,
+                    args.clone(),
+                    options.clone(),
+                    get_spill_memory_budget()?,
+                    ctx.num_pipelines,
+                ),
+                [
+                    (left_input_key, input_left.port),
+                    (right_input_key, input_right.port),
+                ],
+            )
+        },
+
         EquiJoin {
             input_left,
             input_right,
@@ -1038,6 +1289,7 @@ fn to_graph_rec<'a>(
             let forbid_extra_columns = None;
             let cast_columns_policy = CastColumnsPolicy::ERROR_ON_MISMATCH;
             let deletion_files = None;
+            let file_statistics = None;
             let verbose = config::verbose();
 
             ctx.graph.add_node(
@@ -1057,6 +1309,7 @@ fn to_graph_rec<'a>(
                         forbid_extra_columns,
                         cast_columns_policy,
                         deletion_files,
+                        file_statistics,
                         // Initialized later
                         num_pipelines: RelaxedCell::new_usize(0),
                         n_readers_pre_init: RelaxedCell::new_usize(0),
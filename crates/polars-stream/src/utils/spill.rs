@@ -0,0 +1,157 @@
+//! Utilities for nodes that can move (part of) their state out of memory.
+//!
+//! Spilled data is written as Arrow IPC files in a per-node directory under
+//! the Polars temporary directory. Each DataFrame written is stored as a
+//! single record batch (a block), so it can be read back individually.
+use std::fs::File;
+use std::io::BufWriter;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use arrow::io::ipc::read::{FileReader, read_file_metadata};
+use arrow::io::ipc::write::{FileWriter, WriteOptions};
+use polars_core::config;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::CompatLevel;
+use polars_core::schema::{SchemaExt, SchemaRef};
+use polars_error::{PolarsResult, polars_ensure};
+use polars_io::path_utils::POLARS_TEMP_DIR_BASE_PATH;
+
+use crate::memory::get_memory_limit;
+
+/// Returns the memory budget in bytes after which spill-capable nodes start
+/// moving their state to disk, if any. This is the memory limit of the query,
+/// setting `POLARS_FORCE_OOC=1` instead makes nodes spill as soon as possible,
+/// which is useful for testing.
+pub fn get_spill_memory_budget() -> PolarsResult<Option<usize>> {
+    if std::env::var("POLARS_FORCE_OOC").as_deref() == Ok("1") {
+        return Ok(Some(0));
+    }
+    get_memory_limit()
+}
+
+/// A directory containing the spill files of a single node. The directory is
+/// only created once the first file is spilled, and it is removed together
+/// with everything in it when this is dropped.
+pub struct SpillDir {
+    node_name: &'static str,
+    path: PathBuf,
+    num_files: AtomicU64,
+}
+
+impl SpillDir {
+    pub fn new(node_name: &'static str) -> Arc<Self> {
+        static SPILL_DIR_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        let path = POLARS_TEMP_DIR_BASE_PATH.join("spill").join(format!(
+            "{node_name}-{}-{}",
+            std::process::id(),
+            SPILL_DIR_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        Arc::new(Self {
+            node_name,
+            path,
+            num_files: AtomicU64::new(0),
+        })
+    }
+
+    fn next_file_path(&self) -> PolarsResult<PathBuf> {
+        let idx = self.num_files.fetch_add(1, Ordering::Relaxed);
+        std::fs::create_dir_all(&self.path)?;
+        if idx == 0 && config::verbose() {
+            eprintln!("[{}]: spilling to {}", self.node_name, self.path.display());
+        }
+        Ok(self.path.join(format!("{idx}.arrow")))
+    }
+}
+
+impl Drop for SpillDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+/// An IPC file containing spilled DataFrames, one block per DataFrame. The
+/// file is removed when this is dropped.
+pub struct SpillFile {
+    path: PathBuf,
+    schema: SchemaRef,
+    num_blocks: usize,
+    // Keep the directory alive for as long as we are.
+    _dir: Arc<SpillDir>,
+}
+
+impl SpillFile {
+    /// Writes the given DataFrames, which must all have the given schema, to a
+    /// new spill file in dir. The i-th DataFrame becomes the i-th block.
+    pub fn write(
+        dir: &Arc<SpillDir>,
+        schema: SchemaRef,
+        dfs: impl IntoIterator<Item = DataFrame>,
+    ) -> PolarsResult<Self> {
+        let path = dir.next_file_path()?;
+        let file = BufWriter::new(File::create(&path)?);
+        let arrow_schema = Arc::new(schema.to_arrow(CompatLevel::newest()));
+        let mut writer =
+            FileWriter::try_new(file, arrow_schema, None, WriteOptions { compression: None })?;
+
+        let mut num_blocks = 0;
+        for mut df in dfs {
+            polars_ensure!(
+                df.schema().as_ref() == schema.as_ref(),
+                SchemaMismatch: "spilled DataFrame does not match spill file schema"
+            );
+            df.rechunk_mut();
+            let mut batches = df.iter_chunks(CompatLevel::newest(), false);
+            let batch = match batches.next() {
+                Some(batch) => batch,
+                // A DataFrame without chunks, write it as an empty block.
+                None => DataFrame::empty_with_schema(&schema)
+                    .iter_chunks(CompatLevel::newest(), false)
+                    .next()
+                    .unwrap(),
+            };
+            debug_assert!(batches.next().is_none());
+            writer.write(&batch, None)?;
+            num_blocks += 1;
+        }
+        writer.finish()?;
+
+        Ok(Self {
+            path,
+            schema,
+            num_blocks,
+            _dir: dir.clone(),
+        })
+    }
+
+    pub fn num_blocks(&self) -> usize {
+        self.num_blocks
+    }
+
+    /// Reads back the given range of blocks, one DataFrame per block.
+    pub fn read_blocks(&self, blocks: Range<usize>) -> PolarsResult<Vec<DataFrame>> {
+        assert!(blocks.end <= self.num_blocks);
+        let mut file = File::open(&self.path)?;
+        let metadata = read_file_metadata(&mut file)?;
+        let mut reader = FileReader::new(file, metadata, None, None);
+        reader.set_current_block(blocks.start);
+
+        let mut out = Vec::with_capacity(blocks.len());
+        for batch in reader.take(blocks.len()) {
+            let mut df = DataFrame::empty_with_schema(&self.schema);
+            df.append_record_batch(batch?)?;
+            out.push(df);
+        }
+        Ok(out)
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
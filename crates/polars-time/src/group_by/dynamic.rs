@@ -94,6 +94,15 @@ pub trait PolarsTemporalGroupby {
         group_by: Option<GroupsSlice>,
         options: &DynamicGroupOptions,
     ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)>;
+
+    /// Like `group_by_dynamic` without `group_by`, but the first window starts at
+    /// `first_window_start` if given. Also returns the start of every window (in the unit the
+    /// windows are computed in), which allows computing the windows of sorted data chunk by chunk.
+    fn group_by_dynamic_from(
+        &self,
+        options: &DynamicGroupOptions,
+        first_window_start: Option<i64>,
+    ) -> PolarsResult<(Column, Vec<Column>, GroupPositions, Vec<i64>)>;
 }
 
 impl PolarsTemporalGroupby for DataFrame {
@@ -110,7 +119,22 @@ impl PolarsTemporalGroupby for DataFrame {
         group_by: Option<GroupsSlice>,
         options: &DynamicGroupOptions,
     ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)> {
-        Wrap(self).group_by_dynamic(group_by, options)
+        Wrap(self).group_by_dynamic(group_by, options, None, None)
+    }
+
+    fn group_by_dynamic_from(
+        &self,
+        options: &DynamicGroupOptions,
+        first_window_start: Option<i64>,
+    ) -> PolarsResult<(Column, Vec<Column>, GroupPositions, Vec<i64>)> {
+        let mut window_starts = Vec::new();
+        let (time_key, keys, groups) = Wrap(self).group_by_dynamic(
+            None,
+            options,
+            first_window_start,
+            Some(&mut window_starts),
+        )?;
+        Ok((time_key, keys, groups, window_starts))
     }
 }
 
@@ -193,6 +217,8 @@ impl Wrap<&DataFrame> {
         &self,
         group_by: Option<GroupsSlice>,
         options: &DynamicGroupOptions,
+        first_window_start: Option<i64>,
+        window_starts: Option<&mut Vec<i64>>,
     ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)> {
         let time = self.0.column(&options.index_column)?.rechunk();
         if group_by.is_none() {
@@ -224,6 +250,8 @@ impl Wrap<&DataFrame> {
                     options,
                     TimeUnit::Nanoseconds,
                     &time_type,
+                    first_window_start,
+                    window_starts,
                 )?;
                 let out = out.cast(&Int64).unwrap().cast(&Int32).unwrap();
                 for k in &mut keys {
@@ -242,6 +270,8 @@ impl Wrap<&DataFrame> {
                     options,
                     TimeUnit::Nanoseconds,
                     &time_type,
+                    first_window_start,
+                    window_starts,
                 )?;
                 let out = out.cast(&Int64).unwrap();
                 for k in &mut keys {
@@ -257,9 +287,18 @@ impl Wrap<&DataFrame> {
                 dt
             ),
         };
-        self.impl_group_by_dynamic(dt, group_by, options, tu, time_type)
+        self.impl_group_by_dynamic(
+            dt,
+            group_by,
+            options,
+            tu,
+            time_type,
+            first_window_start,
+            window_starts,
+        )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn impl_group_by_dynamic(
         &self,
         mut dt: Column,
@@ -267,6 +306,8 @@ impl Wrap<&DataFrame> {
         options: &DynamicGroupOptions,
         tu: TimeUnit,
         time_type: &DataType,
+        first_window_start: Option<i64>,
+        window_starts: Option<&mut Vec<i64>>,
     ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)> {
         polars_ensure!(!options.every.negative, ComputeError: "'every' argument must be positive");
         if dt.is_empty() {
@@ -290,9 +331,10 @@ impl Wrap<&DataFrame> {
             include_lower_bound = true;
             include_upper_bound = true;
         }
-        if options.label == Label::Left {
+        if options.label == Label::Left || window_starts.is_some() {
             include_lower_bound = true;
-        } else if options.label == Label::Right {
+        }
+        if options.label == Label::Right {
             include_upper_bound = true;
         }
 
@@ -312,7 +354,7 @@ impl Wrap<&DataFrame> {
         let groups = if group_by.is_none() {
             let vals = dt.physical().downcast_iter().next().unwrap();
             let ts = vals.values().as_slice();
-            let (groups, lower, upper) = group_by_windows(
+            let (groups, lower, upper) = group_by_windows_from(
                 w,
                 ts,
                 options.closed_window,
@@ -321,7 +363,11 @@ impl Wrap<&DataFrame> {
                 include_lower_bound,
                 include_upper_bound,
                 options.start_by,
+                first_window_start,
             )?;
+            if let Some(window_starts) = window_starts {
+                window_starts.clone_from(&lower);
+            }
             update_bounds(lower, upper);
             PolarsResult::Ok(GroupsType::Slice {
                 groups,
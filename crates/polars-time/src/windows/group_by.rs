@@ -151,6 +151,34 @@ pub fn group_by_windows(
     include_lower_bound: bool,
     include_upper_bound: bool,
     start_by: StartBy,
+) -> PolarsResult<(GroupsSlice, Vec<i64>, Vec<i64>)> {
+    group_by_windows_from(
+        window,
+        time,
+        closed_window,
+        tu,
+        tz,
+        include_lower_bound,
+        include_upper_bound,
+        start_by,
+        None,
+    )
+}
+
+/// Like [`group_by_windows`], but if `first_window_start` is given the windows start there
+/// instead of at the earliest window given by `start_by`. This allows computing the windows
+/// of sorted data chunk by chunk.
+#[allow(clippy::too_many_arguments)]
+pub fn group_by_windows_from(
+    window: Window,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: &Option<TimeZone>,
+    include_lower_bound: bool,
+    include_upper_bound: bool,
+    start_by: StartBy,
+    first_window_start: Option<i64>,
 ) -> PolarsResult<(GroupsSlice, Vec<i64>, Vec<i64>)> {
     let start = time[0];
     // the boundary we define here is not yet correct. It doesn't take 'period' into account
@@ -183,14 +211,21 @@ pub fn group_by_windows(
     match tz {
         #[cfg(feature = "timezones")]
         Some(tz) => {
-            update_groups_and_bounds(
-                window.get_overlapping_bounds_iter(
+            let tz = tz.parse::<Tz>().ok();
+            let bounds_iter = match first_window_start {
+                Some(start) => {
+                    window.get_overlapping_bounds_iter_from(boundary, start, tu, tz.as_ref())?
+                },
+                None => window.get_overlapping_bounds_iter(
                     boundary,
                     closed_window,
                     tu,
-                    tz.parse::<Tz>().ok().as_ref(),
+                    tz.as_ref(),
                     start_by,
                 )?,
+            };
+            update_groups_and_bounds(
+                bounds_iter,
                 start_offset,
                 time,
                 closed_window,
@@ -202,8 +237,20 @@ pub fn group_by_windows(
             );
         },
         _ => {
+            let bounds_iter = match first_window_start {
+                Some(start) => {
+                    window.get_overlapping_bounds_iter_from(boundary, start, tu, None)?
+                },
+                None => window.get_overlapping_bounds_iter(
+                    boundary,
+                    closed_window,
+                    tu,
+                    None,
+                    start_by,
+                )?,
+            };
             update_groups_and_bounds(
-                window.get_overlapping_bounds_iter(boundary, closed_window, tu, None, start_by)?,
+                bounds_iter,
                 start_offset,
                 time,
                 closed_window,
@@ -180,6 +180,30 @@ impl Window {
     ) -> PolarsResult<BoundsIter<'a>> {
         BoundsIter::new(*self, closed_window, boundary, tu, tz, start_by)
     }
+
+    /// Like [`Window::get_overlapping_bounds_iter`], but the first window starts at `start`
+    /// instead of being derived from the boundary.
+    pub fn get_overlapping_bounds_iter_from<'a>(
+        &'a self,
+        boundary: Bounds,
+        start: i64,
+        tu: TimeUnit,
+        tz: Option<&'a Tz>,
+    ) -> PolarsResult<BoundsIter<'a>> {
+        let offset_fn = match tu {
+            TimeUnit::Nanoseconds => Duration::add_ns,
+            TimeUnit::Microseconds => Duration::add_us,
+            TimeUnit::Milliseconds => Duration::add_ms,
+        };
+        let stop = offset_fn(&self.period, start, tz)?;
+        Ok(BoundsIter {
+            window: *self,
+            boundary,
+            bi: Bounds::new_checked(start, stop),
+            tu,
+            tz,
+        })
+    }
 }
 
 pub struct BoundsIter<'a> {
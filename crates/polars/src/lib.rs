@@ -201,6 +201,7 @@
 //!     - `serde-lazy` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!       Can be used for JSON and more serde supported serialization formats.
 //!     - `parquet` - Read Apache Parquet format
+//!     - `parquet_encryption` - Read and write encrypted Apache Parquet files
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
@@ -208,6 +209,11 @@
 //!          - gzip
 //!          - zlib
 //!          - zstd
+//!     - `compress` - Compress the output of CSV and NDJSON sinks.
+//!       Supported compressions:
+//!          - gzip
+//!          - zstd
+//!     - `bzip2`, `xz`, `lz4` - Support these compressions in `decompress` and `compress`.
 //!
 //! [`StringChunked`]: crate::datatypes::StringChunked
 //! [column selection]: polars_lazy::dsl::col
@@ -8,7 +8,7 @@ use arrow::io::avro::avro_schema::write::{compress, write_block, write_metadata}
 use arrow::io::avro::write;
 use arrow::record_batch::RecordBatchT;
 use avro_schema::schema::{Field as AvroField, Record, Schema as AvroSchema};
-use polars::io::avro::{AvroReader, AvroWriter};
+use polars::io::avro::{AvroCompression, AvroReader, AvroWriter};
 use polars::io::{SerReader, SerWriter};
 use polars::prelude::df;
 use polars_error::PolarsResult;
@@ -415,7 +415,11 @@ fn test_write_and_read_with_compression() -> PolarsResult<()> {
         "string" => &["a", "b"]
     )?;
 
-    let compressions = vec![None, Some(Compression::Deflate), Some(Compression::Snappy)];
+    let compressions = vec![
+        None,
+        Some(AvroCompression::Deflate),
+        Some(AvroCompression::Snappy),
+    ];
 
     for compression in compressions.into_iter() {
         let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
@@ -485,3 +489,55 @@ fn test_with_columns() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_write_multiple_chunks() -> PolarsResult<()> {
+    let mut df = df!(
+        "i64" => &[1, 2],
+        "string" => &["a", "b"]
+    )?;
+    df.vstack_mut(&df!(
+        "i64" => &[3],
+        "string" => &["c"]
+    )?)?;
+    assert_eq!(df.first_col_n_chunks(), 2);
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+
+    AvroWriter::new(&mut buf)
+        .with_compression(Some(AvroCompression::Snappy))
+        .finish(&mut df)?;
+    buf.set_position(0);
+
+    let read_df = AvroReader::new(buf).finish()?;
+    assert!(df.equals(&read_df));
+
+    Ok(())
+}
+
+#[test]
+fn test_write_batched() -> PolarsResult<()> {
+    let df = df!(
+        "i64" => &[1, 2, 3],
+        "string" => &["a", "b", "c"]
+    )?;
+
+    let mut buf: Vec<u8> = Vec::new();
+
+    let mut writer = AvroWriter::new(&mut buf).batched(df.schema())?;
+    writer.write_batch(&df.slice(0, 2))?;
+    writer.write_batch(&df.slice(2, 1))?;
+    drop(writer);
+
+    // Blocks that are written without a header can be appended to the file.
+    let mut writer = AvroWriter::new(&mut buf)
+        .include_header(false)
+        .batched(df.schema())?;
+    writer.write_batch(&df)?;
+    drop(writer);
+
+    let read_df = AvroReader::new(Cursor::new(buf)).finish()?;
+    assert!(read_df.equals(&df.vstack(&df)?));
+
+    Ok(())
+}
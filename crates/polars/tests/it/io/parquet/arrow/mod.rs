@@ -675,7 +675,7 @@ fn integration_write(
         data_page_size: None,
     };
 
-    let column_options = get_column_write_options(schema, &[]);
+    let column_options = get_column_write_options(schema, &[])?;
 
     let row_groups = RowGroupIterator::try_new(
         chunks.iter().cloned().map(Ok),
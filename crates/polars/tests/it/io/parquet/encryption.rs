@@ -0,0 +1,169 @@
+use std::io::Cursor;
+
+use polars::io::parquet::encryption::{
+    ParquetDecryptionOptions, ParquetEncryptionOptions, ParquetKeyRetriever,
+};
+use polars::prelude::*;
+
+fn keys() -> ParquetKeyRetriever {
+    ParquetKeyRetriever::from_static(vec![
+        ("footer".into(), b"0123456789012345".to_vec()),
+        ("pii".into(), b"0123456789012345678901234567890x".to_vec()),
+    ])
+}
+
+fn write(df: &mut DataFrame, encryption: ParquetEncryptionOptions) -> PolarsResult<Vec<u8>> {
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(2))
+        .with_encryption(Some(encryption))
+        .finish(df)?;
+    Ok(buf.into_inner())
+}
+
+fn read(buf: Vec<u8>, decryption: Option<ParquetDecryptionOptions>) -> PolarsResult<DataFrame> {
+    ParquetReader::new(Cursor::new(buf))
+        .with_decryption(decryption)
+        .finish()
+}
+
+#[test]
+fn test_encryption_round_trip() -> PolarsResult<()> {
+    let mut df = df! {
+        "id" => [1, 2, 3, 4, 5],
+        "ssn" => ["123-45-6789", "987-65-4321", "555-55-5555", "111-11-1111", "000-00-0000"],
+    }?;
+
+    let buf = write(
+        &mut df,
+        ParquetEncryptionOptions {
+            footer_key_id: "footer".into(),
+            column_key_ids: vec![("ssn".into(), "pii".into())],
+            keys: keys(),
+            aad_prefix: Some(b"table/part-0".to_vec()),
+            store_aad_prefix: true,
+        },
+    )?;
+    assert_eq!(&buf[..4], b"PARE");
+    assert_eq!(&buf[buf.len() - 4..], b"PARE");
+    // The values of the encrypted column are not stored in plaintext.
+    assert!(!buf.windows(11).any(|w| w == b"123-45-6789"));
+
+    let decryption = ParquetDecryptionOptions {
+        keys: keys(),
+        aad_prefix: None,
+    };
+    assert!(read(buf.clone(), Some(decryption))?.equals(&df));
+
+    // The footer can't be read without its key.
+    assert!(read(buf.clone(), None).is_err());
+    let decryption = ParquetDecryptionOptions {
+        keys: ParquetKeyRetriever::from_static(vec![(
+            "footer".into(),
+            b"5432109876543210".to_vec(),
+        )]),
+        aad_prefix: None,
+    };
+    assert!(read(buf, Some(decryption)).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_encryption_aad_prefix_not_stored() -> PolarsResult<()> {
+    let mut df = df! {
+        "a" => [1, 2, 3],
+    }?;
+
+    let buf = write(
+        &mut df,
+        ParquetEncryptionOptions {
+            footer_key_id: "footer".into(),
+            column_key_ids: vec![],
+            keys: keys(),
+            aad_prefix: Some(b"table/part-0".to_vec()),
+            store_aad_prefix: false,
+        },
+    )?;
+
+    let decryption = |aad_prefix: &[u8]| ParquetDecryptionOptions {
+        keys: ParquetKeyRetriever::from_rust_function(|key_id| keys().retrieve(key_id)),
+        aad_prefix: Some(aad_prefix.to_vec()),
+    };
+    assert!(read(buf.clone(), Some(decryption(b"table/part-0")))?.equals(&df));
+    assert!(read(buf, Some(decryption(b"table/part-1"))).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_encryption_unknown_column() -> PolarsResult<()> {
+    let mut df = df! {
+        "a" => [1, 2, 3],
+    }?;
+
+    let err = write(
+        &mut df,
+        ParquetEncryptionOptions {
+            footer_key_id: "footer".into(),
+            column_key_ids: vec![("b".into(), "pii".into())],
+            keys: keys(),
+            aad_prefix: None,
+            store_aad_prefix: true,
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, PolarsError::ColumnNotFound(_)));
+    Ok(())
+}
+
+#[test]
+fn test_encryption_columns_decrypted_when_read() -> PolarsResult<()> {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let mut df = df! {
+        "id" => [1, 2, 3, 4, 5],
+        "ssn" => ["123-45-6789", "987-65-4321", "555-55-5555", "111-11-1111", "000-00-0000"],
+        "name" => ["a", "b", "c", "d", "e"],
+    }?;
+
+    let buf = write(
+        &mut df,
+        ParquetEncryptionOptions {
+            footer_key_id: "footer".into(),
+            column_key_ids: vec![("ssn".into(), "pii".into()), ("name".into(), "pii".into())],
+            keys: keys(),
+            aad_prefix: None,
+            store_aad_prefix: true,
+        },
+    )?;
+
+    let num_retrieved = Arc::new(AtomicUsize::new(0));
+    let decryption = |key_ids: &'static [&'static str]| {
+        let num_retrieved = num_retrieved.clone();
+        ParquetDecryptionOptions {
+            keys: ParquetKeyRetriever::from_rust_function(move |key_id| {
+                num_retrieved.fetch_add(1, Ordering::Relaxed);
+                polars_ensure!(key_ids.contains(&key_id), ComputeError: "unknown key '{key_id}'");
+                keys().retrieve(key_id)
+            }),
+            aad_prefix: None,
+        }
+    };
+    let read_columns = |columns: &[&str], decryption| {
+        ParquetReader::new(Cursor::new(buf.clone()))
+            .with_columns(Some(columns.iter().map(|c| c.to_string()).collect()))
+            .with_decryption(Some(decryption))
+            .finish()
+    };
+
+    // Columns of which the key is unknown can be skipped.
+    let out = read_columns(&["id"], decryption(&["footer"]))?;
+    assert!(out.equals(&df.select(["id"])?));
+    assert!(read_columns(&["id", "ssn"], decryption(&["footer"])).is_err());
+
+    // Every key is retrieved once, not once per column chunk.
+    num_retrieved.store(0, Ordering::Relaxed);
+    assert!(read(buf.clone(), Some(decryption(&["footer", "pii"])))?.equals(&df));
+    assert_eq!(num_retrieved.load(Ordering::Relaxed), 2);
+    Ok(())
+}
@@ -1,5 +1,7 @@
 #![forbid(unsafe_code)]
 mod arrow;
+#[cfg(feature = "parquet_encryption")]
+mod encryption;
 pub(crate) mod read;
 mod roundtrip;
 mod write;
@@ -200,6 +200,7 @@ from polars.io import (
     read_parquet,
     read_parquet_metadata,
     read_parquet_schema,
+    scan_avro,
     scan_csv,
     scan_delta,
     scan_iceberg,
@@ -312,6 +313,7 @@ __all__ = [
     "read_parquet",
     "read_parquet_metadata",
     "read_parquet_schema",
+    "scan_avro",
     "scan_csv",
     "scan_delta",
     "scan_iceberg",
@@ -105,10 +105,32 @@ Ambiguous: TypeAlias = Literal["earliest", "latest", "raise", "null"]
 AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
 CsvQuoteStyle: TypeAlias = Literal["necessary", "always", "non_numeric", "never"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
-CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
+CsvEncoding: TypeAlias = Literal[
+    "utf8",
+    "utf8-lossy",
+    "latin1",
+    "windows-1250",
+    "windows-1251",
+    "windows-1252",
+    "utf16-le",
+    "utf16-be",
+    "shift-jis",
+    "gb18030",
+    "euc-kr",
+    "big5",
+]
 ColumnMapping: TypeAlias = tuple[Literal["iceberg-column-mapping"], "pa.Schema"]
-DeletionFiles: TypeAlias = tuple[
-    Literal["iceberg-position-delete"], dict[int, list[str]]
+DeletionFiles: TypeAlias = Union[
+    tuple[Literal["iceberg-position-delete"], dict[int, list[str]]],
+    # {source_idx: (data_file_path, data_sequence_number,
+    #   [(path, sequence_number, equality_ids)])}
+    tuple[
+        Literal["iceberg-delete"],
+        dict[int, tuple[str, int, list[tuple[str, int, Union[list[int], None]]]]],
+    ],
+]
+ExternalCompression: TypeAlias = Literal[
+    "uncompressed", "gzip", "zstd", "bzip2", "xz", "lz4"
 ]
 FillNullStrategy: TypeAlias = Literal[
     "forward", "backward", "min", "max", "mean", "zero", "one"
@@ -129,6 +151,13 @@ ParallelStrategy: TypeAlias = Literal[
 ParquetCompression: TypeAlias = Literal[
     "lz4", "uncompressed", "snappy", "gzip", "lzo", "brotli", "zstd"
 ]
+ParquetEncoding: TypeAlias = Literal[
+    "plain",
+    "delta_binary_packed",
+    "delta_length_byte_array",
+    "delta_byte_array",
+    "byte_stream_split",
+]
 PivotAgg: TypeAlias = Literal[
     "min", "max", "first", "last", "sum", "mean", "median", "len"
 ]
@@ -385,6 +414,7 @@ __all__ = [
     "EpochTimeUnit",
     "ExcelSpreadsheetEngine",
     "ExplainFormat",
+    "ExternalCompression",
     "FileSource",
     "FillNullStrategy",
     "FloatFmt",
@@ -415,6 +445,8 @@ __all__ = [
     "ParallelStrategy",
     "ParametricProfileNames",
     "ParquetCompression",
+    "ParquetEncoding",
+    "ParquetKeys",
     "PartitioningScheme",
     "PivotAgg",
     "PolarsDataType",
@@ -467,3 +499,5 @@ class ParquetMetadataContext:
 
 ParquetMetadataFn: TypeAlias = Callable[[ParquetMetadataContext], dict[str, str]]
 ParquetMetadata: TypeAlias = Union[dict[str, str], ParquetMetadataFn]
+
+ParquetKeys: TypeAlias = Union[Mapping[str, bytes], Callable[[str], bytes]]
@@ -395,6 +395,111 @@ class Catalog:
             )
             raise NotImplementedError(msg)
 
+    def sink_table(
+        self,
+        lf: LazyFrame,
+        catalog_name: str,
+        namespace: str,
+        table_name: str,
+        *,
+        mode: Literal["append", "overwrite"] = "append",
+        storage_options: dict[str, str] | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        retries: int = 2,
+    ) -> None:
+        """
+        Write the result of a query to a Delta catalog table.
+
+        The result is written as Parquet files to the storage location of the
+        table, which are then committed as a new version of the table. The
+        table is created by the first write if its storage location does not
+        contain a Delta table yet.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        lf
+            LazyFrame to write. The columns are cast to the types of the table.
+        catalog_name
+            Name of the catalog.
+        namespace
+            Name of the namespace (unity schema).
+        table_name
+            Name of the table.
+        mode : {'append', 'overwrite'}
+            How to handle existing data.
+
+            - If 'append', will add new data.
+            - If 'overwrite', will replace the data of the table with new data.
+        storage_options
+            Options that indicate how to connect to a cloud provider.
+
+            The cloud providers currently supported are AWS, GCP, and Azure.
+            See supported keys here:
+
+            * `aws <https://docs.rs/object_store/latest/object_store/aws/enum.AmazonS3ConfigKey.html>`_
+            * `gcp <https://docs.rs/object_store/latest/object_store/gcp/enum.GoogleConfigKey.html>`_
+            * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
+            * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
+            `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+
+            If `storage_options` is not provided, Polars will try to infer the
+            information from environment variables.
+        credential_provider
+            Provide a function that can be called to provide cloud storage
+            credentials. The function is expected to return a dictionary of
+            credential keys along with an optional credential expiry time.
+
+            By default, temporary credentials for writing to the table are
+            retrieved from the catalog.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        retries
+            Number of retries if accessing a cloud instance fails.
+        """
+        table_info = self.get_table_info(catalog_name, namespace, table_name)
+        _, data_source_format = _extract_location_and_data_format(
+            table_info, "sink table"
+        )
+
+        if data_source_format != "DELTA":
+            msg = (
+                "sink_table: table format of "
+                f"{catalog_name}.{namespace}.{table_name} "
+                f"({data_source_format}) is unsupported."
+            )
+            raise NotImplementedError(msg)
+
+        credential_provider, storage_options = self._init_credentials(  # type: ignore[assignment]
+            credential_provider,
+            storage_options,
+            table_info,
+            write=True,
+            caller_name="Catalog.sink_table",
+        )
+
+        if storage_options:
+            storage_options = list(storage_options.items())  # type: ignore[assignment]
+        else:
+            # Handle empty dict input
+            storage_options = None
+
+        self._client.sink_table(
+            lf._ldf,
+            table_info,
+            mode=mode,
+            credential_provider=credential_provider,
+            cloud_options=storage_options,
+            retries=retries,
+        ).collect(engine="streaming")
+
     def create_catalog(
         self,
         catalog_name: str,
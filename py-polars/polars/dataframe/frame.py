@@ -143,6 +143,7 @@ if TYPE_CHECKING:
         ComparisonOperator,
         ConditionalFormatDict,
         ConnectionOrCursor,
+        CsvEncoding,
         CsvQuoteStyle,
         DbWriteEngine,
         EngineType,
@@ -182,6 +183,7 @@ if TYPE_CHECKING:
     from polars._utils.various import NoDefault
     from polars.interchange.dataframe import PolarsDataFrame
     from polars.io.cloud import CredentialProviderFunction
+    from polars.io.parquet import ParquetEncryption
     from polars.ml.torch import PolarsDataset
 
     if sys.version_info >= (3, 10):
@@ -2901,6 +2903,7 @@ class DataFrame:
         *,
         include_bom: bool = ...,
         include_header: bool = ...,
+        encoding: CsvEncoding = ...,
         separator: str = ...,
         line_terminator: str = ...,
         quote_char: str = ...,
@@ -2925,6 +2928,7 @@ class DataFrame:
         *,
         include_bom: bool = ...,
         include_header: bool = ...,
+        encoding: CsvEncoding = ...,
         separator: str = ...,
         line_terminator: str = ...,
         quote_char: str = ...,
@@ -2948,6 +2952,7 @@ class DataFrame:
         *,
         include_bom: bool = False,
         include_header: bool = True,
+        encoding: CsvEncoding = "utf8",
         separator: str = ",",
         line_terminator: str = "\n",
         quote_char: str = '"',
@@ -2975,9 +2980,15 @@ class DataFrame:
             File path or writable file-like object to which the result will be written.
             If set to `None` (default), the output is returned as a string instead.
         include_bom
-            Whether to include UTF-8 BOM in the CSV output.
+            Whether to include a byte order mark in the CSV output. Only
+            supported by the utf8 and utf16 encodings.
         include_header
             Whether to include header in the CSV output.
+        encoding : {'utf8', 'latin1', 'windows-1252', 'utf16-le', 'shift-jis', ...}
+            Text encoding of the CSV output. One of `utf8`, `latin1`,
+            `windows-1250`, `windows-1251`, `windows-1252`, `utf16-le`,
+            `utf16-be`, `shift-jis`, `gb18030`, `euc-kr` or `big5`. Characters
+            that cannot be represented in the encoding raise an error.
         separator
             Separate CSV fields with this symbol.
         line_terminator
@@ -3077,6 +3088,9 @@ class DataFrame:
         should_return_buffer = False
         target: str | Path | IO[bytes] | IO[str]
         if file is None:
+            if encoding != "utf8":
+                msg = "`encoding` can only be set when writing to a file"
+                raise ValueError(msg)
             target = cast("IO[bytes]", BytesIO())
             should_return_buffer = True
         elif isinstance(file, (str, os.PathLike)):
@@ -3092,6 +3106,7 @@ class DataFrame:
             target,
             include_bom=include_bom,
             include_header=include_header,
+            encoding=encoding,
             separator=separator,
             line_terminator=line_terminator,
             quote_char=quote_char,
@@ -3952,6 +3967,7 @@ class DataFrame:
         ) = "auto",
         retries: int = 2,
         metadata: ParquetMetadata | None = None,
+        encryption: ParquetEncryption | None = None,
     ) -> None:
         """
         Write to Apache Parquet file.
@@ -4044,6 +4060,13 @@ class DataFrame:
             .. warning::
                 This functionality is considered **experimental**. It may be removed or
                 changed at any point without it being considered a breaking change.
+        encryption
+            Encrypt the file with Parquet modular encryption. Cannot be combined with
+            `use_pyarrow`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Examples
         --------
@@ -4089,6 +4112,9 @@ class DataFrame:
             if metadata is not None:
                 msg = "write_parquet with `use_pyarrow=True` cannot be combined with `metadata`"
                 raise ValueError(msg)
+            if encryption is not None:
+                msg = "write_parquet with `use_pyarrow=True` cannot be combined with `encryption`"
+                raise ValueError(msg)
 
             tbl = self.to_arrow()
             data = {}
@@ -4157,6 +4183,7 @@ class DataFrame:
             credential_provider=credential_provider,
             retries=retries,
             metadata=metadata,
+            encryption=encryption,
             engine=engine,
             mkdir=mkdir,
             optimizations=QueryOptFlags._eager(),
@@ -2,17 +2,22 @@ from __future__ import annotations
 
 import contextlib
 from pathlib import Path
-from typing import IO, TYPE_CHECKING
+from typing import IO, TYPE_CHECKING, Any, Literal
 
-from polars._utils.various import normalize_filepath
-from polars._utils.wrap import wrap_df
-from polars.io._utils import parse_columns_arg
+from polars._utils.various import is_path_or_str_sequence, normalize_filepath
+from polars._utils.wrap import wrap_df, wrap_ldf
+from polars.io._utils import parse_columns_arg, parse_row_index_args
+from polars.io.cloud.credential_provider._builder import (
+    _init_credential_provider_builder,
+)
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
-    from polars.polars import PyDataFrame
+    from polars.polars import PyDataFrame, PyLazyFrame
 
 if TYPE_CHECKING:
-    from polars import DataFrame
+    from polars import DataFrame, LazyFrame
+    from polars._typing import SchemaDict
+    from polars.io.cloud import CredentialProviderFunction
 
 
 def read_avro(
@@ -47,3 +52,145 @@ def read_avro(
 
     pydf = PyDataFrame.read_avro(source, column_names, projection, n_rows)
     return wrap_df(pydf)
+
+
+def scan_avro(
+    source: (
+        str
+        | Path
+        | IO[bytes]
+        | bytes
+        | list[str]
+        | list[Path]
+        | list[IO[bytes]]
+        | list[bytes]
+    ),
+    *,
+    n_rows: int | None = None,
+    cache: bool = True,
+    rechunk: bool = False,
+    row_index_name: str | None = None,
+    row_index_offset: int = 0,
+    storage_options: dict[str, Any] | None = None,
+    credential_provider: CredentialProviderFunction | Literal["auto"] | None = "auto",
+    retries: int = 2,
+    file_cache_ttl: int | None = None,
+    hive_partitioning: bool | None = None,
+    hive_schema: SchemaDict | None = None,
+    try_parse_hive_dates: bool = True,
+    include_file_paths: str | None = None,
+) -> LazyFrame:
+    """
+    Lazily read from an Apache Avro file or multiple files via glob patterns.
+
+    This allows the query optimizer to push down projections and slices to the scan
+    level, thereby potentially reducing memory overhead.
+
+    Parameters
+    ----------
+    source
+        Path(s) to a file or directory
+        When needing to authenticate for scanning cloud locations, see the
+        `storage_options` parameter.
+    n_rows
+        Stop reading from Apache Avro file after reading `n_rows`.
+    cache
+        Cache the result after reading.
+    rechunk
+        Reallocate to contiguous memory when all chunks/ files are parsed.
+    row_index_name
+        If not None, this will insert a row index column with give name into the
+        DataFrame
+    row_index_offset
+        Offset to start the row index column (only use if the name is set)
+    storage_options
+        Options that indicate how to connect to a cloud provider.
+
+        The cloud providers currently supported are AWS, GCP, and Azure.
+        See supported keys here:
+
+        * `aws <https://docs.rs/object_store/latest/object_store/aws/enum.AmazonS3ConfigKey.html>`_
+        * `gcp <https://docs.rs/object_store/latest/object_store/gcp/enum.GoogleConfigKey.html>`_
+        * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
+        * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
+          `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+
+        If `storage_options` is not provided, Polars will try to infer the information
+        from environment variables.
+    credential_provider
+        Provide a function that can be called to provide cloud storage
+        credentials. The function is expected to return a dictionary of
+        credential keys along with an optional credential expiry time.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    retries
+        Number of retries if accessing a cloud instance fails.
+    file_cache_ttl
+        Amount of time to keep downloaded cloud files since their last access time,
+        in seconds. Uses the `POLARS_FILE_CACHE_TTL` environment variable
+        (which defaults to 1 hour) if not given.
+    hive_partitioning
+        Infer statistics and schema from Hive partitioned URL and use them
+        to prune reads. This is unset by default (i.e. `None`), meaning it is
+        automatically enabled when a single directory is passed, and otherwise
+        disabled.
+    hive_schema
+        The column names and data types of the columns by which the data is partitioned.
+        If set to `None` (default), the schema of the Hive partitions is inferred.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    try_parse_hive_dates
+        Whether to try parsing hive values as date/datetime types.
+    include_file_paths
+        Include the path of the source file(s) as a column with this name.
+
+    Examples
+    --------
+    >>> pl.scan_avro("data/*.avro").select("a", "b").head(10)  # doctest: +SKIP
+    """
+    sources: list[str] | list[Path] | list[IO[bytes]] | list[bytes] = []
+    if isinstance(source, (str, Path)):
+        source = normalize_filepath(source, check_not_directory=False)
+    elif isinstance(source, list):
+        if is_path_or_str_sequence(source):
+            sources = [
+                normalize_filepath(source, check_not_directory=False)
+                for source in source
+            ]
+        else:
+            sources = source
+
+        source = None  # type: ignore[assignment]
+
+    credential_provider_builder = _init_credential_provider_builder(
+        credential_provider, source, storage_options, "scan_avro"
+    )
+    del credential_provider
+
+    if storage_options:
+        storage_options = list(storage_options.items())  # type: ignore[assignment]
+    else:
+        # Handle empty dict input
+        storage_options = None
+
+    pylf = PyLazyFrame.new_from_avro(
+        source,
+        sources,
+        n_rows,
+        cache,
+        rechunk,
+        parse_row_index_args(row_index_name, row_index_offset),
+        cloud_options=storage_options,
+        credential_provider=credential_provider_builder,
+        retries=retries,
+        file_cache_ttl=file_cache_ttl,
+        hive_partitioning=hive_partitioning,
+        hive_schema=hive_schema,
+        try_parse_hive_dates=try_parse_hive_dates,
+        include_file_paths=include_file_paths,
+    )
+    return wrap_ldf(pylf)
@@ -28,6 +28,12 @@ def _check_arg_is_1byte(
             raise ValueError(msg)
 
 
+def _check_arg_is_not_empty(arg_name: str, arg: str) -> None:
+    if not arg:
+        msg = f"{arg_name} should not be empty"
+        raise ValueError(msg)
+
+
 def _update_columns(df: DataFrame, new_columns: Sequence[str]) -> DataFrame:
     if df.width > len(new_columns):
         cols = df.columns
@@ -35,6 +35,7 @@ class BatchedCsvReader:
         separator: str = ",",
         comment_prefix: str | None = None,
         quote_char: str | None = '"',
+        escape_char: str | None = None,
         skip_rows: int = 0,
         skip_lines: int = 0,
         schema_overrides: SchemaDict | Sequence[PolarsDataType] | None = None,
@@ -96,6 +97,7 @@ class BatchedCsvReader:
             low_memory=low_memory,
             comment_prefix=comment_prefix,
             quote_char=quote_char,
+            escape_char=escape_char,
             null_values=processed_null_values,
             missing_utf8_is_empty_string=missing_utf8_is_empty_string,
             try_parse_dates=try_parse_dates,
@@ -5,10 +5,11 @@ import os
 from collections.abc import Sequence
 from io import BytesIO, StringIO
 from pathlib import Path
-from typing import IO, TYPE_CHECKING, Any, Callable, Literal
+from typing import IO, TYPE_CHECKING, Any, Callable, Literal, get_args
 
 import polars._reexport as pl
 import polars.functions as F
+from polars._typing import CsvEncoding
 from polars._utils.deprecation import deprecate_renamed_parameter
 from polars._utils.various import (
     _process_null_values,
@@ -28,7 +29,11 @@ from polars.io._utils import (
 from polars.io.cloud.credential_provider._builder import (
     _init_credential_provider_builder,
 )
-from polars.io.csv._utils import _check_arg_is_1byte, _update_columns
+from polars.io.csv._utils import (
+    _check_arg_is_1byte,
+    _check_arg_is_not_empty,
+    _update_columns,
+)
 from polars.io.csv.batched_reader import BatchedCsvReader
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -38,7 +43,7 @@ if TYPE_CHECKING:
     from collections.abc import Mapping
 
     from polars import DataFrame, LazyFrame
-    from polars._typing import CsvEncoding, PolarsDataType, SchemaDict
+    from polars._typing import PolarsDataType, SchemaDict
     from polars.io.cloud import CredentialProviderFunction
     from polars.io.cloud.credential_provider._builder import CredentialProviderBuilder
 
@@ -55,6 +60,7 @@ def read_csv(
     separator: str = ",",
     comment_prefix: str | None = None,
     quote_char: str | None = '"',
+    escape_char: str | None = None,
     skip_rows: int = 0,
     skip_lines: int = 0,
     schema: SchemaDict | None = None,
@@ -117,13 +123,18 @@ def read_csv(
         list is shorter than the width of the DataFrame the remaining
         columns will have their original name.
     separator
-        Single byte character to use as separator in the file.
+        Character or string to use as separator in the file. Separators longer than
+        a single byte, such as `||`, are slower to parse.
     comment_prefix
         A string used to indicate the start of a comment line. Comment lines are skipped
         during parsing. Common examples of comment prefixes are `#` and `//`.
     quote_char
         Single byte character used for csv quoting, default = `"`.
         Set to None to turn off special handling and escaping of quotes.
+    escape_char
+        Single byte character that escapes the character following it, such as `\`.
+        An escaped separator, line terminator or quote is read as a literal
+        character. Set to None (default) to only escape quotes by doubling them.
     skip_rows
         Start reading after ``skip_rows`` rows. The header will be parsed at this
         offset. Note that we respect CSV escaping/comments when skipping rows.
@@ -181,10 +192,13 @@ def read_csv(
         Stop reading from CSV file after reading `n_rows`.
         During multi-threaded parsing, an upper bound of `n_rows`
         rows cannot be guaranteed.
-    encoding : {'utf8', 'utf8-lossy', 'windows-1252', 'windows-1252-lossy', ...}
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'shift-jis', ...}
         Lossy means that invalid utf8 values are replaced with `�`
-        characters. When using other encodings than `utf8` or
-        `utf8-lossy`, the input is first decoded in memory with
+        characters. The encodings `latin1`, `windows-1250`, `windows-1251`,
+        `windows-1252`, `utf16-le`, `utf16-be`, `shift-jis`, `gb18030`,
+        `euc-kr` and `big5` are transcoded to utf8 by the reader and raise
+        an error on invalid input. When using any other encoding, e.g.
+        `windows-1252-lossy`, the input is first decoded in memory with
         python. Defaults to `utf8`.
     low_memory
         Reduce memory pressure at the expense of performance.
@@ -217,7 +231,7 @@ def read_csv(
         .. deprecated:: 1.10.0
             This parameter is now a no-op.
     eol_char
-        Single byte end of line character (default: `\n`). When encountering a file
+        End of line character or string (default: `\n`). When encountering a file
         with windows line endings (`\r\n`), one can go with the default `\n`. The extra
         `\r` will be removed when processed.
     raise_if_empty
@@ -276,9 +290,10 @@ def read_csv(
     │ 3   ┆ Charlie ┆ 2002-03-08 │
     └─────┴─────────┴────────────┘
     """
-    _check_arg_is_1byte("separator", separator, can_be_empty=False)
+    _check_arg_is_not_empty("separator", separator)
     _check_arg_is_1byte("quote_char", quote_char, can_be_empty=True)
-    _check_arg_is_1byte("eol_char", eol_char, can_be_empty=False)
+    _check_arg_is_1byte("escape_char", escape_char, can_be_empty=True)
+    _check_arg_is_not_empty("eol_char", eol_char)
 
     projection, columns = parse_columns_arg(columns)
     storage_options = storage_options or {}
@@ -305,6 +320,8 @@ def read_csv(
         and n_threads is None
         and not low_memory
         and null_values is None
+        and len(separator.encode("utf-8")) == 1
+        and escape_char is None
     ):
         include_columns: Sequence[str] | None = None
         if columns:
@@ -460,7 +477,7 @@ def read_csv(
 
     # TODO: scan_csv doesn't support a "dtype slice" (i.e. list[DataType])
     schema_overrides_is_list = isinstance(schema_overrides, Sequence)
-    encoding_supported_in_lazy = encoding in {"utf8", "utf8-lossy"}
+    encoding_supported_in_lazy = encoding in get_args(CsvEncoding)
 
     new_streaming = (
         os.getenv("POLARS_FORCE_NEW_STREAMING") == "1"
@@ -508,6 +525,7 @@ def read_csv(
             separator=separator,
             comment_prefix=comment_prefix,
             quote_char=quote_char,
+            escape_char=escape_char,
             skip_rows=skip_rows,
             skip_lines=skip_lines,
             schema_overrides=schema_overrides,  # type: ignore[arg-type]
@@ -539,9 +557,10 @@ def read_csv(
         df = lf.collect()
 
     else:
+        # Encodings that are not supported natively are decoded in Python.
         with prepare_file_arg(
             source,
-            encoding=encoding,
+            encoding="utf8" if encoding_supported_in_lazy else encoding,
             use_pyarrow=False,
             raise_if_empty=raise_if_empty,
             storage_options=storage_options,
@@ -553,6 +572,7 @@ def read_csv(
                 separator=separator,
                 comment_prefix=comment_prefix,
                 quote_char=quote_char,
+                escape_char=escape_char,
                 skip_rows=skip_rows,
                 skip_lines=skip_lines,
                 schema_overrides=schema_overrides,
@@ -565,7 +585,7 @@ def read_csv(
                 infer_schema_length=infer_schema_length,
                 batch_size=batch_size,
                 n_rows=n_rows,
-                encoding=encoding if encoding == "utf8-lossy" else "utf8",
+                encoding=encoding if encoding_supported_in_lazy else "utf8",
                 low_memory=low_memory,
                 rechunk=rechunk,
                 skip_rows_after_header=skip_rows_after_header,
@@ -591,6 +611,7 @@ def _read_csv_impl(
     separator: str = ",",
     comment_prefix: str | None = None,
     quote_char: str | None = '"',
+    escape_char: str | None = None,
     skip_rows: int = 0,
     skip_lines: int = 0,
     schema: None | SchemaDict = None,
@@ -661,6 +682,7 @@ def _read_csv_impl(
             separator=separator,
             comment_prefix=comment_prefix,
             quote_char=quote_char,
+            escape_char=escape_char,
             skip_rows=skip_rows,
             skip_lines=skip_lines,
             schema=schema,
@@ -715,6 +737,7 @@ def _read_csv_impl(
         low_memory,
         comment_prefix,
         quote_char,
+        escape_char,
         processed_null_values,
         missing_utf8_is_empty_string,
         try_parse_dates,
@@ -741,6 +764,7 @@ def read_csv_batched(
     separator: str = ",",
     comment_prefix: str | None = None,
     quote_char: str | None = '"',
+    escape_char: str | None = None,
     skip_rows: int = 0,
     skip_lines: int = 0,
     schema_overrides: (
@@ -799,13 +823,18 @@ def read_csv_batched(
         list is shorter than the width of the DataFrame the remaining
         columns will have their original name.
     separator
-        Single byte character to use as separator in the file.
+        Character or string to use as separator in the file. Separators longer than
+        a single byte, such as `||`, are slower to parse.
     comment_prefix
         A string used to indicate the start of a comment line. Comment lines are skipped
         during parsing. Common examples of comment prefixes are `#` and `//`.
     quote_char
         Single byte character used for csv quoting, default = `"`.
         Set to None to turn off special handling and escaping of quotes.
+    escape_char
+        Single byte character that escapes the character following it, such as `\`.
+        An escaped separator, line terminator or quote is read as a literal
+        character. Set to None (default) to only escape quotes by doubling them.
     skip_rows
         Start reading after ``skip_rows`` rows. The header will be parsed at this
         offset. Note that we respect CSV escaping/comments when skipping rows.
@@ -850,11 +879,12 @@ def read_csv_batched(
         Stop reading from CSV file after reading `n_rows`.
         During multi-threaded parsing, an upper bound of `n_rows`
         rows cannot be guaranteed.
-    encoding : {'utf8', 'utf8-lossy', ...}
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'shift-jis', ...}
         Lossy means that invalid utf8 values are replaced with `�`
-        characters. When using other encodings than `utf8` or
-        `utf8-lossy`, the input is first decoded in memory with
-        python. Defaults to `utf8`.
+        characters. The encodings `latin1`, `windows-1250`, `windows-1251`,
+        `windows-1252`, `utf16-le`, `utf16-be`, `shift-jis`, `gb18030`,
+        `euc-kr` and `big5` are transcoded to utf8 by the reader and raise
+        an error on invalid input. Defaults to `utf8`.
     low_memory
         Reduce memory pressure at the expense of performance.
     rechunk
@@ -875,7 +905,7 @@ def read_csv_batched(
         .. deprecated:: 1.10.0
             Is a no-op.
     eol_char
-        Single byte end of line character (default: `\n`). When encountering a file
+        End of line character or string (default: `\n`). When encountering a file
         with windows line endings (`\r\n`), one can go with the default `\n`. The extra
         `\r` will be removed when processed.
     raise_if_empty
@@ -1025,6 +1055,7 @@ def read_csv_batched(
         separator=separator,
         comment_prefix=comment_prefix,
         quote_char=quote_char,
+        escape_char=escape_char,
         skip_rows=skip_rows,
         skip_lines=skip_lines,
         schema_overrides=schema_overrides,
@@ -1036,7 +1067,7 @@ def read_csv_batched(
         infer_schema_length=infer_schema_length,
         batch_size=batch_size,
         n_rows=n_rows,
-        encoding=encoding if encoding == "utf8-lossy" else "utf8",
+        encoding=encoding if encoding in get_args(CsvEncoding) else "utf8",
         low_memory=low_memory,
         rechunk=rechunk,
         skip_rows_after_header=skip_rows_after_header,
@@ -1071,6 +1102,7 @@ def scan_csv(
     separator: str = ",",
     comment_prefix: str | None = None,
     quote_char: str | None = '"',
+    escape_char: str | None = None,
     skip_rows: int = 0,
     skip_lines: int = 0,
     schema: SchemaDict | None = None,
@@ -1126,13 +1158,18 @@ def scan_csv(
         column names will be autogenerated in the following format: `column_x`, with
         `x` being an enumeration over every column in the dataset, starting at 1.
     separator
-        Single byte character to use as separator in the file.
+        Character or string to use as separator in the file. Separators longer than
+        a single byte, such as `||`, are slower to parse.
     comment_prefix
         A string used to indicate the start of a comment line. Comment lines are skipped
         during parsing. Common examples of comment prefixes are `#` and `//`.
     quote_char
         Single byte character used for csv quoting, default = `"`.
         Set to None to turn off special handling and escaping of quotes.
+    escape_char
+        Single byte character that escapes the character following it, such as `\`.
+        An escaped separator, line terminator or quote is read as a literal
+        character. Set to None (default) to only escape quotes by doubling them.
     skip_rows
         Start reading after ``skip_rows`` rows. The header will be parsed at this
         offset. Note that we respect CSV escaping/comments when skipping rows.
@@ -1181,9 +1218,12 @@ def scan_csv(
         Set `infer_schema=False` to read all columns as `pl.String`.
     n_rows
         Stop reading from CSV file after reading `n_rows`.
-    encoding : {'utf8', 'utf8-lossy'}
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'shift-jis', ...}
         Lossy means that invalid utf8 values are replaced with `�`
-        characters. Defaults to "utf8".
+        characters. The encodings `latin1`, `windows-1250`, `windows-1251`,
+        `windows-1252`, `utf16-le`, `utf16-be`, `shift-jis`, `gb18030`,
+        `euc-kr` and `big5` are transcoded to utf8 by the reader and raise
+        an error on invalid input. Defaults to "utf8".
     low_memory
         Reduce memory pressure at the expense of performance.
     rechunk
@@ -1200,7 +1240,7 @@ def scan_csv(
         can be inferred, as well as a handful of others. If this does not succeed,
         the column remains of data type `pl.String`.
     eol_char
-        Single byte end of line character (default: `\n`). When encountering a file
+        End of line character or string (default: `\n`). When encountering a file
         with windows line endings (`\r\n`), one can go with the default `\n`. The extra
         `\r` will be removed when processed.
     new_columns
@@ -1336,8 +1376,10 @@ def scan_csv(
             else:
                 return new_columns  # type: ignore[return-value]
 
-    _check_arg_is_1byte("separator", separator, can_be_empty=False)
+    _check_arg_is_not_empty("separator", separator)
     _check_arg_is_1byte("quote_char", quote_char, can_be_empty=True)
+    _check_arg_is_1byte("escape_char", escape_char, can_be_empty=True)
+    _check_arg_is_not_empty("eol_char", eol_char)
 
     if isinstance(source, (str, Path)):
         source = normalize_filepath(source, check_not_directory=False)
@@ -1360,6 +1402,7 @@ def scan_csv(
         separator=separator,
         comment_prefix=comment_prefix,
         quote_char=quote_char,
+        escape_char=escape_char,
         skip_rows=skip_rows,
         skip_lines=skip_lines,
         schema_overrides=schema_overrides,  # type: ignore[arg-type]
@@ -1406,6 +1449,7 @@ def _scan_csv_impl(
     separator: str = ",",
     comment_prefix: str | None = None,
     quote_char: str | None = '"',
+    escape_char: str | None = None,
     skip_rows: int = 0,
     skip_lines: int = 0,
     schema: SchemaDict | None = None,
@@ -1471,6 +1515,7 @@ def _scan_csv_impl(
         low_memory=low_memory,
         comment_prefix=comment_prefix,
         quote_char=quote_char,
+        escape_char=escape_char,
         null_values=processed_null_values,
         missing_utf8_is_empty_string=missing_utf8_is_empty_string,
         infer_schema_length=infer_schema_length,
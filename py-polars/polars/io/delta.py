@@ -1,10 +1,13 @@
 from __future__ import annotations
 
+import contextlib
 import warnings
-from datetime import datetime
+from datetime import datetime, timezone
 from pathlib import Path
 from typing import TYPE_CHECKING, Any
 
+from polars._utils.unstable import issue_unstable_warning
+from polars._utils.wrap import wrap_ldf
 from polars.convert import from_arrow
 from polars.datatypes import Null, Time
 from polars.datatypes.convert import unpack_dtypes
@@ -15,6 +18,9 @@ from polars.io.pyarrow_dataset.functions import scan_pyarrow_dataset
 from polars.io.scan_options.cast_options import ScanCastOptions
 from polars.schema import Schema
 
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    from polars.polars import PyLazyFrame
+
 if TYPE_CHECKING:
     from typing import Literal
 
@@ -173,10 +179,15 @@ def scan_delta(
     use_pyarrow: bool = False,
     pyarrow_options: dict[str, Any] | None = None,
     rechunk: bool | None = None,
+    reader_override: Literal["native", "deltalake"] | None = None,
 ) -> LazyFrame:
     """
     Lazily read from a Delta lake table.
 
+    If the `deltalake` package is not installed, the transaction log of the table is
+    read by Polars, and the statistics of the data files are used to skip files that
+    cannot match a filter. This requires `source` to be a path or URI.
+
     Parameters
     ----------
     source
@@ -214,6 +225,16 @@ def scan_delta(
     rechunk
         Make sure that all columns are contiguous in memory by
         aggregating the chunks into a single array.
+    reader_override
+        Overrides the reader used to read the transaction log.
+
+        Warning: This parameter is considered unstable, and is subject to change.
+
+        Available options:
+
+        * native: Uses the polars native reader, which doesn't require `deltalake`.
+          It doesn't support tables with column mapping.
+        * deltalake: Uses `deltalake`.
 
     Returns
     -------
@@ -291,6 +312,39 @@ def scan_delta(
     ...     table_path, delta_table_options=delta_table_options
     ... ).collect()  # doctest: +SKIP
     """
+    if reader_override is not None:
+        msg = (
+            "the `reader_override` parameter of `scan_delta()` is considered unstable."
+        )
+        issue_unstable_warning(msg)
+
+    if reader_override == "native" or (
+        reader_override is None
+        and not _DELTALAKE_AVAILABLE
+        and isinstance(source, (str, Path))
+        and delta_table_options is None
+        and not use_pyarrow
+        and pyarrow_options is None
+        and _get_path_scheme(source) != "lakefs"
+    ):
+        if not isinstance(source, (str, Path)):
+            msg = "the native Delta reader requires `source` to be a path or URI"
+            raise TypeError(msg)
+        if delta_table_options is not None or use_pyarrow or pyarrow_options:
+            msg = (
+                "`delta_table_options`, `use_pyarrow` and `pyarrow_options` "
+                "require the `deltalake` reader"
+            )
+            raise ValueError(msg)
+
+        return _scan_delta_native(
+            source,
+            version=version,
+            storage_options=storage_options,
+            credential_provider=credential_provider,
+            rechunk=rechunk or False,
+        )
+
     _check_if_delta_available()
 
     credential_provider_creds = {}
@@ -420,6 +474,52 @@ def scan_delta(
     )
 
 
+def _scan_delta_native(
+    source: str | Path,
+    *,
+    version: int | str | datetime | None,
+    storage_options: dict[str, Any] | None,
+    credential_provider: CredentialProviderFunction | Literal["auto"] | None,
+    rechunk: bool,
+) -> LazyFrame:
+    """Scan a Delta lake table by reading its transaction log without `deltalake`."""
+    from polars.io.cloud.credential_provider._builder import (
+        _init_credential_provider_builder,
+    )
+
+    table_uri = _resolve_delta_lake_uri(source)
+
+    credential_provider_builder = _init_credential_provider_builder(
+        credential_provider, table_uri, storage_options, "scan_delta"
+    )
+    del credential_provider
+
+    timestamp_ms = None
+    if isinstance(version, str):
+        # `fromisoformat` only accepts a trailing "Z" from Python 3.11 onwards.
+        version = datetime.fromisoformat(version.replace("Z", "+00:00"))
+    if isinstance(version, datetime):
+        if version.tzinfo is None:
+            version = version.replace(tzinfo=timezone.utc)
+        timestamp_ms = int(version.timestamp() * 1000)
+        version = None
+
+    pylf = PyLazyFrame.new_from_delta(
+        table_uri,
+        version,
+        timestamp_ms,
+        n_rows=None,
+        cache=True,
+        rechunk=rechunk,
+        row_index=None,
+        cloud_options=list(storage_options.items()) if storage_options else None,
+        credential_provider=credential_provider_builder,
+        retries=2,
+        include_file_paths=None,
+    )
+    return wrap_ldf(pylf)
+
+
 def _resolve_delta_lake_uri(table_uri: str | Path, *, strict: bool = True) -> str:
     resolved_uri = str(
         Path(table_uri).expanduser().resolve(strict)
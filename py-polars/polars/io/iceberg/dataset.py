@@ -121,7 +121,11 @@ class IcebergDataset:
         )
 
         sources = []
-        deletion_files: dict[int, list[str]] = {}
+        # {source_idx: (data_file_path, data_sequence_number,
+        #   [(path, sequence_number, equality_ids)])}
+        deletion_files: dict[
+            int, tuple[str, int, list[tuple[str, int, list[int] | None]]]
+        ] = {}
 
         if reader_override != "pyiceberg" and not fallback_reason:
             from pyiceberg.manifest import DataFileContent, FileFormat
@@ -132,23 +136,35 @@ class IcebergDataset:
             start_time = perf_counter()
 
             scan = tbl.scan(
-                snapshot_id=snapshot_id, limit=limit, selected_fields=selected_fields
+                snapshot_id=snapshot_id,
+                limit=limit,
+                selected_fields=selected_fields,
             )
 
             total_deletion_files = 0
 
             for i, file_info in enumerate(scan.plan_files()):
-                if file_info.file.file_format != FileFormat.PARQUET:
-                    fallback_reason = (
-                        f"non-parquet format: {file_info.file.file_format}"
-                    )
+                data_file = file_info.file
+
+                if data_file.file_format != FileFormat.PARQUET:
+                    fallback_reason = f"non-parquet format: {data_file.file_format}"
                     break
 
                 if file_info.delete_files:
-                    deletion_files[i] = []
+                    # The delete files have already been matched to the data file
+                    # using their sequence numbers. The data file is given sequence
+                    # number 0 here, position deletes apply at the same sequence
+                    # number and equality deletes only at a larger one.
+                    deletion_files[i] = (data_file.file_path, 0, [])
 
                     for deletion_file in file_info.delete_files:
-                        if deletion_file.content != DataFileContent.POSITION_DELETES:
+                        equality_ids: list[int] | None = None
+                        sequence_number = 0
+
+                        if deletion_file.content == DataFileContent.EQUALITY_DELETES:
+                            equality_ids = list(deletion_file.equality_ids or [])
+                            sequence_number = 1
+                        elif deletion_file.content != DataFileContent.POSITION_DELETES:
                             fallback_reason = (
                                 "unsupported deletion file type: "
                                 f"{deletion_file.content}"
@@ -162,13 +178,15 @@ class IcebergDataset:
                             )
                             break
 
-                        deletion_files[i].append(deletion_file.file_path)
+                        deletion_files[i][2].append(
+                            (deletion_file.file_path, sequence_number, equality_ids)
+                        )
                         total_deletion_files += 1
 
                 if fallback_reason:
                     break
 
-                sources.append(file_info.file.file_path)
+                sources.append(data_file.file_path)
 
             if verbose:
                 elapsed = perf_counter() - start_time
@@ -226,7 +244,7 @@ class IcebergDataset:
                     # 'PARQUET:field_id'
                     schema_to_pyarrow(iceberg_schema),
                 ),
-                _deletion_files=("iceberg-position-delete", deletion_files),
+                _deletion_files=("iceberg-delete", deletion_files),
             )
 
         elif reader_override == "native":
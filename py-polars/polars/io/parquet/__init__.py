@@ -1,3 +1,7 @@
+from polars.io.parquet.encryption import (
+    ParquetDecryption,
+    ParquetEncryption,
+)
 from polars.io.parquet.field_overwrites import (
     ParquetFieldOverwrites,
 )
@@ -9,6 +13,8 @@ from polars.io.parquet.functions import (
 )
 
 __all__ = [
+    "ParquetDecryption",
+    "ParquetEncryption",
     "ParquetFieldOverwrites",
     "read_parquet",
     "read_parquet_metadata",
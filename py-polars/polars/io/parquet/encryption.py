@@ -0,0 +1,138 @@
+from __future__ import annotations
+
+from collections.abc import Mapping
+from typing import TYPE_CHECKING, Any
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+    from polars._typing import ParquetKeys
+
+
+def _parquet_keys_arg(
+    keys: ParquetKeys,
+) -> list[tuple[str, bytes]] | Callable[..., Any]:
+    if isinstance(keys, Mapping):
+        return [(key_id, bytes(key)) for key_id, key in keys.items()]
+    elif callable(keys):
+        return keys
+
+    msg = f"keys got the wrong type {type(keys)}"
+    raise TypeError(msg)
+
+
+class ParquetEncryption:
+    """
+    Encrypt a Parquet file with the `AES_GCM_V1` algorithm of Parquet encryption.
+
+    The footer and the encrypted columns store the id of their key, by which readers
+    retrieve the key with :class:`ParquetDecryption`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    footer_key_id
+        The id of the key that encrypts the footer. It also encrypts every column if
+        no `column_key_ids` are given.
+    keys
+        The AES keys of 16 or 32 bytes by their id, or a function that returns the key
+        of a key id. Query plans with keys given by id can't be serialized.
+    column_key_ids
+        The ids of the keys of the encrypted columns, by column name. Columns that are
+        not given are written unencrypted. Every column must be in the schema.
+    aad_prefix
+        Authenticated together with every part of the file, e.g. to bind the file to
+        its path.
+    store_aad_prefix
+        Store the `aad_prefix` in the file. If not, readers have to supply it.
+
+    Examples
+    --------
+    >>> lf = pl.LazyFrame({"id": [1, 2], "ssn": ["123-45-6789", "987-65-4321"]})
+    >>> keys = {"footer": b"0123456789012345", "pii": b"5432109876543210"}
+    >>> lf.sink_parquet(
+    ...     "./out.parquet",
+    ...     encryption=ParquetEncryption("footer", keys, column_key_ids={"ssn": "pii"}),
+    ... )  # doctest: +SKIP
+    >>> pl.read_parquet(
+    ...     "./out.parquet", decryption=ParquetDecryption(keys)
+    ... )  # doctest: +SKIP
+    """
+
+    footer_key_id: str  #: The id of the key that encrypts the footer
+    keys: ParquetKeys  #: The keys by their id
+    column_key_ids: dict[str, str] | None  #: The key ids of the encrypted columns
+    aad_prefix: bytes | None  #: Authenticated with every part of the file
+    store_aad_prefix: bool  #: Store the `aad_prefix` in the file
+
+    def __init__(
+        self,
+        footer_key_id: str,
+        keys: ParquetKeys,
+        *,
+        column_key_ids: Mapping[str, str] | None = None,
+        aad_prefix: bytes | None = None,
+        store_aad_prefix: bool = True,
+    ) -> None:
+        self.footer_key_id = footer_key_id
+        self.keys = dict(keys) if isinstance(keys, Mapping) else keys
+        self.column_key_ids = (
+            dict(column_key_ids) if column_key_ids is not None else None
+        )
+        self.aad_prefix = aad_prefix
+        self.store_aad_prefix = store_aad_prefix
+
+    def _to_dict(self) -> dict[str, Any]:
+        return {
+            "footer_key_id": self.footer_key_id,
+            "column_key_ids": list((self.column_key_ids or {}).items()),
+            "keys": _parquet_keys_arg(self.keys),
+            "aad_prefix": self.aad_prefix,
+            "store_aad_prefix": self.store_aad_prefix,
+        }
+
+
+class ParquetDecryption:
+    """
+    Decrypt Parquet files that were encrypted with Parquet modular encryption.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    keys
+        The AES keys by the key id stored in the files, or a function that returns the
+        key of a key id. Query plans with keys given by id can't be serialized.
+    aad_prefix
+        The AAD prefix of files that were written without storing it.
+
+    Examples
+    --------
+    >>> pl.scan_parquet(
+    ...     "./out.parquet",
+    ...     decryption=ParquetDecryption(lambda key_id: kms.get_key(key_id)),
+    ... )  # doctest: +SKIP
+    """
+
+    keys: ParquetKeys  #: The keys by their id
+    aad_prefix: bytes | None  #: The AAD prefix of the files
+
+    def __init__(
+        self,
+        keys: ParquetKeys,
+        *,
+        aad_prefix: bytes | None = None,
+    ) -> None:
+        self.keys = dict(keys) if isinstance(keys, Mapping) else keys
+        self.aad_prefix = aad_prefix
+
+    def _to_dict(self) -> dict[str, Any]:
+        return {
+            "keys": _parquet_keys_arg(self.keys),
+            "aad_prefix": self.aad_prefix,
+        }
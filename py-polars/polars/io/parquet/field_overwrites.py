@@ -1,7 +1,10 @@
 from __future__ import annotations
 
 from collections.abc import Mapping, Sequence
-from typing import Any
+from typing import TYPE_CHECKING, Any
+
+if TYPE_CHECKING:
+    from polars._typing import ParquetCompression, ParquetEncoding
 
 
 def _parquet_field_overwrites_dict_to_dict_list(
@@ -46,6 +49,48 @@ def _parquet_field_overwrites_to_dict(pqo: ParquetFieldOverwrites) -> dict[str,
     if pqo.required is not None:
         d["required"] = pqo.required
 
+    # Bloom filter
+    if pqo.bloom_filter is True:
+        d["bloom_filter"] = (None, None)
+    elif isinstance(pqo.bloom_filter, dict):
+        if unknown := set(pqo.bloom_filter) - {"ndv", "fpp"}:
+            msg = f"unknown bloom filter options: {sorted(unknown)}"
+            raise ValueError(msg)
+        d["bloom_filter"] = (pqo.bloom_filter.get("ndv"), pqo.bloom_filter.get("fpp"))
+
+    # Encoding
+    if pqo.encoding is not None:
+        d["encoding"] = pqo.encoding
+    if pqo.dictionary is not None:
+        d["dictionary"] = pqo.dictionary
+
+    # Compression
+    if pqo.compression is not None:
+        d["compression"] = (pqo.compression, pqo.compression_level)
+    elif pqo.compression_level is not None:
+        msg = "ParquetFieldOverwrites has a `compression_level` but no `compression`"
+        raise ValueError(msg)
+
+    # Statistics
+    if pqo.statistics is True:
+        d["statistics"] = {
+            "min": True,
+            "max": True,
+            "distinct_count": False,
+            "null_count": True,
+        }
+    elif pqo.statistics is False:
+        d["statistics"] = {}
+    elif pqo.statistics == "full":
+        d["statistics"] = {
+            "min": True,
+            "max": True,
+            "distinct_count": True,
+            "null_count": True,
+        }
+    elif isinstance(pqo.statistics, dict):
+        d["statistics"] = pqo.statistics
+
     return d
 
 
@@ -89,6 +134,35 @@ class ParquetFieldOverwrites:
     ...         ),
     ...     },
     ... )  # doctest: +SKIP
+
+    Write bloom filters for a column, which allow readers to skip row groups that
+    don't contain the values of an equality filter. They are sized for the number of
+    distinct values (`ndv`) at a false positive probability (`fpp`), which default to
+    the distinct values in each row group and 0.05. Float columns don't get bloom
+    filters, as equal floats (e.g. `0.0` and `-0.0`) can hash differently.
+
+    >>> lf.sink_parquet(
+    ...     "./out/parquet",
+    ...     field_overwrites={
+    ...         "a": ParquetFieldOverwrites(bloom_filter={"fpp": 0.01}),
+    ...     },
+    ... )  # doctest: +SKIP
+
+    Overwrite the encoding, compression and statistics of a column. Setting an
+    `encoding` disables dictionary encoding, unless `dictionary=True` is also given,
+    in which case the encoding is used when the values don't fit a dictionary.
+
+    >>> lf.sink_parquet(
+    ...     "./out/parquet",
+    ...     field_overwrites={
+    ...         "a": ParquetFieldOverwrites(
+    ...             encoding="delta_binary_packed",
+    ...             compression="zstd",
+    ...             compression_level=10,
+    ...             statistics=False,
+    ...         ),
+    ...     },
+    ... )  # doctest: +SKIP
     """
 
     name: None | str  #: Name of the column or field
@@ -108,6 +182,20 @@ class ParquetFieldOverwrites:
         dict[str, None | str] | None
     )  #: Arrow metadata added to the field before writing
     required: bool | None = None  #: Is the field not allowed to have missing values
+    bloom_filter: (
+        bool | dict[str, int | float] | None
+    )  #: Write a bloom filter for the field, optionally sized with `ndv` and `fpp`
+    encoding: (
+        ParquetEncoding | None
+    )  #: The encoding of the values of a non-nested field
+    dictionary: bool | None = None  #: Try to dictionary-encode the field's values
+    compression: (
+        ParquetCompression | None
+    )  #: The compression of the field, also applied to its children
+    compression_level: int | None = None  #: The level of the field's `compression`
+    statistics: (
+        bool | str | dict[str, bool] | None
+    )  #: The statistics of the field, also applied to its children
 
     def __init__(
         self,
@@ -122,6 +210,12 @@ class ParquetFieldOverwrites:
         field_id: int | None = None,
         metadata: Mapping[str, None | str] | None = None,
         required: bool | None = None,
+        bloom_filter: bool | Mapping[str, int | float] | None = None,
+        encoding: ParquetEncoding | None = None,
+        dictionary: bool | None = None,
+        compression: ParquetCompression | None = None,
+        compression_level: int | None = None,
+        statistics: bool | str | Mapping[str, bool] | None = None,
     ) -> None:
         self.name = name
 
@@ -138,3 +232,15 @@ class ParquetFieldOverwrites:
         else:
             self.metadata = metadata
         self.required = required
+        if isinstance(bloom_filter, Mapping):
+            self.bloom_filter = dict(bloom_filter)
+        else:
+            self.bloom_filter = bloom_filter
+        self.encoding = encoding
+        self.dictionary = dictionary
+        self.compression = compression
+        self.compression_level = compression_level
+        if isinstance(statistics, Mapping):
+            self.statistics = dict(statistics)
+        else:
+            self.statistics = statistics
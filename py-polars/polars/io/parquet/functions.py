@@ -45,6 +45,7 @@ if TYPE_CHECKING:
         SchemaDict,
     )
     from polars.io.cloud import CredentialProviderFunction
+    from polars.io.parquet.encryption import ParquetDecryption
     from polars.io.scan_options import ScanCastOptions
 
 
@@ -75,6 +76,7 @@ def read_parquet(
     include_file_paths: str | None = None,
     missing_columns: Literal["insert", "raise"] = "raise",
     allow_missing_columns: bool | None = None,
+    decryption: ParquetDecryption | None = None,
 ) -> DataFrame:
     """
     Read into a DataFrame from a parquet file.
@@ -194,6 +196,13 @@ def read_parquet(
         .. deprecated:: 1.30.0
             Use the parameter `missing_columns` instead and pass one of
             `('insert', 'raise')`.
+    decryption
+        Keys to decrypt files that were written with Parquet modular encryption.
+        Only valid when `use_pyarrow=False`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
     Returns
     -------
@@ -231,6 +240,9 @@ def read_parquet(
         if schema is not None:
             msg = "`schema` cannot be used with `use_pyarrow=True`"
             raise ValueError(msg)
+        if decryption is not None:
+            msg = "`decryption` cannot be used with `use_pyarrow=True`"
+            raise ValueError(msg)
         if hive_schema is not None:
             msg = (
                 "cannot use `hive_partitions` with `use_pyarrow=True`"
@@ -277,6 +289,7 @@ def read_parquet(
         glob=glob,
         include_file_paths=include_file_paths,
         missing_columns=missing_columns,
+        decryption=decryption,
     )
 
     if columns is not None:
@@ -423,6 +436,7 @@ def scan_parquet(
     allow_missing_columns: bool | None = None,
     extra_columns: Literal["ignore", "raise"] = "raise",
     cast_options: ScanCastOptions | None = None,
+    decryption: ParquetDecryption | None = None,
     _column_mapping: ColumnMapping | None = None,
     _deletion_files: DeletionFiles | None = None,
 ) -> LazyFrame:
@@ -557,6 +571,12 @@ def scan_parquet(
         Configuration for column type-casting during scans. Useful for datasets
         containing files that have differing schemas.
 
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    decryption
+        Keys to decrypt files that were written with Parquet modular encryption.
+
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
@@ -595,6 +615,10 @@ def scan_parquet(
         msg = "The `cast_options` parameter of `scan_parquet` is considered unstable."
         issue_unstable_warning(msg)
 
+    if decryption is not None:
+        msg = "The `decryption` parameter of `scan_parquet` is considered unstable."
+        issue_unstable_warning(msg)
+
     if allow_missing_columns is not None:
         issue_deprecation_warning(
             "the parameter `allow_missing_columns` for `scan_parquet` is deprecated. "
@@ -630,6 +654,7 @@ def scan_parquet(
         parallel=parallel,
         low_memory=low_memory,
         use_statistics=use_statistics,
+        decryption=decryption._to_dict() if decryption is not None else None,
         scan_options=ScanOptions(
             row_index=(
                 (row_index_name, row_index_offset)
@@ -121,11 +121,14 @@ if TYPE_CHECKING:
     from polars import DataFrame, DataType, Expr
     from polars._typing import (
         AsofJoinStrategy,
+        AvroCompression,
         ClosedInterval,
         ColumnNameOrSelector,
+        CsvEncoding,
         CsvQuoteStyle,
         EngineType,
         ExplainFormat,
+        ExternalCompression,
         FillNullStrategy,
         FrameInitTypes,
         IntoExpr,
@@ -150,7 +153,7 @@ if TYPE_CHECKING:
     )
     from polars.dependencies import numpy as np
     from polars.io.cloud import CredentialProviderFunction
-    from polars.io.parquet import ParquetFieldOverwrites
+    from polars.io.parquet import ParquetEncryption, ParquetFieldOverwrites
 
     if sys.version_info >= (3, 10):
         from typing import Concatenate, ParamSpec
@@ -2510,6 +2513,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         | Sequence[ParquetFieldOverwrites]
         | Mapping[str, ParquetFieldOverwrites]
         | None = None,
+        encryption: ParquetEncryption | None = None,
         engine: EngineType = "auto",
         metadata: ParquetMetadata | None = None,
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -2538,6 +2542,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         | Sequence[ParquetFieldOverwrites]
         | Mapping[str, ParquetFieldOverwrites]
         | None = None,
+        encryption: ParquetEncryption | None = None,
         engine: EngineType = "auto",
         metadata: ParquetMetadata | None = None,
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -2566,6 +2571,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         | Sequence[ParquetFieldOverwrites]
         | Mapping[str, ParquetFieldOverwrites]
         | None = None,
+        encryption: ParquetEncryption | None = None,
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
     ) -> LazyFrame | None:
@@ -2681,6 +2687,13 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             This allows more control over the writing process to the granularity of a
             Parquet field.
 
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        encryption
+            Encrypt the file with Parquet modular encryption. The footer is always
+            encrypted, so readers need the keys to read any of the columns.
+
             .. warning::
                 This functionality is considered **unstable**. It may be changed
                 at any point without it being considered a breaking change.
@@ -2763,6 +2776,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         elif callable(metadata):
             metadata = wrap_parquet_metadata_callback(metadata)  # type: ignore[assignment]
 
+        if encryption is not None:
+            msg = "the `encryption` parameter of `sink_parquet` is considered unstable."
+            issue_unstable_warning(msg)
+
         # Convert the field overwrites into something that can be ingested by Rust.
         field_overwrites_dicts: list[dict[str, Any]] = []
         if field_overwrites is not None:
@@ -2803,6 +2820,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             sink_options=sink_options,
             metadata=metadata,
             field_overwrites=field_overwrites_dicts,
+            encryption=encryption._to_dict() if encryption is not None else None,
         )
 
         if not lazy:
@@ -3016,6 +3034,203 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             return None
         return LazyFrame._from_pyldf(ldf)
 
+    @overload
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitioningScheme,
+        *,
+        compression: AvroCompression | None = "uncompressed",
+        name: str = "",
+        maintain_order: bool = True,
+        storage_options: dict[str, Any] | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        retries: int = 2,
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        lazy: Literal[False] = ...,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> None: ...
+
+    @overload
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitioningScheme,
+        *,
+        compression: AvroCompression | None = "uncompressed",
+        name: str = "",
+        maintain_order: bool = True,
+        storage_options: dict[str, Any] | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        retries: int = 2,
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        lazy: Literal[True],
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> LazyFrame: ...
+
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitioningScheme,
+        *,
+        compression: AvroCompression | None = "uncompressed",
+        name: str = "",
+        maintain_order: bool = True,
+        storage_options: dict[str, Any] | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        retries: int = 2,
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        lazy: bool = False,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> LazyFrame | None:
+        """
+        Evaluate the query in streaming mode and write to an Apache Avro file.
+
+        This allows streaming results that are larger than RAM to be written to disk.
+
+        Parameters
+        ----------
+        path
+            File path to which the file should be written.
+        compression : {'uncompressed', 'snappy', 'deflate'}
+            Compression method. Defaults to "uncompressed".
+        name
+            Schema name. Defaults to empty string.
+        maintain_order
+            Maintain the order in which data is processed.
+            Setting this to `False` will be slightly faster.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+        storage_options
+            Options that indicate how to connect to a cloud provider.
+
+            The cloud providers currently supported are AWS, GCP, and Azure.
+            See supported keys here:
+
+            * `aws <https://docs.rs/object_store/latest/object_store/aws/enum.AmazonS3ConfigKey.html>`_
+            * `gcp <https://docs.rs/object_store/latest/object_store/gcp/enum.GoogleConfigKey.html>`_
+            * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
+            * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
+            `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+
+            If `storage_options` is not provided, Polars will try to infer the
+            information from environment variables.
+        credential_provider
+            Provide a function that can be called to provide cloud storage
+            credentials. The function is expected to return a dictionary of
+            credential keys along with an optional credential expiry time.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        retries
+            Number of retries if accessing a cloud instance fails.
+        sync_on_close: { None, 'data', 'all' }
+            Sync to disk when before closing a file.
+
+            * `None` does not sync.
+            * `data` syncs the file contents.
+            * `all` syncs the file contents and metadata.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+        mkdir: bool
+            Recursively create all the directories in the path.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+        lazy: bool
+            Wait to start execution until `collect` is called.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+        engine
+            Select the engine used to process the query, optional.
+            At the moment, if set to `"auto"` (default), the query is run
+            using the polars streaming engine. Polars will also
+            attempt to use the engine set by the `POLARS_ENGINE_AFFINITY`
+            environment variable. If it cannot run the query using the
+            selected engine, the query is run using the polars streaming
+            engine.
+
+            .. note::
+               The GPU engine is currently not supported.
+        optimizations
+            The optimization passes done during query optimization.
+
+            This has no effect if `lazy` is set to `True`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+
+        Returns
+        -------
+        DataFrame
+
+        Examples
+        --------
+        >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
+        >>> lf.sink_avro("out.avro")  # doctest: +SKIP
+        """
+        engine = _select_engine(engine)
+
+        from polars.io.cloud.credential_provider._builder import (
+            _init_credential_provider_builder,
+        )
+
+        credential_provider_builder = _init_credential_provider_builder(
+            credential_provider, path, storage_options, "sink_avro"
+        )
+        del credential_provider
+
+        if storage_options:
+            storage_options = list(storage_options.items())  # type: ignore[assignment]
+        else:
+            # Handle empty dict input
+            storage_options = None
+
+        target = _to_sink_target(path)
+        sink_options = {
+            "sync_on_close": sync_on_close or "none",
+            "maintain_order": maintain_order,
+            "mkdir": mkdir,
+        }
+
+        if compression is None:
+            compression = "uncompressed"
+
+        ldf = self._ldf.sink_avro(
+            target=target,
+            compression=compression,
+            name=name,
+            cloud_options=storage_options,
+            credential_provider=credential_provider_builder,
+            retries=retries,
+            sink_options=sink_options,
+        )
+
+        if not lazy:
+            ldf = ldf.with_optimizations(optimizations._pyoptflags)
+            ldf = LazyFrame._from_pyldf(ldf)
+            ldf.collect(engine=engine)
+            return None
+        return LazyFrame._from_pyldf(ldf)
+
     @overload
     def sink_csv(
         self,
@@ -3023,6 +3238,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         *,
         include_bom: bool = False,
         include_header: bool = True,
+        encoding: CsvEncoding = "utf8",
         separator: str = ",",
         line_terminator: str = "\n",
         quote_char: str = '"',
@@ -3035,6 +3251,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3055,6 +3273,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         *,
         include_bom: bool = False,
         include_header: bool = True,
+        encoding: CsvEncoding = "utf8",
         separator: str = ",",
         line_terminator: str = "\n",
         quote_char: str = '"',
@@ -3067,6 +3286,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3086,6 +3307,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         *,
         include_bom: bool = False,
         include_header: bool = True,
+        encoding: CsvEncoding = "utf8",
         separator: str = ",",
         line_terminator: str = "\n",
         quote_char: str = '"',
@@ -3098,6 +3320,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3120,9 +3344,15 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         path
             File path to which the file should be written.
         include_bom
-            Whether to include UTF-8 BOM in the CSV output.
+            Whether to include a byte order mark in the CSV output. Only
+            supported by the utf8 and utf16 encodings.
         include_header
             Whether to include header in the CSV output.
+        encoding : {'utf8', 'latin1', 'windows-1252', 'utf16-le', 'shift-jis', ...}
+            Text encoding of the CSV output. One of `utf8`, `latin1`,
+            `windows-1250`, `windows-1251`, `windows-1252`, `utf16-le`,
+            `utf16-be`, `shift-jis`, `gb18030`, `euc-kr` or `big5`. Characters
+            that cannot be represented in the encoding raise an error.
         separator
             Separate CSV fields with this symbol.
         line_terminator
@@ -3173,6 +3403,21 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
               Namely, when writing a field that does not parse as a valid float
               or integer, then quotes will be used even if they aren`t strictly
               necessary.
+        compression : {'uncompressed', 'gzip', 'zstd', 'bzip2', 'xz', 'lz4'}
+            Compress the whole output file with this codec. Batches are compressed
+            in parallel and written as concatenated frames. Polars and the
+            command-line tools of these codecs read them back as a single stream,
+            but some decoders stop after the first frame.
+        compression_level
+            The level of compression to use. Higher compression means smaller files
+            on disk, at the cost of slower writes. Uses the codec's default if not
+            set.
+
+            - "gzip" : min-level: 0, max-level: 9.
+            - "zstd" : min-level: -131072, max-level: 22.
+            - "bzip2" : min-level: 1, max-level: 9.
+            - "xz" : min-level: 0, max-level: 9.
+            - "lz4" : min-level: 0, max-level: 16.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -3286,6 +3531,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             target=target,
             include_bom=include_bom,
             include_header=include_header,
+            encoding=encoding,
             separator=ord(separator),
             line_terminator=line_terminator,
             quote_char=ord(quote_char),
@@ -3298,6 +3544,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             decimal_comma=decimal_comma,
             null_value=null_value,
             quote_style=quote_style,
+            compression=compression,
+            compression_level=compression_level,
             cloud_options=storage_options,
             credential_provider=credential_provider_builder,
             retries=retries,
@@ -3316,6 +3564,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitioningScheme,
         *,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3334,6 +3584,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitioningScheme,
         *,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3351,6 +3603,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitioningScheme,
         *,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3372,6 +3626,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         ----------
         path
             File path to which the file should be written.
+        compression : {'uncompressed', 'gzip', 'zstd', 'bzip2', 'xz', 'lz4'}
+            Compress the whole output file with this codec. Batches are compressed
+            in parallel and written as concatenated frames. Polars and the
+            command-line tools of these codecs read them back as a single stream,
+            but some decoders stop after the first frame.
+        compression_level
+            The level of compression to use. Uses the codec's default if not set.
+            See :meth:`sink_csv` for the valid range of each codec.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -3477,6 +3739,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         ldf = self._ldf.sink_json(
             target=target,
+            compression=compression,
+            compression_level=compression_level,
             cloud_options=storage_options,
             credential_provider=credential_provider_builder,
             retries=retries,
@@ -43,6 +43,7 @@ class QueryOptFlags:
         collapse_joins: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
+        sortedness: None | bool = None,
     ) -> None:
         self._pyoptflags = PyOptFlags.default()
         self.update(
@@ -56,6 +57,7 @@ class QueryOptFlags:
             collapse_joins=collapse_joins,
             check_order_observe=check_order_observe,
             fast_projection=fast_projection,
+            sortedness=sortedness,
         )
 
     @classmethod
@@ -77,6 +79,7 @@ class QueryOptFlags:
         collapse_joins: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
+        sortedness: None | bool = None,
     ) -> QueryOptFlags:
         """Create new empty set off optimizations."""
         optflags = QueryOptFlags()
@@ -92,6 +95,7 @@ class QueryOptFlags:
             collapse_joins=collapse_joins,
             check_order_observe=check_order_observe,
             fast_projection=fast_projection,
+            sortedness=sortedness,
         )
 
     def update(
@@ -107,6 +111,7 @@ class QueryOptFlags:
         collapse_joins: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
+        sortedness: None | bool = None,
     ) -> QueryOptFlags:
         """Update the current optimization flags."""
         if predicate_pushdown is not None:
@@ -129,6 +134,8 @@ class QueryOptFlags:
             self.check_order_observe = check_order_observe
         if fast_projection is not None:
             self.fast_projection = fast_projection
+        if sortedness is not None:
+            self.sortedness = sortedness
 
         return self
 
@@ -241,6 +248,15 @@ class QueryOptFlags:
     def fast_projection(self, value: bool) -> None:
         self._pyoptflags.fast_projection = value
 
+    @property
+    def sortedness(self) -> bool:
+        """Remove sorts of data that is known to be sorted already."""
+        return self._pyoptflags.sortedness
+
+    @sortedness.setter
+    def sortedness(self, value: bool) -> None:
+        self._pyoptflags.sortedness = value
+
     def __str__(self) -> str:
         return f"""
 QueryOptFlags {{
@@ -257,6 +273,7 @@ QueryOptFlags {{
     collapse_joins: {self.collapse_joins}
     check_order_observe: {self.check_order_observe}
     fast_projection: {self.fast_projection}
+    sortedness: {self.sortedness}
 
     eager: {self._pyoptflags.eager}
     streaming: {self._pyoptflags.streaming}
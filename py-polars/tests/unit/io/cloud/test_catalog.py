@@ -1,6 +1,17 @@
+from __future__ import annotations
+
+import json
+import threading
+from http.server import BaseHTTPRequestHandler, HTTPServer
+from typing import TYPE_CHECKING, Any
+
 import pytest
 
 import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
 
 
 def test_catalog_require_https() -> None:
@@ -9,3 +20,75 @@ def test_catalog_require_https() -> None:
 
     pl.Catalog("https://")
     pl.Catalog("http://", require_https=False)
+
+
+def test_catalog_sink_table(tmp_path: Path) -> None:
+    table_info = {
+        "name": "t",
+        "table_id": "00000000-0000-0000-0000-000000000000",
+        "table_type": "EXTERNAL",
+        "storage_location": str(tmp_path),
+        "data_source_format": "DELTA",
+        "created_at": None,
+        "updated_at": None,
+    }
+
+    requests: list[str] = []
+
+    class Handler(BaseHTTPRequestHandler):
+        def do_GET(self) -> None:
+            requests.append(self.path)
+            body = json.dumps(table_info).encode()
+            self.send_response(200)
+            self.send_header("Content-Type", "application/json")
+            self.send_header("Content-Length", str(len(body)))
+            self.end_headers()
+            self.wfile.write(body)
+
+        def log_message(self, *args: Any) -> None:
+            pass
+
+    server = HTTPServer(("127.0.0.1", 0), Handler)
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+
+    try:
+        catalog = pl.Catalog(
+            f"http://127.0.0.1:{server.server_port}",
+            bearer_token=None,
+            require_https=False,
+        )
+        df = pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", None]})
+
+        catalog.sink_table(df.lazy(), "c", "s", "t", credential_provider=None)
+        # The table info is only fetched once.
+        assert len(requests) == 1
+
+        catalog.sink_table(
+            df.lazy().select("b", pl.col("a") + 3),
+            "c",
+            "s",
+            "t",
+            credential_provider=None,
+        )
+        assert_frame_equal(
+            pl.scan_delta(str(tmp_path)).collect(),
+            pl.concat([df, df.with_columns(pl.col("a") + 3)]),
+            check_row_order=False,
+        )
+
+        catalog.sink_table(
+            df.lazy().head(1),
+            "c",
+            "s",
+            "t",
+            mode="overwrite",
+            credential_provider=None,
+        )
+        assert_frame_equal(pl.scan_delta(str(tmp_path)).collect(), df.head(1))
+
+        with pytest.raises(pl.exceptions.SchemaError):
+            catalog.sink_table(
+                df.lazy().select("a"), "c", "s", "t", credential_provider=None
+            )
+    finally:
+        server.shutdown()
@@ -89,3 +89,85 @@ def test_with_name() -> None:
     read_df = pl.read_json(raw[raw.find(b"{") : raw.rfind(b"}") + 1])
 
     assert_frame_equal(expected, read_df)
+
+
+@pytest.mark.parametrize("compression", COMPRESSIONS)
+def test_scan_avro(compression: AvroCompression) -> None:
+    df = pl.DataFrame({"a": range(10), "b": [str(i) for i in range(10)]})
+
+    f = io.BytesIO()
+    df.write_avro(f, compression=compression)
+    f.seek(0)
+
+    assert_frame_equal(pl.scan_avro(f).collect(), df)
+    assert_frame_equal(pl.scan_avro(f).select("b").collect(), df.select("b"))
+    assert_frame_equal(pl.scan_avro(f).slice(3, 4).collect(), df.slice(3, 4))
+    assert_frame_equal(pl.scan_avro(f).tail(2).collect(), df.tail(2))
+    assert_frame_equal(pl.scan_avro(f, n_rows=3).collect(), df.head(3))
+    assert pl.scan_avro(f).select(pl.len()).item() == 10
+
+
+def test_scan_avro_row_index_multiple_blocks() -> None:
+    dfs = [
+        pl.DataFrame({"a": range(i * 5, (i + 1) * 5)}, schema={"a": pl.Int64})
+        for i in range(3)
+    ]
+    # Every chunk is written as a separate block.
+    df = pl.concat(dfs, rechunk=False)
+
+    f = io.BytesIO()
+    df.write_avro(f)
+    f.seek(0)
+
+    expected = df.with_row_index("idx", offset=7)
+    out = pl.scan_avro(f, row_index_name="idx", row_index_offset=7)
+    assert_frame_equal(out.collect(), expected)
+    assert_frame_equal(out.slice(4, 7).collect(), expected.slice(4, 7))
+    assert_frame_equal(
+        out.select("idx").slice(12).collect(), expected.select("idx").slice(12)
+    )
+
+
+@pytest.mark.write_disk
+def test_scan_avro_hive(tmp_path: Path) -> None:
+    for part in [1, 2]:
+        (tmp_path / f"part={part}").mkdir()
+        pl.DataFrame({"a": [part * 10, part * 10 + 1]}).write_avro(
+            tmp_path / f"part={part}" / "data.avro"
+        )
+
+    out = pl.scan_avro(tmp_path, include_file_paths="path").collect()
+    assert out.columns == ["a", "part", "path"]
+    assert out["a"].to_list() == [10, 11, 20, 21]
+    assert out["part"].to_list() == [1, 1, 2, 2]
+
+    out = pl.scan_avro(tmp_path).filter(pl.col("part") == 2).collect()
+    assert out["a"].to_list() == [20, 21]
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("compression", COMPRESSIONS)
+def test_sink_avro(compression: AvroCompression, tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "a": range(100_000),
+            "b": pl.Series(range(100_000)).cast(pl.String),
+            "c": [None, 1.5] * 50_000,
+        }
+    )
+
+    file_path = tmp_path / "sink.avro"
+    df.lazy().sink_avro(file_path, compression=compression, name="my_schema")
+
+    assert_frame_equal(pl.read_avro(file_path), df)
+    assert_frame_equal(pl.scan_avro(file_path).collect(), df)
+
+
+@pytest.mark.write_disk
+def test_sink_avro_empty(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": [1, 2]}).clear()
+
+    file_path = tmp_path / "empty.avro"
+    df.lazy().sink_avro(file_path)
+
+    assert_frame_equal(pl.read_avro(file_path), df)
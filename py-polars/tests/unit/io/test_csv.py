@@ -25,7 +25,7 @@ from polars.testing import assert_frame_equal, assert_series_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
-    from polars._typing import CsvQuoteStyle, TimeUnit
+    from polars._typing import CsvEncoding, CsvQuoteStyle, TimeUnit
     from tests.unit.conftest import MemoryUsage
 
 
@@ -532,6 +532,65 @@ def test_read_csv_encoding_lossy(tmp_path: Path) -> None:
         )
 
 
+@pytest.mark.parametrize(
+    ("encoding", "codec", "city"),
+    [
+        ("latin1", "latin-1", "Zürich"),
+        ("windows-1252", "cp1252", "€ Köln"),
+        ("windows-1251", "cp1251", "Москва"),
+        ("utf16-le", "utf-16-le", "東京"),
+        ("shift-jis", "shift_jis", "東京"),
+        ("gb18030", "gb18030", "北京"),
+    ],
+)
+@pytest.mark.write_disk
+def test_read_write_csv_native_encoding(
+    encoding: CsvEncoding, codec: str, city: str, tmp_path: Path
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+
+    df = pl.DataFrame({"id": [1, 2, 3], "city": [city, None, city * 2]})
+    file_path = tmp_path / "encoded.csv"
+    file_path.write_bytes(df.write_csv().encode(codec))
+
+    assert_frame_equal(pl.read_csv(file_path, encoding=encoding), df)
+    assert_frame_equal(pl.read_csv(file_path.read_bytes(), encoding=encoding), df)
+    assert_frame_equal(
+        pl.scan_csv(file_path, encoding=encoding).collect(engine="streaming"), df
+    )
+    assert pl.scan_csv(file_path, encoding=encoding).select(pl.len()).item() == 3
+
+    out_path = tmp_path / "written.csv"
+    df.write_csv(out_path, encoding=encoding)
+    assert out_path.read_bytes() == df.write_csv().encode(codec)
+
+    df.lazy().sink_csv(out_path, encoding=encoding)
+    assert out_path.read_bytes() == df.write_csv().encode(codec)
+
+
+@pytest.mark.write_disk
+def test_read_csv_native_encoding_invalid(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+
+    file_path = tmp_path / "invalid.csv"
+    # A truncated UTF-16 code unit.
+    file_path.write_bytes("a,b\n1,2\n".encode("utf-16-le") + b"\x00")
+
+    with pytest.raises(OSError, match="invalid Utf16Le sequence"):
+        pl.read_csv(file_path, encoding="utf16-le")
+    with pytest.raises(OSError, match="invalid Utf16Le sequence"):
+        pl.scan_csv(file_path, encoding="utf16-le").collect(engine="streaming")
+
+
+def test_write_csv_encoding_unmappable() -> None:
+    df = pl.DataFrame({"a": ["€"]})
+
+    with pytest.raises(InvalidOperationError, match="cannot be encoded in Latin1"):
+        df.write_csv(io.BytesIO(), encoding="latin1")
+    with pytest.raises(ValueError, match="when writing to a file"):
+        df.write_csv(encoding="latin1")
+
+
 @pytest.mark.may_fail_auto_streaming  # read->scan_csv dispatch
 def test_column_rename_and_schema_overrides() -> None:
     csv = textwrap.dedent(
@@ -1225,6 +1284,20 @@ def test_different_eol_char() -> None:
     )
 
 
+def test_read_csv_multi_byte_dialect() -> None:
+    csv = b'a||b\r\n"x||y"||1\r\nz\\||w\\"||2\r\n'
+    expected = pl.DataFrame({"a": ["x||y", 'z||w"'], "b": [1, 2]})
+
+    df = pl.read_csv(csv, separator="||", eol_char="\r\n", escape_char="\\")
+    assert_frame_equal(df, expected)
+    lf = pl.scan_csv(io.BytesIO(csv), separator="||", eol_char="\r\n", escape_char="\\")
+    assert_frame_equal(lf.collect(engine="streaming"), expected)
+    assert lf.select(pl.len()).collect().item() == 2
+
+    with pytest.raises(ValueError, match="should not be empty"):
+        pl.read_csv(csv, separator="")
+
+
 def test_csv_write_escape_headers() -> None:
     df0 = pl.DataFrame({"col,1": ["data,1"], 'col"2': ['data"2'], "col:3": ["data:3"]})
     out = io.BytesIO()
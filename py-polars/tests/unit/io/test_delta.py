@@ -1,10 +1,11 @@
 from __future__ import annotations
 
+import json
 import os
 import warnings
 from datetime import datetime, timezone
 from pathlib import Path
-from typing import Any
+from typing import Any, Literal
 
 import pyarrow as pa
 import pyarrow.fs
@@ -714,3 +715,105 @@ def test_scan_delta_storage_options_from_delta_table(
         assert_frame_equal(q.collect(), df)
 
     assert storage_options_checked
+
+
+@pytest.mark.parametrize("reader_override", ["native", "deltalake"])
+@pytest.mark.write_disk
+def test_scan_delta_partitioned(
+    tmp_path: Path, reader_override: Literal["native", "deltalake"]
+) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 2, 3, 4],
+            "p": ["x", "y", None, "x"],
+            "q": [10, 20, 30, 40],
+        }
+    )
+    df.write_delta(tmp_path, delta_write_options={"partition_by": ["p", "q"]})
+
+    q = pl.scan_delta(tmp_path, reader_override=reader_override)
+    assert q.collect_schema() == df.schema
+    assert_frame_equal(q.collect(), df, check_row_order=False)
+
+    assert_frame_equal(
+        q.filter(pl.col("p") == "x").collect(),
+        df.filter(pl.col("p") == "x"),
+        check_row_order=False,
+    )
+    assert_frame_equal(
+        q.filter(pl.col("p").is_null()).select("a", "q").collect(),
+        pl.DataFrame({"a": [3], "q": [30]}),
+    )
+
+
+@pytest.mark.parametrize("reader_override", ["native", "deltalake"])
+@pytest.mark.write_disk
+def test_scan_delta_overwrite_removes_files(
+    tmp_path: Path, reader_override: Literal["native", "deltalake"]
+) -> None:
+    pl.DataFrame({"a": [1, 2]}).write_delta(tmp_path)
+    pl.DataFrame({"a": [3]}).write_delta(tmp_path, mode="append")
+    pl.DataFrame({"a": [4]}).write_delta(tmp_path, mode="overwrite")
+
+    assert_frame_equal(
+        pl.scan_delta(tmp_path, reader_override=reader_override).collect(),
+        pl.DataFrame({"a": [4]}),
+    )
+    assert_frame_equal(
+        pl.scan_delta(tmp_path, version=1, reader_override=reader_override).collect(),
+        pl.DataFrame({"a": [1, 2, 3]}),
+        check_row_order=False,
+    )
+
+
+@pytest.mark.write_disk
+def test_scan_delta_skip_files_from_statistics(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+    capfd: pytest.CaptureFixture[str],
+) -> None:
+    pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]}).write_delta(tmp_path)
+    pl.DataFrame({"a": [10, 11], "b": ["u", "v"]}).write_delta(
+        tmp_path, mode="append"
+    )
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    capfd.readouterr()
+
+    q = pl.scan_delta(tmp_path, reader_override="native")
+    out = q.filter(pl.col("a") > 5).collect()
+    assert_frame_equal(out, pl.DataFrame({"a": [10, 11], "b": ["u", "v"]}))
+    assert "File statistics allow skipping 1 / 2 files" in capfd.readouterr().err
+
+
+@pytest.mark.write_disk
+def test_scan_delta_deletion_vector(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": range(40)})
+    df.write_delta(tmp_path)
+
+    log_dir = tmp_path / "_delta_log"
+    actions = [
+        json.loads(line)
+        for line in (log_dir / f"{0:020}.json").read_text().splitlines()
+    ]
+    add = next(action["add"] for action in actions if "add" in action)
+
+    # Example of the Delta protocol specification, deletes rows 3, 4, 7, 11, 18 and 29.
+    add["deletionVector"] = {
+        "storageType": "i",
+        "pathOrInlineDv": "wi5b=000010000siXQKl0rr91000f55c8Xg0@@D72lkbi5=-{L",
+        "sizeInBytes": 40,
+        "cardinality": 6,
+    }
+    commit = [{"remove": {"path": add["path"], "dataChange": True}}, {"add": add}]
+    (log_dir / f"{1:020}.json").write_text("\n".join(json.dumps(x) for x in commit))
+
+    expect = df.filter(~pl.col("a").is_in([3, 4, 7, 11, 18, 29]))
+
+    q = pl.scan_delta(tmp_path, reader_override="native")
+    assert_frame_equal(q.collect(), expect)
+    assert_frame_equal(q.head(5).collect(), expect.head(5))
+    assert_frame_equal(q.tail(3).collect(), expect.tail(3))
+    assert_frame_equal(
+        pl.scan_delta(tmp_path, version=0, reader_override="native").collect(), df
+    )
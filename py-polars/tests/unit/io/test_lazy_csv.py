@@ -1,17 +1,27 @@
 from __future__ import annotations
 
+import bz2
+import gzip
 import io
+import lzma
 import tempfile
+import zlib
 from collections import OrderedDict
 from pathlib import Path
+from typing import TYPE_CHECKING
 
 import numpy as np
+import pyarrow as pa
 import pytest
+import zstandard
 
 import polars as pl
 from polars.exceptions import ComputeError, ShapeError
 from polars.testing import assert_frame_equal
 
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
 
 @pytest.fixture
 def foods_file_path(io_files_path: Path) -> Path:
@@ -522,3 +532,66 @@ def test_csv_io_object_utf8_23629() -> None:
         f_str.seek(0)
         df_str = pl.read_csv(f_str)
         assert_frame_equal(df, df_str)
+
+
+def lz4_compress(data: bytes) -> bytes:
+    compressed: bytes = pa.compress(data, codec="lz4", asbytes=True)
+    return compressed
+
+
+@pytest.mark.parametrize(
+    "compress",
+    [
+        gzip.compress,
+        zlib.compress,
+        zstandard.compress,
+        bz2.compress,
+        lzma.compress,
+        lz4_compress,
+    ],
+)
+def test_scan_csv_compressed(compress: Callable[[bytes], bytes]) -> None:
+    df = pl.DataFrame(
+        {
+            "idx": range(10_000),
+            # Quoted line endings may be split across decompressed slices.
+            "text": ["a\nb", "c,d", "e"] * 3_333 + ["f"],
+        }
+    )
+    f = io.BytesIO()
+    df.write_csv(f)
+    data = compress(f.getvalue())
+
+    assert_frame_equal(pl.scan_csv(io.BytesIO(data)).collect(), df)
+    assert_frame_equal(
+        pl.scan_csv(io.BytesIO(data)).slice(5_000, 10).collect(),
+        df.slice(5_000, 10),
+    )
+    assert_frame_equal(
+        pl.scan_csv(io.BytesIO(data), skip_rows_after_header=2_000).collect(),
+        df.slice(2_000),
+    )
+    assert_frame_equal(
+        pl.scan_csv(io.BytesIO(data)).with_row_index().select("index").collect(),
+        df.with_row_index().select("index"),
+    )
+
+
+@pytest.mark.parametrize(
+    "compress",
+    [gzip.compress, zstandard.compress, bz2.compress, lzma.compress, lz4_compress],
+)
+def test_scan_csv_compressed_concatenated_frames(
+    compress: Callable[[bytes], bytes],
+) -> None:
+    df = pl.DataFrame(
+        {"idx": range(10_000), "text": ["a\nb", "c,d", "e"] * 3_333 + ["f"]}
+    )
+    f = io.BytesIO()
+    df.write_csv(f)
+    raw = f.getvalue()
+    # The frames are split in the middle of rows.
+    data = b"".join(compress(raw[i : i + 10_001]) for i in range(0, len(raw), 10_001))
+
+    assert_frame_equal(pl.scan_csv(io.BytesIO(data)).collect(), df)
+    assert_frame_equal(pl.read_csv(io.BytesIO(data)), df)
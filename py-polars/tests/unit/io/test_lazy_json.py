@@ -1,13 +1,21 @@
 from __future__ import annotations
 
+import bz2
+import gzip
+import io
+import lzma
+import zlib
 from typing import TYPE_CHECKING
 
+import pyarrow as pa
 import pytest
+import zstandard
 
 import polars as pl
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
+    from collections.abc import Callable
     from pathlib import Path
 
 
@@ -327,3 +335,60 @@ def test_scan_ndjson_schema_overwrite_22514() -> None:
     q = pl.scan_ndjson(buf, schema_overrides={"a": pl.String})
     assert q.collect_schema() == {"a": pl.String}
     assert_frame_equal(q.collect(), pl.DataFrame({"a": "1"}))
+
+
+def lz4_compress(data: bytes) -> bytes:
+    compressed: bytes = pa.compress(data, codec="lz4", asbytes=True)
+    return compressed
+
+
+@pytest.mark.parametrize(
+    "compress",
+    [
+        gzip.compress,
+        zlib.compress,
+        zstandard.compress,
+        bz2.compress,
+        lzma.compress,
+        lz4_compress,
+    ],
+)
+def test_scan_ndjson_compressed(compress: Callable[[bytes], bytes]) -> None:
+    df = pl.DataFrame(
+        {"idx": range(10_000), "text": ["a", "bc", "def"] * 3_333 + [""]}
+    )
+    f = io.BytesIO()
+    df.write_ndjson(f)
+    data = compress(f.getvalue())
+
+    assert_frame_equal(pl.scan_ndjson(io.BytesIO(data)).collect(), df)
+    assert_frame_equal(
+        pl.scan_ndjson(io.BytesIO(data), row_index_name="index")
+        .slice(5_000, 10)
+        .collect(),
+        df.with_row_index().slice(5_000, 10),
+    )
+    # Negative slices decompress the full file.
+    assert_frame_equal(
+        pl.scan_ndjson(io.BytesIO(data)).tail(10).collect(), df.tail(10)
+    )
+
+
+@pytest.mark.parametrize(
+    "compress",
+    [gzip.compress, zstandard.compress, bz2.compress, lzma.compress, lz4_compress],
+)
+def test_scan_ndjson_compressed_concatenated_frames(
+    compress: Callable[[bytes], bytes],
+) -> None:
+    df = pl.DataFrame(
+        {"idx": range(10_000), "text": ["a", "bc", "def"] * 3_333 + [""]}
+    )
+    f = io.BytesIO()
+    df.write_ndjson(f)
+    raw = f.getvalue()
+    # The frames are split in the middle of rows.
+    data = b"".join(compress(raw[i : i + 10_001]) for i in range(0, len(raw), 10_001))
+
+    assert_frame_equal(pl.scan_ndjson(io.BytesIO(data)).collect(), df)
+    assert_frame_equal(pl.read_ndjson(io.BytesIO(data)), df)
@@ -3,6 +3,7 @@ from __future__ import annotations
 import decimal
 import functools
 import io
+import struct
 import warnings
 from datetime import date, datetime, time, timezone
 from decimal import Decimal
@@ -21,8 +22,12 @@ from hypothesis import given
 from hypothesis import strategies as st
 
 import polars as pl
-from polars.exceptions import ComputeError
-from polars.io.parquet import ParquetFieldOverwrites
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.io.parquet import (
+    ParquetDecryption,
+    ParquetEncryption,
+    ParquetFieldOverwrites,
+)
 from polars.testing import assert_frame_equal, assert_series_equal
 from polars.testing.parametric import column, dataframes
 from polars.testing.parametric.strategies.core import series
@@ -3341,6 +3346,206 @@ def test_field_overwrites_metadata() -> None:
     assert schema[2].type.fields[1].metadata[b"md2"] == b"Yes!"
 
 
+def test_field_overwrites_bloom_filter(
+    monkeypatch: pytest.MonkeyPatch, capfd: pytest.CaptureFixture[str]
+) -> None:
+    f = io.BytesIO()
+    # Interleave the values so the statistics of every row group overlap.
+    a = [i % 10 * 100 + i // 10 for i in range(1_000)]
+    lf = pl.LazyFrame({"a": a}).with_columns(s=pl.format("v{}", "a"))
+    lf.sink_parquet(
+        f,
+        row_group_size=100,
+        field_overwrites={
+            "a": ParquetFieldOverwrites(bloom_filter=True),
+            "s": ParquetFieldOverwrites(bloom_filter={"ndv": 100, "fpp": 0.01}),
+        },
+    )
+
+    f.seek(0)
+    columns = pq.ParquetFile(f).metadata.to_dict()["row_groups"][0]["columns"]
+    assert all(c["bloom_filter_offset"] is not None for c in columns)
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    for expr, expected in [
+        (pl.col("a") == 123, [123]),
+        (pl.col("s") == "v123", [123]),
+        (pl.col("a").is_in([5, 123]), [5, 123]),
+    ]:
+        f.seek(0)
+        capfd.readouterr()
+        out = pl.scan_parquet(f).filter(expr).collect()
+        n_row_groups = len(expected)
+        assert (
+            f"Bloom filter pushdown: reading {n_row_groups} / 10 row groups"
+            in capfd.readouterr().err
+        )
+        assert sorted(out["a"].to_list()) == expected
+
+
+def test_field_overwrites_bloom_filter_float() -> None:
+    f = io.BytesIO()
+    nan = struct.unpack("<d", struct.pack("<Q", 0x7FF8_0000_0000_0001))[0]
+    df = pl.DataFrame({"x": [-0.0, 1.0, nan]})
+    df.lazy().sink_parquet(
+        f, field_overwrites={"x": ParquetFieldOverwrites(bloom_filter=True)}
+    )
+
+    f.seek(0)
+    columns = pq.ParquetFile(f).metadata.to_dict()["row_groups"][0]["columns"]
+    assert columns[0]["bloom_filter_offset"] is None
+
+    for expr in [pl.col("x") == 0.0, pl.col("x").is_nan(), pl.col("x").is_in([0.0])]:
+        f.seek(0)
+        assert_frame_equal(pl.scan_parquet(f).filter(expr).collect(), df.filter(expr))
+
+
+def test_field_overwrites_bloom_filter_invalid() -> None:
+    lf = pl.LazyFrame({"a": [[1, 2]], "b": [1]})
+
+    with pytest.raises(ValueError, match="unknown bloom filter options"):
+        lf.sink_parquet(
+            io.BytesIO(),
+            field_overwrites={"b": ParquetFieldOverwrites(bloom_filter={"size": 8})},
+        )
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="between 0 and 1"):
+        lf.sink_parquet(
+            io.BytesIO(),
+            field_overwrites={"b": ParquetFieldOverwrites(bloom_filter={"fpp": 2.0})},
+        )
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="bloom filter"):
+        lf.sink_parquet(
+            io.BytesIO(),
+            field_overwrites={"a": ParquetFieldOverwrites(bloom_filter=True)},
+        )
+
+
+def test_field_overwrites_encoding_compression_statistics() -> None:
+    f = io.BytesIO()
+    df = pl.DataFrame(
+        {
+            "i": [1, 5, 3, 3, None],
+            "f": [1.5, None, 2.25, -3.0, 1.5],
+            "s": ["abc", "abd", None, "xyz", "abc"],
+            "l": [[1.0], [2.0, 3.0], None, [], [4.0]],
+        }
+    )
+    df.lazy().sink_parquet(
+        f,
+        field_overwrites={
+            "i": ParquetFieldOverwrites(
+                encoding="delta_binary_packed", statistics=False
+            ),
+            "f": ParquetFieldOverwrites(
+                encoding="byte_stream_split", compression="snappy"
+            ),
+            "s": ParquetFieldOverwrites(encoding="delta_byte_array"),
+            "l": ParquetFieldOverwrites(
+                compression="gzip", compression_level=9, dictionary=False
+            ),
+        },
+    )
+
+    f.seek(0)
+    columns = pq.ParquetFile(f).metadata.to_dict()["row_groups"][0]["columns"]
+    columns = {c["path_in_schema"]: c for c in columns}
+    assert "DELTA_BINARY_PACKED" in columns["i"]["encodings"]
+    assert columns["i"]["statistics"] is None
+    assert "BYTE_STREAM_SPLIT" in columns["f"]["encodings"]
+    assert columns["f"]["compression"] == "SNAPPY"
+    assert "DELTA_BYTE_ARRAY" in columns["s"]["encodings"]
+    assert columns["s"]["compression"] == "ZSTD"
+    assert columns["l.list.item"]["compression"] == "GZIP"
+
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+    f.seek(0)
+    assert_frame_equal(pl.from_arrow(pq.read_table(f)), df)  # type: ignore[arg-type]
+
+
+def test_field_overwrites_encoding_invalid() -> None:
+    lf = pl.LazyFrame({"a": [1.0], "s": ["x"]})
+
+    with pytest.raises(ValueError, match="`encoding` must be one of"):
+        lf.sink_parquet(
+            io.BytesIO(),
+            field_overwrites={"a": ParquetFieldOverwrites(encoding="rle")},  # type: ignore[arg-type]
+        )
+
+    with pytest.raises(ValueError, match="no `compression`"):
+        lf.sink_parquet(
+            io.BytesIO(),
+            field_overwrites={"a": ParquetFieldOverwrites(compression_level=3)},
+        )
+
+    with pytest.raises(pl.exceptions.PolarsError, match="cannot be encoded"):
+        lf.sink_parquet(
+            io.BytesIO(),
+            field_overwrites={
+                "s": ParquetFieldOverwrites(encoding="delta_binary_packed")
+            },
+        )
+
+
+@pytest.mark.parametrize("column", ["l", "s"])
+def test_field_overwrites_encoding_nested(column: str) -> None:
+    df = pl.DataFrame({"l": [[1], [2, 3]], "s": [{"x": 1}, {"x": 2}]})
+
+    def overwrites(
+        leaf: ParquetFieldOverwrites,
+    ) -> dict[str, ParquetFieldOverwrites]:
+        children = leaf if column == "l" else {"x": leaf}
+        return {column: ParquetFieldOverwrites(children=children)}
+
+    leaf = ParquetFieldOverwrites(encoding="delta_binary_packed")
+    with pytest.raises(InvalidOperationError, match="nested parquet field"):
+        df.lazy().sink_parquet(io.BytesIO(), field_overwrites=overwrites(leaf))
+
+    # Plain is what the values of nested fields are written with anyway.
+    f = io.BytesIO()
+    leaf = ParquetFieldOverwrites(encoding="plain")
+    df.lazy().sink_parquet(f, field_overwrites=overwrites(leaf))
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+
+def test_page_index_pushdown(
+    monkeypatch: pytest.MonkeyPatch, capfd: pytest.CaptureFixture[str]
+) -> None:
+    df = pl.DataFrame({"a": range(10_000)}).with_columns(s=pl.col("a").cast(pl.String))
+    f = io.BytesIO()
+    df.write_parquet(f, data_page_size=1024)
+
+    f.seek(0)
+    row_group = pq.ParquetFile(f).metadata.row_group(0)
+    for i in range(row_group.num_columns):
+        assert row_group.column(i).has_column_index
+        assert row_group.column(i).has_offset_index
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    for expr in [
+        pl.col("a").is_between(1_000, 1_010, closed="left"),
+        pl.col("s") == "1234",
+        (pl.col("a") < 10) | (pl.col("a") > 9_990),
+    ]:
+        f.seek(0)
+        capfd.readouterr()
+        q = pl.scan_parquet(f).with_row_index().filter(expr)
+        assert_frame_equal(q.collect(), df.with_row_index().filter(expr))
+        assert "Page index pushdown" in capfd.readouterr().err
+
+    f.seek(0)
+    capfd.readouterr()
+    expr = pl.col("a").is_between(1_000, 1_010, closed="left")
+    pl.scan_parquet(f).filter(expr).collect()
+    assert (
+        "Page index pushdown: reading 113 / 10000 rows of row group 0"
+        in capfd.readouterr().err
+    )
+
+
 def multiple_test_sorting_columns() -> None:
     df = pl.DataFrame(
         {
@@ -3513,3 +3718,152 @@ def test_write_nested_categoricals() -> None:
 
     f.seek(0)
     assert_frame_equal(pl.read_parquet(f), df)
+
+
+@pytest.mark.parametrize("column_key_ids", [None, {"ssn": "pii"}])
+def test_parquet_encryption_roundtrip(column_key_ids: dict[str, str] | None) -> None:
+    keys = {"footer": b"0123456789012345", "pii": b"0123456789012345678901234567890x"}
+    df = pl.DataFrame(
+        {
+            "id": [1, 2, 3],
+            "ssn": ["123-45-6789", "987-65-4321", "555-55-5555"],
+        }
+    )
+
+    f = io.BytesIO()
+    df.write_parquet(
+        f,
+        encryption=ParquetEncryption("footer", keys, column_key_ids=column_key_ids),
+    )
+    assert f.getvalue()[:4] == b"PARE"
+    assert b"123-45-6789" not in f.getvalue()
+
+    f.seek(0)
+    out = pl.read_parquet(f, decryption=ParquetDecryption(keys))
+    assert_frame_equal(out, df)
+
+    f.seek(0)
+    out = (
+        pl.scan_parquet(f, decryption=ParquetDecryption(keys.__getitem__))
+        .filter(pl.col("id") > 1)
+        .collect()
+    )
+    assert_frame_equal(out, df.filter(pl.col("id") > 1))
+
+    f.seek(0)
+    with pytest.raises(ComputeError):
+        pl.read_parquet(f)
+
+    f.seek(0)
+    with pytest.raises(ComputeError):
+        pl.read_parquet(
+            f, decryption=ParquetDecryption({"footer": b"5432109876543210"})
+        )
+
+
+def test_parquet_encryption_unknown_column() -> None:
+    keys = {"footer": b"0123456789012345"}
+    encryption = ParquetEncryption("footer", keys, column_key_ids={"b": "footer"})
+    df = pl.DataFrame({"a": [1, 2, 3]})
+
+    with pytest.raises(pl.exceptions.ColumnNotFoundError, match="'b'"):
+        df.write_parquet(io.BytesIO(), encryption=encryption)
+
+    with pytest.raises(pl.exceptions.ColumnNotFoundError, match="'b'"):
+        df.lazy().sink_parquet(io.BytesIO(), encryption=encryption)
+
+
+def test_parquet_encryption_static_keys_not_serialized() -> None:
+    keys = {"footer": b"0123456789012345"}
+    lf = pl.scan_parquet("data.parquet", decryption=ParquetDecryption(keys))
+
+    with pytest.raises(ComputeError, match="cannot serialize static"):
+        lf.serialize()
+
+
+def test_parquet_encryption_aad_prefix() -> None:
+    keys = {"footer": b"0123456789012345"}
+    df = pl.DataFrame({"a": [1, 2, 3]})
+
+    f = io.BytesIO()
+    df.lazy().sink_parquet(
+        f,
+        encryption=ParquetEncryption(
+            "footer", keys, aad_prefix=b"part-0", store_aad_prefix=False
+        ),
+    )
+
+    f.seek(0)
+    out = pl.read_parquet(f, decryption=ParquetDecryption(keys, aad_prefix=b"part-0"))
+    assert_frame_equal(out, df)
+
+    f.seek(0)
+    with pytest.raises(ComputeError):
+        pl.read_parquet(f, decryption=ParquetDecryption(keys, aad_prefix=b"part-1"))
+
+
+def test_parquet_sink_sorting_columns() -> None:
+    lf = pl.LazyFrame({"a": [3, 1, 2, 1], "b": ["x", "z", "y", "w"], "c": [1.0] * 4})
+
+    f = io.BytesIO()
+    lf.sort("a", "b", descending=[False, True]).sink_parquet(f)
+    f.seek(0)
+    assert pq.ParquetFile(f).metadata.row_group(0).sorting_columns == (
+        pq.SortingColumn(0, descending=False, nulls_first=True),
+        pq.SortingColumn(1, descending=True, nulls_first=True),
+    )
+
+    # Floats are not recorded, as Parquet orders NaNs differently.
+    f = io.BytesIO()
+    lf.sort("c", "a").sink_parquet(f)
+    f.seek(0)
+    assert pq.ParquetFile(f).metadata.row_group(0).sorting_columns is None
+
+    # The sorted flag doesn't say where the nulls are, which Parquet requires.
+    f = io.BytesIO()
+    lf.with_columns(pl.col("b").set_sorted(descending=True)).sink_parquet(f)
+    f.seek(0)
+    assert pq.ParquetFile(f).metadata.row_group(0).sorting_columns is None
+
+
+def test_parquet_scan_sorting_columns_removes_sort() -> None:
+    df = pl.DataFrame({"a": [1, 1, 2, 3, 3, 4], "b": [2, 1, 0, 5, 4, 3]}).sort(
+        "a", "b", descending=[False, True]
+    )
+
+    f = io.BytesIO()
+    df.lazy().sort("a", "b", descending=[False, True]).sink_parquet(f)
+
+    f.seek(0)
+    q = pl.scan_parquet(f).sort("a", "b", descending=[False, True])
+    assert "SORT" not in q.explain()
+    assert_frame_equal(q.collect(), df)
+
+    assert "SORT" in q.explain(optimizations=pl.QueryOptFlags(sortedness=False))
+    assert "SORT" in q.explain(optimizations=pl.QueryOptFlags.none())
+
+    # With multiple row groups only the leading column is used, as its values repeat
+    # across row groups.
+    f = io.BytesIO()
+    df.lazy().sort("a", "b", descending=[False, True]).sink_parquet(
+        f, row_group_size=2
+    )
+    f.seek(0)
+    q = pl.scan_parquet(f).sort("a")
+    assert "SORT" not in q.explain()
+    f.seek(0)
+    q = pl.scan_parquet(f).sort("a", "b", descending=[False, True])
+    assert "SORT" in q.explain()
+
+    # Row groups that are sorted on their own, but not relative to each other.
+    f = io.BytesIO()
+    pq.write_table(
+        pa.table({"a": [3, 4, 1, 2]}),
+        f,
+        row_group_size=2,
+        sorting_columns=[pq.SortingColumn(0, nulls_first=True)],
+    )
+    f.seek(0)
+    q = pl.scan_parquet(f).sort("a")
+    assert "SORT" in q.explain()
+    assert_series_equal(q.collect().to_series(), pl.Series("a", [1, 2, 3, 4]))
@@ -32,13 +32,18 @@ class WritePositionDeletes:  # noqa: D101
         self.tmp_path = tmp_path
         self.i = 0
 
-    def __call__(self, positions: pl.Series) -> str:
+    def __call__(self, positions: pl.Series, file_paths: str | list[str] = "") -> str:
         path = self.tmp_path / f"{self.i}"
 
         (
             positions.alias("pos")
             .to_frame()
-            .select(pl.lit("").alias("file_path"), "pos")
+            .select(
+                pl.Series("file_path", file_paths, dtype=pl.String)
+                if isinstance(file_paths, list)
+                else pl.lit(file_paths).alias("file_path"),
+                "pos",
+            )
             .write_parquet(path)
         )
 
@@ -450,3 +455,129 @@ def test_scan_row_deletion_skips_file_with_all_rows_deleted(
 
     assert_frame_equal(q.slice(10).collect(), expect.drop("index"))
     assert_frame_equal(q.with_row_index().slice(10).collect(), expect)
+
+
+@pytest.mark.write_disk
+def test_scan_row_deletion_equality_deletes(
+    tmp_path: Path,
+    write_position_deletes: WritePositionDeletes,
+) -> None:
+    from polars.io.parquet import ParquetFieldOverwrites
+
+    def write_with_field_ids(df: pl.DataFrame, name: str, ids: list[int]) -> str:
+        path = tmp_path / name
+        df.lazy().sink_parquet(
+            path,
+            field_overwrites={
+                c: ParquetFieldOverwrites(metadata={"PARQUET:field_id": str(i)})
+                for c, i in zip(df.columns, ids)
+            },
+        )
+        return str(path)
+
+    data = pl.DataFrame(
+        {"key": [1, 2, 3, 4, 5], "value": ["a", "b", "c", "d", "e"]},
+        schema={"key": pl.Int64, "value": pl.String},
+    )
+    data_files = [
+        write_with_field_ids(data, "data-0.parquet", [1, 2]),
+        write_with_field_ids(data, "data-1.parquet", [1, 2]),
+    ]
+
+    # Equality delete files are matched by field ID, not by name. They can also have
+    # a narrower type than the data files.
+    delete_keys = write_with_field_ids(
+        pl.DataFrame({"k": [2, 4]}, schema={"k": pl.Int32}), "eq-0.parquet", [1]
+    )
+    delete_keys_late = write_with_field_ids(
+        pl.DataFrame({"k": [3]}, schema={"k": pl.Int32}), "eq-1.parquet", [1]
+    )
+    delete_pairs = write_with_field_ids(
+        pl.DataFrame({"k": [5, 1], "v": ["e", "x"]}), "eq-2.parquet", [1, 2]
+    )
+
+    deletion_files = (
+        "iceberg-delete",
+        {
+            # Data sequence number 1
+            0: (
+                data_files[0],
+                1,
+                [
+                    (delete_keys, 2, [1]),
+                    (delete_pairs, 5, [1, 2]),
+                ],
+            ),
+            # Data sequence number 3
+            1: (
+                data_files[1],
+                3,
+                [
+                    # Equality deletes only apply to data files with a smaller
+                    # sequence number.
+                    (delete_keys, 2, [1]),
+                    (delete_keys_late, 3, [1]),
+                    (delete_pairs, 5, [1, 2]),
+                    # Position deletes apply to data files with a smaller or equal
+                    # sequence number.
+                    (write_position_deletes(pl.Series([0]), data_files[1]), 3, None),
+                    (write_position_deletes(pl.Series([4]), data_files[1]), 1, None),
+                ],
+            ),
+        },
+    )
+
+    q = pl.scan_parquet(
+        data_files,
+        _deletion_files=deletion_files,  # type: ignore[arg-type]
+    ).with_row_index()
+
+    expect = pl.DataFrame(
+        {
+            "index": [0, 1, 2, 3, 4],
+            "key": [1, 3, 2, 3, 4],
+            "value": ["a", "c", "b", "c", "d"],
+        },
+        schema={"index": pl.get_index_type(), "key": pl.Int64, "value": pl.String},
+    )
+
+    assert_frame_equal(q.collect(), expect)
+    assert q.select(pl.len()).collect().item() == 5
+    assert_frame_equal(q.head(3).collect(), expect.head(3))
+    assert_frame_equal(q.slice(1, 3).collect(), expect.slice(1, 3))
+    assert_frame_equal(q.tail(2).collect(), expect.tail(2))
+
+
+@pytest.mark.write_disk
+def test_scan_row_deletion_position_deletes_multiple_data_files(
+    tmp_path: Path,
+    write_position_deletes: WritePositionDeletes,
+) -> None:
+    data_files = []
+
+    for i in range(2):
+        path = str(tmp_path / f"data-{i}.parquet")
+        pl.DataFrame({"x": [10 * i + j for j in range(5)]}).write_parquet(path)
+        data_files.append(path)
+
+    # A single position delete file can contain deletes for all data files of a
+    # partition, only the rows referencing the data file being read are applied.
+    shared = write_position_deletes(
+        pl.Series([0, 4, 1]), [data_files[0], data_files[0], data_files[1]]
+    )
+    own_0 = write_position_deletes(pl.Series([2]), data_files[0])
+    own_1 = write_position_deletes(pl.Series([3]), data_files[1])
+
+    q = pl.scan_parquet(
+        data_files,
+        _deletion_files=(
+            "iceberg-delete",
+            {
+                0: (data_files[0], 1, [(shared, 1, None), (own_0, 1, None)]),
+                1: (data_files[1], 1, [(shared, 1, None), (own_1, 1, None)]),
+            },
+        ),
+    )
+
+    assert_frame_equal(q.collect(), pl.DataFrame({"x": [1, 3, 10, 12, 14]}))
+    assert q.select(pl.len()).collect().item() == 5
@@ -1,11 +1,17 @@
+import bz2
+import gzip
 import io
+import lzma
 from pathlib import Path
-from typing import Any
+from typing import Any, Callable
 
+import pyarrow as pa
 import pytest
+import zstandard
 
 import polars as pl
-from polars._typing import EngineType
+from polars._typing import EngineType, ExternalCompression
+from polars.io.partition import PartitionMaxSize
 from polars.testing import assert_frame_equal
 
 SINKS = [
@@ -106,3 +112,69 @@ def test_sink_to_file(tmp_path: Path, sink: Any, scan: Any) -> None:
             scan(f).collect(),
             df,
         )
+
+
+COMPRESSIONS: list[tuple[ExternalCompression, Callable[[bytes], bytes]]] = [
+    ("gzip", gzip.decompress),
+    (
+        "zstd",
+        lambda b: zstandard.ZstdDecompressor().stream_reader(io.BytesIO(b)).read(),
+    ),
+    ("bzip2", bz2.decompress),
+    ("xz", lzma.decompress),
+    ("lz4", lambda b: pa.CompressedInputStream(io.BytesIO(b), "lz4").read()),
+]
+
+
+@pytest.mark.parametrize(
+    ("scan", "sink", "read"),
+    [
+        (pl.scan_csv, pl.LazyFrame.sink_csv, pl.read_csv),
+        (pl.scan_ndjson, pl.LazyFrame.sink_ndjson, pl.read_ndjson),
+    ],
+)
+@pytest.mark.parametrize(("compression", "decompress"), COMPRESSIONS)
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+@pytest.mark.write_disk
+def test_sink_compressed(
+    tmp_path: Path,
+    scan: Any,
+    sink: Any,
+    read: Any,
+    compression: ExternalCompression,
+    decompress: Callable[[bytes], bytes],
+    engine: EngineType,
+) -> None:
+    df = pl.DataFrame({"a": range(50_000), "b": ["x", "yy", None, "zzz", "w"] * 10_000})
+    path = tmp_path / "f"
+
+    sink(df.lazy(), path, compression=compression, engine=engine)
+
+    # Both engines compress the whole file into a single stream.
+    assert_frame_equal(read(io.BytesIO(decompress(path.read_bytes()))), df)
+    assert_frame_equal(scan(path).collect(), df)
+
+
+@pytest.mark.parametrize("sink", [pl.LazyFrame.sink_csv, pl.LazyFrame.sink_ndjson])
+def test_sink_compression_level_invalid(sink: Any) -> None:
+    df = pl.DataFrame({"a": [1, 2, 3]})
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="compression level"):
+        sink(df.lazy(), io.BytesIO(), compression="gzip", compression_level=10)
+
+
+@pytest.mark.write_disk
+def test_sink_compressed_partitioned(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": range(10)})
+
+    df.lazy().sink_csv(
+        PartitionMaxSize(tmp_path, max_size=5),
+        compression="gzip",
+        sync_on_close="data",
+    )
+
+    assert sorted(p.name for p in tmp_path.iterdir()) == [
+        "00000000.csv.gz",
+        "00000001.csv.gz",
+    ]
+    assert_frame_equal(pl.scan_csv(tmp_path / "*.csv.gz").collect(), df)
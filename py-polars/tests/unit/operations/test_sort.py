@@ -542,6 +542,17 @@ def test_set_sorted_schema() -> None:
     ) == {"A": pl.Int64}
 
 
+def test_sort_nulls_last_after_set_sorted() -> None:
+    # The sorted flag doesn't say where the nulls are, so the sort can't be skipped.
+    lf = pl.LazyFrame({"a": [None, 1, 2]}).with_columns(pl.col("a").set_sorted())
+    assert lf.sort("a", nulls_last=True).collect()["a"].to_list() == [1, 2, None]
+
+    lf = pl.LazyFrame({"a": [2, 1, None]}).with_columns(
+        pl.col("a").set_sorted(descending=True)
+    )
+    assert lf.sort("a", descending=True).collect()["a"].to_list() == [None, 2, 1]
+
+
 @pytest.mark.parametrize(
     ("sort_function"),
     [
@@ -9,7 +9,11 @@ import numpy as np
 import pytest
 
 import polars as pl
-from polars.exceptions import PolarsInefficientMapWarning
+from polars.exceptions import (
+    ComputeError,
+    InvalidOperationError,
+    PolarsInefficientMapWarning,
+)
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
@@ -380,3 +384,125 @@ def test_i128_sum_reduction() -> None:
         .item()
         == 6
     )
+
+
+@pytest.fixture
+def window_data() -> pl.LazyFrame:
+    n = 10_000
+    return pl.LazyFrame(
+        {
+            "g": [None if i % 97 == 0 else (i * 7919) % 113 for i in range(n)],
+            "h": [f"k{(i * 31) % 5}" for i in range(n)],
+            "a": [None if i % 11 == 0 else float((i * 13) % 1000) for i in range(n)],
+        }
+    )
+
+
+@pytest.mark.parametrize(
+    "expr",
+    [
+        pl.col("a").sum().over("g"),
+        pl.col("a").mean().over("g", "h"),
+        pl.len().over("h"),
+        pl.col("a") - pl.col("a").mean().over("g"),
+        pl.col("a").first().over(pl.col("g") % 3),
+        (pl.col("a").max() - pl.col("a").min()).over("h"),
+    ],
+)
+def test_streaming_window_aggregation(window_data: pl.LazyFrame, expr: pl.Expr) -> None:
+    q = window_data.select(expr.alias("out"), "g")
+    plan = q.show_graph(raw_output=True, plan_stage="physical", engine="streaming")
+    assert "in-memory-map" not in plan
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.parametrize(
+    ("by", "expr"),
+    [
+        ("g", pl.col("a").cum_sum().over("g")),
+        ("g", pl.col("a").rank().over("g")),
+        ("g", pl.col("a").shift().over("g", order_by="h")),
+        ("g", pl.col("a").head(2).over("g", mapping_strategy="join")),
+        (["h", "g"], pl.col("a").diff().over("g", "h")),
+        (["h", "g"], pl.col("a").cum_max().over("h")),
+    ],
+)
+def test_streaming_window_sorted_input(
+    window_data: pl.LazyFrame, by: str | list[str], expr: pl.Expr
+) -> None:
+    q = window_data.sort(by).filter(pl.col("a") > 100).with_columns(out=expr)
+    plan = q.show_graph(raw_output=True, plan_stage="physical", engine="streaming")
+    assert "sorted-window" in plan
+    assert "in-memory-map" not in plan
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+def test_streaming_window_flagged_sorted_input(window_data: pl.LazyFrame) -> None:
+    # Already sorted data without an explicit sort in the query.
+    df = window_data.collect().sort("g")
+    unflagged = pl.DataFrame(df.to_dict(as_series=False), schema=df.schema)
+    for lf in [
+        df.lazy(),
+        unflagged.lazy().with_columns(pl.col("g").set_sorted()),
+    ]:
+        q = lf.with_columns(out=pl.col("a").cum_sum().over("g"))
+        plan = q.show_graph(raw_output=True, plan_stage="physical", engine="streaming")
+        assert "sorted-window" in plan
+        assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+def test_streaming_window_unsorted_input_fallback(window_data: pl.LazyFrame) -> None:
+    q = window_data.sort("h").select(out=pl.col("a").cum_sum().over("g"))
+    plan = q.show_graph(raw_output=True, plan_stage="physical", engine="streaming")
+    assert "sorted-window" not in plan
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize(
+    "query",
+    [
+        lambda lf: lf.group_by("a").agg(pl.col("b").sum()).sort("a"),
+        lambda lf: lf.sort("a", "b").slice(1000, 100),
+        lambda lf: lf.join(
+            lf.group_by("a").agg(m=pl.col("b").min()), on="a"
+        ).select(pl.col("b", "m").sum()),
+    ],
+)
+def test_streaming_memory_limit_spills(
+    tmp_path: Path, monkeypatch: Any, query: Any
+) -> None:
+    n = 200_000
+    df = pl.DataFrame({"a": [(i * 7919) % 1000 for i in range(n)], "b": range(n)})
+    df.write_parquet(tmp_path / "data.parquet", row_group_size=10_000)
+    expected = query(df.lazy()).collect()
+
+    # The scanned data is over the limit, the result is well under it.
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_STREAMING_MEMORY_LIMIT", "200000")
+    q = query(pl.scan_parquet(tmp_path / "data.parquet"))
+    assert_frame_equal(q.collect(engine="streaming"), expected)
+
+
+@pytest.mark.parametrize(
+    "query",
+    [
+        lambda lf: lf.filter(pl.col("a") % 2 == 0),
+        lambda lf: lf.unique(keep="last"),
+        lambda lf: lf.join_asof(lf.select(b="a"), left_on="a", right_on="b"),
+    ],
+)
+def test_streaming_memory_limit_exceeded(monkeypatch: Any, query: Any) -> None:
+    monkeypatch.setenv("POLARS_STREAMING_MEMORY_LIMIT", "10000")
+
+    lf = query(pl.LazyFrame({"a": range(100_000)}))
+    with pytest.raises(ComputeError, match="memory budget of 10000 bytes"):
+        lf.collect(engine="streaming")
+
+
+def test_streaming_memory_limit_invalid(monkeypatch: Any) -> None:
+    monkeypatch.setenv("POLARS_STREAMING_MEMORY_LIMIT", "10kb")
+
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+    with pytest.raises(InvalidOperationError, match="POLARS_STREAMING_MEMORY_LIMIT"):
+        lf.collect(engine="streaming")
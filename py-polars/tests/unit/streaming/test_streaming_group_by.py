@@ -14,6 +14,8 @@ from tests.unit.conftest import INTEGER_DTYPES
 if TYPE_CHECKING:
     from pathlib import Path
 
+    from polars._typing import ClosedInterval, Label, StartBy
+
 pytestmark = pytest.mark.xdist_group("streaming")
 
 
@@ -292,6 +294,39 @@ def test_streaming_group_by_ooc_q3(
     assert_frame_equal(result, expected)
 
 
+@pytest.mark.write_disk
+def test_streaming_group_by_spill_high_cardinality(
+    tmp_path: Path,
+    monkeypatch: Any,
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+
+    n = 100_000
+    lf = pl.LazyFrame(
+        {
+            "k": pl.int_range(n, eager=True) * 7919 % 20_000,
+            "s": pl.int_range(n, eager=True).cast(pl.String).str.slice(0, 2),
+            "v": pl.int_range(n, eager=True).cast(pl.Float64),
+        }
+    )
+    q = (
+        lf.group_by("k", "s")
+        .agg(
+            pl.col("v").sum().alias("sum"),
+            pl.col("v").mean().alias("mean"),
+            pl.col("v").min().alias("min"),
+            pl.col("v").first().alias("first"),
+            pl.col("v").last().alias("last"),
+            pl.len(),
+        )
+        .sort("k", "s")
+    )
+
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
 def test_streaming_group_by_struct_key() -> None:
     df = pl.DataFrame(
         {"A": [1, 2, 3, 2], "B": ["google", "ms", "apple", "ms"], "C": [2, 3, 4, 3]}
@@ -512,3 +547,85 @@ def test_streaming_group_by_all_null_21593() -> None:
 
     out = df.lazy().group_by(pl.all()).min().collect(engine="streaming")
     assert_frame_equal(df, out, check_row_order=False)
+
+
+@pytest.fixture(scope="module")
+def sorted_sensor_data() -> pl.DataFrame:
+    # Irregular timestamps with duplicates and gaps, spanning a few years.
+    n = 300_000
+    steps = np.tile([0, 1, 37, 3600, 0, 3 * 86400, 59], n // 7 + 1)[:n]
+    return pl.DataFrame(
+        {
+            "t": np.cumsum(steps) * 1000 + 1_600_000_000_000,
+            "v": np.arange(n),
+        }
+    ).with_columns(pl.col("t").cast(pl.Datetime("ms")))
+
+
+@pytest.mark.parametrize(
+    ("every", "period", "offset"),
+    [("1h", "1h", "0h"), ("1d", "3d", "-1d"), ("1mo", "2mo", "5d"), ("2d", "1d", "3h")],
+)
+@pytest.mark.parametrize("closed", ["left", "right", "both", "none"])
+@pytest.mark.parametrize("label", ["left", "right", "datapoint"])
+@pytest.mark.parametrize("start_by", ["window", "datapoint", "wednesday"])
+def test_streaming_group_by_dynamic(
+    sorted_sensor_data: pl.DataFrame,
+    every: str,
+    period: str,
+    offset: str,
+    closed: ClosedInterval,
+    label: Label,
+    start_by: StartBy,
+) -> None:
+    q = (
+        sorted_sensor_data.lazy()
+        .group_by_dynamic(
+            "t",
+            every=every,
+            period=period,
+            offset=offset,
+            closed=closed,
+            label=label,
+            start_by=start_by,
+            include_boundaries=True,
+        )
+        .agg(pl.col("v").sum(), pl.len(), pl.col("v").first().alias("first"))
+    )
+    expected = q.collect(engine="in-memory")
+    assert_frame_equal(q.collect(engine="streaming"), expected)
+    assert_frame_equal(
+        q.slice(10, 20).collect(engine="streaming"), expected.slice(10, 20)
+    )
+
+
+@pytest.mark.parametrize(
+    ("period", "offset"), [("1h", None), ("3d", "0d"), ("1mo", "-2d"), ("10m", "5m")]
+)
+@pytest.mark.parametrize("closed", ["right", "both"])
+def test_streaming_rolling(
+    sorted_sensor_data: pl.DataFrame,
+    period: str,
+    offset: str | None,
+    closed: ClosedInterval,
+) -> None:
+    q = (
+        sorted_sensor_data.lazy()
+        .rolling("t", period=period, offset=offset, closed=closed)
+        .agg(pl.col("v").sum(), pl.len(), pl.col("v").last().alias("last"))
+    )
+    expected = q.collect(engine="in-memory")
+    assert_frame_equal(q.collect(engine="streaming"), expected)
+    assert_frame_equal(
+        q.slice(1000, 50).collect(engine="streaming"), expected.slice(1000, 50)
+    )
+
+
+def test_streaming_group_by_dynamic_unsorted() -> None:
+    q = (
+        pl.LazyFrame({"t": [3, 2, 1], "v": [1, 2, 3]})
+        .group_by_dynamic("t", every="1i")
+        .agg(pl.col("v").sum())
+    )
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="not sorted"):
+        q.collect(engine="streaming")
@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 from datetime import datetime
-from typing import TYPE_CHECKING, Literal
+from typing import TYPE_CHECKING, Any, Literal
 
 import numpy as np
 import pandas as pd
@@ -13,7 +13,7 @@ from polars.testing import assert_frame_equal, assert_series_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
-    from polars._typing import JoinStrategy
+    from polars._typing import AsofJoinStrategy, JoinStrategy, MaintainOrderJoin
 
 pytestmark = pytest.mark.xdist_group("streaming")
 
@@ -386,3 +386,189 @@ def test_invert_order_full_join_22295() -> None:
     lf.join(lf, on=["value", "value_at"], how="full", coalesce=True).collect(
         engine="streaming"
     )
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("how", ["inner", "left", "right", "full"])
+@pytest.mark.parametrize(
+    "maintain_order", ["none", "left", "right", "left_right", "right_left"]
+)
+def test_streaming_join_spill(
+    tmp_path: Path,
+    monkeypatch: Any,
+    how: JoinStrategy,
+    maintain_order: MaintainOrderJoin,
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+
+    n = 50_000
+    lhs = pl.LazyFrame(
+        {
+            "a": [None if i % 97 == 0 else (i * 7919) % 20_000 for i in range(n)],
+            "v": range(n),
+        }
+    )
+    m = 30_000
+    rhs = pl.LazyFrame(
+        {
+            "a": [None if i % 89 == 0 else (i * 104_729) % 25_000 for i in range(m)],
+            "w": [f"w{i}" for i in range(m)],
+        }
+    )
+    q = lhs.join(rhs, on="a", how=how, maintain_order=maintain_order, coalesce=True)
+
+    # The position of unmatched right rows is unspecified when only the left
+    # order is maintained.
+    check_row_order = maintain_order != "none" and not (
+        how == "full" and maintain_order == "left"
+    )
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=check_row_order,
+    )
+
+
+@pytest.mark.parametrize("strategy", ["backward", "forward", "nearest"])
+@pytest.mark.parametrize("allow_exact_matches", [False, True])
+@pytest.mark.parametrize("tolerance", [None, 5])
+def test_streaming_join_asof(
+    strategy: AsofJoinStrategy, allow_exact_matches: bool, tolerance: int | None
+) -> None:
+    n = 300_000
+    lhs = pl.LazyFrame({"t": [i // 3 for i in range(n)], "v": range(n)})
+    m = 200_000
+    rhs = pl.LazyFrame({"t": [None] * 10 + [i // 2 * 3 for i in range(m)]})
+    rhs = rhs.with_columns(w=pl.int_range(pl.len())).set_sorted("t")
+
+    q = lhs.join_asof(
+        rhs,
+        on="t",
+        strategy=strategy,
+        allow_exact_matches=allow_exact_matches,
+        tolerance=tolerance,
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+    assert_frame_equal(
+        q.slice(1000, 50).collect(engine="streaming"),
+        q.slice(1000, 50).collect(engine="in-memory"),
+    )
+
+
+@pytest.mark.parametrize("strategy", ["backward", "forward", "nearest"])
+def test_streaming_join_asof_by(strategy: AsofJoinStrategy) -> None:
+    # The keys are only sorted within each group, some groups only occur on one
+    # side.
+    n = 300_000
+    lhs = pl.LazyFrame({"i": range(n)}).select(
+        g=pl.when(pl.col("i") % 11 == 0).then(None).otherwise(pl.col("i") % 7),
+        t=pl.col("i") // 7 + pl.col("i") % 7 * 1_000,
+        v=pl.col("i"),
+    )
+    m = 200_000
+    rhs = pl.LazyFrame({"j": range(m)}).select(
+        h=pl.col("j") % 5,
+        t=pl.col("j") // 5 * 3 + pl.col("j") % 5 * 1_000,
+        w=pl.col("j"),
+    )
+
+    q = lhs.join_asof(
+        rhs,
+        on="t",
+        left_by="g",
+        right_by="h",
+        strategy=strategy,
+        tolerance=100,
+        check_sortedness=False,
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+    assert_frame_equal(
+        q.slice(1000, 50).collect(engine="streaming"),
+        q.slice(1000, 50).collect(engine="in-memory"),
+    )
+
+
+def test_streaming_join_asof_not_sorted() -> None:
+    lhs = pl.LazyFrame({"t": [3, 1, 2]})
+    rhs = pl.LazyFrame({"t": [1, 2, 3], "w": [1, 2, 3]})
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="not sorted"):
+        lhs.join_asof(rhs, on="t").collect(engine="streaming")
+
+
+def test_streaming_join_asof_by_not_sorted() -> None:
+    # Sorted as a whole within the left groups, but not within the right ones.
+    lhs = pl.LazyFrame({"g": [1, 2], "t": [1, 2]})
+    rhs = pl.LazyFrame({"g": [1, 2, 1], "t": [3, 1, 2], "w": [1, 2, 3]})
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="not sorted"):
+        lhs.join_asof(rhs, on="t", by="g").collect(engine="streaming")
+
+    # Sorted within the groups, but not as a whole.
+    rhs = pl.LazyFrame({"g": [1, 2, 1], "t": [2, 1, 3], "w": [1, 2, 3]})
+    assert_frame_equal(
+        lhs.join_asof(rhs, on="t", by="g").collect(engine="streaming"),
+        lhs.join_asof(rhs, on="t", by="g", check_sortedness=False).collect(),
+    )
+
+
+def _join_where_inputs() -> tuple[pl.LazyFrame, pl.LazyFrame]:
+    n = 3_000
+    lhs = pl.LazyFrame(
+        {
+            "start": [None if i % 97 == 0 else (i * 7919) % 2_000 for i in range(n)],
+            "end": [(i * 104_729) % 1_500 / 3 for i in range(n)],
+            "v": range(n),
+        }
+    )
+    m = 2_500
+    rhs = pl.LazyFrame(
+        {
+            "t": [None if i % 89 == 0 else (i * 31) % 2_000 for i in range(m)],
+            "u": [
+                float("nan") if i % 50 == 0 else (i * 17) % 1_500 / 3
+                for i in range(m)
+            ],
+            "w": range(m),
+        }
+    )
+    return lhs, rhs
+
+
+@pytest.mark.parametrize("op1", ["<", "<=", ">", ">="])
+@pytest.mark.parametrize("op2", [None, "<", ">="])
+def test_streaming_join_where(op1: str, op2: str | None) -> None:
+    lhs, rhs = _join_where_inputs()
+    ops = {
+        "<": pl.Expr.lt,
+        "<=": pl.Expr.le,
+        ">": pl.Expr.gt,
+        ">=": pl.Expr.ge,
+    }
+    predicates = [ops[op1](pl.col("start"), pl.col("t"))]
+    if op2 is not None:
+        predicates.append(ops[op2](pl.col("end"), pl.col("u")))
+    q = lhs.join_where(rhs, *predicates)
+
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
+
+
+@pytest.mark.write_disk
+def test_streaming_join_where_spill(tmp_path: Path, monkeypatch: Any) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+
+    lhs, rhs = _join_where_inputs()
+    q = lhs.join_where(
+        rhs, (pl.col("start") + 5) < pl.col("t"), pl.col("end") > pl.col("u") * 2
+    )
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
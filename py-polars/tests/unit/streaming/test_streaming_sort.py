@@ -2,7 +2,7 @@ from __future__ import annotations
 
 from collections import Counter
 from datetime import datetime
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Any
 
 import numpy as np
 import pytest
@@ -183,3 +183,40 @@ def test_sort_descending_nulls_last(descending: bool, nulls_last: bool) -> None:
         .collect(engine="streaming"),
         pl.DataFrame({"x": ref_x, "y": ref_y}),
     )
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize(
+    ("by", "descending", "nulls_last"),
+    [
+        (["a"], [False], [False]),
+        (["a", "s"], [True, False], [True, False]),
+        (["s", "a", "v"], [False, True, False], [True, True, True]),
+    ],
+)
+@pytest.mark.parametrize("slice_args", [None, (10, 100), (-500, 100), (0, 5)])
+def test_streaming_sort_ooc(
+    tmp_path: Path,
+    monkeypatch: Any,
+    by: list[str],
+    descending: list[bool],
+    nulls_last: list[bool],
+    slice_args: tuple[int, int] | None,
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+
+    n = 100_000
+    lf = pl.LazyFrame(
+        {
+            "a": [None if i % 97 == 0 else (i * 7919) % 1000 for i in range(n)],
+            "s": [f"k{(i * 31) % 300}" for i in range(n)],
+            "v": range(n),
+        }
+    )
+    q = lf.sort(by, descending=descending, nulls_last=nulls_last, maintain_order=True)
+    if slice_args is not None:
+        q = q.slice(*slice_args)
+
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
@@ -10,6 +10,8 @@ from polars.testing import assert_frame_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
+    from polars._typing import UniqueKeepStrategy
+
 pytestmark = pytest.mark.xdist_group("streaming")
 
 
@@ -49,3 +51,35 @@ def test_streaming_unique() -> None:
 
     q = df.lazy().unique(subset=None, maintain_order=False).sort(["a", "b", "c"])
     assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.parametrize("keep", ["first", "last", "any", "none"])
+@pytest.mark.parametrize("maintain_order", [False, True])
+def test_streaming_unique_keep(keep: UniqueKeepStrategy, maintain_order: bool) -> None:
+    n = 200_000
+    df = pl.DataFrame(
+        {
+            "a": pl.int_range(n, eager=True) % 50_000,
+            "b": (pl.int_range(n, eager=True) * 7) % 3,
+            "c": pl.int_range(n, eager=True),
+        }
+    ).with_columns(a=pl.when(pl.col("c") % 11 == 0).then(None).otherwise("a"))
+    q = df.lazy().unique(subset=["a", "b"], keep=keep, maintain_order=maintain_order)
+
+    # Without maintain_order any row of a key may be kept for first and last.
+    keeps_any_row = keep == "any" or (keep != "none" and not maintain_order)
+    expected = q.collect(engine="in-memory")
+    if keeps_any_row:
+        expected = expected.select("a", "b")
+
+    out = q.collect(engine="streaming")
+    if keeps_any_row:
+        out = out.select("a", "b")
+    if not maintain_order or keep == "any":
+        out = out.sort(out.columns)
+        expected = expected.sort(expected.columns)
+    assert_frame_equal(out, expected)
+
+    q = q.slice(10, 20)
+    if maintain_order and keep != "any":
+        assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))